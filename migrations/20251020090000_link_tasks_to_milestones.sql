@@ -0,0 +1,6 @@
+-- Link tasks to the milestone they contribute to
+
+ALTER TABLE tasks
+ADD COLUMN milestone_id BIGINT REFERENCES milestones(id) ON DELETE SET NULL;
+
+CREATE INDEX idx_tasks_milestone_id ON tasks(milestone_id) WHERE deleted_at IS NULL;
@@ -0,0 +1,13 @@
+-- Point-in-time copies of the status report's key metrics for trend display
+
+CREATE TABLE status_snapshots (
+    id BIGSERIAL PRIMARY KEY,
+    total_projects BIGINT NOT NULL,
+    active_projects BIGINT NOT NULL,
+    completed_projects BIGINT NOT NULL,
+    delayed_projects BIGINT NOT NULL,
+    average_progress DOUBLE PRECISION NOT NULL,
+    captured_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_status_snapshots_captured_at ON status_snapshots(captured_at DESC);
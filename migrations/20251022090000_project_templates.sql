@@ -0,0 +1,13 @@
+-- Project templates captured from existing projects, versioned by name
+
+CREATE TABLE project_templates (
+    id BIGSERIAL PRIMARY KEY,
+    name VARCHAR(100) NOT NULL,
+    version INTEGER NOT NULL,
+    source_project_id BIGINT REFERENCES projects(id) ON DELETE SET NULL,
+    template JSONB NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (name, version)
+);
+
+CREATE INDEX idx_project_templates_name ON project_templates(name, version DESC);
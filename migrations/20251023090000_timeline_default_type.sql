@@ -0,0 +1,5 @@
+-- Configurable default type for `timeline create`
+
+INSERT INTO configurations (config_key, config_value, description, data_type) VALUES
+('timeline.default_type', 'project', 'Default type for new timelines (project, sprint, release, phase)', 'string')
+ON CONFLICT (config_key) DO NOTHING;
@@ -0,0 +1,5 @@
+-- Reject duplicate (name, version) pairs in `resource create` unless disabled
+
+INSERT INTO configurations (config_key, config_value, description, data_type) VALUES
+('resource.unique_name_version', 'true', 'Reject new resources whose name and version match an existing one', 'boolean')
+ON CONFLICT (config_key) DO NOTHING;
@@ -0,0 +1,24 @@
+-- Record task status changes in audit_logs so the time spent in the
+-- current status can be reported
+
+CREATE OR REPLACE FUNCTION log_task_status_change()
+RETURNS TRIGGER AS $$
+BEGIN
+    IF NEW.status IS DISTINCT FROM OLD.status THEN
+        INSERT INTO audit_logs (entity_type, entity_id, action, old_values, new_values)
+        VALUES (
+            'task',
+            NEW.id,
+            'status_change',
+            jsonb_build_object('status', OLD.status),
+            jsonb_build_object('status', NEW.status)
+        );
+    END IF;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER log_tasks_status_change
+    AFTER UPDATE OF status ON tasks
+    FOR EACH ROW
+    EXECUTE FUNCTION log_task_status_change();
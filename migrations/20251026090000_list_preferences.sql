@@ -0,0 +1,6 @@
+-- Persisted defaults for list commands, used when --per-page / --sort are omitted
+
+INSERT INTO configurations (config_key, config_value, description, data_type) VALUES
+('list.per_page', '50', 'Default page size for list commands when --per-page is omitted', 'integer'),
+('list.order_by', '', 'Default sort for project lists when --sort is omitted (created-at, name, start-date, end-date); empty keeps the built-in order', 'string')
+ON CONFLICT (config_key) DO NOTHING;
@@ -0,0 +1,19 @@
+-- Individual time entries behind tasks.actual_hours
+
+CREATE TABLE task_work_logs (
+    -- Primary Key
+    id BIGSERIAL PRIMARY KEY,
+
+    -- Foreign Key
+    task_id BIGINT NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+
+    -- Work Information
+    hours DOUBLE PRECISION NOT NULL CHECK (hours > 0),
+    logged_by VARCHAR(100) NOT NULL,
+    note TEXT,
+
+    -- Timestamp
+    logged_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX idx_task_work_logs_task_logged_at ON task_work_logs(task_id, logged_at DESC);
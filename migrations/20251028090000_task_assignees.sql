@@ -0,0 +1,20 @@
+-- Additional people working on a task; tasks.assigned_to stays the primary assignee
+
+CREATE TABLE task_assignees (
+    -- Keys
+    task_id BIGINT NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+    assignee VARCHAR(100) NOT NULL,
+
+    -- Timestamp
+    assigned_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    PRIMARY KEY (task_id, assignee)
+);
+
+CREATE INDEX idx_task_assignees_assignee ON task_assignees(assignee);
+
+-- Existing primary assignees become the first entry of each task
+INSERT INTO task_assignees (task_id, assignee)
+SELECT id, assigned_to
+FROM tasks
+WHERE assigned_to IS NOT NULL AND deleted_at IS NULL;
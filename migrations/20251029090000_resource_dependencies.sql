@@ -0,0 +1,15 @@
+-- Resources that require other resources (e.g. a framework needing a runtime)
+
+CREATE TABLE resource_dependencies (
+    -- Keys
+    resource_id BIGINT NOT NULL REFERENCES resources(id) ON DELETE CASCADE,
+    depends_on_resource_id BIGINT NOT NULL REFERENCES resources(id) ON DELETE CASCADE,
+
+    -- Timestamp
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    PRIMARY KEY (resource_id, depends_on_resource_id),
+    CONSTRAINT resource_dependencies_no_self CHECK (resource_id <> depends_on_resource_id)
+);
+
+CREATE INDEX idx_resource_dependencies_depends_on ON resource_dependencies(depends_on_resource_id);
@@ -0,0 +1,5 @@
+-- Warn in `task update` when actual hours overrun the estimate by more than this percentage
+
+INSERT INTO configurations (config_key, config_value, description, data_type) VALUES
+('task.variance_threshold_pct', '50', 'Warn when a task update leaves actual hours more than this many percent over the estimate', 'integer')
+ON CONFLICT (config_key) DO NOTHING;
@@ -0,0 +1,5 @@
+-- Holidays excluded, along with weekends, from working-day project durations
+
+INSERT INTO configurations (config_key, config_value, description, data_type) VALUES
+('calendar.holidays', '[]', 'Holidays (JSON array of YYYY-MM-DD dates) left out of working-day durations', 'json')
+ON CONFLICT (config_key) DO NOTHING;
@@ -0,0 +1,15 @@
+-- Explicit milestone order within a timeline, so milestones sharing a target date sort stably
+
+ALTER TABLE milestones
+ADD COLUMN sequence INTEGER NOT NULL DEFAULT 0;
+
+-- Number existing milestones in their current (target date) order
+UPDATE milestones m
+SET sequence = ordered.position
+FROM (
+    SELECT id, ROW_NUMBER() OVER (PARTITION BY timeline_id ORDER BY target_date, id) AS position
+    FROM milestones
+) ordered
+WHERE m.id = ordered.id;
+
+CREATE INDEX idx_milestones_timeline_sequence ON milestones(timeline_id, sequence) WHERE deleted_at IS NULL;
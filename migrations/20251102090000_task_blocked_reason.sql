@@ -0,0 +1,4 @@
+-- Record why a task is blocked; cleared when the task leaves the blocked status
+
+ALTER TABLE tasks
+ADD COLUMN blocked_reason TEXT;
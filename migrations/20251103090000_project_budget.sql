@@ -0,0 +1,13 @@
+-- Budget and currency for cost-to-date tracking on projects
+
+ALTER TABLE projects
+    ADD COLUMN budget DOUBLE PRECISION,
+    ADD COLUMN currency VARCHAR(3);
+
+ALTER TABLE projects
+    ADD CONSTRAINT projects_budget_non_negative CHECK (budget IS NULL OR budget >= 0);
+
+-- Rate that turns logged task hours into project cost
+INSERT INTO configurations (config_key, config_value, description, data_type) VALUES
+('cost.hourly_rate', '0', 'Hourly rate applied to task actual hours for project cost; 0 leaves cost uncomputed', 'string')
+ON CONFLICT (config_key) DO NOTHING;
@@ -0,0 +1,5 @@
+-- Earliest date a timeline shift may move anything to
+
+INSERT INTO configurations (config_key, config_value, description, data_type) VALUES
+('timeline.date_floor', '2000-01-01', 'Earliest date (YYYY-MM-DD) timeline shift may move a timeline or milestone to; empty disables the check', 'string')
+ON CONFLICT (config_key) DO NOTHING;
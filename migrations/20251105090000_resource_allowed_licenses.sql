@@ -0,0 +1,5 @@
+-- Licenses accepted by `resource license-report`; an empty list allows all
+
+INSERT INTO configurations (config_key, config_value, description, data_type) VALUES
+('resource.allowed_licenses', '[]', 'Licenses (JSON array) resource license-report treats as compliant; empty allows all', 'json')
+ON CONFLICT (config_key) DO NOTHING;
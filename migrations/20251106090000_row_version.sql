@@ -0,0 +1,26 @@
+-- Row version for optimistic concurrency on projects and tasks; bumped by
+-- trigger on every update so writers can detect concurrent edits
+
+ALTER TABLE projects
+ADD COLUMN version INTEGER NOT NULL DEFAULT 1;
+
+ALTER TABLE tasks
+ADD COLUMN version INTEGER NOT NULL DEFAULT 1;
+
+CREATE OR REPLACE FUNCTION increment_row_version()
+RETURNS TRIGGER AS $$
+BEGIN
+    NEW.version = OLD.version + 1;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER increment_projects_version
+    BEFORE UPDATE ON projects
+    FOR EACH ROW
+    EXECUTE FUNCTION increment_row_version();
+
+CREATE TRIGGER increment_tasks_version
+    BEFORE UPDATE ON tasks
+    FOR EACH ROW
+    EXECUTE FUNCTION increment_row_version();
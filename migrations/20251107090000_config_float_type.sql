@@ -0,0 +1,5 @@
+-- Store the hourly rate as a number so `config set` rejects non-numeric rates
+
+UPDATE configurations
+SET data_type = 'float'
+WHERE config_key = 'cost.hourly_rate' AND data_type = 'string';
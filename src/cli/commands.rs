@@ -1,6 +1,8 @@
 // CLI Command Definitions
 
-use clap::{Parser, Subcommand, ValueEnum};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+
+use crate::domain::task::entity::TaskSortField;
 
 /// DevERP CLI Application
 #[derive(Parser)]
@@ -16,6 +18,18 @@ pub struct Cli {
     #[arg(short, long, global = true)]
     pub verbose: bool,
 
+    /// Connect to this database instead of the configured one
+    #[arg(long, global = true, value_name = "URL")]
+    pub database_url: Option<String>,
+
+    /// Show what a command would change without writing anything
+    #[arg(long, global = true)]
+    pub dry_run: bool,
+
+    /// Write command output to this file instead of stdout
+    #[arg(long, global = true, value_name = "PATH")]
+    pub output_file: Option<String>,
+
     #[command(subcommand)]
     pub command: Commands,
 }
@@ -29,6 +43,28 @@ pub enum OutputFormat {
     Json,
     /// Plain text format
     Plain,
+    /// Comma-separated values (where supported)
+    Csv,
+    /// YAML format
+    Yaml,
+    /// Only the number of matching rows (list commands)
+    Count,
+}
+
+impl OutputFormat {
+    /// Whether the format serializes data instead of drawing tables
+    pub fn is_structured(self) -> bool {
+        matches!(self, OutputFormat::Json | OutputFormat::Yaml)
+    }
+}
+
+/// Direction of a sorted listing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum SortOrder {
+    /// Smallest first
+    Asc,
+    /// Largest first
+    Desc,
 }
 
 impl From<OutputFormat> for crate::utils::formatter::OutputFormat {
@@ -37,10 +73,21 @@ impl From<OutputFormat> for crate::utils::formatter::OutputFormat {
             OutputFormat::Table => crate::utils::formatter::OutputFormat::Table,
             OutputFormat::Json => crate::utils::formatter::OutputFormat::Json,
             OutputFormat::Plain => crate::utils::formatter::OutputFormat::Plain,
+            OutputFormat::Csv => crate::utils::formatter::OutputFormat::Csv,
+            OutputFormat::Yaml => crate::utils::formatter::OutputFormat::Yaml,
+            OutputFormat::Count => crate::utils::formatter::OutputFormat::Count,
         }
     }
 }
 
+/// Retry options shared by mutating commands
+#[derive(Args, Clone, Debug, Default)]
+pub struct RetryArgs {
+    /// Retry up to N times on transient database errors
+    #[arg(long = "retry", value_name = "N", default_value_t = 0)]
+    pub attempts: u32,
+}
+
 /// Main command categories
 #[derive(Subcommand)]
 pub enum Commands {
@@ -60,6 +107,14 @@ pub enum Commands {
     #[command(subcommand)]
     Timeline(TimelineCommand),
 
+    /// Milestone maintenance across timelines
+    #[command(subcommand)]
+    Milestone(MilestoneCommand),
+
+    /// Stored project templates
+    #[command(subcommand)]
+    Template(TemplateCommand),
+
     /// Report generation and analytics
     #[command(subcommand)]
     Report(ReportCommand),
@@ -67,6 +122,22 @@ pub enum Commands {
     /// System configuration
     #[command(subcommand)]
     Config(ConfigCommand),
+
+    /// Export all data to files (JSON by default, CSV with --format csv)
+    Export(ExportArgs),
+}
+
+/// Arguments for exporting data
+#[derive(Parser, Clone, Debug)]
+#[command(group(clap::ArgGroup::new("target").required(true).args(["output", "output_dir"])))]
+pub struct ExportArgs {
+    /// Write every entity type into a single JSON file
+    #[arg(short, long, value_name = "FILE")]
+    pub output: Option<String>,
+
+    /// Write one file per entity type (projects.json, tasks.json, ...) into DIR
+    #[arg(long, value_name = "DIR")]
+    pub output_dir: Option<String>,
 }
 
 /// Project management subcommands
@@ -82,16 +153,26 @@ pub enum ProjectCommand {
     Update(UpdateProjectArgs),
     /// Delete a project
     Delete(DeleteProjectArgs),
+    /// Restore a soft-deleted project
+    Restore(RestoreProjectArgs),
     /// Archive a project
     Archive(ArchiveProjectArgs),
+    /// Set planned start/end dates from the project's timelines
+    DeriveDates(DeriveDatesArgs),
+    /// Create a new project with the same setup as an existing one
+    Clone(CloneProjectArgs),
+    /// Find active projects past their end date (tags them `overdue` by default)
+    ScanOverdue(ScanOverdueArgs),
+    /// Score project health from schedule lag, overdue and blocked tasks
+    Health(ProjectHealthArgs),
 }
 
 /// Arguments for creating a new project
 #[derive(Parser, Clone, Debug)]
 pub struct CreateProjectArgs {
-    /// Project name
-    #[arg(short, long)]
-    pub name: String,
+    /// Project name (overrides the template's name with --from-template)
+    #[arg(short, long, required_unless_present_any = ["from_template", "interactive"])]
+    pub name: Option<String>,
 
     /// Project description
     #[arg(short, long)]
@@ -117,6 +198,14 @@ pub struct CreateProjectArgs {
     #[arg(long)]
     pub end_date: Option<String>,
 
+    /// Planned budget, in --currency
+    #[arg(long)]
+    pub budget: Option<f64>,
+
+    /// Three-letter currency code for the budget (e.g., USD)
+    #[arg(long)]
+    pub currency: Option<String>,
+
     /// Repository URL
     #[arg(long)]
     pub repository_url: Option<String>,
@@ -128,6 +217,22 @@ pub struct CreateProjectArgs {
     /// Tags (comma-separated)
     #[arg(long)]
     pub tags: Option<String>,
+
+    /// Create the project, timelines, milestones and tasks from a JSON template
+    #[arg(long, value_name = "FILE")]
+    pub from_template: Option<String>,
+
+    /// Date that template offsets are relative to (YYYY-MM-DD, default: today)
+    #[arg(long, requires = "from_template")]
+    pub start: Option<String>,
+
+    /// Prompt for name, description, code, status, priority and dates;
+    /// flags given alongside become the defaults
+    #[arg(long, conflicts_with = "from_template")]
+    pub interactive: bool,
+
+    #[command(flatten)]
+    pub retry: RetryArgs,
 }
 
 /// Arguments for listing projects
@@ -149,6 +254,50 @@ pub struct ListProjectArgs {
     #[arg(long)]
     pub tags: Option<String>,
 
+    /// Require every tag in --tags instead of any of them
+    #[arg(long, requires = "tags")]
+    pub tags_all: bool,
+
+    /// Only projects starting on or after this date (YYYY-MM-DD)
+    #[arg(long)]
+    pub start_after: Option<String>,
+
+    /// Only projects starting on or before this date (YYYY-MM-DD)
+    #[arg(long)]
+    pub start_before: Option<String>,
+
+    /// Only projects ending on or after this date (YYYY-MM-DD)
+    #[arg(long)]
+    pub end_after: Option<String>,
+
+    /// Only projects ending on or before this date (YYYY-MM-DD)
+    #[arg(long)]
+    pub end_before: Option<String>,
+
+    /// Sort by field (created-at, name, start-date, end-date, priority, status)
+    #[arg(long, visible_aliases = ["order-by", "sort-by"])]
+    pub sort: Option<String>,
+
+    /// Sort in descending order
+    #[arg(long)]
+    pub desc: bool,
+
+    /// Sort direction (default: asc); same as --desc when desc
+    #[arg(long, value_enum, conflicts_with = "desc")]
+    pub order: Option<SortOrder>,
+
+    /// Place empty sort values first or last (default: last)
+    #[arg(long)]
+    pub nulls: Option<String>,
+
+    /// Include total and completed task counts for each listed project
+    #[arg(long)]
+    pub with_task_counts: bool,
+
+    /// Print how many projects match, by status, above the list
+    #[arg(long)]
+    pub include_counts: bool,
+
     /// Pagination options
     #[command(flatten)]
     pub pagination: PaginationOptions,
@@ -159,6 +308,14 @@ pub struct ListProjectArgs {
 pub struct ShowProjectArgs {
     /// Project ID or UUID
     pub identifier: String,
+
+    /// Print one uncolored key=value per line with ISO dates (for shell scripts)
+    #[arg(long)]
+    pub kv: bool,
+
+    /// Also list linked resources with their version and critical flag
+    #[arg(long, conflicts_with = "kv")]
+    pub with_resources: bool,
 }
 
 /// Arguments for updating a project
@@ -175,6 +332,10 @@ pub struct UpdateProjectArgs {
     #[arg(short, long)]
     pub description: Option<String>,
 
+    /// Remove the project description
+    #[arg(long, conflicts_with = "description")]
+    pub clear_description: bool,
+
     /// New project code
     #[arg(short, long)]
     pub code: Option<String>,
@@ -207,6 +368,14 @@ pub struct UpdateProjectArgs {
     #[arg(long)]
     pub progress: Option<i32>,
 
+    /// New budget
+    #[arg(long)]
+    pub budget: Option<f64>,
+
+    /// New currency code for the budget (e.g., EUR)
+    #[arg(long)]
+    pub currency: Option<String>,
+
     /// Repository URL
     #[arg(long)]
     pub repository_url: Option<String>,
@@ -218,6 +387,13 @@ pub struct UpdateProjectArgs {
     /// Tags (comma-separated)
     #[arg(long)]
     pub tags: Option<String>,
+
+    /// Fail with a conflict if the project is no longer at this version
+    #[arg(long, value_name = "VERSION")]
+    pub expected_version: Option<i32>,
+
+    #[command(flatten)]
+    pub retry: RetryArgs,
 }
 
 /// Arguments for deleting a project
@@ -229,6 +405,20 @@ pub struct DeleteProjectArgs {
     /// Confirm deletion without prompt
     #[arg(long)]
     pub confirm: bool,
+
+    /// Also delete the project's tasks, timelines and milestones
+    #[arg(long)]
+    pub cascade: bool,
+
+    #[command(flatten)]
+    pub retry: RetryArgs,
+}
+
+/// Arguments for restoring a soft-deleted project
+#[derive(Parser, Clone, Debug)]
+pub struct RestoreProjectArgs {
+    /// Project ID
+    pub id: i64,
 }
 
 /// Arguments for archiving a project
@@ -238,6 +428,121 @@ pub struct ArchiveProjectArgs {
     pub identifier: String,
 }
 
+/// Arguments for deriving project dates from timelines
+#[derive(Parser, Clone, Debug)]
+pub struct DeriveDatesArgs {
+    /// Project ID or UUID
+    pub identifier: String,
+
+    /// Overwrite planned dates that are already set
+    #[arg(long)]
+    pub force: bool,
+}
+
+/// Arguments for cloning a project
+#[derive(Parser, Clone, Debug)]
+pub struct CloneProjectArgs {
+    /// Source project ID or UUID
+    pub source: String,
+
+    /// Name of the new project
+    #[arg(short, long)]
+    pub name: String,
+
+    /// Code of the new project
+    #[arg(short, long)]
+    pub code: String,
+
+    /// Start date of the new project; copied dates keep their distance from it
+    /// (YYYY-MM-DD, default: today)
+    #[arg(long)]
+    pub start: Option<String>,
+
+    /// Copy the source's tasks as unassigned todo items
+    #[arg(long)]
+    pub with_tasks: bool,
+
+    /// Copy the source's timelines and milestones
+    #[arg(long)]
+    pub with_timelines: bool,
+
+    #[command(flatten)]
+    pub retry: RetryArgs,
+}
+
+/// Arguments for scanning overdue projects
+#[derive(Parser, Clone, Debug)]
+pub struct ScanOverdueArgs {
+    /// Emit a warning notification per project instead of tagging
+    #[arg(long, conflicts_with = "set_status")]
+    pub notify: bool,
+
+    /// Move overdue projects to this status instead of tagging (e.g., on-hold)
+    #[arg(long, value_name = "STATUS")]
+    pub set_status: Option<String>,
+}
+
+/// Arguments for project health
+#[derive(Parser, Clone, Debug)]
+pub struct ProjectHealthArgs {
+    /// Project ID
+    #[arg(required_unless_present = "all", conflicts_with = "all")]
+    pub id: Option<i64>,
+
+    /// Score every active project, worst first
+    #[arg(long)]
+    pub all: bool,
+}
+
+/// Stored template subcommands
+#[derive(Subcommand, Clone)]
+pub enum TemplateCommand {
+    /// Capture a project's structure as a named, versioned template
+    Save(SaveTemplateArgs),
+    /// Create a new project from a stored template
+    Apply(ApplyTemplateArgs),
+    /// List stored templates and their versions
+    List,
+}
+
+/// Arguments for saving a template
+#[derive(Parser, Clone, Debug)]
+pub struct SaveTemplateArgs {
+    /// Project ID to capture
+    pub project_id: i64,
+
+    /// Template name (saving an existing name adds a new version)
+    #[arg(short, long)]
+    pub name: String,
+}
+
+/// Arguments for applying a stored template
+#[derive(Parser, Clone, Debug)]
+pub struct ApplyTemplateArgs {
+    /// Stored template name
+    #[arg(value_name = "TEMPLATE")]
+    pub template: String,
+
+    /// Template version (default: latest)
+    #[arg(long)]
+    pub version: Option<i32>,
+
+    /// Name for the new project (default: the template's)
+    #[arg(short, long)]
+    pub name: Option<String>,
+
+    /// Short code for the new project
+    #[arg(short, long)]
+    pub code: Option<String>,
+
+    /// Date that template offsets are relative to (YYYY-MM-DD, default: today)
+    #[arg(long)]
+    pub start: Option<String>,
+
+    #[command(flatten)]
+    pub retry: RetryArgs,
+}
+
 /// Task management subcommands
 #[derive(Subcommand, Clone)]
 pub enum TaskCommand {
@@ -251,12 +556,40 @@ pub enum TaskCommand {
     Update(UpdateTaskArgs),
     /// Delete a task
     Delete(DeleteTaskArgs),
+    /// Restore a soft-deleted task
+    Restore(RestoreTaskArgs),
     /// Add task dependency
     AddDependency(AddDependencyArgs),
     /// Remove task dependency
     RemoveDependency(RemoveDependencyArgs),
     /// Add task comment
     AddComment(AddCommentArgs),
+    /// Restore a soft-deleted task comment
+    RestoreComment(RestoreCommentArgs),
+    /// Follow a task and reprint it whenever it changes
+    Watch(WatchTaskArgs),
+    /// Show the longest chain of dependent work in a project
+    CriticalPath(CriticalPathArgs),
+    /// List every task a task depends on, directly or transitively
+    DependencyChain(DependencyChainArgs),
+    /// Scan a project's existing dependencies for cycles
+    AuditCycles(AuditCyclesArgs),
+    /// Show a project's dependency graph (`--dot` for Graphviz)
+    Graph(TaskGraphArgs),
+    /// Create many tasks at once from a JSON or YAML file
+    Import(ImportTaskArgs),
+    /// Show age, time in status, estimate accuracy and overdue state of a task
+    Stats(TaskStatsArgs),
+    /// Log hours worked on a task
+    LogWork(LogWorkArgs),
+    /// Add a person to a task's assignees
+    Assign(AssignTaskArgs),
+    /// Remove a person from a task's assignees
+    Unassign(AssignTaskArgs),
+    /// Move a task to another project
+    Move(MoveTaskArgs),
+    /// Roll a parent task's completion up from its subtasks
+    Rollup(RollupTaskArgs),
 }
 
 /// Arguments for creating a new task
@@ -286,6 +619,10 @@ pub struct CreateTaskArgs {
     #[arg(short, long)]
     pub status: Option<String>,
 
+    /// Why the task is blocked (required with `--status blocked`)
+    #[arg(long, value_name = "REASON")]
+    pub blocked_reason: Option<String>,
+
     /// Task priority
     #[arg(short, long)]
     pub priority: Option<String>,
@@ -309,6 +646,17 @@ pub struct CreateTaskArgs {
     /// Tags (comma-separated)
     #[arg(long)]
     pub tags: Option<String>,
+
+    /// IDs of tasks this task depends on (comma-separated)
+    #[arg(long, value_delimiter = ',')]
+    pub depends_on: Vec<i64>,
+
+    /// Create the task already in progress, started now
+    #[arg(long)]
+    pub start_now: bool,
+
+    #[command(flatten)]
+    pub retry: RetryArgs,
 }
 
 /// Arguments for listing tasks
@@ -318,6 +666,16 @@ pub struct ListTaskArgs {
     #[arg(long)]
     pub project_id: Option<i64>,
 
+    /// Also include tasks of projects that depend on --project-id, up to DEPTH levels (default 1)
+    #[arg(
+        long,
+        value_name = "DEPTH",
+        num_args = 0..=1,
+        default_missing_value = "1",
+        requires = "project_id"
+    )]
+    pub recursive_project: Option<u32>,
+
     /// Filter by status
     #[arg(short, long)]
     pub status: Option<String>,
@@ -338,6 +696,66 @@ pub struct ListTaskArgs {
     #[arg(long)]
     pub parent_task_id: Option<i64>,
 
+    /// Only tasks linked to this milestone
+    #[arg(long)]
+    pub milestone_id: Option<i64>,
+
+    /// Only this task and all of its descendants
+    #[arg(long, value_name = "TASK_ID")]
+    pub subtree: Option<i64>,
+
+    /// Indent the --subtree listing by depth
+    #[arg(long, requires = "subtree")]
+    pub tree: bool,
+
+    /// Search task titles and descriptions (case-insensitive)
+    #[arg(short = 'q', long)]
+    pub search: Option<String>,
+
+    /// Also match --search against task comments
+    #[arg(long, requires = "search")]
+    pub search_comments: bool,
+
+    /// Only tasks with no estimated hours
+    #[arg(long)]
+    pub estimate_missing: bool,
+
+    /// Only tasks with no actual hours logged
+    #[arg(long)]
+    pub actual_missing: bool,
+
+    /// Only tasks updated at or after this time (YYYY-MM-DD or 'YYYY-MM-DD HH:MM:SS +ZZZZ')
+    #[arg(long)]
+    pub updated_after: Option<String>,
+
+    /// Only tasks updated before this time (YYYY-MM-DD or 'YYYY-MM-DD HH:MM:SS +ZZZZ')
+    #[arg(long)]
+    pub updated_before: Option<String>,
+
+    /// Only tasks updated today, using the configured timezone for day boundaries
+    #[arg(long, conflicts_with_all = ["updated_after", "updated_before"])]
+    pub changed_today: bool,
+
+    /// Only emit these fields per task (comma-separated, JSON output only)
+    #[arg(long, value_delimiter = ',')]
+    pub fields: Vec<String>,
+
+    /// Emit one JSON object per line (NDJSON), ignoring --format
+    #[arg(long)]
+    pub json_lines: bool,
+
+    /// Sort by field (priority, status, due-date, created-at, title); default is newest first
+    #[arg(long, visible_aliases = ["order-by", "sort-by"])]
+    pub sort: Option<TaskSortField>,
+
+    /// Sort in descending order
+    #[arg(long)]
+    pub desc: bool,
+
+    /// Sort direction (default: asc); same as --desc when desc
+    #[arg(long, value_enum, conflicts_with = "desc")]
+    pub order: Option<SortOrder>,
+
     /// Pagination options
     #[command(flatten)]
     pub pagination: PaginationOptions,
@@ -364,10 +782,18 @@ pub struct UpdateTaskArgs {
     #[arg(short, long)]
     pub description: Option<String>,
 
+    /// Remove the task description
+    #[arg(long, conflicts_with = "description")]
+    pub clear_description: bool,
+
     /// New task status
     #[arg(short, long)]
     pub status: Option<String>,
 
+    /// Why the task is blocked (required with `--status blocked`)
+    #[arg(long, value_name = "REASON")]
+    pub blocked_reason: Option<String>,
+
     /// New task priority
     #[arg(short, long)]
     pub priority: Option<String>,
@@ -388,6 +814,10 @@ pub struct UpdateTaskArgs {
     #[arg(long)]
     pub due_date: Option<String>,
 
+    /// Remove the due date
+    #[arg(long, conflicts_with = "due_date")]
+    pub clear_due_date: bool,
+
     /// New task type
     #[arg(long)]
     pub task_type: Option<String>,
@@ -395,6 +825,17 @@ pub struct UpdateTaskArgs {
     /// New tags (comma-separated)
     #[arg(long)]
     pub tags: Option<String>,
+
+    /// Mark the task done even if finish-to-start predecessors are still open
+    #[arg(long)]
+    pub force: bool,
+
+    /// Fail with a conflict if the task is no longer at this version
+    #[arg(long, value_name = "VERSION")]
+    pub expected_version: Option<i32>,
+
+    #[command(flatten)]
+    pub retry: RetryArgs,
 }
 
 /// Arguments for deleting a task
@@ -406,6 +847,23 @@ pub struct DeleteTaskArgs {
     /// Confirm deletion without prompt
     #[arg(long)]
     pub confirm: bool,
+
+    #[command(flatten)]
+    pub retry: RetryArgs,
+}
+
+/// Arguments for restoring a soft-deleted task
+#[derive(Parser, Clone, Debug)]
+pub struct RestoreTaskArgs {
+    /// Task ID
+    pub id: i64,
+}
+
+/// Arguments for restoring a soft-deleted task comment
+#[derive(Parser, Clone, Debug)]
+pub struct RestoreCommentArgs {
+    /// Comment ID
+    pub id: i64,
 }
 
 /// Arguments for adding a task dependency
@@ -452,6 +910,138 @@ pub struct AddCommentArgs {
     pub author: Option<String>,
 }
 
+/// Arguments for logging work on a task
+#[derive(Parser, Clone, Debug)]
+pub struct LogWorkArgs {
+    /// Task ID
+    #[arg(long)]
+    pub task_id: i64,
+
+    /// Hours worked (must be positive)
+    #[arg(long)]
+    pub hours: f64,
+
+    /// Who did the work
+    #[arg(long = "by", value_name = "NAME")]
+    pub logged_by: String,
+
+    /// What the time was spent on
+    #[arg(long)]
+    pub note: Option<String>,
+}
+
+/// Arguments for adding or removing a task assignee
+#[derive(Parser, Clone, Debug)]
+pub struct AssignTaskArgs {
+    /// Task ID
+    #[arg(long)]
+    pub task_id: i64,
+
+    /// Assignee name
+    #[arg(long, value_name = "NAME")]
+    pub user: String,
+}
+
+/// Arguments for moving a task to another project
+#[derive(Parser, Clone, Debug)]
+pub struct MoveTaskArgs {
+    /// Task ID
+    #[arg(long)]
+    pub task_id: i64,
+
+    /// ID of the project to move the task to
+    #[arg(long, value_name = "PROJECT_ID")]
+    pub to_project: i64,
+}
+
+/// Arguments for rolling up subtask progress
+#[derive(Parser, Clone, Debug)]
+pub struct RollupTaskArgs {
+    /// Parent task ID
+    #[arg(long)]
+    pub task_id: i64,
+
+    /// Mark the parent done when every subtask is done
+    #[arg(long)]
+    pub complete: bool,
+}
+
+/// Arguments for the dependency chain of a task
+#[derive(Parser, Clone, Debug)]
+pub struct DependencyChainArgs {
+    /// Task ID
+    #[arg(long)]
+    pub task_id: i64,
+
+    /// Stop this many dependency steps from the task
+    #[arg(long)]
+    pub max_depth: Option<u32>,
+}
+
+/// Arguments for auditing a project's dependency cycles
+#[derive(Parser, Clone, Debug)]
+pub struct AuditCyclesArgs {
+    /// Project ID
+    #[arg(long)]
+    pub project_id: i64,
+}
+
+/// Arguments for a project's dependency graph
+#[derive(Parser, Clone, Debug)]
+pub struct TaskGraphArgs {
+    /// Project ID
+    #[arg(long)]
+    pub project_id: i64,
+
+    /// Also report dependency cycles already in the data
+    #[arg(long)]
+    pub detect_cycles: bool,
+
+    /// Print the graph as Graphviz DOT instead of a table
+    #[arg(long)]
+    pub dot: bool,
+}
+
+/// Arguments for the critical path of a project
+#[derive(Parser, Clone, Debug)]
+pub struct CriticalPathArgs {
+    /// Project ID
+    #[arg(long)]
+    pub project_id: i64,
+}
+
+/// Arguments for importing tasks from a file
+#[derive(Parser, Clone, Debug)]
+pub struct ImportTaskArgs {
+    /// JSON or YAML file (by extension) holding an array of tasks with the
+    /// `task create` fields, e.g. title, description, priority, status,
+    /// estimated_hours, tags and task_type
+    #[arg(long, value_name = "PATH")]
+    pub file: String,
+
+    /// Project the tasks are created in
+    #[arg(long)]
+    pub project_id: i64,
+}
+
+/// Arguments for task stats
+#[derive(Parser, Clone, Debug)]
+pub struct TaskStatsArgs {
+    /// Task ID
+    pub id: i64,
+}
+
+/// Arguments for watching a task
+#[derive(Parser, Clone, Debug)]
+pub struct WatchTaskArgs {
+    /// Task ID
+    pub id: i64,
+
+    /// Seconds between polls
+    #[arg(long, default_value_t = 5, value_parser = clap::value_parser!(u64).range(1..))]
+    pub interval: u64,
+}
+
 /// Resource management subcommands
 #[derive(Subcommand, Clone)]
 pub enum ResourceCommand {
@@ -465,12 +1055,34 @@ pub enum ResourceCommand {
     Update(UpdateResourceArgs),
     /// Delete a resource
     Delete(DeleteResourceArgs),
+    /// Restore a soft-deleted resource
+    Restore(RestoreResourceArgs),
     /// Link resource to project
     Link(LinkResourceArgs),
     /// Unlink resource from project
     Unlink(UnlinkResourceArgs),
     /// Show resource usage statistics
     Usage(UsageResourceArgs),
+    /// Mark a resource (or every resource with a tag) as deprecated
+    Deprecate(DeprecateResourceArgs),
+    /// Record that a resource requires another resource
+    AddDependency(ResourceDependencyArgs),
+    /// Remove a dependency between two resources
+    RemoveDependency(ResourceDependencyArgs),
+    /// List the resources a resource depends on
+    Deps(ResourceDepsArgs),
+    /// List deprecated resources that projects still mark as critical
+    RiskReport,
+    /// List licenses in use with their resources and consuming projects
+    LicenseReport(LicenseReportArgs),
+}
+
+/// Arguments for the resource license report
+#[derive(Parser, Clone, Debug)]
+pub struct LicenseReportArgs {
+    /// Write one row per resource to PATH as CSV instead of printing
+    #[arg(long, value_name = "PATH")]
+    pub csv: Option<String>,
 }
 
 /// Arguments for creating a new resource
@@ -511,6 +1123,13 @@ pub struct CreateResourceArgs {
     /// Tags (comma-separated)
     #[arg(long)]
     pub tags: Option<String>,
+
+    /// Create the resource even if one with the same name and version exists
+    #[arg(long)]
+    pub allow_duplicate: bool,
+
+    #[command(flatten)]
+    pub retry: RetryArgs,
 }
 
 /// Arguments for listing resources
@@ -532,6 +1151,14 @@ pub struct ListResourceArgs {
     #[arg(long)]
     pub tags: Option<String>,
 
+    /// Require every tag in --tags instead of any of them
+    #[arg(long, requires = "tags")]
+    pub tags_all: bool,
+
+    /// Only resources not updated in the last N days
+    #[arg(long, value_name = "DAYS", value_parser = clap::value_parser!(i32).range(0..))]
+    pub stale: Option<i32>,
+
     /// Pagination options
     #[command(flatten)]
     pub pagination: PaginationOptions,
@@ -542,6 +1169,10 @@ pub struct ListResourceArgs {
 pub struct ShowResourceArgs {
     /// Resource ID or UUID
     pub identifier: String,
+
+    /// Include usage statistics and linked projects
+    #[arg(long)]
+    pub full: bool,
 }
 
 /// Arguments for updating a resource
@@ -566,6 +1197,10 @@ pub struct UpdateResourceArgs {
     #[arg(short, long)]
     pub version: Option<String>,
 
+    /// Increment the current semver version (major, minor, patch)
+    #[arg(long, conflicts_with = "version")]
+    pub bump_version: Option<String>,
+
     /// New resource URL
     #[arg(short, long)]
     pub url: Option<String>,
@@ -585,6 +1220,9 @@ pub struct UpdateResourceArgs {
     /// New tags (comma-separated)
     #[arg(long)]
     pub tags: Option<String>,
+
+    #[command(flatten)]
+    pub retry: RetryArgs,
 }
 
 /// Arguments for deleting a resource
@@ -596,6 +1234,16 @@ pub struct DeleteResourceArgs {
     /// Confirm deletion without prompt
     #[arg(long)]
     pub confirm: bool,
+
+    #[command(flatten)]
+    pub retry: RetryArgs,
+}
+
+/// Arguments for restoring a soft-deleted resource
+#[derive(Parser, Clone, Debug)]
+pub struct RestoreResourceArgs {
+    /// Resource ID
+    pub id: i64,
 }
 
 /// Arguments for linking a resource to a project
@@ -639,6 +1287,45 @@ pub struct UnlinkResourceArgs {
 pub struct UsageResourceArgs {
     /// Resource ID (optional, if not provided shows all resources)
     pub resource_id: Option<i64>,
+
+    /// Show the full resource-to-project usage matrix (one row per link)
+    #[arg(long, conflicts_with = "resource_id")]
+    pub all: bool,
+}
+
+/// Arguments for deprecating resources
+#[derive(Parser, Clone, Debug)]
+pub struct DeprecateResourceArgs {
+    /// Resource ID or UUID
+    #[arg(required_unless_present = "tag")]
+    pub identifier: Option<String>,
+
+    /// Deprecate every resource carrying this tag (single transaction)
+    #[arg(long, conflicts_with = "identifier")]
+    pub tag: Option<String>,
+
+    /// Reason for the deprecation (stored in resource metadata)
+    #[arg(short, long)]
+    pub reason: Option<String>,
+}
+
+/// Arguments for adding or removing a resource dependency
+#[derive(Parser, Clone, Debug)]
+pub struct ResourceDependencyArgs {
+    /// Resource ID (the dependent resource)
+    #[arg(long)]
+    pub resource_id: i64,
+
+    /// ID of the resource it depends on
+    #[arg(long)]
+    pub depends_on: i64,
+}
+
+/// Arguments for listing a resource's dependencies
+#[derive(Parser, Clone, Debug)]
+pub struct ResourceDepsArgs {
+    /// Resource ID
+    pub resource_id: i64,
 }
 
 /// Timeline management subcommands
@@ -652,14 +1339,79 @@ pub enum TimelineCommand {
     Show(ShowTimelineArgs),
     /// Update a timeline
     Update(UpdateTimelineArgs),
+    /// Move a timeline and all its milestones by a number of days
+    Shift(ShiftTimelineArgs),
     /// Delete a timeline
     Delete(DeleteTimelineArgs),
+    /// Restore a soft-deleted timeline
+    Restore(RestoreTimelineArgs),
     /// Add milestone
     AddMilestone(AddMilestoneArgs),
     /// Update milestone
     UpdateMilestone(UpdateMilestoneArgs),
     /// Complete milestone
     CompleteMilestone(CompleteMilestoneArgs),
+    /// Move a milestone to a new position within its timeline
+    ReorderMilestone(ReorderMilestoneArgs),
+    /// List the tasks linked to a milestone
+    MilestoneTasks(MilestoneTasksArgs),
+    /// Show completion of a single timeline
+    Progress(TimelineProgressArgs),
+    /// Mark a timeline's pending/in-progress milestones past their target date as missed
+    RefreshMilestones(RefreshMilestonesArgs),
+    /// Draw a project's timelines and milestones as a Gantt chart
+    Gantt(GanttArgs),
+}
+
+/// Milestone subcommands
+#[derive(Subcommand, Clone)]
+pub enum MilestoneCommand {
+    /// List milestones across timelines
+    List(ListMilestoneArgs),
+    /// Restore a soft-deleted milestone
+    Restore(RestoreMilestoneArgs),
+    /// Mark pending/in-progress milestones past their target date as missed
+    ScanMissed(ScanMissedArgs),
+}
+
+/// Arguments for listing milestones
+#[derive(Parser, Clone, Debug)]
+pub struct ListMilestoneArgs {
+    /// Filter by project ID
+    #[arg(long)]
+    pub project_id: Option<i64>,
+
+    /// Filter by timeline ID
+    #[arg(long)]
+    pub timeline_id: Option<i64>,
+
+    /// Filter by status
+    #[arg(short, long)]
+    pub status: Option<String>,
+
+    /// Only pending/in-progress milestones past their target date (nothing is
+    /// marked missed; see scan-missed)
+    #[arg(long, conflicts_with = "status")]
+    pub overdue: bool,
+
+    /// Pagination options
+    #[command(flatten)]
+    pub pagination: PaginationOptions,
+}
+
+/// Arguments for restoring a soft-deleted milestone
+#[derive(Parser, Clone, Debug)]
+pub struct RestoreMilestoneArgs {
+    /// Milestone ID
+    pub id: i64,
+}
+
+/// Arguments for scanning for missed milestones
+#[derive(Parser, Clone, Debug)]
+pub struct ScanMissedArgs {
+    /// Only scan this project's milestones (all projects if omitted)
+    #[arg(long)]
+    pub project_id: Option<i64>,
 }
 
 /// Arguments for creating a new timeline
@@ -677,9 +1429,10 @@ pub struct CreateTimelineArgs {
     #[arg(short, long)]
     pub description: Option<String>,
 
-    /// Timeline type (project, sprint, release, phase)
-    #[arg(short = 't', long, default_value = "project")]
-    pub timeline_type: String,
+    /// Timeline type (project, sprint, release, phase) [default: config
+    /// `timeline.default_type`, else project]
+    #[arg(short = 't', long)]
+    pub timeline_type: Option<String>,
 
     /// Start date (YYYY-MM-DD)
     #[arg(long)]
@@ -692,6 +1445,9 @@ pub struct CreateTimelineArgs {
     /// Status (planned, active, completed, cancelled)
     #[arg(short, long)]
     pub status: Option<String>,
+
+    #[command(flatten)]
+    pub retry: RetryArgs,
 }
 
 /// Arguments for listing timelines
@@ -721,6 +1477,41 @@ pub struct ShowTimelineArgs {
     pub id: i64,
 }
 
+/// Arguments for showing timeline progress
+#[derive(Parser, Clone, Debug)]
+pub struct TimelineProgressArgs {
+    /// Timeline ID
+    pub id: i64,
+}
+
+/// Arguments for the Gantt chart of a project
+#[derive(Parser, Clone, Debug)]
+pub struct GanttArgs {
+    /// Project ID
+    #[arg(long)]
+    pub project_id: i64,
+}
+
+/// Arguments for refreshing a timeline's milestone statuses
+#[derive(Parser, Clone, Debug)]
+pub struct RefreshMilestonesArgs {
+    /// Timeline ID
+    #[arg(long)]
+    pub timeline_id: i64,
+}
+
+/// Arguments for shifting a timeline
+#[derive(Parser, Clone, Debug)]
+pub struct ShiftTimelineArgs {
+    /// Timeline ID
+    #[arg(long)]
+    pub id: i64,
+
+    /// Days to move every date by; negative pulls the schedule in
+    #[arg(long, allow_negative_numbers = true)]
+    pub days: i64,
+}
+
 /// Arguments for updating a timeline
 #[derive(Parser, Clone, Debug)]
 pub struct UpdateTimelineArgs {
@@ -750,6 +1541,9 @@ pub struct UpdateTimelineArgs {
     /// New status
     #[arg(short, long)]
     pub status: Option<String>,
+
+    #[command(flatten)]
+    pub retry: RetryArgs,
 }
 
 /// Arguments for deleting a timeline
@@ -761,6 +1555,16 @@ pub struct DeleteTimelineArgs {
     /// Confirm deletion without prompt
     #[arg(long)]
     pub confirm: bool,
+
+    #[command(flatten)]
+    pub retry: RetryArgs,
+}
+
+/// Arguments for restoring a soft-deleted timeline
+#[derive(Parser, Clone, Debug)]
+pub struct RestoreTimelineArgs {
+    /// Timeline ID
+    pub id: i64,
 }
 
 /// Arguments for adding a milestone
@@ -789,6 +1593,10 @@ pub struct AddMilestoneArgs {
     /// Status (pending, in_progress, completed, missed, cancelled)
     #[arg(short, long)]
     pub status: Option<String>,
+
+    /// IDs of tasks in the same project to link (comma-separated)
+    #[arg(long, value_delimiter = ',')]
+    pub link_tasks: Vec<i64>,
 }
 
 /// Arguments for updating a milestone
@@ -820,6 +1628,10 @@ pub struct UpdateMilestoneArgs {
     /// New completion percentage (0-100)
     #[arg(long)]
     pub completion_percentage: Option<i32>,
+
+    /// Mark the milestone completed even if linked tasks are not all done
+    #[arg(long)]
+    pub force: bool,
 }
 
 /// Arguments for completing a milestone
@@ -831,21 +1643,156 @@ pub struct CompleteMilestoneArgs {
     /// Actual completion date (YYYY-MM-DD), defaults to today
     #[arg(long)]
     pub actual_date: Option<String>,
+
+    /// Complete the milestone even if linked tasks are not all done
+    #[arg(long)]
+    pub force: bool,
+}
+
+/// Arguments for reordering a milestone
+#[derive(Parser, Clone, Debug)]
+pub struct ReorderMilestoneArgs {
+    /// Milestone ID
+    #[arg(long)]
+    pub id: i64,
+
+    /// New position within the timeline, starting at 1
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..))]
+    pub position: u32,
+}
+
+/// Arguments for listing a milestone's linked tasks
+#[derive(Parser, Clone, Debug)]
+pub struct MilestoneTasksArgs {
+    /// Milestone ID
+    #[arg(long)]
+    pub id: i64,
 }
 
 /// Report generation subcommands
 #[derive(Subcommand, Clone)]
 pub enum ReportCommand {
     /// Overall status report
-    Status,
+    Status {
+        /// Include recent snapshots of the key metrics
+        #[arg(long)]
+        with_trend: bool,
+
+        /// Number of snapshots to include with --with-trend
+        #[arg(long, default_value_t = 10, requires = "with_trend")]
+        trend_points: usize,
+
+        /// Re-run the report every --interval seconds until Ctrl-C
+        #[arg(long, conflicts_with = "csv")]
+        watch: bool,
+
+        /// Seconds between refreshes with --watch (at least 2)
+        #[arg(
+            long,
+            default_value_t = 5,
+            requires = "watch",
+            value_parser = clap::value_parser!(u64).range(2..)
+        )]
+        interval: u64,
+
+        #[command(flatten)]
+        csv: ReportCsvArgs,
+    },
+    /// Record the current status metrics as a snapshot
+    Snapshot,
     /// Project summary report
-    ProjectSummary,
+    ProjectSummary {
+        #[command(flatten)]
+        csv: ReportCsvArgs,
+    },
     /// Task analytics report
-    TaskAnalytics,
+    TaskAnalytics {
+        /// Include a per-assignee breakdown
+        #[arg(long)]
+        by_assignee: bool,
+
+        #[command(flatten)]
+        csv: ReportCsvArgs,
+    },
     /// Resource usage report
-    ResourceUsage,
+    ResourceUsage {
+        /// Suggest unused, deprecated resources to archive
+        #[arg(long)]
+        suggest_cleanup: bool,
+
+        #[command(flatten)]
+        csv: ReportCsvArgs,
+    },
     /// Timeline progress report
-    TimelineProgress,
+    TimelineProgress {
+        #[command(flatten)]
+        csv: ReportCsvArgs,
+    },
+    /// Completed work per finished sprint and the average velocity
+    Velocity {
+        /// Only report on this project's sprints
+        #[arg(long)]
+        project_id: Option<i64>,
+
+        /// Average over the N most recent sprints (by end date) only
+        #[arg(long, value_name = "N", value_parser = clap::value_parser!(u32).range(1..))]
+        last: Option<u32>,
+
+        #[command(flatten)]
+        csv: ReportCsvArgs,
+    },
+    /// Remaining estimated hours per day of a timeline against the ideal line
+    /// (`--csv` writes one row per day)
+    Burndown {
+        /// Timeline to chart
+        #[arg(long)]
+        timeline_id: i64,
+
+        #[command(flatten)]
+        csv: ReportCsvArgs,
+    },
+    /// Open tasks and milestones past their due/target date
+    Overdue {
+        /// Print the items as iCalendar events instead of a table
+        #[arg(long, conflicts_with = "csv")]
+        ics: bool,
+
+        #[command(flatten)]
+        csv: ReportCsvArgs,
+    },
+    /// Overdue open tasks grouped by assignee, most overdue first
+    OverdueByAssignee,
+    /// Days each late milestone slipped past its target, largest first
+    /// (open milestones are projected up to today)
+    MilestoneSlip {
+        #[command(flatten)]
+        csv: ReportCsvArgs,
+    },
+    /// Schedule, progress and task completion scores for one project
+    ProjectHealth {
+        /// Project ID
+        project_id: i64,
+
+        #[command(flatten)]
+        csv: ReportCsvArgs,
+    },
+    /// Write the status, task analytics, resource usage, timeline progress and
+    /// project summary reports into a directory (JSON by default, CSV with
+    /// --format csv)
+    ExportAll {
+        /// Directory to write the reports into; created if missing
+        #[arg(long, value_name = "PATH")]
+        dir: String,
+    },
+}
+
+/// CSV file output shared by the report subcommands
+#[derive(Args, Clone, Debug, Default)]
+pub struct ReportCsvArgs {
+    /// Write the report to PATH as CSV instead of printing it; list sections
+    /// go to sibling files named PATH_<section>.csv
+    #[arg(long, value_name = "PATH")]
+    pub csv: Option<String>,
 }
 
 /// Configuration subcommands
@@ -866,6 +1813,9 @@ pub enum ConfigCommand {
         /// Optional description
         #[arg(short, long)]
         description: Option<String>,
+        /// Value type (string, int, float, bool, json); required when creating a new key
+        #[arg(short = 't', long = "type", value_name = "TYPE")]
+        data_type: Option<String>,
     },
     /// Reset all configurations to default values
     Reset {
@@ -879,6 +1829,10 @@ pub enum ConfigCommand {
         #[arg(short, long)]
         verbose: bool,
     },
+    /// Check every stored value parses as its configured type
+    Validate,
+    /// Check the database has every expected table; fails if any is missing
+    CheckSchema,
 }
 
 /// Common pagination options
@@ -888,20 +1842,34 @@ pub struct PaginationOptions {
     #[arg(long, default_value = "1")]
     pub page: u32,
 
-    /// Number of items per page
-    #[arg(long, default_value = "50")]
-    pub per_page: u32,
+    /// Number of items per page (default: the list.per_page setting, else 50)
+    #[arg(long)]
+    pub per_page: Option<u32>,
 }
 
 impl PaginationOptions {
+    /// Page size used when neither `--per-page` nor a stored preference is set
+    pub const DEFAULT_PER_PAGE: u32 = 50;
+
+    /// Use `per_page` unless `--per-page` was given explicitly
+    pub fn or_per_page(mut self, per_page: u32) -> Self {
+        self.per_page.get_or_insert(per_page);
+        self
+    }
+
+    /// Effective number of items per page
+    pub fn per_page(&self) -> u32 {
+        self.per_page.unwrap_or(Self::DEFAULT_PER_PAGE)
+    }
+
     /// Calculate offset for database queries
     pub fn offset(&self) -> i64 {
-        ((self.page.saturating_sub(1)) * self.per_page) as i64
+        ((self.page.saturating_sub(1)) * self.per_page()) as i64
     }
 
     /// Get limit for database queries
     pub fn limit(&self) -> i64 {
-        self.per_page as i64
+        self.per_page() as i64
     }
 }
 
@@ -913,19 +1881,19 @@ mod tests {
     fn test_pagination_offset() {
         let opts = PaginationOptions {
             page: 1,
-            per_page: 10,
+            per_page: Some(10),
         };
         assert_eq!(opts.offset(), 0);
 
         let opts = PaginationOptions {
             page: 2,
-            per_page: 10,
+            per_page: Some(10),
         };
         assert_eq!(opts.offset(), 10);
 
         let opts = PaginationOptions {
             page: 5,
-            per_page: 20,
+            per_page: Some(20),
         };
         assert_eq!(opts.offset(), 80);
     }
@@ -934,17 +1902,147 @@ mod tests {
     fn test_pagination_limit() {
         let opts = PaginationOptions {
             page: 1,
-            per_page: 10,
+            per_page: Some(10),
         };
         assert_eq!(opts.limit(), 10);
 
         let opts = PaginationOptions {
             page: 2,
-            per_page: 25,
+            per_page: Some(25),
         };
         assert_eq!(opts.limit(), 25);
     }
 
+    #[test]
+    fn test_stored_per_page_applies_only_without_flag() {
+        let parse = |args: &[&str]| match Cli::try_parse_from(args).unwrap().command {
+            Commands::Project(ProjectCommand::List(list)) => list.pagination,
+            _ => unreachable!(),
+        };
+
+        let omitted = parse(&["deverp", "project", "list"]);
+        assert_eq!(omitted.per_page(), PaginationOptions::DEFAULT_PER_PAGE);
+        assert_eq!(omitted.or_per_page(15).limit(), 15);
+
+        let explicit = parse(&[
+            "deverp",
+            "project",
+            "list",
+            "--per-page",
+            "5",
+            "--page",
+            "3",
+        ]);
+        let explicit = explicit.or_per_page(15);
+        assert_eq!(explicit.limit(), 5);
+        assert_eq!(explicit.offset(), 10);
+    }
+
+    #[test]
+    fn test_report_status_watch_interval_bounds() {
+        let parse = |args: &[&str]| -> Result<(bool, u64), clap::Error> {
+            match Cli::try_parse_from(args)?.command {
+                Commands::Report(ReportCommand::Status {
+                    watch, interval, ..
+                }) => Ok((watch, interval)),
+                _ => unreachable!(),
+            }
+        };
+
+        assert_eq!(
+            parse(&["deverp", "report", "status", "--watch"]).unwrap(),
+            (true, 5)
+        );
+        assert_eq!(
+            parse(&["deverp", "report", "status", "--watch", "--interval", "2"]).unwrap(),
+            (true, 2)
+        );
+        assert!(parse(&["deverp", "report", "status", "--watch", "--interval", "1"]).is_err());
+        assert!(parse(&["deverp", "report", "status", "--interval", "10"]).is_err());
+    }
+
+    #[test]
+    fn test_report_overdue_ics_is_a_local_flag() {
+        let cli = Cli::try_parse_from(["deverp", "report", "overdue", "--ics"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Commands::Report(ReportCommand::Overdue { ics: true, .. })
+        ));
+
+        assert!(Cli::try_parse_from(["deverp", "--format", "ics", "report", "overdue"]).is_err());
+        assert!(
+            Cli::try_parse_from(["deverp", "report", "overdue", "--ics", "--csv", "x.csv"])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_task_graph_dot_is_a_local_flag() {
+        let cli =
+            Cli::try_parse_from(["deverp", "task", "graph", "--project-id", "1", "--dot"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Commands::Task(TaskCommand::Graph(TaskGraphArgs { dot: true, .. }))
+        ));
+
+        assert!(Cli::try_parse_from([
+            "deverp",
+            "--format",
+            "dot",
+            "task",
+            "graph",
+            "--project-id",
+            "1"
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn test_task_list_recursive_project_depth() {
+        let list = |args: &[&str]| -> Result<Option<u32>, clap::Error> {
+            let cli = Cli::try_parse_from(["deverp", "task", "list"].iter().chain(args))?;
+            match cli.command {
+                Commands::Task(TaskCommand::List(args)) => Ok(args.recursive_project),
+                _ => unreachable!(),
+            }
+        };
+
+        assert_eq!(list(&["--project-id", "1"]).unwrap(), None);
+        assert_eq!(
+            list(&["--project-id", "1", "--recursive-project"]).unwrap(),
+            Some(1)
+        );
+        assert_eq!(
+            list(&["--project-id", "1", "--recursive-project", "3"]).unwrap(),
+            Some(3)
+        );
+        assert!(list(&["--recursive-project"]).is_err());
+    }
+
+    #[test]
+    fn test_task_list_sort_flags() {
+        let list = |args: &[&str]| -> Result<ListTaskArgs, clap::Error> {
+            let cli = Cli::try_parse_from(["deverp", "task", "list"].iter().chain(args))?;
+            match cli.command {
+                Commands::Task(TaskCommand::List(args)) => Ok(args),
+                _ => unreachable!(),
+            }
+        };
+
+        for flag in ["--sort", "--sort-by", "--order-by"] {
+            let args = list(&[flag, "due-date", "--desc"]).unwrap();
+            assert_eq!(args.sort, Some(TaskSortField::DueDate));
+            assert!(args.desc);
+        }
+        // A direction alone is accepted; the handler applies it to creation time
+        assert_eq!(
+            list(&["--order", "desc"]).unwrap().order,
+            Some(SortOrder::Desc)
+        );
+        assert!(list(&["--sort", "size"]).is_err());
+        assert!(list(&["--desc", "--order", "asc"]).is_err());
+    }
+
     #[test]
     fn test_output_format_conversion() {
         let table_format: crate::utils::formatter::OutputFormat = OutputFormat::Table.into();
@@ -952,5 +2050,8 @@ mod tests {
 
         let json_format: crate::utils::formatter::OutputFormat = OutputFormat::Json.into();
         assert_eq!(json_format, crate::utils::formatter::OutputFormat::Json);
+
+        let count_format: crate::utils::formatter::OutputFormat = OutputFormat::Count.into();
+        assert_eq!(count_format, crate::utils::formatter::OutputFormat::Count);
     }
 }
@@ -1,69 +1,100 @@
 // Configuration CLI commands
 
 use super::commands::{ConfigCommand, OutputFormat};
-use crate::config::settings::Settings;
-use crate::infrastructure::database;
+use super::context::AppContext;
+use super::output::{self, outln, output_structured, table_header, table_row, OutputSink};
 use crate::Result;
-use std::sync::Arc;
 
 use crate::domain::config::service::ConfigService;
-use crate::infrastructure::repositories::config_repo::PostgresConfigRepository;
+use crate::domain::config::{ConfigDataType, ListPreferences};
 use crate::utils::error::DevErpError;
-use crate::utils::formatter::{table_header, table_row};
 
-/// Handle config commands
-pub async fn handle(command: ConfigCommand, _format: OutputFormat) -> Result<()> {
-    // Establish database connection
-    let settings = Settings::default();
-    let pool = database::establish_connection(&settings.database).await?;
+/// Stored list defaults (`list.per_page`, `list.order_by`) for list commands
+pub(super) async fn list_preferences(ctx: &AppContext) -> Result<ListPreferences> {
+    ctx.config_service().list_preferences().await
+}
 
-    // Create repository and service
-    let repo = Arc::new(PostgresConfigRepository::new(pool.clone()));
-    let service = ConfigService::new(repo, pool);
+/// Handle config commands
+pub async fn handle(ctx: &AppContext, command: ConfigCommand, format: OutputFormat) -> Result<()> {
+    let out = ctx.out();
+    let service = ctx.config_service();
 
     match command {
-        ConfigCommand::Show { key } => handle_show(service, key).await,
+        ConfigCommand::Show { key } => handle_show(out, service, key, format).await,
         ConfigCommand::Set {
             key,
             value,
             description,
-        } => handle_set(service, key, value, description).await,
-        ConfigCommand::Reset { confirm } => handle_reset(service, confirm).await,
-        ConfigCommand::TestDb { verbose } => handle_test_db(service, verbose).await,
+            data_type,
+        } => {
+            handle_set(
+                out,
+                service,
+                key,
+                value,
+                description,
+                data_type,
+                ctx.dry_run(),
+            )
+            .await
+        }
+        ConfigCommand::Reset { confirm } => {
+            handle_reset(out, service, confirm, ctx.dry_run()).await
+        }
+        ConfigCommand::TestDb { verbose } => handle_test_db(out, service, verbose).await,
+        ConfigCommand::Validate => handle_validate(out, service).await,
+        ConfigCommand::CheckSchema => handle_check_schema(out, service, format).await,
     }
 }
 
-async fn handle_show(service: ConfigService, key: Option<String>) -> Result<()> {
+async fn handle_show(
+    out: &dyn OutputSink,
+    service: ConfigService,
+    key: Option<String>,
+    format: OutputFormat,
+) -> Result<()> {
     match key {
         Some(k) => {
             // Show single configuration
             let config = service.get_config(&k).await?;
+            if format.is_structured() {
+                return output_structured(out, format, &config);
+            }
 
-            table_header(&["Key", "Value", "Type", "Description"]);
-            table_row(&[
-                config.config_key,
-                config.config_value,
-                config.data_type.to_string(),
-                config.description.unwrap_or_else(|| "-".to_string()),
-            ]);
+            table_header(out, &["Key", "Value", "Type", "Description"]);
+            table_row(
+                out,
+                &[
+                    config.config_key,
+                    config.config_value,
+                    config.data_type.to_string(),
+                    config.description.unwrap_or_else(|| "-".to_string()),
+                ],
+            );
         }
         None => {
             // Show all configurations
             let configs = service.get_all_configs().await?;
+            if format.is_structured() {
+                return output_structured(out, format, &configs);
+            }
 
-            table_header(&["Key", "Value", "Type", "Required", "Description"]);
+            table_header(out, &["Key", "Value", "Type", "Required", "Description"]);
             for config in configs {
-                table_row(&[
-                    config.config_key,
-                    config.config_value,
-                    config.data_type.to_string(),
-                    if config.is_required {
-                        "Yes".to_string()
-                    } else {
-                        "No".to_string()
-                    },
-                    config.description.unwrap_or_else(|| "-".to_string()),
-                ]);
+                table_row(
+                    out,
+                    &[
+                        config.config_key,
+                        config.config_value,
+                        config.data_type.to_string(),
+                        if config.is_required {
+                            "Yes".to_string()
+                        } else {
+                            "No".to_string()
+                        },
+                        config.description.unwrap_or_else(|| "-".to_string()),
+                    ],
+                );
             }
         }
     }
@@ -72,22 +103,46 @@ async fn handle_show(service: ConfigService, key: Option<String>) -> Result<()>
 }
 
 async fn handle_set(
+    out: &dyn OutputSink,
     service: ConfigService,
     key: String,
     value: String,
     description: Option<String>,
+    data_type: Option<String>,
+    dry_run: bool,
 ) -> Result<()> {
-    let config = service.set_config(&key, value, description).await?;
+    let data_type = data_type
+        .map(|t| t.parse::<ConfigDataType>())
+        .transpose()
+        .map_err(DevErpError::Validation)?;
+
+    if dry_run {
+        output::dry_run(out, &format!("set {} to '{}'", key, value));
+        return Ok(());
+    }
+    let config = service
+        .set_config(&key, value, description, data_type)
+        .await?;
 
-    println!("Configuration updated successfully:");
-    println!("  Key: {}", config.config_key);
-    println!("  Value: {}", config.config_value);
-    println!("  Type: {}", config.data_type);
+    outln!(out, "Configuration updated successfully:");
+    outln!(out, "  Key: {}", config.config_key);
+    outln!(out, "  Value: {}", config.config_value);
+    outln!(out, "  Type: {}", config.data_type);
 
     Ok(())
 }
 
-async fn handle_reset(service: ConfigService, confirm: bool) -> Result<()> {
+async fn handle_reset(
+    out: &dyn OutputSink,
+    service: ConfigService,
+    confirm: bool,
+    dry_run: bool,
+) -> Result<()> {
+    if dry_run {
+        output::dry_run(out, "reset all configurations to their default values");
+        return Ok(());
+    }
+
     if !confirm {
         return Err(DevErpError::Validation(
             "Reset operation requires --confirm flag to prevent accidental data loss".to_string(),
@@ -95,30 +150,90 @@ async fn handle_reset(service: ConfigService, confirm: bool) -> Result<()> {
     }
 
     service.reset_to_defaults().await?;
-    println!("All configurations have been reset to default values");
+    outln!(out, "All configurations have been reset to default values");
 
     Ok(())
 }
 
-async fn handle_test_db(service: ConfigService, verbose: bool) -> Result<()> {
+async fn handle_test_db(out: &dyn OutputSink, service: ConfigService, verbose: bool) -> Result<()> {
     // Test basic connectivity
     let connection_result = service.test_database_connection().await?;
-    println!("✓ {}", connection_result);
+    outln!(out, "✓ {}", connection_result);
 
     if verbose {
         // Get database version
         let version = service.get_database_version().await?;
-        println!("\nDatabase Information:");
-        println!("  Version: {}", version);
+        outln!(out, "\nDatabase Information:");
+        outln!(out, "  Version: {}", version);
 
         // Get database statistics
         let stats = service.get_database_stats().await?;
-        println!("\nDatabase Statistics:");
-        println!("  Projects: {}", stats.project_count);
-        println!("  Tasks: {}", stats.task_count);
-        println!("  Resources: {}", stats.resource_count);
-        println!("  Timelines: {}", stats.timeline_count);
+        outln!(out, "\nDatabase Statistics:");
+        outln!(out, "  Projects: {}", stats.project_count);
+        outln!(out, "  Tasks: {}", stats.task_count);
+        outln!(out, "  Resources: {}", stats.resource_count);
+        outln!(out, "  Timelines: {}", stats.timeline_count);
     }
 
     Ok(())
 }
+
+async fn handle_validate(out: &dyn OutputSink, service: ConfigService) -> Result<()> {
+    let invalid = service.find_invalid_configs().await?;
+
+    if invalid.is_empty() {
+        outln!(out, "✓ All configurations are valid");
+        return Ok(());
+    }
+
+    table_header(out, &["Key", "Type", "Value", "Problem"]);
+    for config in &invalid {
+        table_row(
+            out,
+            &[
+                config.config_key.clone(),
+                config.data_type.to_string(),
+                config.config_value.clone(),
+                config.error.clone(),
+            ],
+        );
+    }
+
+    Err(DevErpError::Validation(format!(
+        "{} configuration value(s) do not match their type",
+        invalid.len()
+    )))
+}
+
+async fn handle_check_schema(
+    out: &dyn OutputSink,
+    service: ConfigService,
+    format: OutputFormat,
+) -> Result<()> {
+    let status = service.check_schema().await?;
+
+    if format.is_structured() {
+        output_structured(out, format, &status)?;
+    } else {
+        match status.migration_version {
+            Some(version) => outln!(out, "Migration version: {}", version),
+            None => outln!(out, "Migration version: not tracked"),
+        }
+        if status.is_complete() {
+            outln!(out, "✓ All expected tables are present");
+        } else {
+            outln!(out, "Missing tables:");
+            for table in &status.missing_tables {
+                outln!(out, "  ✗ {}", table);
+            }
+        }
+    }
+
+    if status.is_complete() {
+        return Ok(());
+    }
+    Err(DevErpError::Config(format!(
+        "Database schema is incomplete; missing {} (run `sqlx migrate run`)",
+        status.missing_tables.join(", ")
+    )))
+}
@@ -0,0 +1,79 @@
+// Shared state for a single CLI invocation
+
+use sqlx::PgPool;
+use std::sync::Arc;
+
+use super::output::{OutputSink, StdoutSink};
+use crate::config::settings::Settings;
+use crate::domain::config::service::ConfigService;
+use crate::infrastructure::database;
+use crate::infrastructure::repositories::config_repo::PostgresConfigRepository;
+use crate::Result;
+
+/// Resources shared by every handler of one command
+///
+/// Built once in `Cli::execute` and passed down by reference, so a command
+/// opens a single connection pool however many services it touches.
+#[derive(Clone)]
+pub struct AppContext {
+    pool: PgPool,
+    sink: Arc<dyn OutputSink>,
+    dry_run: bool,
+}
+
+impl AppContext {
+    /// Connect to the database described by `settings`
+    pub async fn connect(settings: &Settings) -> Result<Self> {
+        let pool = database::establish_connection(&settings.database).await?;
+        Ok(Self::new(pool))
+    }
+
+    /// Wrap an existing pool, writing output to stdout
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            pool,
+            sink: Arc::new(StdoutSink),
+            dry_run: false,
+        }
+    }
+
+    /// Send command output to `sink` instead of stdout
+    pub fn with_sink(mut self, sink: Arc<dyn OutputSink>) -> Self {
+        self.sink = sink;
+        self
+    }
+
+    /// Make mutating handlers report what they would do instead of doing it
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Whether `--dry-run` was given
+    pub fn dry_run(&self) -> bool {
+        self.dry_run
+    }
+
+    /// Where handlers write their output
+    pub fn out(&self) -> &dyn OutputSink {
+        self.sink.as_ref()
+    }
+
+    /// Handle to the shared pool (cheap to clone)
+    pub fn pool(&self) -> PgPool {
+        self.pool.clone()
+    }
+
+    /// Configuration service backed by the shared pool
+    pub fn config_service(&self) -> ConfigService {
+        ConfigService::new(
+            Arc::new(PostgresConfigRepository::new(self.pool())),
+            self.pool(),
+        )
+    }
+
+    /// Wait for checked-out connections to be returned, then close the pool
+    pub async fn close(&self) {
+        self.pool.close().await;
+    }
+}
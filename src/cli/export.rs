@@ -0,0 +1,61 @@
+// Export CLI command implementation
+
+use std::path::Path;
+use std::sync::Arc;
+
+use super::commands::{ExportArgs, OutputFormat};
+use super::context::AppContext;
+use super::output::{self, outln, summary_line};
+use crate::domain::export::{ExportFormat, ExportService};
+use crate::infrastructure::repositories::export_repo::PostgresExportRepository;
+use crate::utils::error::DevErpError;
+use crate::Result;
+
+/// Handle the export command
+pub async fn handle(ctx: &AppContext, args: ExportArgs, format: OutputFormat) -> Result<()> {
+    let out = ctx.out();
+    let export_format = match format {
+        OutputFormat::Table | OutputFormat::Json => ExportFormat::Json,
+        OutputFormat::Csv => ExportFormat::Csv,
+        OutputFormat::Plain | OutputFormat::Yaml | OutputFormat::Count => {
+            return Err(DevErpError::Validation(
+                "Export supports --format json or --format csv".to_string(),
+            ))
+        }
+    };
+
+    let pool = ctx.pool();
+    let service = ExportService::new(Arc::new(PostgresExportRepository::new(pool)));
+
+    if let Some(dir) = args.output_dir {
+        let files = service
+            .export_to_dir(Path::new(&dir), export_format)
+            .await?;
+
+        output::success(out, &format!("Exported {} files to {}", files.len(), dir));
+        outln!(out);
+        for file in &files {
+            summary_line(
+                out,
+                file.entity,
+                &format!("{} ({} records)", file.path.display(), file.records),
+            );
+        }
+        outln!(out);
+    } else if let Some(path) = args.output {
+        let data = service
+            .export_to_file(Path::new(&path), export_format)
+            .await?;
+
+        output::success(out, &format!("Exported data to {}", path));
+        outln!(out);
+        summary_line(out, "projects", &data.projects.len().to_string());
+        summary_line(out, "tasks", &data.tasks.len().to_string());
+        summary_line(out, "resources", &data.resources.len().to_string());
+        summary_line(out, "timelines", &data.timelines.len().to_string());
+        summary_line(out, "milestones", &data.milestones.len().to_string());
+        outln!(out);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,211 @@
+// Gantt-style ASCII rendering of timelines and milestones
+
+use chrono::NaiveDate;
+use colored::{ColoredString, Colorize};
+use terminal_size::{terminal_size, Width};
+
+use crate::domain::timeline::entity::{Milestone, MilestoneStatus, Timeline};
+
+/// Width used when the terminal width cannot be detected
+const DEFAULT_WIDTH: usize = 80;
+
+/// Narrowest chart area, however small the terminal
+const MIN_CHART_WIDTH: usize = 10;
+
+/// Longest label before it is cut short
+const MAX_LABEL_WIDTH: usize = 24;
+
+/// Maps dates onto the columns of a chart `width` cells wide
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GanttAxis {
+    start: NaiveDate,
+    end: NaiveDate,
+    width: usize,
+}
+
+impl GanttAxis {
+    /// Axis from `start` to `end`; a reversed range is swapped
+    pub fn new(start: NaiveDate, end: NaiveDate, width: usize) -> Self {
+        Self {
+            start: start.min(end),
+            end: start.max(end),
+            width: width.max(1),
+        }
+    }
+
+    /// Column of `date`, clamped to the axis
+    ///
+    /// When the axis covers a single day every date lands in column 0.
+    pub fn column(&self, date: NaiveDate) -> usize {
+        let days = (self.end - self.start).num_days();
+        if days == 0 {
+            return 0;
+        }
+        let offset = (date - self.start).num_days().clamp(0, days);
+        (offset as f64 / days as f64 * (self.width - 1) as f64).round() as usize
+    }
+
+    /// First column and length of a bar from `from` to `to`, at least one cell
+    pub fn span(&self, from: NaiveDate, to: NaiveDate) -> (usize, usize) {
+        let first = self.column(from.min(to));
+        let last = self.column(from.max(to));
+        (first, last - first + 1)
+    }
+}
+
+/// Width of the terminal on stdout, else `COLUMNS`, else 80
+///
+/// Output piped to a file or another program has no terminal, so `COLUMNS`
+/// (or the default) decides the width there.
+pub fn terminal_width() -> usize {
+    let tty = terminal_size().map(|(Width(width), _)| usize::from(width));
+    width_from(tty, std::env::var("COLUMNS").ok())
+}
+
+fn width_from(tty: Option<usize>, columns: Option<String>) -> usize {
+    tty.filter(|&w| w > 0)
+        .or_else(|| {
+            columns
+                .and_then(|c| c.trim().parse::<usize>().ok())
+                .filter(|&c| c > 0)
+        })
+        .unwrap_or(DEFAULT_WIDTH)
+}
+
+/// Render each timeline as a bar followed by one row per milestone
+///
+/// The axis runs from the earliest start to the latest end across all
+/// timelines and milestone target dates. Returns no lines for no timelines.
+pub fn render_gantt(timelines: &[(Timeline, Vec<Milestone>)], total_width: usize) -> Vec<String> {
+    let dates = timelines.iter().flat_map(|(timeline, milestones)| {
+        [timeline.start_date, timeline.end_date]
+            .into_iter()
+            .chain(milestones.iter().map(|m| m.target_date))
+    });
+    let (Some(start), Some(end)) = (dates.clone().min(), dates.max()) else {
+        return Vec::new();
+    };
+
+    let label_width = timelines
+        .iter()
+        .flat_map(|(timeline, milestones)| {
+            std::iter::once(timeline.name.chars().count())
+                .chain(milestones.iter().map(|m| m.name.chars().count() + 2))
+        })
+        .max()
+        .unwrap_or(0)
+        .min(MAX_LABEL_WIDTH);
+    // Label, a space and the two bar edges
+    let chart_width = total_width
+        .saturating_sub(label_width + 3)
+        .max(MIN_CHART_WIDTH);
+    let axis = GanttAxis::new(start, end, chart_width);
+
+    let start_label = start.to_string();
+    let end_label = end.to_string();
+    let gap = chart_width.saturating_sub(start_label.len() + end_label.len());
+    // Date labels sit over the first and last chart columns
+    let mut lines = vec![format!(
+        "{}  {}{}{}",
+        " ".repeat(label_width),
+        start_label.dimmed(),
+        " ".repeat(gap.max(1)),
+        end_label.dimmed()
+    )];
+
+    for (timeline, milestones) in timelines {
+        let (first, len) = axis.span(timeline.start_date, timeline.end_date);
+        lines.push(format!(
+            "{} |{}{}{}|",
+            fit_label(&timeline.name, label_width).bold(),
+            " ".repeat(first),
+            "█".repeat(len).bright_blue(),
+            " ".repeat(chart_width - first - len)
+        ));
+
+        for milestone in milestones {
+            let column = axis.column(milestone.target_date);
+            lines.push(format!(
+                "{} |{}{}{}|",
+                fit_label(&format!("  {}", milestone.name), label_width),
+                " ".repeat(column),
+                milestone_marker(&milestone.status),
+                " ".repeat(chart_width - column - 1)
+            ));
+        }
+    }
+
+    lines
+}
+
+/// Pad or cut `label` to exactly `width` characters
+fn fit_label(label: &str, width: usize) -> String {
+    if label.chars().count() <= width {
+        return format!("{:<width$}", label, width = width);
+    }
+    let cut: String = label.chars().take(width.saturating_sub(1)).collect();
+    format!("{}…", cut)
+}
+
+/// The `◆` marker colored by milestone status
+fn milestone_marker(status: &MilestoneStatus) -> ColoredString {
+    match status {
+        MilestoneStatus::Completed => "◆".bright_green(),
+        MilestoneStatus::InProgress => "◆".bright_yellow(),
+        MilestoneStatus::Missed => "◆".bright_red(),
+        MilestoneStatus::Cancelled => "◆".dimmed(),
+        MilestoneStatus::Pending => "◆".normal(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_axis_scales_dates_to_columns() {
+        // Ten days over eleven columns: one column per day
+        let axis = GanttAxis::new(date(3, 1), date(3, 11), 11);
+
+        assert_eq!(axis.column(date(3, 1)), 0);
+        assert_eq!(axis.column(date(3, 6)), 5);
+        assert_eq!(axis.column(date(3, 11)), 10);
+        // Out-of-range dates stick to the edges
+        assert_eq!(axis.column(date(2, 1)), 0);
+        assert_eq!(axis.column(date(4, 1)), 10);
+
+        assert_eq!(axis.span(date(3, 3), date(3, 5)), (2, 3));
+    }
+
+    #[test]
+    fn test_axis_with_zero_width_range() {
+        let axis = GanttAxis::new(date(3, 1), date(3, 1), 40);
+
+        assert_eq!(axis.column(date(3, 1)), 0);
+        assert_eq!(axis.span(date(3, 1), date(3, 1)), (0, 1));
+    }
+
+    #[test]
+    fn test_width_defaults_to_80_columns() {
+        assert_eq!(width_from(None, Some("120".to_string())), 120);
+        assert_eq!(width_from(None, Some("0".to_string())), DEFAULT_WIDTH);
+        assert_eq!(width_from(None, Some("wide".to_string())), DEFAULT_WIDTH);
+        assert_eq!(width_from(None, None), DEFAULT_WIDTH);
+    }
+
+    #[test]
+    fn test_width_prefers_the_terminal_over_columns() {
+        assert_eq!(width_from(Some(132), Some("120".to_string())), 132);
+        assert_eq!(width_from(Some(0), Some("120".to_string())), 120);
+    }
+
+    #[test]
+    fn test_fit_label_pads_and_truncates() {
+        assert_eq!(fit_label("Beta", 6), "Beta  ");
+        assert_eq!(fit_label("Release candidate", 8), "Release…");
+    }
+}
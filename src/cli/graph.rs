@@ -0,0 +1,137 @@
+// Graphviz DOT rendering of task dependency graphs
+
+use std::collections::HashSet;
+
+use crate::domain::task::entity::{DependencyType, Task, TaskDependency};
+
+/// Edge color for each kind of dependency
+fn edge_color(dependency_type: &DependencyType) -> &'static str {
+    match dependency_type {
+        DependencyType::FinishToStart => "black",
+        DependencyType::StartToStart => "blue",
+        DependencyType::FinishToFinish => "darkgreen",
+        DependencyType::StartToFinish => "orange",
+    }
+}
+
+/// Escape `text` for use inside a double-quoted DOT string
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// Render a project's tasks and dependencies as a DOT digraph
+///
+/// Edges point from the prerequisite to the task that waits on it, so the
+/// graph reads in working order. Edges on any of `cycles` (as returned by
+/// `find_cycles`) are drawn thick and red in place of their type color, and
+/// each cycle is listed in a comment at the top.
+pub fn render_dot(
+    project_id: i64,
+    tasks: &[Task],
+    dependencies: &[TaskDependency],
+    cycles: &[Vec<i64>],
+) -> String {
+    let cycle_edges: HashSet<(i64, i64)> = cycles
+        .iter()
+        .flat_map(|cycle| {
+            cycle
+                .iter()
+                .zip(cycle.iter().cycle().skip(1))
+                .map(|(&task, &depends_on)| (task, depends_on))
+        })
+        .collect();
+
+    let mut lines = vec![format!("digraph \"project_{}\" {{", project_id)];
+    for cycle in cycles {
+        let ids: Vec<String> = cycle
+            .iter()
+            .chain(cycle.first())
+            .map(|id| format!("#{}", id))
+            .collect();
+        lines.push(format!("  // cycle: {}", ids.join(" -> ")));
+    }
+    lines.push("  rankdir=LR;".to_string());
+    lines.push("  node [shape=box];".to_string());
+
+    for task in tasks {
+        lines.push(format!(
+            "  t{} [label=\"#{} {}\"];",
+            task.id,
+            task.id,
+            escape(&task.title)
+        ));
+    }
+
+    for dep in dependencies {
+        let attrs = if cycle_edges.contains(&(dep.task_id, dep.depends_on_task_id)) {
+            "color=\"red\", penwidth=3".to_string()
+        } else {
+            format!("color=\"{}\"", edge_color(&dep.dependency_type))
+        };
+        lines.push(format!(
+            "  t{} -> t{} [label=\"{}\", {}];",
+            dep.depends_on_task_id, dep.task_id, dep.dependency_type, attrs
+        ));
+    }
+
+    lines.push("}".to_string());
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    use crate::domain::task::entity::TaskType;
+
+    fn task(id: i64, title: &str) -> Task {
+        Task {
+            id,
+            title: title.to_string(),
+            task_type: Some(TaskType::Feature),
+            ..Task::test_default()
+        }
+    }
+
+    fn dependency(task_id: i64, depends_on: i64, kind: DependencyType) -> TaskDependency {
+        TaskDependency {
+            task_id,
+            depends_on_task_id: depends_on,
+            dependency_type: kind,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_render_dot_labels_nodes_and_colors_edges() {
+        let tasks = [task(1, "Design \"v2\" API"), task(2, "Build")];
+        let deps = [dependency(2, 1, DependencyType::StartToStart)];
+
+        let dot = render_dot(7, &tasks, &deps, &[]);
+
+        assert!(dot.starts_with("digraph \"project_7\" {"));
+        assert!(dot.contains("t1 [label=\"#1 Design \\\"v2\\\" API\"];"));
+        assert!(dot.contains("t1 -> t2 [label=\"start_to_start\", color=\"blue\"];"));
+        assert!(dot.ends_with('}'));
+    }
+
+    #[test]
+    fn test_render_dot_highlights_cycles() {
+        let tasks = [task(1, "A"), task(2, "B"), task(3, "C")];
+        let deps = [
+            dependency(1, 2, DependencyType::FinishToStart),
+            dependency(2, 1, DependencyType::FinishToFinish),
+            dependency(3, 1, DependencyType::FinishToStart),
+        ];
+
+        let dot = render_dot(1, &tasks, &deps, &[vec![1, 2]]);
+
+        assert!(dot.contains("// cycle: #1 -> #2 -> #1"));
+        assert!(dot.contains("t2 -> t1 [label=\"finish_to_start\", color=\"red\", penwidth=3];"));
+        assert!(dot.contains("t1 -> t2 [label=\"finish_to_finish\", color=\"red\", penwidth=3];"));
+        assert!(dot.contains("t1 -> t3 [label=\"finish_to_start\", color=\"black\"];"));
+    }
+}
@@ -1,34 +1,80 @@
 // CLI Interface Layer
 
 pub mod commands;
+pub mod context;
+pub mod gantt;
+pub mod graph;
 pub mod output;
 
 // Command handlers
 pub mod config;
+pub mod export;
 pub mod project;
 pub mod report;
 pub mod resource;
 pub mod task;
+pub mod template;
 pub mod timeline;
 
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::config::settings::Settings;
 use crate::Result;
 pub use commands::Cli;
 use commands::Commands;
+pub use context::AppContext;
+use output::FileSink;
 
 impl Cli {
     /// Execute the CLI command
+    ///
+    /// Opens one connection pool for the whole command and closes it once the
+    /// handler returns, whether or not it succeeded.
     pub async fn execute(&self) -> Result<()> {
+        let mut settings = Settings::default();
+        if let Some(url) = &self.database_url {
+            settings.database = settings.database.with_url(url)?;
+        }
+        let sink = match &self.output_file {
+            Some(path) => Some(Arc::new(FileSink::create(Path::new(path))?)),
+            None => None,
+        };
+        let mut ctx = AppContext::connect(&settings)
+            .await?
+            .with_dry_run(self.dry_run);
+        if let Some(sink) = &sink {
+            ctx = ctx.with_sink(sink.clone());
+        }
+        let result = self.dispatch(&ctx).await;
+        ctx.close().await;
+        result?;
+
+        // A failed write to --output-file fails the command
+        if let Some(sink) = sink {
+            sink.finish()?;
+        }
+        Ok(())
+    }
+
+    /// Run the selected command's handler
+    async fn dispatch(&self, ctx: &AppContext) -> Result<()> {
         match &self.command {
-            Commands::Project(cmd) => project::handle(cmd.clone(), self.format).await,
-            Commands::Task(cmd) => task::handle(cmd.clone(), self.format).await,
-            Commands::Resource(cmd) => resource::handle(cmd.clone(), self.format).await,
-            Commands::Timeline(cmd) => timeline::handle(cmd.clone(), self.format).await,
-            Commands::Report(cmd) => report::handle(cmd.clone(), self.format).await,
-            Commands::Config(cmd) => config::handle(cmd.clone(), self.format).await,
+            Commands::Project(cmd) => project::handle(ctx, cmd.clone(), self.format).await,
+            Commands::Task(cmd) => task::handle(ctx, cmd.clone(), self.format).await,
+            Commands::Resource(cmd) => resource::handle(ctx, cmd.clone(), self.format).await,
+            Commands::Timeline(cmd) => timeline::handle(ctx, cmd.clone(), self.format).await,
+            Commands::Milestone(cmd) => {
+                timeline::handle_milestone(ctx, cmd.clone(), self.format).await
+            }
+            Commands::Template(cmd) => template::handle(ctx, cmd.clone(), self.format).await,
+            Commands::Report(cmd) => report::handle(ctx, cmd.clone(), self.format).await,
+            Commands::Config(cmd) => config::handle(ctx, cmd.clone(), self.format).await,
+            Commands::Export(args) => export::handle(ctx, args.clone(), self.format).await,
         }
     }
 }
 
 // Re-export commonly used types
 pub use commands::{OutputFormat, PaginationOptions};
-pub use output::{OutputManager, PaginatedOutput};
+pub use output::{BufferSink, OutputManager, OutputSink, PaginatedOutput};
@@ -1,56 +1,177 @@
 // Output formatting utilities for CLI
 
+use std::fs::File;
+use std::io::{self, LineWriter, Write};
+use std::path::Path;
+use std::sync::Mutex;
+
+use super::commands::OutputFormat;
+use crate::utils::error::DevErpError;
 use crate::utils::formatter;
 use colored::Colorize;
 use serde::Serialize;
 
+/// Destination for command output
+///
+/// Handlers write through the sink on their [`AppContext`](super::AppContext)
+/// instead of printing directly, so the same code can target the terminal,
+/// a file, or an in-memory buffer in tests. The terminal sink panics like
+/// `println!` if a write fails; [`FileSink`] keeps the error for
+/// [`FileSink::finish`] instead.
+pub trait OutputSink: Send + Sync {
+    /// Write `text` without a trailing newline
+    fn write_str(&self, text: &str);
+
+    /// Write `line` followed by a newline
+    fn write_line(&self, line: &str) {
+        self.write_str(line);
+        self.write_str("\n");
+    }
+}
+
+/// `println!` for an [`OutputSink`]
+macro_rules! outln {
+    ($out:expr) => {
+        $out.write_line("")
+    };
+    ($out:expr, $($arg:tt)*) => {
+        $out.write_line(&format!($($arg)*))
+    };
+}
+
+/// `print!` for an [`OutputSink`]
+macro_rules! out {
+    ($out:expr, $($arg:tt)*) => {
+        $out.write_str(&format!($($arg)*))
+    };
+}
+
+pub(crate) use out;
+pub(crate) use outln;
+
+/// Writes to standard output
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StdoutSink;
+
+impl OutputSink for StdoutSink {
+    fn write_str(&self, text: &str) {
+        print!("{}", text);
+    }
+}
+
+/// Writes to a file, line-buffered
+///
+/// The first failed write is kept and later writes are dropped, so a full
+/// disk surfaces as one error from [`finish`](Self::finish).
+#[derive(Debug)]
+pub struct FileSink {
+    file: Mutex<LineWriter<File>>,
+    error: Mutex<Option<io::Error>>,
+}
+
+impl FileSink {
+    /// Create (or truncate) the file at `path`
+    pub fn create(path: &Path) -> io::Result<Self> {
+        Ok(Self {
+            file: Mutex::new(LineWriter::new(File::create(path)?)),
+            error: Mutex::new(None),
+        })
+    }
+
+    /// Flush the file, failing with the first write error if there was one
+    pub fn finish(&self) -> io::Result<()> {
+        if let Some(error) = self.error.lock().unwrap_or_else(|e| e.into_inner()).take() {
+            return Err(error);
+        }
+        self.file.lock().unwrap_or_else(|e| e.into_inner()).flush()
+    }
+}
+
+impl OutputSink for FileSink {
+    fn write_str(&self, text: &str) {
+        let mut error = self.error.lock().unwrap_or_else(|e| e.into_inner());
+        if error.is_some() {
+            return;
+        }
+        let mut file = self.file.lock().unwrap_or_else(|e| e.into_inner());
+        if let Err(e) = file.write_all(text.as_bytes()) {
+            *error = Some(e);
+        }
+    }
+}
+
+/// Collects output in memory, for tests
+#[derive(Debug, Default)]
+pub struct BufferSink {
+    buffer: Mutex<String>,
+}
+
+impl BufferSink {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Everything written so far
+    pub fn contents(&self) -> String {
+        self.buffer
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+}
+
+impl OutputSink for BufferSink {
+    fn write_str(&self, text: &str) {
+        self.buffer
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push_str(text);
+    }
+}
+
 /// Print paginated results with metadata
 pub struct PaginatedOutput<T> {
     pub items: Vec<T>,
     pub page: u32,
     pub per_page: u32,
-    pub total: Option<usize>,
+    /// Matching items across all pages
+    pub total: i64,
 }
 
 impl<T> PaginatedOutput<T> {
-    pub fn new(items: Vec<T>, page: u32, per_page: u32) -> Self {
+    pub fn new(items: Vec<T>, page: u32, per_page: u32, total: i64) -> Self {
         Self {
             items,
             page,
             per_page,
-            total: None,
+            total,
         }
     }
 
-    pub fn with_total(mut self, total: usize) -> Self {
-        self.total = Some(total);
-        self
+    /// Number of pages needed for `total` items, at least one
+    pub fn total_pages(&self) -> u32 {
+        let per_page = i64::from(self.per_page.max(1));
+        let pages = (self.total.max(0) + per_page - 1) / per_page;
+        u32::try_from(pages.max(1)).unwrap_or(u32::MAX)
     }
 
-    pub fn print_metadata(&self) {
-        let start = ((self.page - 1) * self.per_page) + 1;
-        let end = start + self.items.len() as u32 - 1;
+    pub fn print_metadata(&self, out: &dyn OutputSink) {
+        let total_pages = self.total_pages();
+        outln!(
+            out,
+            "\n{} Page {} of {} ({} total)",
+            "ℹ".blue().bold(),
+            self.page,
+            total_pages,
+            self.total
+        );
 
-        if let Some(total) = self.total {
-            println!(
-                "\n{} Showing {}-{} of {} items (Page {})",
-                "ℹ".blue().bold(),
-                start,
-                end,
-                total,
-                self.page
-            );
-
-            let total_pages = (total as f64 / self.per_page as f64).ceil() as u32;
-            if self.page < total_pages {
-                println!("{} Use --page {} to see more", "→".cyan(), self.page + 1);
-            }
-        } else {
-            println!(
-                "\n{} Showing {} items (Page {})",
-                "ℹ".blue().bold(),
-                self.items.len(),
-                self.page
+        if self.page < total_pages {
+            outln!(
+                out,
+                "{} Use --page {} to see more",
+                "→".cyan(),
+                self.page + 1
             );
         }
     }
@@ -67,77 +188,277 @@ impl OutputManager {
     }
 
     /// Output a single item
-    pub fn output_single<T: Serialize>(&self, item: &T) -> crate::Result<()> {
+    pub fn output_single<T: Serialize>(&self, out: &dyn OutputSink, item: &T) -> crate::Result<()> {
         match self.format {
-            formatter::OutputFormat::Json => formatter::output_json(item),
-            formatter::OutputFormat::Table | formatter::OutputFormat::Plain => {
-                // For single items in table/plain format, use key-value display
-                formatter::output_json(item) // Fallback to JSON for complex structures
-            }
+            formatter::OutputFormat::Yaml => write_yaml(out, item),
+            // Tables fall back to JSON for complex structures
+            formatter::OutputFormat::Json | formatter::OutputFormat::Table => write_json(out, item),
+            other => Err(unsupported_format(other)),
         }
     }
 
     /// Output a list of items
-    pub fn output_list<T: Serialize>(&self, items: &[T]) -> crate::Result<()> {
+    pub fn output_list<T: Serialize>(
+        &self,
+        out: &dyn OutputSink,
+        items: &[T],
+    ) -> crate::Result<()> {
         match self.format {
-            formatter::OutputFormat::Json => formatter::output_json(items),
-            formatter::OutputFormat::Table | formatter::OutputFormat::Plain => {
-                formatter::output_json(items) // Fallback to JSON for now
+            formatter::OutputFormat::Yaml => write_yaml(out, items),
+            // Tables fall back to JSON for now
+            formatter::OutputFormat::Json | formatter::OutputFormat::Table => {
+                write_json(out, items)
             }
+            other => Err(unsupported_format(other)),
         }
     }
 
     /// Output a paginated list
-    pub fn output_paginated<T: Serialize>(&self, output: &PaginatedOutput<T>) -> crate::Result<()> {
-        self.output_list(&output.items)?;
+    pub fn output_paginated<T: Serialize>(
+        &self,
+        out: &dyn OutputSink,
+        output: &PaginatedOutput<T>,
+    ) -> crate::Result<()> {
+        self.output_list(out, &output.items)?;
 
-        if self.format != formatter::OutputFormat::Json {
-            output.print_metadata();
+        if !matches!(
+            self.format,
+            formatter::OutputFormat::Json | formatter::OutputFormat::Yaml
+        ) {
+            output.print_metadata(out);
         }
 
         Ok(())
     }
 }
 
+/// Point a rejected completion at `--force`, the flag that skips the check
+pub fn with_force_hint(err: DevErpError) -> DevErpError {
+    match err {
+        DevErpError::Validation(message) => {
+            DevErpError::Validation(format!("{} (use --force to override)", message))
+        }
+        other => other,
+    }
+}
+
+/// Error for a `--format` the output cannot be rendered in
+fn unsupported_format(format: formatter::OutputFormat) -> DevErpError {
+    let name = match format {
+        formatter::OutputFormat::Table => "table",
+        formatter::OutputFormat::Json => "json",
+        formatter::OutputFormat::Plain => "plain",
+        formatter::OutputFormat::Csv => "csv",
+        formatter::OutputFormat::Yaml => "yaml",
+        formatter::OutputFormat::Count => "count",
+    };
+    DevErpError::Validation(format!(
+        "--format {} is not supported for this output",
+        name
+    ))
+}
+
+fn write_json<T: Serialize + ?Sized>(out: &dyn OutputSink, data: &T) -> crate::Result<()> {
+    let json = serde_json::to_string_pretty(data)
+        .map_err(|e| DevErpError::Internal(format!("JSON serialization error: {}", e)))?;
+    out.write_line(&json);
+    Ok(())
+}
+
+fn write_yaml<T: Serialize + ?Sized>(out: &dyn OutputSink, data: &T) -> crate::Result<()> {
+    out.write_str(&formatter::to_yaml(data)?);
+    Ok(())
+}
+
+/// Print data in the selected structured format (JSON unless YAML was chosen)
+pub fn output_structured<T: Serialize + ?Sized>(
+    out: &dyn OutputSink,
+    format: OutputFormat,
+    data: &T,
+) -> crate::Result<()> {
+    match format {
+        OutputFormat::Yaml => write_yaml(out, data),
+        OutputFormat::Json => write_json(out, data),
+        other => Err(unsupported_format(other.into())),
+    }
+}
+
+/// Print the notice for a `--dry-run` command that would `action`
+pub fn dry_run(out: &dyn OutputSink, action: &str) {
+    outln!(
+        out,
+        "{} Dry run: would {}. Nothing was changed.",
+        "ℹ".blue().bold(),
+        action
+    );
+}
+
+/// Print a dry-run notice followed by the fields set on `input`
+///
+/// Fields left unset (`null` once serialized) are skipped, so an update
+/// lists only what it would change.
+pub fn dry_run_input<T: Serialize>(
+    out: &dyn OutputSink,
+    action: &str,
+    input: &T,
+) -> crate::Result<()> {
+    dry_run(out, action);
+
+    let value = serde_json::to_value(input)
+        .map_err(|e| DevErpError::Internal(format!("JSON serialization error: {}", e)))?;
+    if let serde_json::Value::Object(fields) = value {
+        for (key, value) in fields.iter().filter(|(_, value)| !value.is_null()) {
+            match value {
+                serde_json::Value::String(text) => summary_line(out, key, text),
+                other => summary_line(out, key, &other.to_string()),
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Like [`dry_run_input`], also listing the fields the update would clear
+///
+/// A cleared `Option<Option<_>>` field serializes to `null` just like an
+/// unset one, so callers name the cleared fields explicitly.
+pub fn dry_run_update<T: Serialize>(
+    out: &dyn OutputSink,
+    action: &str,
+    input: &T,
+    cleared: &[&str],
+) -> crate::Result<()> {
+    dry_run_input(out, action, input)?;
+    for field in cleared {
+        summary_line(out, field, "(cleared)");
+    }
+    Ok(())
+}
+
+/// Print a success message
+pub fn success(out: &dyn OutputSink, message: &str) {
+    outln!(out, "{} {}", "✓".green().bold(), message);
+}
+
+/// Print a warning message
+pub fn warning(out: &dyn OutputSink, message: &str) {
+    outln!(out, "{} {}", "⚠".yellow().bold(), message);
+}
+
+/// Print a bare row count, for shell conditionals
+pub fn count(out: &dyn OutputSink, count: i64) -> crate::Result<()> {
+    outln!(out, "{}", count);
+    Ok(())
+}
+
+/// Print items as newline-delimited JSON, see [`formatter::to_ndjson`]
+pub fn ndjson<T: Serialize>(out: &dyn OutputSink, items: &[T]) -> crate::Result<()> {
+    out.write_str(&formatter::to_ndjson(items)?);
+    Ok(())
+}
+
+/// Print rows in CSV format
+pub fn csv(out: &dyn OutputSink, headers: &[&str], rows: &[Vec<String>]) {
+    out.write_str(&formatter::to_csv(headers, rows));
+}
+
+/// Print a key-value pair
+pub fn key_value(out: &dyn OutputSink, key: &str, value: &str) {
+    outln!(out, "{}: {}", key.bold(), value);
+}
+
+/// Print an underlined section header
+pub fn section_header(out: &dyn OutputSink, title: &str) {
+    outln!(out, "\n{}", title.bold().underline());
+}
+
+/// Print a table header
+pub fn table_header(out: &dyn OutputSink, columns: &[&str]) {
+    out.write_line(&formatter::format_table_header(columns));
+}
+
+/// Print a table row
+pub fn table_row(out: &dyn OutputSink, values: &[String]) {
+    out.write_line(&values.join(" | "));
+}
+
+/// Render a percentage (0-100) as a bar of `width` cells
+pub fn progress_bar(percent: f64, width: usize) -> String {
+    let filled = ((percent.clamp(0.0, 100.0) / 100.0) * width as f64).round() as usize;
+    format!("{}{}", "█".repeat(filled), "░".repeat(width - filled))
+}
+
 /// Print a divider line
-pub fn divider() {
-    println!("{}", "─".repeat(80).dimmed());
+pub fn divider(out: &dyn OutputSink) {
+    outln!(out, "{}", "─".repeat(80).dimmed());
 }
 
 /// Print a section title
-pub fn section_title(title: &str) {
-    println!("\n{}", title.bold().cyan());
-    divider();
+pub fn section_title(out: &dyn OutputSink, title: &str) {
+    outln!(out, "\n{}", title.bold().cyan());
+    divider(out);
 }
 
 /// Print a summary line (key: value)
-pub fn summary_line(key: &str, value: &str) {
-    println!("  {}: {}", key.bold(), value);
+pub fn summary_line(out: &dyn OutputSink, key: &str, value: &str) {
+    outln!(out, "  {}: {}", key.bold(), value);
 }
 
 /// Print an indented message
-pub fn indent(message: &str, level: usize) {
+pub fn indent(out: &dyn OutputSink, message: &str, level: usize) {
     let spacing = "  ".repeat(level);
-    println!("{}{}", spacing, message);
+    outln!(out, "{}{}", spacing, message);
 }
 
 /// Print a list item
-pub fn list_item(text: &str) {
-    println!("  {} {}", "•".cyan(), text);
+pub fn list_item(out: &dyn OutputSink, text: &str) {
+    outln!(out, "  {} {}", "•".cyan(), text);
 }
 
 /// Print a numbered item
-pub fn numbered_item(number: usize, text: &str) {
-    println!("  {}. {}", number.to_string().bold(), text);
+pub fn numbered_item(out: &dyn OutputSink, number: usize, text: &str) {
+    outln!(out, "  {}. {}", number.to_string().bold(), text);
 }
 
 /// Print an empty state message
-pub fn empty_state(entity: &str) {
-    println!("\n{} No {} found.", "ℹ".blue().bold(), entity);
-    println!("  Use the 'create' command to add one.\n");
+pub fn empty_state(out: &dyn OutputSink, entity: &str) {
+    outln!(out, "\n{} No {} found.", "ℹ".blue().bold(), entity);
+    outln!(out, "  Use the 'create' command to add one.\n");
+}
+
+/// Message for an empty list result
+///
+/// `total` is the unfiltered row count: when it is zero nothing exists yet
+/// and the message suggests `create_command` as the next step; otherwise the
+/// filters simply matched nothing.
+pub fn empty_list_message(entity: &str, total: i64, create_command: &str) -> String {
+    if total == 0 {
+        format!(
+            "No {} yet.\n  Get started with: {}",
+            entity,
+            create_command.bold()
+        )
+    } else {
+        format!(
+            "No {} match the given filters ({} in total).",
+            entity, total
+        )
+    }
+}
+
+/// Print an empty list result, see [`empty_list_message`]
+pub fn empty_list(out: &dyn OutputSink, entity: &str, total: i64, create_command: &str) {
+    outln!(
+        out,
+        "\n{} {}\n",
+        "ℹ".blue().bold(),
+        empty_list_message(entity, total, create_command)
+    );
 }
 
 /// Confirm action with user
+///
+/// The prompt always goes to the terminal, whatever the output sink.
 pub fn confirm(prompt: &str) -> bool {
     use std::io::{self, Write};
 
@@ -150,18 +471,103 @@ pub fn confirm(prompt: &str) -> bool {
     matches!(input.trim().to_lowercase().as_str(), "y" | "yes")
 }
 
+/// Ask for a line of input, asking again until `parse` accepts it
+///
+/// An empty answer stands for `default` when there is one, which is shown in
+/// brackets. Like [`confirm`], the prompt always goes to the terminal.
+pub fn prompt<T>(
+    label: &str,
+    default: Option<&str>,
+    parse: impl Fn(&str) -> Result<T, String>,
+) -> crate::Result<T> {
+    prompt_from(
+        &mut io::stdin().lock(),
+        &mut io::stdout(),
+        label,
+        default,
+        parse,
+    )
+}
+
+/// [`prompt`] reading answers from `input` and writing to `output`
+///
+/// Fails if `input` runs out before a valid answer is given.
+pub fn prompt_from<T>(
+    input: &mut impl io::BufRead,
+    output: &mut impl Write,
+    label: &str,
+    default: Option<&str>,
+    parse: impl Fn(&str) -> Result<T, String>,
+) -> crate::Result<T> {
+    let default = default.filter(|d| !d.is_empty());
+    loop {
+        match default {
+            Some(default) => write!(output, "{} {} [{}]: ", "?".yellow().bold(), label, default)?,
+            None => write!(output, "{} {}: ", "?".yellow().bold(), label)?,
+        }
+        output.flush()?;
+
+        let mut line = String::new();
+        if input.read_line(&mut line)? == 0 {
+            return Err(DevErpError::Validation(format!(
+                "No answer given for {}",
+                label
+            )));
+        }
+
+        let answer = match line.trim() {
+            "" => default.unwrap_or(""),
+            answer => answer,
+        };
+        match parse(answer) {
+            Ok(value) => return Ok(value),
+            Err(err) => writeln!(output, "{} {}", "✗".red().bold(), err)?,
+        }
+    }
+}
+
+/// Format a `key=value` line that shell scripts can `source`
+///
+/// Values made only of safe characters are emitted bare; anything else is
+/// single-quoted with embedded quotes escaped as `'\''`.
+pub fn kv_line(key: &str, value: &str) -> String {
+    let is_safe = |c: char| c.is_ascii_alphanumeric() || "-_.,:/@+%".contains(c);
+
+    if value.chars().all(is_safe) {
+        format!("{}={}", key, value)
+    } else {
+        format!("{}='{}'", key, value.replace('\'', "'\\''"))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_file_sink_reports_failed_writes() {
+        // Every write to /dev/full fails with "no space left on device"
+        let sink = FileSink::create(Path::new("/dev/full")).unwrap();
+        outln!(sink, "first");
+        outln!(sink, "second");
+        assert!(sink.finish().is_err());
+    }
+
     #[test]
     fn test_paginated_output_metadata() {
         let items = vec![1, 2, 3, 4, 5];
-        let output = PaginatedOutput::new(items, 1, 10).with_total(25);
+        let output = PaginatedOutput::new(items, 1, 10, 25);
 
-        assert_eq!(output.total, Some(25));
+        assert_eq!(output.total, 25);
         assert_eq!(output.page, 1);
         assert_eq!(output.per_page, 10);
+        assert_eq!(output.total_pages(), 3);
+
+        let sink = BufferSink::new();
+        output.print_metadata(&sink);
+        assert!(sink.contents().contains("Page 1 of 3 (25 total)"));
+        assert!(sink.contents().contains("Use --page 2"));
     }
 
     #[test]
@@ -169,4 +575,132 @@ mod tests {
         let manager = OutputManager::new(formatter::OutputFormat::Table);
         assert_eq!(manager.format, formatter::OutputFormat::Table);
     }
+
+    #[test]
+    fn test_output_manager_rejects_unsupported_formats() {
+        let sink = BufferSink::new();
+        for format in [
+            formatter::OutputFormat::Csv,
+            formatter::OutputFormat::Plain,
+            formatter::OutputFormat::Count,
+        ] {
+            let manager = OutputManager::new(format);
+            assert!(matches!(
+                manager.output_single(&sink, &1),
+                Err(DevErpError::Validation(_))
+            ));
+            assert!(matches!(
+                manager.output_list(&sink, &[1, 2]),
+                Err(DevErpError::Validation(_))
+            ));
+        }
+        assert!(sink.contents().is_empty());
+
+        OutputManager::new(formatter::OutputFormat::Json)
+            .output_list(&sink, &[1, 2])
+            .unwrap();
+        assert!(sink.contents().contains('2'));
+    }
+
+    #[test]
+    fn test_empty_list_message_distinguishes_no_data_from_no_matches() {
+        let fresh = empty_list_message("tasks", 0, "deverp task create");
+        assert!(fresh.contains("No tasks yet."));
+        assert!(fresh.contains("deverp task create"));
+
+        let filtered = empty_list_message("tasks", 3, "deverp task create");
+        assert!(filtered.contains("match the given filters"));
+        assert!(!filtered.contains("deverp task create"));
+    }
+
+    #[test]
+    fn test_buffer_sink_collects_helper_output() {
+        let buffer = BufferSink::new();
+
+        summary_line(&buffer, "Name", "Billing API");
+        count(&buffer, 3).unwrap();
+        csv(&buffer, &["id"], &[vec!["7".to_string()]]);
+
+        let contents = buffer.contents();
+        assert!(contents.contains("Billing API\n"));
+        assert!(contents.ends_with("3\nid\n7\n"));
+    }
+
+    #[test]
+    fn test_prompt_reasks_until_valid_and_uses_default() {
+        let parse = |s: &str| {
+            s.parse::<u32>()
+                .map_err(|_| format!("'{}' is not a number", s))
+        };
+        let mut input = io::Cursor::new("ten\n\n");
+        let mut output = Vec::new();
+
+        let value = prompt_from(&mut input, &mut output, "Count", Some("3"), parse).unwrap();
+
+        assert_eq!(value, 3);
+        let printed = String::from_utf8(output).unwrap();
+        assert_eq!(printed.matches("Count [3]: ").count(), 2);
+        assert!(printed.contains("'ten' is not a number"));
+
+        // Running out of input is an error rather than an endless loop
+        let mut empty = io::Cursor::new("");
+        let result = prompt_from(&mut empty, &mut Vec::new(), "Count", None, parse);
+        assert!(matches!(result, Err(DevErpError::Validation(_))));
+    }
+
+    #[test]
+    fn test_dry_run_input_lists_only_set_fields() {
+        #[derive(Serialize)]
+        struct Update {
+            id: i64,
+            name: Option<String>,
+            status: Option<String>,
+        }
+        let buffer = BufferSink::new();
+
+        let update = Update {
+            id: 4,
+            name: Some("Renamed".to_string()),
+            status: None,
+        };
+        dry_run_input(&buffer, "update project #4", &update).unwrap();
+
+        let contents = buffer.contents();
+        assert!(contents.contains("would update project #4"));
+        assert!(contents.contains("Renamed"));
+        assert!(!contents.contains("status"));
+    }
+
+    #[test]
+    fn test_dry_run_update_lists_cleared_fields() {
+        #[derive(Serialize)]
+        struct Update {
+            id: i64,
+            description: Option<Option<String>>,
+            due_date: Option<Option<String>>,
+        }
+        let buffer = BufferSink::new();
+
+        let update = Update {
+            id: 5,
+            description: Some(None),
+            due_date: None,
+        };
+        dry_run_update(&buffer, "update task #5", &update, &["description"]).unwrap();
+
+        let contents = buffer.contents();
+        assert!(contents.contains("would update task #5"));
+        assert!(contents.contains("description"));
+        assert!(contents.contains("(cleared)"));
+        assert!(!contents.contains("due_date"));
+    }
+
+    #[test]
+    fn test_kv_line_quotes_unsafe_values() {
+        assert_eq!(kv_line("id", "42"), "id=42");
+        assert_eq!(kv_line("end_date", "2025-06-30"), "end_date=2025-06-30");
+        assert_eq!(kv_line("code", ""), "code=");
+        assert_eq!(kv_line("name", "Billing API"), "name='Billing API'");
+        assert_eq!(kv_line("name", "Bob's app"), "name='Bob'\\''s app'");
+    }
 }
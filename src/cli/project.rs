@@ -1,150 +1,352 @@
 // Project CLI commands implementation
 
-use chrono::NaiveDate;
+use chrono::{Local, NaiveDate};
 use colored::Colorize;
+use std::io::{BufRead, Write};
+use std::path::Path;
 use std::sync::Arc;
 use uuid::Uuid;
 
 use super::commands::{
-    ArchiveProjectArgs, CreateProjectArgs, DeleteProjectArgs, ListProjectArgs, OutputFormat,
-    ProjectCommand, ShowProjectArgs, UpdateProjectArgs,
+    ArchiveProjectArgs, CloneProjectArgs, CreateProjectArgs, DeleteProjectArgs, DeriveDatesArgs,
+    ListProjectArgs, OutputFormat, ProjectCommand, ProjectHealthArgs, RestoreProjectArgs,
+    ScanOverdueArgs, ShowProjectArgs, SortOrder, UpdateProjectArgs,
 };
-use super::output::{confirm, empty_state, section_title, summary_line, PaginatedOutput};
-use crate::config::settings::Settings;
+use super::config::list_preferences;
+use super::context::AppContext;
+use super::output::{
+    self, confirm, empty_list, empty_state, kv_line, out, outln, output_structured, progress_bar,
+    prompt_from, section_title, summary_line, OutputSink, PaginatedOutput,
+};
+use super::template::{parse_start_date, print_applied_template};
 use crate::domain::project::{
-    entity::{CreateProject, Priority, ProjectFilter, ProjectStatus, UpdateProject},
+    entity::{
+        CloneProjectOptions, CreateProject, NullsOrder, OverdueAction, Priority, Project,
+        ProjectDetail, ProjectFilter, ProjectSortField, ProjectStatus, TagMatchMode, UpdateProject,
+    },
     service::ProjectService,
 };
-use crate::infrastructure::{database, repositories::project_repo::PostgresProjectRepository};
+use crate::domain::report::{HealthCategory, ProjectHealth, ReportService};
+use crate::domain::resource::{entity::ProjectResourceDetail, service::ResourceService};
+use crate::domain::template::TemplateService;
+use crate::infrastructure::repositories::{
+    project_repo::PostgresProjectRepository,
+    resource_repo::{PostgresResourceDependencyRepository, PostgresResourceRepository},
+    task_repo::PostgresTaskRepository,
+    template_repo::PostgresTemplateRepository,
+    timeline_repo::{PostgresMilestoneRepository, PostgresTimelineRepository},
+};
 use crate::utils::error::DevErpError;
-use crate::utils::formatter;
+use crate::utils::retry::retry_transient;
 use crate::Result;
 
+/// Width of the schedule bars in `project show`
+const SPARKLINE_WIDTH: usize = 20;
+
+/// Allowed gap (in percentage points) before a project counts as behind or ahead
+const SCHEDULE_TOLERANCE: f64 = 5.0;
+
 /// Handle project commands
-pub async fn handle(command: ProjectCommand, format: OutputFormat) -> Result<()> {
+pub async fn handle(ctx: &AppContext, command: ProjectCommand, format: OutputFormat) -> Result<()> {
     match command {
-        ProjectCommand::Create(args) => handle_create(args, format).await,
-        ProjectCommand::List(args) => handle_list(args, format).await,
-        ProjectCommand::Show(args) => handle_show(args, format).await,
-        ProjectCommand::Update(args) => handle_update(args, format).await,
-        ProjectCommand::Delete(args) => handle_delete(args, format).await,
-        ProjectCommand::Archive(args) => handle_archive(args, format).await,
+        ProjectCommand::Create(args) => handle_create(ctx, args, format).await,
+        ProjectCommand::List(args) => handle_list(ctx, args, format).await,
+        ProjectCommand::Show(args) => handle_show(ctx, args, format).await,
+        ProjectCommand::Update(args) => handle_update(ctx, args, format).await,
+        ProjectCommand::Delete(args) => handle_delete(ctx, args, format).await,
+        ProjectCommand::Restore(args) => handle_restore(ctx, args).await,
+        ProjectCommand::Archive(args) => handle_archive(ctx, args, format).await,
+        ProjectCommand::DeriveDates(args) => handle_derive_dates(ctx, args, format).await,
+        ProjectCommand::Clone(args) => handle_clone(ctx, args, format).await,
+        ProjectCommand::ScanOverdue(args) => handle_scan_overdue(ctx, args, format).await,
+        ProjectCommand::Health(args) => handle_health(ctx, args, format).await,
     }
 }
 
-/// Create database connection and project service
-async fn create_service() -> Result<ProjectService> {
-    let settings = Settings::default();
-    let pool = database::establish_connection(&settings.database).await?;
-    let repository = Arc::new(PostgresProjectRepository::new(pool));
-    Ok(ProjectService::new(repository))
+/// Create the project service on the shared pool
+///
+/// Loads the configured holidays so working-day durations skip them.
+async fn create_service(ctx: &AppContext) -> Result<ProjectService> {
+    let pool = ctx.pool();
+    let repository = Arc::new(PostgresProjectRepository::new(pool.clone()));
+    let timeline_repository = Arc::new(PostgresTimelineRepository::new(pool.clone()));
+    let task_repository = Arc::new(PostgresTaskRepository::new(pool.clone()));
+    let template_repository = Arc::new(PostgresTemplateRepository::new(pool));
+    let holidays = ctx.config_service().calendar_holidays().await?;
+    Ok(ProjectService::new(
+        repository,
+        timeline_repository,
+        task_repository,
+        template_repository,
+    )
+    .with_holidays(holidays))
 }
 
 /// Handle project create command
-async fn handle_create(args: CreateProjectArgs, _format: OutputFormat) -> Result<()> {
-    let service = create_service().await?;
-
-    // Parse status if provided
-    let status = if let Some(status_str) = args.status {
-        Some(
-            status_str
-                .parse::<ProjectStatus>()
-                .map_err(DevErpError::Validation)?,
-        )
-    } else {
-        None
-    };
-
-    // Parse priority if provided
-    let priority = if let Some(priority_str) = args.priority {
-        Some(
-            priority_str
-                .parse::<Priority>()
-                .map_err(DevErpError::Validation)?,
-        )
-    } else {
-        None
-    };
+async fn handle_create(
+    ctx: &AppContext,
+    args: CreateProjectArgs,
+    format: OutputFormat,
+) -> Result<()> {
+    let out = ctx.out();
+    if args.from_template.is_some() {
+        return handle_create_from_template(ctx, args, format).await;
+    }
 
-    // Parse dates if provided
-    let start_date = if let Some(date_str) = args.start_date {
-        Some(
-            NaiveDate::parse_from_str(&date_str, "%Y-%m-%d").map_err(|_| {
-                DevErpError::Validation(format!(
-                    "Invalid start date format: {}. Expected YYYY-MM-DD",
-                    date_str
-                ))
-            })?,
-        )
-    } else {
-        None
-    };
+    let service = create_service(ctx).await?;
 
-    let end_date = if let Some(date_str) = args.end_date {
-        Some(
-            NaiveDate::parse_from_str(&date_str, "%Y-%m-%d").map_err(|_| {
-                DevErpError::Validation(format!(
-                    "Invalid end date format: {}. Expected YYYY-MM-DD",
-                    date_str
-                ))
-            })?,
-        )
+    let input = if args.interactive {
+        prompt_create_project(&mut std::io::stdin().lock(), &mut std::io::stdout(), &args)?
     } else {
-        None
-    };
-
-    // Parse tags if provided
-    let tags = args.tags.map(|tags_str| {
-        tags_str
-            .split(',')
-            .map(|s| s.trim().to_string())
-            .filter(|s| !s.is_empty())
-            .collect()
-    });
-
-    // Create project input
-    let input = CreateProject {
-        name: args.name,
-        description: args.description,
-        code: args.code,
-        status,
-        priority,
-        start_date,
-        end_date,
-        repository_url: args.repository_url,
-        repository_branch: args.repository_branch,
-        tags,
-        metadata: None,
+        // Parse status if provided
+        let status = if let Some(status_str) = args.status {
+            Some(
+                status_str
+                    .parse::<ProjectStatus>()
+                    .map_err(DevErpError::Validation)?,
+            )
+        } else {
+            None
+        };
+
+        // Parse priority if provided
+        let priority = if let Some(priority_str) = args.priority {
+            Some(
+                priority_str
+                    .parse::<Priority>()
+                    .map_err(DevErpError::Validation)?,
+            )
+        } else {
+            None
+        };
+
+        // Parse dates if provided
+        let start_date = if let Some(date_str) = args.start_date {
+            Some(
+                NaiveDate::parse_from_str(&date_str, "%Y-%m-%d").map_err(|_| {
+                    DevErpError::Validation(format!(
+                        "Invalid start date format: {}. Expected YYYY-MM-DD",
+                        date_str
+                    ))
+                })?,
+            )
+        } else {
+            None
+        };
+
+        let end_date = if let Some(date_str) = args.end_date {
+            Some(
+                NaiveDate::parse_from_str(&date_str, "%Y-%m-%d").map_err(|_| {
+                    DevErpError::Validation(format!(
+                        "Invalid end date format: {}. Expected YYYY-MM-DD",
+                        date_str
+                    ))
+                })?,
+            )
+        } else {
+            None
+        };
+
+        // Parse tags if provided
+        let tags = args.tags.as_deref().map(CreateProject::parse_tags);
+
+        // Create project input
+        CreateProject {
+            name: args.name.unwrap_or_default(),
+            description: args.description,
+            code: args.code,
+            status,
+            priority,
+            start_date,
+            end_date,
+            budget: args.budget,
+            currency: args.currency.map(|c| c.to_uppercase()),
+            repository_url: args.repository_url,
+            repository_branch: args.repository_branch,
+            tags,
+            metadata: None,
+        }
     };
 
     // Validate input
     input.validate().map_err(DevErpError::Validation)?;
 
+    if ctx.dry_run() {
+        return output::dry_run_input(out, "create this project", &input);
+    }
+
     // Create project
-    let project = service.create_project(input).await?;
+    let project = retry_transient(args.retry.attempts, || {
+        service.create_project(input.clone())
+    })
+    .await?;
 
     // Display success message
-    println!("{} Project created successfully!", "✓".green().bold());
-    println!();
-    summary_line("ID", &project.id.to_string());
-    summary_line("UUID", &project.uuid.to_string());
-    summary_line("Name", &project.name);
+    outln!(out, "{} Project created successfully!", "✓".green().bold());
+    outln!(out);
+    summary_line(out, "ID", &project.id.to_string());
+    summary_line(out, "UUID", &project.uuid.to_string());
+    summary_line(out, "Name", &project.name);
     if let Some(ref desc) = project.description {
-        summary_line("Description", desc);
+        summary_line(out, "Description", desc);
     }
     if let Some(ref code) = project.code {
-        summary_line("Code", code);
+        summary_line(out, "Code", code);
+    }
+    summary_line(out, "Status", &project.status.to_string());
+    summary_line(out, "Priority", &project.priority.to_string());
+    outln!(out);
+
+    Ok(())
+}
+
+/// Build a new project's input by prompting for each field in turn
+///
+/// Flags given alongside `--interactive` are offered as defaults, so enter
+/// keeps them; status and priority otherwise default to planning and medium.
+/// Each answer is checked before moving on. Repository and tag flags are
+/// used as given without a prompt.
+fn prompt_create_project(
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+    args: &CreateProjectArgs,
+) -> Result<CreateProject> {
+    let optional = |answer: &str| Ok((!answer.is_empty()).then(|| answer.to_string()));
+    let date = |answer: &str| {
+        if answer.is_empty() {
+            return Ok(None);
+        }
+        NaiveDate::parse_from_str(answer, "%Y-%m-%d")
+            .map(Some)
+            .map_err(|_| format!("Invalid date: {}. Expected YYYY-MM-DD", answer))
+    };
+
+    let name = prompt_from(input, output, "Name", args.name.as_deref(), |answer| {
+        CreateProject::validate_name(answer).map(|()| answer.to_string())
+    })?;
+    let description = prompt_from(
+        input,
+        output,
+        "Description",
+        args.description.as_deref(),
+        optional,
+    )?;
+    let code = prompt_from(input, output, "Code", args.code.as_deref(), |answer| {
+        if !answer.is_empty() {
+            CreateProject::validate_code(answer)?;
+        }
+        optional(answer)
+    })?;
+    let status = prompt_from(
+        input,
+        output,
+        "Status",
+        Some(args.status.as_deref().unwrap_or("planning")),
+        |answer| answer.parse::<ProjectStatus>(),
+    )?;
+    let priority = prompt_from(
+        input,
+        output,
+        "Priority",
+        Some(args.priority.as_deref().unwrap_or("medium")),
+        |answer| answer.parse::<Priority>(),
+    )?;
+    let start_date = prompt_from(
+        input,
+        output,
+        "Start date (YYYY-MM-DD)",
+        args.start_date.as_deref(),
+        date,
+    )?;
+    let end_date = prompt_from(
+        input,
+        output,
+        "End date (YYYY-MM-DD)",
+        args.end_date.as_deref(),
+        |answer| {
+            let end = date(answer)?;
+            CreateProject::validate_dates(start_date, end).map(|()| end)
+        },
+    )?;
+
+    Ok(CreateProject {
+        name,
+        description,
+        code,
+        status: Some(status),
+        priority: Some(priority),
+        start_date,
+        end_date,
+        budget: args.budget,
+        currency: args.currency.as_ref().map(|c| c.to_uppercase()),
+        repository_url: args.repository_url.clone(),
+        repository_branch: args.repository_branch.clone(),
+        tags: args.tags.as_deref().map(CreateProject::parse_tags),
+        metadata: None,
+    })
+}
+
+/// Handle project create --from-template
+async fn handle_create_from_template(
+    ctx: &AppContext,
+    args: CreateProjectArgs,
+    format: OutputFormat,
+) -> Result<()> {
+    let out = ctx.out();
+    let path = args.from_template.unwrap_or_default();
+    let start = parse_start_date(args.start)?;
+
+    let pool = ctx.pool();
+    let service = TemplateService::new(Arc::new(PostgresTemplateRepository::new(pool)));
+
+    // Validate the template before touching the database
+    let template = service.load_template(Path::new(&path))?;
+
+    if ctx.dry_run() {
+        output::dry_run(
+            out,
+            &format!(
+                "create a project from template '{}' starting {}",
+                path, start
+            ),
+        );
+        return Ok(());
     }
-    summary_line("Status", &project.status.to_string());
-    summary_line("Priority", &project.priority.to_string());
-    println!();
+
+    let applied = retry_transient(args.retry.attempts, || {
+        service.apply_template(&template, start, args.name.clone(), args.code.clone())
+    })
+    .await?;
+
+    if format.is_structured() {
+        return output_structured(out, format, &applied);
+    }
+
+    print_applied_template(out, &applied, &format!("template {}", path), start);
 
     Ok(())
 }
 
+/// Parse an optional YYYY-MM-DD date passed to a list filter flag
+fn parse_filter_date(flag: &str, value: Option<String>) -> Result<Option<NaiveDate>> {
+    value
+        .map(|date_str| {
+            NaiveDate::parse_from_str(&date_str, "%Y-%m-%d").map_err(|_| {
+                DevErpError::Validation(format!(
+                    "Invalid {} date: {}. Expected YYYY-MM-DD",
+                    flag, date_str
+                ))
+            })
+        })
+        .transpose()
+}
+
 /// Handle project list command
-async fn handle_list(args: ListProjectArgs, format: OutputFormat) -> Result<()> {
-    let service = create_service().await?;
+async fn handle_list(ctx: &AppContext, args: ListProjectArgs, format: OutputFormat) -> Result<()> {
+    let out = ctx.out();
+    let service = create_service(ctx).await?;
+    let preferences = list_preferences(ctx).await?;
+    let pagination = args.pagination.or_per_page(preferences.per_page);
 
     // Parse status filter if provided
     let status = if let Some(status_str) = args.status {
@@ -169,13 +371,34 @@ async fn handle_list(args: ListProjectArgs, format: OutputFormat) -> Result<()>
     };
 
     // Parse tags filter if provided
-    let tags = args.tags.map(|tags_str| {
-        tags_str
-            .split(',')
-            .map(|s| s.trim().to_string())
-            .filter(|s| !s.is_empty())
-            .collect()
-    });
+    let tags = args.tags.as_deref().map(CreateProject::parse_tags);
+
+    // Parse date range filters if provided
+    let start_date_from = parse_filter_date("--start-after", args.start_after)?;
+    let start_date_to = parse_filter_date("--start-before", args.start_before)?;
+    let end_date_from = parse_filter_date("--end-after", args.end_after)?;
+    let end_date_to = parse_filter_date("--end-before", args.end_before)?;
+
+    // Parse sort options if provided, falling back to the stored preference
+    let sort_by = if let Some(sort_str) = args.sort {
+        Some(
+            sort_str
+                .parse::<ProjectSortField>()
+                .map_err(DevErpError::Validation)?,
+        )
+    } else {
+        preferences.order_by
+    };
+
+    let nulls = if let Some(nulls_str) = args.nulls {
+        Some(
+            nulls_str
+                .parse::<NullsOrder>()
+                .map_err(DevErpError::Validation)?,
+        )
+    } else {
+        None
+    };
 
     // Build filter
     let filter = ProjectFilter {
@@ -183,45 +406,89 @@ async fn handle_list(args: ListProjectArgs, format: OutputFormat) -> Result<()>
         priority,
         search: args.search,
         tags,
-        offset: Some(args.pagination.offset()),
-        limit: Some(args.pagination.limit()),
+        tag_match_mode: if args.tags_all {
+            TagMatchMode::All
+        } else {
+            TagMatchMode::Any
+        },
+        start_date_from,
+        start_date_to,
+        end_date_from,
+        end_date_to,
+        include_deleted: false,
+        offset: Some(pagination.offset()),
+        limit: Some(pagination.limit()),
+        sort_by,
+        sort_desc: args.desc || args.order == Some(SortOrder::Desc),
+        nulls,
+    };
+
+    if format == OutputFormat::Count {
+        return output::count(out, service.count_projects(filter).await?);
+    }
+
+    // Counts cover every matching project, not just this page
+    let status_counts = if args.include_counts {
+        Some(service.count_projects_by_status(filter.clone()).await?)
+    } else {
+        None
     };
 
     // Get projects
-    let projects = service.list_projects(filter).await?;
+    let projects = service.list_projects(filter.clone()).await?;
+
+    // Counts for the whole page come from a single grouped query
+    let counted = if args.with_task_counts {
+        Some(service.with_task_counts(projects.clone()).await?)
+    } else {
+        None
+    };
 
     // Display results based on format
-    match format {
-        OutputFormat::Json => {
-            return formatter::output_json(&projects);
-        }
-        _ => {
-            // Table/Plain format
-            if projects.is_empty() {
-                empty_state("projects");
-                return Ok(());
-            }
-        }
+    if format.is_structured() {
+        return match counted {
+            Some(ref counted) => output_structured(out, format, counted),
+            None => output_structured(out, format, &projects),
+        };
+    }
+
+    // Table/Plain format
+    if projects.is_empty() {
+        let total = service.count_projects(ProjectFilter::default()).await?;
+        empty_list(
+            out,
+            "projects",
+            total,
+            "deverp project create --name <NAME>",
+        );
+        return Ok(());
+    }
+
+    if let Some(ref counts) = status_counts {
+        outln!(out, "{}", status_counts_header(counts));
+        outln!(out);
     }
 
-    section_title(&format!("Projects ({} found)", projects.len()));
-    println!();
+    section_title(out, &format!("Projects ({} found)", projects.len()));
+    outln!(out);
 
     for project in &projects {
-        println!(
+        outln!(
+            out,
             "  {} {} - {}",
             "•".cyan(),
             project.name.bold(),
             project.status.to_string().dimmed()
         );
-        println!(
+        outln!(
+            out,
             "    ID: {} | UUID: {}",
             project.id.to_string().yellow(),
             project.uuid.to_string().dimmed()
         );
 
         if let Some(ref code) = project.code {
-            println!("    Code: {}", code.cyan());
+            outln!(out, "    Code: {}", code.cyan());
         }
 
         if let Some(ref desc) = project.description {
@@ -230,26 +497,42 @@ async fn handle_list(args: ListProjectArgs, format: OutputFormat) -> Result<()>
             } else {
                 desc.clone()
             };
-            println!("    {}", short_desc.dimmed());
+            outln!(out, "    {}", short_desc.dimmed());
         }
 
-        println!(
+        outln!(
+            out,
             "    Priority: {} | Progress: {}%",
             project.priority.to_string().cyan(),
             project.progress_percentage.unwrap_or(0)
         );
 
+        if let Some(counts) = counted
+            .iter()
+            .flatten()
+            .find(|c| c.project.id == project.id)
+        {
+            outln!(
+                out,
+                "    Tasks: {}/{} completed",
+                counts.completed_tasks,
+                counts.total_tasks
+            );
+        }
+
         // Display dates if available
         if let Some(start_date) = project.start_date {
-            print!("    ");
+            out!(out, "    ");
             if let Some(end_date) = project.end_date {
-                println!(
+                outln!(
+                    out,
                     "Period: {} → {}",
                     start_date.format("%Y-%m-%d").to_string().green(),
                     end_date.format("%Y-%m-%d").to_string().green()
                 );
             } else {
-                println!(
+                outln!(
+                    out,
                     "Start: {}",
                     start_date.format("%Y-%m-%d").to_string().green()
                 );
@@ -258,34 +541,44 @@ async fn handle_list(args: ListProjectArgs, format: OutputFormat) -> Result<()>
 
         // Display actual dates if available
         if project.actual_start_date.is_some() || project.actual_end_date.is_some() {
-            print!("    Actual: ");
+            out!(out, "    Actual: ");
             if let Some(actual_start) = project.actual_start_date {
-                print!("{}", actual_start.format("%Y-%m-%d").to_string().yellow());
+                out!(
+                    out,
+                    "{}",
+                    actual_start.format("%Y-%m-%d").to_string().yellow()
+                );
                 if let Some(actual_end) = project.actual_end_date {
-                    print!(" → {}", actual_end.format("%Y-%m-%d").to_string().yellow());
+                    out!(
+                        out,
+                        " → {}",
+                        actual_end.format("%Y-%m-%d").to_string().yellow()
+                    );
                 }
             } else if let Some(actual_end) = project.actual_end_date {
-                print!(
+                out!(
+                    out,
                     "End: {}",
                     actual_end.format("%Y-%m-%d").to_string().yellow()
                 );
             }
-            println!();
+            outln!(out);
         }
 
         // Display repository info if available
         if let Some(ref repo_url) = project.repository_url {
-            print!("    Repository: {}", repo_url.blue());
+            out!(out, "    Repository: {}", repo_url.blue());
             if let Some(ref branch) = project.repository_branch {
-                print!(" ({})", branch.cyan());
+                out!(out, " ({})", branch.cyan());
             }
-            println!();
+            outln!(out);
         }
 
         // Display tags if available
         if let Some(ref tags) = project.tags {
             if !tags.is_empty() {
-                println!(
+                outln!(
+                    out,
                     "    Tags: {}",
                     tags.iter()
                         .map(|t| format!("#{}", t))
@@ -296,19 +589,21 @@ async fn handle_list(args: ListProjectArgs, format: OutputFormat) -> Result<()>
             }
         }
 
-        println!();
+        outln!(out);
     }
 
     // Show pagination info
-    let output = PaginatedOutput::new(projects, args.pagination.page, args.pagination.per_page);
-    output.print_metadata();
+    let total = service.count_projects(filter).await?;
+    let output = PaginatedOutput::new(projects, pagination.page, pagination.per_page(), total);
+    output.print_metadata(out);
 
     Ok(())
 }
 
 /// Handle project show command
-async fn handle_show(args: ShowProjectArgs, _format: OutputFormat) -> Result<()> {
-    let service = create_service().await?;
+async fn handle_show(ctx: &AppContext, args: ShowProjectArgs, format: OutputFormat) -> Result<()> {
+    let out = ctx.out();
+    let service = create_service(ctx).await?;
 
     // Try to parse as UUID first, then as ID
     let project = if let Ok(uuid) = Uuid::parse_str(&args.identifier) {
@@ -321,76 +616,265 @@ async fn handle_show(args: ShowProjectArgs, _format: OutputFormat) -> Result<()>
         ));
     };
 
+    let details = if args.with_resources {
+        let pool = ctx.pool();
+        let resource_service = ResourceService::new(
+            Arc::new(PostgresResourceRepository::new(pool.clone())),
+            Arc::new(PostgresResourceDependencyRepository::new(pool)),
+        );
+        Some(
+            resource_service
+                .get_project_resource_details(project.id)
+                .await?,
+        )
+    } else {
+        None
+    };
+
+    if format.is_structured() && !args.kv {
+        return match details {
+            Some(resources) => {
+                output_structured(out, format, &ProjectDetail { project, resources })
+            }
+            None => output_structured(out, format, &project),
+        };
+    }
+
+    if args.kv {
+        for line in project_kv_lines(&project) {
+            outln!(out, "{}", line);
+        }
+        return Ok(());
+    }
+
     // Display project details
-    section_title(&format!("Project: {}", project.name));
-    println!();
+    section_title(out, &format!("Project: {}", project.name));
+    outln!(out);
 
-    summary_line("ID", &project.id.to_string());
-    summary_line("UUID", &project.uuid.to_string());
-    summary_line("Name", &project.name);
+    summary_line(out, "ID", &project.id.to_string());
+    summary_line(out, "UUID", &project.uuid.to_string());
+    summary_line(out, "Name", &project.name);
 
     if let Some(ref desc) = project.description {
-        summary_line("Description", desc);
+        summary_line(out, "Description", desc);
     }
 
     if let Some(ref code) = project.code {
-        summary_line("Code", code);
+        summary_line(out, "Code", code);
     }
 
-    summary_line("Status", &project.status.to_string());
-    summary_line("Priority", &project.priority.to_string());
+    summary_line(out, "Status", &project.status.to_string());
+    summary_line(out, "Priority", &project.priority.to_string());
     summary_line(
+        out,
         "Progress",
         &format!("{}%", project.progress_percentage.unwrap_or(0)),
     );
+    if let Some(lines) = schedule_sparkline(&project, Local::now().date_naive()) {
+        for line in lines {
+            outln!(out, "    {}", line);
+        }
+    }
 
     if let Some(start_date) = project.start_date {
-        summary_line("Start Date", &start_date.to_string());
+        summary_line(out, "Start Date", &start_date.to_string());
     }
 
     if let Some(end_date) = project.end_date {
-        summary_line("End Date", &end_date.to_string());
+        summary_line(out, "End Date", &end_date.to_string());
+    }
+
+    let rate = ctx.config_service().hourly_rate().await?;
+    let stats = service
+        .with_hourly_rate(rate)
+        .get_project_stats(project.id)
+        .await?;
+    if let Some(days) = stats.working_duration_days {
+        summary_line(out, "Working Days", &days.to_string());
     }
 
     if let Some(actual_start) = project.actual_start_date {
-        summary_line("Actual Start", &actual_start.to_string());
+        summary_line(out, "Actual Start", &actual_start.to_string());
     }
 
     if let Some(actual_end) = project.actual_end_date {
-        summary_line("Actual End", &actual_end.to_string());
+        summary_line(out, "Actual End", &actual_end.to_string());
+    }
+
+    if let Some(budget) = project.budget {
+        summary_line(
+            out,
+            "Budget",
+            &format_amount(budget, project.currency.as_deref()),
+        );
+    }
+    if let Some(cost) = stats.estimated_cost {
+        let mut line = format_amount(cost, project.currency.as_deref());
+        if let Some(pct) = stats.budget_utilization_pct {
+            line.push_str(&format!(" ({:.1}% of budget)", pct));
+        }
+        summary_line(out, "Cost to Date", &line);
     }
 
     if let Some(ref repo_url) = project.repository_url {
-        summary_line("Repository", repo_url);
+        summary_line(out, "Repository", repo_url);
         if let Some(ref branch) = project.repository_branch {
-            summary_line("Branch", branch);
+            summary_line(out, "Branch", branch);
         }
     }
 
     if let Some(ref tags) = project.tags {
         if !tags.is_empty() {
-            summary_line("Tags", &tags.join(", "));
+            summary_line(out, "Tags", &tags.join(", "));
         }
     }
 
-    println!();
+    outln!(out);
     summary_line(
+        out,
         "Created",
         &project.created_at.format("%Y-%m-%d %H:%M:%S").to_string(),
     );
     summary_line(
+        out,
         "Updated",
         &project.updated_at.format("%Y-%m-%d %H:%M:%S").to_string(),
     );
-
-    println!();
+    summary_line(out, "Version", &project.version.to_string());
+
+    outln!(out);
+
+    if let Some(details) = details {
+        section_title(out, &format!("Resources ({})", details.len()));
+        outln!(out);
+        if details.is_empty() {
+            empty_state(out, "linked resources");
+        } else {
+            output::table_header(out, &["ID", "Resource", "Type", "Version Used", "Critical"]);
+            for row in project_resource_rows(&details) {
+                output::table_row(out, &row);
+            }
+        }
+        outln!(out);
+    }
 
     Ok(())
 }
 
+/// Amount to two decimals, followed by the currency code when there is one
+fn format_amount(amount: f64, currency: Option<&str>) -> String {
+    match currency {
+        Some(currency) => format!("{:.2} {}", amount, currency),
+        None => format!("{:.2}", amount),
+    }
+}
+
+/// Table rows for a project's linked resources
+///
+/// Falls back to the resource's own version when the link doesn't pin one.
+fn project_resource_rows(details: &[ProjectResourceDetail]) -> Vec<Vec<String>> {
+    details
+        .iter()
+        .map(|detail| {
+            let version = detail
+                .link
+                .version_used
+                .as_ref()
+                .or(detail.resource.version.as_ref())
+                .cloned()
+                .unwrap_or_else(|| "-".to_string());
+
+            vec![
+                detail.resource.id.to_string(),
+                detail.resource.name.clone(),
+                detail.resource.resource_type.to_string(),
+                version,
+                if detail.link.is_critical.unwrap_or(false) {
+                    "yes".to_string()
+                } else {
+                    "no".to_string()
+                },
+            ]
+        })
+        .collect()
+}
+
+/// Expected vs. actual progress bars, stacked, with a schedule verdict
+///
+/// Returns `None` when the planned dates don't allow an expectation.
+fn schedule_sparkline(project: &Project, today: NaiveDate) -> Option<Vec<String>> {
+    let expected = project.expected_progress(today)?;
+    let actual = f64::from(project.progress_percentage.unwrap_or(0));
+
+    let gap = actual - expected;
+    let verdict = if gap < -SCHEDULE_TOLERANCE {
+        format!("behind by {:.0} pts", -gap)
+    } else if gap > SCHEDULE_TOLERANCE {
+        format!("ahead by {:.0} pts", gap)
+    } else {
+        "on schedule".to_string()
+    };
+
+    Some(vec![
+        format!(
+            "expected {} {:>3.0}%",
+            progress_bar(expected, SPARKLINE_WIDTH),
+            expected
+        ),
+        format!(
+            "actual   {} {:>3.0}%  {}",
+            progress_bar(actual, SPARKLINE_WIDTH),
+            actual,
+            verdict
+        ),
+    ])
+}
+
+/// Render a project as stable `key=value` lines
+///
+/// Every key is always present (empty when unset) so scripts can rely on the
+/// variable being defined after `source`.
+fn project_kv_lines(project: &Project) -> Vec<String> {
+    let date = |d: Option<NaiveDate>| d.map(|d| d.to_string()).unwrap_or_default();
+
+    vec![
+        kv_line("id", &project.id.to_string()),
+        kv_line("uuid", &project.uuid.to_string()),
+        kv_line("name", &project.name),
+        kv_line("description", project.description.as_deref().unwrap_or("")),
+        kv_line("code", project.code.as_deref().unwrap_or("")),
+        kv_line("status", &project.status.to_string()),
+        kv_line("priority", &project.priority.to_string()),
+        kv_line(
+            "progress",
+            &project.progress_percentage.unwrap_or(0).to_string(),
+        ),
+        kv_line("start_date", &date(project.start_date)),
+        kv_line("end_date", &date(project.end_date)),
+        kv_line("actual_start_date", &date(project.actual_start_date)),
+        kv_line("actual_end_date", &date(project.actual_end_date)),
+        kv_line(
+            "repository_url",
+            project.repository_url.as_deref().unwrap_or(""),
+        ),
+        kv_line(
+            "repository_branch",
+            project.repository_branch.as_deref().unwrap_or(""),
+        ),
+        kv_line("tags", &project.tags.clone().unwrap_or_default().join(",")),
+        kv_line("created_at", &project.created_at.to_rfc3339()),
+        kv_line("updated_at", &project.updated_at.to_rfc3339()),
+    ]
+}
+
 /// Handle project update command
-async fn handle_update(args: UpdateProjectArgs, _format: OutputFormat) -> Result<()> {
-    let service = create_service().await?;
+async fn handle_update(
+    ctx: &AppContext,
+    args: UpdateProjectArgs,
+    _format: OutputFormat,
+) -> Result<()> {
+    let out = ctx.out();
+    let service = create_service(ctx).await?;
 
     // Get the project ID
     let id = if let Ok(uuid) = Uuid::parse_str(&args.identifier) {
@@ -480,19 +964,17 @@ async fn handle_update(args: UpdateProjectArgs, _format: OutputFormat) -> Result
     };
 
     // Parse tags if provided
-    let tags = args.tags.map(|tags_str| {
-        tags_str
-            .split(',')
-            .map(|s| s.trim().to_string())
-            .filter(|s| !s.is_empty())
-            .collect()
-    });
+    let tags = args.tags.as_deref().map(CreateProject::parse_tags);
 
     // Create update input
     let input = UpdateProject {
         id,
         name: args.name,
-        description: args.description,
+        description: if args.clear_description {
+            Some(None)
+        } else {
+            args.description.map(Some)
+        },
         code: args.code,
         status,
         priority,
@@ -501,37 +983,58 @@ async fn handle_update(args: UpdateProjectArgs, _format: OutputFormat) -> Result
         actual_start_date,
         actual_end_date,
         progress_percentage: args.progress,
+        budget: args.budget,
+        currency: args.currency.map(|c| c.to_uppercase()),
         repository_url: args.repository_url,
         repository_branch: args.repository_branch,
         tags,
         metadata: None,
+        expected_version: args.expected_version,
     };
 
     // Validate input
     input.validate().map_err(DevErpError::Validation)?;
 
+    if ctx.dry_run() {
+        let cleared: &[&str] = if args.clear_description {
+            &["description"]
+        } else {
+            &[]
+        };
+        return output::dry_run_update(out, &format!("update project #{}", id), &input, cleared);
+    }
+
     // Update project
-    let project = service.update_project(input).await?;
+    let project = retry_transient(args.retry.attempts, || {
+        service.update_project(input.clone())
+    })
+    .await?;
 
     // Display success message
-    println!("{} Project updated successfully!", "✓".green().bold());
-    println!();
-    summary_line("ID", &project.id.to_string());
-    summary_line("Name", &project.name);
-    summary_line("Status", &project.status.to_string());
-    summary_line("Priority", &project.priority.to_string());
+    outln!(out, "{} Project updated successfully!", "✓".green().bold());
+    outln!(out);
+    summary_line(out, "ID", &project.id.to_string());
+    summary_line(out, "Name", &project.name);
+    summary_line(out, "Status", &project.status.to_string());
+    summary_line(out, "Priority", &project.priority.to_string());
     summary_line(
+        out,
         "Progress",
         &format!("{}%", project.progress_percentage.unwrap_or(0)),
     );
-    println!();
+    outln!(out);
 
     Ok(())
 }
 
 /// Handle project delete command
-async fn handle_delete(args: DeleteProjectArgs, _format: OutputFormat) -> Result<()> {
-    let service = create_service().await?;
+async fn handle_delete(
+    ctx: &AppContext,
+    args: DeleteProjectArgs,
+    _format: OutputFormat,
+) -> Result<()> {
+    let out = ctx.out();
+    let service = create_service(ctx).await?;
 
     // Get the project
     let project = if let Ok(uuid) = Uuid::parse_str(&args.identifier) {
@@ -544,23 +1047,43 @@ async fn handle_delete(args: DeleteProjectArgs, _format: OutputFormat) -> Result
         ));
     };
 
+    let scope = if args.cascade {
+        " and all of its tasks, timelines and milestones"
+    } else {
+        ""
+    };
+
+    if ctx.dry_run() {
+        output::dry_run(out, &format!("delete project '{}'{}", project.name, scope));
+        print_project_target(out, &project);
+        return Ok(());
+    }
+
     // Confirm deletion
     if !args.confirm {
         let confirmed = confirm(&format!(
-            "Are you sure you want to delete project '{}'? This action cannot be undone.",
-            project.name
+            "Are you sure you want to delete project '{}'{}? This action cannot be undone.",
+            project.name, scope
         ));
 
         if !confirmed {
-            println!("Deletion cancelled.");
+            outln!(out, "Deletion cancelled.");
             return Ok(());
         }
     }
 
     // Delete project
-    service.delete_project(project.id).await?;
+    if args.cascade {
+        retry_transient(args.retry.attempts, || {
+            service.delete_project_cascade(project.id)
+        })
+        .await?;
+    } else {
+        retry_transient(args.retry.attempts, || service.delete_project(project.id)).await?;
+    }
 
-    println!(
+    outln!(
+        out,
         "{} Project '{}' deleted successfully.",
         "✓".green().bold(),
         project.name
@@ -569,9 +1092,50 @@ async fn handle_delete(args: DeleteProjectArgs, _format: OutputFormat) -> Result
     Ok(())
 }
 
+/// Identify the project a dry-run delete or archive would touch
+fn print_project_target(out: &dyn OutputSink, project: &Project) {
+    summary_line(out, "ID", &project.id.to_string());
+    summary_line(out, "Name", &project.name);
+    summary_line(out, "Status", &project.status.to_string());
+    outln!(out);
+}
+
+/// Handle project restore command
+async fn handle_restore(ctx: &AppContext, args: RestoreProjectArgs) -> Result<()> {
+    let out = ctx.out();
+    let service = create_service(ctx).await?;
+
+    if ctx.dry_run() {
+        output::dry_run(out, &format!("restore project #{}", args.id));
+        return Ok(());
+    }
+
+    if !service.restore_project(args.id).await? {
+        return Err(DevErpError::NotFound(format!(
+            "Project with id {} not found or not deleted",
+            args.id
+        )));
+    }
+
+    let project = service.get_project(args.id).await?;
+    outln!(
+        out,
+        "{} Project '{}' restored successfully.",
+        "✓".green().bold(),
+        project.name
+    );
+
+    Ok(())
+}
+
 /// Handle project archive command
-async fn handle_archive(args: ArchiveProjectArgs, _format: OutputFormat) -> Result<()> {
-    let service = create_service().await?;
+async fn handle_archive(
+    ctx: &AppContext,
+    args: ArchiveProjectArgs,
+    _format: OutputFormat,
+) -> Result<()> {
+    let out = ctx.out();
+    let service = create_service(ctx).await?;
 
     // Get the project
     let project = if let Ok(uuid) = Uuid::parse_str(&args.identifier) {
@@ -584,16 +1148,539 @@ async fn handle_archive(args: ArchiveProjectArgs, _format: OutputFormat) -> Resu
         ));
     };
 
+    if ctx.dry_run() {
+        output::dry_run(out, &format!("archive project '{}'", project.name));
+        print_project_target(out, &project);
+        return Ok(());
+    }
+
     // Archive project (set status to Archived)
     let archived_project = service.archive_project(project.id).await?;
 
-    println!(
+    outln!(
+        out,
         "{} Project '{}' archived successfully.",
         "✓".green().bold(),
         archived_project.name
     );
-    summary_line("Status", &archived_project.status.to_string());
-    println!();
+    summary_line(out, "Status", &archived_project.status.to_string());
+    outln!(out);
+
+    Ok(())
+}
+
+/// Handle project derive-dates command
+async fn handle_derive_dates(
+    ctx: &AppContext,
+    args: DeriveDatesArgs,
+    _format: OutputFormat,
+) -> Result<()> {
+    let out = ctx.out();
+    let service = create_service(ctx).await?;
+
+    let project = if let Ok(uuid) = Uuid::parse_str(&args.identifier) {
+        service.get_project_by_uuid(uuid).await?
+    } else if let Ok(id) = args.identifier.parse::<i64>() {
+        service.get_project(id).await?
+    } else {
+        return Err(DevErpError::Validation(
+            "Invalid identifier. Must be a valid UUID or numeric ID".to_string(),
+        ));
+    };
+
+    if ctx.dry_run() {
+        output::dry_run(
+            out,
+            &format!(
+                "derive the dates of project '{}' from its timelines",
+                project.name
+            ),
+        );
+        return Ok(());
+    }
+
+    let updated = service
+        .derive_dates_from_timelines(project.id, args.force)
+        .await?;
+
+    outln!(
+        out,
+        "{} Project '{}' dates derived from timelines.",
+        "✓".green().bold(),
+        updated.name
+    );
+    if let Some(start_date) = updated.start_date {
+        summary_line(out, "Start Date", &start_date.to_string());
+    }
+    if let Some(end_date) = updated.end_date {
+        summary_line(out, "End Date", &end_date.to_string());
+    }
+    outln!(out);
+
+    Ok(())
+}
+
+/// Handle project clone command
+async fn handle_clone(
+    ctx: &AppContext,
+    args: CloneProjectArgs,
+    format: OutputFormat,
+) -> Result<()> {
+    let out = ctx.out();
+    let service = create_service(ctx).await?;
+    let start = parse_start_date(args.start)?;
+
+    let source = if let Ok(uuid) = Uuid::parse_str(&args.source) {
+        service.get_project_by_uuid(uuid).await?
+    } else if let Ok(id) = args.source.parse::<i64>() {
+        service.get_project(id).await?
+    } else {
+        return Err(DevErpError::Validation(
+            "Invalid identifier. Must be a valid UUID or numeric ID".to_string(),
+        ));
+    };
+
+    if ctx.dry_run() {
+        output::dry_run(
+            out,
+            &format!(
+                "clone project '{}' as '{}' starting {}",
+                source.name, args.name, start
+            ),
+        );
+        return Ok(());
+    }
+
+    let options = CloneProjectOptions {
+        start,
+        with_timelines: args.with_timelines,
+        with_tasks: args.with_tasks,
+    };
+    let cloned = retry_transient(args.retry.attempts, || {
+        service.clone_project(
+            source.id,
+            args.name.clone(),
+            Some(args.code.clone()),
+            options,
+        )
+    })
+    .await?;
+
+    if format.is_structured() {
+        return output_structured(out, format, &cloned);
+    }
+
+    print_applied_template(out, &cloned, &format!("project '{}'", source.name), start);
+
+    Ok(())
+}
+
+/// Handle project scan-overdue command
+async fn handle_scan_overdue(
+    ctx: &AppContext,
+    args: ScanOverdueArgs,
+    format: OutputFormat,
+) -> Result<()> {
+    let out = ctx.out();
+    let action = if let Some(status_str) = args.set_status {
+        OverdueAction::SetStatus(
+            status_str
+                .parse::<ProjectStatus>()
+                .map_err(DevErpError::Validation)?,
+        )
+    } else if args.notify {
+        OverdueAction::Notify
+    } else {
+        OverdueAction::Tag
+    };
+
+    let service = create_service(ctx).await?;
+    let today = Local::now().date_naive();
+    let projects = if ctx.dry_run() {
+        service.find_overdue(today).await?
+    } else {
+        service.scan_overdue(action.clone(), today).await?
+    };
+
+    if format.is_structured() {
+        return output_structured(out, format, &projects);
+    }
+
+    if projects.is_empty() {
+        outln!(out, "{} No overdue projects found", "✓".green().bold());
+        return Ok(());
+    }
+
+    let verb = match (action, ctx.dry_run()) {
+        (OverdueAction::Tag, false) => "Tagged".to_string(),
+        (OverdueAction::Tag, true) => "Would tag".to_string(),
+        (OverdueAction::Notify, false) => "Notified".to_string(),
+        (OverdueAction::Notify, true) => "Would notify".to_string(),
+        (OverdueAction::SetStatus(status), false) => format!("Set to {}", status),
+        (OverdueAction::SetStatus(status), true) => format!("Would set to {}", status),
+    };
+    section_title(
+        out,
+        &format!("Overdue Projects ({}) - {}", projects.len(), verb),
+    );
+    outln!(out);
+    for project in &projects {
+        let end_date = project.end_date.unwrap_or(today);
+        outln!(
+            out,
+            "  {} #{} {} - ended {}, {} day(s) overdue",
+            "!".yellow().bold(),
+            project.id,
+            project.name.bold(),
+            end_date,
+            (today - end_date).num_days()
+        );
+    }
+    outln!(out);
 
     Ok(())
 }
+
+/// Column headers for `project health --format csv`
+const HEALTH_HEADERS: [&str; 5] = [
+    "project",
+    "score",
+    "category",
+    "overdue_tasks",
+    "blocked_tasks",
+];
+
+/// Flatten health results into table/CSV rows
+fn health_rows(health: &[ProjectHealth]) -> Vec<Vec<String>> {
+    health
+        .iter()
+        .map(|h| {
+            vec![
+                h.project_name.clone(),
+                h.score.to_string(),
+                h.category.to_string(),
+                h.overdue_tasks.to_string(),
+                h.blocked_tasks.to_string(),
+            ]
+        })
+        .collect()
+}
+
+/// Handle project health command
+async fn handle_health(
+    ctx: &AppContext,
+    args: ProjectHealthArgs,
+    format: OutputFormat,
+) -> Result<()> {
+    let out = ctx.out();
+    let pool = ctx.pool();
+    let service = ReportService::new(
+        Arc::new(PostgresProjectRepository::new(pool.clone())),
+        Arc::new(PostgresTaskRepository::new(pool.clone())),
+        Arc::new(PostgresResourceRepository::new(pool.clone())),
+        Arc::new(PostgresTimelineRepository::new(pool.clone())),
+        Arc::new(PostgresMilestoneRepository::new(pool)),
+    );
+
+    let today = Local::now().date_naive();
+    let health = match args.id {
+        Some(id) => vec![service.project_health(id, today).await?],
+        None => service.generate_project_health(today).await?,
+    };
+
+    match format {
+        OutputFormat::Json | OutputFormat::Yaml => output_structured(out, format, &health),
+        OutputFormat::Csv => {
+            output::csv(out, &HEALTH_HEADERS, &health_rows(&health));
+            Ok(())
+        }
+        OutputFormat::Count => output::count(out, health.len() as i64),
+        OutputFormat::Table | OutputFormat::Plain => {
+            if health.is_empty() {
+                empty_state(out, "active projects");
+                return Ok(());
+            }
+
+            section_title(out, &format!("Project Health ({})", health.len()));
+            outln!(out);
+            output::table_header(out, &["Project", "Score", "Category", "Overdue", "Blocked"]);
+            for (h, mut row) in health.iter().zip(health_rows(&health)) {
+                row[2] = match h.category {
+                    HealthCategory::Healthy => row[2].green().to_string(),
+                    HealthCategory::AtRisk => row[2].yellow().to_string(),
+                    HealthCategory::Critical => row[2].red().bold().to_string(),
+                };
+                output::table_row(out, &row);
+            }
+            Ok(())
+        }
+    }
+}
+
+/// One-line summary of matching projects: the total, then each non-empty status
+fn status_counts_header(counts: &[(ProjectStatus, i64)]) -> String {
+    let total: i64 = counts.iter().map(|(_, count)| count).sum();
+    let breakdown: Vec<String> = counts
+        .iter()
+        .filter(|(_, count)| *count > 0)
+        .map(|(status, count)| format!("{} {}", count, status))
+        .collect();
+
+    if breakdown.is_empty() {
+        format!("{} matching", total)
+    } else {
+        format!("{} matching: {}", total, breakdown.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::resource::entity::{ProjectResource, Resource, ResourceType};
+    use crate::utils::formatter;
+    use chrono::{TimeZone, Utc};
+
+    fn sample_project() -> Project {
+        let timestamp = Utc.with_ymd_and_hms(2025, 1, 2, 3, 4, 5).unwrap();
+        Project {
+            id: 7,
+            uuid: Uuid::nil(),
+            name: "Billing API".to_string(),
+            description: None,
+            code: Some("BILL".to_string()),
+            status: ProjectStatus::Active,
+            priority: Priority::High,
+            start_date: NaiveDate::from_ymd_opt(2025, 1, 1),
+            end_date: None,
+            actual_start_date: None,
+            actual_end_date: None,
+            progress_percentage: Some(40),
+            budget: None,
+            currency: None,
+            repository_url: None,
+            repository_branch: None,
+            tags: Some(vec!["backend".to_string(), "rust".to_string()]),
+            metadata: None,
+            version: 1,
+            created_at: timestamp,
+            updated_at: timestamp,
+            deleted_at: None,
+        }
+    }
+
+    #[test]
+    fn test_status_counts_header() {
+        let counts = [
+            (ProjectStatus::Planning, 0),
+            (ProjectStatus::Active, 5),
+            (ProjectStatus::Completed, 2),
+        ];
+        assert_eq!(
+            status_counts_header(&counts),
+            "7 matching: 5 active, 2 completed"
+        );
+        assert_eq!(
+            status_counts_header(&[(ProjectStatus::OnHold, 0)]),
+            "0 matching"
+        );
+    }
+
+    #[test]
+    fn test_project_kv_lines_are_stable_and_sourceable() {
+        let lines = project_kv_lines(&sample_project());
+
+        let keys: Vec<&str> = lines
+            .iter()
+            .map(|line| line.split_once('=').unwrap().0)
+            .collect();
+        assert_eq!(
+            keys,
+            vec![
+                "id",
+                "uuid",
+                "name",
+                "description",
+                "code",
+                "status",
+                "priority",
+                "progress",
+                "start_date",
+                "end_date",
+                "actual_start_date",
+                "actual_end_date",
+                "repository_url",
+                "repository_branch",
+                "tags",
+                "created_at",
+                "updated_at",
+            ]
+        );
+        assert!(keys
+            .iter()
+            .all(|k| k.chars().all(|c| c.is_ascii_lowercase() || c == '_')));
+
+        assert!(lines.contains(&"id=7".to_string()));
+        assert!(lines.contains(&"name='Billing API'".to_string()));
+        assert!(lines.contains(&"description=".to_string()));
+        assert!(lines.contains(&"start_date=2025-01-01".to_string()));
+        assert!(lines.contains(&"end_date=".to_string()));
+        assert!(lines.contains(&"tags=backend,rust".to_string()));
+        assert!(lines.contains(&"created_at=2025-01-02T03:04:05+00:00".to_string()));
+        assert!(lines.iter().all(|line| !line.contains('\x1b')));
+    }
+
+    #[test]
+    fn test_interactive_create_reprompts_and_keeps_flag_defaults() {
+        use clap::Parser;
+
+        let args = CreateProjectArgs::try_parse_from([
+            "create",
+            "--interactive",
+            "--priority",
+            "high",
+            "--tags",
+            "web, beta",
+        ])
+        .unwrap();
+        let answers = [
+            // An empty name is rejected
+            "",
+            "Wizard App",
+            // No description
+            "",
+            "WIZ",
+            "someday",
+            "active",
+            // Keep --priority high
+            "",
+            "2025-02-30",
+            "2025-03-01",
+            // Before the start date
+            "2025-02-01",
+            "2025-06-30",
+        ];
+        let mut input = std::io::Cursor::new(answers.join("\n") + "\n");
+        let mut output = Vec::new();
+
+        let project = prompt_create_project(&mut input, &mut output, &args).unwrap();
+
+        assert_eq!(project.name, "Wizard App");
+        assert_eq!(project.description, None);
+        assert_eq!(project.code.as_deref(), Some("WIZ"));
+        assert_eq!(project.status, Some(ProjectStatus::Active));
+        assert_eq!(project.priority, Some(Priority::High));
+        assert_eq!(project.start_date, NaiveDate::from_ymd_opt(2025, 3, 1));
+        assert_eq!(project.end_date, NaiveDate::from_ymd_opt(2025, 6, 30));
+        assert_eq!(
+            project.tags,
+            Some(vec!["web".to_string(), "beta".to_string()])
+        );
+        assert!(project.validate().is_ok());
+
+        let printed = String::from_utf8(output).unwrap();
+        assert!(printed.contains("Priority [high]: "));
+        assert!(printed.contains("Project name cannot be empty"));
+        assert!(printed.contains("End date must be after or equal to start date"));
+    }
+
+    #[test]
+    fn test_format_amount_with_and_without_currency() {
+        assert_eq!(format_amount(12500.0, Some("USD")), "12500.00 USD");
+        assert_eq!(format_amount(1234.5, None), "1234.50");
+    }
+
+    #[test]
+    fn test_project_resource_rows_show_link_version() {
+        let timestamp = Utc.with_ymd_and_hms(2025, 1, 2, 3, 4, 5).unwrap();
+        let resource = Resource {
+            id: 3,
+            uuid: Uuid::nil(),
+            name: "tokio".to_string(),
+            description: None,
+            resource_type: ResourceType::Library,
+            version: Some("1.40.0".to_string()),
+            url: None,
+            documentation_url: None,
+            license: None,
+            status: None,
+            metadata: None,
+            tags: None,
+            created_at: timestamp,
+            updated_at: timestamp,
+            deleted_at: None,
+        };
+        let link = ProjectResource {
+            project_id: 7,
+            resource_id: 3,
+            usage_notes: None,
+            version_used: Some("1.36.0".to_string()),
+            is_critical: Some(true),
+            added_at: timestamp,
+            removed_at: None,
+        };
+
+        let mut unpinned = ProjectResourceDetail {
+            resource: resource.clone(),
+            link: link.clone(),
+        };
+        unpinned.link.version_used = None;
+        unpinned.link.is_critical = None;
+
+        let rows = project_resource_rows(&[ProjectResourceDetail { resource, link }, unpinned]);
+
+        assert_eq!(rows[0], vec!["3", "tokio", "library", "1.36.0", "yes"]);
+        assert_eq!(rows[1][3], "1.40.0");
+        assert_eq!(rows[1][4], "no");
+    }
+
+    #[test]
+    fn test_schedule_sparkline_marks_lagging_project_behind() {
+        let mut project = sample_project();
+        let today = NaiveDate::from_ymd_opt(2025, 1, 9).unwrap();
+
+        // No planned end date, so no expectation to compare against
+        assert!(schedule_sparkline(&project, today).is_none());
+
+        project.end_date = NaiveDate::from_ymd_opt(2025, 1, 11);
+        let lines = schedule_sparkline(&project, today).unwrap();
+
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with("expected"));
+        assert!(lines[0].contains(" 80%"));
+        assert!(lines[1].contains(" 40%"));
+        assert!(lines[1].ends_with("behind by 40 pts"));
+    }
+
+    #[test]
+    fn test_health_csv_has_numeric_score_column() {
+        let health = vec![
+            ProjectHealth {
+                project_id: 3,
+                project_name: "Portal, EU".to_string(),
+                score: 35,
+                category: HealthCategory::Critical,
+                overdue_tasks: 3,
+                blocked_tasks: 1,
+            },
+            ProjectHealth {
+                project_id: 7,
+                project_name: "Billing API".to_string(),
+                score: 90,
+                category: HealthCategory::Healthy,
+                overdue_tasks: 0,
+                blocked_tasks: 0,
+            },
+        ];
+
+        let csv = formatter::to_csv(&HEALTH_HEADERS, &health_rows(&health));
+        let lines: Vec<&str> = csv.lines().collect();
+
+        assert_eq!(lines.len(), 3);
+        assert_eq!(
+            lines[0],
+            "project,score,category,overdue_tasks,blocked_tasks"
+        );
+        assert_eq!(lines[1], "\"Portal, EU\",35,critical,3,1");
+        assert_eq!(lines[2], "Billing API,90,healthy,0,0");
+    }
+}
@@ -1,32 +1,44 @@
 // Report CLI commands
 
-use super::commands::{OutputFormat, ReportCommand};
-use crate::config::settings::Settings;
-use crate::infrastructure::database;
-use crate::utils::formatter::{key_value, section_header, table_header, table_row};
+use super::commands::{OutputFormat, ReportCommand, ReportCsvArgs};
+use super::context::AppContext;
+use super::output::{
+    self, key_value, out, outln, output_structured, progress_bar, section_header, table_header,
+    table_row, OutputSink,
+};
+use crate::utils::error::DevErpError;
+use crate::utils::watch::repeat;
 use crate::Result;
+use chrono::Local;
+use std::fs;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::Duration;
 
-use crate::domain::report::ReportService;
+use crate::domain::export::ExportFormat;
+use crate::domain::report::{report_to_csv, CsvReport, ReportService};
+use crate::domain::snapshot::SnapshotService;
 use crate::infrastructure::repositories::{
     project_repo::PostgresProjectRepository,
     resource_repo::PostgresResourceRepository,
+    snapshot_repo::PostgresSnapshotRepository,
     task_repo::PostgresTaskRepository,
     timeline_repo::{PostgresMilestoneRepository, PostgresTimelineRepository},
 };
 
 /// Handle report commands
-pub async fn handle(command: ReportCommand, _format: OutputFormat) -> Result<()> {
+pub async fn handle(ctx: &AppContext, command: ReportCommand, format: OutputFormat) -> Result<()> {
+    let out = ctx.out();
     // Establish database connection
-    let settings = Settings::default();
-    let pool = database::establish_connection(&settings.database).await?;
+    let pool = ctx.pool();
 
     // Create repositories
     let project_repo = Arc::new(PostgresProjectRepository::new(pool.clone()));
     let task_repo = Arc::new(PostgresTaskRepository::new(pool.clone()));
     let resource_repo = Arc::new(PostgresResourceRepository::new(pool.clone()));
     let timeline_repo = Arc::new(PostgresTimelineRepository::new(pool.clone()));
-    let milestone_repo = Arc::new(PostgresMilestoneRepository::new(pool));
+    let milestone_repo = Arc::new(PostgresMilestoneRepository::new(pool.clone()));
+    let snapshots = SnapshotService::new(Arc::new(PostgresSnapshotRepository::new(pool)));
 
     // Create report service
     let service = ReportService::new(
@@ -38,46 +50,297 @@ pub async fn handle(command: ReportCommand, _format: OutputFormat) -> Result<()>
     );
 
     match command {
-        ReportCommand::Status => handle_status(service).await,
-        ReportCommand::ProjectSummary => handle_project_summary(service).await,
-        ReportCommand::TaskAnalytics => handle_task_analytics(service).await,
-        ReportCommand::ResourceUsage => handle_resource_usage(service).await,
-        ReportCommand::TimelineProgress => handle_timeline_progress(service).await,
+        ReportCommand::Status {
+            with_trend,
+            trend_points,
+            watch,
+            interval,
+            csv,
+        } => {
+            let trend_points = if with_trend { Some(trend_points) } else { None };
+            if watch {
+                return handle_status_watch(
+                    out,
+                    &service,
+                    &snapshots,
+                    trend_points,
+                    interval,
+                    format,
+                )
+                .await;
+            }
+            handle_status(out, &service, &snapshots, trend_points, &csv, format).await
+        }
+        ReportCommand::Snapshot => {
+            if ctx.dry_run() {
+                output::dry_run(out, "record a status snapshot");
+                return Ok(());
+            }
+            handle_snapshot(out, service, snapshots).await
+        }
+        ReportCommand::ProjectSummary { csv } => {
+            handle_project_summary(out, service, csv, format).await
+        }
+        ReportCommand::TaskAnalytics { by_assignee, csv } => {
+            handle_task_analytics(out, service, by_assignee, csv, format).await
+        }
+        ReportCommand::ResourceUsage {
+            suggest_cleanup,
+            csv,
+        } => handle_resource_usage(out, service, suggest_cleanup, csv, format).await,
+        ReportCommand::TimelineProgress { csv } => {
+            handle_timeline_progress(out, service, csv, format).await
+        }
+        ReportCommand::Velocity {
+            project_id,
+            last,
+            csv,
+        } => {
+            handle_velocity(
+                out,
+                service,
+                project_id,
+                last.map(|n| n as usize),
+                csv,
+                format,
+            )
+            .await
+        }
+        ReportCommand::Burndown { timeline_id, csv } => {
+            handle_burndown(out, service, timeline_id, csv, format).await
+        }
+        ReportCommand::Overdue { ics, csv } => handle_overdue(out, service, ics, csv, format).await,
+        ReportCommand::OverdueByAssignee => handle_overdue_by_assignee(out, service, format).await,
+        ReportCommand::MilestoneSlip { csv } => {
+            handle_milestone_slip(out, service, csv, format).await
+        }
+        ReportCommand::ProjectHealth { project_id, csv } => {
+            handle_project_health(out, service, project_id, csv, format).await
+        }
+        ReportCommand::ExportAll { dir } => handle_export_all(out, service, &dir, format).await,
+    }
+}
+
+/// Write `report` to the `--csv` path if one was given
+///
+/// Returns `true` when the report was written, so the caller can skip
+/// printing it.
+fn write_csv<T: CsvReport>(out: &dyn OutputSink, args: &ReportCsvArgs, report: &T) -> Result<bool> {
+    let Some(ref path) = args.csv else {
+        return Ok(false);
+    };
+
+    let files = write_report_csv(Path::new(path), report)?;
+    output::success(out, &format!("Report written to {}", path));
+    for file in files.iter().skip(1) {
+        outln!(out, "  {}", file.display());
+    }
+
+    Ok(true)
+}
+
+/// Write a report to `path` as CSV, returning every file written
+///
+/// The main table goes to `path`; each list section goes next to it as
+/// `<stem>_<section>.<ext>`, so `usage.csv` gets `usage_most_used_resources.csv`.
+fn write_report_csv<T: CsvReport>(path: &Path, report: &T) -> Result<Vec<PathBuf>> {
+    let csv = report_to_csv(report)?;
+
+    fs::write(path, csv.main)?;
+    let mut written = vec![path.to_path_buf()];
+
+    for (section, contents) in csv.sections {
+        let section_path = section_path(path, &section);
+        fs::write(&section_path, contents)?;
+        written.push(section_path);
+    }
+
+    Ok(written)
+}
+
+/// Sibling file for a list section of the report at `path`
+fn section_path(path: &Path, section: &str) -> PathBuf {
+    let stem = path
+        .file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "report".to_string());
+    let extension = path
+        .extension()
+        .map(|e| e.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "csv".to_string());
+
+    path.with_file_name(format!("{}_{}.{}", stem, section, extension))
+}
+
+/// Write one report to `<dir>/<name>.<ext>`, returning the files written
+fn write_report<T: CsvReport>(
+    dir: &Path,
+    name: &str,
+    report: &T,
+    format: ExportFormat,
+) -> Result<Vec<PathBuf>> {
+    let path = dir.join(format!("{}.{}", name, format.extension()));
+    match format {
+        ExportFormat::Csv => write_report_csv(&path, report),
+        ExportFormat::Json => {
+            let json = serde_json::to_string_pretty(report)
+                .map_err(|e| DevErpError::Internal(format!("JSON serialization error: {}", e)))?;
+            fs::write(&path, json)?;
+            Ok(vec![path])
+        }
     }
 }
 
+/// Handle report export-all command
+///
+/// Writes the project status, task analytics, resource usage, timeline
+/// progress and project summary reports into `dir`, one file each, named
+/// after the report (`task_analytics.csv`, ...) and including the optional
+/// assignee and cleanup sections. The directory is created if missing and
+/// existing files are overwritten. CSV list sections go to sibling files as
+/// with `--csv`.
+async fn handle_export_all(
+    out: &dyn OutputSink,
+    service: ReportService,
+    dir: &str,
+    format: OutputFormat,
+) -> Result<()> {
+    let export_format = match format {
+        OutputFormat::Table | OutputFormat::Json => ExportFormat::Json,
+        OutputFormat::Csv => ExportFormat::Csv,
+        OutputFormat::Plain | OutputFormat::Yaml | OutputFormat::Count => {
+            return Err(DevErpError::Validation(
+                "Report export-all supports --format json or --format csv".to_string(),
+            ))
+        }
+    };
+
+    let path = Path::new(dir);
+    fs::create_dir_all(path)?;
+
+    let mut files = Vec::new();
+    files.extend(write_report(
+        path,
+        "project_status",
+        &service.generate_project_status_report().await?,
+        export_format,
+    )?);
+    files.extend(write_report(
+        path,
+        "task_analytics",
+        &service.generate_task_analytics(true).await?,
+        export_format,
+    )?);
+    files.extend(write_report(
+        path,
+        "resource_usage",
+        &service.generate_resource_usage_report(true).await?,
+        export_format,
+    )?);
+    files.extend(write_report(
+        path,
+        "timeline_progress",
+        &service.generate_timeline_progress_report().await?,
+        export_format,
+    )?);
+    files.extend(write_report(
+        path,
+        "project_summary",
+        &service.generate_project_summary().await?,
+        export_format,
+    )?);
+
+    output::success(out, &format!("Wrote {} files to {}", files.len(), dir));
+    for file in &files {
+        outln!(out, "  {}", file.display());
+    }
+
+    Ok(())
+}
+
 /// Handle status report command
-async fn handle_status(service: ReportService) -> Result<()> {
+async fn handle_status(
+    out: &dyn OutputSink,
+    service: &ReportService,
+    snapshots: &SnapshotService,
+    trend_points: Option<usize>,
+    csv: &ReportCsvArgs,
+    format: OutputFormat,
+) -> Result<()> {
     let report = service.generate_project_status_report().await?;
 
-    section_header("PROJECT STATUS REPORT");
+    if csv.csv.is_some() {
+        match trend_points {
+            Some(points) => write_csv(out, csv, &snapshots.with_trend(report, points).await?)?,
+            None => write_csv(out, csv, &report)?,
+        };
+        return Ok(());
+    }
+
+    if format.is_structured() {
+        return match trend_points {
+            Some(points) => {
+                output_structured(out, format, &snapshots.with_trend(report, points).await?)
+            }
+            None => output_structured(out, format, &report),
+        };
+    }
+
+    section_header(out, "PROJECT STATUS REPORT");
 
-    println!();
-    println!("Overall Statistics:");
-    key_value("Total Projects", &report.total_projects.to_string());
-    key_value("Active Projects", &report.active_projects.to_string());
-    key_value("Completed Projects", &report.completed_projects.to_string());
-    key_value("On Hold Projects", &report.on_hold_projects.to_string());
-    key_value("Cancelled Projects", &report.cancelled_projects.to_string());
-    key_value("Archived Projects", &report.archived_projects.to_string());
+    outln!(out);
+    outln!(out, "Overall Statistics:");
+    key_value(out, "Total Projects", &report.total_projects.to_string());
+    key_value(out, "Active Projects", &report.active_projects.to_string());
     key_value(
+        out,
+        "Completed Projects",
+        &report.completed_projects.to_string(),
+    );
+    key_value(
+        out,
+        "On Hold Projects",
+        &report.on_hold_projects.to_string(),
+    );
+    key_value(
+        out,
+        "Cancelled Projects",
+        &report.cancelled_projects.to_string(),
+    );
+    key_value(
+        out,
+        "Archived Projects",
+        &report.archived_projects.to_string(),
+    );
+    key_value(
+        out,
         "Average Progress",
         &format!("{:.1}%", report.average_progress),
     );
-    key_value("Delayed Projects", &report.delayed_projects.to_string());
+    key_value(
+        out,
+        "Delayed Projects",
+        &report.delayed_projects.to_string(),
+    );
 
-    println!();
-    println!("Projects by Priority:");
+    outln!(out);
+    outln!(out, "Projects by Priority:");
     key_value(
+        out,
         "  Critical",
         &report.projects_by_priority.critical.to_string(),
     );
-    key_value("  High", &report.projects_by_priority.high.to_string());
-    key_value("  Medium", &report.projects_by_priority.medium.to_string());
-    key_value("  Low", &report.projects_by_priority.low.to_string());
+    key_value(out, "  High", &report.projects_by_priority.high.to_string());
+    key_value(
+        out,
+        "  Medium",
+        &report.projects_by_priority.medium.to_string(),
+    );
+    key_value(out, "  Low", &report.projects_by_priority.low.to_string());
 
-    println!();
+    outln!(out);
     key_value(
+        out,
         "Generated At",
         &report
             .generated_at
@@ -85,116 +348,302 @@ async fn handle_status(service: ReportService) -> Result<()> {
             .to_string(),
     );
 
+    if let Some(points) = trend_points {
+        let trend = snapshots.recent(points).await?;
+
+        outln!(out);
+        outln!(out, "Trend:");
+        if trend.is_empty() {
+            outln!(
+                out,
+                "  No snapshots recorded yet (run `deverp report snapshot`)."
+            );
+        } else {
+            table_header(
+                out,
+                &[
+                    "Captured At",
+                    "Total",
+                    "Active",
+                    "Completed",
+                    "Delayed",
+                    "Avg",
+                ],
+            );
+            for snapshot in trend {
+                table_row(
+                    out,
+                    &[
+                        snapshot.captured_at.format("%Y-%m-%d %H:%M").to_string(),
+                        snapshot.total_projects.to_string(),
+                        snapshot.active_projects.to_string(),
+                        snapshot.completed_projects.to_string(),
+                        snapshot.delayed_projects.to_string(),
+                        format!("{:.1}%", snapshot.average_progress),
+                    ],
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Handle report status --watch: redraw the report every `interval` seconds
+///
+/// Stops cleanly on Ctrl-C, leaving the prompt on a fresh line.
+async fn handle_status_watch(
+    out: &dyn OutputSink,
+    service: &ReportService,
+    snapshots: &SnapshotService,
+    trend_points: Option<usize>,
+    interval: u64,
+    format: OutputFormat,
+) -> Result<()> {
+    let csv = ReportCsvArgs::default();
+    let csv = &csv;
+
+    repeat(Duration::from_secs(interval), move || async move {
+        // Clear the screen and move the cursor home
+        out!(out, "\x1b[2J\x1b[H");
+        handle_status(out, service, snapshots, trend_points, csv, format).await?;
+        outln!(out, "\nRefreshing every {}s (Ctrl-C to stop)", interval);
+        Ok(())
+    })
+    .await?;
+
+    outln!(out);
+    Ok(())
+}
+
+/// Handle report snapshot command
+async fn handle_snapshot(
+    out: &dyn OutputSink,
+    service: ReportService,
+    snapshots: SnapshotService,
+) -> Result<()> {
+    let report = service.generate_project_status_report().await?;
+    let snapshot = snapshots.capture(&report).await?;
+
+    output::success(
+        out,
+        &format!(
+            "Status snapshot #{} recorded at {}",
+            snapshot.id,
+            snapshot.captured_at.format("%Y-%m-%d %H:%M:%S UTC")
+        ),
+    );
+
     Ok(())
 }
 
 /// Handle project summary report command
-async fn handle_project_summary(service: ReportService) -> Result<()> {
+async fn handle_project_summary(
+    out: &dyn OutputSink,
+    service: ReportService,
+    csv: ReportCsvArgs,
+    format: OutputFormat,
+) -> Result<()> {
     let summary = service.generate_project_summary().await?;
+    if write_csv(out, &csv, &summary)? {
+        return Ok(());
+    }
 
-    section_header("PROJECT SUMMARY");
+    if format.is_structured() {
+        return output_structured(out, format, &summary);
+    }
+
+    section_header(out, "PROJECT SUMMARY");
 
     if summary.is_empty() {
-        println!("\nNo projects found.");
+        outln!(out, "\nNo projects found.");
         return Ok(());
     }
 
-    println!();
-    table_header(&[
-        "ID",
-        "Code",
-        "Name",
-        "Status",
-        "Priority",
-        "Progress",
-        "Tasks",
-        "Completed",
-        "Start Date",
-        "End Date",
-    ]);
+    outln!(out);
+    table_header(
+        out,
+        &[
+            "ID",
+            "Code",
+            "Name",
+            "Status",
+            "Priority",
+            "Progress",
+            "Tasks",
+            "Completed",
+            "Start Date",
+            "End Date",
+        ],
+    );
 
     for item in summary {
-        table_row(&[
-            item.project_id.to_string(),
-            item.project_code.unwrap_or_else(|| "-".to_string()),
-            item.project_name,
-            item.status,
-            item.priority,
-            format!("{}%", item.progress_percentage),
-            item.total_tasks.to_string(),
-            format!("{}/{}", item.completed_tasks, item.total_tasks),
-            item.start_date.unwrap_or_else(|| "-".to_string()),
-            item.end_date.unwrap_or_else(|| "-".to_string()),
-        ]);
+        table_row(
+            out,
+            &[
+                item.project_id.to_string(),
+                item.project_code.unwrap_or_else(|| "-".to_string()),
+                item.project_name,
+                item.status,
+                item.priority,
+                format!("{}%", item.progress_percentage),
+                item.total_tasks.to_string(),
+                format!("{}/{}", item.completed_tasks, item.total_tasks),
+                item.start_date.unwrap_or_else(|| "-".to_string()),
+                item.end_date.unwrap_or_else(|| "-".to_string()),
+            ],
+        );
     }
 
     Ok(())
 }
 
 /// Handle task analytics report command
-async fn handle_task_analytics(service: ReportService) -> Result<()> {
-    let report = service.generate_task_analytics().await?;
+async fn handle_task_analytics(
+    out: &dyn OutputSink,
+    service: ReportService,
+    by_assignee: bool,
+    csv: ReportCsvArgs,
+    format: OutputFormat,
+) -> Result<()> {
+    let report = service.generate_task_analytics(by_assignee).await?;
+    if write_csv(out, &csv, &report)? {
+        return Ok(());
+    }
+
+    if format.is_structured() {
+        return output_structured(out, format, &report);
+    }
 
-    section_header("TASK ANALYTICS REPORT");
+    section_header(out, "TASK ANALYTICS REPORT");
 
-    println!();
-    println!("Overall Statistics:");
-    key_value("Total Tasks", &report.total_tasks.to_string());
+    outln!(out);
+    outln!(out, "Overall Statistics:");
+    key_value(out, "Total Tasks", &report.total_tasks.to_string());
     key_value(
+        out,
         "Completion Rate",
         &format!("{:.1}%", report.completion_rate),
     );
-    key_value("Overdue Tasks", &report.overdue_tasks.to_string());
+    key_value(out, "Overdue Tasks", &report.overdue_tasks.to_string());
     key_value(
+        out,
         "On-Time Completions",
         &report.on_time_completion_count.to_string(),
     );
 
-    println!();
-    println!("Tasks by Status:");
-    key_value("  Todo", &report.tasks_by_status.todo.to_string());
+    outln!(out);
+    outln!(out, "Tasks by Status:");
+    key_value(out, "  Todo", &report.tasks_by_status.todo.to_string());
     key_value(
+        out,
         "  In Progress",
         &report.tasks_by_status.in_progress.to_string(),
     );
-    key_value("  Blocked", &report.tasks_by_status.blocked.to_string());
-    key_value("  Review", &report.tasks_by_status.review.to_string());
-    key_value("  Testing", &report.tasks_by_status.testing.to_string());
-    key_value("  Done", &report.tasks_by_status.done.to_string());
-    key_value("  Cancelled", &report.tasks_by_status.cancelled.to_string());
+    key_value(
+        out,
+        "  Blocked",
+        &report.tasks_by_status.blocked.to_string(),
+    );
+    key_value(out, "  Review", &report.tasks_by_status.review.to_string());
+    key_value(
+        out,
+        "  Testing",
+        &report.tasks_by_status.testing.to_string(),
+    );
+    key_value(out, "  Done", &report.tasks_by_status.done.to_string());
+    key_value(
+        out,
+        "  Cancelled",
+        &report.tasks_by_status.cancelled.to_string(),
+    );
 
-    println!();
-    println!("Tasks by Priority:");
-    key_value("  Critical", &report.tasks_by_priority.critical.to_string());
-    key_value("  High", &report.tasks_by_priority.high.to_string());
-    key_value("  Medium", &report.tasks_by_priority.medium.to_string());
-    key_value("  Low", &report.tasks_by_priority.low.to_string());
+    outln!(out);
+    outln!(out, "Tasks by Priority:");
+    key_value(
+        out,
+        "  Critical",
+        &report.tasks_by_priority.critical.to_string(),
+    );
+    key_value(out, "  High", &report.tasks_by_priority.high.to_string());
+    key_value(
+        out,
+        "  Medium",
+        &report.tasks_by_priority.medium.to_string(),
+    );
+    key_value(out, "  Low", &report.tasks_by_priority.low.to_string());
 
-    println!();
-    println!("Time Tracking:");
+    outln!(out);
+    outln!(out, "Time Tracking:");
     key_value(
+        out,
         "Total Estimated Hours",
         &format!("{:.1}", report.total_estimated_hours),
     );
     key_value(
+        out,
         "Total Actual Hours",
         &format!("{:.1}", report.total_actual_hours),
     );
     key_value(
+        out,
         "Average Estimated Hours",
         &format!("{:.1}", report.avg_estimated_hours),
     );
     key_value(
+        out,
         "Average Actual Hours",
         &format!("{:.1}", report.avg_actual_hours),
     );
     key_value(
+        out,
         "Time Variance",
         &format!("{:.1}%", report.time_variance_percentage),
     );
 
-    println!();
+    if let Some(ref breakdown) = report.by_assignee {
+        outln!(out);
+        outln!(out, "Tasks by Assignee:");
+        table_header(
+            out,
+            &[
+                "Assignee",
+                "Total",
+                "Todo",
+                "In Progress",
+                "Blocked",
+                "Review",
+                "Testing",
+                "Done",
+                "Cancelled",
+                "Completion",
+            ],
+        );
+
+        for item in breakdown {
+            table_row(
+                out,
+                &[
+                    item.assigned_to
+                        .clone()
+                        .unwrap_or_else(|| "(unassigned)".to_string()),
+                    item.total_tasks.to_string(),
+                    item.tasks_by_status.todo.to_string(),
+                    item.tasks_by_status.in_progress.to_string(),
+                    item.tasks_by_status.blocked.to_string(),
+                    item.tasks_by_status.review.to_string(),
+                    item.tasks_by_status.testing.to_string(),
+                    item.tasks_by_status.done.to_string(),
+                    item.tasks_by_status.cancelled.to_string(),
+                    format!("{:.1}%", item.completion_rate),
+                ],
+            );
+        }
+    }
+
+    outln!(out);
     key_value(
+        out,
         "Generated At",
         &report
             .generated_at
@@ -206,52 +655,114 @@ async fn handle_task_analytics(service: ReportService) -> Result<()> {
 }
 
 /// Handle resource usage report command
-async fn handle_resource_usage(service: ReportService) -> Result<()> {
-    let report = service.generate_resource_usage_report().await?;
+async fn handle_resource_usage(
+    out: &dyn OutputSink,
+    service: ReportService,
+    suggest_cleanup: bool,
+    csv: ReportCsvArgs,
+    format: OutputFormat,
+) -> Result<()> {
+    let report = service
+        .generate_resource_usage_report(suggest_cleanup)
+        .await?;
+    if write_csv(out, &csv, &report)? {
+        return Ok(());
+    }
 
-    section_header("RESOURCE USAGE REPORT");
+    if format.is_structured() {
+        return output_structured(out, format, &report);
+    }
+
+    section_header(out, "RESOURCE USAGE REPORT");
 
-    println!();
-    println!("Overall Statistics:");
-    key_value("Total Resources", &report.total_resources.to_string());
-    key_value("Active Resources", &report.active_resources.to_string());
+    outln!(out);
+    outln!(out, "Overall Statistics:");
+    key_value(out, "Total Resources", &report.total_resources.to_string());
+    key_value(
+        out,
+        "Active Resources",
+        &report.active_resources.to_string(),
+    );
     key_value(
+        out,
         "Deprecated Resources",
         &report.deprecated_resources.to_string(),
     );
-    key_value("Unused Resources", &report.unused_resources.to_string());
+    key_value(
+        out,
+        "Unused Resources",
+        &report.unused_resources.to_string(),
+    );
 
-    println!();
-    println!("Resources by Type:");
-    key_value("  Library", &report.resources_by_type.library.to_string());
-    key_value("  API", &report.resources_by_type.api.to_string());
-    key_value("  Tool", &report.resources_by_type.tool.to_string());
-    key_value("  Service", &report.resources_by_type.service.to_string());
+    outln!(out);
+    outln!(out, "Resources by Type:");
     key_value(
+        out,
+        "  Library",
+        &report.resources_by_type.library.to_string(),
+    );
+    key_value(out, "  API", &report.resources_by_type.api.to_string());
+    key_value(out, "  Tool", &report.resources_by_type.tool.to_string());
+    key_value(
+        out,
+        "  Service",
+        &report.resources_by_type.service.to_string(),
+    );
+    key_value(
+        out,
         "  Documentation",
         &report.resources_by_type.documentation.to_string(),
     );
-    key_value("  Other", &report.resources_by_type.other.to_string());
+    key_value(out, "  Other", &report.resources_by_type.other.to_string());
 
     if !report.most_used_resources.is_empty() {
-        println!();
-        section_header("TOP 10 MOST USED RESOURCES");
-        println!();
-        table_header(&["ID", "Name", "Type", "Projects", "Critical Projects"]);
+        outln!(out);
+        section_header(out, "TOP 10 MOST USED RESOURCES");
+        outln!(out);
+        table_header(
+            out,
+            &["ID", "Name", "Type", "Projects", "Critical Projects"],
+        );
 
         for item in &report.most_used_resources {
-            table_row(&[
-                item.resource_id.to_string(),
-                item.resource_name.clone(),
-                item.resource_type.clone(),
-                item.project_count.to_string(),
-                item.critical_project_count.to_string(),
-            ]);
+            table_row(
+                out,
+                &[
+                    item.resource_id.to_string(),
+                    item.resource_name.clone(),
+                    item.resource_type.clone(),
+                    item.project_count.to_string(),
+                    item.critical_project_count.to_string(),
+                ],
+            );
+        }
+    }
+
+    if let Some(ref suggestions) = report.cleanup_suggestions {
+        outln!(out);
+        section_header(out, "CLEANUP SUGGESTIONS");
+        outln!(out);
+        if suggestions.is_empty() {
+            outln!(out, "No unused deprecated resources to archive.");
+        } else {
+            table_header(out, &["ID", "Name", "Type", "Archive With"]);
+            for suggestion in suggestions {
+                table_row(
+                    out,
+                    &[
+                        suggestion.resource_id.to_string(),
+                        suggestion.resource_name.clone(),
+                        suggestion.resource_type.clone(),
+                        suggestion.archive_command.clone(),
+                    ],
+                );
+            }
         }
     }
 
-    println!();
+    outln!(out);
     key_value(
+        out,
         "Generated At",
         &report
             .generated_at
@@ -263,43 +774,73 @@ async fn handle_resource_usage(service: ReportService) -> Result<()> {
 }
 
 /// Handle timeline progress report command
-async fn handle_timeline_progress(service: ReportService) -> Result<()> {
+async fn handle_timeline_progress(
+    out: &dyn OutputSink,
+    service: ReportService,
+    csv: ReportCsvArgs,
+    format: OutputFormat,
+) -> Result<()> {
     let report = service.generate_timeline_progress_report().await?;
+    if write_csv(out, &csv, &report)? {
+        return Ok(());
+    }
+
+    if format.is_structured() {
+        return output_structured(out, format, &report);
+    }
 
-    section_header("TIMELINE PROGRESS REPORT");
+    section_header(out, "TIMELINE PROGRESS REPORT");
 
-    println!();
-    println!("Timeline Statistics:");
-    key_value("Total Timelines", &report.total_timelines.to_string());
-    key_value("Active Timelines", &report.active_timelines.to_string());
+    outln!(out);
+    outln!(out, "Timeline Statistics:");
+    key_value(out, "Total Timelines", &report.total_timelines.to_string());
     key_value(
+        out,
+        "Active Timelines",
+        &report.active_timelines.to_string(),
+    );
+    key_value(
+        out,
         "Completed Timelines",
         &report.completed_timelines.to_string(),
     );
 
-    println!();
-    println!("Milestone Statistics:");
-    key_value("Total Milestones", &report.total_milestones.to_string());
+    outln!(out);
+    outln!(out, "Milestone Statistics:");
+    key_value(
+        out,
+        "Total Milestones",
+        &report.total_milestones.to_string(),
+    );
     key_value(
+        out,
         "Completed Milestones",
         &report.completed_milestones.to_string(),
     );
-    key_value("Missed Milestones", &report.missed_milestones.to_string());
     key_value(
+        out,
+        "Missed Milestones",
+        &report.missed_milestones.to_string(),
+    );
+    key_value(
+        out,
         "Completion Rate",
         &format!("{:.1}%", report.milestone_completion_rate),
     );
     key_value(
+        out,
         "On-Time Completion Rate",
         &format!("{:.1}%", report.on_time_milestone_rate),
     );
     key_value(
+        out,
         "Upcoming Milestones (30 days)",
         &report.upcoming_milestones_count.to_string(),
     );
 
-    println!();
+    outln!(out);
     key_value(
+        out,
         "Generated At",
         &report
             .generated_at
@@ -309,3 +850,337 @@ async fn handle_timeline_progress(service: ReportService) -> Result<()> {
 
     Ok(())
 }
+
+async fn handle_velocity(
+    out: &dyn OutputSink,
+    service: ReportService,
+    project_id: Option<i64>,
+    last: Option<usize>,
+    csv: ReportCsvArgs,
+    format: OutputFormat,
+) -> Result<()> {
+    let today = Local::now().date_naive();
+    let report = service
+        .generate_velocity_report(project_id, last, today)
+        .await?;
+    if write_csv(out, &csv, &report)? {
+        return Ok(());
+    }
+
+    if format.is_structured() {
+        return output_structured(out, format, &report);
+    }
+
+    section_header(out, "VELOCITY REPORT");
+
+    outln!(out);
+    if report.sprints.is_empty() {
+        outln!(out, "No finished sprints found.");
+        return Ok(());
+    }
+
+    table_header(
+        out,
+        &["Sprint", "Project", "Ended", "Tasks Done", "Hours Done"],
+    );
+    for sprint in &report.sprints {
+        table_row(
+            out,
+            &[
+                sprint.sprint_name.clone(),
+                sprint.project_id.to_string(),
+                sprint.end_date.to_string(),
+                sprint.completed_tasks.to_string(),
+                format!("{:.1}", sprint.completed_hours),
+            ],
+        );
+    }
+
+    outln!(out);
+    let window = match last {
+        Some(n) => format!("last {} sprint(s)", n.min(report.sprints.len())),
+        None => format!("all {} sprint(s)", report.sprints.len()),
+    };
+    key_value(out, "Averaged Over", &window);
+    key_value(
+        out,
+        "Average Tasks/Sprint",
+        &format!("{:.1}", report.average_tasks),
+    );
+    key_value(
+        out,
+        "Average Hours/Sprint",
+        &format!("{:.1}", report.average_hours),
+    );
+
+    Ok(())
+}
+
+/// Handle burndown report command
+async fn handle_burndown(
+    out: &dyn OutputSink,
+    service: ReportService,
+    timeline_id: i64,
+    csv: ReportCsvArgs,
+    format: OutputFormat,
+) -> Result<()> {
+    let report = service.generate_burndown(timeline_id).await?;
+    // Plotting tools want the daily points as the main table
+    if write_csv(out, &csv, &report.points)? {
+        return Ok(());
+    }
+
+    if format.is_structured() {
+        return output_structured(out, format, &report);
+    }
+
+    section_header(out, &format!("BURNDOWN: {}", report.timeline_name));
+    outln!(out);
+
+    let total = report
+        .points
+        .first()
+        .map_or(0.0, |p| p.ideal_hours.max(p.remaining_hours));
+    table_header(out, &["Date", "Remaining", "Ideal", "Burndown"]);
+    for point in &report.points {
+        let percent = if total > 0.0 {
+            point.remaining_hours / total * 100.0
+        } else {
+            0.0
+        };
+        table_row(
+            out,
+            &[
+                point.date.to_string(),
+                format!("{:.1}", point.remaining_hours),
+                format!("{:.1}", point.ideal_hours),
+                progress_bar(percent, 20),
+            ],
+        );
+    }
+    outln!(out);
+
+    Ok(())
+}
+
+/// Handle project health report command
+async fn handle_project_health(
+    out: &dyn OutputSink,
+    service: ReportService,
+    project_id: i64,
+    csv: ReportCsvArgs,
+    format: OutputFormat,
+) -> Result<()> {
+    let today = Local::now().date_naive();
+    let report = service.project_health_report(project_id, today).await?;
+    if write_csv(out, &csv, &report)? {
+        return Ok(());
+    }
+
+    if format.is_structured() {
+        return output_structured(out, format, &report);
+    }
+
+    section_header(out, &format!("PROJECT HEALTH: {}", report.project_name));
+
+    outln!(out);
+    key_value(out, "Schedule", &format!("{}/100", report.schedule_score));
+    key_value(out, "Progress", &format!("{}/100", report.progress_score));
+    key_value(
+        out,
+        "Task Completion",
+        &format!("{}/100", report.task_completion_score),
+    );
+    key_value(out, "Overall", &format!("{}/100", report.overall_score));
+
+    outln!(out);
+    if report.risk_flags.is_empty() {
+        outln!(out, "No risks flagged.");
+    } else {
+        outln!(out, "Risks:");
+        for flag in &report.risk_flags {
+            outln!(out, "  - {}", flag);
+        }
+    }
+
+    Ok(())
+}
+
+/// Handle overdue report command
+async fn handle_overdue(
+    out: &dyn OutputSink,
+    service: ReportService,
+    ics: bool,
+    csv: ReportCsvArgs,
+    format: OutputFormat,
+) -> Result<()> {
+    let today = Local::now().date_naive();
+    let report = service.generate_overdue_report(today).await?;
+    if write_csv(out, &csv, &report)? {
+        return Ok(());
+    }
+
+    if ics {
+        out!(out, "{}", report.to_ics());
+        return Ok(());
+    }
+
+    if format.is_structured() {
+        return output_structured(out, format, &report);
+    }
+
+    section_header(out, "OVERDUE REPORT");
+
+    outln!(out);
+    if report.items.is_empty() {
+        outln!(out, "Nothing is overdue.");
+        return Ok(());
+    }
+
+    table_header(
+        out,
+        &["Kind", "ID", "Project", "Name", "Due", "Days Overdue"],
+    );
+    for item in &report.items {
+        table_row(
+            out,
+            &[
+                item.kind.to_string(),
+                item.id.to_string(),
+                item.project_id.to_string(),
+                item.name.clone(),
+                item.due_date.to_string(),
+                item.days_overdue.to_string(),
+            ],
+        );
+    }
+
+    Ok(())
+}
+
+/// Handle milestone slip report command
+async fn handle_milestone_slip(
+    out: &dyn OutputSink,
+    service: ReportService,
+    csv: ReportCsvArgs,
+    format: OutputFormat,
+) -> Result<()> {
+    let today = Local::now().date_naive();
+    let report = service.generate_milestone_slip_report(today).await?;
+    if write_csv(out, &csv, &report)? {
+        return Ok(());
+    }
+
+    if format.is_structured() {
+        return output_structured(out, format, &report);
+    }
+
+    section_header(out, "MILESTONE SLIP REPORT");
+
+    outln!(out);
+    if report.items.is_empty() {
+        outln!(out, "No milestone has slipped.");
+        return Ok(());
+    }
+
+    key_value(
+        out,
+        "Average Slip",
+        &format!("{:.1} days", report.average_slip_days),
+    );
+    key_value(out, "Max Slip", &format!("{} days", report.max_slip_days));
+    outln!(out);
+
+    table_header(
+        out,
+        &[
+            "ID",
+            "Project",
+            "Milestone",
+            "Target",
+            "Actual",
+            "Slip (days)",
+        ],
+    );
+    for item in &report.items {
+        table_row(
+            out,
+            &[
+                item.milestone_id.to_string(),
+                item.project_id.to_string(),
+                item.name.clone(),
+                item.target_date.to_string(),
+                match item.actual_date {
+                    Some(date) => date.to_string(),
+                    None => "open".to_string(),
+                },
+                if item.projected {
+                    format!("{} (projected)", item.slip_days)
+                } else {
+                    item.slip_days.to_string()
+                },
+            ],
+        );
+    }
+
+    Ok(())
+}
+
+/// Handle overdue-by-assignee report command
+async fn handle_overdue_by_assignee(
+    out: &dyn OutputSink,
+    service: ReportService,
+    format: OutputFormat,
+) -> Result<()> {
+    let reports = service.generate_overdue_by_assignee().await?;
+
+    if format.is_structured() {
+        return output_structured(out, format, &reports);
+    }
+
+    section_header(out, "OVERDUE TASKS BY ASSIGNEE");
+
+    outln!(out);
+    if reports.is_empty() {
+        outln!(out, "Nothing is overdue.");
+        return Ok(());
+    }
+
+    for report in &reports {
+        outln!(
+            out,
+            "{} ({} overdue)",
+            report.assignee,
+            report.overdue_count
+        );
+        for (id, title, due_date) in &report.tasks {
+            outln!(
+                out,
+                "  #{:<6} {}  due {}",
+                id,
+                title,
+                due_date.with_timezone(&Local).format("%Y-%m-%d")
+            );
+        }
+        outln!(out);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_section_path_sits_next_to_report() {
+        assert_eq!(
+            section_path(Path::new("out/usage.csv"), "most_used_resources"),
+            PathBuf::from("out/usage_most_used_resources.csv")
+        );
+        assert_eq!(
+            section_path(Path::new("usage"), "trend"),
+            PathBuf::from("usage_trend.csv")
+        );
+    }
+}
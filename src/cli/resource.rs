@@ -5,47 +5,77 @@ use std::sync::Arc;
 use uuid::Uuid;
 
 use super::commands::{
-    CreateResourceArgs, DeleteResourceArgs, LinkResourceArgs, ListResourceArgs, OutputFormat,
-    ResourceCommand, ShowResourceArgs, UnlinkResourceArgs, UpdateResourceArgs, UsageResourceArgs,
+    CreateResourceArgs, DeleteResourceArgs, DeprecateResourceArgs, LicenseReportArgs,
+    LinkResourceArgs, ListResourceArgs, OutputFormat, ResourceCommand, ResourceDependencyArgs,
+    ResourceDepsArgs, RestoreResourceArgs, ShowResourceArgs, UnlinkResourceArgs,
+    UpdateResourceArgs, UsageResourceArgs,
 };
-use super::output::{confirm, empty_state, section_title, summary_line};
-use crate::config::settings::Settings;
+use super::config::list_preferences;
+use super::context::AppContext;
+use super::output::{
+    self, confirm, empty_list, empty_state, outln, output_structured, section_title, summary_line,
+    OutputSink, PaginatedOutput,
+};
+use crate::domain::project::entity::TagMatchMode;
 use crate::domain::resource::{
     entity::{
-        CreateResource, LinkResourceToProject, ResourceFilter, ResourceStatus, ResourceType,
-        UpdateResource,
+        CreateResource, CreateResourceDependency, CriticalDeprecatedResource, LicenseReport,
+        LinkResourceToProject, ResourceFilter, ResourceStatus, ResourceType, ResourceUsageLink,
+        UpdateResource, VersionBump,
     },
     service::ResourceService,
 };
-use crate::infrastructure::{database, repositories::resource_repo::PostgresResourceRepository};
+use crate::infrastructure::repositories::resource_repo::{
+    PostgresResourceDependencyRepository, PostgresResourceRepository,
+};
 use crate::utils::error::DevErpError;
+use crate::utils::formatter;
+use crate::utils::retry::retry_transient;
 use crate::Result;
 
 /// Handle resource commands
-pub async fn handle(command: ResourceCommand, _format: OutputFormat) -> Result<()> {
+pub async fn handle(
+    ctx: &AppContext,
+    command: ResourceCommand,
+    format: OutputFormat,
+) -> Result<()> {
     match command {
-        ResourceCommand::Create(args) => handle_create(args).await,
-        ResourceCommand::List(args) => handle_list(args).await,
-        ResourceCommand::Show(args) => handle_show(args).await,
-        ResourceCommand::Update(args) => handle_update(args).await,
-        ResourceCommand::Delete(args) => handle_delete(args).await,
-        ResourceCommand::Link(args) => handle_link(args).await,
-        ResourceCommand::Unlink(args) => handle_unlink(args).await,
-        ResourceCommand::Usage(args) => handle_usage(args).await,
+        ResourceCommand::Create(args) => handle_create(ctx, args).await,
+        ResourceCommand::List(args) => handle_list(ctx, args, format).await,
+        ResourceCommand::Show(args) => handle_show(ctx, args, format).await,
+        ResourceCommand::Update(args) => handle_update(ctx, args).await,
+        ResourceCommand::Delete(args) => handle_delete(ctx, args).await,
+        ResourceCommand::Restore(args) => handle_restore(ctx, args).await,
+        ResourceCommand::Link(args) => handle_link(ctx, args).await,
+        ResourceCommand::Unlink(args) => handle_unlink(ctx, args).await,
+        ResourceCommand::Usage(args) => handle_usage(ctx, args, format).await,
+        ResourceCommand::Deprecate(args) => handle_deprecate(ctx, args).await,
+        ResourceCommand::AddDependency(args) => handle_add_dependency(ctx, args).await,
+        ResourceCommand::RemoveDependency(args) => handle_remove_dependency(ctx, args).await,
+        ResourceCommand::Deps(args) => handle_deps(ctx, args, format).await,
+        ResourceCommand::RiskReport => handle_risk_report(ctx, format).await,
+        ResourceCommand::LicenseReport(args) => handle_license_report(ctx, args, format).await,
     }
 }
 
-/// Create database connection and resource service
-async fn create_service() -> Result<ResourceService> {
-    let settings = Settings::default();
-    let pool = database::establish_connection(&settings.database).await?;
-    let repository = Arc::new(PostgresResourceRepository::new(pool));
-    Ok(ResourceService::new(repository))
+/// Create the resource service on the shared pool
+fn create_service(ctx: &AppContext) -> ResourceService {
+    let pool = ctx.pool();
+    let repository = Arc::new(PostgresResourceRepository::new(pool.clone()));
+    let dependency_repo = Arc::new(PostgresResourceDependencyRepository::new(pool));
+    ResourceService::new(repository, dependency_repo)
+}
+
+/// Whether the `resource.unique_name_version` guard is enabled
+async fn configured_unique_name_version(ctx: &AppContext) -> Result<bool> {
+    ctx.config_service().resource_unique_name_version().await
 }
 
 /// Handle resource create command
-async fn handle_create(args: CreateResourceArgs) -> Result<()> {
-    let service = create_service().await?;
+async fn handle_create(ctx: &AppContext, args: CreateResourceArgs) -> Result<()> {
+    let out = ctx.out();
+    let unique_name_version = !args.allow_duplicate && configured_unique_name_version(ctx).await?;
+    let service = create_service(ctx).with_unique_name_version(unique_name_version);
 
     // Parse resource type
     let resource_type = args
@@ -87,25 +117,33 @@ async fn handle_create(args: CreateResourceArgs) -> Result<()> {
         tags,
     };
 
+    if ctx.dry_run() {
+        return output::dry_run_input(out, "create this resource", &input);
+    }
+
     // Create resource
-    let resource = service.create_resource(input).await?;
+    let resource = retry_transient(args.retry.attempts, || {
+        service.create_resource(input.clone())
+    })
+    .await?;
 
     // Display success message
-    section_title("Resource Created");
-    println!("{}: {}", "ID".bright_cyan(), resource.id);
-    println!("{}: {}", "UUID".bright_cyan(), resource.uuid);
-    println!("{}: {}", "Name".bright_cyan(), resource.name.bold());
-    println!("{}: {}", "Type".bright_cyan(), resource.resource_type);
+    section_title(out, "Resource Created");
+    outln!(out, "{}: {}", "ID".bright_cyan(), resource.id);
+    outln!(out, "{}: {}", "UUID".bright_cyan(), resource.uuid);
+    outln!(out, "{}: {}", "Name".bright_cyan(), resource.name.bold());
+    outln!(out, "{}: {}", "Type".bright_cyan(), resource.resource_type);
     if let Some(desc) = &resource.description {
-        println!("{}: {}", "Description".bright_cyan(), desc);
+        outln!(out, "{}: {}", "Description".bright_cyan(), desc);
     }
     if let Some(version) = &resource.version {
-        println!("{}: {}", "Version".bright_cyan(), version);
+        outln!(out, "{}: {}", "Version".bright_cyan(), version);
     }
     if let Some(url) = &resource.url {
-        println!("{}: {}", "URL".bright_cyan(), url);
+        outln!(out, "{}: {}", "URL".bright_cyan(), url);
     }
-    println!(
+    outln!(
+        out,
         "{}: {}",
         "Created".bright_cyan(),
         resource.created_at.format("%Y-%m-%d %H:%M:%S")
@@ -115,8 +153,12 @@ async fn handle_create(args: CreateResourceArgs) -> Result<()> {
 }
 
 /// Handle resource list command
-async fn handle_list(args: ListResourceArgs) -> Result<()> {
-    let service = create_service().await?;
+async fn handle_list(ctx: &AppContext, args: ListResourceArgs, format: OutputFormat) -> Result<()> {
+    let out = ctx.out();
+    let service = create_service(ctx);
+    let pagination = args
+        .pagination
+        .or_per_page(list_preferences(ctx).await?.per_page);
 
     // Parse resource type if provided
     let resource_type = if let Some(type_str) = args.resource_type {
@@ -155,32 +197,50 @@ async fn handle_list(args: ListResourceArgs) -> Result<()> {
         status,
         name_contains: args.search,
         tags,
-        offset: Some(args.pagination.offset()),
-        limit: Some(args.pagination.limit()),
+        tag_match_mode: if args.tags_all {
+            TagMatchMode::All
+        } else {
+            TagMatchMode::Any
+        },
+        stale_days: args.stale,
+        offset: Some(pagination.offset()),
+        limit: Some(pagination.limit()),
     };
 
+    if format == OutputFormat::Count {
+        return output::count(out, service.count_resources(filter).await?);
+    }
+
     // Get resources
-    let resources = service.list_resources(filter).await?;
+    let resources = service.list_resources(filter.clone()).await?;
 
     if resources.is_empty() {
-        empty_state("No resources found");
+        let total = service.count_resources(ResourceFilter::default()).await?;
+        empty_list(
+            out,
+            "resources",
+            total,
+            "deverp resource create --name <NAME> --resource-type <TYPE>",
+        );
         return Ok(());
     }
 
     // Display resources
-    section_title(&format!("Resources ({})", resources.len()));
-    println!();
+    section_title(out, &format!("Resources ({})", resources.len()));
+    outln!(out);
 
-    for resource in resources {
-        println!("  {} {}", "●".bright_green(), resource.name.bold());
-        println!(
+    for resource in &resources {
+        outln!(out, "  {} {}", "●".bright_green(), resource.name.bold());
+        outln!(
+            out,
             "    {}: {} | {}: {}",
             "ID".dimmed(),
             resource.id,
             "UUID".dimmed(),
             resource.uuid
         );
-        println!(
+        outln!(
+            out,
             "    {}: {} | {}: {}",
             "Type".dimmed(),
             resource.resource_type,
@@ -192,7 +252,7 @@ async fn handle_list(args: ListResourceArgs) -> Result<()> {
                 .unwrap_or_else(|| "active".to_string())
         );
         if let Some(version) = &resource.version {
-            println!("    {}: {}", "Version".dimmed(), version);
+            outln!(out, "    {}: {}", "Version".dimmed(), version);
         }
         if let Some(desc) = &resource.description {
             let short_desc = if desc.len() > 60 {
@@ -200,17 +260,23 @@ async fn handle_list(args: ListResourceArgs) -> Result<()> {
             } else {
                 desc.clone()
             };
-            println!("    {}: {}", "Description".dimmed(), short_desc);
+            outln!(out, "    {}: {}", "Description".dimmed(), short_desc);
         }
-        println!();
+        outln!(out);
     }
 
+    // Show pagination info
+    let total = service.count_resources(filter).await?;
+    let output = PaginatedOutput::new(resources, pagination.page, pagination.per_page(), total);
+    output.print_metadata(out);
+
     Ok(())
 }
 
 /// Handle resource show command
-async fn handle_show(args: ShowResourceArgs) -> Result<()> {
-    let service = create_service().await?;
+async fn handle_show(ctx: &AppContext, args: ShowResourceArgs, format: OutputFormat) -> Result<()> {
+    let out = ctx.out();
+    let service = create_service(ctx);
 
     // Try to parse as UUID first, otherwise as ID
     let resource = if let Ok(uuid) = args.identifier.parse::<Uuid>() {
@@ -225,14 +291,28 @@ async fn handle_show(args: ShowResourceArgs) -> Result<()> {
         service.get_resource(id).await?
     };
 
+    let record = if args.full {
+        Some(service.get_resource_record(resource.id).await?)
+    } else {
+        None
+    };
+
+    if format.is_structured() {
+        return match record {
+            Some(record) => output_structured(out, format, &record),
+            None => output_structured(out, format, &resource),
+        };
+    }
+
     // Display resource details
-    section_title("Resource Details");
-    println!();
-    println!("{}: {}", "ID".bright_cyan(), resource.id);
-    println!("{}: {}", "UUID".bright_cyan(), resource.uuid);
-    println!("{}: {}", "Name".bright_cyan(), resource.name.bold());
-    println!("{}: {}", "Type".bright_cyan(), resource.resource_type);
-    println!(
+    section_title(out, "Resource Details");
+    outln!(out);
+    outln!(out, "{}: {}", "ID".bright_cyan(), resource.id);
+    outln!(out, "{}: {}", "UUID".bright_cyan(), resource.uuid);
+    outln!(out, "{}: {}", "Name".bright_cyan(), resource.name.bold());
+    outln!(out, "{}: {}", "Type".bright_cyan(), resource.resource_type);
+    outln!(
+        out,
         "{}: {}",
         "Status".bright_cyan(),
         resource
@@ -243,56 +323,83 @@ async fn handle_show(args: ShowResourceArgs) -> Result<()> {
     );
 
     if let Some(desc) = &resource.description {
-        println!();
-        println!("{}:", "Description".bright_cyan());
-        println!("  {}", desc);
+        outln!(out);
+        outln!(out, "{}:", "Description".bright_cyan());
+        outln!(out, "  {}", desc);
     }
 
     if let Some(version) = &resource.version {
-        println!();
-        println!("{}: {}", "Version".bright_cyan(), version);
+        outln!(out);
+        outln!(out, "{}: {}", "Version".bright_cyan(), version);
     }
 
     if let Some(url) = &resource.url {
-        println!("{}: {}", "URL".bright_cyan(), url);
+        outln!(out, "{}: {}", "URL".bright_cyan(), url);
     }
 
     if let Some(doc_url) = &resource.documentation_url {
-        println!("{}: {}", "Documentation URL".bright_cyan(), doc_url);
+        outln!(out, "{}: {}", "Documentation URL".bright_cyan(), doc_url);
     }
 
     if let Some(license) = &resource.license {
-        println!("{}: {}", "License".bright_cyan(), license);
+        outln!(out, "{}: {}", "License".bright_cyan(), license);
     }
 
     if let Some(tags) = &resource.tags {
         if !tags.is_empty() {
-            println!();
-            println!("{}:", "Tags".bright_cyan());
+            outln!(out);
+            outln!(out, "{}:", "Tags".bright_cyan());
             for tag in tags {
-                println!("  - {}", tag);
+                outln!(out, "  - {}", tag);
             }
         }
     }
 
-    println!();
-    println!(
+    outln!(out);
+    outln!(
+        out,
         "{}: {}",
         "Created".bright_cyan(),
         resource.created_at.format("%Y-%m-%d %H:%M:%S")
     );
-    println!(
+    outln!(
+        out,
         "{}: {}",
         "Updated".bright_cyan(),
         resource.updated_at.format("%Y-%m-%d %H:%M:%S")
     );
 
+    if let Some(record) = record {
+        outln!(out);
+        outln!(
+            out,
+            "{}: {} projects ({} critical)",
+            "Usage".bright_cyan(),
+            record.usage.total_projects,
+            record.usage.critical_projects
+        );
+        for link in &record.projects {
+            outln!(
+                out,
+                "  - #{} {}{}{}",
+                link.project_id,
+                link.project_name,
+                link.version_used
+                    .as_deref()
+                    .map(|v| format!(" @ {}", v))
+                    .unwrap_or_default(),
+                if link.is_critical { " (critical)" } else { "" }
+            );
+        }
+    }
+
     Ok(())
 }
 
 /// Handle resource update command
-async fn handle_update(args: UpdateResourceArgs) -> Result<()> {
-    let service = create_service().await?;
+async fn handle_update(ctx: &AppContext, args: UpdateResourceArgs) -> Result<()> {
+    let out = ctx.out();
+    let service = create_service(ctx);
 
     // Try to parse as UUID first, otherwise as ID
     let id = if let Ok(uuid) = args.identifier.parse::<Uuid>() {
@@ -338,13 +445,23 @@ async fn handle_update(args: UpdateResourceArgs) -> Result<()> {
             .collect()
     });
 
+    // Bump the stored version if requested
+    let version = if let Some(bump_str) = args.bump_version {
+        let bump = bump_str
+            .parse::<VersionBump>()
+            .map_err(DevErpError::Validation)?;
+        Some(service.next_version(id, bump).await?)
+    } else {
+        args.version
+    };
+
     // Create update input
     let input = UpdateResource {
         id,
         name: args.name,
         description: args.description,
         resource_type,
-        version: args.version,
+        version,
         url: args.url,
         documentation_url: args.documentation_url,
         license: args.license,
@@ -353,14 +470,25 @@ async fn handle_update(args: UpdateResourceArgs) -> Result<()> {
         tags,
     };
 
+    if ctx.dry_run() {
+        return output::dry_run_input(out, &format!("update resource #{}", id), &input);
+    }
+
     // Update resource
-    let resource = service.update_resource(input).await?;
+    let resource = retry_transient(args.retry.attempts, || {
+        service.update_resource(input.clone())
+    })
+    .await?;
 
     // Display success message
-    section_title("Resource Updated");
-    println!("{}: {}", "ID".bright_cyan(), resource.id);
-    println!("{}: {}", "Name".bright_cyan(), resource.name.bold());
-    println!(
+    section_title(out, "Resource Updated");
+    outln!(out, "{}: {}", "ID".bright_cyan(), resource.id);
+    outln!(out, "{}: {}", "Name".bright_cyan(), resource.name.bold());
+    if let Some(ref version) = resource.version {
+        outln!(out, "{}: {}", "Version".bright_cyan(), version);
+    }
+    outln!(
+        out,
         "{}: {}",
         "Updated".bright_cyan(),
         resource.updated_at.format("%Y-%m-%d %H:%M:%S")
@@ -370,8 +498,9 @@ async fn handle_update(args: UpdateResourceArgs) -> Result<()> {
 }
 
 /// Handle resource delete command
-async fn handle_delete(args: DeleteResourceArgs) -> Result<()> {
-    let service = create_service().await?;
+async fn handle_delete(ctx: &AppContext, args: DeleteResourceArgs) -> Result<()> {
+    let out = ctx.out();
+    let service = create_service(ctx);
 
     // Try to parse as UUID first, otherwise as ID
     let id = if let Ok(uuid) = args.identifier.parse::<Uuid>() {
@@ -389,6 +518,18 @@ async fn handle_delete(args: DeleteResourceArgs) -> Result<()> {
     // Get resource to display name
     let resource = service.get_resource(id).await?;
 
+    if ctx.dry_run() {
+        output::dry_run(out, &format!("delete resource '{}'", resource.name));
+        summary_line(out, "ID", &resource.id.to_string());
+        summary_line(out, "Name", &resource.name);
+        summary_line(out, "Type", &resource.resource_type.to_string());
+        if let Some(ref version) = resource.version {
+            summary_line(out, "Version", version);
+        }
+        outln!(out);
+        return Ok(());
+    }
+
     // Confirm deletion
     if !args.confirm {
         let confirmed = confirm(&format!(
@@ -396,15 +537,16 @@ async fn handle_delete(args: DeleteResourceArgs) -> Result<()> {
             resource.name
         ));
         if !confirmed {
-            println!("Deletion cancelled.");
+            outln!(out, "Deletion cancelled.");
             return Ok(());
         }
     }
 
     // Delete resource
-    service.delete_resource(id).await?;
+    retry_transient(args.retry.attempts, || service.delete_resource(id)).await?;
 
     summary_line(
+        out,
         "Resource Deleted",
         &format!("'{}' deleted successfully", resource.name),
     );
@@ -412,9 +554,119 @@ async fn handle_delete(args: DeleteResourceArgs) -> Result<()> {
     Ok(())
 }
 
+/// Handle resource restore command
+async fn handle_restore(ctx: &AppContext, args: RestoreResourceArgs) -> Result<()> {
+    let out = ctx.out();
+    let service = create_service(ctx);
+
+    if ctx.dry_run() {
+        output::dry_run(out, &format!("restore resource #{}", args.id));
+        return Ok(());
+    }
+
+    service.restore_resource(args.id).await?;
+
+    let resource = service.get_resource(args.id).await?;
+    summary_line(
+        out,
+        "Resource Restored",
+        &format!("'{}' restored successfully", resource.name),
+    );
+
+    Ok(())
+}
+
+/// Handle resource deprecate command
+async fn handle_deprecate(ctx: &AppContext, args: DeprecateResourceArgs) -> Result<()> {
+    let out = ctx.out();
+    let service = create_service(ctx);
+
+    if let Some(tag) = args.tag {
+        if ctx.dry_run() {
+            output::dry_run(out, &format!("deprecate the resources tagged '{}'", tag));
+            return Ok(());
+        }
+
+        let result = service
+            .deprecate_resources_by_tag(&tag, args.reason)
+            .await?;
+
+        if result.deprecated.is_empty() && result.already_deprecated == 0 {
+            empty_state(out, &format!("resources tagged '{}'", tag));
+            return Ok(());
+        }
+
+        outln!(
+            out,
+            "{} Deprecated {} resource(s) tagged '{}'",
+            "✓".green().bold(),
+            result.deprecated.len(),
+            tag
+        );
+        for resource in &result.deprecated {
+            outln!(out, "  - {} (ID: {})", resource.name, resource.id);
+        }
+        if result.already_deprecated > 0 {
+            summary_line(
+                out,
+                "Already Deprecated",
+                &result.already_deprecated.to_string(),
+            );
+        }
+
+        if !result.affected_links.is_empty() {
+            outln!(out);
+            outln!(out, "{}", "Affected projects:".bold());
+            for link in &result.affected_links {
+                let critical = if link.is_critical { " [critical]" } else { "" };
+                outln!(
+                    out,
+                    "  - {} (ID: {}) uses {}{}",
+                    link.project_name,
+                    link.project_id,
+                    link.resource_name,
+                    critical
+                );
+            }
+        }
+
+        return Ok(());
+    }
+
+    // Clap guarantees an identifier when no tag was given
+    let identifier = args.identifier.unwrap_or_default();
+    let id = if let Ok(uuid) = identifier.parse::<Uuid>() {
+        service.get_resource_by_uuid(uuid).await?.id
+    } else {
+        identifier.parse::<i64>().map_err(|_| {
+            DevErpError::Validation(format!(
+                "Invalid resource identifier: {}. Must be a valid ID or UUID",
+                identifier
+            ))
+        })?
+    };
+
+    if ctx.dry_run() {
+        let resource = service.get_resource(id).await?;
+        output::dry_run(out, &format!("deprecate resource '{}'", resource.name));
+        return Ok(());
+    }
+
+    let resource = service.deprecate_resource(id, args.reason).await?;
+
+    summary_line(
+        out,
+        "Resource Deprecated",
+        &format!("'{}' is now deprecated", resource.name),
+    );
+
+    Ok(())
+}
+
 /// Handle resource link command
-async fn handle_link(args: LinkResourceArgs) -> Result<()> {
-    let service = create_service().await?;
+async fn handle_link(ctx: &AppContext, args: LinkResourceArgs) -> Result<()> {
+    let out = ctx.out();
+    let service = create_service(ctx);
 
     // Create link input
     let input = LinkResourceToProject {
@@ -425,29 +677,43 @@ async fn handle_link(args: LinkResourceArgs) -> Result<()> {
         is_critical: Some(args.is_critical),
     };
 
+    if ctx.dry_run() {
+        return output::dry_run_input(
+            out,
+            &format!(
+                "link resource #{} to project #{}",
+                input.resource_id, input.project_id
+            ),
+            &input,
+        );
+    }
+
     // Link resource to project
     let project_resource = service.link_resource_to_project(input).await?;
 
     // Display success message
-    section_title("Resource Linked to Project");
-    println!(
+    section_title(out, "Resource Linked to Project");
+    outln!(
+        out,
         "{}: {}",
         "Project ID".bright_cyan(),
         project_resource.project_id
     );
-    println!(
+    outln!(
+        out,
         "{}: {}",
         "Resource ID".bright_cyan(),
         project_resource.resource_id
     );
     if let Some(notes) = &project_resource.usage_notes {
-        println!("{}: {}", "Usage Notes".bright_cyan(), notes);
+        outln!(out, "{}: {}", "Usage Notes".bright_cyan(), notes);
     }
     if let Some(version) = &project_resource.version_used {
-        println!("{}: {}", "Version Used".bright_cyan(), version);
+        outln!(out, "{}: {}", "Version Used".bright_cyan(), version);
     }
     if let Some(critical) = project_resource.is_critical {
-        println!(
+        outln!(
+            out,
             "{}: {}",
             "Critical".bright_cyan(),
             if critical { "Yes" } else { "No" }
@@ -458,8 +724,20 @@ async fn handle_link(args: LinkResourceArgs) -> Result<()> {
 }
 
 /// Handle resource unlink command
-async fn handle_unlink(args: UnlinkResourceArgs) -> Result<()> {
-    let service = create_service().await?;
+async fn handle_unlink(ctx: &AppContext, args: UnlinkResourceArgs) -> Result<()> {
+    let out = ctx.out();
+    let service = create_service(ctx);
+
+    if ctx.dry_run() {
+        output::dry_run(
+            out,
+            &format!(
+                "unlink resource #{} from project #{}",
+                args.resource_id, args.project_id
+            ),
+        );
+        return Ok(());
+    }
 
     // Unlink resource from project
     service
@@ -467,6 +745,7 @@ async fn handle_unlink(args: UnlinkResourceArgs) -> Result<()> {
         .await?;
 
     summary_line(
+        out,
         "Resource Unlinked",
         &format!(
             "Resource {} unlinked from project {}",
@@ -477,24 +756,322 @@ async fn handle_unlink(args: UnlinkResourceArgs) -> Result<()> {
     Ok(())
 }
 
+/// Handle resource add-dependency command
+async fn handle_add_dependency(ctx: &AppContext, args: ResourceDependencyArgs) -> Result<()> {
+    let out = ctx.out();
+    let service = create_service(ctx);
+
+    if ctx.dry_run() {
+        output::dry_run(
+            out,
+            &format!(
+                "make resource #{} depend on resource #{}",
+                args.resource_id, args.depends_on
+            ),
+        );
+        return Ok(());
+    }
+
+    service
+        .add_resource_dependency(CreateResourceDependency {
+            resource_id: args.resource_id,
+            depends_on_resource_id: args.depends_on,
+        })
+        .await?;
+
+    summary_line(
+        out,
+        "Dependency Added",
+        &format!(
+            "Resource {} now depends on resource {}",
+            args.resource_id, args.depends_on
+        ),
+    );
+
+    Ok(())
+}
+
+/// Handle resource remove-dependency command
+async fn handle_remove_dependency(ctx: &AppContext, args: ResourceDependencyArgs) -> Result<()> {
+    let out = ctx.out();
+    let service = create_service(ctx);
+
+    if ctx.dry_run() {
+        output::dry_run(
+            out,
+            &format!(
+                "remove the dependency of resource #{} on resource #{}",
+                args.resource_id, args.depends_on
+            ),
+        );
+        return Ok(());
+    }
+
+    service
+        .remove_resource_dependency(args.resource_id, args.depends_on)
+        .await?;
+
+    summary_line(
+        out,
+        "Dependency Removed",
+        &format!(
+            "Resource {} no longer depends on resource {}",
+            args.resource_id, args.depends_on
+        ),
+    );
+
+    Ok(())
+}
+
+/// Handle resource deps command
+async fn handle_deps(ctx: &AppContext, args: ResourceDepsArgs, format: OutputFormat) -> Result<()> {
+    let out = ctx.out();
+    let service = create_service(ctx);
+    let dependencies = service.get_resource_dependencies(args.resource_id).await?;
+
+    if format.is_structured() {
+        return output_structured(out, format, &dependencies);
+    }
+
+    if dependencies.is_empty() {
+        outln!(out, "{}", "This resource has no dependencies.".yellow());
+        return Ok(());
+    }
+
+    section_title(
+        out,
+        &format!(
+            "Dependencies of Resource {} ({})",
+            args.resource_id,
+            dependencies.len()
+        ),
+    );
+    outln!(out);
+    for resource in &dependencies {
+        outln!(
+            out,
+            "  {} #{} {}{} ({})",
+            "→".bright_cyan(),
+            resource.id,
+            resource.name.bold(),
+            resource
+                .version
+                .as_deref()
+                .map(|v| format!(" {}", v))
+                .unwrap_or_default(),
+            resource.resource_type
+        );
+    }
+
+    Ok(())
+}
+
+/// Handle resource risk-report command
+async fn handle_risk_report(ctx: &AppContext, format: OutputFormat) -> Result<()> {
+    let out = ctx.out();
+    let service = create_service(ctx);
+    let risks = service.find_critical_deprecated().await?;
+
+    if format.is_structured() {
+        return output_structured(out, format, &risks);
+    }
+    if format == OutputFormat::Csv {
+        output::csv(out, &RISK_REPORT_HEADERS, &risk_report_rows(&risks));
+        return Ok(());
+    }
+
+    if risks.is_empty() {
+        outln!(
+            out,
+            "{}",
+            "No deprecated resources are marked critical by any project.".green()
+        );
+        return Ok(());
+    }
+
+    section_title(
+        out,
+        &format!("Critical Deprecated Resources ({})", risks.len()),
+    );
+    outln!(out);
+    output::table_header(out, &["ID", "Resource", "Affected Projects"]);
+    for row in risk_report_rows(&risks) {
+        output::table_row(out, &row);
+    }
+    outln!(out);
+    outln!(
+        out,
+        "{}",
+        "Plan a migration for these resources before they are archived.".yellow()
+    );
+
+    Ok(())
+}
+
+/// Column headers for the risk report CSV
+const RISK_REPORT_HEADERS: [&str; 3] = ["resource_id", "resource", "affected_project_ids"];
+
+/// One row per resource, affected project IDs joined by spaces
+fn risk_report_rows(risks: &[CriticalDeprecatedResource]) -> Vec<Vec<String>> {
+    risks
+        .iter()
+        .map(|risk| {
+            vec![
+                risk.resource_id.to_string(),
+                risk.resource_name.clone(),
+                risk.affected_project_ids
+                    .iter()
+                    .map(|id| id.to_string())
+                    .collect::<Vec<_>>()
+                    .join(" "),
+            ]
+        })
+        .collect()
+}
+
+/// Handle resource license-report command
+async fn handle_license_report(
+    ctx: &AppContext,
+    args: LicenseReportArgs,
+    format: OutputFormat,
+) -> Result<()> {
+    let out = ctx.out();
+    let allowed = ctx.config_service().resource_allowed_licenses().await?;
+    let service = create_service(ctx).with_allowed_licenses(allowed);
+    let report = service.generate_license_report().await?;
+
+    if let Some(path) = args.csv {
+        let csv = formatter::to_csv(&LICENSE_REPORT_HEADERS, &license_report_rows(&report));
+        std::fs::write(&path, csv)?;
+        output::success(out, &format!("License report written to {}", path));
+        return Ok(());
+    }
+    if format.is_structured() {
+        return output_structured(out, format, &report);
+    }
+    if format == OutputFormat::Csv {
+        output::csv(out, &LICENSE_REPORT_HEADERS, &license_report_rows(&report));
+        return Ok(());
+    }
+
+    if report.groups.is_empty() {
+        outln!(out, "{}", "No resources recorded yet.".dimmed());
+        return Ok(());
+    }
+
+    section_title(out, &format!("Licenses ({})", report.groups.len()));
+    for group in &report.groups {
+        outln!(out);
+        if group.compliant {
+            outln!(out, "  {}", group.license.bold());
+        } else {
+            outln!(
+                out,
+                "  {} {}",
+                group.license.bold(),
+                "(non-compliant)".red().bold()
+            );
+        }
+        for resource in &group.resources {
+            let name = match resource.version {
+                Some(ref version) => format!("{} {}", resource.resource_name, version),
+                None => resource.resource_name.clone(),
+            };
+            let projects = if resource.project_names.is_empty() {
+                "unused".dimmed().to_string()
+            } else {
+                resource.project_names.join(", ")
+            };
+            outln!(out, "    {} - {}", name, projects);
+        }
+    }
+    outln!(out);
+
+    if report.non_compliant_resources > 0 {
+        outln!(
+            out,
+            "{}",
+            format!(
+                "{} resource(s) use a license outside resource.allowed_licenses.",
+                report.non_compliant_resources
+            )
+            .yellow()
+        );
+    }
+
+    Ok(())
+}
+
+/// Column headers for the license report CSV
+const LICENSE_REPORT_HEADERS: [&str; 6] = [
+    "license",
+    "compliant",
+    "resource_id",
+    "resource",
+    "version",
+    "projects",
+];
+
+/// One row per resource, project names joined by semicolons
+fn license_report_rows(report: &LicenseReport) -> Vec<Vec<String>> {
+    report
+        .groups
+        .iter()
+        .flat_map(|group| {
+            group.resources.iter().map(move |resource| {
+                vec![
+                    group.license.clone(),
+                    group.compliant.to_string(),
+                    resource.resource_id.to_string(),
+                    resource.resource_name.clone(),
+                    resource.version.clone().unwrap_or_default(),
+                    resource.project_names.join("; "),
+                ]
+            })
+        })
+        .collect()
+}
+
 /// Handle resource usage command
-async fn handle_usage(args: UsageResourceArgs) -> Result<()> {
-    let service = create_service().await?;
+async fn handle_usage(
+    ctx: &AppContext,
+    args: UsageResourceArgs,
+    format: OutputFormat,
+) -> Result<()> {
+    let out = ctx.out();
+    let service = create_service(ctx);
+
+    if args.all {
+        let links = service.get_usage_matrix().await?;
+        return print_usage_matrix(out, &links, format);
+    }
 
     if let Some(resource_id) = args.resource_id {
         // Get usage for specific resource
         let stats = service.get_resource_usage(resource_id).await?;
 
-        section_title(&format!("Resource Usage: {}", stats.resource_name));
-        println!();
-        println!("{}: {}", "Resource ID".bright_cyan(), stats.resource_id);
-        println!("{}: {}", "Resource Type".bright_cyan(), stats.resource_type);
-        println!(
+        section_title(out, &format!("Resource Usage: {}", stats.resource_name));
+        outln!(out);
+        outln!(
+            out,
+            "{}: {}",
+            "Resource ID".bright_cyan(),
+            stats.resource_id
+        );
+        outln!(
+            out,
+            "{}: {}",
+            "Resource Type".bright_cyan(),
+            stats.resource_type
+        );
+        outln!(
+            out,
             "{}: {}",
             "Total Projects".bright_cyan(),
             stats.total_projects
         );
-        println!(
+        outln!(
+            out,
             "{}: {}",
             "Critical Projects".bright_cyan(),
             stats.critical_projects
@@ -504,32 +1081,187 @@ async fn handle_usage(args: UsageResourceArgs) -> Result<()> {
         let all_stats = service.get_all_resource_usage().await?;
 
         if all_stats.is_empty() {
-            empty_state("No resources found");
+            empty_state(out, "No resources found");
             return Ok(());
         }
 
-        section_title(&format!("Resource Usage Statistics ({})", all_stats.len()));
-        println!();
+        section_title(
+            out,
+            &format!("Resource Usage Statistics ({})", all_stats.len()),
+        );
+        outln!(out);
 
         for stats in all_stats {
-            println!("  {} {}", "●".bright_green(), stats.resource_name.bold());
-            println!(
+            outln!(
+                out,
+                "  {} {}",
+                "●".bright_green(),
+                stats.resource_name.bold()
+            );
+            outln!(
+                out,
                 "    {}: {} | {}: {}",
                 "ID".dimmed(),
                 stats.resource_id,
                 "Type".dimmed(),
                 stats.resource_type
             );
-            println!(
+            outln!(
+                out,
                 "    {}: {} | {}: {}",
                 "Projects".dimmed(),
                 stats.total_projects,
                 "Critical".dimmed(),
                 stats.critical_projects
             );
-            println!();
+            outln!(out);
         }
     }
 
     Ok(())
 }
+
+/// Column headers for the usage matrix
+const USAGE_MATRIX_HEADERS: [&str; 4] = ["resource", "project", "version_used", "is_critical"];
+
+/// Flatten usage links into matrix rows
+fn usage_matrix_rows(links: &[ResourceUsageLink]) -> Vec<Vec<String>> {
+    links
+        .iter()
+        .map(|link| {
+            vec![
+                link.resource_name.clone(),
+                link.project_name.clone(),
+                link.version_used.clone().unwrap_or_default(),
+                link.is_critical.to_string(),
+            ]
+        })
+        .collect()
+}
+
+/// Print the resource-to-project usage matrix
+fn print_usage_matrix(
+    out: &dyn OutputSink,
+    links: &[ResourceUsageLink],
+    format: OutputFormat,
+) -> Result<()> {
+    match format {
+        OutputFormat::Json | OutputFormat::Yaml => output_structured(out, format, links),
+        OutputFormat::Csv => {
+            output::csv(out, &USAGE_MATRIX_HEADERS, &usage_matrix_rows(links));
+            Ok(())
+        }
+        OutputFormat::Table | OutputFormat::Plain | OutputFormat::Count => {
+            if links.is_empty() {
+                empty_state(out, "resource links");
+                return Ok(());
+            }
+
+            section_title(
+                out,
+                &format!("Resource Usage Matrix ({} links)", links.len()),
+            );
+            outln!(out);
+            output::table_header(out, &["Resource", "Project", "Version Used", "Critical"]);
+            for row in usage_matrix_rows(links) {
+                output::table_row(out, &row);
+            }
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::resource::entity::ResourceLicenseUsage;
+
+    #[test]
+    fn test_usage_matrix_csv_has_row_per_link() {
+        let links = vec![
+            ResourceUsageLink {
+                resource_id: 1,
+                resource_name: "serde".to_string(),
+                project_id: 10,
+                project_name: "Billing, EU".to_string(),
+                version_used: Some("1.0.200".to_string()),
+                is_critical: true,
+            },
+            ResourceUsageLink {
+                resource_id: 1,
+                resource_name: "serde".to_string(),
+                project_id: 11,
+                project_name: "Portal".to_string(),
+                version_used: None,
+                is_critical: false,
+            },
+        ];
+
+        let csv = formatter::to_csv(&USAGE_MATRIX_HEADERS, &usage_matrix_rows(&links));
+        let lines: Vec<&str> = csv.lines().collect();
+
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0], "resource,project,version_used,is_critical");
+        assert_eq!(lines[1], "serde,\"Billing, EU\",1.0.200,true");
+        assert_eq!(lines[2], "serde,Portal,,false");
+    }
+
+    #[test]
+    fn test_license_report_rows_flatten_groups() {
+        let rows = vec![
+            ResourceLicenseUsage {
+                resource_id: 7,
+                resource_name: "openssl".to_string(),
+                version: Some("3.0".to_string()),
+                license: Some("Apache-2.0".to_string()),
+                project_id: Some(1),
+                project_name: Some("Billing".to_string()),
+            },
+            ResourceLicenseUsage {
+                resource_id: 7,
+                resource_name: "openssl".to_string(),
+                version: Some("3.0".to_string()),
+                license: Some("Apache-2.0".to_string()),
+                project_id: Some(2),
+                project_name: Some("Portal, EU".to_string()),
+            },
+            ResourceLicenseUsage {
+                resource_id: 8,
+                resource_name: "vendored".to_string(),
+                version: None,
+                license: None,
+                project_id: None,
+                project_name: None,
+            },
+        ];
+        let report = LicenseReport::build(&rows, &["Apache-2.0".to_string()]);
+
+        let csv = formatter::to_csv(&LICENSE_REPORT_HEADERS, &license_report_rows(&report));
+        let lines: Vec<&str> = csv.lines().collect();
+
+        assert_eq!(
+            lines[0],
+            "license,compliant,resource_id,resource,version,projects"
+        );
+        assert_eq!(
+            lines[1],
+            "Apache-2.0,true,7,openssl,3.0,\"Billing; Portal, EU\""
+        );
+        assert_eq!(lines[2], "(unspecified),false,8,vendored,,");
+    }
+
+    #[test]
+    fn test_risk_report_rows_join_project_ids() {
+        let risks = vec![CriticalDeprecatedResource {
+            resource_id: 4,
+            resource_name: "left-pad".to_string(),
+            affected_project_ids: vec![2, 9],
+        }];
+
+        let csv = formatter::to_csv(&RISK_REPORT_HEADERS, &risk_report_rows(&risks));
+        let lines: Vec<&str> = csv.lines().collect();
+
+        assert_eq!(lines[0], "resource_id,resource,affected_project_ids");
+        assert_eq!(lines[1], "4,left-pad,2 9");
+    }
+}
@@ -2,60 +2,129 @@
 
 use chrono::{DateTime, NaiveDate, Utc};
 use colored::Colorize;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
 use std::sync::Arc;
+use std::time::Duration;
 use uuid::Uuid;
 
 use super::commands::{
-    AddCommentArgs, AddDependencyArgs, CreateTaskArgs, DeleteTaskArgs, ListTaskArgs, OutputFormat,
-    RemoveDependencyArgs, ShowTaskArgs, TaskCommand, UpdateTaskArgs,
+    AddCommentArgs, AddDependencyArgs, AssignTaskArgs, AuditCyclesArgs, CreateTaskArgs,
+    CriticalPathArgs, DeleteTaskArgs, DependencyChainArgs, ImportTaskArgs, ListTaskArgs,
+    LogWorkArgs, MoveTaskArgs, OutputFormat, RemoveDependencyArgs, RestoreCommentArgs,
+    RestoreTaskArgs, RollupTaskArgs, ShowTaskArgs, SortOrder, TaskCommand, TaskGraphArgs,
+    TaskStatsArgs, UpdateTaskArgs, WatchTaskArgs,
+};
+use super::config::list_preferences;
+use super::context::AppContext;
+use super::graph::render_dot;
+use super::output::{
+    self, confirm, empty_list, list_item, outln, output_structured, section_title, summary_line,
+    OutputSink, PaginatedOutput,
 };
-use super::output::{confirm, empty_state, section_title, summary_line, PaginatedOutput};
 use crate::config::settings::Settings;
 use crate::domain::task::{
     entity::{
-        CreateTask, CreateTaskComment, CreateTaskDependency, DependencyType, TaskFilter,
-        TaskPriority, TaskStatus, TaskType, UpdateTask,
+        CompletionCheck, CreateTask, CreateTaskComment, CreateTaskDependency, DependencyType, Task,
+        TaskDetail, TaskFilter, TaskPriority, TaskRollup, TaskSortField, TaskStatus, TaskType,
+        UpdateTask, UpdateTaskResult,
     },
     service::TaskService,
 };
-use crate::infrastructure::{
-    database,
-    repositories::{
-        PostgresTaskCommentRepository, PostgresTaskDependencyRepository, PostgresTaskRepository,
-    },
+use crate::infrastructure::repositories::{
+    PostgresProjectRepository, PostgresTaskAssigneeRepository, PostgresTaskCommentRepository,
+    PostgresTaskDependencyRepository, PostgresTaskRepository, PostgresTaskWorkLogRepository,
 };
 use crate::utils::error::DevErpError;
+use crate::utils::formatter;
+use crate::utils::retry::retry_transient;
+use crate::utils::time::local_day_bounds;
+use crate::utils::watch::watch;
 use crate::Result;
 
+/// Work log entries shown by `task show`
+const RECENT_WORK_LOG_ENTRIES: i64 = 5;
+
+/// Task fields selectable with `task list --fields`
+const TASK_JSON_FIELDS: [&str; 23] = [
+    "id",
+    "uuid",
+    "project_id",
+    "parent_task_id",
+    "milestone_id",
+    "title",
+    "description",
+    "task_number",
+    "status",
+    "blocked_reason",
+    "priority",
+    "assigned_to",
+    "estimated_hours",
+    "actual_hours",
+    "due_date",
+    "started_at",
+    "completed_at",
+    "task_type",
+    "tags",
+    "version",
+    "created_at",
+    "updated_at",
+    "deleted_at",
+];
+
 /// Handle task commands
-pub async fn handle(command: TaskCommand, _format: OutputFormat) -> Result<()> {
+pub async fn handle(ctx: &AppContext, command: TaskCommand, format: OutputFormat) -> Result<()> {
     match command {
-        TaskCommand::Create(args) => handle_create(args).await,
-        TaskCommand::List(args) => handle_list(args).await,
-        TaskCommand::Show(args) => handle_show(args).await,
-        TaskCommand::Update(args) => handle_update(args).await,
-        TaskCommand::Delete(args) => handle_delete(args).await,
-        TaskCommand::AddDependency(args) => handle_add_dependency(args).await,
-        TaskCommand::RemoveDependency(args) => handle_remove_dependency(args).await,
-        TaskCommand::AddComment(args) => handle_add_comment(args).await,
+        TaskCommand::Create(args) => handle_create(ctx, args).await,
+        TaskCommand::List(args) => handle_list(ctx, args, format).await,
+        TaskCommand::Show(args) => handle_show(ctx, args, format).await,
+        TaskCommand::Update(args) => handle_update(ctx, args).await,
+        TaskCommand::Delete(args) => handle_delete(ctx, args).await,
+        TaskCommand::Restore(args) => handle_restore(ctx, args).await,
+        TaskCommand::AddDependency(args) => handle_add_dependency(ctx, args).await,
+        TaskCommand::RemoveDependency(args) => handle_remove_dependency(ctx, args).await,
+        TaskCommand::AddComment(args) => handle_add_comment(ctx, args).await,
+        TaskCommand::RestoreComment(args) => handle_restore_comment(ctx, args).await,
+        TaskCommand::Watch(args) => handle_watch(ctx, args).await,
+        TaskCommand::CriticalPath(args) => handle_critical_path(ctx, args).await,
+        TaskCommand::DependencyChain(args) => handle_dependency_chain(ctx, args).await,
+        TaskCommand::AuditCycles(args) => handle_audit_cycles(ctx, args, format).await,
+        TaskCommand::Graph(args) => handle_graph(ctx, args, format).await,
+        TaskCommand::Import(args) => handle_import(ctx, args, format).await,
+        TaskCommand::Stats(args) => handle_stats(ctx, args, format).await,
+        TaskCommand::LogWork(args) => handle_log_work(ctx, args).await,
+        TaskCommand::Assign(args) => handle_assign(ctx, args).await,
+        TaskCommand::Unassign(args) => handle_unassign(ctx, args).await,
+        TaskCommand::Move(args) => handle_move(ctx, args).await,
+        TaskCommand::Rollup(args) => handle_rollup(ctx, args, format).await,
     }
 }
 
-/// Create database connection and task service
-async fn create_service() -> Result<TaskService> {
-    let settings = Settings::default();
-    let pool = database::establish_connection(&settings.database).await?;
+/// Create the task service on the shared pool
+fn create_service(ctx: &AppContext) -> TaskService {
+    let pool = ctx.pool();
 
     let task_repo = Arc::new(PostgresTaskRepository::new(pool.clone()));
     let dependency_repo = Arc::new(PostgresTaskDependencyRepository::new(pool.clone()));
-    let comment_repo = Arc::new(PostgresTaskCommentRepository::new(pool));
-
-    Ok(TaskService::new(task_repo, dependency_repo, comment_repo))
+    let comment_repo = Arc::new(PostgresTaskCommentRepository::new(pool.clone()));
+    let work_log_repo = Arc::new(PostgresTaskWorkLogRepository::new(pool.clone()));
+    let assignee_repo = Arc::new(PostgresTaskAssigneeRepository::new(pool.clone()));
+    let project_repo = Arc::new(PostgresProjectRepository::new(pool));
+
+    TaskService::new(
+        task_repo,
+        dependency_repo,
+        comment_repo,
+        work_log_repo,
+        assignee_repo,
+        project_repo,
+    )
 }
 
 /// Handle task create command
-async fn handle_create(args: CreateTaskArgs) -> Result<()> {
-    let service = create_service().await?;
+async fn handle_create(ctx: &AppContext, args: CreateTaskArgs) -> Result<()> {
+    let out = ctx.out();
+    let service = create_service(ctx);
 
     // Parse status if provided
     let status = if let Some(status_str) = args.status {
@@ -67,6 +136,7 @@ async fn handle_create(args: CreateTaskArgs) -> Result<()> {
     } else {
         None
     };
+    let status = start_now_status(status, args.start_now)?;
 
     // Parse priority if provided
     let priority = if let Some(priority_str) = args.priority {
@@ -114,6 +184,7 @@ async fn handle_create(args: CreateTaskArgs) -> Result<()> {
         description: args.description,
         task_number: args.task_number,
         status,
+        blocked_reason: args.blocked_reason,
         priority,
         assigned_to: args.assigned_to,
         estimated_hours: args.estimated_hours,
@@ -125,32 +196,71 @@ async fn handle_create(args: CreateTaskArgs) -> Result<()> {
     // Validate input
     input.validate().map_err(DevErpError::Validation)?;
 
-    // Create task
-    let task = service.create_task(input).await?;
+    if ctx.dry_run() {
+        output::dry_run_input(out, "create this task", &input)?;
+        if !args.depends_on.is_empty() {
+            let ids: Vec<String> = args.depends_on.iter().map(i64::to_string).collect();
+            summary_line(out, "depends_on", &ids.join(", "));
+        }
+        return Ok(());
+    }
+
+    // Create task (and its dependencies, all or nothing)
+    let (task, dependencies) = if args.depends_on.is_empty() {
+        (
+            retry_transient(args.retry.attempts, || service.create_task(input.clone())).await?,
+            Vec::new(),
+        )
+    } else {
+        retry_transient(args.retry.attempts, || {
+            service.create_task_with_dependencies(input.clone(), &args.depends_on)
+        })
+        .await?
+    };
 
     // Display success message
-    println!("{} Task created successfully!", "✓".green().bold());
-    println!();
-    summary_line("ID", &task.id.to_string());
-    summary_line("UUID", &task.uuid.to_string());
-    summary_line("Title", &task.title);
-    summary_line("Project ID", &task.project_id.to_string());
+    outln!(out, "{} Task created successfully!", "✓".green().bold());
+    outln!(out);
+    summary_line(out, "ID", &task.id.to_string());
+    summary_line(out, "UUID", &task.uuid.to_string());
+    summary_line(out, "Title", &task.title);
+    summary_line(out, "Project ID", &task.project_id.to_string());
     if let Some(ref desc) = task.description {
-        summary_line("Description", desc);
+        summary_line(out, "Description", desc);
     }
-    summary_line("Status", &task.status.to_string());
-    summary_line("Priority", &task.priority.to_string());
+    summary_line(out, "Status", &task.status.to_string());
+    summary_line(out, "Priority", &task.priority.to_string());
     if let Some(ref task_type) = task.task_type {
-        summary_line("Type", &task_type.to_string());
+        summary_line(out, "Type", &task_type.to_string());
+    }
+    if !dependencies.is_empty() {
+        let ids: Vec<String> = dependencies
+            .iter()
+            .map(|d| d.depends_on_task_id.to_string())
+            .collect();
+        summary_line(out, "Depends On", &ids.join(", "));
     }
-    println!();
+    outln!(out);
 
     Ok(())
 }
 
 /// Handle task list command
-async fn handle_list(args: ListTaskArgs) -> Result<()> {
-    let service = create_service().await?;
+async fn handle_list(ctx: &AppContext, args: ListTaskArgs, format: OutputFormat) -> Result<()> {
+    let out = ctx.out();
+    if !args.fields.is_empty() {
+        if !format.is_structured() && !args.json_lines {
+            return Err(DevErpError::Validation(
+                "--fields requires --format json, --format yaml or --json-lines".to_string(),
+            ));
+        }
+        formatter::validate_fields(&args.fields, &TASK_JSON_FIELDS)?;
+    }
+
+    let service = create_service(ctx);
+    let pagination = args
+        .pagination
+        .or_per_page(list_preferences(ctx).await?.per_page);
 
     // Parse status filter if provided
     let status = if let Some(status_str) = args.status {
@@ -185,30 +295,123 @@ async fn handle_list(args: ListTaskArgs) -> Result<()> {
         None
     };
 
+    // Resolve the updated_at window; --changed-today uses local day boundaries
+    let (updated_after, updated_before) = if args.changed_today {
+        let timezone = Settings::new()?.application.timezone()?;
+        let (start, end) = local_day_bounds(timezone, Utc::now());
+        (Some(start), Some(end))
+    } else {
+        (
+            args.updated_after
+                .as_deref()
+                .map(parse_datetime)
+                .transpose()?,
+            args.updated_before
+                .as_deref()
+                .map(parse_datetime)
+                .transpose()?,
+        )
+    };
+
+    // A direction without --sort applies to creation time, as for projects
+    let sort_desc = args.desc || args.order == Some(SortOrder::Desc);
+    let sort_by = match (args.sort, args.desc || args.order.is_some()) {
+        (None, true) => Some(TaskSortField::CreatedAt),
+        (sort, _) => sort,
+    };
+
+    // --recursive-project widens the single project into the set depending on it
+    let (project_id, project_ids) = match (args.project_id, args.recursive_project) {
+        (Some(project_id), Some(depth)) => (
+            None,
+            Some(service.expand_dependent_projects(project_id, depth).await?),
+        ),
+        (project_id, _) => (project_id, None),
+    };
+
     // Build filter
     let filter = TaskFilter {
-        project_id: args.project_id,
+        project_id,
+        project_ids,
         status,
         priority,
         task_type,
         assigned_to: args.assigned_to,
         parent_task_id: args.parent_task_id,
+        milestone_id: args.milestone_id,
+        subtree_of: args.subtree,
+        search: args.search,
+        search_comments: args.search_comments,
         include_deleted: false,
-        offset: Some(args.pagination.offset()),
-        limit: Some(args.pagination.limit()),
+        estimate_missing: args.estimate_missing,
+        actual_missing: args.actual_missing,
+        updated_after,
+        updated_before,
+        sort_by,
+        sort_desc,
+        offset: Some(pagination.offset()),
+        limit: Some(pagination.limit()),
     };
 
+    // An unknown subtree root is an error rather than an empty list
+    if let Some(root_id) = args.subtree {
+        service.get_task_by_id(root_id).await?;
+    }
+
+    if format == OutputFormat::Count {
+        return output::count(out, service.count_tasks(filter).await?);
+    }
+
     // Get tasks
-    let tasks = service.list_tasks(filter).await?;
+    let tasks = service.list_tasks(filter.clone()).await?;
+
+    if args.json_lines {
+        if args.fields.is_empty() {
+            return output::ndjson(out, &tasks);
+        }
+        return output::ndjson(out, &formatter::project_fields(&tasks, &args.fields)?);
+    }
+
+    if format.is_structured() {
+        if args.fields.is_empty() {
+            return output_structured(out, format, &tasks);
+        }
+        return output_structured(
+            out,
+            format,
+            &formatter::project_fields(&tasks, &args.fields)?,
+        );
+    }
 
     // Display results
     if tasks.is_empty() {
-        empty_state("tasks");
+        let total = service.count_tasks(TaskFilter::default()).await?;
+        empty_list(
+            out,
+            "tasks",
+            total,
+            "deverp task create --project-id <ID> --title <TITLE>",
+        );
         return Ok(());
     }
 
-    section_title(&format!("Tasks ({} found)", tasks.len()));
-    println!();
+    section_title(out, &format!("Tasks ({} found)", tasks.len()));
+    outln!(out);
+
+    if args.tree {
+        for (depth, task) in task_tree(&tasks) {
+            outln!(
+                out,
+                "  {}{} {} [{}]",
+                "  ".repeat(depth),
+                task.title.bold(),
+                format!("#{}", task.id).yellow(),
+                task.status
+            );
+        }
+        outln!(out);
+        return Ok(());
+    }
 
     for task in &tasks {
         // Status color coding
@@ -228,13 +431,15 @@ async fn handle_list(args: ListTaskArgs) -> Result<()> {
             TaskPriority::Low => "-".dimmed(),
         };
 
-        println!(
+        outln!(
+            out,
             "  {} {} {}",
             priority_indicator,
             task.title.bold(),
             status_str
         );
-        println!(
+        outln!(
+            out,
             "    ID: {} | UUID: {} | Project: {}",
             task.id.to_string().yellow(),
             task.uuid.to_string().dimmed(),
@@ -247,7 +452,7 @@ async fn handle_list(args: ListTaskArgs) -> Result<()> {
             } else {
                 desc.clone()
             };
-            println!("    {}", short_desc.dimmed());
+            outln!(out, "    {}", short_desc.dimmed());
         }
 
         // Additional info
@@ -262,22 +467,62 @@ async fn handle_list(args: ListTaskArgs) -> Result<()> {
             info_parts.push(format!("Due: {}", due_date.format("%Y-%m-%d")));
         }
         if !info_parts.is_empty() {
-            println!("    {}", info_parts.join(" | ").dimmed());
+            outln!(out, "    {}", info_parts.join(" | ").dimmed());
         }
 
-        println!();
+        outln!(out);
     }
 
     // Show pagination info
-    let output = PaginatedOutput::new(tasks, args.pagination.page, args.pagination.per_page);
-    output.print_metadata();
+    let total = service.count_tasks(filter).await?;
+    let output = PaginatedOutput::new(tasks, pagination.page, pagination.per_page(), total);
+    output.print_metadata(out);
 
     Ok(())
 }
 
+/// Order tasks depth-first under their parents, pairing each with its depth
+///
+/// Tasks whose parent is not in `tasks` (e.g. filtered out by status) start
+/// a new top-level branch. Each task appears once even if parent links loop.
+fn task_tree(tasks: &[Task]) -> Vec<(usize, &Task)> {
+    let ids: HashSet<i64> = tasks.iter().map(|t| t.id).collect();
+    let mut children: HashMap<i64, Vec<&Task>> = HashMap::new();
+    let mut roots = Vec::new();
+    for task in tasks {
+        match task.parent_task_id.filter(|parent| ids.contains(parent)) {
+            Some(parent) => children.entry(parent).or_default().push(task),
+            None => roots.push(task),
+        }
+    }
+
+    let mut rows = Vec::with_capacity(tasks.len());
+    let mut visited = HashSet::new();
+    let mut stack: Vec<(usize, &Task)> = roots.into_iter().rev().map(|t| (0, t)).collect();
+    // Tasks caught in a parent cycle have no root; list them at the top level
+    let mut leftovers = tasks.iter();
+    while let Some((depth, task)) = stack.pop().or_else(|| {
+        leftovers
+            .by_ref()
+            .find(|t| !visited.contains(&t.id))
+            .map(|t| (0, t))
+    }) {
+        if !visited.insert(task.id) {
+            continue;
+        }
+        rows.push((depth, task));
+        if let Some(kids) = children.get(&task.id) {
+            stack.extend(kids.iter().rev().map(|kid| (depth + 1, *kid)));
+        }
+    }
+
+    rows
+}
+
 /// Handle task show command
-async fn handle_show(args: ShowTaskArgs) -> Result<()> {
-    let service = create_service().await?;
+async fn handle_show(ctx: &AppContext, args: ShowTaskArgs, format: OutputFormat) -> Result<()> {
+    let out = ctx.out();
+    let service = create_service(ctx);
 
     // Try to parse as UUID first, then as ID
     let task = if let Ok(uuid) = Uuid::parse_str(&args.identifier) {
@@ -290,48 +535,167 @@ async fn handle_show(args: ShowTaskArgs) -> Result<()> {
         ));
     };
 
-    // Display task details
-    section_title(&format!("Task: {}", task.title));
-    println!();
+    let detail = TaskDetail {
+        assignees: service.list_assignees(task.id).await?,
+        dependencies: service.get_task_dependencies(task.id).await?,
+        comments: service.get_task_comments(task.id).await?,
+        recent_work_logs: service
+            .recent_work_logs(task.id, RECENT_WORK_LOG_ENTRIES)
+            .await?,
+        task,
+    };
+
+    if format.is_structured() {
+        return output_structured(out, format, &detail);
+    }
+
+    let TaskDetail {
+        task,
+        assignees,
+        dependencies,
+        comments,
+        recent_work_logs,
+    } = detail;
+
+    print_task_details(out, &task);
+
+    let rollup = service.rollup_progress(task.id, false).await?;
+    if rollup.total_subtasks > 0 {
+        summary_line(out, "Subtasks", &subtask_summary(&rollup));
+    }
+
+    // Everyone sharing the task, primary assignee first
+    if !assignees.is_empty() {
+        outln!(out);
+        section_title(out, "Assignees");
+        for (i, assignee) in assignees.iter().enumerate() {
+            if i == 0 && task.assigned_to.is_some() {
+                outln!(
+                    out,
+                    "  {} {} {}",
+                    "👤".cyan(),
+                    assignee.bold(),
+                    "(primary)".dimmed()
+                );
+            } else {
+                outln!(out, "  {} {}", "👤".cyan(), assignee);
+            }
+        }
+    }
+
+    if !dependencies.is_empty() {
+        outln!(out);
+        section_title(out, "Dependencies");
+        for dep in dependencies {
+            outln!(
+                out,
+                "  {} Task {} depends on Task {} ({})",
+                "→".cyan(),
+                dep.task_id,
+                dep.depends_on_task_id,
+                dep.dependency_type.to_string().dimmed()
+            );
+        }
+    }
+
+    if !comments.is_empty() {
+        outln!(out);
+        section_title(out, "Comments");
+        for comment in comments {
+            let author = comment.author.as_deref().unwrap_or("Unknown");
+            outln!(
+                out,
+                "  {} {} - {}",
+                "💬".cyan(),
+                author.bold(),
+                comment
+                    .created_at
+                    .format("%Y-%m-%d %H:%M:%S")
+                    .to_string()
+                    .dimmed()
+            );
+            outln!(out, "    {}", comment.comment_text);
+            outln!(out);
+        }
+    }
+
+    if !recent_work_logs.is_empty() {
+        outln!(out);
+        section_title(out, "Recent Work");
+        for log in recent_work_logs {
+            outln!(
+                out,
+                "  {} {:>6.2}h {} - {}",
+                "⏱".cyan(),
+                log.hours,
+                log.logged_by.bold(),
+                log.logged_at
+                    .format("%Y-%m-%d %H:%M:%S")
+                    .to_string()
+                    .dimmed()
+            );
+            if let Some(ref note) = log.note {
+                outln!(out, "    {}", note);
+            }
+        }
+    }
+
+    outln!(out);
+
+    Ok(())
+}
+
+/// Print the fields of a single task
+fn print_task_details(out: &dyn OutputSink, task: &Task) {
+    section_title(out, &format!("Task: {}", task.title));
+    outln!(out);
 
-    summary_line("ID", &task.id.to_string());
-    summary_line("UUID", &task.uuid.to_string());
-    summary_line("Title", &task.title);
-    summary_line("Project ID", &task.project_id.to_string());
+    summary_line(out, "ID", &task.id.to_string());
+    summary_line(out, "UUID", &task.uuid.to_string());
+    summary_line(out, "Title", &task.title);
+    summary_line(out, "Project ID", &task.project_id.to_string());
 
     if let Some(ref desc) = task.description {
-        summary_line("Description", desc);
+        summary_line(out, "Description", desc);
     }
 
     if let Some(parent_id) = task.parent_task_id {
-        summary_line("Parent Task", &parent_id.to_string());
+        summary_line(out, "Parent Task", &parent_id.to_string());
     }
 
     if let Some(ref task_number) = task.task_number {
-        summary_line("Task Number", task_number);
+        summary_line(out, "Task Number", task_number);
     }
 
-    summary_line("Status", &task.status.to_string());
-    summary_line("Priority", &task.priority.to_string());
+    summary_line(out, "Status", &task.status.to_string());
+    if task.status == TaskStatus::Blocked {
+        let reason = task
+            .blocked_reason
+            .as_deref()
+            .unwrap_or("no reason recorded");
+        summary_line(out, "Blocked By", &reason.red().bold().to_string());
+    }
+    summary_line(out, "Priority", &task.priority.to_string());
 
     if let Some(ref task_type) = task.task_type {
-        summary_line("Type", &task_type.to_string());
+        summary_line(out, "Type", &task_type.to_string());
     }
 
     if let Some(ref assigned_to) = task.assigned_to {
-        summary_line("Assigned To", assigned_to);
+        summary_line(out, "Assigned To", assigned_to);
     }
 
     if let Some(estimated_hours) = task.estimated_hours {
-        summary_line("Estimated Hours", &format!("{:.2}", estimated_hours));
+        summary_line(out, "Estimated Hours", &format!("{:.2}", estimated_hours));
     }
 
     if let Some(actual_hours) = task.actual_hours {
-        summary_line("Actual Hours", &format!("{:.2}", actual_hours));
+        summary_line(out, "Actual Hours", &format!("{:.2}", actual_hours));
     }
 
     if let Some(due_date) = task.due_date {
         summary_line(
+            out,
             "Due Date",
             &due_date.format("%Y-%m-%d %H:%M:%S").to_string(),
         );
@@ -339,6 +703,7 @@ async fn handle_show(args: ShowTaskArgs) -> Result<()> {
 
     if let Some(started_at) = task.started_at {
         summary_line(
+            out,
             "Started At",
             &started_at.format("%Y-%m-%d %H:%M:%S").to_string(),
         );
@@ -346,6 +711,7 @@ async fn handle_show(args: ShowTaskArgs) -> Result<()> {
 
     if let Some(completed_at) = task.completed_at {
         summary_line(
+            out,
             "Completed At",
             &completed_at.format("%Y-%m-%d %H:%M:%S").to_string(),
         );
@@ -353,66 +719,457 @@ async fn handle_show(args: ShowTaskArgs) -> Result<()> {
 
     if let Some(ref tags) = task.tags {
         if !tags.is_empty() {
-            summary_line("Tags", &tags.join(", "));
+            summary_line(out, "Tags", &tags.join(", "));
         }
     }
 
-    println!();
+    outln!(out);
     summary_line(
+        out,
         "Created",
         &task.created_at.format("%Y-%m-%d %H:%M:%S").to_string(),
     );
     summary_line(
+        out,
         "Updated",
         &task.updated_at.format("%Y-%m-%d %H:%M:%S").to_string(),
     );
+    summary_line(out, "Version", &task.version.to_string());
+}
 
-    // Get and display dependencies
-    let dependencies = service.get_task_dependencies(task.id).await?;
-    if !dependencies.is_empty() {
-        println!();
-        section_title("Dependencies");
-        for dep in dependencies {
-            println!(
-                "  {} Task {} depends on Task {} ({})",
-                "→".cyan(),
-                dep.task_id,
-                dep.depends_on_task_id,
-                dep.dependency_type.to_string().dimmed()
-            );
+/// Handle task watch command
+async fn handle_watch(ctx: &AppContext, args: WatchTaskArgs) -> Result<()> {
+    let out = ctx.out();
+    let service = create_service(ctx);
+    let id = args.id;
+
+    outln!(
+        out,
+        "{}",
+        format!(
+            "Watching task {} every {}s (Ctrl-C to stop)",
+            id, args.interval
+        )
+        .dimmed()
+    );
+
+    watch(
+        Duration::from_secs(args.interval),
+        || service.get_task_by_id(id),
+        |task, changed| {
+            if !changed.is_empty() {
+                outln!(out);
+                outln!(
+                    out,
+                    "{} {} changed: {}",
+                    Utc::now().format("%H:%M:%S").to_string().dimmed(),
+                    "●".yellow(),
+                    changed.join(", ")
+                );
+            }
+            print_task_details(out, task);
+        },
+    )
+    .await
+}
+
+/// Handle task critical-path command
+async fn handle_critical_path(ctx: &AppContext, args: CriticalPathArgs) -> Result<()> {
+    let out = ctx.out();
+    let service = create_service(ctx);
+    let path = service.compute_critical_path(args.project_id).await?;
+
+    if path.task_ids.is_empty() {
+        outln!(out, "{}", "No tasks found in this project.".yellow());
+        return Ok(());
+    }
+
+    section_title(out, &format!("Critical Path: Project {}", args.project_id));
+    outln!(out);
+
+    for (step, id) in path.task_ids.iter().enumerate() {
+        let task = service.get_task_by_id(*id).await?;
+        let hours = task.estimated_hours.unwrap_or(0.0);
+        outln!(
+            out,
+            "  {:>2}. #{} {} {}",
+            step + 1,
+            task.id,
+            task.title,
+            format!("({:.2}h, {})", hours, task.status).dimmed()
+        );
+    }
+
+    outln!(out);
+    summary_line(out, "Tasks", &path.task_ids.len().to_string());
+    summary_line(out, "Total Hours", &format!("{:.2}", path.total_hours));
+    outln!(out);
+
+    Ok(())
+}
+
+/// Handle task dependency-chain command
+async fn handle_dependency_chain(ctx: &AppContext, args: DependencyChainArgs) -> Result<()> {
+    let out = ctx.out();
+    let service = create_service(ctx);
+    let chain = service
+        .get_dependency_chain(args.task_id, args.max_depth)
+        .await?;
+
+    // The chain always starts with the task itself
+    if chain.len() <= 1 {
+        outln!(out, "{}", "This task has no dependencies.".yellow());
+        return Ok(());
+    }
+
+    section_title(out, &format!("Dependency Chain: Task {}", args.task_id));
+    outln!(out);
+
+    for id in chain.iter().skip(1) {
+        let task = service.get_task_by_id(*id).await?;
+        outln!(
+            out,
+            "  {} #{} {} {}",
+            "→".cyan(),
+            task.id,
+            task.title,
+            format!("({})", task.status).dimmed()
+        );
+    }
+
+    outln!(out);
+    summary_line(out, "Dependencies", &(chain.len() - 1).to_string());
+    if let Some(max_depth) = args.max_depth {
+        summary_line(out, "Max Depth", &max_depth.to_string());
+    }
+    outln!(out);
+
+    Ok(())
+}
+
+/// Handle task audit-cycles command
+async fn handle_audit_cycles(
+    ctx: &AppContext,
+    args: AuditCyclesArgs,
+    format: OutputFormat,
+) -> Result<()> {
+    let out = ctx.out();
+    let service = create_service(ctx);
+    let cycles = service.audit_cycles(args.project_id).await?;
+
+    if format.is_structured() {
+        return output_structured(out, format, &cycles);
+    }
+
+    if cycles.is_empty() {
+        outln!(
+            out,
+            "{} No dependency cycles in project {}.",
+            "✓".green().bold(),
+            args.project_id
+        );
+        return Ok(());
+    }
+
+    section_title(
+        out,
+        &format!(
+            "Dependency Cycles: Project {} ({} found)",
+            args.project_id,
+            cycles.len()
+        ),
+    );
+    outln!(out);
+
+    for cycle in &cycles {
+        // Close the loop so the cycle reads back to where it started
+        let ids: Vec<String> = cycle
+            .iter()
+            .chain(cycle.first())
+            .map(|id| format!("#{}", id))
+            .collect();
+        outln!(out, "  {} {}", "✗".red(), ids.join(" → "));
+    }
+
+    outln!(out);
+    outln!(
+        out,
+        "{}",
+        "Remove one dependency from each cycle with `deverp task remove-dependency`.".dimmed()
+    );
+
+    Ok(())
+}
+
+/// Handle task graph command
+///
+/// `task graph --dot` prints Graphviz source; json/yaml print the adjacency list
+/// (under `graph`, next to `cycles`, with `--detect-cycles`).
+async fn handle_graph(ctx: &AppContext, args: TaskGraphArgs, format: OutputFormat) -> Result<()> {
+    let out = ctx.out();
+    let service = create_service(ctx);
+    let cycles = if args.detect_cycles {
+        service.audit_cycles(args.project_id).await?
+    } else {
+        Vec::new()
+    };
+
+    let mut tasks = service
+        .list_tasks(TaskFilter {
+            project_id: Some(args.project_id),
+            ..Default::default()
+        })
+        .await?;
+    tasks.sort_by_key(|t| t.id);
+
+    if args.dot {
+        let dependencies = service.get_project_dependencies(args.project_id).await?;
+        outln!(
+            out,
+            "{}",
+            render_dot(args.project_id, &tasks, &dependencies, &cycles)
+        );
+        return Ok(());
+    }
+
+    let graph = service.get_dependency_graph(args.project_id).await?;
+
+    if format.is_structured() {
+        if args.detect_cycles {
+            let value = serde_json::json!({ "graph": graph, "cycles": cycles });
+            return output_structured(out, format, &value);
         }
+        return output_structured(out, format, &graph);
     }
 
-    // Get and display comments
-    let comments = service.get_task_comments(task.id).await?;
-    if !comments.is_empty() {
-        println!();
-        section_title("Comments");
-        for comment in comments {
-            let author = comment.author.as_deref().unwrap_or("Unknown");
-            println!(
-                "  {} {} - {}",
-                "💬".cyan(),
-                author.bold(),
-                comment
-                    .created_at
-                    .format("%Y-%m-%d %H:%M:%S")
-                    .to_string()
-                    .dimmed()
+    if graph.is_empty() {
+        outln!(out, "{}", "No tasks found in this project.".yellow());
+        return Ok(());
+    }
+
+    let titles: HashMap<i64, &str> = tasks.iter().map(|t| (t.id, t.title.as_str())).collect();
+    section_title(
+        out,
+        &format!("Dependency Graph: Project {}", args.project_id),
+    );
+    outln!(out);
+    for (task_id, depends_on) in &graph {
+        let title = titles.get(task_id).copied().unwrap_or("");
+        if depends_on.is_empty() {
+            outln!(out, "  #{} {}", task_id, title);
+        } else {
+            let ids: Vec<String> = depends_on.iter().map(|id| format!("#{}", id)).collect();
+            outln!(
+                out,
+                "  #{} {} {} {}",
+                task_id,
+                title,
+                "← depends on".dimmed(),
+                ids.join(", ")
             );
-            println!("    {}", comment.comment_text);
-            println!();
         }
     }
 
-    println!();
+    if args.detect_cycles {
+        outln!(out);
+        if cycles.is_empty() {
+            outln!(out, "{} No dependency cycles.", "✓".green().bold());
+        } else {
+            for cycle in &cycles {
+                let ids: Vec<String> = cycle
+                    .iter()
+                    .chain(cycle.first())
+                    .map(|id| format!("#{}", id))
+                    .collect();
+                outln!(out, "  {} Cycle: {}", "✗".red(), ids.join(" → "));
+            }
+        }
+    }
 
     Ok(())
 }
 
+/// Handle task stats command
+async fn handle_stats(ctx: &AppContext, args: TaskStatsArgs, format: OutputFormat) -> Result<()> {
+    let out = ctx.out();
+    let service = create_service(ctx);
+    let stats = service.task_stats(args.id, Utc::now()).await?;
+
+    if format.is_structured() {
+        return output_structured(out, format, &stats);
+    }
+
+    let unknown = || "unknown".dimmed().to_string();
+    let hours = |h: Option<f64>| h.map_or_else(unknown, |h| format!("{:.2}", h));
+
+    section_title(out, &format!("Task Stats: #{}", stats.task_id));
+    outln!(out);
+    summary_line(out, "Status", &stats.status.to_string());
+    summary_line(out, "Age", &format!("{} day(s)", stats.age_days));
+    summary_line(
+        out,
+        "In Status For",
+        &stats
+            .days_in_status
+            .map_or_else(unknown, |d| format!("{} day(s)", d)),
+    );
+    summary_line(out, "Estimated Hours", &hours(stats.estimated_hours));
+    summary_line(out, "Actual Hours", &hours(stats.actual_hours));
+    summary_line(
+        out,
+        "Estimate Accuracy",
+        &stats
+            .estimate_accuracy
+            .map_or_else(unknown, |r| format!("{:.2}x", r)),
+    );
+    summary_line(
+        out,
+        "Overdue",
+        &if stats.overdue {
+            "yes".red().to_string()
+        } else {
+            "no".to_string()
+        },
+    );
+    outln!(out);
+
+    Ok(())
+}
+
+/// Handle task rollup command
+async fn handle_rollup(ctx: &AppContext, args: RollupTaskArgs, format: OutputFormat) -> Result<()> {
+    let out = ctx.out();
+    let service = create_service(ctx);
+    let rollup = service
+        .rollup_progress(args.task_id, args.complete && !ctx.dry_run())
+        .await?;
+
+    if format.is_structured() {
+        return output_structured(out, format, &rollup);
+    }
+
+    if rollup.total_subtasks == 0 {
+        outln!(
+            out,
+            "{}",
+            format!("Task #{} has no open subtasks.", rollup.task_id).yellow()
+        );
+        return Ok(());
+    }
+
+    section_title(out, &format!("Subtask Rollup: #{}", rollup.task_id));
+    outln!(out);
+    summary_line(out, "Subtasks", &subtask_summary(&rollup));
+    summary_line(
+        out,
+        "Completion",
+        &format!(
+            "{} {:.1}%",
+            output::progress_bar(rollup.completion_pct, 20),
+            rollup.completion_pct
+        ),
+    );
+    outln!(out);
+
+    if rollup.marked_done {
+        output::success(out, &format!("Task #{} marked done", rollup.task_id));
+    } else if args.complete && ctx.dry_run() && rollup.done_subtasks == rollup.total_subtasks {
+        let parent = service.get_task_by_id(rollup.task_id).await?;
+        if !matches!(parent.status, TaskStatus::Done | TaskStatus::Cancelled) {
+            output::dry_run(out, &format!("mark task #{} done", rollup.task_id));
+        }
+    }
+
+    Ok(())
+}
+
+/// "X/Y done" for a task's direct subtasks
+fn subtask_summary(rollup: &TaskRollup) -> String {
+    format!("{}/{} done", rollup.done_subtasks, rollup.total_subtasks)
+}
+
+/// Handle task import command
+async fn handle_import(ctx: &AppContext, args: ImportTaskArgs, format: OutputFormat) -> Result<()> {
+    let out = ctx.out();
+    let path = Path::new(&args.file);
+    let contents = std::fs::read_to_string(path)?;
+    let tasks = parse_task_import(&contents, is_yaml_path(path))
+        .map_err(|e| DevErpError::Validation(format!("Cannot parse {}: {}", args.file, e)))?;
+
+    if ctx.dry_run() {
+        output::dry_run(
+            out,
+            &format!(
+                "import {} task(s) into project #{}",
+                tasks.len(),
+                args.project_id
+            ),
+        );
+        for task in &tasks {
+            list_item(out, &task.title);
+        }
+        return Ok(());
+    }
+
+    let service = create_service(ctx);
+    let summary = service.import_tasks(args.project_id, tasks).await?;
+
+    if format.is_structured() {
+        return output_structured(out, format, &summary);
+    }
+
+    for task in &summary.created {
+        outln!(out, "  {} #{} {}", "+".green(), task.id, task.title);
+    }
+    for failure in &summary.failed {
+        outln!(
+            out,
+            "  {} [{}] {}: {}",
+            "x".red(),
+            failure.index,
+            failure.title,
+            failure.error
+        );
+    }
+
+    outln!(out);
+    summary_line(out, "Created", &summary.created.len().to_string());
+    summary_line(out, "Failed", &summary.failed.len().to_string());
+
+    if summary.failed.is_empty() {
+        output::success(out, &format!("Imported {} task(s)", summary.created.len()));
+    } else {
+        output::warning(
+            out,
+            &format!("{} task(s) could not be imported", summary.failed.len()),
+        );
+    }
+
+    Ok(())
+}
+
+/// Whether an import file should be read as YAML rather than JSON
+fn is_yaml_path(path: &Path) -> bool {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| e.eq_ignore_ascii_case("yaml") || e.eq_ignore_ascii_case("yml"))
+}
+
+/// Parse an import file's array of task definitions
+///
+/// `project_id` may be left out of each entry; the caller fills it in.
+fn parse_task_import(contents: &str, yaml: bool) -> std::result::Result<Vec<CreateTask>, String> {
+    if yaml {
+        serde_yaml::from_str(contents).map_err(|e| e.to_string())
+    } else {
+        serde_json::from_str(contents).map_err(|e| e.to_string())
+    }
+}
+
 /// Handle task update command
-async fn handle_update(args: UpdateTaskArgs) -> Result<()> {
-    let service = create_service().await?;
+async fn handle_update(ctx: &AppContext, args: UpdateTaskArgs) -> Result<()> {
+    let out = ctx.out();
+    let service = create_service(ctx);
 
     // Get the task ID
     let id = if let Ok(uuid) = Uuid::parse_str(&args.identifier) {
@@ -459,9 +1216,11 @@ async fn handle_update(args: UpdateTaskArgs) -> Result<()> {
         None
     };
 
-    // Parse due date if provided
+    // Parse due date if provided; `--clear-due-date` sets it to NULL
     let due_date = if let Some(date_str) = args.due_date {
-        Some(parse_datetime(&date_str)?)
+        Some(Some(parse_datetime(&date_str)?))
+    } else if args.clear_due_date {
+        Some(None)
     } else {
         None
     };
@@ -479,8 +1238,13 @@ async fn handle_update(args: UpdateTaskArgs) -> Result<()> {
     let input = UpdateTask {
         id,
         title: args.title,
-        description: args.description,
+        description: if args.clear_description {
+            Some(None)
+        } else {
+            args.description.map(Some)
+        },
         status,
+        blocked_reason: args.blocked_reason,
         priority,
         assigned_to: args.assigned_to,
         estimated_hours: args.estimated_hours,
@@ -488,29 +1252,64 @@ async fn handle_update(args: UpdateTaskArgs) -> Result<()> {
         due_date,
         task_type,
         tags,
+        expected_version: args.expected_version,
     };
 
     // Validate input
     input.validate().map_err(DevErpError::Validation)?;
 
+    if ctx.dry_run() {
+        let cleared: Vec<&str> = [
+            (args.clear_description, "description"),
+            (args.clear_due_date, "due_date"),
+        ]
+        .into_iter()
+        .filter_map(|(clear, field)| clear.then_some(field))
+        .collect();
+        return output::dry_run_update(out, &format!("update task #{}", id), &input, &cleared);
+    }
+
+    let threshold = ctx.config_service().task_variance_threshold_pct().await?;
+    let service = service.with_variance_threshold_pct(threshold);
+
+    // Only the predecessor check can reject a valid update to done with no
+    // blocked reason, so that is when `--force` would have helped
+    let check = if args.force {
+        CompletionCheck::Skip
+    } else {
+        CompletionCheck::Enforce
+    };
+    let gated = check == CompletionCheck::Enforce
+        && input.status == Some(TaskStatus::Done)
+        && input.blocked_reason.is_none();
+
     // Update task
-    let task = service.update_task(input).await?;
+    let UpdateTaskResult { task, warnings } = retry_transient(args.retry.attempts, || {
+        service.update_task(input.clone(), check)
+    })
+    .await
+    .map_err(|e| if gated { output::with_force_hint(e) } else { e })?;
 
     // Display success message
-    println!("{} Task updated successfully!", "✓".green().bold());
-    println!();
-    summary_line("ID", &task.id.to_string());
-    summary_line("Title", &task.title);
-    summary_line("Status", &task.status.to_string());
-    summary_line("Priority", &task.priority.to_string());
-    println!();
+    outln!(out, "{} Task updated successfully!", "✓".green().bold());
+    outln!(out);
+    summary_line(out, "ID", &task.id.to_string());
+    summary_line(out, "Title", &task.title);
+    summary_line(out, "Status", &task.status.to_string());
+    summary_line(out, "Priority", &task.priority.to_string());
+    outln!(out);
+
+    for message in &warnings {
+        output::warning(out, &message.yellow().to_string());
+    }
 
     Ok(())
 }
 
 /// Handle task delete command
-async fn handle_delete(args: DeleteTaskArgs) -> Result<()> {
-    let service = create_service().await?;
+async fn handle_delete(ctx: &AppContext, args: DeleteTaskArgs) -> Result<()> {
+    let out = ctx.out();
+    let service = create_service(ctx);
 
     // Get the task
     let task = if let Ok(uuid) = Uuid::parse_str(&args.identifier) {
@@ -523,6 +1322,16 @@ async fn handle_delete(args: DeleteTaskArgs) -> Result<()> {
         ));
     };
 
+    if ctx.dry_run() {
+        output::dry_run(out, &format!("delete task '{}'", task.title));
+        summary_line(out, "ID", &task.id.to_string());
+        summary_line(out, "Title", &task.title);
+        summary_line(out, "Project ID", &task.project_id.to_string());
+        summary_line(out, "Status", &task.status.to_string());
+        outln!(out);
+        return Ok(());
+    }
+
     // Confirm deletion
     if !args.confirm {
         let confirmed = confirm(&format!(
@@ -531,15 +1340,16 @@ async fn handle_delete(args: DeleteTaskArgs) -> Result<()> {
         ));
 
         if !confirmed {
-            println!("Deletion cancelled.");
+            outln!(out, "Deletion cancelled.");
             return Ok(());
         }
     }
 
     // Delete task
-    service.delete_task(task.id).await?;
+    retry_transient(args.retry.attempts, || service.delete_task(task.id)).await?;
 
-    println!(
+    outln!(
+        out,
         "{} Task '{}' deleted successfully.",
         "✓".green().bold(),
         task.title
@@ -548,9 +1358,55 @@ async fn handle_delete(args: DeleteTaskArgs) -> Result<()> {
     Ok(())
 }
 
+/// Handle task restore-comment command
+async fn handle_restore_comment(ctx: &AppContext, args: RestoreCommentArgs) -> Result<()> {
+    let out = ctx.out();
+    let service = create_service(ctx);
+
+    if ctx.dry_run() {
+        output::dry_run(out, &format!("restore comment #{}", args.id));
+        return Ok(());
+    }
+
+    service.restore_task_comment(args.id).await?;
+
+    outln!(
+        out,
+        "{} Comment {} restored successfully.",
+        "✓".green().bold(),
+        args.id
+    );
+
+    Ok(())
+}
+
+/// Handle task restore command
+async fn handle_restore(ctx: &AppContext, args: RestoreTaskArgs) -> Result<()> {
+    let out = ctx.out();
+    let service = create_service(ctx);
+
+    if ctx.dry_run() {
+        output::dry_run(out, &format!("restore task #{}", args.id));
+        return Ok(());
+    }
+
+    service.restore_task(args.id).await?;
+
+    let task = service.get_task_by_id(args.id).await?;
+    outln!(
+        out,
+        "{} Task '{}' restored successfully.",
+        "✓".green().bold(),
+        task.title
+    );
+
+    Ok(())
+}
+
 /// Handle add dependency command
-async fn handle_add_dependency(args: AddDependencyArgs) -> Result<()> {
-    let service = create_service().await?;
+async fn handle_add_dependency(ctx: &AppContext, args: AddDependencyArgs) -> Result<()> {
+    let out = ctx.out();
+    let service = create_service(ctx);
 
     // Parse dependency type if provided
     let dependency_type = if let Some(type_str) = args.dependency_type {
@@ -573,23 +1429,47 @@ async fn handle_add_dependency(args: AddDependencyArgs) -> Result<()> {
     // Validate input
     input.validate().map_err(DevErpError::Validation)?;
 
+    if ctx.dry_run() {
+        return output::dry_run_input(out, "add this task dependency", &input);
+    }
+
     // Add dependency
     let dependency = service.add_task_dependency(input).await?;
 
     // Display success message
-    println!("{} Task dependency added successfully!", "✓".green().bold());
-    println!();
-    summary_line("Task ID", &dependency.task_id.to_string());
-    summary_line("Depends On", &dependency.depends_on_task_id.to_string());
-    summary_line("Type", &dependency.dependency_type.to_string());
-    println!();
+    outln!(
+        out,
+        "{} Task dependency added successfully!",
+        "✓".green().bold()
+    );
+    outln!(out);
+    summary_line(out, "Task ID", &dependency.task_id.to_string());
+    summary_line(
+        out,
+        "Depends On",
+        &dependency.depends_on_task_id.to_string(),
+    );
+    summary_line(out, "Type", &dependency.dependency_type.to_string());
+    outln!(out);
 
     Ok(())
 }
 
 /// Handle remove dependency command
-async fn handle_remove_dependency(args: RemoveDependencyArgs) -> Result<()> {
-    let service = create_service().await?;
+async fn handle_remove_dependency(ctx: &AppContext, args: RemoveDependencyArgs) -> Result<()> {
+    let out = ctx.out();
+    let service = create_service(ctx);
+
+    if ctx.dry_run() {
+        output::dry_run(
+            out,
+            &format!(
+                "remove the dependency of task #{} on task #{}",
+                args.task_id, args.depends_on_task_id
+            ),
+        );
+        return Ok(());
+    }
 
     // Remove dependency
     service
@@ -597,24 +1477,27 @@ async fn handle_remove_dependency(args: RemoveDependencyArgs) -> Result<()> {
         .await?;
 
     // Display success message
-    println!(
+    outln!(
+        out,
         "{} Task dependency removed successfully!",
         "✓".green().bold()
     );
-    println!();
-    summary_line("Task ID", &args.task_id.to_string());
+    outln!(out);
+    summary_line(out, "Task ID", &args.task_id.to_string());
     summary_line(
+        out,
         "Removed Dependency On",
         &args.depends_on_task_id.to_string(),
     );
-    println!();
+    outln!(out);
 
     Ok(())
 }
 
 /// Handle add comment command
-async fn handle_add_comment(args: AddCommentArgs) -> Result<()> {
-    let service = create_service().await?;
+async fn handle_add_comment(ctx: &AppContext, args: AddCommentArgs) -> Result<()> {
+    let out = ctx.out();
+    let service = create_service(ctx);
 
     // Create comment input
     let input = CreateTaskComment {
@@ -626,23 +1509,136 @@ async fn handle_add_comment(args: AddCommentArgs) -> Result<()> {
     // Validate input
     input.validate().map_err(DevErpError::Validation)?;
 
+    if ctx.dry_run() {
+        return output::dry_run_input(out, "add this comment", &input);
+    }
+
     // Add comment
     let comment = service.add_task_comment(input).await?;
 
     // Display success message
-    println!("{} Comment added successfully!", "✓".green().bold());
-    println!();
-    summary_line("Comment ID", &comment.id.to_string());
-    summary_line("Task ID", &comment.task_id.to_string());
+    outln!(out, "{} Comment added successfully!", "✓".green().bold());
+    outln!(out);
+    summary_line(out, "Comment ID", &comment.id.to_string());
+    summary_line(out, "Task ID", &comment.task_id.to_string());
     if let Some(ref author) = comment.author {
-        summary_line("Author", author);
+        summary_line(out, "Author", author);
+    }
+    outln!(out, "  {}", comment.comment_text);
+    outln!(out);
+
+    Ok(())
+}
+
+/// Handle task log-work command
+async fn handle_log_work(ctx: &AppContext, args: LogWorkArgs) -> Result<()> {
+    let out = ctx.out();
+    let service = create_service(ctx);
+
+    if ctx.dry_run() {
+        output::dry_run(
+            out,
+            &format!("log {:.2} hour(s) on task #{}", args.hours, args.task_id),
+        );
+        return Ok(());
+    }
+
+    let log = service
+        .log_work(args.task_id, args.hours, args.logged_by, args.note)
+        .await?;
+    let task = service.get_task_by_id(log.task_id).await?;
+
+    outln!(out, "{} Work logged successfully!", "✓".green().bold());
+    outln!(out);
+    summary_line(out, "Task ID", &log.task_id.to_string());
+    summary_line(out, "Hours", &format!("{:.2}", log.hours));
+    summary_line(out, "Logged By", &log.logged_by);
+    summary_line(
+        out,
+        "Actual Hours",
+        &format!("{:.2}", task.actual_hours.unwrap_or(0.0)),
+    );
+    outln!(out);
+
+    Ok(())
+}
+
+/// Handle task assign command
+async fn handle_assign(ctx: &AppContext, args: AssignTaskArgs) -> Result<()> {
+    let out = ctx.out();
+    let service = create_service(ctx);
+
+    if ctx.dry_run() {
+        output::dry_run(
+            out,
+            &format!("assign {} to task #{}", args.user, args.task_id),
+        );
+        return Ok(());
     }
-    println!("  {}", comment.comment_text);
-    println!();
+
+    service.assign_task(args.task_id, &args.user).await?;
+    let assignees = service.list_assignees(args.task_id).await?;
+
+    outln!(
+        out,
+        "{} {} assigned to task {}",
+        "✓".green().bold(),
+        args.user.bold(),
+        args.task_id
+    );
+    summary_line(out, "Assignees", &assignees.join(", "));
 
     Ok(())
 }
 
+/// Handle task unassign command
+async fn handle_unassign(ctx: &AppContext, args: AssignTaskArgs) -> Result<()> {
+    let out = ctx.out();
+    let service = create_service(ctx);
+
+    if ctx.dry_run() {
+        output::dry_run(
+            out,
+            &format!("unassign {} from task #{}", args.user, args.task_id),
+        );
+        return Ok(());
+    }
+
+    service.unassign_task(args.task_id, &args.user).await?;
+    let assignees = service.list_assignees(args.task_id).await?;
+
+    outln!(
+        out,
+        "{} {} unassigned from task {}",
+        "✓".green().bold(),
+        args.user.bold(),
+        args.task_id
+    );
+    if assignees.is_empty() {
+        summary_line(out, "Assignees", "none");
+    } else {
+        summary_line(out, "Assignees", &assignees.join(", "));
+    }
+
+    Ok(())
+}
+
+/// Status for `task create`, forced to in-progress by `--start-now`
+///
+/// An explicit `--status` other than in_progress contradicts the flag.
+fn start_now_status(status: Option<TaskStatus>, start_now: bool) -> Result<Option<TaskStatus>> {
+    if !start_now {
+        return Ok(status);
+    }
+    match status {
+        None | Some(TaskStatus::InProgress) => Ok(Some(TaskStatus::InProgress)),
+        Some(other) => Err(DevErpError::Validation(format!(
+            "--start-now conflicts with --status {}",
+            other
+        ))),
+    }
+}
+
 /// Parse datetime from string (supports both YYYY-MM-DD and YYYY-MM-DD HH:MM:SS formats)
 fn parse_datetime(date_str: &str) -> Result<DateTime<Utc>> {
     // Try parsing as full datetime first
@@ -663,3 +1659,179 @@ fn parse_datetime(date_str: &str) -> Result<DateTime<Utc>> {
         date_str
     )))
 }
+
+/// Handle task move command
+async fn handle_move(ctx: &AppContext, args: MoveTaskArgs) -> Result<()> {
+    let out = ctx.out();
+    let service = create_service(ctx);
+
+    if ctx.dry_run() {
+        let task = service.get_task_by_id(args.task_id).await?;
+        output::dry_run(
+            out,
+            &format!(
+                "move task #{} '{}' from project {} to project {}",
+                task.id, task.title, task.project_id, args.to_project
+            ),
+        );
+        return Ok(());
+    }
+
+    let UpdateTaskResult { task, warnings } =
+        service.move_task(args.task_id, args.to_project).await?;
+
+    outln!(
+        out,
+        "{} Task {} moved to project {}",
+        "✓".green().bold(),
+        task.id,
+        task.project_id
+    );
+    summary_line(out, "Title", &task.title);
+    if let Some(parent_id) = task.parent_task_id {
+        summary_line(out, "Parent Task", &parent_id.to_string());
+    }
+
+    for message in &warnings {
+        output::warning(out, &message.yellow().to_string());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::task::entity::Task;
+
+    fn sample_task() -> Task {
+        Task {
+            id: 3,
+            title: "Write docs".to_string(),
+            description: Some("User guide".to_string()),
+            status: TaskStatus::InProgress,
+            priority: TaskPriority::High,
+            assigned_to: Some("alice".to_string()),
+            estimated_hours: Some(4.0),
+            ..Task::test_default()
+        }
+    }
+
+    #[test]
+    fn test_start_now_forces_in_progress() {
+        assert_eq!(start_now_status(None, false).unwrap(), None);
+        assert_eq!(
+            start_now_status(Some(TaskStatus::Review), false).unwrap(),
+            Some(TaskStatus::Review)
+        );
+        assert_eq!(
+            start_now_status(None, true).unwrap(),
+            Some(TaskStatus::InProgress)
+        );
+        assert_eq!(
+            start_now_status(Some(TaskStatus::InProgress), true).unwrap(),
+            Some(TaskStatus::InProgress)
+        );
+        assert!(start_now_status(Some(TaskStatus::Todo), true).is_err());
+    }
+
+    #[test]
+    fn test_fields_projection_keeps_only_requested_keys() {
+        let fields = vec!["id".to_string(), "title".to_string(), "status".to_string()];
+        formatter::validate_fields(&fields, &TASK_JSON_FIELDS).unwrap();
+
+        let projected = formatter::project_fields(&[sample_task()], &fields).unwrap();
+        let object = projected[0].as_object().unwrap();
+
+        let mut keys: Vec<&str> = object.keys().map(String::as_str).collect();
+        keys.sort();
+        assert_eq!(keys, vec!["id", "status", "title"]);
+        assert_eq!(object["title"], "Write docs");
+    }
+
+    #[test]
+    fn test_task_tree_orders_children_under_parents() {
+        let task = |id, parent| Task {
+            id,
+            parent_task_id: parent,
+            ..sample_task()
+        };
+        // Newest first, as `task list` returns them
+        let tasks = vec![
+            task(4, Some(2)),
+            task(3, Some(1)),
+            task(2, Some(1)),
+            task(1, None),
+            task(9, Some(8)),
+        ];
+
+        let rows: Vec<(usize, i64)> = task_tree(&tasks)
+            .into_iter()
+            .map(|(depth, t)| (depth, t.id))
+            .collect();
+        assert_eq!(rows, vec![(0, 1), (1, 3), (1, 2), (2, 4), (0, 9)]);
+
+        // A parent loop still lists every task exactly once
+        let looped = vec![task(5, Some(6)), task(6, Some(5))];
+        assert_eq!(task_tree(&looped).len(), 2);
+    }
+
+    #[test]
+    fn test_task_field_allowlist_matches_serialized_task() {
+        let value = serde_json::to_value(sample_task()).unwrap();
+        let object = value.as_object().unwrap();
+
+        assert_eq!(object.len(), TASK_JSON_FIELDS.len());
+        assert!(TASK_JSON_FIELDS.iter().all(|f| object.contains_key(*f)));
+    }
+
+    #[test]
+    fn test_json_lines_each_line_parses() {
+        let mut second = sample_task();
+        second.id = 2;
+        second.description = Some("multi\nline".to_string());
+
+        let ndjson = formatter::to_ndjson(&[sample_task(), second]).unwrap();
+        let lines: Vec<&str> = ndjson.lines().collect();
+        assert_eq!(lines.len(), 2);
+
+        for (line, id) in lines.iter().zip([3, 2]) {
+            let value: serde_json::Value = serde_json::from_str(line).unwrap();
+            assert_eq!(value["id"], id);
+        }
+    }
+
+    #[test]
+    fn test_task_list_accepts_count_format() {
+        use crate::cli::commands::{Cli, Commands};
+        use clap::Parser;
+
+        let cli = Cli::try_parse_from(["deverp", "task", "list", "--format", "count"]).unwrap();
+
+        assert_eq!(cli.format, OutputFormat::Count);
+        assert!(matches!(cli.command, Commands::Task(TaskCommand::List(_))));
+    }
+
+    #[test]
+    fn test_parse_task_import_json_and_yaml() {
+        let json = r#"[
+            {"title": "Set up CI", "priority": "high", "estimated_hours": 3.5},
+            {"title": "Write docs", "tags": ["docs"], "task_type": "docs"}
+        ]"#;
+        let tasks = parse_task_import(json, false).unwrap();
+        assert_eq!(tasks.len(), 2);
+        assert_eq!(tasks[0].priority, Some(TaskPriority::High));
+        assert_eq!(tasks[0].project_id, 0);
+        assert_eq!(tasks[1].tags, Some(vec!["docs".to_string()]));
+
+        let yaml = "- title: Set up CI\n  status: in_progress\n- title: Write docs\n";
+        let tasks = parse_task_import(yaml, true).unwrap();
+        assert_eq!(tasks[0].status, Some(TaskStatus::InProgress));
+        assert_eq!(tasks[1].title, "Write docs");
+
+        assert!(parse_task_import(r#"[{"description": "no title"}]"#, false).is_err());
+        assert!(parse_task_import("{not json", false).is_err());
+        assert!(is_yaml_path(Path::new("backlog.YML")));
+        assert!(!is_yaml_path(Path::new("backlog.json")));
+    }
+}
@@ -0,0 +1,220 @@
+// Stored template CLI commands implementation
+
+use chrono::{Local, NaiveDate};
+use colored::Colorize;
+use std::sync::Arc;
+
+use super::commands::{ApplyTemplateArgs, OutputFormat, SaveTemplateArgs, TemplateCommand};
+use super::context::AppContext;
+use super::output::{
+    self, empty_state, outln, output_structured, section_title, summary_line, OutputSink,
+};
+use crate::domain::template::{AppliedTemplate, TemplateService};
+use crate::infrastructure::repositories::template_repo::PostgresTemplateRepository;
+use crate::utils::error::DevErpError;
+use crate::utils::retry::retry_transient;
+use crate::Result;
+
+/// Handle template commands
+pub async fn handle(
+    ctx: &AppContext,
+    command: TemplateCommand,
+    format: OutputFormat,
+) -> Result<()> {
+    match command {
+        TemplateCommand::Save(args) => handle_save(ctx, args, format).await,
+        TemplateCommand::Apply(args) => handle_apply(ctx, args, format).await,
+        TemplateCommand::List => handle_list(ctx, format).await,
+    }
+}
+
+/// Create the template service on the shared pool
+fn create_service(ctx: &AppContext) -> TemplateService {
+    let pool = ctx.pool();
+    TemplateService::new(Arc::new(PostgresTemplateRepository::new(pool)))
+}
+
+/// Parse the date template offsets are relative to, defaulting to today
+pub(super) fn parse_start_date(start: Option<String>) -> Result<NaiveDate> {
+    match start {
+        Some(date_str) => NaiveDate::parse_from_str(&date_str, "%Y-%m-%d").map_err(|_| {
+            DevErpError::Validation(format!(
+                "Invalid start date format: {}. Expected YYYY-MM-DD",
+                date_str
+            ))
+        }),
+        None => Ok(Local::now().date_naive()),
+    }
+}
+
+/// Print a summary of the entities created from a template or another project
+///
+/// `origin` finishes the sentence "Project created from ...".
+pub(super) fn print_applied_template(
+    out: &dyn OutputSink,
+    applied: &AppliedTemplate,
+    origin: &str,
+    start: NaiveDate,
+) {
+    let project = &applied.project;
+    outln!(
+        out,
+        "{} Project created from {}",
+        "✓".green().bold(),
+        origin.cyan()
+    );
+    outln!(out);
+    summary_line(out, "ID", &project.id.to_string());
+    summary_line(out, "Name", &project.name);
+    if let Some(ref code) = project.code {
+        summary_line(out, "Code", code);
+    }
+    summary_line(out, "Start Date", &start.to_string());
+    if let Some(end) = project.end_date {
+        summary_line(out, "End Date", &end.to_string());
+    }
+    summary_line(out, "Timelines", &applied.timelines.len().to_string());
+    summary_line(out, "Milestones", &applied.milestones.len().to_string());
+    summary_line(out, "Tasks", &applied.tasks.len().to_string());
+    outln!(out);
+}
+
+/// Handle template save command
+async fn handle_save(ctx: &AppContext, args: SaveTemplateArgs, format: OutputFormat) -> Result<()> {
+    let out = ctx.out();
+    let service = create_service(ctx);
+
+    if ctx.dry_run() {
+        output::dry_run(
+            out,
+            &format!(
+                "save project #{} as template '{}'",
+                args.project_id, args.name
+            ),
+        );
+        return Ok(());
+    }
+
+    let stored = service.save_template(args.project_id, &args.name).await?;
+
+    if format.is_structured() {
+        return output_structured(out, format, &stored);
+    }
+
+    outln!(
+        out,
+        "{} Project {} saved as template {} v{}",
+        "✓".green().bold(),
+        args.project_id,
+        stored.name.cyan(),
+        stored.version
+    );
+    outln!(out);
+    summary_line(
+        out,
+        "Timelines",
+        &stored.template.timelines.len().to_string(),
+    );
+    summary_line(
+        out,
+        "Milestones",
+        &stored
+            .template
+            .timelines
+            .iter()
+            .map(|t| t.milestones.len())
+            .sum::<usize>()
+            .to_string(),
+    );
+    summary_line(out, "Tasks", &stored.template.tasks.len().to_string());
+    outln!(out);
+
+    Ok(())
+}
+
+/// Handle template apply command
+async fn handle_apply(
+    ctx: &AppContext,
+    args: ApplyTemplateArgs,
+    format: OutputFormat,
+) -> Result<()> {
+    let out = ctx.out();
+    let start = parse_start_date(args.start)?;
+    let service = create_service(ctx);
+
+    let stored = service
+        .get_stored_template(&args.template, args.version)
+        .await?;
+
+    if ctx.dry_run() {
+        output::dry_run(
+            out,
+            &format!(
+                "create a project from template {} v{} starting {}",
+                stored.name, stored.version, start
+            ),
+        );
+        return Ok(());
+    }
+
+    let applied = retry_transient(args.retry.attempts, || {
+        service.apply_template(
+            &stored.template,
+            start,
+            args.name.clone(),
+            args.code.clone(),
+        )
+    })
+    .await?;
+
+    if format.is_structured() {
+        return output_structured(out, format, &applied);
+    }
+
+    let origin = format!("template {} v{}", stored.name, stored.version);
+    print_applied_template(out, &applied, &origin, start);
+
+    Ok(())
+}
+
+/// Handle template list command
+async fn handle_list(ctx: &AppContext, format: OutputFormat) -> Result<()> {
+    let out = ctx.out();
+    let service = create_service(ctx);
+
+    let templates = service.list_stored_templates().await?;
+
+    if format.is_structured() {
+        return output_structured(out, format, &templates);
+    }
+
+    if templates.is_empty() {
+        empty_state(out, "templates");
+        return Ok(());
+    }
+
+    section_title(out, &format!("Templates ({} versions)", templates.len()));
+    outln!(out);
+    output::table_header(
+        out,
+        &["Name", "Version", "Source Project", "Tasks", "Saved"],
+    );
+    for stored in &templates {
+        output::table_row(
+            out,
+            &[
+                stored.name.clone(),
+                stored.version.to_string(),
+                stored
+                    .source_project_id
+                    .map(|id| id.to_string())
+                    .unwrap_or_else(|| "-".to_string()),
+                stored.template.tasks.len().to_string(),
+                stored.created_at.format("%Y-%m-%d %H:%M").to_string(),
+            ],
+        );
+    }
+    outln!(out);
+
+    Ok(())
+}
@@ -5,61 +5,99 @@ use colored::Colorize;
 use std::sync::Arc;
 
 use super::commands::{
-    AddMilestoneArgs, CompleteMilestoneArgs, CreateTimelineArgs, DeleteTimelineArgs,
-    ListTimelineArgs, OutputFormat, ShowTimelineArgs, TimelineCommand, UpdateMilestoneArgs,
-    UpdateTimelineArgs,
+    AddMilestoneArgs, CompleteMilestoneArgs, CreateTimelineArgs, DeleteTimelineArgs, GanttArgs,
+    ListMilestoneArgs, ListTimelineArgs, MilestoneCommand, MilestoneTasksArgs, OutputFormat,
+    RefreshMilestonesArgs, ReorderMilestoneArgs, RestoreMilestoneArgs, RestoreTimelineArgs,
+    ScanMissedArgs, ShiftTimelineArgs, ShowTimelineArgs, TimelineCommand, TimelineProgressArgs,
+    UpdateMilestoneArgs, UpdateTimelineArgs,
 };
-use super::output::{confirm, empty_state, section_title, summary_line};
-use crate::config::settings::Settings;
+use super::config::list_preferences;
+use super::context::AppContext;
+use super::gantt::{render_gantt, terminal_width};
+use super::output::{
+    self, confirm, empty_list, outln, output_structured, section_title, summary_line,
+    PaginatedOutput,
+};
+use crate::domain::task::entity::{CompletionCheck, TaskStatus};
 use crate::domain::timeline::{
     entity::{
-        CreateMilestone, CreateTimeline, MilestoneStatus, TimelineFilter, TimelineStatus,
-        TimelineType, UpdateMilestone, UpdateTimeline,
+        CreateMilestone, CreateTimeline, MilestoneFilter, MilestoneStatus, TimelineDetail,
+        TimelineFilter, TimelineStatus, TimelineType, UpdateMilestone, UpdateTimeline,
     },
     service::TimelineService,
 };
-use crate::infrastructure::{
-    database,
-    repositories::timeline_repo::{PostgresMilestoneRepository, PostgresTimelineRepository},
+use crate::infrastructure::repositories::task_repo::PostgresTaskRepository;
+use crate::infrastructure::repositories::timeline_repo::{
+    PostgresMilestoneRepository, PostgresTimelineRepository,
 };
 use crate::utils::error::DevErpError;
+use crate::utils::retry::retry_transient;
 use crate::Result;
 
 /// Handle timeline commands
-pub async fn handle(command: TimelineCommand, _format: OutputFormat) -> Result<()> {
+pub async fn handle(
+    ctx: &AppContext,
+    command: TimelineCommand,
+    format: OutputFormat,
+) -> Result<()> {
     match command {
-        TimelineCommand::Create(args) => handle_create(args).await,
-        TimelineCommand::List(args) => handle_list(args).await,
-        TimelineCommand::Show(args) => handle_show(args).await,
-        TimelineCommand::Update(args) => handle_update(args).await,
-        TimelineCommand::Delete(args) => handle_delete(args).await,
-        TimelineCommand::AddMilestone(args) => handle_add_milestone(args).await,
-        TimelineCommand::UpdateMilestone(args) => handle_update_milestone(args).await,
-        TimelineCommand::CompleteMilestone(args) => handle_complete_milestone(args).await,
+        TimelineCommand::Create(args) => handle_create(ctx, args).await,
+        TimelineCommand::List(args) => handle_list(ctx, args, format).await,
+        TimelineCommand::Show(args) => handle_show(ctx, args, format).await,
+        TimelineCommand::Update(args) => handle_update(ctx, args).await,
+        TimelineCommand::Shift(args) => handle_shift(ctx, args).await,
+        TimelineCommand::Delete(args) => handle_delete(ctx, args).await,
+        TimelineCommand::Restore(args) => handle_restore(ctx, args).await,
+        TimelineCommand::AddMilestone(args) => handle_add_milestone(ctx, args).await,
+        TimelineCommand::UpdateMilestone(args) => handle_update_milestone(ctx, args).await,
+        TimelineCommand::CompleteMilestone(args) => handle_complete_milestone(ctx, args).await,
+        TimelineCommand::ReorderMilestone(args) => handle_reorder_milestone(ctx, args).await,
+        TimelineCommand::MilestoneTasks(args) => handle_milestone_tasks(ctx, args, format).await,
+        TimelineCommand::Progress(args) => handle_progress(ctx, args, format).await,
+        TimelineCommand::RefreshMilestones(args) => handle_refresh_milestones(ctx, args).await,
+        TimelineCommand::Gantt(args) => handle_gantt(ctx, args).await,
     }
 }
 
-/// Create database connection and timeline service
-async fn create_service() -> Result<TimelineService> {
-    let settings = Settings::default();
-    let pool = database::establish_connection(&settings.database).await?;
+/// Handle milestone commands
+pub async fn handle_milestone(
+    ctx: &AppContext,
+    command: MilestoneCommand,
+    format: OutputFormat,
+) -> Result<()> {
+    match command {
+        MilestoneCommand::List(args) => handle_list_milestones(ctx, args, format).await,
+        MilestoneCommand::Restore(args) => handle_restore_milestone(ctx, args).await,
+        MilestoneCommand::ScanMissed(args) => handle_scan_missed(ctx, args, format).await,
+    }
+}
+
+/// Create the timeline service on the shared pool
+fn create_service(ctx: &AppContext) -> TimelineService {
+    let pool = ctx.pool();
     let timeline_repository = Arc::new(PostgresTimelineRepository::new(pool.clone()));
-    let milestone_repository = Arc::new(PostgresMilestoneRepository::new(pool));
-    Ok(TimelineService::new(
-        timeline_repository,
-        milestone_repository,
-    ))
+    let milestone_repository = Arc::new(PostgresMilestoneRepository::new(pool.clone()));
+    let task_repository = Arc::new(PostgresTaskRepository::new(pool));
+    TimelineService::new(timeline_repository, milestone_repository, task_repository)
 }
 
-/// Handle timeline create command
-async fn handle_create(args: CreateTimelineArgs) -> Result<()> {
-    let service = create_service().await?;
+/// Default timeline type from the `timeline.default_type` configuration
+async fn configured_timeline_type(ctx: &AppContext) -> Result<TimelineType> {
+    ctx.config_service().default_timeline_type().await
+}
 
-    // Parse timeline type
-    let timeline_type = args
-        .timeline_type
-        .parse::<TimelineType>()
-        .map_err(DevErpError::Validation)?;
+/// Handle timeline create command
+async fn handle_create(ctx: &AppContext, args: CreateTimelineArgs) -> Result<()> {
+    let out = ctx.out();
+    let service = create_service(ctx);
+
+    // Parse timeline type, falling back to the configured default
+    let timeline_type = match args.timeline_type {
+        Some(type_str) => type_str
+            .parse::<TimelineType>()
+            .map_err(DevErpError::Validation)?,
+        None => configured_timeline_type(ctx).await?,
+    };
 
     // Parse status if provided
     let status = if let Some(status_str) = args.status {
@@ -98,26 +136,40 @@ async fn handle_create(args: CreateTimelineArgs) -> Result<()> {
         status,
     };
 
+    if ctx.dry_run() {
+        return output::dry_run_input(out, "create this timeline", &input);
+    }
+
     // Create timeline
-    let timeline = service.create_timeline(input).await?;
+    let timeline = retry_transient(args.retry.attempts, || {
+        service.create_timeline(input.clone())
+    })
+    .await?;
 
     // Display success message
-    section_title("Timeline Created");
-    println!("{}: {}", "ID".bright_cyan(), timeline.id);
-    println!("{}: {}", "Name".bright_cyan(), timeline.name.bold());
-    println!("{}: {}", "Project ID".bright_cyan(), timeline.project_id);
-    println!("{}: {}", "Type".bright_cyan(), timeline.timeline_type);
-    println!("{}: {}", "Status".bright_cyan(), timeline.status);
-    println!(
+    section_title(out, "Timeline Created");
+    outln!(out, "{}: {}", "ID".bright_cyan(), timeline.id);
+    outln!(out, "{}: {}", "Name".bright_cyan(), timeline.name.bold());
+    outln!(
+        out,
+        "{}: {}",
+        "Project ID".bright_cyan(),
+        timeline.project_id
+    );
+    outln!(out, "{}: {}", "Type".bright_cyan(), timeline.timeline_type);
+    outln!(out, "{}: {}", "Status".bright_cyan(), timeline.status);
+    outln!(
+        out,
         "{}: {} to {}",
         "Period".bright_cyan(),
         timeline.start_date,
         timeline.end_date
     );
     if let Some(desc) = &timeline.description {
-        println!("{}: {}", "Description".bright_cyan(), desc);
+        outln!(out, "{}: {}", "Description".bright_cyan(), desc);
     }
-    println!(
+    outln!(
+        out,
         "{}: {}",
         "Created".bright_cyan(),
         timeline.created_at.format("%Y-%m-%d %H:%M:%S")
@@ -127,8 +179,12 @@ async fn handle_create(args: CreateTimelineArgs) -> Result<()> {
 }
 
 /// Handle timeline list command
-async fn handle_list(args: ListTimelineArgs) -> Result<()> {
-    let service = create_service().await?;
+async fn handle_list(ctx: &AppContext, args: ListTimelineArgs, format: OutputFormat) -> Result<()> {
+    let out = ctx.out();
+    let service = create_service(ctx);
+    let pagination = args
+        .pagination
+        .or_per_page(list_preferences(ctx).await?.per_page);
 
     // Parse timeline type if provided
     let timeline_type = if let Some(type_str) = args.timeline_type {
@@ -157,39 +213,53 @@ async fn handle_list(args: ListTimelineArgs) -> Result<()> {
         project_id: args.project_id,
         timeline_type,
         status,
-        offset: Some(args.pagination.offset()),
-        limit: Some(args.pagination.limit()),
+        include_deleted: false,
+        offset: Some(pagination.offset()),
+        limit: Some(pagination.limit()),
     };
 
+    if format == OutputFormat::Count {
+        return output::count(out, service.count_timelines(filter).await?);
+    }
+
     // Get timelines
-    let timelines = service.list_timelines(filter).await?;
+    let timelines = service.list_timelines(filter.clone()).await?;
 
     if timelines.is_empty() {
-        empty_state("No timelines found");
+        let total = service.count_timelines(TimelineFilter::default()).await?;
+        empty_list(
+            out,
+            "timelines",
+            total,
+            "deverp timeline create --project-id <ID> --name <NAME>",
+        );
         return Ok(());
     }
 
     // Display timelines
-    section_title(&format!("Timelines ({})", timelines.len()));
-    println!();
+    section_title(out, &format!("Timelines ({})", timelines.len()));
+    outln!(out);
 
-    for timeline in timelines {
-        println!("  {} {}", "●".bright_green(), timeline.name.bold());
-        println!(
+    for timeline in &timelines {
+        outln!(out, "  {} {}", "●".bright_green(), timeline.name.bold());
+        outln!(
+            out,
             "    {}: {} | {}: {}",
             "ID".dimmed(),
             timeline.id,
             "Project".dimmed(),
             timeline.project_id
         );
-        println!(
+        outln!(
+            out,
             "    {}: {} | {}: {}",
             "Type".dimmed(),
             timeline.timeline_type,
             "Status".dimmed(),
             timeline.status
         );
-        println!(
+        outln!(
+            out,
             "    {}: {} to {}",
             "Period".dimmed(),
             timeline.start_date,
@@ -201,17 +271,23 @@ async fn handle_list(args: ListTimelineArgs) -> Result<()> {
             } else {
                 desc.clone()
             };
-            println!("    {}: {}", "Description".dimmed(), short_desc);
+            outln!(out, "    {}: {}", "Description".dimmed(), short_desc);
         }
-        println!();
+        outln!(out);
     }
 
+    // Show pagination info
+    let total = service.count_timelines(filter).await?;
+    let output = PaginatedOutput::new(timelines, pagination.page, pagination.per_page(), total);
+    output.print_metadata(out);
+
     Ok(())
 }
 
 /// Handle timeline show command
-async fn handle_show(args: ShowTimelineArgs) -> Result<()> {
-    let service = create_service().await?;
+async fn handle_show(ctx: &AppContext, args: ShowTimelineArgs, format: OutputFormat) -> Result<()> {
+    let out = ctx.out();
+    let service = create_service(ctx);
 
     // Get timeline
     let timeline = service.get_timeline(args.id).await?;
@@ -219,15 +295,32 @@ async fn handle_show(args: ShowTimelineArgs) -> Result<()> {
     // Get milestones for this timeline
     let milestones = service.get_milestones_by_timeline(args.id).await?;
 
+    if format.is_structured() {
+        return output_structured(
+            out,
+            format,
+            &TimelineDetail {
+                timeline,
+                milestones,
+            },
+        );
+    }
+
     // Display timeline details
-    section_title("Timeline Details");
-    println!();
-    println!("{}: {}", "ID".bright_cyan(), timeline.id);
-    println!("{}: {}", "Name".bright_cyan(), timeline.name.bold());
-    println!("{}: {}", "Project ID".bright_cyan(), timeline.project_id);
-    println!("{}: {}", "Type".bright_cyan(), timeline.timeline_type);
-    println!("{}: {}", "Status".bright_cyan(), timeline.status);
-    println!(
+    section_title(out, "Timeline Details");
+    outln!(out);
+    outln!(out, "{}: {}", "ID".bright_cyan(), timeline.id);
+    outln!(out, "{}: {}", "Name".bright_cyan(), timeline.name.bold());
+    outln!(
+        out,
+        "{}: {}",
+        "Project ID".bright_cyan(),
+        timeline.project_id
+    );
+    outln!(out, "{}: {}", "Type".bright_cyan(), timeline.timeline_type);
+    outln!(out, "{}: {}", "Status".bright_cyan(), timeline.status);
+    outln!(
+        out,
         "{}: {} to {}",
         "Period".bright_cyan(),
         timeline.start_date,
@@ -235,18 +328,20 @@ async fn handle_show(args: ShowTimelineArgs) -> Result<()> {
     );
 
     if let Some(desc) = &timeline.description {
-        println!();
-        println!("{}:", "Description".bright_cyan());
-        println!("  {}", desc);
+        outln!(out);
+        outln!(out, "{}:", "Description".bright_cyan());
+        outln!(out, "  {}", desc);
     }
 
-    println!();
-    println!(
+    outln!(out);
+    outln!(
+        out,
         "{}: {}",
         "Created".bright_cyan(),
         timeline.created_at.format("%Y-%m-%d %H:%M:%S")
     );
-    println!(
+    outln!(
+        out,
         "{}: {}",
         "Updated".bright_cyan(),
         timeline.updated_at.format("%Y-%m-%d %H:%M:%S")
@@ -254,9 +349,9 @@ async fn handle_show(args: ShowTimelineArgs) -> Result<()> {
 
     // Display milestones
     if !milestones.is_empty() {
-        println!();
-        section_title(&format!("Milestones ({})", milestones.len()));
-        println!();
+        outln!(out);
+        section_title(out, &format!("Milestones ({})", milestones.len()));
+        outln!(out);
 
         for milestone in milestones {
             let status_color = match milestone.status.as_str() {
@@ -266,15 +361,17 @@ async fn handle_show(args: ShowTimelineArgs) -> Result<()> {
                 _ => "○".dimmed(),
             };
 
-            println!("  {} {}", status_color, milestone.name.bold());
-            println!(
+            outln!(out, "  {} {}", status_color, milestone.name.bold());
+            outln!(
+                out,
                 "    {}: {} | {}: {}%",
                 "ID".dimmed(),
                 milestone.id,
                 "Progress".dimmed(),
                 milestone.completion_percentage
             );
-            println!(
+            outln!(
+                out,
                 "    {}: {} | {}: {}",
                 "Target".dimmed(),
                 milestone.target_date,
@@ -282,7 +379,7 @@ async fn handle_show(args: ShowTimelineArgs) -> Result<()> {
                 milestone.status
             );
             if let Some(actual) = milestone.actual_date {
-                println!("    {}: {}", "Completed".dimmed(), actual);
+                outln!(out, "    {}: {}", "Completed".dimmed(), actual);
             }
             if let Some(desc) = &milestone.description {
                 let short_desc = if desc.len() > 50 {
@@ -290,18 +387,60 @@ async fn handle_show(args: ShowTimelineArgs) -> Result<()> {
                 } else {
                     desc.clone()
                 };
-                println!("    {}: {}", "Description".dimmed(), short_desc);
+                outln!(out, "    {}: {}", "Description".dimmed(), short_desc);
             }
-            println!();
+            outln!(out);
         }
     }
 
     Ok(())
 }
 
+/// Handle timeline gantt command
+async fn handle_gantt(ctx: &AppContext, args: GanttArgs) -> Result<()> {
+    let out = ctx.out();
+    let service = create_service(ctx);
+
+    let mut timelines = service.get_timelines_by_project(args.project_id).await?;
+    timelines.sort_by_key(|t| (t.start_date, t.id));
+    if timelines.is_empty() {
+        empty_list(
+            out,
+            "timelines",
+            0,
+            "deverp timeline create --project-id <ID> --name <NAME>",
+        );
+        return Ok(());
+    }
+
+    let mut milestones = service.get_milestones_by_project(args.project_id).await?;
+    milestones.sort_by_key(|m| m.target_date);
+    let rows: Vec<_> = timelines
+        .into_iter()
+        .map(|timeline| {
+            let own = milestones
+                .iter()
+                .filter(|m| m.timeline_id == timeline.id)
+                .cloned()
+                .collect();
+            (timeline, own)
+        })
+        .collect();
+
+    section_title(out, &format!("Project {} Timeline", args.project_id));
+    outln!(out);
+    for line in render_gantt(&rows, terminal_width()) {
+        outln!(out, "{}", line);
+    }
+    outln!(out);
+
+    Ok(())
+}
+
 /// Handle timeline update command
-async fn handle_update(args: UpdateTimelineArgs) -> Result<()> {
-    let service = create_service().await?;
+async fn handle_update(ctx: &AppContext, args: UpdateTimelineArgs) -> Result<()> {
+    let out = ctx.out();
+    let service = create_service(ctx);
 
     // Parse timeline type if provided
     let timeline_type = if let Some(type_str) = args.timeline_type {
@@ -363,14 +502,22 @@ async fn handle_update(args: UpdateTimelineArgs) -> Result<()> {
         status,
     };
 
+    if ctx.dry_run() {
+        return output::dry_run_input(out, &format!("update timeline #{}", args.id), &input);
+    }
+
     // Update timeline
-    let timeline = service.update_timeline(input).await?;
+    let timeline = retry_transient(args.retry.attempts, || {
+        service.update_timeline(input.clone())
+    })
+    .await?;
 
     // Display success message
-    section_title("Timeline Updated");
-    println!("{}: {}", "ID".bright_cyan(), timeline.id);
-    println!("{}: {}", "Name".bright_cyan(), timeline.name.bold());
-    println!(
+    section_title(out, "Timeline Updated");
+    outln!(out, "{}: {}", "ID".bright_cyan(), timeline.id);
+    outln!(out, "{}: {}", "Name".bright_cyan(), timeline.name.bold());
+    outln!(
+        out,
         "{}: {}",
         "Updated".bright_cyan(),
         timeline.updated_at.format("%Y-%m-%d %H:%M:%S")
@@ -380,12 +527,27 @@ async fn handle_update(args: UpdateTimelineArgs) -> Result<()> {
 }
 
 /// Handle timeline delete command
-async fn handle_delete(args: DeleteTimelineArgs) -> Result<()> {
-    let service = create_service().await?;
+async fn handle_delete(ctx: &AppContext, args: DeleteTimelineArgs) -> Result<()> {
+    let out = ctx.out();
+    let service = create_service(ctx);
 
     // Get timeline to display name
     let timeline = service.get_timeline(args.id).await?;
 
+    if ctx.dry_run() {
+        output::dry_run(out, &format!("delete timeline '{}'", timeline.name));
+        summary_line(out, "ID", &timeline.id.to_string());
+        summary_line(out, "Name", &timeline.name);
+        summary_line(out, "Project ID", &timeline.project_id.to_string());
+        summary_line(
+            out,
+            "Dates",
+            &format!("{} → {}", timeline.start_date, timeline.end_date),
+        );
+        outln!(out);
+        return Ok(());
+    }
+
     // Confirm deletion
     if !args.confirm {
         let confirmed = confirm(&format!(
@@ -393,15 +555,16 @@ async fn handle_delete(args: DeleteTimelineArgs) -> Result<()> {
             timeline.name
         ));
         if !confirmed {
-            println!("Deletion cancelled.");
+            outln!(out, "Deletion cancelled.");
             return Ok(());
         }
     }
 
     // Delete timeline
-    service.delete_timeline(args.id).await?;
+    retry_transient(args.retry.attempts, || service.delete_timeline(args.id)).await?;
 
     summary_line(
+        out,
         "Timeline Deleted",
         &format!("'{}' deleted successfully", timeline.name),
     );
@@ -409,9 +572,54 @@ async fn handle_delete(args: DeleteTimelineArgs) -> Result<()> {
     Ok(())
 }
 
+/// Handle timeline restore command
+async fn handle_restore(ctx: &AppContext, args: RestoreTimelineArgs) -> Result<()> {
+    let out = ctx.out();
+    let service = create_service(ctx);
+
+    if ctx.dry_run() {
+        output::dry_run(out, &format!("restore timeline #{}", args.id));
+        return Ok(());
+    }
+
+    service.restore_timeline(args.id).await?;
+
+    let timeline = service.get_timeline(args.id).await?;
+    summary_line(
+        out,
+        "Timeline Restored",
+        &format!("'{}' restored successfully", timeline.name),
+    );
+
+    Ok(())
+}
+
+/// Handle milestone restore command
+async fn handle_restore_milestone(ctx: &AppContext, args: RestoreMilestoneArgs) -> Result<()> {
+    let out = ctx.out();
+    let service = create_service(ctx);
+
+    if ctx.dry_run() {
+        output::dry_run(out, &format!("restore milestone #{}", args.id));
+        return Ok(());
+    }
+
+    service.restore_milestone(args.id).await?;
+
+    let milestone = service.get_milestone(args.id).await?;
+    summary_line(
+        out,
+        "Milestone Restored",
+        &format!("'{}' restored successfully", milestone.name),
+    );
+
+    Ok(())
+}
+
 /// Handle add milestone command
-async fn handle_add_milestone(args: AddMilestoneArgs) -> Result<()> {
-    let service = create_service().await?;
+async fn handle_add_milestone(ctx: &AppContext, args: AddMilestoneArgs) -> Result<()> {
+    let out = ctx.out();
+    let service = create_service(ctx);
 
     // Parse status if provided
     let status = if let Some(status_str) = args.status {
@@ -433,6 +641,7 @@ async fn handle_add_milestone(args: AddMilestoneArgs) -> Result<()> {
     })?;
 
     // Create milestone input
+    let linked_tasks = args.link_tasks;
     let input = CreateMilestone {
         timeline_id: args.timeline_id,
         project_id: args.project_id,
@@ -442,22 +651,42 @@ async fn handle_add_milestone(args: AddMilestoneArgs) -> Result<()> {
         status,
         completion_percentage: None,
         metadata: None,
+        task_ids: linked_tasks.clone(),
     };
 
+    if ctx.dry_run() {
+        return output::dry_run_input(out, "add this milestone", &input);
+    }
+
     // Create milestone
     let milestone = service.create_milestone(input).await?;
 
     // Display success message
-    section_title("Milestone Added");
-    println!("{}: {}", "ID".bright_cyan(), milestone.id);
-    println!("{}: {}", "Name".bright_cyan(), milestone.name.bold());
-    println!("{}: {}", "Timeline ID".bright_cyan(), milestone.timeline_id);
-    println!("{}: {}", "Target Date".bright_cyan(), milestone.target_date);
-    println!("{}: {}", "Status".bright_cyan(), milestone.status);
+    section_title(out, "Milestone Added");
+    outln!(out, "{}: {}", "ID".bright_cyan(), milestone.id);
+    outln!(out, "{}: {}", "Name".bright_cyan(), milestone.name.bold());
+    outln!(
+        out,
+        "{}: {}",
+        "Timeline ID".bright_cyan(),
+        milestone.timeline_id
+    );
+    outln!(
+        out,
+        "{}: {}",
+        "Target Date".bright_cyan(),
+        milestone.target_date
+    );
+    outln!(out, "{}: {}", "Status".bright_cyan(), milestone.status);
     if let Some(desc) = &milestone.description {
-        println!("{}: {}", "Description".bright_cyan(), desc);
+        outln!(out, "{}: {}", "Description".bright_cyan(), desc);
+    }
+    if !linked_tasks.is_empty() {
+        let ids: Vec<String> = linked_tasks.iter().map(|id| id.to_string()).collect();
+        outln!(out, "{}: {}", "Linked Tasks".bright_cyan(), ids.join(", "));
     }
-    println!(
+    outln!(
+        out,
         "{}: {}",
         "Created".bright_cyan(),
         milestone.created_at.format("%Y-%m-%d %H:%M:%S")
@@ -467,8 +696,9 @@ async fn handle_add_milestone(args: AddMilestoneArgs) -> Result<()> {
 }
 
 /// Handle update milestone command
-async fn handle_update_milestone(args: UpdateMilestoneArgs) -> Result<()> {
-    let service = create_service().await?;
+async fn handle_update_milestone(ctx: &AppContext, args: UpdateMilestoneArgs) -> Result<()> {
+    let out = ctx.out();
+    let service = create_service(ctx);
 
     // Parse status if provided
     let status = if let Some(status_str) = args.status {
@@ -530,20 +760,39 @@ async fn handle_update_milestone(args: UpdateMilestoneArgs) -> Result<()> {
         metadata: None,
     };
 
+    if ctx.dry_run() {
+        return output::dry_run_input(out, &format!("update milestone #{}", args.id), &input);
+    }
+
     // Update milestone
-    let milestone = service.update_milestone(input).await?;
+    let check = if args.force {
+        CompletionCheck::Skip
+    } else {
+        CompletionCheck::Enforce
+    };
+    let gated =
+        check == CompletionCheck::Enforce && input.status == Some(MilestoneStatus::Completed);
+    let milestone = service.update_milestone(input, check).await.map_err(|e| {
+        if gated {
+            output::with_force_hint(e)
+        } else {
+            e
+        }
+    })?;
 
     // Display success message
-    section_title("Milestone Updated");
-    println!("{}: {}", "ID".bright_cyan(), milestone.id);
-    println!("{}: {}", "Name".bright_cyan(), milestone.name.bold());
-    println!(
+    section_title(out, "Milestone Updated");
+    outln!(out, "{}: {}", "ID".bright_cyan(), milestone.id);
+    outln!(out, "{}: {}", "Name".bright_cyan(), milestone.name.bold());
+    outln!(
+        out,
         "{}: {}%",
         "Progress".bright_cyan(),
         milestone.completion_percentage
     );
-    println!("{}: {}", "Status".bright_cyan(), milestone.status);
-    println!(
+    outln!(out, "{}: {}", "Status".bright_cyan(), milestone.status);
+    outln!(
+        out,
         "{}: {}",
         "Updated".bright_cyan(),
         milestone.updated_at.format("%Y-%m-%d %H:%M:%S")
@@ -553,8 +802,9 @@ async fn handle_update_milestone(args: UpdateMilestoneArgs) -> Result<()> {
 }
 
 /// Handle complete milestone command
-async fn handle_complete_milestone(args: CompleteMilestoneArgs) -> Result<()> {
-    let service = create_service().await?;
+async fn handle_complete_milestone(ctx: &AppContext, args: CompleteMilestoneArgs) -> Result<()> {
+    let out = ctx.out();
+    let service = create_service(ctx);
 
     // Parse actual date or use today
     let actual_date = if let Some(date_str) = args.actual_date {
@@ -580,18 +830,414 @@ async fn handle_complete_milestone(args: CompleteMilestoneArgs) -> Result<()> {
         metadata: None,
     };
 
-    let milestone = service.update_milestone(input).await?;
+    if ctx.dry_run() {
+        let milestone = service.get_milestone(args.id).await?;
+        output::dry_run(
+            out,
+            &format!(
+                "mark milestone '{}' completed on {}",
+                milestone.name, actual_date
+            ),
+        );
+        return Ok(());
+    }
+
+    let milestone = if args.force {
+        service
+            .update_milestone(input, CompletionCheck::Skip)
+            .await?
+    } else {
+        service
+            .update_milestone(input, CompletionCheck::Enforce)
+            .await
+            .map_err(output::with_force_hint)?
+    };
 
     // Display success message
-    section_title("Milestone Completed");
-    println!("{}: {}", "ID".bright_cyan(), milestone.id);
-    println!("{}: {}", "Name".bright_cyan(), milestone.name.bold());
-    println!(
+    section_title(out, "Milestone Completed");
+    outln!(out, "{}: {}", "ID".bright_cyan(), milestone.id);
+    outln!(out, "{}: {}", "Name".bright_cyan(), milestone.name.bold());
+    outln!(
+        out,
         "{}: {}",
         "Completed On".bright_cyan(),
         milestone.actual_date.unwrap()
     );
-    println!("{}: {}", "Status".bright_cyan(), milestone.status);
+    outln!(out, "{}: {}", "Status".bright_cyan(), milestone.status);
+
+    Ok(())
+}
+
+/// Handle timeline reorder-milestone command
+async fn handle_reorder_milestone(ctx: &AppContext, args: ReorderMilestoneArgs) -> Result<()> {
+    let out = ctx.out();
+    let service = create_service(ctx);
+
+    if ctx.dry_run() {
+        let milestone = service.get_milestone(args.id).await?;
+        output::dry_run(
+            out,
+            &format!(
+                "move milestone '{}' to position {} in timeline {}",
+                milestone.name, args.position, milestone.timeline_id
+            ),
+        );
+        return Ok(());
+    }
+
+    let milestones = service
+        .reorder_milestone(args.id, args.position as usize)
+        .await?;
+
+    section_title(out, "Milestone Reordered");
+    for milestone in &milestones {
+        let line = format!(
+            "  {}. {} {}",
+            milestone.sequence,
+            milestone.target_date.to_string().dimmed(),
+            milestone.name
+        );
+        if milestone.id == args.id {
+            outln!(out, "{}", line.bold());
+        } else {
+            outln!(out, "{}", line);
+        }
+    }
+
+    Ok(())
+}
+
+/// Handle timeline milestone-tasks command
+async fn handle_milestone_tasks(
+    ctx: &AppContext,
+    args: MilestoneTasksArgs,
+    format: OutputFormat,
+) -> Result<()> {
+    let out = ctx.out();
+    let service = create_service(ctx);
+
+    let milestone = service.get_milestone(args.id).await?;
+    let tasks = service.milestone_tasks(args.id).await?;
+
+    if format.is_structured() {
+        return output_structured(out, format, &tasks);
+    }
+
+    let progress = service.milestone_task_progress(args.id).await?;
+
+    section_title(out, &format!("Milestone Tasks: {}", milestone.name));
+    summary_line(
+        out,
+        "Done",
+        &format!("{}/{}", progress.done_tasks, progress.total_tasks),
+    );
+
+    if tasks.is_empty() {
+        outln!(out);
+        outln!(out, "  {}", "No tasks linked to this milestone.".dimmed());
+        return Ok(());
+    }
+
+    outln!(out);
+    for task in &tasks {
+        let status = match task.status {
+            TaskStatus::Done => task.status.to_string().green(),
+            TaskStatus::Cancelled => task.status.to_string().dimmed(),
+            _ => task.status.to_string().yellow(),
+        };
+        outln!(
+            out,
+            "  {} {} [{}]",
+            format!("#{}", task.id).yellow(),
+            task.title.bold(),
+            status
+        );
+    }
+    outln!(out);
+
+    Ok(())
+}
+
+/// Handle timeline shift command
+async fn handle_shift(ctx: &AppContext, args: ShiftTimelineArgs) -> Result<()> {
+    let out = ctx.out();
+    let floor = ctx.config_service().timeline_date_floor().await?;
+    let service = create_service(ctx).with_date_floor(floor);
+
+    if ctx.dry_run() {
+        let timeline = service.get_timeline(args.id).await?;
+        output::dry_run(
+            out,
+            &format!(
+                "move timeline '{}' and its milestones by {} day(s)",
+                timeline.name, args.days
+            ),
+        );
+        return Ok(());
+    }
+
+    let shift = service.shift_timeline(args.id, args.days).await?;
+
+    outln!(
+        out,
+        "{} Shifted timeline '{}' by {} day(s)",
+        "✓".green().bold(),
+        shift.timeline.name,
+        shift.days
+    );
+    outln!(
+        out,
+        "  {} {} to {}",
+        "Dates:".bright_cyan(),
+        shift.timeline.start_date,
+        shift.timeline.end_date
+    );
+    outln!(
+        out,
+        "  {} {}",
+        "Milestones moved:".bright_cyan(),
+        shift.milestones_moved
+    );
+
+    Ok(())
+}
+
+/// Handle timeline progress command
+async fn handle_progress(
+    ctx: &AppContext,
+    args: TimelineProgressArgs,
+    format: OutputFormat,
+) -> Result<()> {
+    let out = ctx.out();
+    let service = create_service(ctx);
+
+    let progress = service.progress(args.id).await?;
+
+    if format.is_structured() {
+        return output_structured(out, format, &progress);
+    }
+
+    section_title(
+        out,
+        &format!("Timeline Progress: {}", progress.timeline_name),
+    );
+    outln!(out);
+    summary_line(
+        out,
+        "Completion",
+        &format!("{:.1}%", progress.completion_percentage),
+    );
+    summary_line(out, "Completed", &progress.completed_milestones.to_string());
+    summary_line(out, "Missed", &progress.missed_milestones.to_string());
+    summary_line(out, "Upcoming", &progress.upcoming_milestones.to_string());
+
+    if progress.milestones.is_empty() {
+        outln!(out);
+        outln!(out, "  {}", "No milestones on this timeline.".dimmed());
+        return Ok(());
+    }
+
+    outln!(out);
+    for milestone in &progress.milestones {
+        let source = if milestone.task_derived {
+            " (from tasks)"
+        } else {
+            ""
+        };
+        outln!(
+            out,
+            "  {} {} - {:.0}%{} [{}]",
+            milestone.target_date.to_string().dimmed(),
+            milestone.name.bold(),
+            milestone.completion_percentage,
+            source.dimmed(),
+            milestone.status
+        );
+    }
+    outln!(out);
+
+    Ok(())
+}
+
+/// Handle timeline refresh-milestones command
+async fn handle_refresh_milestones(ctx: &AppContext, args: RefreshMilestonesArgs) -> Result<()> {
+    let out = ctx.out();
+    let service = create_service(ctx);
+
+    if ctx.dry_run() {
+        output::dry_run(
+            out,
+            &format!(
+                "mark the overdue milestones in timeline {} as missed",
+                args.timeline_id
+            ),
+        );
+        return Ok(());
+    }
+
+    let today = Local::now().date_naive();
+    let updated = service
+        .refresh_milestone_statuses(args.timeline_id, today)
+        .await?;
+
+    if updated == 0 {
+        outln!(
+            out,
+            "{} No overdue milestones in timeline {}",
+            "✓".green().bold(),
+            args.timeline_id
+        );
+    } else {
+        outln!(
+            out,
+            "{} Marked {} milestone(s) in timeline {} as missed",
+            "✓".green().bold(),
+            updated,
+            args.timeline_id
+        );
+    }
+
+    Ok(())
+}
+
+/// Handle milestone list command
+async fn handle_list_milestones(
+    ctx: &AppContext,
+    args: ListMilestoneArgs,
+    format: OutputFormat,
+) -> Result<()> {
+    let out = ctx.out();
+    let service = create_service(ctx);
+    let pagination = args
+        .pagination
+        .or_per_page(list_preferences(ctx).await?.per_page);
+
+    // Parse status if provided
+    let status = if let Some(status_str) = args.status {
+        Some(
+            status_str
+                .parse::<MilestoneStatus>()
+                .map_err(DevErpError::Validation)?,
+        )
+    } else {
+        None
+    };
+
+    let today = Local::now().date_naive();
+    let filter = MilestoneFilter {
+        timeline_id: args.timeline_id,
+        project_id: args.project_id,
+        status,
+        overdue_as_of: args.overdue.then_some(today),
+        include_deleted: false,
+        offset: Some(pagination.offset()),
+        limit: Some(pagination.limit()),
+    };
+
+    if format == OutputFormat::Count {
+        return output::count(out, service.count_milestones(filter).await?);
+    }
+
+    let milestones = service.list_milestones(filter).await?;
+
+    if format.is_structured() {
+        return output_structured(out, format, &milestones);
+    }
+
+    if milestones.is_empty() {
+        if args.overdue {
+            outln!(out, "{} No overdue milestones found", "✓".green().bold());
+        } else {
+            outln!(out, "{}", "No milestones found.".yellow());
+        }
+        return Ok(());
+    }
+
+    let title = if args.overdue {
+        "Overdue Milestones"
+    } else {
+        "Milestones"
+    };
+    section_title(out, &format!("{} ({})", title, milestones.len()));
+    outln!(out);
+    for milestone in &milestones {
+        let late = if milestone.target_date < today
+            && matches!(
+                milestone.status,
+                MilestoneStatus::Pending | MilestoneStatus::InProgress
+            ) {
+            format!(
+                ", {} day(s) late",
+                (today - milestone.target_date).num_days()
+            )
+            .red()
+            .to_string()
+        } else {
+            String::new()
+        };
+        outln!(
+            out,
+            "  {} #{} {} (project {}, timeline {}) - {} due {}{}",
+            "●".bright_green(),
+            milestone.id,
+            milestone.name.bold(),
+            milestone.project_id,
+            milestone.timeline_id,
+            milestone.status,
+            milestone.target_date,
+            late
+        );
+    }
+    outln!(out);
+
+    Ok(())
+}
+
+/// Handle milestone scan-missed command
+async fn handle_scan_missed(
+    ctx: &AppContext,
+    args: ScanMissedArgs,
+    format: OutputFormat,
+) -> Result<()> {
+    let out = ctx.out();
+    let service = create_service(ctx);
+
+    if ctx.dry_run() {
+        output::dry_run(out, "mark overdue milestones as missed");
+        return Ok(());
+    }
+
+    let today = Local::now().date_naive();
+    let missed = service
+        .scan_missed_milestones(args.project_id, today)
+        .await?;
+
+    if format.is_structured() {
+        return output_structured(out, format, &missed);
+    }
+
+    if missed.is_empty() {
+        outln!(out, "{} No overdue milestones found", "✓".green().bold());
+        return Ok(());
+    }
+
+    section_title(out, &format!("Milestones Marked Missed ({})", missed.len()));
+    outln!(out);
+    for milestone in &missed {
+        let days_late = (today - milestone.target_date).num_days();
+        outln!(
+            out,
+            "  {} #{} {} (project {}, timeline {}) - due {}, {} day(s) late",
+            "✗".red().bold(),
+            milestone.id,
+            milestone.name.bold(),
+            milestone.project_id,
+            milestone.timeline_id,
+            milestone.target_date,
+            days_late
+        );
+    }
+    outln!(out);
 
     Ok(())
 }
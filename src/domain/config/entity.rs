@@ -2,6 +2,8 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 
+use crate::domain::project::entity::ProjectSortField;
+
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
 pub struct Configuration {
     pub id: i64,
@@ -23,6 +25,8 @@ pub enum ConfigDataType {
     String,
     #[serde(rename = "integer")]
     Integer,
+    #[serde(rename = "float")]
+    Float,
     #[serde(rename = "boolean")]
     Boolean,
     #[serde(rename = "json")]
@@ -34,12 +38,43 @@ impl std::fmt::Display for ConfigDataType {
         match self {
             ConfigDataType::String => write!(f, "string"),
             ConfigDataType::Integer => write!(f, "integer"),
+            ConfigDataType::Float => write!(f, "float"),
             ConfigDataType::Boolean => write!(f, "boolean"),
             ConfigDataType::Json => write!(f, "json"),
         }
     }
 }
 
+/// Accepts the stored names and the short forms `int` and `bool`
+impl std::str::FromStr for ConfigDataType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "string" => Ok(ConfigDataType::String),
+            "integer" | "int" => Ok(ConfigDataType::Integer),
+            "float" => Ok(ConfigDataType::Float),
+            "boolean" | "bool" => Ok(ConfigDataType::Boolean),
+            "json" => Ok(ConfigDataType::Json),
+            _ => Err(format!(
+                "Invalid configuration type: {} (expected string, int, float, bool or json)",
+                s
+            )),
+        }
+    }
+}
+
+/// A configuration value parsed according to its data type
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(untagged)]
+pub enum ConfigValue {
+    String(String),
+    Integer(i64),
+    Float(f64),
+    Boolean(bool),
+    Json(serde_json::Value),
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateConfiguration {
     pub config_key: String,
@@ -57,6 +92,32 @@ pub struct UpdateConfiguration {
     pub description: Option<String>,
 }
 
+/// A stored configuration whose value does not parse as its data type
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct InvalidConfiguration {
+    pub config_key: String,
+    pub config_value: String,
+    pub data_type: ConfigDataType,
+    pub error: String,
+}
+
+/// Stored defaults for list commands, applied when the flags are omitted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ListPreferences {
+    pub per_page: u32,
+    /// Sort for `project list`; `None` keeps the repository's default order
+    pub order_by: Option<ProjectSortField>,
+}
+
+impl Default for ListPreferences {
+    fn default() -> Self {
+        Self {
+            per_page: 50,
+            order_by: None,
+        }
+    }
+}
+
 impl Configuration {
     pub fn validate_value(&self) -> Result<(), String> {
         match self.data_type {
@@ -65,6 +126,9 @@ impl Configuration {
                     .parse::<i64>()
                     .map_err(|_| format!("Value '{}' is not a valid integer", self.config_value))?;
             }
+            ConfigDataType::Float => {
+                self.get_as_float()?;
+            }
             ConfigDataType::Boolean => {
                 self.config_value
                     .parse::<bool>()
@@ -81,6 +145,17 @@ impl Configuration {
         Ok(())
     }
 
+    /// The stored value parsed as its data type
+    pub fn typed_value(&self) -> Result<ConfigValue, String> {
+        Ok(match self.data_type {
+            ConfigDataType::String => ConfigValue::String(self.get_as_string()),
+            ConfigDataType::Integer => ConfigValue::Integer(self.get_as_integer()?),
+            ConfigDataType::Float => ConfigValue::Float(self.get_as_float()?),
+            ConfigDataType::Boolean => ConfigValue::Boolean(self.get_as_boolean()?),
+            ConfigDataType::Json => ConfigValue::Json(self.get_as_json()?),
+        })
+    }
+
     pub fn get_as_string(&self) -> String {
         self.config_value.clone()
     }
@@ -91,6 +166,15 @@ impl Configuration {
             .map_err(|_| format!("Cannot parse '{}' as integer", self.config_value))
     }
 
+    /// The value as a finite number; `NaN` and infinities are rejected
+    pub fn get_as_float(&self) -> Result<f64, String> {
+        self.config_value
+            .parse::<f64>()
+            .ok()
+            .filter(|v| v.is_finite())
+            .ok_or_else(|| format!("Cannot parse '{}' as float", self.config_value))
+    }
+
     pub fn get_as_boolean(&self) -> Result<bool, String> {
         self.config_value
             .parse::<bool>()
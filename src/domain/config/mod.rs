@@ -2,6 +2,9 @@ pub mod entity;
 pub mod repository;
 pub mod service;
 
-pub use entity::{ConfigDataType, Configuration, CreateConfiguration, UpdateConfiguration};
+pub use entity::{
+    ConfigDataType, ConfigValue, Configuration, CreateConfiguration, InvalidConfiguration,
+    ListPreferences, UpdateConfiguration,
+};
 pub use repository::ConfigRepository;
 pub use service::ConfigService;
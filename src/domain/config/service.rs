@@ -1,11 +1,59 @@
 use std::sync::Arc;
 
+use chrono::NaiveDate;
+
+use serde::Serialize;
 use sqlx::PgPool;
+use tracing::warn;
 
-use crate::domain::config::entity::{ConfigDataType, Configuration, UpdateConfiguration};
+use crate::domain::config::entity::{
+    ConfigDataType, ConfigValue, Configuration, CreateConfiguration, InvalidConfiguration,
+    ListPreferences, UpdateConfiguration,
+};
 use crate::domain::config::repository::ConfigRepository;
+use crate::domain::task::service::DEFAULT_VARIANCE_THRESHOLD_PCT;
+use crate::domain::timeline::entity::TimelineType;
 use crate::utils::error::DevErpError;
 
+/// Configuration key holding the default type for new timelines
+pub const TIMELINE_DEFAULT_TYPE_KEY: &str = "timeline.default_type";
+
+/// Configuration key toggling the duplicate (name, version) guard on new resources
+pub const RESOURCE_UNIQUE_NAME_VERSION_KEY: &str = "resource.unique_name_version";
+
+/// Configuration key holding the licenses `resource license-report` accepts
+pub const RESOURCE_ALLOWED_LICENSES_KEY: &str = "resource.allowed_licenses";
+
+/// Configuration key holding the estimate overrun, in percent, that `task update` warns about
+pub const TASK_VARIANCE_THRESHOLD_PCT_KEY: &str = "task.variance_threshold_pct";
+
+/// Configuration key holding the holidays excluded from working-day durations
+pub const CALENDAR_HOLIDAYS_KEY: &str = "calendar.holidays";
+
+/// Configuration key holding the hourly rate that prices logged task hours
+pub const COST_HOURLY_RATE_KEY: &str = "cost.hourly_rate";
+
+/// Configuration key holding the earliest date `timeline shift` may move anything to
+pub const TIMELINE_DATE_FLOOR_KEY: &str = "timeline.date_floor";
+
+/// Configuration key holding the page size used when `--per-page` is omitted
+pub const LIST_PER_PAGE_KEY: &str = "list.per_page";
+
+/// Configuration key holding the project list sort used when `--sort` is omitted
+pub const LIST_ORDER_BY_KEY: &str = "list.order_by";
+
+/// Tables `config check-schema` expects a migrated database to have
+pub const EXPECTED_TABLES: [&str; 8] = [
+    "projects",
+    "tasks",
+    "timelines",
+    "milestones",
+    "resources",
+    "task_dependencies",
+    "task_comments",
+    "configurations",
+];
+
 pub struct ConfigService {
     repository: Arc<dyn ConfigRepository>,
     pool: PgPool,
@@ -24,20 +72,60 @@ impl ConfigService {
         Ok(config)
     }
 
+    /// Value of `key` parsed as its declared data type
+    pub async fn get_typed(&self, key: &str) -> Result<ConfigValue, DevErpError> {
+        self.get_config(key)
+            .await?
+            .typed_value()
+            .map_err(|e| DevErpError::Validation(format!("Configuration '{}': {}", key, e)))
+    }
+
     pub async fn get_all_configs(&self) -> Result<Vec<Configuration>, DevErpError> {
         self.repository.find_all().await
     }
 
+    /// Store `value` under `key`, validated against the key's data type
+    ///
+    /// An unknown key is created when `data_type` is given and reported as
+    /// not found otherwise, so a mistyped key does not silently add a new
+    /// entry. For an existing key `data_type` must match the stored type.
     pub async fn set_config(
         &self,
         key: &str,
         value: String,
         description: Option<String>,
+        data_type: Option<ConfigDataType>,
     ) -> Result<Configuration, DevErpError> {
-        // First, get the existing configuration to check its data type
-        let existing = self.repository.find_by_key(key).await?.ok_or_else(|| {
-            DevErpError::NotFound(format!("Configuration key '{}' not found", key))
-        })?;
+        let Some(existing) = self.repository.find_by_key(key).await? else {
+            let Some(data_type) = data_type else {
+                return Err(DevErpError::NotFound(format!(
+                    "Configuration key '{}' not found (pass --type to create it)",
+                    key
+                )));
+            };
+            self.validate_value(&value, &data_type)?;
+
+            return self
+                .repository
+                .create(CreateConfiguration {
+                    config_key: key.to_string(),
+                    config_value: value,
+                    description,
+                    data_type,
+                    is_encrypted: false,
+                    is_required: false,
+                })
+                .await;
+        };
+
+        if let Some(data_type) = data_type {
+            if data_type != existing.data_type {
+                return Err(DevErpError::Validation(format!(
+                    "Configuration key '{}' is stored as {}, not {}",
+                    key, existing.data_type, data_type
+                )));
+            }
+        }
 
         // Validate the value based on data type
         self.validate_value(&value, &existing.data_type)?;
@@ -52,6 +140,273 @@ impl ConfigService {
         self.repository.update(update).await
     }
 
+    /// Stored configurations whose value does not parse as their data type
+    ///
+    /// `set_config` rejects such values, so these come from edits made
+    /// directly in the database.
+    pub async fn find_invalid_configs(&self) -> Result<Vec<InvalidConfiguration>, DevErpError> {
+        let configs = self.repository.find_all().await?;
+
+        Ok(configs
+            .into_iter()
+            .filter_map(|config| {
+                let error = config.validate_value().err()?;
+                Some(InvalidConfiguration {
+                    config_key: config.config_key,
+                    config_value: config.config_value,
+                    data_type: config.data_type,
+                    error,
+                })
+            })
+            .collect())
+    }
+
+    /// Timeline type used when `timeline create` is run without `--timeline-type`
+    ///
+    /// Falls back to [`TimelineType::Project`] when the key is missing, and
+    /// also (with a warning) when it holds an unknown type.
+    pub async fn default_timeline_type(&self) -> Result<TimelineType, DevErpError> {
+        let Some(config) = self
+            .repository
+            .find_by_key(TIMELINE_DEFAULT_TYPE_KEY)
+            .await?
+        else {
+            return Ok(TimelineType::Project);
+        };
+
+        Ok(config
+            .config_value
+            .parse::<TimelineType>()
+            .unwrap_or_else(|err| {
+                warn!(
+                    "Ignoring {} = '{}' ({}), using project",
+                    TIMELINE_DEFAULT_TYPE_KEY, config.config_value, err
+                );
+                TimelineType::Project
+            }))
+    }
+
+    /// Whether new resources must not repeat an existing (name, version) pair
+    ///
+    /// Defaults to `true` when the key is missing or not a boolean.
+    pub async fn resource_unique_name_version(&self) -> Result<bool, DevErpError> {
+        let Some(config) = self
+            .repository
+            .find_by_key(RESOURCE_UNIQUE_NAME_VERSION_KEY)
+            .await?
+        else {
+            return Ok(true);
+        };
+
+        Ok(config.config_value.parse::<bool>().unwrap_or_else(|_| {
+            warn!(
+                "Ignoring {} = '{}' (expected true or false), using true",
+                RESOURCE_UNIQUE_NAME_VERSION_KEY, config.config_value
+            );
+            true
+        }))
+    }
+
+    /// Licenses from `resource.allowed_licenses`, a JSON array of strings
+    ///
+    /// Blank and non-string entries are skipped with a warning; a missing key
+    /// or a value that is not an array means no allowlist.
+    pub async fn resource_allowed_licenses(&self) -> Result<Vec<String>, DevErpError> {
+        let Some(config) = self
+            .repository
+            .find_by_key(RESOURCE_ALLOWED_LICENSES_KEY)
+            .await?
+        else {
+            return Ok(Vec::new());
+        };
+
+        let entries = match config.get_as_json() {
+            Ok(serde_json::Value::Array(entries)) => entries,
+            Ok(_) => {
+                warn!(
+                    "Ignoring {} = '{}' (expected an array of licenses)",
+                    RESOURCE_ALLOWED_LICENSES_KEY, config.config_value
+                );
+                return Ok(Vec::new());
+            }
+            Err(err) => {
+                warn!("Ignoring {} ({})", RESOURCE_ALLOWED_LICENSES_KEY, err);
+                return Ok(Vec::new());
+            }
+        };
+
+        Ok(entries
+            .iter()
+            .filter_map(|entry| {
+                let license = entry.as_str().map(str::trim).filter(|l| !l.is_empty());
+                if license.is_none() {
+                    warn!(
+                        "Ignoring {} entry {} (expected a license name)",
+                        RESOURCE_ALLOWED_LICENSES_KEY, entry
+                    );
+                }
+                license.map(str::to_string)
+            })
+            .collect())
+    }
+
+    /// Percentage by which actual hours may exceed the estimate before
+    /// `task update` warns, 50 when unset
+    ///
+    /// A negative or non-integer value is ignored with a warning.
+    pub async fn task_variance_threshold_pct(&self) -> Result<f64, DevErpError> {
+        let Some(config) = self
+            .repository
+            .find_by_key(TASK_VARIANCE_THRESHOLD_PCT_KEY)
+            .await?
+        else {
+            return Ok(DEFAULT_VARIANCE_THRESHOLD_PCT);
+        };
+
+        match config.get_as_integer() {
+            Ok(pct) if pct >= 0 => Ok(pct as f64),
+            Ok(_) => {
+                warn!(
+                    "Ignoring {} = '{}' (must not be negative), using {}",
+                    TASK_VARIANCE_THRESHOLD_PCT_KEY,
+                    config.config_value,
+                    DEFAULT_VARIANCE_THRESHOLD_PCT
+                );
+                Ok(DEFAULT_VARIANCE_THRESHOLD_PCT)
+            }
+            Err(err) => {
+                warn!(
+                    "Ignoring {} ({}), using {}",
+                    TASK_VARIANCE_THRESHOLD_PCT_KEY, err, DEFAULT_VARIANCE_THRESHOLD_PCT
+                );
+                Ok(DEFAULT_VARIANCE_THRESHOLD_PCT)
+            }
+        }
+    }
+
+    /// Holidays from `calendar.holidays`, a JSON array of `YYYY-MM-DD` strings
+    ///
+    /// Entries that are not dates are skipped with a warning; a missing key
+    /// or a value that is not an array means no holidays.
+    pub async fn calendar_holidays(&self) -> Result<Vec<NaiveDate>, DevErpError> {
+        let Some(config) = self.repository.find_by_key(CALENDAR_HOLIDAYS_KEY).await? else {
+            return Ok(Vec::new());
+        };
+
+        let entries = match config.get_as_json() {
+            Ok(serde_json::Value::Array(entries)) => entries,
+            Ok(_) => {
+                warn!(
+                    "Ignoring {} = '{}' (expected an array of dates)",
+                    CALENDAR_HOLIDAYS_KEY, config.config_value
+                );
+                return Ok(Vec::new());
+            }
+            Err(err) => {
+                warn!("Ignoring {} ({})", CALENDAR_HOLIDAYS_KEY, err);
+                return Ok(Vec::new());
+            }
+        };
+
+        Ok(entries
+            .iter()
+            .filter_map(|entry| {
+                let date = entry
+                    .as_str()
+                    .and_then(|s| NaiveDate::parse_from_str(s, "%Y-%m-%d").ok());
+                if date.is_none() {
+                    warn!(
+                        "Ignoring {} entry {} (expected YYYY-MM-DD)",
+                        CALENDAR_HOLIDAYS_KEY, entry
+                    );
+                }
+                date
+            })
+            .collect())
+    }
+
+    /// Rate from `cost.hourly_rate`, or `None` when project costs are not tracked
+    ///
+    /// A missing key or a rate of 0 disables costing; a negative or
+    /// non-numeric value does too, with a warning.
+    pub async fn hourly_rate(&self) -> Result<Option<f64>, DevErpError> {
+        let Some(config) = self.repository.find_by_key(COST_HOURLY_RATE_KEY).await? else {
+            return Ok(None);
+        };
+
+        match config.config_value.trim().parse::<f64>() {
+            Ok(rate) if rate.is_finite() && rate >= 0.0 => Ok(Some(rate).filter(|&r| r > 0.0)),
+            _ => {
+                warn!(
+                    "Ignoring {} = '{}' (expected a non-negative number)",
+                    COST_HOURLY_RATE_KEY, config.config_value
+                );
+                Ok(None)
+            }
+        }
+    }
+
+    /// Earliest date from `timeline.date_floor`, or `None` for no floor
+    ///
+    /// An empty value disables the check; a value that is not a
+    /// `YYYY-MM-DD` date does too, with a warning.
+    pub async fn timeline_date_floor(&self) -> Result<Option<NaiveDate>, DevErpError> {
+        let Some(config) = self.repository.find_by_key(TIMELINE_DATE_FLOOR_KEY).await? else {
+            return Ok(None);
+        };
+
+        let value = config.config_value.trim();
+        if value.is_empty() {
+            return Ok(None);
+        }
+        match NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+            Ok(floor) => Ok(Some(floor)),
+            Err(_) => {
+                warn!(
+                    "Ignoring {} = '{}' (expected YYYY-MM-DD)",
+                    TIMELINE_DATE_FLOOR_KEY, config.config_value
+                );
+                Ok(None)
+            }
+        }
+    }
+
+    /// Defaults for list commands, layered under explicit flags by the caller
+    ///
+    /// Missing keys keep the built-in defaults; a page size below 1 or an
+    /// unknown sort field is ignored with a warning.
+    pub async fn list_preferences(&self) -> Result<ListPreferences, DevErpError> {
+        let mut preferences = ListPreferences::default();
+
+        if let Some(config) = self.repository.find_by_key(LIST_PER_PAGE_KEY).await? {
+            match config.get_as_integer() {
+                Ok(per_page) if per_page >= 1 => {
+                    preferences.per_page = u32::try_from(per_page).unwrap_or(u32::MAX)
+                }
+                Ok(_) => warn!(
+                    "Ignoring {} = '{}' (must be at least 1), using {}",
+                    LIST_PER_PAGE_KEY, config.config_value, preferences.per_page
+                ),
+                Err(err) => warn!(
+                    "Ignoring {} ({}), using {}",
+                    LIST_PER_PAGE_KEY, err, preferences.per_page
+                ),
+            }
+        }
+
+        if let Some(config) = self.repository.find_by_key(LIST_ORDER_BY_KEY).await? {
+            let value = config.get_as_string();
+            if !value.trim().is_empty() {
+                match value.trim().parse() {
+                    Ok(field) => preferences.order_by = Some(field),
+                    Err(err) => warn!("Ignoring {} = '{}' ({})", LIST_ORDER_BY_KEY, value, err),
+                }
+            }
+        }
+
+        Ok(preferences)
+    }
+
     pub async fn reset_to_defaults(&self) -> Result<(), DevErpError> {
         self.repository.reset_to_defaults().await
     }
@@ -70,6 +425,40 @@ impl ConfigService {
         }
     }
 
+    /// Which expected tables are missing, and the latest applied migration
+    ///
+    /// The migration version comes from `sqlx`'s `_sqlx_migrations` table and
+    /// is `None` when migrations were applied some other way.
+    pub async fn check_schema(&self) -> Result<SchemaStatus, DevErpError> {
+        let present = sqlx::query_scalar!(
+            r#"
+            SELECT table_name as "table_name!"
+            FROM information_schema.tables
+            WHERE table_schema = current_schema()
+            "#
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        let tracked = sqlx::query_scalar!(
+            "SELECT to_regclass('_sqlx_migrations') IS NOT NULL as \"tracked!\""
+        )
+        .fetch_one(&self.pool)
+        .await?;
+        let migration_version = if tracked {
+            sqlx::query_scalar!("SELECT MAX(version) FROM _sqlx_migrations WHERE success")
+                .fetch_one(&self.pool)
+                .await?
+        } else {
+            None
+        };
+
+        Ok(SchemaStatus {
+            missing_tables: missing_tables(&present),
+            migration_version,
+        })
+    }
+
     pub async fn get_database_version(&self) -> Result<String, DevErpError> {
         let result = sqlx::query!("SELECT version() as version")
             .fetch_one(&self.pool)
@@ -124,6 +513,15 @@ impl ConfigService {
                     DevErpError::Validation(format!("Value '{}' is not a valid integer", value))
                 })?;
             }
+            ConfigDataType::Float => {
+                value
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|v| v.is_finite())
+                    .ok_or_else(|| {
+                        DevErpError::Validation(format!("Value '{}' is not a valid number", value))
+                    })?;
+            }
             ConfigDataType::Boolean => {
                 value.parse::<bool>().map_err(|_| {
                     DevErpError::Validation(format!(
@@ -145,6 +543,29 @@ impl ConfigService {
     }
 }
 
+/// Result of `check_schema`
+#[derive(Debug, Clone, Serialize)]
+pub struct SchemaStatus {
+    pub missing_tables: Vec<String>,
+    pub migration_version: Option<i64>,
+}
+
+impl SchemaStatus {
+    /// True when every expected table exists
+    pub fn is_complete(&self) -> bool {
+        self.missing_tables.is_empty()
+    }
+}
+
+/// The `EXPECTED_TABLES` not in `present`, in the order they are listed
+fn missing_tables(present: &[String]) -> Vec<String> {
+    EXPECTED_TABLES
+        .iter()
+        .filter(|table| !present.iter().any(|p| p == *table))
+        .map(|table| table.to_string())
+        .collect()
+}
+
 #[derive(Debug)]
 pub struct DatabaseStats {
     pub project_count: i64,
@@ -157,6 +578,7 @@ pub struct DatabaseStats {
 mod tests {
     use super::*;
     use crate::domain::config::entity::CreateConfiguration;
+    use crate::domain::project::entity::ProjectSortField;
     use mockall::mock;
     use mockall::predicate::*;
 
@@ -190,6 +612,21 @@ mod tests {
             .is_err());
     }
 
+    #[tokio::test]
+    async fn test_validate_value_float() {
+        let service = create_test_service().await;
+        assert!(service
+            .validate_value("82.5", &ConfigDataType::Float)
+            .is_ok());
+        assert!(service.validate_value("90", &ConfigDataType::Float).is_ok());
+        assert!(service
+            .validate_value("abc", &ConfigDataType::Float)
+            .is_err());
+        assert!(service
+            .validate_value("NaN", &ConfigDataType::Float)
+            .is_err());
+    }
+
     #[tokio::test]
     async fn test_validate_value_boolean_valid() {
         let service = create_test_service().await;
@@ -233,6 +670,346 @@ mod tests {
             .is_ok());
     }
 
+    fn config_row(key: &str, value: &str) -> Configuration {
+        Configuration {
+            id: 1,
+            config_key: key.to_string(),
+            config_value: value.to_string(),
+            description: None,
+            data_type: ConfigDataType::String,
+            is_encrypted: false,
+            is_required: false,
+            created_at: chrono::Utc::now(),
+            updated_at: chrono::Utc::now(),
+        }
+    }
+
+    fn service_with(repo: MockConfigRepo) -> ConfigService {
+        let pool =
+            PgPool::connect_lazy("postgres://localhost/test").expect("Failed to create pool");
+        ConfigService::new(Arc::new(repo), pool)
+    }
+
+    #[tokio::test]
+    async fn test_find_invalid_configs_reports_malformed_integer() {
+        let mut repo = MockConfigRepo::new();
+        repo.expect_find_all().returning(|| {
+            let mut per_page = config_row(LIST_PER_PAGE_KEY, "fifty");
+            per_page.data_type = ConfigDataType::Integer;
+            let mut unique = config_row(RESOURCE_UNIQUE_NAME_VERSION_KEY, "true");
+            unique.data_type = ConfigDataType::Boolean;
+            Ok(vec![
+                per_page,
+                unique,
+                config_row(TIMELINE_DEFAULT_TYPE_KEY, "sprint"),
+            ])
+        });
+
+        let invalid = service_with(repo).find_invalid_configs().await.unwrap();
+
+        assert_eq!(invalid.len(), 1);
+        assert_eq!(invalid[0].config_key, LIST_PER_PAGE_KEY);
+        assert_eq!(invalid[0].data_type, ConfigDataType::Integer);
+        assert!(invalid[0].error.contains("not a valid integer"));
+    }
+
+    #[tokio::test]
+    async fn test_set_config_creates_typed_key_only_with_type() {
+        let mut repo = MockConfigRepo::new();
+        repo.expect_find_by_key().returning(|_| Ok(None));
+        repo.expect_create().times(1).returning(|input| {
+            let mut row = config_row(&input.config_key, &input.config_value);
+            row.data_type = input.data_type;
+            Ok(row)
+        });
+        let service = service_with(repo);
+
+        let created = service
+            .set_config(
+                "app.timeout",
+                "30".to_string(),
+                None,
+                Some(ConfigDataType::Integer),
+            )
+            .await
+            .unwrap();
+        assert_eq!(created.data_type, ConfigDataType::Integer);
+
+        let untyped = service
+            .set_config("app.timeout", "30".to_string(), None, None)
+            .await;
+        assert!(matches!(untyped, Err(DevErpError::NotFound(_))));
+
+        let invalid = service
+            .set_config(
+                "app.debug",
+                "yes".to_string(),
+                None,
+                Some(ConfigDataType::Boolean),
+            )
+            .await;
+        assert!(matches!(invalid, Err(DevErpError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_get_typed_parses_value_and_reports_unknown_keys() {
+        let mut repo = MockConfigRepo::new();
+        repo.expect_find_by_key()
+            .with(eq(LIST_PER_PAGE_KEY))
+            .returning(|key| {
+                let mut row = config_row(key, "25");
+                row.data_type = ConfigDataType::Integer;
+                Ok(Some(row))
+            });
+        repo.expect_find_by_key().returning(|_| Ok(None));
+        let service = service_with(repo);
+
+        assert_eq!(
+            service.get_typed(LIST_PER_PAGE_KEY).await.unwrap(),
+            ConfigValue::Integer(25)
+        );
+        assert!(matches!(
+            service.get_typed("missing.key").await,
+            Err(DevErpError::NotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_default_timeline_type_uses_configured_value() {
+        let mut repo = MockConfigRepo::new();
+        repo.expect_find_by_key()
+            .with(eq(TIMELINE_DEFAULT_TYPE_KEY))
+            .returning(|key| Ok(Some(config_row(key, "sprint"))));
+
+        let service = service_with(repo);
+        assert_eq!(
+            service.default_timeline_type().await.unwrap(),
+            TimelineType::Sprint
+        );
+    }
+
+    #[tokio::test]
+    async fn test_default_timeline_type_falls_back_to_project() {
+        let mut invalid = MockConfigRepo::new();
+        invalid
+            .expect_find_by_key()
+            .returning(|key| Ok(Some(config_row(key, "marathon"))));
+        let mut missing = MockConfigRepo::new();
+        missing.expect_find_by_key().returning(|_| Ok(None));
+
+        for repo in [invalid, missing] {
+            assert_eq!(
+                service_with(repo).default_timeline_type().await.unwrap(),
+                TimelineType::Project
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_resource_unique_name_version_can_be_disabled() {
+        let mut repo = MockConfigRepo::new();
+        repo.expect_find_by_key()
+            .with(eq(RESOURCE_UNIQUE_NAME_VERSION_KEY))
+            .returning(|key| Ok(Some(config_row(key, "false"))));
+        assert!(!service_with(repo)
+            .resource_unique_name_version()
+            .await
+            .unwrap());
+
+        let mut missing = MockConfigRepo::new();
+        missing.expect_find_by_key().returning(|_| Ok(None));
+        assert!(service_with(missing)
+            .resource_unique_name_version()
+            .await
+            .unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_calendar_holidays_skips_invalid_entries() {
+        let mut repo = MockConfigRepo::new();
+        repo.expect_find_by_key()
+            .with(eq(CALENDAR_HOLIDAYS_KEY))
+            .returning(|key| {
+                Ok(Some(config_row(
+                    key,
+                    r#"["2025-05-05", "someday", 20250606, "2025-12-25"]"#,
+                )))
+            });
+        assert_eq!(
+            service_with(repo).calendar_holidays().await.unwrap(),
+            vec![
+                NaiveDate::from_ymd_opt(2025, 5, 5).unwrap(),
+                NaiveDate::from_ymd_opt(2025, 12, 25).unwrap(),
+            ]
+        );
+
+        for value in [r#""2025-05-05""#, "not json"] {
+            let mut invalid = MockConfigRepo::new();
+            invalid
+                .expect_find_by_key()
+                .returning(move |key| Ok(Some(config_row(key, value))));
+            assert!(service_with(invalid)
+                .calendar_holidays()
+                .await
+                .unwrap()
+                .is_empty());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_resource_allowed_licenses_skips_invalid_entries() {
+        let mut repo = MockConfigRepo::new();
+        repo.expect_find_by_key()
+            .with(eq(RESOURCE_ALLOWED_LICENSES_KEY))
+            .returning(|key| Ok(Some(config_row(key, r#"["MIT", " ", 3, " Apache-2.0 "]"#))));
+        assert_eq!(
+            service_with(repo)
+                .resource_allowed_licenses()
+                .await
+                .unwrap(),
+            vec!["MIT", "Apache-2.0"]
+        );
+
+        let mut missing = MockConfigRepo::new();
+        missing.expect_find_by_key().returning(|_| Ok(None));
+        assert!(service_with(missing)
+            .resource_allowed_licenses()
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_hourly_rate_disabled_when_zero_or_invalid() {
+        let mut repo = MockConfigRepo::new();
+        repo.expect_find_by_key()
+            .with(eq(COST_HOURLY_RATE_KEY))
+            .returning(|key| Ok(Some(config_row(key, "82.5"))));
+        assert_eq!(service_with(repo).hourly_rate().await.unwrap(), Some(82.5));
+
+        for value in ["0", "-40", "cheap"] {
+            let mut disabled = MockConfigRepo::new();
+            disabled
+                .expect_find_by_key()
+                .returning(move |key| Ok(Some(config_row(key, value))));
+            assert_eq!(service_with(disabled).hourly_rate().await.unwrap(), None);
+        }
+    }
+
+    #[test]
+    fn test_missing_tables_keeps_expected_order() {
+        let present: Vec<String> = ["tasks", "projects", "audit_logs", "timelines", "resources"]
+            .iter()
+            .map(|t| t.to_string())
+            .collect();
+
+        assert_eq!(
+            missing_tables(&present),
+            [
+                "milestones",
+                "task_dependencies",
+                "task_comments",
+                "configurations"
+            ]
+        );
+
+        let all: Vec<String> = EXPECTED_TABLES.iter().map(|t| t.to_string()).collect();
+        assert!(missing_tables(&all).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_timeline_date_floor_parses_date_or_disables() {
+        let mut repo = MockConfigRepo::new();
+        repo.expect_find_by_key()
+            .with(eq(TIMELINE_DATE_FLOOR_KEY))
+            .returning(|key| Ok(Some(config_row(key, "2020-01-01"))));
+        assert_eq!(
+            service_with(repo).timeline_date_floor().await.unwrap(),
+            NaiveDate::from_ymd_opt(2020, 1, 1)
+        );
+
+        for value in ["", "  ", "last year"] {
+            let mut disabled = MockConfigRepo::new();
+            disabled
+                .expect_find_by_key()
+                .returning(move |key| Ok(Some(config_row(key, value))));
+            assert_eq!(
+                service_with(disabled).timeline_date_floor().await.unwrap(),
+                None
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_task_variance_threshold_falls_back_to_default() {
+        let mut repo = MockConfigRepo::new();
+        repo.expect_find_by_key()
+            .with(eq(TASK_VARIANCE_THRESHOLD_PCT_KEY))
+            .returning(|key| Ok(Some(config_row(key, "25"))));
+        assert_eq!(
+            service_with(repo)
+                .task_variance_threshold_pct()
+                .await
+                .unwrap(),
+            25.0
+        );
+
+        for value in ["-10", "lots"] {
+            let mut invalid = MockConfigRepo::new();
+            invalid
+                .expect_find_by_key()
+                .returning(move |key| Ok(Some(config_row(key, value))));
+            assert_eq!(
+                service_with(invalid)
+                    .task_variance_threshold_pct()
+                    .await
+                    .unwrap(),
+                DEFAULT_VARIANCE_THRESHOLD_PCT
+            );
+        }
+
+        let mut missing = MockConfigRepo::new();
+        missing.expect_find_by_key().returning(|_| Ok(None));
+        assert_eq!(
+            service_with(missing)
+                .task_variance_threshold_pct()
+                .await
+                .unwrap(),
+            DEFAULT_VARIANCE_THRESHOLD_PCT
+        );
+    }
+
+    #[tokio::test]
+    async fn test_list_preferences_read_stored_values() {
+        let mut repo = MockConfigRepo::new();
+        repo.expect_find_by_key()
+            .with(eq(LIST_PER_PAGE_KEY))
+            .returning(|key| Ok(Some(config_row(key, "15"))));
+        repo.expect_find_by_key()
+            .with(eq(LIST_ORDER_BY_KEY))
+            .returning(|key| Ok(Some(config_row(key, "end-date"))));
+
+        let preferences = service_with(repo).list_preferences().await.unwrap();
+        assert_eq!(preferences.per_page, 15);
+        assert_eq!(preferences.order_by, Some(ProjectSortField::EndDate));
+    }
+
+    #[tokio::test]
+    async fn test_list_preferences_ignore_invalid_values() {
+        let mut repo = MockConfigRepo::new();
+        repo.expect_find_by_key()
+            .with(eq(LIST_PER_PAGE_KEY))
+            .returning(|key| Ok(Some(config_row(key, "0"))));
+        repo.expect_find_by_key()
+            .with(eq(LIST_ORDER_BY_KEY))
+            .returning(|key| Ok(Some(config_row(key, ""))));
+
+        assert_eq!(
+            service_with(repo).list_preferences().await.unwrap(),
+            ListPreferences::default()
+        );
+    }
+
     async fn create_test_service() -> ConfigService {
         let mock_repo = MockConfigRepo::new();
         // Create a dummy pool with a test database URL
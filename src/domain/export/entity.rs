@@ -0,0 +1,45 @@
+// Export entities
+
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+use crate::domain::project::entity::Project;
+use crate::domain::resource::entity::Resource;
+use crate::domain::task::entity::Task;
+use crate::domain::timeline::entity::{Milestone, Timeline};
+
+/// File format for exported data
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Json,
+    Csv,
+}
+
+impl ExportFormat {
+    /// File extension used for this format
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Json => "json",
+            ExportFormat::Csv => "csv",
+        }
+    }
+}
+
+/// Every non-deleted row of each exported entity type
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ExportData {
+    pub projects: Vec<Project>,
+    pub tasks: Vec<Task>,
+    pub resources: Vec<Resource>,
+    pub timelines: Vec<Timeline>,
+    pub milestones: Vec<Milestone>,
+}
+
+/// A file written by an export
+#[derive(Debug, Clone, Serialize)]
+pub struct ExportedFile {
+    /// Entity type stored in the file (e.g. "projects")
+    pub entity: &'static str,
+    pub path: PathBuf,
+    pub records: usize,
+}
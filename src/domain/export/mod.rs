@@ -0,0 +1,9 @@
+// Export domain module - full data dumps for backups and version control
+
+pub mod entity;
+pub mod repository;
+pub mod service;
+
+pub use entity::{ExportData, ExportFormat, ExportedFile};
+pub use repository::ExportRepository;
+pub use service::ExportService;
@@ -0,0 +1,13 @@
+// Export repository trait
+
+use async_trait::async_trait;
+
+use super::entity::ExportData;
+use crate::utils::error::DevErpError;
+
+/// Read access to complete, unpaginated entity tables
+#[async_trait]
+pub trait ExportRepository: Send + Sync {
+    /// Load every non-deleted project, task, resource, timeline and milestone
+    async fn load_all(&self) -> Result<ExportData, DevErpError>;
+}
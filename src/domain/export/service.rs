@@ -0,0 +1,172 @@
+// Export service
+
+use serde::Serialize;
+use serde_json::Value;
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+use tracing::{debug, info};
+
+use super::entity::{ExportData, ExportFormat, ExportedFile};
+use super::repository::ExportRepository;
+use crate::utils::error::DevErpError;
+use crate::utils::formatter::to_csv;
+
+/// Service for dumping the database to files
+pub struct ExportService {
+    repository: Arc<dyn ExportRepository>,
+}
+
+impl ExportService {
+    /// Create a new export service
+    ///
+    /// # Arguments
+    /// * `repository` - The export repository implementation
+    pub fn new(repository: Arc<dyn ExportRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// Write all entities into a single JSON document
+    ///
+    /// CSV cannot hold several entity types in one file, so only JSON is
+    /// accepted here; use [`ExportService::export_to_dir`] for CSV.
+    pub async fn export_to_file(
+        &self,
+        path: &Path,
+        format: ExportFormat,
+    ) -> Result<ExportData, DevErpError> {
+        if format != ExportFormat::Json {
+            return Err(DevErpError::Validation(
+                "Single-file export only supports JSON; use --output-dir for CSV".to_string(),
+            ));
+        }
+
+        let data = self.repository.load_all().await?;
+        fs::write(path, to_json(&data)?)?;
+
+        info!(path = %path.display(), "Exported data to file");
+        Ok(data)
+    }
+
+    /// Write one file per entity type (projects.json, tasks.json, ...) into `dir`
+    ///
+    /// The directory is created if missing; existing files are overwritten.
+    pub async fn export_to_dir(
+        &self,
+        dir: &Path,
+        format: ExportFormat,
+    ) -> Result<Vec<ExportedFile>, DevErpError> {
+        debug!("Service: Exporting entities to {}", dir.display());
+
+        fs::create_dir_all(dir)?;
+        let data = self.repository.load_all().await?;
+
+        let files = vec![
+            write_entity(dir, "projects", &data.projects, format)?,
+            write_entity(dir, "tasks", &data.tasks, format)?,
+            write_entity(dir, "resources", &data.resources, format)?,
+            write_entity(dir, "timelines", &data.timelines, format)?,
+            write_entity(dir, "milestones", &data.milestones, format)?,
+        ];
+
+        info!(dir = %dir.display(), files = files.len(), "Exported data to directory");
+        Ok(files)
+    }
+}
+
+/// Serialize one entity list to `<dir>/<entity>.<ext>`
+fn write_entity<T: Serialize>(
+    dir: &Path,
+    entity: &'static str,
+    records: &[T],
+    format: ExportFormat,
+) -> Result<ExportedFile, DevErpError> {
+    let path = dir.join(format!("{}.{}", entity, format.extension()));
+
+    let content = match format {
+        ExportFormat::Json => to_json(records)?,
+        ExportFormat::Csv => records_to_csv(records)?,
+    };
+    fs::write(&path, content)?;
+
+    Ok(ExportedFile {
+        entity,
+        path,
+        records: records.len(),
+    })
+}
+
+fn to_json<T: Serialize + ?Sized>(data: &T) -> Result<String, DevErpError> {
+    serde_json::to_string_pretty(data).map_err(json_error)
+}
+
+fn json_error(err: serde_json::Error) -> DevErpError {
+    DevErpError::Internal(format!("JSON serialization error: {}", err))
+}
+
+/// Render serializable records as CSV, one column per top-level field
+///
+/// Nulls become empty cells and nested values (arrays, objects) are stored
+/// as compact JSON.
+fn records_to_csv<T: Serialize>(records: &[T]) -> Result<String, DevErpError> {
+    let rows: Vec<serde_json::Map<String, Value>> = records
+        .iter()
+        .map(
+            |record| match serde_json::to_value(record).map_err(json_error)? {
+                Value::Object(map) => Ok(map),
+                other => Err(DevErpError::Internal(format!(
+                    "Cannot export non-object record as CSV: {}",
+                    other
+                ))),
+            },
+        )
+        .collect::<Result<_, DevErpError>>()?;
+
+    let Some(first) = rows.first() else {
+        return Ok(String::new());
+    };
+    let headers: Vec<&str> = first.keys().map(String::as_str).collect();
+
+    let cells: Vec<Vec<String>> = rows
+        .iter()
+        .map(|row| {
+            headers
+                .iter()
+                .map(|h| match row.get(*h) {
+                    None | Some(Value::Null) => String::new(),
+                    Some(Value::String(s)) => s.clone(),
+                    Some(other) => other.to_string(),
+                })
+                .collect()
+        })
+        .collect();
+
+    Ok(to_csv(&headers, &cells))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_records_to_csv_flattens_values() {
+        let records = vec![
+            json!({ "id": 1, "name": "Alpha, Inc", "tags": ["a", "b"], "code": null }),
+            json!({ "id": 2, "name": "Beta", "tags": [], "code": "B" }),
+        ];
+
+        let csv = records_to_csv(&records).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+
+        assert_eq!(lines[0], "code,id,name,tags");
+        assert_eq!(lines[1], ",1,\"Alpha, Inc\",\"[\"\"a\"\",\"\"b\"\"]\"");
+        assert_eq!(lines[2], "B,2,Beta,[]");
+    }
+
+    #[test]
+    fn test_records_to_csv_empty() {
+        let records: Vec<Value> = Vec::new();
+        assert_eq!(records_to_csv(&records).unwrap(), "");
+    }
+}
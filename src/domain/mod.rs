@@ -1,8 +1,11 @@
 // Domain layer - Business logic
 
 pub mod config;
+pub mod export;
 pub mod project;
 pub mod report;
 pub mod resource;
+pub mod snapshot;
 pub mod task;
+pub mod template;
 pub mod timeline;
@@ -5,6 +5,8 @@ use serde::{Deserialize, Serialize};
 use sqlx::{FromRow, Type};
 use uuid::Uuid;
 
+use crate::domain::resource::entity::ProjectResourceDetail;
+
 /// Project entity representing a development project
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
 pub struct Project {
@@ -27,24 +29,66 @@ pub struct Project {
 
     pub progress_percentage: Option<i32>,
 
+    /// Planned spend, in `currency`
+    pub budget: Option<f64>,
+    /// ISO 4217 code such as `USD`
+    pub currency: Option<String>,
+
     pub repository_url: Option<String>,
     pub repository_branch: Option<String>,
 
     pub tags: Option<Vec<String>>,
     pub metadata: Option<sqlx::types::JsonValue>,
 
+    /// Incremented on every update, for optimistic concurrency
+    pub version: i32,
+
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub deleted_at: Option<DateTime<Utc>>,
 }
 
+/// Project with the number of its tasks, for `project list --with-task-counts`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectWithTaskCounts {
+    #[serde(flatten)]
+    pub project: Project,
+    pub total_tasks: i64,
+    pub completed_tasks: i64,
+}
+
+/// Project with its linked resources, for `project show --with-resources`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectDetail {
+    pub project: Project,
+    pub resources: Vec<ProjectResourceDetail>,
+}
+
+impl Project {
+    /// Progress the schedule implies by `today`, as a percentage
+    ///
+    /// This is the elapsed share of the planned window, clamped to 0-100.
+    /// Returns `None` when either planned date is missing or the window is empty.
+    pub fn expected_progress(&self, today: NaiveDate) -> Option<f64> {
+        let (start, end) = (self.start_date?, self.end_date?);
+        let total = (end - start).num_days();
+        if total <= 0 {
+            return None;
+        }
+
+        let elapsed = (today - start).num_days().clamp(0, total);
+        Some(elapsed as f64 * 100.0 / total as f64)
+    }
+}
+
 /// Project status enum
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
-#[serde(rename_all = "lowercase")]
+#[serde(rename_all = "snake_case")]
 #[sqlx(type_name = "varchar", rename_all = "snake_case")]
 pub enum ProjectStatus {
     Planning,
     Active,
+    #[serde(alias = "onhold")]
     OnHold,
     Completed,
     Archived,
@@ -52,6 +96,16 @@ pub enum ProjectStatus {
 }
 
 impl ProjectStatus {
+    /// Every status, in lifecycle order
+    pub const ALL: [ProjectStatus; 6] = [
+        ProjectStatus::Planning,
+        ProjectStatus::Active,
+        ProjectStatus::OnHold,
+        ProjectStatus::Completed,
+        ProjectStatus::Archived,
+        ProjectStatus::Cancelled,
+    ];
+
     pub fn as_str(&self) -> &'static str {
         match self {
             ProjectStatus::Planning => "planning",
@@ -77,7 +131,7 @@ impl std::str::FromStr for ProjectStatus {
         match s {
             "planning" => Ok(ProjectStatus::Planning),
             "active" => Ok(ProjectStatus::Active),
-            "on_hold" => Ok(ProjectStatus::OnHold),
+            "on_hold" | "on-hold" => Ok(ProjectStatus::OnHold),
             "completed" => Ok(ProjectStatus::Completed),
             "archived" => Ok(ProjectStatus::Archived),
             "cancelled" => Ok(ProjectStatus::Cancelled),
@@ -154,6 +208,8 @@ pub struct CreateProject {
     pub priority: Option<Priority>,
     pub start_date: Option<NaiveDate>,
     pub end_date: Option<NaiveDate>,
+    pub budget: Option<f64>,
+    pub currency: Option<String>,
     pub repository_url: Option<String>,
     pub repository_branch: Option<String>,
     pub tags: Option<Vec<String>>,
@@ -161,36 +217,66 @@ pub struct CreateProject {
 }
 
 impl CreateProject {
+    /// Longest project name the schema allows, in characters
+    pub const MAX_NAME_LEN: usize = 255;
+    /// Longest project code the schema allows, in characters
+    pub const MAX_CODE_LEN: usize = 50;
+
     /// Validate the create project input
     pub fn validate(&self) -> Result<(), String> {
-        // Name cannot be empty
-        if self.name.trim().is_empty() {
-            return Err("Project name cannot be empty".to_string());
+        Self::validate_name(&self.name)?;
+        if let Some(ref code) = self.code {
+            Self::validate_code(code)?;
         }
+        Self::validate_dates(self.start_date, self.end_date)?;
+
+        validate_budget(self.budget, self.currency.as_deref())
+    }
 
-        // Name length validation (max 255 characters based on schema)
-        if self.name.len() > 255 {
-            return Err("Project name cannot exceed 255 characters".to_string());
+    /// Check a project name: non-blank and within the schema's length
+    pub fn validate_name(name: &str) -> Result<(), String> {
+        if name.trim().is_empty() {
+            return Err("Project name cannot be empty".to_string());
+        }
+        if name.chars().count() > Self::MAX_NAME_LEN {
+            return Err(format!(
+                "Project name cannot exceed {} characters",
+                Self::MAX_NAME_LEN
+            ));
         }
+        Ok(())
+    }
 
-        // Code validation if provided (max 50 characters based on schema)
-        if let Some(ref code) = self.code {
-            if code.len() > 50 {
-                return Err("Project code cannot exceed 50 characters".to_string());
-            }
-            if code.trim().is_empty() {
-                return Err("Project code cannot be empty if provided".to_string());
-            }
+    /// Check a given project code: non-blank and within the schema's length
+    pub fn validate_code(code: &str) -> Result<(), String> {
+        if code.chars().count() > Self::MAX_CODE_LEN {
+            return Err(format!(
+                "Project code cannot exceed {} characters",
+                Self::MAX_CODE_LEN
+            ));
+        }
+        if code.trim().is_empty() {
+            return Err("Project code cannot be empty if provided".to_string());
         }
+        Ok(())
+    }
 
-        // Date validation
-        if let (Some(start), Some(end)) = (&self.start_date, &self.end_date) {
-            if end < start {
-                return Err("End date must be after or equal to start date".to_string());
+    /// Check that the end date, if any, is not before the start date
+    pub fn validate_dates(start: Option<NaiveDate>, end: Option<NaiveDate>) -> Result<(), String> {
+        match (start, end) {
+            (Some(start), Some(end)) if end < start => {
+                Err("End date must be after or equal to start date".to_string())
             }
+            _ => Ok(()),
         }
+    }
 
-        Ok(())
+    /// Split a comma-separated tag list, trimming tags and dropping blanks
+    pub fn parse_tags(tags: &str) -> Vec<String> {
+        tags.split(',')
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+            .collect()
     }
 }
 
@@ -199,7 +285,8 @@ impl CreateProject {
 pub struct UpdateProject {
     pub id: i64,
     pub name: Option<String>,
-    pub description: Option<String>,
+    /// `Some(None)` clears the description; `None` leaves it unchanged
+    pub description: Option<Option<String>>,
     pub code: Option<String>,
     pub status: Option<ProjectStatus>,
     pub priority: Option<Priority>,
@@ -208,10 +295,14 @@ pub struct UpdateProject {
     pub actual_start_date: Option<NaiveDate>,
     pub actual_end_date: Option<NaiveDate>,
     pub progress_percentage: Option<i32>,
+    pub budget: Option<f64>,
+    pub currency: Option<String>,
     pub repository_url: Option<String>,
     pub repository_branch: Option<String>,
     pub tags: Option<Vec<String>>,
     pub metadata: Option<sqlx::types::JsonValue>,
+    /// Reject the update with a conflict unless the row is still at this version
+    pub expected_version: Option<i32>,
 }
 
 impl UpdateProject {
@@ -244,10 +335,41 @@ impl UpdateProject {
             }
         }
 
-        Ok(())
+        validate_budget(self.budget, self.currency.as_deref())
     }
 }
 
+/// Budget must be a non-negative amount and currency a three-letter code
+fn validate_budget(budget: Option<f64>, currency: Option<&str>) -> Result<(), String> {
+    if let Some(budget) = budget {
+        if !budget.is_finite() || budget < 0.0 {
+            return Err("Budget must be a non-negative amount".to_string());
+        }
+    }
+
+    if let Some(currency) = currency {
+        if currency.len() != 3 || !currency.chars().all(|c| c.is_ascii_uppercase()) {
+            return Err(format!(
+                "Invalid currency: {} (expected a three-letter code such as USD)",
+                currency
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// What `scan_overdue` does with each overdue project
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OverdueAction {
+    /// Add the `overdue` tag
+    Tag,
+    /// Emit a warning-level notification event
+    Notify,
+    /// Move the project to the given status
+    SetStatus(ProjectStatus),
+}
+
 /// Filter options for listing projects
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ProjectFilter {
@@ -255,11 +377,205 @@ pub struct ProjectFilter {
     pub priority: Option<Priority>,
     pub search: Option<String>,
     pub tags: Option<Vec<String>>,
+    /// Whether `tags` must match any or all of a project's tags
+    #[serde(default)]
+    pub tag_match_mode: TagMatchMode,
+    /// Only projects starting on or after this date
+    pub start_date_from: Option<NaiveDate>,
+    /// Only projects starting on or before this date
+    pub start_date_to: Option<NaiveDate>,
+    /// Only projects ending on or after this date
+    pub end_date_from: Option<NaiveDate>,
+    /// Only projects ending on or before this date
+    pub end_date_to: Option<NaiveDate>,
+    /// Include soft-deleted projects; they are excluded by default
+    #[serde(default)]
+    pub include_deleted: bool,
     pub offset: Option<i64>,
     pub limit: Option<i64>,
+    /// Column to order by (defaults to creation time)
+    pub sort_by: Option<ProjectSortField>,
+    /// Sort in descending order
+    pub sort_desc: bool,
+    /// Where rows with an empty sort value go (defaults to last)
+    pub nulls: Option<NullsOrder>,
+}
+
+/// Rank of a `priority` column for ORDER BY, Low=1 up to Critical=4
+///
+/// Projects and tasks store priority with the same names, so both sort on it.
+pub const PRIORITY_RANK_SQL: &str = "CASE priority WHEN 'critical' THEN 4 WHEN 'high' THEN 3 \
+     WHEN 'medium' THEN 2 WHEN 'low' THEN 1 END";
+
+/// Sortable project columns
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProjectSortField {
+    #[default]
+    CreatedAt,
+    Name,
+    StartDate,
+    EndDate,
+    /// By weight, Low=1 up to Critical=4
+    Priority,
+    /// By lifecycle stage, Planning first
+    Status,
+}
+
+impl ProjectSortField {
+    /// Column or rank expression used in ORDER BY
+    ///
+    /// Only these fixed strings ever reach the query, never user input.
+    pub fn column(&self) -> &'static str {
+        match self {
+            ProjectSortField::CreatedAt => "created_at",
+            ProjectSortField::Name => "name",
+            ProjectSortField::StartDate => "start_date",
+            ProjectSortField::EndDate => "end_date",
+            ProjectSortField::Priority => PRIORITY_RANK_SQL,
+            ProjectSortField::Status => {
+                "CASE status WHEN 'planning' THEN 1 WHEN 'active' THEN 2 WHEN 'on_hold' THEN 3 \
+                 WHEN 'completed' THEN 4 WHEN 'archived' THEN 5 WHEN 'cancelled' THEN 6 END"
+            }
+        }
+    }
+}
+
+impl std::str::FromStr for ProjectSortField {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().replace('-', "_").as_str() {
+            "created_at" | "created" => Ok(ProjectSortField::CreatedAt),
+            "name" => Ok(ProjectSortField::Name),
+            "start_date" | "start" => Ok(ProjectSortField::StartDate),
+            "end_date" | "end" => Ok(ProjectSortField::EndDate),
+            "priority" => Ok(ProjectSortField::Priority),
+            "status" => Ok(ProjectSortField::Status),
+            _ => Err(format!(
+                "Invalid sort field: {} (expected created-at, name, start-date, end-date, priority or status)",
+                s
+            )),
+        }
+    }
+}
+
+/// What `clone_project` copies besides the project itself
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CloneProjectOptions {
+    /// Date the copy begins on; every copied date shifts by the same amount
+    pub start: NaiveDate,
+    /// Copy timelines and their milestones
+    pub with_timelines: bool,
+    /// Copy tasks as `todo` skeletons
+    pub with_tasks: bool,
+}
+
+/// How a list of tags filters rows
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TagMatchMode {
+    /// At least one of the tags
+    #[default]
+    Any,
+    /// Every one of the tags
+    All,
+}
+
+impl TagMatchMode {
+    /// Postgres array operator testing a row's tags against the filter tags
+    pub fn operator(&self) -> &'static str {
+        match self {
+            TagMatchMode::Any => "&&",
+            TagMatchMode::All => "@>",
+        }
+    }
+}
+
+/// Placement of NULL values in an ordered listing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NullsOrder {
+    First,
+    Last,
+}
+
+impl std::str::FromStr for NullsOrder {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "first" => Ok(NullsOrder::First),
+            "last" => Ok(NullsOrder::Last),
+            _ => Err(format!(
+                "Invalid nulls placement: {} (expected first or last)",
+                s
+            )),
+        }
+    }
 }
 
 impl ProjectFilter {
+    /// Validate the filter's date ranges
+    pub fn validate(&self) -> Result<(), String> {
+        if let (Some(from), Some(to)) = (self.start_date_from, self.start_date_to) {
+            if from > to {
+                return Err(format!(
+                    "Start date range is empty: {} is after {}",
+                    from, to
+                ));
+            }
+        }
+        if let (Some(from), Some(to)) = (self.end_date_from, self.end_date_to) {
+            if from > to {
+                return Err(format!("End date range is empty: {} is after {}", from, to));
+            }
+        }
+        Ok(())
+    }
+
+    /// Date bounds as `(column, operator, date)`, in the order they are bound
+    ///
+    /// Projects without the bounded date never match.
+    pub fn date_bounds(&self) -> Vec<(&'static str, &'static str, NaiveDate)> {
+        [
+            ("start_date", ">=", self.start_date_from),
+            ("start_date", "<=", self.start_date_to),
+            ("end_date", ">=", self.end_date_from),
+            ("end_date", "<=", self.end_date_to),
+        ]
+        .into_iter()
+        .filter_map(|(column, op, date)| date.map(|date| (column, op, date)))
+        .collect()
+    }
+
+    /// Build the ORDER BY clause for this filter
+    ///
+    /// Empty values sort last unless `nulls` says otherwise, and creation time
+    /// breaks ties so paging stays stable.
+    pub fn order_by_clause(&self) -> String {
+        let Some(field) = self.sort_by else {
+            return "ORDER BY created_at DESC".to_string();
+        };
+
+        let direction = if self.sort_desc { "DESC" } else { "ASC" };
+        let nulls = match self.nulls.unwrap_or(NullsOrder::Last) {
+            NullsOrder::First => "NULLS FIRST",
+            NullsOrder::Last => "NULLS LAST",
+        };
+
+        if field == ProjectSortField::CreatedAt {
+            format!("ORDER BY created_at {} {}", direction, nulls)
+        } else {
+            format!(
+                "ORDER BY {} {} {}, created_at DESC",
+                field.column(),
+                direction,
+                nulls
+            )
+        }
+    }
+
     /// Get the limit with a default value
     pub fn get_limit(&self) -> i64 {
         self.limit.unwrap_or(50).min(100) // Default 50, max 100
@@ -275,6 +591,97 @@ impl ProjectFilter {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_create_project_field_validators() {
+        // Limits count characters, as the VARCHAR columns do
+        assert!(CreateProject::validate_name(&"é".repeat(255)).is_ok());
+        assert!(CreateProject::validate_name(&"a".repeat(256)).is_err());
+        assert!(CreateProject::validate_name("  ").is_err());
+
+        assert!(CreateProject::validate_code("PRJ-1").is_ok());
+        assert!(CreateProject::validate_code(&"C".repeat(51)).is_err());
+        assert!(CreateProject::validate_code(" ").is_err());
+
+        let date = |d| NaiveDate::from_ymd_opt(2025, 1, d);
+        assert!(CreateProject::validate_dates(date(1), date(1)).is_ok());
+        assert!(CreateProject::validate_dates(date(2), date(1)).is_err());
+        assert!(CreateProject::validate_dates(None, date(1)).is_ok());
+
+        assert_eq!(
+            CreateProject::parse_tags(" web, ,api ,"),
+            vec!["web".to_string(), "api".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_filter_date_ranges() {
+        let date = |d| NaiveDate::from_ymd_opt(2025, 1, d).unwrap();
+        let filter = ProjectFilter {
+            start_date_from: Some(date(1)),
+            end_date_to: Some(date(31)),
+            ..Default::default()
+        };
+        assert!(filter.validate().is_ok());
+        assert_eq!(
+            filter.date_bounds(),
+            vec![("start_date", ">=", date(1)), ("end_date", "<=", date(31))]
+        );
+
+        let inverted = ProjectFilter {
+            end_date_from: Some(date(20)),
+            end_date_to: Some(date(10)),
+            ..Default::default()
+        };
+        assert!(inverted.validate().is_err());
+    }
+
+    #[test]
+    fn test_order_by_clause_default() {
+        let filter = ProjectFilter::default();
+        assert_eq!(filter.order_by_clause(), "ORDER BY created_at DESC");
+    }
+
+    #[test]
+    fn test_order_by_clause_end_date_nulls_last() {
+        let filter = ProjectFilter {
+            sort_by: Some("end-date".parse().unwrap()),
+            ..Default::default()
+        };
+        assert_eq!(
+            filter.order_by_clause(),
+            "ORDER BY end_date ASC NULLS LAST, created_at DESC"
+        );
+
+        let filter = ProjectFilter {
+            sort_by: Some(ProjectSortField::StartDate),
+            sort_desc: true,
+            nulls: Some(NullsOrder::First),
+            ..Default::default()
+        };
+        assert_eq!(
+            filter.order_by_clause(),
+            "ORDER BY start_date DESC NULLS FIRST, created_at DESC"
+        );
+    }
+
+    #[test]
+    fn test_sort_field_from_str() {
+        assert_eq!(
+            "end_date".parse::<ProjectSortField>().unwrap(),
+            ProjectSortField::EndDate
+        );
+        assert_eq!(
+            "Start-Date".parse::<ProjectSortField>().unwrap(),
+            ProjectSortField::StartDate
+        );
+        assert_eq!(
+            "Priority".parse::<ProjectSortField>().unwrap(),
+            ProjectSortField::Priority
+        );
+        assert!("budget".parse::<ProjectSortField>().is_err());
+        assert!("middle".parse::<NullsOrder>().is_err());
+    }
+
     #[test]
     fn test_project_status_from_str() {
         assert_eq!(
@@ -289,6 +696,10 @@ mod tests {
             "on_hold".parse::<ProjectStatus>().unwrap(),
             ProjectStatus::OnHold
         );
+        assert_eq!(
+            "on-hold".parse::<ProjectStatus>().unwrap(),
+            ProjectStatus::OnHold
+        );
         assert_eq!(
             "completed".parse::<ProjectStatus>().unwrap(),
             ProjectStatus::Completed
@@ -324,6 +735,8 @@ mod tests {
             priority: None,
             start_date: None,
             end_date: None,
+            budget: None,
+            currency: None,
             repository_url: None,
             repository_branch: None,
             tags: None,
@@ -368,10 +781,13 @@ mod tests {
             actual_start_date: None,
             actual_end_date: None,
             progress_percentage: Some(50),
+            budget: None,
+            currency: None,
             repository_url: None,
             repository_branch: None,
             tags: None,
             metadata: None,
+            expected_version: None,
         };
         assert!(valid.validate().is_ok());
 
@@ -383,6 +799,43 @@ mod tests {
         assert!(invalid_progress.validate().is_err());
     }
 
+    #[test]
+    fn test_expected_progress() {
+        let timestamp = Utc::now();
+        let mut project = Project {
+            id: 1,
+            uuid: Uuid::nil(),
+            name: "Schedule".to_string(),
+            description: None,
+            code: None,
+            status: ProjectStatus::Active,
+            priority: Priority::Medium,
+            start_date: NaiveDate::from_ymd_opt(2025, 1, 1),
+            end_date: NaiveDate::from_ymd_opt(2025, 1, 11),
+            actual_start_date: None,
+            actual_end_date: None,
+            progress_percentage: None,
+            budget: None,
+            currency: None,
+            repository_url: None,
+            repository_branch: None,
+            tags: None,
+            metadata: None,
+            version: 1,
+            created_at: timestamp,
+            updated_at: timestamp,
+            deleted_at: None,
+        };
+
+        let day = |d| NaiveDate::from_ymd_opt(2025, 1, d).unwrap();
+        assert_eq!(project.expected_progress(day(6)), Some(50.0));
+        assert_eq!(project.expected_progress(day(1)), Some(0.0));
+        assert_eq!(project.expected_progress(day(31)), Some(100.0));
+
+        project.end_date = None;
+        assert_eq!(project.expected_progress(day(6)), None);
+    }
+
     #[test]
     fn test_project_filter_defaults() {
         let filter = ProjectFilter::default();
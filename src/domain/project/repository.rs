@@ -1,6 +1,7 @@
 // Project repository trait
 
 use async_trait::async_trait;
+use chrono::NaiveDate;
 use uuid::Uuid;
 
 use super::entity::{CreateProject, Project, ProjectFilter, UpdateProject};
@@ -74,7 +75,8 @@ pub trait ProjectRepository: Send + Sync {
     /// Count projects matching the filter criteria
     ///
     /// # Arguments
-    /// * `filter` - Filter options (pagination is ignored)
+    /// * `filter` - Filter options (pagination is ignored); soft-deleted
+    ///   projects are only counted when `include_deleted` is set
     ///
     /// # Returns
     /// * `Ok(i64)` - Number of matching projects
@@ -106,6 +108,19 @@ pub trait ProjectRepository: Send + Sync {
     /// * `Err(DevErpError)` - Database error
     async fn soft_delete(&self, id: i64) -> Result<bool, DevErpError>;
 
+    /// Soft delete a project with its tasks, timelines and milestones
+    ///
+    /// Runs in a single transaction: either every row is marked deleted or
+    /// none is.
+    ///
+    /// # Arguments
+    /// * `id` - The internal project ID
+    ///
+    /// # Returns
+    /// * `Ok(bool)` - true if project was deleted, false if not found
+    /// * `Err(DevErpError)` - Database error
+    async fn soft_delete_cascade(&self, id: i64) -> Result<bool, DevErpError>;
+
     /// Hard delete a project (permanently removes from database)
     ///
     /// **WARNING**: This operation is irreversible. Use with caution.
@@ -149,6 +164,16 @@ pub trait ProjectRepository: Send + Sync {
     /// * `Ok(Vec<Project>)` - List of projects with the tag (may be empty)
     /// * `Err(DevErpError)` - Database error
     async fn find_by_tag(&self, tag: &str) -> Result<Vec<Project>, DevErpError>;
+
+    /// Find active projects whose planned end date is before `today`
+    ///
+    /// # Arguments
+    /// * `today` - The reference date
+    ///
+    /// # Returns
+    /// * `Ok(Vec<Project>)` - Overdue projects, most overdue first (may be empty)
+    /// * `Err(DevErpError)` - Database error
+    async fn find_overdue(&self, today: NaiveDate) -> Result<Vec<Project>, DevErpError>;
 }
 
 #[cfg(test)]
@@ -1,11 +1,23 @@
 // Project service with business logic
 
+use chrono::NaiveDate;
+use std::collections::HashMap;
 use std::sync::Arc;
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
 use uuid::Uuid;
 
-use super::entity::{CreateProject, Project, ProjectFilter, ProjectStatus, UpdateProject};
+use super::entity::{
+    CloneProjectOptions, CreateProject, OverdueAction, Project, ProjectFilter, ProjectStatus,
+    ProjectWithTaskCounts, UpdateProject,
+};
 use super::repository::ProjectRepository;
+use crate::domain::task::entity::TaskFilter;
+use crate::domain::task::repository::TaskRepository;
+use crate::domain::template::entity::AppliedTemplate;
+use crate::domain::template::repository::TemplateRepository;
+use crate::domain::timeline::entity::Timeline;
+use crate::domain::timeline::repository::TimelineRepository;
+use crate::utils::calendar::working_days_between;
 use crate::utils::error::DevErpError;
 
 /// Project service containing business logic
@@ -18,6 +30,11 @@ use crate::utils::error::DevErpError;
 /// - Domain logic enforcement
 pub struct ProjectService {
     repository: Arc<dyn ProjectRepository>,
+    timeline_repository: Arc<dyn TimelineRepository>,
+    task_repository: Arc<dyn TaskRepository>,
+    template_repository: Arc<dyn TemplateRepository>,
+    holidays: Vec<NaiveDate>,
+    hourly_rate: Option<f64>,
 }
 
 impl ProjectService {
@@ -25,8 +42,42 @@ impl ProjectService {
     ///
     /// # Arguments
     /// * `repository` - The project repository implementation
-    pub fn new(repository: Arc<dyn ProjectRepository>) -> Self {
-        Self { repository }
+    /// * `timeline_repository` - The timeline repository implementation
+    /// * `task_repository` - The task repository implementation
+    /// * `template_repository` - The template repository implementation
+    pub fn new(
+        repository: Arc<dyn ProjectRepository>,
+        timeline_repository: Arc<dyn TimelineRepository>,
+        task_repository: Arc<dyn TaskRepository>,
+        template_repository: Arc<dyn TemplateRepository>,
+    ) -> Self {
+        Self {
+            repository,
+            timeline_repository,
+            task_repository,
+            template_repository,
+            holidays: Vec::new(),
+            hourly_rate: None,
+        }
+    }
+
+    /// Set the holidays left out of working-day durations
+    ///
+    /// # Arguments
+    /// * `holidays` - Dates treated as non-working, typically from `calendar.holidays`
+    pub fn with_holidays(mut self, holidays: Vec<NaiveDate>) -> Self {
+        self.holidays = holidays;
+        self
+    }
+
+    /// Set the rate that prices task actual hours in project stats
+    ///
+    /// # Arguments
+    /// * `hourly_rate` - Cost per logged hour, typically from `cost.hourly_rate`;
+    ///   `None` leaves project costs uncomputed
+    pub fn with_hourly_rate(mut self, hourly_rate: Option<f64>) -> Self {
+        self.hourly_rate = hourly_rate;
+        self
     }
 
     /// Create a new project
@@ -140,6 +191,45 @@ impl ProjectService {
         Ok(count)
     }
 
+    /// Count projects matching filter criteria, per status
+    ///
+    /// One count per status the filter allows, in lifecycle order; statuses
+    /// without matching projects are included with a count of zero.
+    ///
+    /// # Arguments
+    /// * `filter` - Filter options (pagination is ignored)
+    ///
+    /// # Returns
+    /// * `Ok(Vec<(ProjectStatus, i64)>)` - Number of matching projects per status
+    pub async fn count_projects_by_status(
+        &self,
+        filter: ProjectFilter,
+    ) -> Result<Vec<(ProjectStatus, i64)>, DevErpError> {
+        debug!(
+            "Service: Counting projects by status with filter: {:?}",
+            filter
+        );
+
+        let statuses = match filter.status {
+            Some(ref status) => vec![status.clone()],
+            None => ProjectStatus::ALL.to_vec(),
+        };
+
+        let mut counts = Vec::with_capacity(statuses.len());
+        for status in statuses {
+            let count = self
+                .repository
+                .count(ProjectFilter {
+                    status: Some(status.clone()),
+                    ..filter.clone()
+                })
+                .await?;
+            counts.push((status, count));
+        }
+
+        Ok(counts)
+    }
+
     /// Update an existing project
     ///
     /// # Arguments
@@ -202,12 +292,10 @@ impl ProjectService {
 
         // Apply business rules based on status change
         match new_status {
-            ProjectStatus::Active => {
-                // Set actual start date if not already set
-                if current.actual_start_date.is_none() {
-                    update.actual_start_date = Some(chrono::Utc::now().date_naive());
-                    info!(project_id = %id, "Setting actual_start_date on status change to Active");
-                }
+            // Set actual start date if not already set
+            ProjectStatus::Active if current.actual_start_date.is_none() => {
+                update.actual_start_date = Some(chrono::Utc::now().date_naive());
+                info!(project_id = %id, "Setting actual_start_date on status change to Active");
             }
             ProjectStatus::Completed => {
                 // Set actual end date if not already set
@@ -292,6 +380,153 @@ impl ProjectService {
         Ok(project)
     }
 
+    /// Attach task counts to a page of projects
+    ///
+    /// # Arguments
+    /// * `projects` - Projects to count tasks for
+    ///
+    /// # Business Rules
+    /// - All counts come from a single grouped query, regardless of page size
+    /// - Projects without tasks get zero counts
+    pub async fn with_task_counts(
+        &self,
+        projects: Vec<Project>,
+    ) -> Result<Vec<ProjectWithTaskCounts>, DevErpError> {
+        let ids: Vec<i64> = projects.iter().map(|p| p.id).collect();
+        let counts: HashMap<i64, (i64, i64)> = if ids.is_empty() {
+            HashMap::new()
+        } else {
+            self.task_repository
+                .count_by_project(&ids)
+                .await?
+                .into_iter()
+                .map(|c| (c.project_id, (c.total_tasks, c.completed_tasks)))
+                .collect()
+        };
+
+        Ok(projects
+            .into_iter()
+            .map(|project| {
+                let (total_tasks, completed_tasks) =
+                    counts.get(&project.id).copied().unwrap_or((0, 0));
+                ProjectWithTaskCounts {
+                    project,
+                    total_tasks,
+                    completed_tasks,
+                }
+            })
+            .collect())
+    }
+
+    /// Set the planned project window from its timelines
+    ///
+    /// # Arguments
+    /// * `id` - Project ID
+    /// * `force` - Overwrite planned dates that are already set
+    ///
+    /// # Returns
+    /// * `Ok(Project)` - The project with derived dates
+    ///
+    /// # Business Rules
+    /// - start_date becomes the earliest timeline start, end_date the latest timeline end
+    /// - The project must have at least one timeline
+    /// - Existing dates that differ from the derived ones are only replaced with `force`
+    pub async fn derive_dates_from_timelines(
+        &self,
+        id: i64,
+        force: bool,
+    ) -> Result<Project, DevErpError> {
+        debug!("Service: Deriving dates for project {} from timelines", id);
+
+        let project = self.get_project(id).await?;
+        let timelines = self.timeline_repository.find_by_project(id).await?;
+
+        let (start_date, end_date) = timeline_window(&timelines).ok_or_else(|| {
+            DevErpError::Validation(format!(
+                "Project '{}' has no timelines to derive dates from",
+                project.name
+            ))
+        })?;
+
+        let start_changes = project.start_date.is_some_and(|d| d != start_date);
+        let end_changes = project.end_date.is_some_and(|d| d != end_date);
+        if (start_changes || end_changes) && !force {
+            return Err(DevErpError::Conflict(format!(
+                "Project '{}' already has planned dates; use --force to overwrite them",
+                project.name
+            )));
+        }
+
+        let update = UpdateProject {
+            id,
+            start_date: Some(start_date),
+            end_date: Some(end_date),
+            ..Default::default()
+        };
+
+        let project = self.repository.update(update).await?;
+
+        info!(project_id = %id, %start_date, %end_date, "Project dates derived from timelines");
+
+        Ok(project)
+    }
+
+    /// Create a new project shaped like an existing one
+    ///
+    /// # Arguments
+    /// * `source_id` - The project to copy
+    /// * `new_name` - Name of the new project
+    /// * `new_code` - Code of the new project, if any
+    /// * `options` - New start date and which child entities to copy
+    ///
+    /// # Returns
+    /// * `Ok(AppliedTemplate)` - The new project and everything copied into it
+    /// * `Err(DevErpError::NotFound)` - The source project does not exist
+    /// * `Err(DevErpError::Conflict)` - The code is already taken
+    ///
+    /// # Business Rules
+    /// - The copy starts in planning with 0% progress and no actual dates
+    /// - Copied dates keep their distance from the source's start date
+    /// - Copied tasks are reset to `todo`, unassigned and without logged hours
+    /// - Everything is created in a single transaction
+    pub async fn clone_project(
+        &self,
+        source_id: i64,
+        new_name: String,
+        new_code: Option<String>,
+        options: CloneProjectOptions,
+    ) -> Result<AppliedTemplate, DevErpError> {
+        debug!("Service: Cloning project {} as '{}'", source_id, new_name);
+
+        let source = self
+            .template_repository
+            .load_source(source_id)
+            .await?
+            .ok_or_else(|| {
+                DevErpError::NotFound(format!("Project with id {} not found", source_id))
+            })?;
+
+        let plan = source.clone_plan(
+            new_name,
+            new_code,
+            options.start,
+            options.with_timelines,
+            options.with_tasks,
+        );
+        let cloned = self.template_repository.apply(plan).await?;
+
+        info!(
+            source_id = %source_id,
+            project_id = %cloned.project.id,
+            timelines = cloned.timelines.len(),
+            milestones = cloned.milestones.len(),
+            tasks = cloned.tasks.len(),
+            "Project cloned"
+        );
+
+        Ok(cloned)
+    }
+
     /// Delete a project (soft delete)
     ///
     /// # Arguments
@@ -311,6 +546,27 @@ impl ProjectService {
         Ok(deleted)
     }
 
+    /// Soft delete a project together with its tasks, timelines and milestones
+    ///
+    /// All rows are marked deleted atomically; if any step fails nothing is.
+    ///
+    /// # Arguments
+    /// * `id` - Project ID
+    ///
+    /// # Returns
+    /// * `Ok(bool)` - true if deleted, false if not found
+    pub async fn delete_project_cascade(&self, id: i64) -> Result<bool, DevErpError> {
+        debug!("Service: Soft deleting project {} and its children", id);
+
+        let deleted = self.repository.soft_delete_cascade(id).await?;
+
+        if deleted {
+            info!(project_id = %id, "Project and its children soft deleted");
+        }
+
+        Ok(deleted)
+    }
+
     /// Permanently delete a project (hard delete)
     ///
     /// **WARNING**: This permanently removes the project from the database.
@@ -376,6 +632,72 @@ impl ProjectService {
         Ok(projects)
     }
 
+    /// Active projects past their planned end date, left untouched
+    pub async fn find_overdue(&self, today: NaiveDate) -> Result<Vec<Project>, DevErpError> {
+        self.repository.find_overdue(today).await
+    }
+
+    /// Find active projects past their planned end date and act on them
+    ///
+    /// # Arguments
+    /// * `action` - What to do with each overdue project
+    /// * `today` - The reference date; projects ending before it are overdue
+    ///
+    /// # Returns
+    /// * `Ok(Vec<Project>)` - The overdue projects, as left by the action
+    ///
+    /// # Business Rules
+    /// - Only active projects with an end date are considered
+    /// - Tagging is idempotent; projects already tagged `overdue` are not updated again
+    pub async fn scan_overdue(
+        &self,
+        action: OverdueAction,
+        today: NaiveDate,
+    ) -> Result<Vec<Project>, DevErpError> {
+        debug!("Service: Scanning for overdue projects ({:?})", action);
+
+        let overdue = self.repository.find_overdue(today).await?;
+        let mut affected = Vec::with_capacity(overdue.len());
+
+        for project in overdue {
+            let project = match action {
+                OverdueAction::Tag => {
+                    let mut tags = project.tags.clone().unwrap_or_default();
+                    if tags.iter().any(|t| t == OVERDUE_TAG) {
+                        project
+                    } else {
+                        tags.push(OVERDUE_TAG.to_string());
+                        self.repository
+                            .update(UpdateProject {
+                                id: project.id,
+                                tags: Some(tags),
+                                ..Default::default()
+                            })
+                            .await?
+                    }
+                }
+                OverdueAction::Notify => {
+                    let days_overdue = project.end_date.map(|end| (today - end).num_days());
+                    warn!(
+                        project_id = %project.id,
+                        days_overdue = ?days_overdue,
+                        "Project '{}' is past its planned end date",
+                        project.name
+                    );
+                    project
+                }
+                OverdueAction::SetStatus(ref status) => {
+                    self.update_status(project.id, status.clone()).await?
+                }
+            };
+            affected.push(project);
+        }
+
+        info!(count = affected.len(), "Overdue project scan finished");
+
+        Ok(affected)
+    }
+
     /// Calculate project statistics
     ///
     /// Returns basic statistics about a project
@@ -388,13 +710,17 @@ impl ProjectService {
     pub async fn get_project_stats(&self, id: i64) -> Result<ProjectStats, DevErpError> {
         let project = self.get_project(id).await?;
 
-        // Calculate duration
+        // Calculate duration, in calendar days and in working days
         let duration_days = if let (Some(start), Some(end)) = (project.start_date, project.end_date)
         {
             Some((end - start).num_days())
         } else {
             None
         };
+        let working_duration_days = match (project.start_date, project.end_date) {
+            (Some(start), Some(end)) => Some(working_days_between(start, end, &self.holidays)),
+            _ => None,
+        };
 
         // Calculate actual duration
         let actual_duration_days = if let (Some(start), Some(end)) =
@@ -405,6 +731,16 @@ impl ProjectService {
             None
         };
 
+        // Price the hours logged so far, when a rate is configured
+        let estimated_cost = match self.hourly_rate {
+            Some(rate) => Some(self.total_actual_hours(project.id).await? * rate),
+            None => None,
+        };
+        let budget_utilization_pct = match (estimated_cost, project.budget) {
+            (Some(cost), Some(budget)) if budget > 0.0 => Some(cost * 100.0 / budget),
+            _ => None,
+        };
+
         // Check if overdue (planned end date passed but not completed)
         let is_overdue = if let Some(end_date) = project.end_date {
             let today = chrono::Utc::now().date_naive();
@@ -419,16 +755,44 @@ impl ProjectService {
             status: project.status.clone(),
             progress_percentage: project.progress_percentage.unwrap_or(0),
             duration_days,
+            working_duration_days,
             actual_duration_days,
+            budget: project.budget,
+            estimated_cost,
+            budget_utilization_pct,
             is_overdue,
-            days_overdue: if is_overdue && project.end_date.is_some() {
-                let today = chrono::Utc::now().date_naive();
-                Some((today - project.end_date.unwrap()).num_days())
-            } else {
-                None
+            days_overdue: match project.end_date {
+                Some(end_date) if is_overdue => {
+                    let today = chrono::Utc::now().date_naive();
+                    Some((today - end_date).num_days())
+                }
+                _ => None,
             },
         })
     }
+
+    /// Sum of actual hours logged on a project's tasks
+    async fn total_actual_hours(&self, project_id: i64) -> Result<f64, DevErpError> {
+        let tasks = self
+            .task_repository
+            .find_all(TaskFilter {
+                project_id: Some(project_id),
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(tasks.iter().filter_map(|t| t.actual_hours).sum())
+    }
+}
+
+/// Tag added to projects flagged by `scan_overdue`
+pub const OVERDUE_TAG: &str = "overdue";
+
+/// Earliest start and latest end across the given timelines
+fn timeline_window(timelines: &[Timeline]) -> Option<(NaiveDate, NaiveDate)> {
+    let start = timelines.iter().map(|t| t.start_date).min()?;
+    let end = timelines.iter().map(|t| t.end_date).max()?;
+    Some((start, end))
 }
 
 /// Project statistics
@@ -439,7 +803,15 @@ pub struct ProjectStats {
     pub status: ProjectStatus,
     pub progress_percentage: i32,
     pub duration_days: Option<i64>,
+    /// Planned duration without weekends and configured holidays
+    pub working_duration_days: Option<i64>,
     pub actual_duration_days: Option<i64>,
+    pub budget: Option<f64>,
+    /// Actual hours across the project's tasks priced at the hourly rate;
+    /// `None` when no rate is configured
+    pub estimated_cost: Option<f64>,
+    /// `estimated_cost` as a percentage of `budget`
+    pub budget_utilization_pct: Option<f64>,
     pub is_overdue: bool,
     pub days_overdue: Option<i64>,
 }
@@ -447,7 +819,14 @@ pub struct ProjectStats {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use chrono::NaiveDate;
+    use crate::domain::task::entity::{
+        AssigneeStatusCount, CreateTask, ProjectTaskCount, Task, TaskDependency, TaskFilter,
+        UpdateTask,
+    };
+    use crate::domain::template::entity::{
+        ProjectTemplate, StoredTemplate, TemplatePlan, TemplateSource,
+    };
+    use crate::domain::timeline::entity::{CreateTimeline, TimelineFilter, UpdateTimeline};
     use mockall::mock;
     use mockall::predicate::*;
 
@@ -465,13 +844,82 @@ mod tests {
             async fn count(&self, filter: ProjectFilter) -> Result<i64, DevErpError>;
             async fn update(&self, project: UpdateProject) -> Result<Project, DevErpError>;
             async fn soft_delete(&self, id: i64) -> Result<bool, DevErpError>;
+            async fn soft_delete_cascade(&self, id: i64) -> Result<bool, DevErpError>;
             async fn delete(&self, id: i64) -> Result<bool, DevErpError>;
             async fn restore(&self, id: i64) -> Result<bool, DevErpError>;
             async fn code_exists(&self, code: &str, exclude_id: Option<i64>) -> Result<bool, DevErpError>;
             async fn find_by_tag(&self, tag: &str) -> Result<Vec<Project>, DevErpError>;
+            async fn find_overdue(&self, today: NaiveDate) -> Result<Vec<Project>, DevErpError>;
+        }
+    }
+
+    mock! {
+        pub TaskRepo {}
+
+        #[async_trait::async_trait]
+        impl TaskRepository for TaskRepo {
+            async fn create(&self, task: CreateTask) -> Result<Task, DevErpError>;
+            async fn create_with_dependencies(
+                &self,
+                task: CreateTask,
+                depends_on: &[i64],
+            ) -> Result<(Task, Vec<TaskDependency>), DevErpError>;
+            async fn find_by_id(&self, id: i64) -> Result<Option<Task>, DevErpError>;
+            async fn find_by_uuid(&self, uuid: Uuid) -> Result<Option<Task>, DevErpError>;
+            async fn find_all(&self, filter: TaskFilter) -> Result<Vec<Task>, DevErpError>;
+            async fn update(&self, task: UpdateTask) -> Result<Task, DevErpError>;
+            async fn delete(&self, id: i64) -> Result<bool, DevErpError>;
+            async fn soft_delete(&self, id: i64) -> Result<bool, DevErpError>;
+            async fn restore(&self, id: i64) -> Result<bool, DevErpError>;
+            async fn count(&self, filter: TaskFilter) -> Result<i64, DevErpError>;
+            async fn count_by_assignee(&self) -> Result<Vec<AssigneeStatusCount>, DevErpError>;
+            async fn count_by_project(&self, project_ids: &[i64]) -> Result<Vec<ProjectTaskCount>, DevErpError>;
+            async fn last_status_change(&self, task_id: i64) -> Result<Option<chrono::DateTime<chrono::Utc>>, DevErpError>;
+            async fn move_to_project(&self, task_id: i64, project_id: i64, clear_parent: bool) -> Result<Task, DevErpError>;
+        }
+    }
+
+    mock! {
+        pub TimelineRepo {}
+
+        #[async_trait::async_trait]
+        impl TimelineRepository for TimelineRepo {
+            async fn create(&self, timeline: CreateTimeline) -> Result<Timeline, DevErpError>;
+            async fn find_by_id(&self, id: i64) -> Result<Option<Timeline>, DevErpError>;
+            async fn find_all(&self, filter: TimelineFilter) -> Result<Vec<Timeline>, DevErpError>;
+            async fn find_by_project(&self, project_id: i64) -> Result<Vec<Timeline>, DevErpError>;
+            async fn count(&self, filter: TimelineFilter) -> Result<i64, DevErpError>;
+            async fn update(&self, timeline: UpdateTimeline) -> Result<Timeline, DevErpError>;
+            async fn soft_delete(&self, id: i64) -> Result<bool, DevErpError>;
+            async fn delete(&self, id: i64) -> Result<bool, DevErpError>;
+            async fn restore(&self, id: i64) -> Result<bool, DevErpError>;
+            async fn shift_dates(&self, id: i64, days: i32) -> Result<u64, DevErpError>;
+        }
+    }
+
+    mock! {
+        pub TemplateRepo {}
+
+        #[async_trait::async_trait]
+        impl TemplateRepository for TemplateRepo {
+            async fn apply(&self, plan: TemplatePlan) -> Result<AppliedTemplate, DevErpError>;
+            async fn load_source(&self, project_id: i64) -> Result<Option<TemplateSource>, DevErpError>;
+            async fn save(&self, name: &str, source_project_id: Option<i64>, template: &ProjectTemplate) -> Result<StoredTemplate, DevErpError>;
+            async fn find_stored(&self, name: &str, version: Option<i32>) -> Result<Option<StoredTemplate>, DevErpError>;
+            async fn list_stored(&self) -> Result<Vec<StoredTemplate>, DevErpError>;
         }
     }
 
+    /// Service over `repo`, with mocks that expect no calls for everything else
+    fn service_with(repo: MockProjectRepo, task_repo: MockTaskRepo) -> ProjectService {
+        ProjectService::new(
+            Arc::new(repo),
+            Arc::new(MockTimelineRepo::new()),
+            Arc::new(task_repo),
+            Arc::new(MockTemplateRepo::new()),
+        )
+    }
+
     fn create_test_project() -> Project {
         use crate::domain::project::entity::Priority;
 
@@ -488,10 +936,13 @@ mod tests {
             actual_start_date: None,
             actual_end_date: None,
             progress_percentage: Some(0),
+            budget: None,
+            currency: None,
             repository_url: None,
             repository_branch: Some("main".to_string()),
             tags: Some(vec!["test".to_string()]),
             metadata: None,
+            version: 1,
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
             deleted_at: None,
@@ -510,7 +961,7 @@ mod tests {
             .times(1)
             .returning(move |_| Ok(Some(create_test_project())));
 
-        let service = ProjectService::new(Arc::new(mock_repo));
+        let service = service_with(mock_repo, MockTaskRepo::new());
         let result = service.get_project(expected_id).await;
 
         assert!(result.is_ok());
@@ -519,6 +970,64 @@ mod tests {
         assert_eq!(project.name, "Test Project");
     }
 
+    #[tokio::test]
+    async fn test_project_stats_count_working_days() {
+        let mut mock_repo = MockProjectRepo::new();
+        mock_repo.expect_find_by_id().returning(|_| {
+            let mut project = create_test_project();
+            // Monday to the Monday two weeks later
+            project.start_date = NaiveDate::from_ymd_opt(2025, 3, 3);
+            project.end_date = NaiveDate::from_ymd_opt(2025, 3, 17);
+            Ok(Some(project))
+        });
+
+        let service = service_with(mock_repo, MockTaskRepo::new())
+            .with_holidays(vec![NaiveDate::from_ymd_opt(2025, 3, 12).unwrap()]);
+        let stats = service.get_project_stats(1).await.unwrap();
+
+        assert_eq!(stats.duration_days, Some(14));
+        assert_eq!(stats.working_duration_days, Some(9));
+    }
+
+    fn task_with_hours(id: i64, actual_hours: Option<f64>) -> Task {
+        Task {
+            id,
+            title: format!("Task {}", id),
+            status: crate::domain::task::entity::TaskStatus::Done,
+            actual_hours,
+            ..Task::test_default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_project_stats_price_actual_hours_against_budget() {
+        let mut mock_repo = MockProjectRepo::new();
+        mock_repo.expect_find_by_id().returning(|_| {
+            let mut project = create_test_project();
+            project.budget = Some(2000.0);
+            project.currency = Some("USD".to_string());
+            Ok(Some(project))
+        });
+        let mut task_repo = MockTaskRepo::new();
+        task_repo
+            .expect_find_all()
+            .withf(|filter| filter.project_id == Some(1))
+            .returning(|_| {
+                Ok(vec![
+                    task_with_hours(1, Some(6.0)),
+                    task_with_hours(2, None),
+                    task_with_hours(3, Some(4.0)),
+                ])
+            });
+
+        let service = service_with(mock_repo, task_repo).with_hourly_rate(Some(50.0));
+        let stats = service.get_project_stats(1).await.unwrap();
+
+        assert_eq!(stats.budget, Some(2000.0));
+        assert_eq!(stats.estimated_cost, Some(500.0));
+        assert_eq!(stats.budget_utilization_pct, Some(25.0));
+    }
+
     #[tokio::test]
     async fn test_get_project_not_found() {
         let mut mock_repo = MockProjectRepo::new();
@@ -529,7 +1038,7 @@ mod tests {
             .times(1)
             .returning(|_| Ok(None));
 
-        let service = ProjectService::new(Arc::new(mock_repo));
+        let service = service_with(mock_repo, MockTaskRepo::new());
         let result = service.get_project(999).await;
 
         assert!(result.is_err());
@@ -564,7 +1073,7 @@ mod tests {
             Ok(p)
         });
 
-        let service = ProjectService::new(Arc::new(mock_repo));
+        let service = service_with(mock_repo, MockTaskRepo::new());
         let result = service.update_status(1, ProjectStatus::Active).await;
 
         assert!(result.is_ok());
@@ -572,4 +1081,64 @@ mod tests {
         assert_eq!(project.status, ProjectStatus::Active);
         assert!(project.actual_start_date.is_some());
     }
+
+    #[tokio::test]
+    async fn test_scan_overdue_skips_already_tagged() {
+        let mut mock_repo = MockProjectRepo::new();
+
+        mock_repo.expect_find_overdue().times(1).returning(|_| {
+            let mut p = create_test_project();
+            p.status = ProjectStatus::Active;
+            p.tags = Some(vec![OVERDUE_TAG.to_string()]);
+            Ok(vec![p])
+        });
+        mock_repo.expect_update().times(0);
+
+        let service = service_with(mock_repo, MockTaskRepo::new());
+        let today = NaiveDate::from_ymd_opt(2026, 2, 1).unwrap();
+        let flagged = service
+            .scan_overdue(OverdueAction::Tag, today)
+            .await
+            .unwrap();
+
+        assert_eq!(flagged.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_task_counts_use_one_grouped_query() {
+        let mut task_repo = MockTaskRepo::new();
+        task_repo
+            .expect_count_by_project()
+            .withf(|ids| ids == [1, 2, 3])
+            .times(1)
+            .returning(|_| {
+                Ok(vec![
+                    ProjectTaskCount {
+                        project_id: 1,
+                        total_tasks: 5,
+                        completed_tasks: 2,
+                    },
+                    ProjectTaskCount {
+                        project_id: 3,
+                        total_tasks: 1,
+                        completed_tasks: 1,
+                    },
+                ])
+            });
+
+        let service = service_with(MockProjectRepo::new(), task_repo);
+        let projects = (1..=3)
+            .map(|id| Project {
+                id,
+                ..create_test_project()
+            })
+            .collect();
+
+        let counted = service.with_task_counts(projects).await.unwrap();
+        let counts: Vec<(i64, i64, i64)> = counted
+            .iter()
+            .map(|p| (p.project.id, p.total_tasks, p.completed_tasks))
+            .collect();
+        assert_eq!(counts, vec![(1, 5, 2), (2, 0, 0), (3, 1, 1)]);
+    }
 }
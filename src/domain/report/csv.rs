@@ -0,0 +1,322 @@
+// Flat CSV rendering of report structs
+
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::HashMap;
+
+use super::service::{
+    AssigneeBreakdown, BurndownPoint, CleanupSuggestion, MilestoneSlip, MilestoneSlipReport,
+    OverdueItem, OverdueReport, ProjectHealthReport, ProjectStatusReport, ProjectSummaryItem,
+    ResourceUsageItem, ResourceUsageReport, SprintVelocity, TaskAnalyticsReport,
+    TimelineProgressReport, VelocityReport,
+};
+use crate::domain::snapshot::{StatusSnapshot, StatusTrendReport};
+use crate::utils::error::DevErpError;
+
+/// A report rendered as CSV tables
+///
+/// `main` holds the report's scalar fields as a single row (or one row per
+/// item for list reports). Every list field becomes its own table in
+/// `sections`, keyed by the field name, since it cannot share the main row.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReportCsv {
+    pub main: String,
+    pub sections: Vec<(String, String)>,
+}
+
+/// A report type with a fixed CSV layout
+///
+/// Columns are taken from the flattened fields of `Self::default()`, so the
+/// header never depends on the data: an empty list still gets its header and
+/// a `None` field still gets its column.
+pub trait CsvReport: Serialize + Default {
+    /// Columns of the main table
+    fn columns() -> Vec<String> {
+        let sections = Self::sections();
+        match serde_json::to_value(Self::default()) {
+            Ok(Value::Object(fields)) => fields
+                .into_iter()
+                .filter(|(key, value)| {
+                    !value.is_array() && !sections.iter().any(|(name, _)| name == key)
+                })
+                .flat_map(|(key, value)| flatten_field(&key, value))
+                .map(|(column, _)| column)
+                .collect(),
+            Ok(value) => flatten_field("value", value)
+                .into_iter()
+                .map(|(column, _)| column)
+                .collect(),
+            Err(_) => Vec::new(),
+        }
+    }
+
+    /// List fields written as their own tables, with the columns of each
+    fn sections() -> Vec<(&'static str, Vec<String>)> {
+        Vec::new()
+    }
+}
+
+/// A list report: one main-table row per item
+impl<T: CsvReport> CsvReport for Vec<T> {
+    fn columns() -> Vec<String> {
+        T::columns()
+    }
+}
+
+/// Render a report as CSV, flattening nested structs into prefixed columns
+///
+/// Columns follow serde_json's key order, which is alphabetical. A nested
+/// struct in a field named `<x>_by_<y>` is flattened into
+/// `<y>_<field>` columns (e.g. `tasks_by_status.todo` becomes `status_todo`);
+/// other nested structs use the full field name as the prefix.
+pub fn report_to_csv<T: CsvReport>(report: &T) -> Result<ReportCsv, DevErpError> {
+    let value = serde_json::to_value(report)
+        .map_err(|e| DevErpError::Internal(format!("JSON serialization error: {}", e)))?;
+
+    match value {
+        Value::Array(items) => Ok(ReportCsv {
+            main: write_table(&T::columns(), &items)?,
+            sections: Vec::new(),
+        }),
+        Value::Object(mut fields) => {
+            let mut sections = Vec::new();
+            for (name, columns) in T::sections() {
+                // An unrequested optional section is null: write just its header
+                let items = match fields.remove(name) {
+                    Some(Value::Array(items)) => items,
+                    _ => Vec::new(),
+                };
+                sections.push((name.to_string(), write_table(&columns, &items)?));
+            }
+
+            Ok(ReportCsv {
+                main: write_table(&T::columns(), &[Value::Object(fields)])?,
+                sections,
+            })
+        }
+        other => Err(DevErpError::Internal(format!(
+            "Cannot render report as CSV: {}",
+            other
+        ))),
+    }
+}
+
+/// Section entry for a list field whose items are `T`
+pub fn section<T: CsvReport>(name: &'static str) -> (&'static str, Vec<String>) {
+    (name, T::columns())
+}
+
+/// Flatten one field into `(column, cell)` pairs
+fn flatten_field(key: &str, value: Value) -> Vec<(String, String)> {
+    match value {
+        Value::Object(nested) => {
+            let prefix = key.split_once("_by_").map_or(key, |(_, by)| by);
+            nested
+                .into_iter()
+                .flat_map(|(child, value)| flatten_field(&format!("{}_{}", prefix, child), value))
+                .collect()
+        }
+        Value::Null => vec![(key.to_string(), String::new())],
+        Value::String(s) => vec![(key.to_string(), s)],
+        other => vec![(key.to_string(), other.to_string())],
+    }
+}
+
+/// Flatten one item into its cells, keyed by column
+fn flatten_row(item: &Value) -> HashMap<String, String> {
+    match item {
+        Value::Object(fields) => fields
+            .iter()
+            .flat_map(|(key, value)| flatten_field(key, value.clone()))
+            .collect(),
+        other => flatten_field("value", other.clone()).into_iter().collect(),
+    }
+}
+
+/// Write `items` under a fixed header, leaving absent cells empty
+fn write_table(columns: &[String], items: &[Value]) -> Result<String, DevErpError> {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    writer.write_record(columns).map_err(csv_error)?;
+
+    for item in items {
+        let cells = flatten_row(item);
+        writer
+            .write_record(
+                columns
+                    .iter()
+                    .map(|column| cells.get(column).map_or("", String::as_str)),
+            )
+            .map_err(csv_error)?;
+    }
+
+    let bytes = writer
+        .into_inner()
+        .map_err(|e| DevErpError::Internal(format!("CSV write error: {}", e)))?;
+    String::from_utf8(bytes).map_err(|e| DevErpError::Internal(format!("CSV write error: {}", e)))
+}
+
+/// Wrap a csv writer error
+fn csv_error(e: csv::Error) -> DevErpError {
+    DevErpError::Internal(format!("CSV write error: {}", e))
+}
+
+impl CsvReport for String {}
+impl CsvReport for ProjectStatusReport {}
+impl CsvReport for TimelineProgressReport {}
+impl CsvReport for ProjectSummaryItem {}
+impl CsvReport for BurndownPoint {}
+impl CsvReport for AssigneeBreakdown {}
+impl CsvReport for ResourceUsageItem {}
+impl CsvReport for CleanupSuggestion {}
+impl CsvReport for SprintVelocity {}
+impl CsvReport for OverdueItem {}
+impl CsvReport for MilestoneSlip {}
+impl CsvReport for StatusSnapshot {}
+
+impl CsvReport for StatusTrendReport {
+    fn sections() -> Vec<(&'static str, Vec<String>)> {
+        vec![section::<StatusSnapshot>("trend")]
+    }
+}
+
+impl CsvReport for TaskAnalyticsReport {
+    fn sections() -> Vec<(&'static str, Vec<String>)> {
+        vec![section::<AssigneeBreakdown>("by_assignee")]
+    }
+}
+
+impl CsvReport for ResourceUsageReport {
+    fn sections() -> Vec<(&'static str, Vec<String>)> {
+        vec![
+            section::<ResourceUsageItem>("most_used_resources"),
+            section::<CleanupSuggestion>("cleanup_suggestions"),
+        ]
+    }
+}
+
+impl CsvReport for VelocityReport {
+    fn sections() -> Vec<(&'static str, Vec<String>)> {
+        vec![section::<SprintVelocity>("sprints")]
+    }
+}
+
+impl CsvReport for ProjectHealthReport {
+    fn sections() -> Vec<(&'static str, Vec<String>)> {
+        vec![section::<String>("risk_flags")]
+    }
+}
+
+impl CsvReport for OverdueReport {
+    fn sections() -> Vec<(&'static str, Vec<String>)> {
+        vec![section::<OverdueItem>("items")]
+    }
+}
+
+impl CsvReport for MilestoneSlipReport {
+    fn sections() -> Vec<(&'static str, Vec<String>)> {
+        vec![section::<MilestoneSlip>("items")]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Default, Serialize)]
+    struct Breakdown {
+        todo: i64,
+        done: i64,
+    }
+
+    #[derive(Debug, Default, Serialize)]
+    struct Item {
+        resource_id: i64,
+        resource_name: String,
+        note: Option<String>,
+    }
+
+    #[derive(Debug, Default, Serialize)]
+    struct Report {
+        total_tasks: i64,
+        tasks_by_status: Breakdown,
+        owner: Option<String>,
+        items: Vec<Item>,
+        extra: Option<Vec<Item>>,
+    }
+
+    impl CsvReport for Item {}
+
+    impl CsvReport for Report {
+        fn sections() -> Vec<(&'static str, Vec<String>)> {
+            vec![section::<Item>("items"), section::<Item>("extra")]
+        }
+    }
+
+    #[test]
+    fn test_nested_structs_become_prefixed_columns() {
+        let report = Report {
+            total_tasks: 3,
+            tasks_by_status: Breakdown { todo: 2, done: 1 },
+            ..Default::default()
+        };
+
+        let csv = report_to_csv(&report).unwrap();
+        assert_eq!(
+            csv.main,
+            "owner,status_done,status_todo,total_tasks\n,1,2,3\n"
+        );
+    }
+
+    #[test]
+    fn test_lists_become_separate_sections() {
+        let report = Report {
+            items: vec![
+                Item {
+                    resource_id: 1,
+                    resource_name: "serde, json".to_string(),
+                    note: None,
+                },
+                Item {
+                    resource_id: 2,
+                    resource_name: "tokio".to_string(),
+                    note: Some("async".to_string()),
+                },
+            ],
+            ..Default::default()
+        };
+
+        let csv = report_to_csv(&report).unwrap();
+        assert_eq!(
+            csv.sections[0],
+            (
+                "items".to_string(),
+                "note,resource_id,resource_name\n,1,\"serde, json\"\nasync,2,tokio\n".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_empty_and_missing_sections_keep_their_header() {
+        let csv = report_to_csv(&Report::default()).unwrap();
+        assert_eq!(
+            csv.sections,
+            vec![
+                (
+                    "items".to_string(),
+                    "note,resource_id,resource_name\n".to_string()
+                ),
+                (
+                    "extra".to_string(),
+                    "note,resource_id,resource_name\n".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_list_report_header_comes_from_item_type() {
+        let csv = report_to_csv(&Vec::<Item>::new()).unwrap();
+        assert_eq!(csv.main, "note,resource_id,resource_name\n");
+        assert!(csv.sections.is_empty());
+    }
+}
@@ -1,5 +1,12 @@
 // Report module - Business logic for report generation and analytics
 
+pub mod csv;
 pub mod service;
 
-pub use service::ReportService;
+pub use csv::{report_to_csv, section, CsvReport, ReportCsv};
+pub use service::{
+    compute_burndown, compute_health, compute_milestone_slips, group_overdue_by_assignee,
+    score_project_health, AssigneeOverdueReport, BurndownPoint, BurndownReport, CleanupSuggestion,
+    HealthCategory, MilestoneSlip, MilestoneSlipReport, OverdueItem, OverdueKind, OverdueReport,
+    ProjectHealth, ProjectHealthReport, ReportService, SprintVelocity, VelocityReport,
+};
@@ -1,21 +1,27 @@
 // Report Service - Business logic for generating analytics and reports
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
+use std::fmt;
 use std::sync::Arc;
 
-use crate::domain::project::entity::{Priority, ProjectFilter, ProjectStatus};
+use crate::domain::project::entity::{Priority, Project, ProjectFilter, ProjectStatus};
 use crate::domain::project::repository::ProjectRepository;
 use crate::domain::resource::entity::{ResourceFilter, ResourceStatus, ResourceType};
 use crate::domain::resource::repository::ResourceRepository;
-use crate::domain::task::entity::{TaskFilter, TaskPriority, TaskStatus};
+use crate::domain::task::entity::{
+    AssigneeStatusCount, Task, TaskFilter, TaskPriority, TaskStatus,
+};
 use crate::domain::task::repository::TaskRepository;
-use crate::domain::timeline::entity::{MilestoneStatus, TimelineFilter, TimelineStatus};
+use crate::domain::timeline::entity::{
+    Milestone, MilestoneFilter, MilestoneStatus, TimelineFilter, TimelineStatus, TimelineType,
+};
 use crate::domain::timeline::repository::{MilestoneRepository, TimelineRepository};
 use crate::utils::error::DevErpError;
+use crate::utils::ics::{to_ics, CalendarEvent};
 
 /// Project Status Report - Overall project statistics
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ProjectStatusReport {
     /// Total number of projects
     pub total_projects: i64,
@@ -40,7 +46,7 @@ pub struct ProjectStatusReport {
 }
 
 /// Priority Distribution
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct PriorityDistribution {
     pub critical: i64,
     pub high: i64,
@@ -49,7 +55,7 @@ pub struct PriorityDistribution {
 }
 
 /// Task Analytics Report - Task completion and progress statistics
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct TaskAnalyticsReport {
     /// Total number of tasks
     pub total_tasks: i64,
@@ -73,12 +79,15 @@ pub struct TaskAnalyticsReport {
     pub overdue_tasks: i64,
     /// Tasks completed on time
     pub on_time_completion_count: i64,
+    /// Per-assignee breakdown (only when requested)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub by_assignee: Option<Vec<AssigneeBreakdown>>,
     /// Report generation timestamp
     pub generated_at: DateTime<Utc>,
 }
 
 /// Task Status Distribution
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct TaskStatusDistribution {
     pub todo: i64,
     pub in_progress: i64,
@@ -89,8 +98,37 @@ pub struct TaskStatusDistribution {
     pub cancelled: i64,
 }
 
+impl TaskStatusDistribution {
+    /// Add `count` tasks to the bucket for `status`
+    fn add(&mut self, status: &TaskStatus, count: i64) {
+        let bucket = match status {
+            TaskStatus::Todo => &mut self.todo,
+            TaskStatus::InProgress => &mut self.in_progress,
+            TaskStatus::Blocked => &mut self.blocked,
+            TaskStatus::Review => &mut self.review,
+            TaskStatus::Testing => &mut self.testing,
+            TaskStatus::Done => &mut self.done,
+            TaskStatus::Cancelled => &mut self.cancelled,
+        };
+        *bucket += count;
+    }
+}
+
+/// Task counts for a single assignee
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AssigneeBreakdown {
+    /// Assignee name, `None` for unassigned tasks
+    pub assigned_to: Option<String>,
+    /// Total tasks assigned
+    pub total_tasks: i64,
+    /// Tasks by status
+    pub tasks_by_status: TaskStatusDistribution,
+    /// Completion rate (percentage)
+    pub completion_rate: f64,
+}
+
 /// Resource Usage Report - Resource utilization statistics
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ResourceUsageReport {
     /// Total number of resources
     pub total_resources: i64,
@@ -104,12 +142,15 @@ pub struct ResourceUsageReport {
     pub most_used_resources: Vec<ResourceUsageItem>,
     /// Unused resources
     pub unused_resources: i64,
+    /// Unused, deprecated resources that could be archived (only when requested)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cleanup_suggestions: Option<Vec<CleanupSuggestion>>,
     /// Report generation timestamp
     pub generated_at: DateTime<Utc>,
 }
 
 /// Resource Type Distribution
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ResourceTypeDistribution {
     pub library: i64,
     pub api: i64,
@@ -120,7 +161,7 @@ pub struct ResourceTypeDistribution {
 }
 
 /// Resource Usage Item
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ResourceUsageItem {
     pub resource_id: i64,
     pub resource_name: String,
@@ -129,8 +170,41 @@ pub struct ResourceUsageItem {
     pub critical_project_count: i64,
 }
 
+/// A resource suggested for archival and the command that archives it
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CleanupSuggestion {
+    pub resource_id: i64,
+    pub resource_name: String,
+    pub resource_type: String,
+    pub archive_command: String,
+}
+
+impl CleanupSuggestion {
+    fn for_item(item: &ResourceUsageItem) -> Self {
+        Self {
+            resource_id: item.resource_id,
+            resource_name: item.resource_name.clone(),
+            resource_type: item.resource_type.clone(),
+            archive_command: format!(
+                "deverp resource update {} --status archived",
+                item.resource_id
+            ),
+        }
+    }
+}
+
+/// Whether no project uses the resource
+fn is_unused(item: &ResourceUsageItem) -> bool {
+    item.project_count == 0
+}
+
+/// Unused resources already marked deprecated are safe to archive
+fn is_cleanup_candidate(item: &ResourceUsageItem, status: Option<&ResourceStatus>) -> bool {
+    is_unused(item) && matches!(status, Some(ResourceStatus::Deprecated))
+}
+
 /// Timeline Progress Report - Timeline and milestone tracking
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct TimelineProgressReport {
     /// Total number of timelines
     pub total_timelines: i64,
@@ -154,8 +228,135 @@ pub struct TimelineProgressReport {
     pub generated_at: DateTime<Utc>,
 }
 
-/// Project Summary Item
+/// Work completed during one finished sprint
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SprintVelocity {
+    pub timeline_id: i64,
+    pub project_id: i64,
+    pub sprint_name: String,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    /// Tasks marked done within the sprint window
+    pub completed_tasks: i64,
+    /// Estimated hours of those tasks
+    pub completed_hours: f64,
+}
+
+/// Velocity Report - Completed work per sprint and its average
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct VelocityReport {
+    /// Sprints that contribute to the averages, oldest first
+    pub sprints: Vec<SprintVelocity>,
+    /// Average completed tasks per sprint
+    pub average_tasks: f64,
+    /// Average completed estimated hours per sprint
+    pub average_hours: f64,
+    /// Report generation timestamp
+    pub generated_at: DateTime<Utc>,
+}
+
+impl VelocityReport {
+    /// Build the report from finished sprints, keeping only the `last` most
+    /// recent ones (by end date) when given
+    pub fn from_sprints(mut sprints: Vec<SprintVelocity>, last: Option<usize>) -> Self {
+        sprints.sort_by_key(|s| (s.end_date, s.timeline_id));
+        if let Some(last) = last {
+            let skip = sprints.len().saturating_sub(last);
+            sprints.drain(..skip);
+        }
+
+        let (average_tasks, average_hours) = if sprints.is_empty() {
+            (0.0, 0.0)
+        } else {
+            let n = sprints.len() as f64;
+            (
+                sprints
+                    .iter()
+                    .map(|s| s.completed_tasks as f64)
+                    .sum::<f64>()
+                    / n,
+                sprints.iter().map(|s| s.completed_hours).sum::<f64>() / n,
+            )
+        };
+
+        Self {
+            sprints,
+            average_tasks,
+            average_hours,
+            generated_at: Utc::now(),
+        }
+    }
+}
+
+/// Remaining and ideal work on one day of a burndown
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BurndownPoint {
+    pub date: NaiveDate,
+    /// Estimated hours of tasks not yet done at the end of `date`
+    pub remaining_hours: f64,
+    /// Straight-line target from the full estimate down to zero
+    pub ideal_hours: f64,
+}
+
+/// Burndown Report - Remaining estimated work per day of a timeline
 #[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BurndownReport {
+    pub timeline_id: i64,
+    pub timeline_name: String,
+    /// One point per day from the timeline's start to its end date
+    pub points: Vec<BurndownPoint>,
+    /// Report generation timestamp
+    pub generated_at: DateTime<Utc>,
+}
+
+/// Burndown points for each day from `start` to `end`
+///
+/// The scope is the estimated hours of `tasks`, less cancelled ones. A task
+/// stops counting as remaining on the day of its `completed_at`; a done task
+/// without one counts as finished from the start, and a reopened task stays
+/// remaining throughout.
+pub fn compute_burndown(start: NaiveDate, end: NaiveDate, tasks: &[Task]) -> Vec<BurndownPoint> {
+    let scoped: Vec<(Option<NaiveDate>, f64)> = tasks
+        .iter()
+        .filter(|t| t.status != TaskStatus::Cancelled)
+        .filter_map(|t| {
+            let hours = t.estimated_hours?;
+            let done_on = match t.status {
+                TaskStatus::Done => Some(t.completed_at.map_or(start, |at| at.date_naive())),
+                _ => None,
+            };
+            Some((done_on, hours))
+        })
+        .collect();
+    let total: f64 = scoped.iter().map(|(_, hours)| hours).sum();
+    let days = (end - start).num_days();
+
+    start
+        .iter_days()
+        .take_while(|date| *date <= end)
+        .enumerate()
+        .map(|(day, date)| {
+            let remaining = scoped
+                .iter()
+                .filter(|(done_on, _)| done_on.is_none_or(|done| done > date))
+                .map(|(_, hours)| hours)
+                .sum();
+            let ideal = if days == 0 {
+                0.0
+            } else {
+                total * (days - day as i64) as f64 / days as f64
+            };
+            BurndownPoint {
+                date,
+                remaining_hours: remaining,
+                ideal_hours: ideal,
+            }
+        })
+        .collect()
+}
+
+/// Project Summary Item
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ProjectSummaryItem {
     pub project_id: i64,
     pub project_name: String,
@@ -169,6 +370,354 @@ pub struct ProjectSummaryItem {
     pub end_date: Option<String>,
 }
 
+/// Health bucket for a project's health score
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HealthCategory {
+    Healthy,
+    AtRisk,
+    Critical,
+}
+
+impl fmt::Display for HealthCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HealthCategory::Healthy => write!(f, "healthy"),
+            HealthCategory::AtRisk => write!(f, "at_risk"),
+            HealthCategory::Critical => write!(f, "critical"),
+        }
+    }
+}
+
+/// Project Health Item
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectHealth {
+    pub project_id: i64,
+    pub project_name: String,
+    /// 0 (critical) to 100 (healthy)
+    pub score: i32,
+    pub category: HealthCategory,
+    /// Open tasks whose due date has passed
+    pub overdue_tasks: i64,
+    pub blocked_tasks: i64,
+}
+
+/// Score a project's health from 0 to 100
+///
+/// Starts at 100 and deducts the schedule lag (expected minus actual
+/// progress, at most 40 points) plus 10 points per overdue task and per
+/// blocked task, each capped at 30. Scores of 70 and above are healthy,
+/// 40 and above at risk, anything lower critical.
+pub fn compute_health(
+    project: &Project,
+    overdue_tasks: i64,
+    blocked_tasks: i64,
+    today: NaiveDate,
+) -> (i32, HealthCategory) {
+    let lag = project
+        .expected_progress(today)
+        .map(|expected| expected - project.progress_percentage.unwrap_or(0) as f64)
+        .unwrap_or(0.0)
+        .clamp(0.0, 40.0);
+
+    let deduction =
+        lag.round() as i64 + (overdue_tasks * 10).min(30) + (blocked_tasks * 10).min(30);
+    let score = (100 - deduction).clamp(0, 100) as i32;
+
+    let category = match score {
+        70.. => HealthCategory::Healthy,
+        40..=69 => HealthCategory::AtRisk,
+        _ => HealthCategory::Critical,
+    };
+
+    (score, category)
+}
+
+/// Points a late project loses per day past its planned end date
+const SCHEDULE_PENALTY_PER_DAY_LATE: i64 = 5;
+
+/// Health rollup for a single project
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ProjectHealthReport {
+    pub project_id: i64,
+    pub project_name: String,
+    /// 0-100, how well the project keeps to its planned end date
+    pub schedule_score: i32,
+    /// 0-100, the project's recorded progress
+    pub progress_score: i32,
+    /// 0-100, share of the project's tasks that are done
+    pub task_completion_score: i32,
+    /// Weighted average: schedule 40%, progress 30%, task completion 30%
+    pub overall_score: i32,
+    pub risk_flags: Vec<String>,
+}
+
+/// Roll a project's schedule, progress and task completion into one report
+///
+/// Closed (completed, archived, cancelled) projects and projects without a
+/// planned end date get a full schedule score. Otherwise the schedule score
+/// is 100 minus the progress lag (expected minus actual progress) minus
+/// 5 points per day past the end date. A project without tasks scores 0 for
+/// task completion.
+pub fn score_project_health(
+    project: &Project,
+    total_tasks: i64,
+    completed_tasks: i64,
+    today: NaiveDate,
+) -> ProjectHealthReport {
+    let progress = project.progress_percentage.unwrap_or(0).clamp(0, 100);
+    let closed = matches!(
+        project.status,
+        ProjectStatus::Completed | ProjectStatus::Archived | ProjectStatus::Cancelled
+    );
+    let days_late = project
+        .end_date
+        .filter(|_| !closed)
+        .map(|end| (today - end).num_days().max(0))
+        .unwrap_or(0);
+
+    let schedule_score = if closed || project.end_date.is_none() {
+        100
+    } else {
+        let lag = project
+            .expected_progress(today)
+            .map(|expected| (expected - progress as f64).max(0.0))
+            .unwrap_or(0.0);
+        (100 - lag.round() as i64 - days_late * SCHEDULE_PENALTY_PER_DAY_LATE).clamp(0, 100) as i32
+    };
+
+    let task_completion_score = if total_tasks > 0 {
+        (completed_tasks * 100 / total_tasks) as i32
+    } else {
+        0
+    };
+
+    let overall_score = ((schedule_score * 40 + progress * 30 + task_completion_score * 30) as f64
+        / 100.0)
+        .round() as i32;
+
+    let mut risk_flags = Vec::new();
+    if days_late > 0 {
+        risk_flags.push("overdue".to_string());
+    }
+    if total_tasks == 0 {
+        risk_flags.push("no tasks".to_string());
+    }
+    if project.status == ProjectStatus::Active && progress == 0 {
+        risk_flags.push("stalled (0% with active status)".to_string());
+    }
+
+    ProjectHealthReport {
+        project_id: project.id,
+        project_name: project.name.clone(),
+        schedule_score,
+        progress_score: progress,
+        task_completion_score,
+        overall_score,
+        risk_flags,
+    }
+}
+
+/// Kind of item that can appear in the overdue report
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OverdueKind {
+    #[default]
+    Task,
+    Milestone,
+}
+
+impl fmt::Display for OverdueKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OverdueKind::Task => write!(f, "task"),
+            OverdueKind::Milestone => write!(f, "milestone"),
+        }
+    }
+}
+
+/// An open task or milestone whose due/target date has passed
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OverdueItem {
+    pub kind: OverdueKind,
+    pub id: i64,
+    pub project_id: i64,
+    pub name: String,
+    /// The original due date (tasks) or target date (milestones)
+    pub due_date: NaiveDate,
+    pub days_overdue: i64,
+}
+
+/// Overdue Report - Everything that has slipped past its date
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OverdueReport {
+    pub items: Vec<OverdueItem>,
+    pub generated_at: DateTime<Utc>,
+}
+
+impl OverdueReport {
+    /// Render the report as an iCalendar feed, one all-day event per item
+    /// on the date it was originally due
+    pub fn to_ics(&self) -> String {
+        let events: Vec<CalendarEvent> = self
+            .items
+            .iter()
+            .map(|item| CalendarEvent {
+                uid: format!("{}-{}-overdue@deverp", item.kind, item.id),
+                date: item.due_date,
+                summary: format!("Overdue {}: {}", item.kind, item.name),
+                description: format!(
+                    "{} day{} overdue (project #{})",
+                    item.days_overdue,
+                    if item.days_overdue == 1 { "" } else { "s" },
+                    item.project_id
+                ),
+            })
+            .collect();
+
+        to_ics(&events, self.generated_at)
+    }
+}
+
+/// Key under which overdue tasks without an assignee are grouped
+pub const UNASSIGNED: &str = "(unassigned)";
+
+/// Overdue open tasks of one assignee
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssigneeOverdueReport {
+    pub assignee: String,
+    pub overdue_count: i64,
+    /// Task ID, title and due date, oldest due date first
+    pub tasks: Vec<(i64, String, DateTime<Utc>)>,
+}
+
+/// Group open tasks due before `now` by their primary assignee
+///
+/// Assignees with the most overdue tasks come first, ties broken by name.
+pub fn group_overdue_by_assignee(
+    tasks: Vec<Task>,
+    now: DateTime<Utc>,
+) -> Vec<AssigneeOverdueReport> {
+    let mut reports: Vec<AssigneeOverdueReport> = Vec::new();
+
+    for task in tasks {
+        if matches!(task.status, TaskStatus::Done | TaskStatus::Cancelled) {
+            continue;
+        }
+        let Some(due_date) = task.due_date.filter(|due| *due < now) else {
+            continue;
+        };
+
+        let assignee = task
+            .assigned_to
+            .filter(|a| !a.trim().is_empty())
+            .unwrap_or_else(|| UNASSIGNED.to_string());
+        let index = match reports.iter().position(|r| r.assignee == assignee) {
+            Some(index) => index,
+            None => {
+                reports.push(AssigneeOverdueReport {
+                    assignee,
+                    overdue_count: 0,
+                    tasks: Vec::new(),
+                });
+                reports.len() - 1
+            }
+        };
+
+        let entry = &mut reports[index];
+        entry.overdue_count += 1;
+        entry.tasks.push((task.id, task.title, due_date));
+    }
+
+    for report in &mut reports {
+        report.tasks.sort_by_key(|(id, _, due)| (*due, *id));
+    }
+    reports.sort_by(|a, b| {
+        b.overdue_count
+            .cmp(&a.overdue_count)
+            .then_with(|| a.assignee.cmp(&b.assignee))
+    });
+
+    reports
+}
+
+/// How far one milestone ran, or is running, past its target date
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MilestoneSlip {
+    pub milestone_id: i64,
+    pub project_id: i64,
+    pub name: String,
+    pub target_date: NaiveDate,
+    /// Completion date; `None` for a milestone that is still open
+    pub actual_date: Option<NaiveDate>,
+    pub slip_days: i64,
+    /// The milestone is still open, so the slip is measured up to today
+    pub projected: bool,
+}
+
+/// Milestone Slip Report - Size of each schedule slip, largest first
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MilestoneSlipReport {
+    pub items: Vec<MilestoneSlip>,
+    /// Mean slip across `items`, 0 when nothing slipped
+    pub average_slip_days: f64,
+    /// Largest slip in `items`, 0 when nothing slipped
+    pub max_slip_days: i64,
+    pub generated_at: DateTime<Utc>,
+}
+
+/// Slips of completed milestones finished after their target, and projected
+/// slips of open milestones already past it as of `today`
+///
+/// Completed milestones without an actual date and cancelled milestones are
+/// left out. Items are ordered by slip, largest first, ties by milestone id.
+pub fn compute_milestone_slips(
+    milestones: Vec<Milestone>,
+    today: NaiveDate,
+) -> MilestoneSlipReport {
+    let mut items: Vec<MilestoneSlip> = milestones
+        .into_iter()
+        .filter_map(|milestone| {
+            let (end, projected) = match milestone.status {
+                MilestoneStatus::Completed => (milestone.actual_date?, false),
+                MilestoneStatus::Cancelled => return None,
+                MilestoneStatus::Pending
+                | MilestoneStatus::InProgress
+                | MilestoneStatus::Missed => (today, true),
+            };
+            let slip_days = (end - milestone.target_date).num_days();
+            (slip_days > 0).then(|| MilestoneSlip {
+                milestone_id: milestone.id,
+                project_id: milestone.project_id,
+                name: milestone.name,
+                target_date: milestone.target_date,
+                actual_date: milestone.actual_date.filter(|_| !projected),
+                slip_days,
+                projected,
+            })
+        })
+        .collect();
+    items.sort_by(|a, b| {
+        b.slip_days
+            .cmp(&a.slip_days)
+            .then_with(|| a.milestone_id.cmp(&b.milestone_id))
+    });
+
+    let max_slip_days = items.first().map_or(0, |item| item.slip_days);
+    let average_slip_days = if items.is_empty() {
+        0.0
+    } else {
+        items.iter().map(|item| item.slip_days).sum::<i64>() as f64 / items.len() as f64
+    };
+
+    MilestoneSlipReport {
+        items,
+        average_slip_days,
+        max_slip_days,
+        generated_at: Utc::now(),
+    }
+}
+
 /// Report Service
 pub struct ReportService {
     project_repo: Arc<dyn ProjectRepository>,
@@ -327,7 +876,12 @@ impl ReportService {
     }
 
     /// Generate task analytics report
-    pub async fn generate_task_analytics(&self) -> Result<TaskAnalyticsReport, DevErpError> {
+    ///
+    /// With `by_assignee`, the report also carries a per-assignee breakdown.
+    pub async fn generate_task_analytics(
+        &self,
+        by_assignee: bool,
+    ) -> Result<TaskAnalyticsReport, DevErpError> {
         // Count total tasks
         let total_filter = TaskFilter::default();
         let total_tasks = self.task_repo.count(total_filter).await?;
@@ -503,6 +1057,13 @@ impl ReportService {
             })
             .count() as i64;
 
+        let by_assignee = if by_assignee {
+            let counts = self.task_repo.count_by_assignee().await?;
+            Some(build_assignee_breakdown(counts))
+        } else {
+            None
+        };
+
         Ok(TaskAnalyticsReport {
             total_tasks,
             tasks_by_status: TaskStatusDistribution {
@@ -528,12 +1089,18 @@ impl ReportService {
             time_variance_percentage,
             overdue_tasks,
             on_time_completion_count,
+            by_assignee,
             generated_at: Utc::now(),
         })
     }
 
     /// Generate resource usage report
-    pub async fn generate_resource_usage_report(&self) -> Result<ResourceUsageReport, DevErpError> {
+    ///
+    /// With `suggest_cleanup`, the report also lists resources to archive.
+    pub async fn generate_resource_usage_report(
+        &self,
+        suggest_cleanup: bool,
+    ) -> Result<ResourceUsageReport, DevErpError> {
         // Get all resources
         let all_resources = self
             .resource_repo
@@ -593,26 +1160,28 @@ impl ReportService {
 
         // Get resource usage statistics
         let mut resource_usage_items = Vec::new();
+        let mut cleanup_candidates = Vec::new();
         for resource in &all_resources {
             let usage = self.resource_repo.get_usage_stats(resource.id).await?;
-            resource_usage_items.push(ResourceUsageItem {
+            let item = ResourceUsageItem {
                 resource_id: resource.id,
                 resource_name: resource.name.clone(),
                 resource_type: resource.resource_type.to_string(),
                 project_count: usage.total_projects,
                 critical_project_count: usage.critical_projects,
-            });
+            };
+            if is_cleanup_candidate(&item, resource.status.as_ref()) {
+                cleanup_candidates.push(CleanupSuggestion::for_item(&item));
+            }
+            resource_usage_items.push(item);
         }
 
         // Sort by project count and take top 10
-        resource_usage_items.sort_by(|a, b| b.project_count.cmp(&a.project_count));
+        resource_usage_items.sort_by_key(|r| std::cmp::Reverse(r.project_count));
         let most_used_resources = resource_usage_items.iter().take(10).cloned().collect();
 
         // Count unused resources
-        let unused_resources = resource_usage_items
-            .iter()
-            .filter(|r| r.project_count == 0)
-            .count() as i64;
+        let unused_resources = resource_usage_items.iter().filter(|r| is_unused(r)).count() as i64;
 
         Ok(ResourceUsageReport {
             total_resources,
@@ -628,6 +1197,7 @@ impl ReportService {
             },
             most_used_resources,
             unused_resources,
+            cleanup_suggestions: suggest_cleanup.then_some(cleanup_candidates),
             generated_at: Utc::now(),
         })
     }
@@ -731,6 +1301,100 @@ impl ReportService {
         })
     }
 
+    /// Generate the sprint velocity report
+    ///
+    /// Considers sprint timelines that ended on or before `today`, optionally
+    /// for one project only, and averages over the `last` most recent ones.
+    pub async fn generate_velocity_report(
+        &self,
+        project_id: Option<i64>,
+        last: Option<usize>,
+        today: NaiveDate,
+    ) -> Result<VelocityReport, DevErpError> {
+        let mut sprints = Vec::new();
+        loop {
+            let page = self
+                .timeline_repo
+                .find_all(TimelineFilter {
+                    project_id,
+                    timeline_type: Some(TimelineType::Sprint),
+                    offset: Some(sprints.len() as i64),
+                    limit: Some(REPORT_PAGE_SIZE),
+                    ..Default::default()
+                })
+                .await?;
+            let done = (page.len() as i64) < REPORT_PAGE_SIZE;
+            sprints.extend(page);
+            if done {
+                break;
+            }
+        }
+        sprints.retain(|s| s.end_date <= today);
+
+        let mut velocities = Vec::with_capacity(sprints.len());
+        for sprint in sprints {
+            let completed = self
+                .task_repo
+                .find_all(TaskFilter {
+                    project_id: Some(sprint.project_id),
+                    status: Some(TaskStatus::Done),
+                    ..Default::default()
+                })
+                .await?;
+
+            let in_sprint: Vec<_> = completed
+                .iter()
+                .filter(|t| {
+                    t.completed_at.is_some_and(|at| {
+                        let day = at.date_naive();
+                        day >= sprint.start_date && day <= sprint.end_date
+                    })
+                })
+                .collect();
+
+            velocities.push(SprintVelocity {
+                timeline_id: sprint.id,
+                project_id: sprint.project_id,
+                sprint_name: sprint.name,
+                start_date: sprint.start_date,
+                end_date: sprint.end_date,
+                completed_tasks: in_sprint.len() as i64,
+                completed_hours: in_sprint.iter().filter_map(|t| t.estimated_hours).sum(),
+            });
+        }
+
+        Ok(VelocityReport::from_sprints(velocities, last))
+    }
+
+    /// Generate the burndown of a timeline
+    ///
+    /// Counts the estimated hours of every task in the timeline's project,
+    /// see [`compute_burndown`].
+    pub async fn generate_burndown(&self, timeline_id: i64) -> Result<BurndownReport, DevErpError> {
+        let timeline = self
+            .timeline_repo
+            .find_by_id(timeline_id)
+            .await?
+            .ok_or_else(|| {
+                DevErpError::NotFound(format!("Timeline with id {} not found", timeline_id))
+            })?;
+
+        let tasks = self
+            .task_repo
+            .find_all(TaskFilter {
+                project_id: Some(timeline.project_id),
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(BurndownReport {
+            timeline_id: timeline.id,
+            timeline_name: timeline.name,
+            points: compute_burndown(timeline.start_date, timeline.end_date, &tasks),
+            generated_at: Utc::now(),
+        })
+    }
+
     /// Generate project summary report
     pub async fn generate_project_summary(&self) -> Result<Vec<ProjectSummaryItem>, DevErpError> {
         let projects = self.project_repo.find_all(ProjectFilter::default()).await?;
@@ -771,4 +1435,532 @@ impl ReportService {
 
         Ok(summary_items)
     }
+
+    /// Compute the health of a single project
+    pub async fn project_health(
+        &self,
+        project_id: i64,
+        today: NaiveDate,
+    ) -> Result<ProjectHealth, DevErpError> {
+        let project = self
+            .project_repo
+            .find_by_id(project_id)
+            .await?
+            .ok_or_else(|| {
+                DevErpError::NotFound(format!("Project with id {} not found", project_id))
+            })?;
+
+        self.health_of(project, today).await
+    }
+
+    /// Roll up a single project's schedule, progress and task completion
+    pub async fn project_health_report(
+        &self,
+        project_id: i64,
+        today: NaiveDate,
+    ) -> Result<ProjectHealthReport, DevErpError> {
+        let project = self
+            .project_repo
+            .find_by_id(project_id)
+            .await?
+            .ok_or_else(|| {
+                DevErpError::NotFound(format!("Project with id {} not found", project_id))
+            })?;
+
+        let counts = self.task_repo.count_by_project(&[project_id]).await?;
+        let (total_tasks, completed_tasks) = counts
+            .first()
+            .map_or((0, 0), |c| (c.total_tasks, c.completed_tasks));
+
+        Ok(score_project_health(
+            &project,
+            total_tasks,
+            completed_tasks,
+            today,
+        ))
+    }
+
+    /// Compute the health of every active project, worst score first
+    pub async fn generate_project_health(
+        &self,
+        today: NaiveDate,
+    ) -> Result<Vec<ProjectHealth>, DevErpError> {
+        let mut projects = Vec::new();
+        loop {
+            let page = self
+                .project_repo
+                .find_all(ProjectFilter {
+                    status: Some(ProjectStatus::Active),
+                    offset: Some(projects.len() as i64),
+                    limit: Some(REPORT_PAGE_SIZE),
+                    ..Default::default()
+                })
+                .await?;
+            let done = (page.len() as i64) < REPORT_PAGE_SIZE;
+            projects.extend(page);
+            if done {
+                break;
+            }
+        }
+
+        let mut health = Vec::with_capacity(projects.len());
+        for project in projects {
+            health.push(self.health_of(project, today).await?);
+        }
+
+        health.sort_by_key(|h| (h.score, h.project_id));
+        Ok(health)
+    }
+
+    /// Count a project's overdue and blocked tasks and score it
+    async fn health_of(
+        &self,
+        project: Project,
+        today: NaiveDate,
+    ) -> Result<ProjectHealth, DevErpError> {
+        let tasks = self
+            .task_repo
+            .find_all(TaskFilter {
+                project_id: Some(project.id),
+                ..Default::default()
+            })
+            .await?;
+
+        let overdue_tasks = tasks
+            .iter()
+            .filter(|t| !matches!(t.status, TaskStatus::Done | TaskStatus::Cancelled))
+            .filter(|t| t.due_date.is_some_and(|due| due.date_naive() < today))
+            .count() as i64;
+        let blocked_tasks = tasks
+            .iter()
+            .filter(|t| t.status == TaskStatus::Blocked)
+            .count() as i64;
+
+        let (score, category) = compute_health(&project, overdue_tasks, blocked_tasks, today);
+
+        Ok(ProjectHealth {
+            project_id: project.id,
+            project_name: project.name,
+            score,
+            category,
+            overdue_tasks,
+            blocked_tasks,
+        })
+    }
+    /// Collect open tasks and milestones whose date is before `today`,
+    /// oldest first
+    pub async fn generate_overdue_report(
+        &self,
+        today: NaiveDate,
+    ) -> Result<OverdueReport, DevErpError> {
+        let mut items = Vec::new();
+
+        let tasks = self.task_repo.find_all(TaskFilter::default()).await?;
+        for task in tasks {
+            if matches!(task.status, TaskStatus::Done | TaskStatus::Cancelled) {
+                continue;
+            }
+            let Some(due_date) = task.due_date.map(|due| due.date_naive()) else {
+                continue;
+            };
+            if due_date < today {
+                items.push(OverdueItem {
+                    kind: OverdueKind::Task,
+                    id: task.id,
+                    project_id: task.project_id,
+                    name: task.title,
+                    due_date,
+                    days_overdue: (today - due_date).num_days(),
+                });
+            }
+        }
+
+        let mut timelines = Vec::new();
+        loop {
+            let page = self
+                .timeline_repo
+                .find_all(TimelineFilter {
+                    offset: Some(timelines.len() as i64),
+                    limit: Some(REPORT_PAGE_SIZE),
+                    ..Default::default()
+                })
+                .await?;
+            let done = (page.len() as i64) < REPORT_PAGE_SIZE;
+            timelines.extend(page);
+            if done {
+                break;
+            }
+        }
+
+        for timeline in &timelines {
+            let milestones = self.milestone_repo.find_by_timeline(timeline.id).await?;
+            for milestone in milestones {
+                if matches!(
+                    milestone.status,
+                    MilestoneStatus::Completed | MilestoneStatus::Cancelled
+                ) || milestone.target_date >= today
+                {
+                    continue;
+                }
+                items.push(OverdueItem {
+                    kind: OverdueKind::Milestone,
+                    id: milestone.id,
+                    project_id: milestone.project_id,
+                    name: milestone.name,
+                    due_date: milestone.target_date,
+                    days_overdue: (today - milestone.target_date).num_days(),
+                });
+            }
+        }
+
+        items.sort_by_key(|item| (item.due_date, item.kind == OverdueKind::Milestone, item.id));
+
+        Ok(OverdueReport {
+            items,
+            generated_at: Utc::now(),
+        })
+    }
+
+    /// Slip of every late milestone, measured against `today` for open ones
+    pub async fn generate_milestone_slip_report(
+        &self,
+        today: NaiveDate,
+    ) -> Result<MilestoneSlipReport, DevErpError> {
+        let mut milestones = Vec::new();
+        loop {
+            let page = self
+                .milestone_repo
+                .find_all(MilestoneFilter {
+                    offset: Some(milestones.len() as i64),
+                    limit: Some(REPORT_PAGE_SIZE),
+                    ..Default::default()
+                })
+                .await?;
+            let done = (page.len() as i64) < REPORT_PAGE_SIZE;
+            milestones.extend(page);
+            if done {
+                break;
+            }
+        }
+
+        Ok(compute_milestone_slips(milestones, today))
+    }
+
+    /// Overdue open tasks grouped by assignee, most overdue first
+    pub async fn generate_overdue_by_assignee(
+        &self,
+    ) -> Result<Vec<AssigneeOverdueReport>, DevErpError> {
+        let tasks = self.task_repo.find_all(TaskFilter::default()).await?;
+        Ok(group_overdue_by_assignee(tasks, Utc::now()))
+    }
+}
+
+/// Page size used when walking every matching row through a paginated repository
+const REPORT_PAGE_SIZE: i64 = 100;
+
+/// Fold per-status counts into one entry per assignee, unassigned last
+fn build_assignee_breakdown(counts: Vec<AssigneeStatusCount>) -> Vec<AssigneeBreakdown> {
+    let mut breakdown: Vec<AssigneeBreakdown> = Vec::new();
+
+    for row in counts {
+        let index = match breakdown
+            .iter()
+            .position(|b| b.assigned_to == row.assigned_to)
+        {
+            Some(index) => index,
+            None => {
+                breakdown.push(AssigneeBreakdown {
+                    assigned_to: row.assigned_to.clone(),
+                    total_tasks: 0,
+                    tasks_by_status: TaskStatusDistribution::default(),
+                    completion_rate: 0.0,
+                });
+                breakdown.len() - 1
+            }
+        };
+
+        let entry = &mut breakdown[index];
+        entry.total_tasks += row.count;
+        entry.tasks_by_status.add(&row.status, row.count);
+    }
+
+    for entry in &mut breakdown {
+        if entry.total_tasks > 0 {
+            entry.completion_rate =
+                (entry.tasks_by_status.done as f64 / entry.total_tasks as f64) * 100.0;
+        }
+    }
+
+    // Unassigned tasks are reported separately after everyone else
+    breakdown.sort_by_key(|b| b.assigned_to.is_none());
+
+    breakdown
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sprint(id: i64, end_day: u32, completed_tasks: i64) -> SprintVelocity {
+        SprintVelocity {
+            timeline_id: id,
+            project_id: 1,
+            sprint_name: format!("Sprint {}", id),
+            start_date: NaiveDate::from_ymd_opt(2025, 3, end_day - 13).unwrap(),
+            end_date: NaiveDate::from_ymd_opt(2025, 3, end_day).unwrap(),
+            completed_tasks,
+            completed_hours: completed_tasks as f64 * 4.0,
+        }
+    }
+
+    #[test]
+    fn test_cleanup_candidates_are_unused_and_deprecated() {
+        let item = |resource_id, project_count| ResourceUsageItem {
+            resource_id,
+            resource_name: format!("resource-{}", resource_id),
+            resource_type: "library".to_string(),
+            project_count,
+            critical_project_count: 0,
+        };
+
+        let deprecated = ResourceStatus::Deprecated;
+        let active = ResourceStatus::Active;
+        assert!(is_cleanup_candidate(&item(1, 0), Some(&deprecated)));
+        assert!(!is_cleanup_candidate(&item(2, 3), Some(&active)));
+        // Still in use, or unused but not yet deprecated
+        assert!(!is_cleanup_candidate(&item(3, 1), Some(&deprecated)));
+        assert!(!is_cleanup_candidate(&item(4, 0), Some(&active)));
+
+        let suggestion = CleanupSuggestion::for_item(&item(1, 0));
+        assert_eq!(
+            suggestion.archive_command,
+            "deverp resource update 1 --status archived"
+        );
+    }
+
+    #[test]
+    fn test_project_health_report_scores_and_flags() {
+        let date = |m, d| NaiveDate::from_ymd_opt(2025, m, d).unwrap();
+        let timestamp = Utc::now();
+        let mut project = Project {
+            id: 3,
+            uuid: uuid::Uuid::nil(),
+            name: "Checkout".to_string(),
+            description: None,
+            code: None,
+            status: ProjectStatus::Active,
+            priority: Priority::Medium,
+            start_date: Some(date(1, 1)),
+            end_date: Some(date(3, 1)),
+            actual_start_date: None,
+            actual_end_date: None,
+            progress_percentage: Some(0),
+            budget: None,
+            currency: None,
+            repository_url: None,
+            repository_branch: None,
+            tags: None,
+            metadata: None,
+            version: 1,
+            created_at: timestamp,
+            updated_at: timestamp,
+            deleted_at: None,
+        };
+
+        // Four days past the end date with nothing done
+        let report = score_project_health(&project, 0, 0, date(3, 5));
+        assert_eq!(report.schedule_score, 0);
+        assert_eq!(report.overall_score, 0);
+        assert_eq!(
+            report.risk_flags,
+            vec!["overdue", "no tasks", "stalled (0% with active status)"]
+        );
+
+        // On track: half the window elapsed, half the progress made
+        project.progress_percentage = Some(50);
+        let report = score_project_health(&project, 4, 2, date(1, 30));
+        assert_eq!(report.schedule_score, 100);
+        assert_eq!(report.task_completion_score, 50);
+        assert_eq!(report.overall_score, 70);
+        assert!(report.risk_flags.is_empty());
+
+        // Completed projects are never behind schedule
+        project.status = ProjectStatus::Completed;
+        let report = score_project_health(&project, 4, 4, date(6, 1));
+        assert_eq!(report.schedule_score, 100);
+        assert!(report.risk_flags.is_empty());
+    }
+
+    #[test]
+    fn test_velocity_last_n_sprints_only() {
+        // Given out of order; the two oldest sprints were slow
+        let sprints = vec![
+            sprint(3, 28, 9),
+            sprint(1, 14, 1),
+            sprint(4, 30, 11),
+            sprint(2, 20, 2),
+        ];
+
+        let all = VelocityReport::from_sprints(sprints.clone(), None);
+        assert_eq!(all.sprints.len(), 4);
+        assert_eq!(all.average_tasks, 5.75);
+
+        let recent = VelocityReport::from_sprints(sprints, Some(2));
+        let ids: Vec<i64> = recent.sprints.iter().map(|s| s.timeline_id).collect();
+        assert_eq!(ids, vec![3, 4]);
+        assert_eq!(recent.average_tasks, 10.0);
+        assert_eq!(recent.average_hours, 40.0);
+    }
+
+    #[test]
+    fn test_overdue_tasks_grouped_by_assignee() {
+        let now = Utc::now();
+        let task = |id, assigned_to: Option<&str>, status, days_ago: Option<i64>| Task {
+            id,
+            title: format!("Task {}", id),
+            status,
+            assigned_to: assigned_to.map(str::to_string),
+            due_date: days_ago.map(|days| now - chrono::Duration::days(days)),
+            ..Task::test_default()
+        };
+
+        let reports = group_overdue_by_assignee(
+            vec![
+                task(1, Some("alice"), TaskStatus::Todo, Some(1)),
+                task(2, Some("bob"), TaskStatus::InProgress, Some(2)),
+                task(3, Some("bob"), TaskStatus::Blocked, Some(5)),
+                task(4, None, TaskStatus::Todo, Some(3)),
+                // Finished, not yet due, or without a due date: never overdue
+                task(5, Some("alice"), TaskStatus::Done, Some(4)),
+                task(6, Some("carol"), TaskStatus::Cancelled, Some(4)),
+                task(7, Some("carol"), TaskStatus::Todo, Some(-2)),
+                task(8, Some("carol"), TaskStatus::Todo, None),
+            ],
+            now,
+        );
+
+        let summary: Vec<(&str, i64)> = reports
+            .iter()
+            .map(|r| (r.assignee.as_str(), r.overdue_count))
+            .collect();
+        assert_eq!(summary, vec![("bob", 2), (UNASSIGNED, 1), ("alice", 1)]);
+        // Oldest due date first
+        let bob: Vec<i64> = reports[0].tasks.iter().map(|(id, _, _)| *id).collect();
+        assert_eq!(bob, vec![3, 2]);
+    }
+
+    #[test]
+    fn test_burndown_counts_hours_until_completion() {
+        let date = |day| NaiveDate::from_ymd_opt(2025, 3, day).unwrap();
+        let at = |day| date(day).and_hms_opt(12, 0, 0).unwrap().and_utc();
+        let task = |id, status, hours: Option<f64>, completed_day: Option<u32>| Task {
+            id,
+            title: format!("Task {}", id),
+            status,
+            estimated_hours: hours,
+            completed_at: completed_day.map(at),
+            created_at: at(1),
+            updated_at: at(1),
+            ..Task::test_default()
+        };
+
+        let points = compute_burndown(
+            date(1),
+            date(5),
+            &[
+                task(1, TaskStatus::Done, Some(8.0), Some(2)),
+                task(2, TaskStatus::Done, Some(4.0), Some(4)),
+                task(3, TaskStatus::InProgress, Some(4.0), None),
+                // Reopened after completion: still remaining
+                task(4, TaskStatus::Todo, Some(4.0), Some(2)),
+                // Outside the scope
+                task(5, TaskStatus::Cancelled, Some(16.0), None),
+                task(6, TaskStatus::Todo, None, None),
+            ],
+        );
+
+        let remaining: Vec<f64> = points.iter().map(|p| p.remaining_hours).collect();
+        assert_eq!(remaining, vec![20.0, 12.0, 12.0, 8.0, 8.0]);
+        let ideal: Vec<f64> = points.iter().map(|p| p.ideal_hours).collect();
+        assert_eq!(ideal, vec![20.0, 15.0, 10.0, 5.0, 0.0]);
+        assert_eq!(points[4].date, date(5));
+    }
+
+    #[test]
+    fn test_overdue_report_ics_has_event_per_item() {
+        let item = |kind, id, day, days_overdue| OverdueItem {
+            kind,
+            id,
+            project_id: 1,
+            name: format!("Item {}", id),
+            due_date: NaiveDate::from_ymd_opt(2025, 3, day).unwrap(),
+            days_overdue,
+        };
+        let report = OverdueReport {
+            items: vec![
+                item(OverdueKind::Task, 7, 1, 9),
+                item(OverdueKind::Milestone, 7, 9, 1),
+                item(OverdueKind::Task, 8, 9, 1),
+            ],
+            generated_at: Utc::now(),
+        };
+
+        let ics = report.to_ics();
+
+        assert_eq!(ics.matches("BEGIN:VEVENT\r\n").count(), 3);
+        assert!(ics.contains("DTSTART;VALUE=DATE:20250301\r\n"));
+        assert!(ics.contains("UID:milestone-7-overdue@deverp\r\n"));
+        assert!(ics.contains("DESCRIPTION:9 days overdue (project #1)\r\n"));
+        assert!(ics.contains("DESCRIPTION:1 day overdue (project #1)\r\n"));
+    }
+
+    #[test]
+    fn test_milestone_slips_sorted_with_projected_open_milestones() {
+        let date = |day| NaiveDate::from_ymd_opt(2025, 3, day).unwrap();
+        let milestone = |id, target, actual: Option<u32>, status| Milestone {
+            id,
+            timeline_id: 1,
+            project_id: 1,
+            name: format!("Milestone {}", id),
+            description: None,
+            target_date: date(target),
+            actual_date: actual.map(date),
+            status,
+            completion_percentage: 0,
+            sequence: id as i32,
+            metadata: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            deleted_at: None,
+        };
+        let milestones = vec![
+            // Finished two days late
+            milestone(1, 5, Some(7), MilestoneStatus::Completed),
+            // Finished early, not a slip
+            milestone(2, 10, Some(8), MilestoneStatus::Completed),
+            // Still open, ten days past target as of the 20th
+            milestone(3, 10, None, MilestoneStatus::InProgress),
+            // Open but not due yet
+            milestone(4, 25, None, MilestoneStatus::Pending),
+            milestone(5, 1, None, MilestoneStatus::Cancelled),
+            // Missed and still open
+            milestone(6, 18, None, MilestoneStatus::Missed),
+        ];
+
+        let report = compute_milestone_slips(milestones, date(20));
+
+        let slips: Vec<(i64, i64, bool)> = report
+            .items
+            .iter()
+            .map(|item| (item.milestone_id, item.slip_days, item.projected))
+            .collect();
+        assert_eq!(slips, vec![(3, 10, true), (1, 2, false), (6, 2, true)]);
+        assert_eq!(report.items[1].actual_date, Some(date(7)));
+        assert_eq!(report.max_slip_days, 10);
+        assert!((report.average_slip_days - 14.0 / 3.0).abs() < 1e-9);
+
+        let empty = compute_milestone_slips(Vec::new(), date(20));
+        assert_eq!(empty.max_slip_days, 0);
+        assert_eq!(empty.average_slip_days, 0.0);
+    }
 }
@@ -3,8 +3,11 @@ use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 use uuid::Uuid;
 
+use crate::domain::project::entity::TagMatchMode;
+
 /// Resource types for development resources
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::Type, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
 #[sqlx(type_name = "VARCHAR", rename_all = "lowercase")]
 pub enum ResourceType {
     Library,
@@ -46,6 +49,7 @@ impl std::str::FromStr for ResourceType {
 
 /// Resource status
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::Type, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
 #[sqlx(type_name = "VARCHAR", rename_all = "lowercase")]
 pub enum ResourceStatus {
     Active,
@@ -76,6 +80,56 @@ impl std::str::FromStr for ResourceStatus {
     }
 }
 
+/// Semver component to increment
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VersionBump {
+    Major,
+    Minor,
+    Patch,
+}
+
+impl std::str::FromStr for VersionBump {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "major" => Ok(VersionBump::Major),
+            "minor" => Ok(VersionBump::Minor),
+            "patch" => Ok(VersionBump::Patch),
+            _ => Err(format!(
+                "Invalid version bump: {} (expected major, minor or patch)",
+                s
+            )),
+        }
+    }
+}
+
+/// Increment one component of a semver version string
+///
+/// Lower components reset to zero, and any pre-release or build metadata is
+/// dropped, so `1.2.3-beta` bumped by minor becomes `1.3.0`.
+pub fn bump_version(current: &str, bump: VersionBump) -> Result<String, String> {
+    let mut version = semver::Version::parse(current.trim())
+        .map_err(|e| format!("Version '{}' is not valid semver: {}", current, e))?;
+
+    match bump {
+        VersionBump::Major => {
+            version.major += 1;
+            version.minor = 0;
+            version.patch = 0;
+        }
+        VersionBump::Minor => {
+            version.minor += 1;
+            version.patch = 0;
+        }
+        VersionBump::Patch => version.patch += 1,
+    }
+    version.pre = semver::Prerelease::EMPTY;
+    version.build = semver::BuildMetadata::EMPTY;
+
+    Ok(version.to_string())
+}
+
 /// Resource entity representing development resources
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
 pub struct Resource {
@@ -137,10 +191,42 @@ pub struct ResourceFilter {
     pub status: Option<ResourceStatus>,
     pub name_contains: Option<String>,
     pub tags: Option<Vec<String>>,
+    /// Whether `tags` must match any or all of a resource's tags
+    #[serde(default)]
+    pub tag_match_mode: TagMatchMode,
+    /// Only resources whose `updated_at` is more than this many days ago
+    #[serde(default)]
+    pub stale_days: Option<i32>,
     pub offset: Option<i64>,
     pub limit: Option<i64>,
 }
 
+/// A resource that requires another resource
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, PartialEq, Eq)]
+pub struct ResourceDependency {
+    pub resource_id: i64,
+    pub depends_on_resource_id: i64,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Input for adding a resource dependency
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateResourceDependency {
+    pub resource_id: i64,
+    pub depends_on_resource_id: i64,
+}
+
+impl CreateResourceDependency {
+    /// Validate the create dependency input
+    pub fn validate(&self) -> Result<(), String> {
+        if self.resource_id == self.depends_on_resource_id {
+            return Err("A resource cannot depend on itself".to_string());
+        }
+
+        Ok(())
+    }
+}
+
 /// ProjectResource entity representing the link between projects and resources
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
 pub struct ProjectResource {
@@ -183,6 +269,164 @@ pub struct ResourceUsageStats {
     pub critical_projects: i64,
 }
 
+/// A single resource-to-project link in the usage matrix
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct ResourceUsageLink {
+    pub resource_id: i64,
+    pub resource_name: String,
+    pub project_id: i64,
+    pub project_name: String,
+    pub version_used: Option<String>,
+    pub is_critical: bool,
+}
+
+/// A deprecated resource that projects still link as critical
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct CriticalDeprecatedResource {
+    pub resource_id: i64,
+    pub resource_name: String,
+    pub affected_project_ids: Vec<i64>,
+}
+
+/// A live resource and one project actively using it, for the license report
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct ResourceLicenseUsage {
+    pub resource_id: i64,
+    pub resource_name: String,
+    pub version: Option<String>,
+    pub license: Option<String>,
+    /// `None` when no project uses the resource
+    pub project_id: Option<i64>,
+    pub project_name: Option<String>,
+}
+
+/// License group label for resources without a license
+pub const UNSPECIFIED_LICENSE: &str = "(unspecified)";
+
+/// A resource in the license report and the projects consuming it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LicensedResource {
+    pub resource_id: i64,
+    pub resource_name: String,
+    pub version: Option<String>,
+    pub project_names: Vec<String>,
+}
+
+/// Every resource under one license string
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LicenseGroup {
+    pub license: String,
+    /// False when an allowlist is configured and does not include this license
+    pub compliant: bool,
+    pub resources: Vec<LicensedResource>,
+}
+
+/// Licenses in use, grouped by license string
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LicenseReport {
+    pub groups: Vec<LicenseGroup>,
+    /// Resources whose group is not compliant
+    pub non_compliant_resources: usize,
+}
+
+impl LicenseReport {
+    /// Group usage rows by license
+    ///
+    /// Blank licenses fall under [`UNSPECIFIED_LICENSE`], which sorts after
+    /// every named license. With an empty `allowed` list everything is
+    /// compliant; otherwise a license must match an entry, ignoring case.
+    pub fn build(rows: &[ResourceLicenseUsage], allowed: &[String]) -> Self {
+        let mut groups: Vec<LicenseGroup> = Vec::new();
+
+        for row in rows {
+            let license = row
+                .license
+                .as_deref()
+                .map(str::trim)
+                .filter(|l| !l.is_empty())
+                .unwrap_or(UNSPECIFIED_LICENSE);
+
+            let group = match groups.iter().position(|g| g.license == license) {
+                Some(index) => &mut groups[index],
+                None => {
+                    let compliant = allowed.is_empty()
+                        || (license != UNSPECIFIED_LICENSE
+                            && allowed
+                                .iter()
+                                .any(|a| a.trim().eq_ignore_ascii_case(license)));
+                    groups.push(LicenseGroup {
+                        license: license.to_string(),
+                        compliant,
+                        resources: Vec::new(),
+                    });
+                    groups.last_mut().unwrap()
+                }
+            };
+
+            let resource = match group
+                .resources
+                .iter()
+                .position(|r| r.resource_id == row.resource_id)
+            {
+                Some(index) => &mut group.resources[index],
+                None => {
+                    group.resources.push(LicensedResource {
+                        resource_id: row.resource_id,
+                        resource_name: row.resource_name.clone(),
+                        version: row.version.clone(),
+                        project_names: Vec::new(),
+                    });
+                    group.resources.last_mut().unwrap()
+                }
+            };
+            if let Some(ref project_name) = row.project_name {
+                resource.project_names.push(project_name.clone());
+            }
+        }
+
+        groups.sort_by(|a, b| {
+            (a.license == UNSPECIFIED_LICENSE, &a.license)
+                .cmp(&(b.license == UNSPECIFIED_LICENSE, &b.license))
+        });
+        let non_compliant_resources = groups
+            .iter()
+            .filter(|g| !g.compliant)
+            .map(|g| g.resources.len())
+            .sum();
+
+        Self {
+            groups,
+            non_compliant_resources,
+        }
+    }
+}
+
+/// A resource with its usage statistics and the projects linking it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResourceRecord {
+    pub resource: Resource,
+    pub usage: ResourceUsageStats,
+    pub projects: Vec<ResourceUsageLink>,
+}
+
+/// A resource together with the details of its link to one project
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectResourceDetail {
+    pub resource: Resource,
+    pub link: ProjectResource,
+}
+
+/// Outcome of deprecating every resource carrying a tag
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BulkDeprecation {
+    /// Resources that were deprecated by this operation
+    pub deprecated: Vec<Resource>,
+    /// Tagged resources that were already deprecated and left untouched
+    pub already_deprecated: usize,
+    /// Project links of the deprecated resources
+    pub affected_links: Vec<ResourceUsageLink>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -222,4 +466,58 @@ mod tests {
         );
         assert!("invalid".parse::<ResourceStatus>().is_err());
     }
+
+    fn usage(id: i64, license: Option<&str>, project: Option<&str>) -> ResourceLicenseUsage {
+        ResourceLicenseUsage {
+            resource_id: id,
+            resource_name: format!("lib-{}", id),
+            version: None,
+            license: license.map(str::to_string),
+            project_id: project.map(|_| 1),
+            project_name: project.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_license_report_groups_and_flags() {
+        let rows = vec![
+            usage(1, Some("MIT"), Some("Billing")),
+            usage(1, Some("MIT"), Some("Portal")),
+            usage(2, None, Some("Billing")),
+            usage(3, Some("GPL-3.0"), None),
+            usage(4, Some("  "), None),
+            usage(5, Some("Apache-2.0"), Some("Portal")),
+        ];
+
+        let report = LicenseReport::build(&rows, &["mit".to_string(), "Apache-2.0".to_string()]);
+        let licenses: Vec<&str> = report.groups.iter().map(|g| g.license.as_str()).collect();
+        assert_eq!(
+            licenses,
+            vec!["Apache-2.0", "GPL-3.0", "MIT", UNSPECIFIED_LICENSE]
+        );
+        let compliant: Vec<bool> = report.groups.iter().map(|g| g.compliant).collect();
+        assert_eq!(compliant, vec![true, false, true, false]);
+        assert_eq!(report.non_compliant_resources, 3);
+        assert_eq!(
+            report.groups[2].resources[0].project_names,
+            vec!["Billing", "Portal"]
+        );
+        assert!(report.groups[1].resources[0].project_names.is_empty());
+
+        let open = LicenseReport::build(&rows, &[]);
+        assert!(open.groups.iter().all(|g| g.compliant));
+        assert_eq!(open.non_compliant_resources, 0);
+    }
+
+    #[test]
+    fn test_bump_version() {
+        assert_eq!(bump_version("1.2.3", VersionBump::Patch).unwrap(), "1.2.4");
+        assert_eq!(bump_version("1.2.3", VersionBump::Minor).unwrap(), "1.3.0");
+        assert_eq!(
+            bump_version("1.2.3-rc.1", VersionBump::Major).unwrap(),
+            "2.0.0"
+        );
+        assert!(bump_version("1.2", VersionBump::Patch).is_err());
+        assert!(bump_version("latest", VersionBump::Minor).is_err());
+    }
 }
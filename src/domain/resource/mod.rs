@@ -3,8 +3,9 @@ pub mod repository;
 pub mod service;
 
 pub use entity::{
-    CreateResource, LinkResourceToProject, ProjectResource, Resource, ResourceFilter,
-    ResourceStatus, ResourceType, ResourceUsageStats, UpdateProjectResource, UpdateResource,
+    bump_version, BulkDeprecation, CreateResource, CreateResourceDependency, LinkResourceToProject,
+    ProjectResource, Resource, ResourceDependency, ResourceFilter, ResourceRecord, ResourceStatus,
+    ResourceType, ResourceUsageStats, UpdateProjectResource, UpdateResource, VersionBump,
 };
-pub use repository::ResourceRepository;
+pub use repository::{ResourceDependencyRepository, ResourceRepository};
 pub use service::ResourceService;
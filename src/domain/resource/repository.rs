@@ -4,8 +4,10 @@ use uuid::Uuid;
 use crate::utils::error::DevErpError;
 
 use super::entity::{
-    CreateResource, LinkResourceToProject, ProjectResource, Resource, ResourceFilter,
-    ResourceUsageStats, UpdateProjectResource, UpdateResource,
+    CreateResource, CreateResourceDependency, CriticalDeprecatedResource, LinkResourceToProject,
+    ProjectResource, ProjectResourceDetail, Resource, ResourceDependency, ResourceFilter,
+    ResourceLicenseUsage, ResourceUsageLink, ResourceUsageStats, UpdateProjectResource,
+    UpdateResource,
 };
 
 /// Repository trait for Resource operations
@@ -20,15 +22,36 @@ pub trait ResourceRepository: Send + Sync {
     /// Find resource by UUID
     async fn find_by_uuid(&self, uuid: Uuid) -> Result<Option<Resource>, DevErpError>;
 
+    /// Find a live resource with exactly this name and version
+    ///
+    /// A `None` version only matches resources without a version.
+    async fn find_by_name_version(
+        &self,
+        name: &str,
+        version: Option<String>,
+    ) -> Result<Option<Resource>, DevErpError>;
+
     /// Find all resources matching the filter
     async fn find_all(&self, filter: ResourceFilter) -> Result<Vec<Resource>, DevErpError>;
 
+    /// Count resources matching the filter, ignoring pagination
+    async fn count(&self, filter: ResourceFilter) -> Result<i64, DevErpError>;
+
     /// Update an existing resource
     async fn update(&self, resource: UpdateResource) -> Result<Resource, DevErpError>;
 
+    /// Update several resources atomically (all or none)
+    async fn update_many(
+        &self,
+        resources: Vec<UpdateResource>,
+    ) -> Result<Vec<Resource>, DevErpError>;
+
     /// Soft delete a resource
     async fn soft_delete(&self, id: i64) -> Result<bool, DevErpError>;
 
+    /// Restore a soft-deleted resource; false if not found or not deleted
+    async fn restore(&self, id: i64) -> Result<bool, DevErpError>;
+
     /// Hard delete a resource (use with caution)
     async fn delete(&self, id: i64) -> Result<bool, DevErpError>;
 
@@ -54,6 +77,13 @@ pub trait ResourceRepository: Send + Sync {
     /// Find all resources linked to a project
     async fn find_by_project_id(&self, project_id: i64) -> Result<Vec<Resource>, DevErpError>;
 
+    /// Find all resources linked to a project with their link details,
+    /// critical resources first
+    async fn find_details_by_project_id(
+        &self,
+        project_id: i64,
+    ) -> Result<Vec<ProjectResourceDetail>, DevErpError>;
+
     /// Find all projects using a resource
     async fn find_projects_using_resource(&self, resource_id: i64)
         -> Result<Vec<i64>, DevErpError>;
@@ -63,4 +93,44 @@ pub trait ResourceRepository: Send + Sync {
 
     /// Get usage statistics for all resources
     async fn get_all_usage_stats(&self) -> Result<Vec<ResourceUsageStats>, DevErpError>;
+
+    /// Get every active resource-project link (the usage matrix)
+    async fn get_usage_matrix(&self) -> Result<Vec<ResourceUsageLink>, DevErpError>;
+
+    /// Find deprecated resources with active critical links, grouped by resource
+    async fn find_critical_deprecated(
+        &self,
+    ) -> Result<Vec<CriticalDeprecatedResource>, DevErpError>;
+
+    /// Get every live resource with its license, once per active project link
+    /// (once with no project when unused)
+    async fn get_license_usage(&self) -> Result<Vec<ResourceLicenseUsage>, DevErpError>;
+}
+
+/// Repository trait for dependencies between resources
+#[async_trait]
+pub trait ResourceDependencyRepository: Send + Sync {
+    /// Record that a resource depends on another
+    ///
+    /// Rejects an existing edge with `Conflict` and an edge that would close
+    /// a cycle with `Validation`. Implementations must make those checks and
+    /// the insert atomic with respect to other `add` calls, and must count
+    /// edges through soft-deleted resources, which can be restored.
+    async fn add(
+        &self,
+        dependency: CreateResourceDependency,
+    ) -> Result<ResourceDependency, DevErpError>;
+
+    /// Remove a dependency, returning false if it did not exist
+    async fn remove(
+        &self,
+        resource_id: i64,
+        depends_on_resource_id: i64,
+    ) -> Result<bool, DevErpError>;
+
+    /// Direct dependencies of a resource
+    async fn get_dependencies(
+        &self,
+        resource_id: i64,
+    ) -> Result<Vec<ResourceDependency>, DevErpError>;
 }
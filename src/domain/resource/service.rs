@@ -1,3 +1,5 @@
+use chrono::Utc;
+use std::collections::HashSet;
 use std::sync::Arc;
 use tracing::{info, warn};
 use uuid::Uuid;
@@ -6,21 +8,47 @@ use crate::utils::error::DevErpError;
 
 use super::{
     entity::{
-        CreateResource, LinkResourceToProject, ProjectResource, Resource, ResourceFilter,
-        ResourceUsageStats, UpdateProjectResource, UpdateResource,
+        bump_version, BulkDeprecation, CreateResource, CreateResourceDependency,
+        CriticalDeprecatedResource, LicenseReport, LinkResourceToProject, ProjectResource,
+        ProjectResourceDetail, Resource, ResourceDependency, ResourceFilter, ResourceRecord,
+        ResourceStatus, ResourceUsageLink, ResourceUsageStats, UpdateProjectResource,
+        UpdateResource, VersionBump,
     },
-    repository::ResourceRepository,
+    repository::{ResourceDependencyRepository, ResourceRepository},
 };
 
 /// Service for resource management business logic
 pub struct ResourceService {
     repository: Arc<dyn ResourceRepository>,
+    dependency_repo: Arc<dyn ResourceDependencyRepository>,
+    unique_name_version: bool,
+    allowed_licenses: Vec<String>,
 }
 
 impl ResourceService {
-    /// Create a new ResourceService with the given repository
-    pub fn new(repository: Arc<dyn ResourceRepository>) -> Self {
-        Self { repository }
+    /// Create a new ResourceService with the given repositories
+    pub fn new(
+        repository: Arc<dyn ResourceRepository>,
+        dependency_repo: Arc<dyn ResourceDependencyRepository>,
+    ) -> Self {
+        Self {
+            repository,
+            dependency_repo,
+            unique_name_version: false,
+            allowed_licenses: Vec::new(),
+        }
+    }
+
+    /// Reject new resources that repeat an existing (name, version) pair
+    pub fn with_unique_name_version(mut self, enabled: bool) -> Self {
+        self.unique_name_version = enabled;
+        self
+    }
+
+    /// Licenses the license report treats as compliant; empty allows all
+    pub fn with_allowed_licenses(mut self, allowed_licenses: Vec<String>) -> Self {
+        self.allowed_licenses = allowed_licenses;
+        self
     }
 
     /// Create a new resource with validation
@@ -50,6 +78,21 @@ impl ResourceService {
             }
         }
 
+        if self.unique_name_version {
+            if let Some(existing) = self
+                .repository
+                .find_by_name_version(&input.name, input.version.clone())
+                .await?
+            {
+                return Err(DevErpError::Conflict(format!(
+                    "Resource '{}' version {} already exists (id {}); pass --allow-duplicate to create it anyway",
+                    existing.name,
+                    existing.version.as_deref().unwrap_or("(none)"),
+                    existing.id
+                )));
+            }
+        }
+
         let resource = self.repository.create(input).await?;
         info!(resource_id = %resource.id, resource_name = %resource.name, "Created new resource");
 
@@ -80,6 +123,21 @@ impl ResourceService {
         self.repository.find_all(filter).await
     }
 
+    /// Count resources matching a filter
+    pub async fn count_resources(&self, filter: ResourceFilter) -> Result<i64, DevErpError> {
+        self.repository.count(filter).await
+    }
+
+    /// Compute a resource's version after a semver bump, without storing it
+    pub async fn next_version(&self, id: i64, bump: VersionBump) -> Result<String, DevErpError> {
+        let resource = self.get_resource(id).await?;
+        let current = resource.version.ok_or_else(|| {
+            DevErpError::Validation(format!("Resource {} has no version to bump", id))
+        })?;
+
+        bump_version(&current, bump).map_err(DevErpError::Validation)
+    }
+
     /// Update a resource
     pub async fn update_resource(&self, input: UpdateResource) -> Result<Resource, DevErpError> {
         // Validate the resource exists
@@ -150,6 +208,130 @@ impl ResourceService {
         Ok(())
     }
 
+    /// Restore a soft-deleted resource
+    pub async fn restore_resource(&self, id: i64) -> Result<(), DevErpError> {
+        let restored = self.repository.restore(id).await?;
+        if !restored {
+            return Err(DevErpError::NotFound(format!(
+                "Resource with id {} not found or not deleted",
+                id
+            )));
+        }
+
+        info!(resource_id = %id, "Restored resource");
+        Ok(())
+    }
+
+    /// Mark a resource as deprecated, recording the reason in its metadata
+    pub async fn deprecate_resource(
+        &self,
+        id: i64,
+        reason: Option<String>,
+    ) -> Result<Resource, DevErpError> {
+        let resource = self.get_resource(id).await?;
+
+        if resource.status == Some(ResourceStatus::Deprecated) {
+            return Err(DevErpError::Conflict(format!(
+                "Resource '{}' is already deprecated",
+                resource.name
+            )));
+        }
+
+        let resource = self
+            .repository
+            .update(Self::deprecation_update(&resource, reason.as_deref()))
+            .await?;
+        info!(resource_id = %resource.id, resource_name = %resource.name, "Deprecated resource");
+
+        Ok(resource)
+    }
+
+    /// Deprecate every resource tagged with `tag` in a single transaction
+    ///
+    /// Resources that are already deprecated are skipped and counted.
+    pub async fn deprecate_resources_by_tag(
+        &self,
+        tag: &str,
+        reason: Option<String>,
+    ) -> Result<BulkDeprecation, DevErpError> {
+        let tagged = self
+            .repository
+            .find_all(ResourceFilter {
+                tags: Some(vec![tag.to_string()]),
+                ..Default::default()
+            })
+            .await?;
+
+        let (already, targets): (Vec<_>, Vec<_>) = tagged
+            .into_iter()
+            .partition(|r| r.status == Some(ResourceStatus::Deprecated));
+
+        if targets.is_empty() {
+            return Ok(BulkDeprecation {
+                deprecated: Vec::new(),
+                already_deprecated: already.len(),
+                affected_links: Vec::new(),
+            });
+        }
+
+        let updates = targets
+            .iter()
+            .map(|r| Self::deprecation_update(r, reason.as_deref()))
+            .collect();
+        let deprecated = self.repository.update_many(updates).await?;
+
+        let ids: HashSet<i64> = deprecated.iter().map(|r| r.id).collect();
+        let affected_links = self
+            .repository
+            .get_usage_matrix()
+            .await?
+            .into_iter()
+            .filter(|link| ids.contains(&link.resource_id))
+            .collect();
+
+        info!(tag = %tag, count = deprecated.len(), "Deprecated tagged resources");
+
+        Ok(BulkDeprecation {
+            deprecated,
+            already_deprecated: already.len(),
+            affected_links,
+        })
+    }
+
+    /// Build the update that deprecates `resource`
+    ///
+    /// Existing metadata is kept; the deprecation time and reason are added.
+    fn deprecation_update(resource: &Resource, reason: Option<&str>) -> UpdateResource {
+        let mut metadata = match resource.metadata {
+            Some(serde_json::Value::Object(ref map)) => map.clone(),
+            _ => serde_json::Map::new(),
+        };
+        metadata.insert(
+            "deprecated_at".to_string(),
+            serde_json::Value::String(Utc::now().to_rfc3339()),
+        );
+        if let Some(reason) = reason {
+            metadata.insert(
+                "deprecation_reason".to_string(),
+                serde_json::Value::String(reason.to_string()),
+            );
+        }
+
+        UpdateResource {
+            id: resource.id,
+            name: None,
+            description: None,
+            resource_type: None,
+            version: None,
+            url: None,
+            documentation_url: None,
+            license: None,
+            status: Some(ResourceStatus::Deprecated),
+            metadata: Some(serde_json::Value::Object(metadata)),
+            tags: None,
+        }
+    }
+
     /// Link a resource to a project
     pub async fn link_resource_to_project(
         &self,
@@ -222,6 +404,14 @@ impl ResourceService {
         self.repository.find_by_project_id(project_id).await
     }
 
+    /// Get all resources for a project with version/critical link details
+    pub async fn get_project_resource_details(
+        &self,
+        project_id: i64,
+    ) -> Result<Vec<ProjectResourceDetail>, DevErpError> {
+        self.repository.find_details_by_project_id(project_id).await
+    }
+
     /// Get resource usage statistics
     pub async fn get_resource_usage(
         &self,
@@ -230,11 +420,53 @@ impl ResourceService {
         self.repository.get_usage_stats(resource_id).await
     }
 
+    /// Get a resource with its usage statistics and linked projects
+    pub async fn get_resource_record(&self, id: i64) -> Result<ResourceRecord, DevErpError> {
+        let resource = self.get_resource(id).await?;
+        let usage = self.get_resource_usage(id).await?;
+        let projects = self
+            .get_usage_matrix()
+            .await?
+            .into_iter()
+            .filter(|link| link.resource_id == id)
+            .collect();
+
+        Ok(ResourceRecord {
+            resource,
+            usage,
+            projects,
+        })
+    }
+
     /// Get usage statistics for all resources
     pub async fn get_all_resource_usage(&self) -> Result<Vec<ResourceUsageStats>, DevErpError> {
         self.repository.get_all_usage_stats().await
     }
 
+    /// Get the full resource-to-project usage matrix
+    pub async fn get_usage_matrix(&self) -> Result<Vec<ResourceUsageLink>, DevErpError> {
+        self.repository.get_usage_matrix().await
+    }
+
+    /// Deprecated resources that active projects still mark as critical
+    ///
+    /// Each entry lists the projects that would need a migration plan. Links
+    /// that were removed do not count.
+    pub async fn find_critical_deprecated(
+        &self,
+    ) -> Result<Vec<CriticalDeprecatedResource>, DevErpError> {
+        self.repository.find_critical_deprecated().await
+    }
+
+    /// Licenses in use, each with its resources and the projects consuming them
+    ///
+    /// Resources without a license are grouped under "(unspecified)". When an
+    /// allowlist is set, licenses outside it are flagged non-compliant.
+    pub async fn generate_license_report(&self) -> Result<LicenseReport, DevErpError> {
+        let rows = self.repository.get_license_usage().await?;
+        Ok(LicenseReport::build(&rows, &self.allowed_licenses))
+    }
+
     /// Analyze resource utilization
     pub async fn analyze_resource_utilization(
         &self,
@@ -243,11 +475,78 @@ impl ResourceService {
 
         // Sort by total projects descending
         let mut sorted_stats = stats;
-        sorted_stats.sort_by(|a, b| b.total_projects.cmp(&a.total_projects));
+        sorted_stats.sort_by_key(|s| std::cmp::Reverse(s.total_projects));
 
         Ok(sorted_stats)
     }
 
+    /// Record that `resource_id` depends on `depends_on_resource_id`
+    ///
+    /// Self-dependencies, duplicates and edges that would close a cycle are
+    /// rejected; the repository checks the latter two atomically with the insert.
+    pub async fn add_resource_dependency(
+        &self,
+        dependency: CreateResourceDependency,
+    ) -> Result<ResourceDependency, DevErpError> {
+        dependency.validate().map_err(DevErpError::Validation)?;
+
+        // Verify both resources exist
+        self.get_resource(dependency.resource_id).await?;
+        self.get_resource(dependency.depends_on_resource_id).await?;
+
+        let added = self.dependency_repo.add(dependency).await?;
+
+        info!(
+            resource_id = %added.resource_id,
+            depends_on = %added.depends_on_resource_id,
+            "Resource dependency added"
+        );
+
+        Ok(added)
+    }
+
+    /// Remove a dependency between two resources
+    pub async fn remove_resource_dependency(
+        &self,
+        resource_id: i64,
+        depends_on_resource_id: i64,
+    ) -> Result<(), DevErpError> {
+        let removed = self
+            .dependency_repo
+            .remove(resource_id, depends_on_resource_id)
+            .await?;
+
+        if !removed {
+            return Err(DevErpError::NotFound(format!(
+                "Resource {} does not depend on resource {}",
+                resource_id, depends_on_resource_id
+            )));
+        }
+
+        info!(
+            resource_id = %resource_id,
+            depends_on = %depends_on_resource_id,
+            "Resource dependency removed"
+        );
+
+        Ok(())
+    }
+
+    /// Resources that `resource_id` directly depends on
+    pub async fn get_resource_dependencies(
+        &self,
+        resource_id: i64,
+    ) -> Result<Vec<Resource>, DevErpError> {
+        self.get_resource(resource_id).await?;
+
+        let mut resources = Vec::new();
+        for dependency in self.dependency_repo.get_dependencies(resource_id).await? {
+            resources.push(self.get_resource(dependency.depends_on_resource_id).await?);
+        }
+
+        Ok(resources)
+    }
+
     /// Basic URL validation
     fn is_valid_url(url: &str) -> bool {
         url.starts_with("http://") || url.starts_with("https://") || url.starts_with("file://")
@@ -257,7 +556,7 @@ impl ResourceService {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::resource::entity::{ResourceStatus, ResourceType};
+    use crate::domain::resource::entity::{ResourceLicenseUsage, ResourceType};
     use async_trait::async_trait;
     use chrono::Utc;
     use mockall::mock;
@@ -271,17 +570,36 @@ mod tests {
             async fn create(&self, resource: CreateResource) -> Result<Resource, DevErpError>;
             async fn find_by_id(&self, id: i64) -> Result<Option<Resource>, DevErpError>;
             async fn find_by_uuid(&self, uuid: Uuid) -> Result<Option<Resource>, DevErpError>;
+            async fn find_by_name_version(&self, name: &str, version: Option<String>) -> Result<Option<Resource>, DevErpError>;
             async fn find_all(&self, filter: ResourceFilter) -> Result<Vec<Resource>, DevErpError>;
+            async fn count(&self, filter: ResourceFilter) -> Result<i64, DevErpError>;
             async fn update(&self, resource: UpdateResource) -> Result<Resource, DevErpError>;
+            async fn update_many(&self, resources: Vec<UpdateResource>) -> Result<Vec<Resource>, DevErpError>;
             async fn soft_delete(&self, id: i64) -> Result<bool, DevErpError>;
+            async fn restore(&self, id: i64) -> Result<bool, DevErpError>;
             async fn delete(&self, id: i64) -> Result<bool, DevErpError>;
             async fn link_to_project(&self, link: LinkResourceToProject) -> Result<ProjectResource, DevErpError>;
             async fn unlink_from_project(&self, project_id: i64, resource_id: i64) -> Result<bool, DevErpError>;
             async fn update_project_resource(&self, update: UpdateProjectResource) -> Result<ProjectResource, DevErpError>;
             async fn find_by_project_id(&self, project_id: i64) -> Result<Vec<Resource>, DevErpError>;
+            async fn find_details_by_project_id(&self, project_id: i64) -> Result<Vec<ProjectResourceDetail>, DevErpError>;
             async fn find_projects_using_resource(&self, resource_id: i64) -> Result<Vec<i64>, DevErpError>;
             async fn get_usage_stats(&self, resource_id: i64) -> Result<ResourceUsageStats, DevErpError>;
             async fn get_all_usage_stats(&self) -> Result<Vec<ResourceUsageStats>, DevErpError>;
+            async fn get_usage_matrix(&self) -> Result<Vec<ResourceUsageLink>, DevErpError>;
+            async fn find_critical_deprecated(&self) -> Result<Vec<CriticalDeprecatedResource>, DevErpError>;
+            async fn get_license_usage(&self) -> Result<Vec<ResourceLicenseUsage>, DevErpError>;
+        }
+    }
+
+    mock! {
+        pub DependencyRepo {}
+
+        #[async_trait]
+        impl ResourceDependencyRepository for DependencyRepo {
+            async fn add(&self, dependency: CreateResourceDependency) -> Result<ResourceDependency, DevErpError>;
+            async fn remove(&self, resource_id: i64, depends_on_resource_id: i64) -> Result<bool, DevErpError>;
+            async fn get_dependencies(&self, resource_id: i64) -> Result<Vec<ResourceDependency>, DevErpError>;
         }
     }
 
@@ -305,6 +623,48 @@ mod tests {
         }
     }
 
+    /// Service over `repo`, with a dependency mock that expects no calls
+    fn service_with(repo: MockResourceRepo) -> ResourceService {
+        ResourceService::new(Arc::new(repo), Arc::new(MockDependencyRepo::new()))
+    }
+
+    /// Service over resources 1-4 whose dependency repository expects `adds` inserts
+    fn service_expecting_adds(adds: usize) -> ResourceService {
+        let mut repo = MockResourceRepo::new();
+        repo.expect_find_by_id()
+            .returning(|id| Ok(Some(create_test_resource(id, &format!("resource-{}", id)))));
+
+        let mut dependency_repo = MockDependencyRepo::new();
+        dependency_repo.expect_add().times(adds).returning(|d| {
+            Ok(ResourceDependency {
+                resource_id: d.resource_id,
+                depends_on_resource_id: d.depends_on_resource_id,
+                created_at: Utc::now(),
+            })
+        });
+
+        ResourceService::new(Arc::new(repo), Arc::new(dependency_repo))
+    }
+
+    #[tokio::test]
+    async fn test_add_resource_dependency_validates_before_inserting() {
+        let dependency = |resource_id, depends_on_resource_id| CreateResourceDependency {
+            resource_id,
+            depends_on_resource_id,
+        };
+
+        let result = service_expecting_adds(0)
+            .add_resource_dependency(dependency(4, 4))
+            .await;
+        assert!(matches!(result, Err(DevErpError::Validation(msg)) if msg.contains("itself")));
+
+        let added = service_expecting_adds(1)
+            .add_resource_dependency(dependency(1, 3))
+            .await
+            .unwrap();
+        assert_eq!(added.depends_on_resource_id, 3);
+    }
+
     #[test]
     fn test_is_valid_url() {
         assert!(ResourceService::is_valid_url("http://example.com"));
@@ -323,7 +683,7 @@ mod tests {
             .times(1)
             .returning(move |_| Ok(create_test_resource(1, "Test Library")));
 
-        let service = ResourceService::new(Arc::new(mock_repo));
+        let service = service_with(mock_repo);
 
         let input = CreateResource {
             name: "Test Library".to_string(),
@@ -347,7 +707,7 @@ mod tests {
     #[tokio::test]
     async fn test_create_resource_validation_empty_name() {
         let mock_repo = MockResourceRepo::new();
-        let service = ResourceService::new(Arc::new(mock_repo));
+        let service = service_with(mock_repo);
 
         let input = CreateResource {
             name: "   ".to_string(), // Empty name after trim
@@ -375,7 +735,7 @@ mod tests {
     #[tokio::test]
     async fn test_create_resource_validation_invalid_url() {
         let mock_repo = MockResourceRepo::new();
-        let service = ResourceService::new(Arc::new(mock_repo));
+        let service = service_with(mock_repo);
 
         let input = CreateResource {
             name: "Test Resource".to_string(),
@@ -400,6 +760,45 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_create_resource_duplicate_name_version() {
+        let input = CreateResource {
+            name: "Test Library".to_string(),
+            description: None,
+            resource_type: ResourceType::Library,
+            version: Some("1.0.0".to_string()),
+            url: None,
+            documentation_url: None,
+            license: None,
+            status: None,
+            metadata: None,
+            tags: None,
+        };
+
+        // Guard on: the existing pair is reported as a conflict
+        let mut guarded = MockResourceRepo::new();
+        guarded
+            .expect_find_by_name_version()
+            .withf(|name, version| name == "Test Library" && version.as_deref() == Some("1.0.0"))
+            .returning(|name, _| Ok(Some(create_test_resource(7, name))));
+        guarded.expect_create().never();
+
+        let service = service_with(guarded).with_unique_name_version(true);
+        let result = service.create_resource(input.clone()).await;
+        assert!(matches!(result, Err(DevErpError::Conflict(msg)) if msg.contains("id 7")));
+
+        // Guard off: the duplicate is created without looking it up
+        let mut unguarded = MockResourceRepo::new();
+        unguarded.expect_find_by_name_version().never();
+        unguarded
+            .expect_create()
+            .times(1)
+            .returning(|input| Ok(create_test_resource(8, &input.name)));
+
+        let service = service_with(unguarded).with_unique_name_version(false);
+        assert_eq!(service.create_resource(input).await.unwrap().id, 8);
+    }
+
     #[tokio::test]
     async fn test_get_resource_success() {
         let mut mock_repo = MockResourceRepo::new();
@@ -410,7 +809,7 @@ mod tests {
             .times(1)
             .returning(move |_| Ok(Some(create_test_resource(1, "Test Library"))));
 
-        let service = ResourceService::new(Arc::new(mock_repo));
+        let service = service_with(mock_repo);
 
         let result = service.get_resource(1).await;
         assert!(result.is_ok());
@@ -428,7 +827,7 @@ mod tests {
             .times(1)
             .returning(|_| Ok(None));
 
-        let service = ResourceService::new(Arc::new(mock_repo));
+        let service = service_with(mock_repo);
 
         let result = service.get_resource(999).await;
         assert!(result.is_err());
@@ -462,7 +861,7 @@ mod tests {
             .times(1)
             .returning(|_| Ok(true));
 
-        let service = ResourceService::new(Arc::new(mock_repo));
+        let service = service_with(mock_repo);
 
         let result = service.delete_resource(1).await;
         assert!(result.is_ok());
@@ -487,7 +886,7 @@ mod tests {
                 })
             });
 
-        let service = ResourceService::new(Arc::new(mock_repo));
+        let service = service_with(mock_repo);
 
         let input = LinkResourceToProject {
             project_id: 1,
@@ -504,4 +903,71 @@ mod tests {
         assert_eq!(link.resource_id, 1);
         assert_eq!(link.is_critical, Some(true));
     }
+
+    #[tokio::test]
+    async fn test_deprecate_by_tag_skips_already_deprecated() {
+        let mut mock_repo = MockResourceRepo::new();
+
+        mock_repo
+            .expect_find_all()
+            .withf(|filter| filter.tags == Some(vec!["legacy".to_string()]))
+            .times(1)
+            .returning(|_| {
+                let mut old = create_test_resource(2, "old-lib");
+                old.status = Some(ResourceStatus::Deprecated);
+                Ok(vec![create_test_resource(1, "legacy-lib"), old])
+            });
+
+        mock_repo
+            .expect_update_many()
+            .withf(|updates| {
+                updates.len() == 1
+                    && updates[0].id == 1
+                    && updates[0].status == Some(ResourceStatus::Deprecated)
+                    && updates[0].metadata.as_ref().unwrap()["deprecation_reason"] == "EOL"
+            })
+            .times(1)
+            .returning(|updates| {
+                Ok(updates
+                    .into_iter()
+                    .map(|u| {
+                        let mut r = create_test_resource(u.id, "legacy-lib");
+                        r.status = u.status;
+                        r
+                    })
+                    .collect())
+            });
+
+        mock_repo.expect_get_usage_matrix().times(1).returning(|| {
+            Ok(vec![
+                ResourceUsageLink {
+                    resource_id: 1,
+                    resource_name: "legacy-lib".to_string(),
+                    project_id: 10,
+                    project_name: "Portal".to_string(),
+                    version_used: None,
+                    is_critical: false,
+                },
+                ResourceUsageLink {
+                    resource_id: 3,
+                    resource_name: "other".to_string(),
+                    project_id: 11,
+                    project_name: "Billing".to_string(),
+                    version_used: None,
+                    is_critical: true,
+                },
+            ])
+        });
+
+        let service = service_with(mock_repo);
+        let result = service
+            .deprecate_resources_by_tag("legacy", Some("EOL".to_string()))
+            .await
+            .unwrap();
+
+        assert_eq!(result.deprecated.len(), 1);
+        assert_eq!(result.already_deprecated, 1);
+        assert_eq!(result.affected_links.len(), 1);
+        assert_eq!(result.affected_links[0].project_id, 10);
+    }
 }
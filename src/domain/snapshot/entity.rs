@@ -0,0 +1,49 @@
+// Snapshot entities
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+use crate::domain::report::service::ProjectStatusReport;
+
+/// Key status metrics captured at a point in time
+#[derive(Debug, Clone, Default, Serialize, Deserialize, FromRow)]
+pub struct StatusSnapshot {
+    pub id: i64,
+    pub total_projects: i64,
+    pub active_projects: i64,
+    pub completed_projects: i64,
+    pub delayed_projects: i64,
+    pub average_progress: f64,
+    pub captured_at: DateTime<Utc>,
+}
+
+/// Input for recording a status snapshot
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateStatusSnapshot {
+    pub total_projects: i64,
+    pub active_projects: i64,
+    pub completed_projects: i64,
+    pub delayed_projects: i64,
+    pub average_progress: f64,
+}
+
+impl From<&ProjectStatusReport> for CreateStatusSnapshot {
+    fn from(report: &ProjectStatusReport) -> Self {
+        Self {
+            total_projects: report.total_projects,
+            active_projects: report.active_projects,
+            completed_projects: report.completed_projects,
+            delayed_projects: report.delayed_projects,
+            average_progress: report.average_progress,
+        }
+    }
+}
+
+/// Status report together with its recent history, oldest snapshot first
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct StatusTrendReport {
+    #[serde(flatten)]
+    pub current: ProjectStatusReport,
+    pub trend: Vec<StatusSnapshot>,
+}
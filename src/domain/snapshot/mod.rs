@@ -0,0 +1,9 @@
+// Snapshot domain module - historical copies of report metrics
+
+pub mod entity;
+pub mod repository;
+pub mod service;
+
+pub use entity::{StatusSnapshot, StatusTrendReport};
+pub use repository::SnapshotRepository;
+pub use service::SnapshotService;
@@ -0,0 +1,16 @@
+// Snapshot repository trait
+
+use async_trait::async_trait;
+
+use super::entity::{CreateStatusSnapshot, StatusSnapshot};
+use crate::utils::error::DevErpError;
+
+/// Persistence for status snapshots
+#[async_trait]
+pub trait SnapshotRepository: Send + Sync {
+    /// Record a new snapshot
+    async fn create(&self, snapshot: CreateStatusSnapshot) -> Result<StatusSnapshot, DevErpError>;
+
+    /// Fetch the most recent `limit` snapshots, newest first
+    async fn find_recent(&self, limit: i64) -> Result<Vec<StatusSnapshot>, DevErpError>;
+}
@@ -0,0 +1,53 @@
+// Snapshot service
+
+use std::sync::Arc;
+use tracing::{debug, info};
+
+use super::entity::{StatusSnapshot, StatusTrendReport};
+use super::repository::SnapshotRepository;
+use crate::domain::report::service::ProjectStatusReport;
+use crate::utils::error::DevErpError;
+
+/// Service for recording and reading status report history
+pub struct SnapshotService {
+    repository: Arc<dyn SnapshotRepository>,
+}
+
+impl SnapshotService {
+    /// Create a new snapshot service
+    ///
+    /// # Arguments
+    /// * `repository` - The snapshot repository implementation
+    pub fn new(repository: Arc<dyn SnapshotRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// Record the key metrics of a status report
+    pub async fn capture(
+        &self,
+        report: &ProjectStatusReport,
+    ) -> Result<StatusSnapshot, DevErpError> {
+        let snapshot = self.repository.create(report.into()).await?;
+        info!(snapshot_id = snapshot.id, "Status snapshot captured");
+        Ok(snapshot)
+    }
+
+    /// The last `points` snapshots in chronological order
+    pub async fn recent(&self, points: usize) -> Result<Vec<StatusSnapshot>, DevErpError> {
+        debug!("Service: Loading last {} status snapshots", points);
+
+        let mut snapshots = self.repository.find_recent(points as i64).await?;
+        snapshots.reverse();
+        Ok(snapshots)
+    }
+
+    /// Attach the last `points` snapshots to a freshly generated report
+    pub async fn with_trend(
+        &self,
+        current: ProjectStatusReport,
+        points: usize,
+    ) -> Result<StatusTrendReport, DevErpError> {
+        let trend = self.recent(points).await?;
+        Ok(StatusTrendReport { current, trend })
+    }
+}
@@ -3,6 +3,8 @@ use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 use uuid::Uuid;
 
+use crate::domain::project::entity::PRIORITY_RANK_SQL;
+
 /// Main Task entity representing a task in the system
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
 pub struct Task {
@@ -10,10 +12,13 @@ pub struct Task {
     pub uuid: Uuid,
     pub project_id: i64,
     pub parent_task_id: Option<i64>,
+    pub milestone_id: Option<i64>,
     pub title: String,
     pub description: Option<String>,
     pub task_number: Option<String>,
     pub status: TaskStatus,
+    /// Why the task is blocked; only set while the status is `Blocked`
+    pub blocked_reason: Option<String>,
     pub priority: TaskPriority,
     pub assigned_to: Option<String>,
     pub estimated_hours: Option<f64>,
@@ -23,13 +28,49 @@ pub struct Task {
     pub completed_at: Option<DateTime<Utc>>,
     pub task_type: Option<TaskType>,
     pub tags: Option<Vec<String>>,
+    /// Incremented on every update, for optimistic concurrency
+    pub version: i32,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub deleted_at: Option<DateTime<Utc>>,
 }
 
+#[cfg(test)]
+impl Task {
+    /// A live `Todo` task in project 1, to fill in with struct-update syntax
+    pub fn test_default() -> Self {
+        let now = Utc::now();
+        Self {
+            id: 1,
+            uuid: Uuid::nil(),
+            project_id: 1,
+            parent_task_id: None,
+            milestone_id: None,
+            title: "Task".to_string(),
+            description: None,
+            task_number: None,
+            status: TaskStatus::Todo,
+            blocked_reason: None,
+            priority: TaskPriority::Medium,
+            assigned_to: None,
+            estimated_hours: None,
+            actual_hours: None,
+            due_date: None,
+            started_at: None,
+            completed_at: None,
+            task_type: None,
+            tags: None,
+            version: 1,
+            created_at: now,
+            updated_at: now,
+            deleted_at: None,
+        }
+    }
+}
+
 /// Task status enum
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::Type, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
 #[sqlx(type_name = "VARCHAR", rename_all = "snake_case")]
 #[derive(Default)]
 pub enum TaskStatus {
@@ -74,8 +115,26 @@ impl std::str::FromStr for TaskStatus {
     }
 }
 
+impl TaskStatus {
+    /// How far along a task in this status counts as, in percent
+    ///
+    /// `None` for cancelled tasks, which drop out of progress rollups.
+    pub fn completion_pct(&self) -> Option<f64> {
+        match self {
+            TaskStatus::Todo => Some(0.0),
+            TaskStatus::Blocked => Some(25.0),
+            TaskStatus::InProgress => Some(50.0),
+            TaskStatus::Review => Some(75.0),
+            TaskStatus::Testing => Some(90.0),
+            TaskStatus::Done => Some(100.0),
+            TaskStatus::Cancelled => None,
+        }
+    }
+}
+
 /// Task priority enum
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::Type, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
 #[sqlx(type_name = "VARCHAR", rename_all = "lowercase")]
 #[derive(Default)]
 pub enum TaskPriority {
@@ -113,6 +172,7 @@ impl std::str::FromStr for TaskPriority {
 
 /// Task type enum
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::Type, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
 #[sqlx(type_name = "VARCHAR", rename_all = "lowercase")]
 #[derive(Default)]
 pub enum TaskType {
@@ -157,6 +217,23 @@ impl std::str::FromStr for TaskType {
     }
 }
 
+/// Number of tasks per assignee and status
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct AssigneeStatusCount {
+    /// Assignee, `None` for unassigned tasks
+    pub assigned_to: Option<String>,
+    pub status: TaskStatus,
+    pub count: i64,
+}
+
+/// Number of tasks in a project, and how many of them are done
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, PartialEq, Eq)]
+pub struct ProjectTaskCount {
+    pub project_id: i64,
+    pub total_tasks: i64,
+    pub completed_tasks: i64,
+}
+
 /// Task dependency entity
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
 pub struct TaskDependency {
@@ -166,8 +243,52 @@ pub struct TaskDependency {
     pub created_at: DateTime<Utc>,
 }
 
+/// A task after an update, with anything worth flagging about the new values
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateTaskResult {
+    pub task: Task,
+    /// Human-readable notes, e.g. actual hours far above the estimate
+    pub warnings: Vec<String>,
+}
+
+/// Whether completing a task or milestone first checks the tasks it waits on
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CompletionCheck {
+    /// Reject completion while any of those tasks is unfinished
+    #[default]
+    Enforce,
+    /// Complete regardless, logging a warning when the check is skipped
+    Skip,
+}
+
+/// Longest chain of dependent tasks in a project, weighted by estimated hours
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CriticalPath {
+    /// Task IDs in the order they have to be worked on
+    pub task_ids: Vec<i64>,
+    pub total_hours: f64,
+}
+
+/// Metrics for a single task
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TaskStats {
+    pub task_id: i64,
+    pub status: TaskStatus,
+    /// Whole days since the task was created
+    pub age_days: i64,
+    /// When the task entered its current status, if known
+    pub status_since: Option<DateTime<Utc>>,
+    pub days_in_status: Option<i64>,
+    pub estimated_hours: Option<f64>,
+    pub actual_hours: Option<f64>,
+    /// Actual divided by estimated hours; above 1.0 means over estimate
+    pub estimate_accuracy: Option<f64>,
+    pub overdue: bool,
+}
+
 /// Dependency type enum
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::Type, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
 #[sqlx(type_name = "VARCHAR", rename_all = "snake_case")]
 #[derive(Default)]
 pub enum DependencyType {
@@ -215,15 +336,65 @@ pub struct TaskComment {
     pub deleted_at: Option<DateTime<Utc>>,
 }
 
+/// Hours logged against a task by one person
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct TaskWorkLog {
+    pub id: i64,
+    pub task_id: i64,
+    pub hours: f64,
+    pub logged_by: String,
+    pub note: Option<String>,
+    pub logged_at: DateTime<Utc>,
+}
+
+/// One of the people assigned to a task
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct TaskAssignee {
+    pub task_id: i64,
+    pub assignee: String,
+    pub assigned_at: DateTime<Utc>,
+}
+
+/// A parent task's completion rolled up from its subtasks
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskRollup {
+    pub task_id: i64,
+    /// Direct subtasks, not counting cancelled ones
+    pub total_subtasks: usize,
+    pub done_subtasks: usize,
+    /// Average completion of the direct subtasks, each of which counts its
+    /// own subtasks the same way
+    pub completion_pct: f64,
+    /// Whether the rollup moved the parent to done
+    pub marked_done: bool,
+}
+
+/// A task with the records `task show` lists under it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskDetail {
+    pub task: Task,
+    /// Everyone on the task, primary assignee first
+    pub assignees: Vec<String>,
+    pub dependencies: Vec<TaskDependency>,
+    pub comments: Vec<TaskComment>,
+    /// The latest entries only, not the full work history
+    pub recent_work_logs: Vec<TaskWorkLog>,
+}
+
 /// Input structure for creating a new task
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateTask {
+    /// Left out of `task import` files, which take it from `--project-id`
+    #[serde(default)]
     pub project_id: i64,
     pub parent_task_id: Option<i64>,
     pub title: String,
     pub description: Option<String>,
     pub task_number: Option<String>,
     pub status: Option<TaskStatus>,
+    /// Required when the task starts out `Blocked`
+    #[serde(default)]
+    pub blocked_reason: Option<String>,
     pub priority: Option<TaskPriority>,
     pub assigned_to: Option<String>,
     pub estimated_hours: Option<f64>,
@@ -253,20 +424,42 @@ impl CreateTask {
     }
 }
 
+/// A task from an import file that could not be created
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskImportFailure {
+    /// Position of the task in the file, starting at 0
+    pub index: usize,
+    pub title: String,
+    pub error: String,
+}
+
+/// Outcome of a bulk task import
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskImportSummary {
+    pub created: Vec<Task>,
+    pub failed: Vec<TaskImportFailure>,
+}
+
 /// Input structure for updating a task
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UpdateTask {
     pub id: i64,
     pub title: Option<String>,
-    pub description: Option<String>,
+    /// `Some(None)` clears the description; `None` leaves it unchanged
+    pub description: Option<Option<String>>,
     pub status: Option<TaskStatus>,
+    /// Required when the update moves the task into `Blocked`
+    pub blocked_reason: Option<String>,
     pub priority: Option<TaskPriority>,
     pub assigned_to: Option<String>,
     pub estimated_hours: Option<f64>,
     pub actual_hours: Option<f64>,
-    pub due_date: Option<DateTime<Utc>>,
+    /// `Some(None)` clears the due date; `None` leaves it unchanged
+    pub due_date: Option<Option<DateTime<Utc>>>,
     pub task_type: Option<TaskType>,
     pub tags: Option<Vec<String>>,
+    /// Reject the update with a conflict unless the row is still at this version
+    pub expected_version: Option<i32>,
 }
 
 impl UpdateTask {
@@ -293,6 +486,12 @@ impl UpdateTask {
             }
         }
 
+        if let Some(ref reason) = self.blocked_reason {
+            if reason.trim().is_empty() {
+                return Err("Blocked reason cannot be empty".to_string());
+            }
+        }
+
         Ok(())
     }
 }
@@ -301,16 +500,111 @@ impl UpdateTask {
 #[derive(Debug, Clone, Default)]
 pub struct TaskFilter {
     pub project_id: Option<i64>,
+    /// Only tasks in any of these projects (combined with `project_id` if both are set)
+    pub project_ids: Option<Vec<i64>>,
     pub status: Option<TaskStatus>,
     pub priority: Option<TaskPriority>,
     pub task_type: Option<TaskType>,
     pub assigned_to: Option<String>,
     pub parent_task_id: Option<i64>,
+    /// Only tasks linked to this milestone
+    pub milestone_id: Option<i64>,
+    /// Only this task and everything below it in the parent/child hierarchy
+    pub subtree_of: Option<i64>,
+    /// Case-insensitive match on title or description; blank means no filter
+    pub search: Option<String>,
+    /// Let `search` also match the task's comments
+    pub search_comments: bool,
+    /// Include soft-deleted tasks; they are excluded by default
     pub include_deleted: bool,
+    /// Only tasks without an estimate (`estimated_hours IS NULL`)
+    pub estimate_missing: bool,
+    /// Only tasks without logged actuals (`actual_hours IS NULL`)
+    pub actual_missing: bool,
+    /// Only tasks with `updated_at >= updated_after`
+    pub updated_after: Option<DateTime<Utc>>,
+    /// Only tasks with `updated_at < updated_before`
+    pub updated_before: Option<DateTime<Utc>>,
+    /// Field to order by (defaults to newest first)
+    pub sort_by: Option<TaskSortField>,
+    /// Sort in descending order
+    pub sort_desc: bool,
     pub offset: Option<i64>,
     pub limit: Option<i64>,
 }
 
+impl TaskFilter {
+    /// Build the ORDER BY clause for this filter
+    ///
+    /// Empty due dates sort last in either direction, and creation time
+    /// breaks ties so paging stays stable.
+    pub fn order_by_clause(&self) -> String {
+        let Some(field) = self.sort_by else {
+            return "ORDER BY created_at DESC".to_string();
+        };
+
+        let direction = if self.sort_desc { "DESC" } else { "ASC" };
+        if field == TaskSortField::CreatedAt {
+            format!("ORDER BY created_at {}", direction)
+        } else {
+            format!(
+                "ORDER BY {} {} NULLS LAST, created_at DESC",
+                field.column(),
+                direction
+            )
+        }
+    }
+}
+
+/// Sortable task fields
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskSortField {
+    /// By weight, Low=1 up to Critical=4
+    Priority,
+    /// By workflow stage, Todo first and Cancelled last
+    Status,
+    DueDate,
+    CreatedAt,
+    Title,
+}
+
+impl TaskSortField {
+    /// Column or rank expression used in ORDER BY
+    ///
+    /// Only these fixed strings ever reach the query, never user input.
+    pub fn column(&self) -> &'static str {
+        match self {
+            TaskSortField::Priority => PRIORITY_RANK_SQL,
+            TaskSortField::Status => {
+                "CASE status WHEN 'todo' THEN 1 WHEN 'in_progress' THEN 2 WHEN 'blocked' THEN 3 \
+                 WHEN 'review' THEN 4 WHEN 'testing' THEN 5 WHEN 'done' THEN 6 \
+                 WHEN 'cancelled' THEN 7 END"
+            }
+            TaskSortField::DueDate => "due_date",
+            TaskSortField::CreatedAt => "created_at",
+            TaskSortField::Title => "title",
+        }
+    }
+}
+
+impl std::str::FromStr for TaskSortField {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().replace('-', "_").as_str() {
+            "priority" => Ok(TaskSortField::Priority),
+            "status" => Ok(TaskSortField::Status),
+            "due_date" | "due" => Ok(TaskSortField::DueDate),
+            "created_at" | "created" => Ok(TaskSortField::CreatedAt),
+            "title" => Ok(TaskSortField::Title),
+            _ => Err(format!(
+                "Invalid sort field: {} (expected priority, status, due-date, created-at or title)",
+                s
+            )),
+        }
+    }
+}
+
 /// Input for creating a task comment
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateTaskComment {
@@ -319,6 +613,47 @@ pub struct CreateTaskComment {
     pub author: Option<String>,
 }
 
+/// Input structure for logging work on a task
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateTaskWorkLog {
+    pub task_id: i64,
+    pub hours: f64,
+    pub logged_by: String,
+    pub note: Option<String>,
+}
+
+impl CreateTaskWorkLog {
+    /// Validate the work log input
+    pub fn validate(&self) -> Result<(), String> {
+        if !self.hours.is_finite() || self.hours <= 0.0 {
+            return Err("Logged hours must be a positive number".to_string());
+        }
+
+        if self.logged_by.trim().is_empty() {
+            return Err("Work log author cannot be empty".to_string());
+        }
+
+        if self.logged_by.len() > 100 {
+            return Err("Work log author cannot exceed 100 characters".to_string());
+        }
+
+        Ok(())
+    }
+}
+
+/// Validate an assignee name for `task assign`
+pub fn validate_assignee(assignee: &str) -> Result<(), String> {
+    if assignee.trim().is_empty() {
+        return Err("Assignee cannot be empty".to_string());
+    }
+
+    if assignee.len() > 100 {
+        return Err("Assignee cannot exceed 100 characters".to_string());
+    }
+
+    Ok(())
+}
+
 impl CreateTaskComment {
     /// Validate the create comment input
     pub fn validate(&self) -> Result<(), String> {
@@ -353,6 +688,35 @@ impl CreateTaskDependency {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_task_order_by_clause() {
+        assert_eq!(
+            TaskFilter::default().order_by_clause(),
+            "ORDER BY created_at DESC"
+        );
+
+        let filter = TaskFilter {
+            sort_by: Some("due-date".parse().unwrap()),
+            sort_desc: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            filter.order_by_clause(),
+            "ORDER BY due_date DESC NULLS LAST, created_at DESC"
+        );
+
+        let filter = TaskFilter {
+            sort_by: Some(TaskSortField::Priority),
+            ..Default::default()
+        };
+        assert!(filter
+            .order_by_clause()
+            .starts_with("ORDER BY CASE priority WHEN 'critical' THEN 4"));
+        assert!("estimate; DROP TABLE tasks"
+            .parse::<TaskSortField>()
+            .is_err());
+    }
+
     #[test]
     fn test_task_status_parsing() {
         assert_eq!("todo".parse::<TaskStatus>().unwrap(), TaskStatus::Todo);
@@ -411,6 +775,7 @@ mod tests {
             description: None,
             task_number: None,
             status: None,
+            blocked_reason: None,
             priority: None,
             assigned_to: None,
             estimated_hours: Some(5.0),
@@ -466,4 +831,30 @@ mod tests {
         };
         assert!(empty_comment.validate().is_err());
     }
+
+    #[test]
+    fn test_work_log_hours_must_be_positive() {
+        let log = |hours| CreateTaskWorkLog {
+            task_id: 1,
+            hours,
+            logged_by: "alice".to_string(),
+            note: None,
+        };
+
+        assert!(log(1.5).validate().is_ok());
+        assert!(log(0.0).validate().is_err());
+        assert!(log(-2.0).validate().is_err());
+        assert!(log(f64::NAN).validate().is_err());
+
+        let mut anonymous = log(1.0);
+        anonymous.logged_by = "  ".to_string();
+        assert!(anonymous.validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_assignee() {
+        assert!(validate_assignee("alice").is_ok());
+        assert!(validate_assignee(" ").is_err());
+        assert!(validate_assignee(&"a".repeat(101)).is_err());
+    }
 }
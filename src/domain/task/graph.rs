@@ -0,0 +1,99 @@
+// Task dependency graph algorithms
+
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+use super::entity::TaskDependency;
+
+/// Dependency cycles among `dependencies`, one per back edge found
+///
+/// Each cycle lists task IDs in "depends on" order (each task depends on the
+/// next, and the last on the first), rotated to start at its lowest ID.
+/// Every task that sits on a cycle is part of at least one reported cycle.
+pub fn find_cycles(dependencies: &[TaskDependency]) -> Vec<Vec<i64>> {
+    let mut edges: BTreeMap<i64, BTreeSet<i64>> = BTreeMap::new();
+    for dep in dependencies {
+        edges
+            .entry(dep.task_id)
+            .or_default()
+            .insert(dep.depends_on_task_id);
+        edges.entry(dep.depends_on_task_id).or_default();
+    }
+
+    let mut finished: HashSet<i64> = HashSet::new();
+    let mut cycles: BTreeSet<Vec<i64>> = BTreeSet::new();
+
+    for &root in edges.keys() {
+        if finished.contains(&root) {
+            continue;
+        }
+        // Iterative DFS; `path` mirrors the stack so back edges can be unwound
+        let mut path: Vec<i64> = vec![root];
+        let mut stack = vec![edges[&root].iter()];
+        while let Some(next) = stack.last_mut().map(|children| children.next()) {
+            match next {
+                Some(&child) if finished.contains(&child) => {}
+                Some(&child) => {
+                    if let Some(start) = path.iter().position(|&id| id == child) {
+                        cycles.insert(rotate_to_min(&path[start..]));
+                    } else {
+                        path.push(child);
+                        stack.push(edges[&child].iter());
+                    }
+                }
+                None => {
+                    stack.pop();
+                    if let Some(done) = path.pop() {
+                        finished.insert(done);
+                    }
+                }
+            }
+        }
+    }
+
+    cycles.into_iter().collect()
+}
+
+/// Rotate a cycle so it starts at its lowest task ID
+fn rotate_to_min(cycle: &[i64]) -> Vec<i64> {
+    let start = cycle
+        .iter()
+        .enumerate()
+        .min_by_key(|(_, id)| **id)
+        .map_or(0, |(i, _)| i);
+    cycle[start..]
+        .iter()
+        .chain(&cycle[..start])
+        .copied()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dependency(task_id: i64, depends_on_task_id: i64) -> TaskDependency {
+        TaskDependency {
+            task_id,
+            depends_on_task_id,
+            dependency_type: Default::default(),
+            created_at: chrono::Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_find_cycles_reports_each_cycle() {
+        // 1 -> 2 -> 3 -> 1, a separate 4 <-> 5 loop, and an acyclic 6 -> 1
+        let deps = [
+            dependency(1, 2),
+            dependency(2, 3),
+            dependency(3, 1),
+            dependency(5, 4),
+            dependency(4, 5),
+            dependency(6, 1),
+        ];
+        assert_eq!(find_cycles(&deps), vec![vec![1, 2, 3], vec![4, 5]]);
+
+        let acyclic = [dependency(2, 1), dependency(3, 1), dependency(3, 2)];
+        assert!(find_cycles(&acyclic).is_empty());
+    }
+}
@@ -1,13 +1,19 @@
 // Task domain module
 
 pub mod entity;
+pub mod graph;
 pub mod repository;
 pub mod service;
 
 // Re-export commonly used types
 pub use entity::{
-    CreateTask, CreateTaskComment, CreateTaskDependency, DependencyType, Task, TaskComment,
-    TaskDependency, TaskFilter, TaskPriority, TaskStatus, TaskType, UpdateTask,
+    AssigneeStatusCount, CompletionCheck, CreateTask, CreateTaskComment, CreateTaskDependency,
+    CreateTaskWorkLog, CriticalPath, DependencyType, ProjectTaskCount, Task, TaskAssignee,
+    TaskComment, TaskDependency, TaskDetail, TaskFilter, TaskImportFailure, TaskImportSummary,
+    TaskPriority, TaskStats, TaskStatus, TaskType, TaskWorkLog, UpdateTask, UpdateTaskResult,
+};
+pub use repository::{
+    TaskAssigneeRepository, TaskCommentRepository, TaskDependencyRepository, TaskRepository,
+    TaskWorkLogRepository,
 };
-pub use repository::{TaskCommentRepository, TaskDependencyRepository, TaskRepository};
 pub use service::TaskService;
@@ -1,11 +1,13 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 use crate::utils::error::DevErpError;
 
 use super::entity::{
-    CreateTask, CreateTaskComment, CreateTaskDependency, Task, TaskComment, TaskDependency,
-    TaskFilter, UpdateTask,
+    AssigneeStatusCount, CreateTask, CreateTaskComment, CreateTaskDependency, CreateTaskWorkLog,
+    ProjectTaskCount, Task, TaskAssignee, TaskComment, TaskDependency, TaskFilter, TaskWorkLog,
+    UpdateTask,
 };
 
 /// Repository trait for Task operations
@@ -14,6 +16,17 @@ pub trait TaskRepository: Send + Sync {
     /// Create a new task
     async fn create(&self, task: CreateTask) -> Result<Task, DevErpError>;
 
+    /// Create a task and its dependencies on `depends_on` in one transaction
+    ///
+    /// Each dependency is checked for existence and cycles inside the
+    /// transaction; if any check or insert fails, neither the task nor any
+    /// dependency is kept.
+    async fn create_with_dependencies(
+        &self,
+        task: CreateTask,
+        depends_on: &[i64],
+    ) -> Result<(Task, Vec<TaskDependency>), DevErpError>;
+
     /// Find a task by its internal ID
     async fn find_by_id(&self, id: i64) -> Result<Option<Task>, DevErpError>;
 
@@ -32,14 +45,46 @@ pub trait TaskRepository: Send + Sync {
     /// Soft delete a task (sets deleted_at timestamp)
     async fn soft_delete(&self, id: i64) -> Result<bool, DevErpError>;
 
+    /// Restore a soft-deleted task; false if not found or not deleted
+    async fn restore(&self, id: i64) -> Result<bool, DevErpError>;
+
     /// Count tasks matching the filter
+    ///
+    /// Soft-deleted tasks are excluded unless `filter.include_deleted` is set.
     async fn count(&self, filter: TaskFilter) -> Result<i64, DevErpError>;
+
+    /// Count non-deleted tasks grouped by assignee and status
+    async fn count_by_assignee(&self) -> Result<Vec<AssigneeStatusCount>, DevErpError>;
+
+    /// Count non-deleted tasks for each of the given projects in one query
+    ///
+    /// Projects without tasks are omitted from the result.
+    async fn count_by_project(
+        &self,
+        project_ids: &[i64],
+    ) -> Result<Vec<ProjectTaskCount>, DevErpError>;
+
+    /// When the task's status last changed, if a change was recorded
+    async fn last_status_change(&self, task_id: i64) -> Result<Option<DateTime<Utc>>, DevErpError>;
+
+    /// Move a task to `project_id`, dropping its milestone link and, when
+    /// `clear_parent` is set, its parent task
+    async fn move_to_project(
+        &self,
+        task_id: i64,
+        project_id: i64,
+        clear_parent: bool,
+    ) -> Result<Task, DevErpError>;
 }
 
 /// Repository trait for Task Dependency operations
 #[async_trait]
 pub trait TaskDependencyRepository: Send + Sync {
     /// Add a dependency between two tasks
+    ///
+    /// Implementations must make the cycle check and the insert atomic with
+    /// respect to other `add_dependency` calls, so concurrent additions can
+    /// never close a cycle between them.
     async fn add_dependency(
         &self,
         dependency: CreateTaskDependency,
@@ -65,8 +110,41 @@ pub trait TaskDependencyRepository: Send + Sync {
         depends_on_task_id: i64,
     ) -> Result<bool, DevErpError>;
 
-    /// Get all task IDs in the dependency path from start_task_id
-    async fn get_dependency_chain(&self, start_task_id: i64) -> Result<Vec<i64>, DevErpError>;
+    /// Get all task IDs in the dependency path from start_task_id, breadth first
+    ///
+    /// Issues one query per task visited. With `max_depth`, tasks more than
+    /// that many dependency steps away are left out of the chain.
+    async fn get_dependency_chain(
+        &self,
+        start_task_id: i64,
+        max_depth: Option<u32>,
+    ) -> Result<Vec<i64>, DevErpError>;
+
+    /// Same chain as `get_dependency_chain`, fetched in a single query
+    ///
+    /// Ordered by distance from `start_task_id`, then by task ID.
+    async fn get_dependency_chain_batched(
+        &self,
+        start_task_id: i64,
+        max_depth: Option<u32>,
+    ) -> Result<Vec<i64>, DevErpError>;
+
+    /// Get every dependency between two live tasks of the given project
+    async fn get_project_dependencies(
+        &self,
+        project_id: i64,
+    ) -> Result<Vec<TaskDependency>, DevErpError>;
+
+    /// Projects with a live task depending on a live task of any given project
+    ///
+    /// The given projects themselves are excluded. Sorted by project ID.
+    async fn find_dependent_projects(&self, project_ids: &[i64]) -> Result<Vec<i64>, DevErpError>;
+
+    /// Find every cycle in a project's existing dependencies
+    ///
+    /// Each cycle is a list of task IDs, each depending on the next and the
+    /// last on the first. Empty when the dependency graph is acyclic.
+    async fn find_all_cycles(&self, project_id: i64) -> Result<Vec<Vec<i64>>, DevErpError>;
 }
 
 /// Repository trait for Task Comment operations
@@ -86,4 +164,38 @@ pub trait TaskCommentRepository: Send + Sync {
 
     /// Soft delete a comment
     async fn soft_delete(&self, id: i64) -> Result<bool, DevErpError>;
+
+    /// Restore a soft-deleted comment, returning false if it was not deleted
+    async fn restore(&self, id: i64) -> Result<bool, DevErpError>;
+}
+
+/// Repository trait for task work log operations
+#[async_trait]
+pub trait TaskWorkLogRepository: Send + Sync {
+    /// Record a work log entry and add its hours to the task's `actual_hours`
+    async fn create(&self, log: CreateTaskWorkLog) -> Result<TaskWorkLog, DevErpError>;
+
+    /// The latest `limit` entries for a task, newest first
+    async fn find_recent_by_task(
+        &self,
+        task_id: i64,
+        limit: i64,
+    ) -> Result<Vec<TaskWorkLog>, DevErpError>;
+}
+
+/// Repository trait for the people assigned to a task
+///
+/// `tasks.assigned_to` is kept as the primary assignee: the first person
+/// added to an unassigned task becomes primary, and removing the primary
+/// hands the role to the longest-standing remaining assignee.
+#[async_trait]
+pub trait TaskAssigneeRepository: Send + Sync {
+    /// Add an assignee, returning false if they were already assigned
+    async fn add_assignee(&self, task_id: i64, assignee: &str) -> Result<bool, DevErpError>;
+
+    /// Remove an assignee, returning false if they were not assigned
+    async fn remove_assignee(&self, task_id: i64, assignee: &str) -> Result<bool, DevErpError>;
+
+    /// Everyone assigned to a task, in the order they were added
+    async fn list_assignees(&self, task_id: i64) -> Result<Vec<TaskAssignee>, DevErpError>;
 }
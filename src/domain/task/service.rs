@@ -1,20 +1,36 @@
+use chrono::{DateTime, Utc};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::sync::Arc;
 use tracing::{debug, info, warn};
 use uuid::Uuid;
 
+use crate::domain::project::repository::ProjectRepository;
 use crate::utils::error::DevErpError;
 
 use super::entity::{
-    CreateTask, CreateTaskComment, CreateTaskDependency, Task, TaskComment, TaskDependency,
-    TaskFilter, TaskStatus, UpdateTask,
+    validate_assignee, CompletionCheck, CreateTask, CreateTaskComment, CreateTaskDependency,
+    CreateTaskWorkLog, CriticalPath, DependencyType, Task, TaskAssignee, TaskComment,
+    TaskDependency, TaskFilter, TaskImportFailure, TaskImportSummary, TaskRollup, TaskStats,
+    TaskStatus, TaskWorkLog, UpdateTask, UpdateTaskResult,
 };
-use super::repository::{TaskCommentRepository, TaskDependencyRepository, TaskRepository};
+use super::repository::{
+    TaskAssigneeRepository, TaskCommentRepository, TaskDependencyRepository, TaskRepository,
+    TaskWorkLogRepository,
+};
+
+/// How far, in percent, actual hours may exceed the estimate before an
+/// update warns about it
+pub const DEFAULT_VARIANCE_THRESHOLD_PCT: f64 = 50.0;
 
 /// Task service containing business logic for task management
 pub struct TaskService {
     task_repo: Arc<dyn TaskRepository>,
     dependency_repo: Arc<dyn TaskDependencyRepository>,
     comment_repo: Arc<dyn TaskCommentRepository>,
+    work_log_repo: Arc<dyn TaskWorkLogRepository>,
+    assignee_repo: Arc<dyn TaskAssigneeRepository>,
+    project_repo: Arc<dyn ProjectRepository>,
+    variance_threshold_pct: f64,
 }
 
 impl TaskService {
@@ -23,25 +39,51 @@ impl TaskService {
         task_repo: Arc<dyn TaskRepository>,
         dependency_repo: Arc<dyn TaskDependencyRepository>,
         comment_repo: Arc<dyn TaskCommentRepository>,
+        work_log_repo: Arc<dyn TaskWorkLogRepository>,
+        assignee_repo: Arc<dyn TaskAssigneeRepository>,
+        project_repo: Arc<dyn ProjectRepository>,
     ) -> Self {
         Self {
             task_repo,
             dependency_repo,
             comment_repo,
+            work_log_repo,
+            assignee_repo,
+            project_repo,
+            variance_threshold_pct: DEFAULT_VARIANCE_THRESHOLD_PCT,
         }
     }
 
+    /// Warn on updates leaving actual hours more than `pct` percent over the estimate
+    pub fn with_variance_threshold_pct(mut self, pct: f64) -> Self {
+        self.variance_threshold_pct = pct;
+        self
+    }
+
     /// Create a new task
+    ///
+    /// A task created as `Blocked` needs a blocked reason, as when it is
+    /// blocked later by an update.
     pub async fn create_task(&self, input: CreateTask) -> Result<Task, DevErpError> {
         debug!("Creating new task: {}", input.title);
 
         // Validate input
         input.validate().map_err(DevErpError::Validation)?;
+        check_initial_blocked_reason(&input)?;
+
+        self.ensure_parent_exists(input.parent_task_id).await?;
+
+        let task = self.task_repo.create(input).await?;
 
-        // If parent_task_id is provided, verify it exists
-        if let Some(parent_id) = input.parent_task_id {
-            let parent = self.task_repo.find_by_id(parent_id).await?;
-            if parent.is_none() {
+        info!(task_id = %task.id, task_uuid = %task.uuid, "Task created successfully");
+
+        Ok(task)
+    }
+
+    /// Fail with `NotFound` if a parent task is given but does not exist
+    async fn ensure_parent_exists(&self, parent_task_id: Option<i64>) -> Result<(), DevErpError> {
+        if let Some(parent_id) = parent_task_id {
+            if self.task_repo.find_by_id(parent_id).await?.is_none() {
                 return Err(DevErpError::NotFound(format!(
                     "Parent task with id {} not found",
                     parent_id
@@ -49,11 +91,81 @@ impl TaskService {
             }
         }
 
-        let task = self.task_repo.create(input).await?;
+        Ok(())
+    }
 
-        info!(task_id = %task.id, task_uuid = %task.uuid, "Task created successfully");
+    /// Create every task in `tasks` under `project_id`
+    ///
+    /// Tasks are created one by one; a task that fails validation (or names a
+    /// parent that does not exist) is recorded in the summary and the rest
+    /// are still created. Any other error, such as a database failure, stops
+    /// the import.
+    pub async fn import_tasks(
+        &self,
+        project_id: i64,
+        tasks: Vec<CreateTask>,
+    ) -> Result<TaskImportSummary, DevErpError> {
+        let mut summary = TaskImportSummary {
+            created: Vec::new(),
+            failed: Vec::new(),
+        };
 
-        Ok(task)
+        for (index, mut input) in tasks.into_iter().enumerate() {
+            input.project_id = project_id;
+            let title = input.title.clone();
+
+            match self.create_task(input).await {
+                Ok(task) => summary.created.push(task),
+                Err(DevErpError::Validation(error) | DevErpError::NotFound(error)) => {
+                    warn!(index, title = %title, "Skipping invalid task in import");
+                    summary.failed.push(TaskImportFailure {
+                        index,
+                        title,
+                        error,
+                    });
+                }
+                Err(err) => return Err(err),
+            }
+        }
+
+        info!(
+            project_id = %project_id,
+            created = summary.created.len(),
+            failed = summary.failed.len(),
+            "Task import finished"
+        );
+
+        Ok(summary)
+    }
+
+    /// Create a task together with the tasks it depends on
+    ///
+    /// The task and every dependency are written in one transaction, with the
+    /// existence and cycle checks inside it: if any dependency is invalid,
+    /// nothing is created.
+    pub async fn create_task_with_dependencies(
+        &self,
+        input: CreateTask,
+        depends_on: &[i64],
+    ) -> Result<(Task, Vec<TaskDependency>), DevErpError> {
+        debug!("Creating new task with dependencies: {}", input.title);
+
+        input.validate().map_err(DevErpError::Validation)?;
+        check_initial_blocked_reason(&input)?;
+        self.ensure_parent_exists(input.parent_task_id).await?;
+
+        let (task, dependencies) = self
+            .task_repo
+            .create_with_dependencies(input, depends_on)
+            .await?;
+
+        info!(
+            task_id = %task.id,
+            dependencies = dependencies.len(),
+            "Task created with dependencies"
+        );
+
+        Ok((task, dependencies))
     }
 
     /// Get a task by its ID
@@ -93,7 +205,18 @@ impl TaskService {
     }
 
     /// Update a task
-    pub async fn update_task(&self, input: UpdateTask) -> Result<Task, DevErpError> {
+    ///
+    /// Marking a task done is rejected while a finish-to-start predecessor is
+    /// still open, unless `check` is `CompletionCheck::Skip`.
+    ///
+    /// When the update sets estimated or actual hours and the task ends up
+    /// over its estimate by more than the variance threshold, the result
+    /// carries a warning; the update is applied either way.
+    pub async fn update_task(
+        &self,
+        input: UpdateTask,
+        check: CompletionCheck,
+    ) -> Result<UpdateTaskResult, DevErpError> {
         debug!("Updating task with id: {}", input.id);
 
         // Validate input
@@ -101,6 +224,22 @@ impl TaskService {
 
         // Verify task exists
         let existing_task = self.get_task_by_id(input.id).await?;
+        check_blocked_reason(
+            &existing_task.status,
+            input.status.as_ref(),
+            input.blocked_reason.as_deref(),
+        )?;
+
+        if input.status == Some(TaskStatus::Done) && existing_task.status != TaskStatus::Done {
+            match check {
+                CompletionCheck::Enforce => self.ensure_predecessors_finished(input.id).await?,
+                CompletionCheck::Skip => {
+                    warn!(task_id = %input.id, "Skipping predecessor check (forced)");
+                }
+            }
+        }
+
+        let hours_changed = input.estimated_hours.is_some() || input.actual_hours.is_some();
 
         // If status is being updated to 'done', set completed_at if not already set
         let updated_task = if let Some(ref new_status) = input.status {
@@ -117,14 +256,65 @@ impl TaskService {
 
         info!(task_id = %updated_task.id, "Task updated successfully");
 
-        Ok(updated_task)
+        let mut warnings = Vec::new();
+        if hours_changed {
+            if let Some(warning) = variance_warning(
+                updated_task.estimated_hours,
+                updated_task.actual_hours,
+                self.variance_threshold_pct,
+            ) {
+                warn!(task_id = %updated_task.id, "{}", warning);
+                warnings.push(warning);
+            }
+        }
+
+        Ok(UpdateTaskResult {
+            task: updated_task,
+            warnings,
+        })
+    }
+
+    /// Reject completing `task_id` while a finish-to-start predecessor is open
+    ///
+    /// Cancelled predecessors count as finished; they will never be done.
+    async fn ensure_predecessors_finished(&self, task_id: i64) -> Result<(), DevErpError> {
+        let mut open = Vec::new();
+        for dependency in self.dependency_repo.get_dependencies(task_id).await? {
+            if dependency.dependency_type != DependencyType::FinishToStart {
+                continue;
+            }
+            let predecessor = self
+                .task_repo
+                .find_by_id(dependency.depends_on_task_id)
+                .await?;
+            if let Some(predecessor) = predecessor {
+                if !matches!(predecessor.status, TaskStatus::Done | TaskStatus::Cancelled) {
+                    open.push(predecessor.id);
+                }
+            }
+        }
+
+        if open.is_empty() {
+            return Ok(());
+        }
+        open.sort_unstable();
+        let ids: Vec<String> = open.iter().map(|id| id.to_string()).collect();
+        Err(DevErpError::Validation(format!(
+            "Task {} cannot be marked done before its predecessors are finished: {}",
+            task_id,
+            ids.join(", ")
+        )))
     }
 
     /// Change task status with validation
+    ///
+    /// Moving a task into `Blocked` requires a `blocked_reason`; leaving
+    /// `Blocked` clears the stored reason.
     pub async fn change_task_status(
         &self,
         task_id: i64,
         new_status: TaskStatus,
+        blocked_reason: Option<String>,
     ) -> Result<Task, DevErpError> {
         debug!("Changing status of task {} to {}", task_id, new_status);
 
@@ -137,6 +327,11 @@ impl TaskService {
                 task.status, new_status
             )));
         }
+        check_blocked_reason(&task.status, Some(&new_status), blocked_reason.as_deref())?;
+
+        if new_status == TaskStatus::Done && task.status != TaskStatus::Done {
+            self.ensure_predecessors_finished(task_id).await?;
+        }
 
         // If changing to 'in_progress' and started_at is not set, we could set it
         // If changing to 'done' and completed_at is not set, we could set it
@@ -145,6 +340,7 @@ impl TaskService {
         let update = UpdateTask {
             id: task_id,
             status: Some(new_status.clone()),
+            blocked_reason,
             title: None,
             description: None,
             priority: None,
@@ -154,6 +350,7 @@ impl TaskService {
             due_date: None,
             task_type: None,
             tags: None,
+            expected_version: None,
         };
 
         let updated_task = self.task_repo.update(update).await?;
@@ -239,6 +436,148 @@ impl TaskService {
         Ok(())
     }
 
+    /// Restore a soft-deleted task
+    pub async fn restore_task(&self, id: i64) -> Result<(), DevErpError> {
+        debug!("Restoring task with id: {}", id);
+
+        let restored = self.task_repo.restore(id).await?;
+
+        if !restored {
+            return Err(DevErpError::NotFound(format!(
+                "Task with id {} not found or not deleted",
+                id
+            )));
+        }
+
+        info!(task_id = %id, "Task restored successfully");
+
+        Ok(())
+    }
+
+    /// Move a task to another project
+    ///
+    /// The task's milestone link is dropped, and so is its parent unless the
+    /// parent already lives in the target project. Subtasks are not moved
+    /// along; any left behind are reported as a warning.
+    pub async fn move_task(
+        &self,
+        task_id: i64,
+        new_project_id: i64,
+    ) -> Result<UpdateTaskResult, DevErpError> {
+        debug!("Moving task {} to project {}", task_id, new_project_id);
+
+        let task = self.get_task_by_id(task_id).await?;
+        if task.project_id == new_project_id {
+            return Err(DevErpError::Validation(format!(
+                "Task {} already belongs to project {}",
+                task_id, new_project_id
+            )));
+        }
+        if self
+            .project_repo
+            .find_by_id(new_project_id)
+            .await?
+            .is_none()
+        {
+            return Err(DevErpError::NotFound(format!(
+                "Project with id {} not found",
+                new_project_id
+            )));
+        }
+
+        let clear_parent = match task.parent_task_id {
+            Some(parent_id) => self
+                .task_repo
+                .find_by_id(parent_id)
+                .await?
+                .is_none_or(|parent| parent.project_id != new_project_id),
+            None => false,
+        };
+
+        let subtasks = self
+            .task_repo
+            .find_all(TaskFilter {
+                parent_task_id: Some(task_id),
+                ..Default::default()
+            })
+            .await?;
+
+        let moved = self
+            .task_repo
+            .move_to_project(task_id, new_project_id, clear_parent)
+            .await?;
+
+        let mut warnings = Vec::new();
+        if !subtasks.is_empty() {
+            let ids: Vec<String> = subtasks.iter().map(|t| format!("#{}", t.id)).collect();
+            warnings.push(format!(
+                "{} subtask(s) stay in project {}: {}",
+                subtasks.len(),
+                task.project_id,
+                ids.join(", ")
+            ));
+        }
+
+        info!(
+            task_id = %task_id,
+            from_project = %task.project_id,
+            to_project = %new_project_id,
+            "Task moved to another project"
+        );
+
+        Ok(UpdateTaskResult {
+            task: moved,
+            warnings,
+        })
+    }
+
+    /// Roll a parent task's completion up from its subtasks, at any depth
+    ///
+    /// With `auto_complete`, a parent whose non-cancelled subtasks are all
+    /// done is moved to done through the usual status checks.
+    pub async fn rollup_progress(
+        &self,
+        parent_task_id: i64,
+        auto_complete: bool,
+    ) -> Result<TaskRollup, DevErpError> {
+        let parent = self.get_task_by_id(parent_task_id).await?;
+
+        // Walk down level by level; subtasks may live in other projects
+        let mut descendants = Vec::new();
+        let mut seen = HashSet::from([parent.id]);
+        let mut pending = vec![parent.id];
+        while let Some(id) = pending.pop() {
+            let children = self
+                .task_repo
+                .find_all(TaskFilter {
+                    parent_task_id: Some(id),
+                    ..Default::default()
+                })
+                .await?;
+            for child in children {
+                if seen.insert(child.id) {
+                    pending.push(child.id);
+                    descendants.push(child);
+                }
+            }
+        }
+
+        let mut rollup = rollup_subtasks(parent.id, &descendants);
+
+        let all_done = rollup.total_subtasks > 0 && rollup.done_subtasks == rollup.total_subtasks;
+        if auto_complete
+            && all_done
+            && !matches!(parent.status, TaskStatus::Done | TaskStatus::Cancelled)
+        {
+            self.change_task_status(parent.id, TaskStatus::Done, None)
+                .await?;
+            rollup.marked_done = true;
+            info!(task_id = %parent.id, "Parent task completed by subtask rollup");
+        }
+
+        Ok(rollup)
+    }
+
     /// Count tasks matching a filter
     pub async fn count_tasks(&self, filter: TaskFilter) -> Result<i64, DevErpError> {
         self.task_repo.count(filter).await
@@ -263,6 +602,21 @@ impl TaskService {
         let _task = self.get_task_by_id(dependency.task_id).await?;
         let _depends_on = self.get_task_by_id(dependency.depends_on_task_id).await?;
 
+        // Reject duplicates up front instead of surfacing the primary key violation
+        let existing = self
+            .dependency_repo
+            .get_dependencies(dependency.task_id)
+            .await?;
+        if existing
+            .iter()
+            .any(|d| d.depends_on_task_id == dependency.depends_on_task_id)
+        {
+            return Err(DevErpError::Conflict(format!(
+                "Dependency already exists: task {} already depends on task {}",
+                dependency.task_id, dependency.depends_on_task_id
+            )));
+        }
+
         // Check if this would create a circular dependency
         if self
             .dependency_repo
@@ -350,6 +704,110 @@ impl TaskService {
         Ok(dependents)
     }
 
+    /// Every task `task_id` depends on, directly or transitively
+    ///
+    /// The task itself comes first, then the others by distance. With
+    /// `max_depth`, the walk stops that many dependency steps out.
+    pub async fn get_dependency_chain(
+        &self,
+        task_id: i64,
+        max_depth: Option<u32>,
+    ) -> Result<Vec<i64>, DevErpError> {
+        debug!("Fetching dependency chain for task {}", task_id);
+
+        // Verify task exists
+        let _task = self.get_task_by_id(task_id).await?;
+
+        self.dependency_repo
+            .get_dependency_chain_batched(task_id, max_depth)
+            .await
+    }
+
+    /// Every dependency cycle among a project's tasks
+    ///
+    /// New cycles are rejected when dependencies are added, so any found
+    /// here came from edits made outside the application.
+    pub async fn audit_cycles(&self, project_id: i64) -> Result<Vec<Vec<i64>>, DevErpError> {
+        debug!("Auditing dependency cycles in project {}", project_id);
+
+        let cycles = self.dependency_repo.find_all_cycles(project_id).await?;
+
+        if !cycles.is_empty() {
+            warn!(
+                project_id = %project_id,
+                count = cycles.len(),
+                "Dependency cycles found"
+            );
+        }
+
+        Ok(cycles)
+    }
+
+    /// Every dependency between two live tasks of a project
+    pub async fn get_project_dependencies(
+        &self,
+        project_id: i64,
+    ) -> Result<Vec<TaskDependency>, DevErpError> {
+        self.dependency_repo
+            .get_project_dependencies(project_id)
+            .await
+    }
+
+    /// A project plus the projects that depend on it, up to `depth` levels out
+    ///
+    /// Project dependency edges come from cross-project task dependencies:
+    /// a project depends on another when one of its tasks depends on one of
+    /// the other's. Depth 0 is just the project itself. Sorted by project ID.
+    pub async fn expand_dependent_projects(
+        &self,
+        project_id: i64,
+        depth: u32,
+    ) -> Result<Vec<i64>, DevErpError> {
+        let mut projects = vec![project_id];
+        let mut frontier = vec![project_id];
+
+        for _ in 0..depth {
+            let dependents = self
+                .dependency_repo
+                .find_dependent_projects(&frontier)
+                .await?;
+            frontier = dependents
+                .into_iter()
+                .filter(|id| !projects.contains(id))
+                .collect();
+            if frontier.is_empty() {
+                break;
+            }
+            projects.extend(&frontier);
+        }
+
+        projects.sort_unstable();
+        Ok(projects)
+    }
+
+    /// Adjacency list of a project's dependency graph
+    ///
+    /// One entry per task, by ascending ID, listing the tasks it depends on.
+    /// Tasks without dependencies get an empty list.
+    pub async fn get_dependency_graph(
+        &self,
+        project_id: i64,
+    ) -> Result<Vec<(i64, Vec<i64>)>, DevErpError> {
+        debug!("Building dependency graph for project {}", project_id);
+
+        let tasks = self
+            .task_repo
+            .find_all(TaskFilter {
+                project_id: Some(project_id),
+                ..Default::default()
+            })
+            .await?;
+        let dependencies = self.get_project_dependencies(project_id).await?;
+        let task_ids: Vec<i64> = tasks.iter().map(|t| t.id).collect();
+
+        Ok(dependency_adjacency(&task_ids, &dependencies))
+    }
+
     /// Check if adding a dependency would create a cycle
     pub async fn would_create_cycle(
         &self,
@@ -361,6 +819,80 @@ impl TaskService {
             .await
     }
 
+    /// Age, time in the current status, estimate accuracy and overdue flag
+    /// for one task, as of `now`
+    ///
+    /// Time in status comes from the recorded status changes. Without one,
+    /// it falls back to `completed_at`/`started_at` for done and in-progress
+    /// tasks and to the creation time for tasks still in `todo`; otherwise
+    /// it is left unknown.
+    pub async fn task_stats(&self, id: i64, now: DateTime<Utc>) -> Result<TaskStats, DevErpError> {
+        let task = self.get_task_by_id(id).await?;
+
+        let status_since = match self.task_repo.last_status_change(id).await? {
+            Some(changed_at) => Some(changed_at),
+            None => match task.status {
+                TaskStatus::Done => task.completed_at,
+                TaskStatus::InProgress => task.started_at,
+                TaskStatus::Todo => Some(task.created_at),
+                _ => None,
+            },
+        };
+
+        let overdue = !matches!(task.status, TaskStatus::Done | TaskStatus::Cancelled)
+            && task.due_date.is_some_and(|due| due < now);
+
+        Ok(TaskStats {
+            task_id: task.id,
+            age_days: (now - task.created_at).num_days(),
+            days_in_status: status_since.map(|since| (now - since).num_days()),
+            status_since,
+            estimate_accuracy: estimate_accuracy(task.estimated_hours, task.actual_hours),
+            estimated_hours: task.estimated_hours,
+            actual_hours: task.actual_hours,
+            status: task.status,
+            overdue,
+        })
+    }
+
+    /// Find the longest-duration chain of dependent tasks in a project
+    ///
+    /// Tasks without an estimate weigh 0 hours. Dependencies on tasks in
+    /// other projects are ignored.
+    pub async fn compute_critical_path(
+        &self,
+        project_id: i64,
+    ) -> Result<CriticalPath, DevErpError> {
+        debug!("Computing critical path for project {}", project_id);
+
+        let tasks = self
+            .task_repo
+            .find_all(TaskFilter {
+                project_id: Some(project_id),
+                ..Default::default()
+            })
+            .await?;
+        let dependencies = self
+            .dependency_repo
+            .get_project_dependencies(project_id)
+            .await?;
+
+        let weights: Vec<(i64, f64)> = tasks
+            .iter()
+            .map(|t| (t.id, t.estimated_hours.unwrap_or(0.0)))
+            .collect();
+        let path = longest_path(&weights, &dependencies)?;
+
+        info!(
+            project_id = %project_id,
+            length = path.task_ids.len(),
+            total_hours = path.total_hours,
+            "Computed critical path"
+        );
+
+        Ok(path)
+    }
+
     // ===== Task Comment Management =====
 
     /// Add a comment to a task
@@ -440,11 +972,439 @@ impl TaskService {
 
         Ok(())
     }
+
+    /// Restore a soft-deleted task comment
+    pub async fn restore_task_comment(&self, comment_id: i64) -> Result<(), DevErpError> {
+        debug!("Restoring comment {}", comment_id);
+
+        let restored = self.comment_repo.restore(comment_id).await?;
+
+        if !restored {
+            return Err(DevErpError::NotFound(format!(
+                "Comment with id {} not found or not deleted",
+                comment_id
+            )));
+        }
+
+        info!(comment_id = %comment_id, "Task comment restored successfully");
+
+        Ok(())
+    }
+
+    /// Log `hours` of work on a task and add them to its `actual_hours`
+    pub async fn log_work(
+        &self,
+        task_id: i64,
+        hours: f64,
+        logged_by: String,
+        note: Option<String>,
+    ) -> Result<TaskWorkLog, DevErpError> {
+        debug!("Logging {} hours on task {}", hours, task_id);
+
+        let input = CreateTaskWorkLog {
+            task_id,
+            hours,
+            logged_by,
+            note,
+        };
+        input.validate().map_err(DevErpError::Validation)?;
+
+        // Verify task exists
+        let _task = self.get_task_by_id(task_id).await?;
+
+        let work_log = self.work_log_repo.create(input).await?;
+
+        info!(
+            work_log_id = %work_log.id,
+            task_id = %task_id,
+            hours = work_log.hours,
+            "Work logged successfully"
+        );
+
+        Ok(work_log)
+    }
+
+    /// The latest `limit` work log entries for a task, newest first
+    pub async fn recent_work_logs(
+        &self,
+        task_id: i64,
+        limit: i64,
+    ) -> Result<Vec<TaskWorkLog>, DevErpError> {
+        self.work_log_repo.find_recent_by_task(task_id, limit).await
+    }
+
+    /// Add `assignee` to a task; they become primary if the task had none
+    pub async fn assign_task(&self, task_id: i64, assignee: &str) -> Result<(), DevErpError> {
+        debug!("Assigning {} to task {}", assignee, task_id);
+
+        validate_assignee(assignee).map_err(DevErpError::Validation)?;
+
+        if self
+            .list_assignees(task_id)
+            .await?
+            .iter()
+            .any(|a| a == assignee)
+        {
+            return Err(DevErpError::Conflict(format!(
+                "{} is already assigned to task {}",
+                assignee, task_id
+            )));
+        }
+
+        self.assignee_repo.add_assignee(task_id, assignee).await?;
+
+        info!(task_id = %task_id, assignee = %assignee, "Task assignee added");
+
+        Ok(())
+    }
+
+    /// Remove `assignee` from a task, handing the primary role on if needed
+    pub async fn unassign_task(&self, task_id: i64, assignee: &str) -> Result<(), DevErpError> {
+        debug!("Unassigning {} from task {}", assignee, task_id);
+
+        // Verify task exists
+        let _task = self.get_task_by_id(task_id).await?;
+
+        let removed = self
+            .assignee_repo
+            .remove_assignee(task_id, assignee)
+            .await?;
+        if !removed {
+            return Err(DevErpError::NotFound(format!(
+                "{} is not assigned to task {}",
+                assignee, task_id
+            )));
+        }
+
+        info!(task_id = %task_id, assignee = %assignee, "Task assignee removed");
+
+        Ok(())
+    }
+
+    /// Everyone assigned to a task, primary assignee first
+    pub async fn list_assignees(&self, task_id: i64) -> Result<Vec<String>, DevErpError> {
+        let task = self.get_task_by_id(task_id).await?;
+        let assignees = self.assignee_repo.list_assignees(task_id).await?;
+
+        Ok(merge_assignees(task.assigned_to.as_deref(), assignees))
+    }
+}
+
+/// Primary assignee followed by the others in the order they were added
+///
+/// The primary may have been set through `assigned_to` alone, so it is
+/// listed even when it has no assignee row.
+fn merge_assignees(primary: Option<&str>, assignees: Vec<TaskAssignee>) -> Vec<String> {
+    let mut names: Vec<String> = primary.map(str::to_string).into_iter().collect();
+    for assignee in assignees {
+        if Some(assignee.assignee.as_str()) != primary {
+            names.push(assignee.assignee);
+        }
+    }
+    names
+}
+
+/// Longest path found so far ending at a task
+#[derive(Clone, Copy)]
+struct PathEnd {
+    hours: f64,
+    steps: usize,
+    previous: Option<i64>,
+}
+
+impl PathEnd {
+    /// More hours wins; with equal hours the path through more tasks does, so
+    /// unestimated tasks at the end of a chain are not dropped
+    fn outweighs(&self, other: &PathEnd) -> bool {
+        self.hours > other.hours || (self.hours == other.hours && self.steps > other.steps)
+    }
+}
+
+/// Check the blocked reason of a new task, which starts out as `Todo` unless
+/// a status is given
+fn check_initial_blocked_reason(input: &CreateTask) -> Result<(), DevErpError> {
+    check_blocked_reason(
+        &TaskStatus::Todo,
+        input.status.as_ref(),
+        input.blocked_reason.as_deref(),
+    )
+}
+
+/// Check the blocked reason on a status change from `current` to `new_status`
+///
+/// Entering `Blocked` needs a reason; staying blocked may update it; any
+/// other status takes none, since the stored reason is cleared on the way out.
+fn check_blocked_reason(
+    current: &TaskStatus,
+    new_status: Option<&TaskStatus>,
+    reason: Option<&str>,
+) -> Result<(), DevErpError> {
+    let target = new_status.unwrap_or(current);
+    if *target != TaskStatus::Blocked {
+        return match reason {
+            Some(_) => Err(DevErpError::Validation(
+                "A blocked reason can only be set on a blocked task".to_string(),
+            )),
+            None => Ok(()),
+        };
+    }
+
+    let has_reason = reason.is_some_and(|r| !r.trim().is_empty());
+    if *current != TaskStatus::Blocked && !has_reason {
+        return Err(DevErpError::Validation(
+            "A reason is required to mark a task blocked (use --blocked-reason)".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Completion of `parent_id` from `descendants`, every task below it
+///
+/// Each direct subtask counts equally. A subtask with subtasks of its own
+/// counts as their rolled-up completion unless it is already done; one with
+/// none counts by its status. Cancelled subtasks are left out.
+pub fn rollup_subtasks(parent_id: i64, descendants: &[Task]) -> TaskRollup {
+    let mut children: HashMap<i64, Vec<&Task>> = HashMap::new();
+    for task in descendants {
+        if let Some(parent) = task.parent_task_id {
+            children.entry(parent).or_default().push(task);
+        }
+    }
+
+    let direct: Vec<&Task> = children
+        .get(&parent_id)
+        .map(|kids| {
+            kids.iter()
+                .copied()
+                .filter(|t| t.status != TaskStatus::Cancelled)
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let mut visiting = HashSet::from([parent_id]);
+    let completions: Vec<f64> = direct
+        .iter()
+        .filter_map(|task| subtree_completion(task, &children, &mut visiting))
+        .collect();
+    let completion_pct = if completions.is_empty() {
+        0.0
+    } else {
+        completions.iter().sum::<f64>() / completions.len() as f64
+    };
+
+    TaskRollup {
+        task_id: parent_id,
+        total_subtasks: direct.len(),
+        done_subtasks: direct
+            .iter()
+            .filter(|t| t.status == TaskStatus::Done)
+            .count(),
+        completion_pct,
+        marked_done: false,
+    }
+}
+
+/// Completion of `task` counting its own subtasks; `None` when cancelled
+fn subtree_completion(
+    task: &Task,
+    children: &HashMap<i64, Vec<&Task>>,
+    visiting: &mut HashSet<i64>,
+) -> Option<f64> {
+    let own = task.status.completion_pct()?;
+    if task.status == TaskStatus::Done || !visiting.insert(task.id) {
+        return Some(own);
+    }
+
+    let completions: Vec<f64> = children
+        .get(&task.id)
+        .into_iter()
+        .flatten()
+        .filter_map(|child| subtree_completion(child, children, visiting))
+        .collect();
+    visiting.remove(&task.id);
+
+    if completions.is_empty() {
+        Some(own)
+    } else {
+        Some(completions.iter().sum::<f64>() / completions.len() as f64)
+    }
+}
+
+/// Ratio of actual to estimated hours
+///
+/// `None` unless both are known and the estimate is above zero.
+pub fn estimate_accuracy(estimated: Option<f64>, actual: Option<f64>) -> Option<f64> {
+    match (estimated, actual) {
+        (Some(estimated), Some(actual)) if estimated > 0.0 => Some(actual / estimated),
+        _ => None,
+    }
+}
+
+/// Warning for actual hours more than `threshold_pct` percent over the estimate
+///
+/// Nothing is reported unless both values are known and the estimate is
+/// positive.
+pub fn variance_warning(
+    estimated: Option<f64>,
+    actual: Option<f64>,
+    threshold_pct: f64,
+) -> Option<String> {
+    let (estimated, actual) = (estimated?, actual?);
+    if estimated <= 0.0 {
+        return None;
+    }
+    let over_pct = (actual - estimated) / estimated * 100.0;
+    (over_pct > threshold_pct).then(|| {
+        format!(
+            "Actual hours ({}) exceed the estimate ({}) by {:.0}%, above the {}% threshold",
+            actual, estimated, over_pct, threshold_pct
+        )
+    })
+}
+
+/// Longest weighted path through a task dependency graph
+///
+/// `tasks` pairs each task ID with its weight in hours; dependencies whose
+/// ends are not both in `tasks` are skipped. With no dependencies every task
+/// is its own trivial path, so the heaviest single task wins. Remaining ties
+/// go to the lower task ID.
+pub fn longest_path(
+    tasks: &[(i64, f64)],
+    dependencies: &[TaskDependency],
+) -> Result<CriticalPath, DevErpError> {
+    let weights: BTreeMap<i64, f64> = tasks.iter().copied().collect();
+    let mut predecessors: BTreeMap<i64, BTreeSet<i64>> =
+        weights.keys().map(|id| (*id, BTreeSet::new())).collect();
+    let mut successors: BTreeMap<i64, Vec<i64>> = BTreeMap::new();
+
+    for dep in dependencies {
+        if !weights.contains_key(&dep.task_id) || !weights.contains_key(&dep.depends_on_task_id) {
+            continue;
+        }
+        if let Some(preds) = predecessors.get_mut(&dep.task_id) {
+            if preds.insert(dep.depends_on_task_id) {
+                successors
+                    .entry(dep.depends_on_task_id)
+                    .or_default()
+                    .push(dep.task_id);
+            }
+        }
+    }
+
+    // Kahn's algorithm; `ready` is ordered so the result is deterministic
+    let mut remaining: HashMap<i64, usize> = predecessors
+        .iter()
+        .map(|(id, preds)| (*id, preds.len()))
+        .collect();
+    let mut ready: BTreeSet<i64> = remaining
+        .iter()
+        .filter(|(_, count)| **count == 0)
+        .map(|(id, _)| *id)
+        .collect();
+    // Per task: hours and task count of the longest path ending there, and
+    // the predecessor on that path
+    let mut best: HashMap<i64, PathEnd> = HashMap::new();
+
+    while let Some(id) = ready.pop_first() {
+        let mut longest: Option<(i64, PathEnd)> = None;
+        for pred in &predecessors[&id] {
+            let candidate = best[pred];
+            if longest.is_none_or(|(_, current)| candidate.outweighs(&current)) {
+                longest = Some((*pred, candidate));
+            }
+        }
+        let (hours, steps) = longest.map_or((0.0, 0), |(_, end)| (end.hours, end.steps));
+        best.insert(
+            id,
+            PathEnd {
+                hours: hours + weights[&id],
+                steps: steps + 1,
+                previous: longest.map(|(pred, _)| pred),
+            },
+        );
+
+        for next in successors.get(&id).into_iter().flatten() {
+            let count = remaining.get_mut(next).expect("successor is a known task");
+            *count -= 1;
+            if *count == 0 {
+                ready.insert(*next);
+            }
+        }
+    }
+
+    if best.len() < weights.len() {
+        return Err(DevErpError::Validation(
+            "Task dependencies contain a cycle; no critical path exists".to_string(),
+        ));
+    }
+
+    let mut end: Option<(i64, PathEnd)> = None;
+    for id in weights.keys() {
+        let candidate = best[id];
+        if end.is_none_or(|(_, current)| candidate.outweighs(&current)) {
+            end = Some((*id, candidate));
+        }
+    }
+
+    let Some((
+        end_id,
+        PathEnd {
+            hours: total_hours, ..
+        },
+    )) = end
+    else {
+        return Ok(CriticalPath {
+            task_ids: Vec::new(),
+            total_hours: 0.0,
+        });
+    };
+
+    let mut task_ids = vec![end_id];
+    let mut current = end_id;
+    while let Some(pred) = best[&current].previous {
+        task_ids.push(pred);
+        current = pred;
+    }
+    task_ids.reverse();
+
+    Ok(CriticalPath {
+        task_ids,
+        total_hours,
+    })
+}
+
+/// Adjacency list over `task_ids`, each task with the tasks it depends on
+///
+/// Both lists come out sorted. Dependencies on tasks outside `task_ids` are
+/// skipped.
+pub fn dependency_adjacency(
+    task_ids: &[i64],
+    dependencies: &[TaskDependency],
+) -> Vec<(i64, Vec<i64>)> {
+    let mut edges: BTreeMap<i64, BTreeSet<i64>> =
+        task_ids.iter().map(|&id| (id, BTreeSet::new())).collect();
+    for dep in dependencies {
+        if !edges.contains_key(&dep.depends_on_task_id) {
+            continue;
+        }
+        if let Some(depends_on) = edges.get_mut(&dep.task_id) {
+            depends_on.insert(dep.depends_on_task_id);
+        }
+    }
+
+    edges
+        .into_iter()
+        .map(|(id, depends_on)| (id, depends_on.into_iter().collect()))
+        .collect()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::project::entity::{CreateProject, Project, ProjectFilter, UpdateProject};
+    use crate::domain::task::entity::{AssigneeStatusCount, ProjectTaskCount};
+    use chrono::NaiveDate;
 
     #[test]
     fn test_valid_status_transitions() {
@@ -452,6 +1412,9 @@ mod tests {
             Arc::new(MockTaskRepository),
             Arc::new(MockDependencyRepository),
             Arc::new(MockCommentRepository),
+            Arc::new(MockWorkLogRepository),
+            Arc::new(MockAssigneeRepository),
+            Arc::new(MockProjectRepository),
         );
 
         // Valid transitions
@@ -465,6 +1428,170 @@ mod tests {
         assert!(!service.is_valid_status_transition(&TaskStatus::Todo, &TaskStatus::Review));
     }
 
+    fn dependency(task_id: i64, depends_on_task_id: i64) -> TaskDependency {
+        TaskDependency {
+            task_id,
+            depends_on_task_id,
+            dependency_type: Default::default(),
+            created_at: chrono::Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_longest_path_follows_heaviest_branch() {
+        // 1 -> 2 -> 4 and 1 -> 3 -> 4; the branch through 3 is longer
+        let tasks = [(1, 2.0), (2, 1.0), (3, 5.0), (4, 3.0)];
+        let deps = [
+            dependency(2, 1),
+            dependency(3, 1),
+            dependency(4, 2),
+            dependency(4, 3),
+        ];
+
+        let path = longest_path(&tasks, &deps).unwrap();
+        assert_eq!(path.task_ids, vec![1, 3, 4]);
+        assert_eq!(path.total_hours, 10.0);
+    }
+
+    #[test]
+    fn test_longest_path_without_dependencies() {
+        // Unestimated tasks weigh nothing, so a single estimated task wins
+        let tasks = [(1, 0.0), (2, 4.0), (3, 0.0)];
+        let path = longest_path(&tasks, &[]).unwrap();
+        assert_eq!(path.task_ids, vec![2]);
+        assert_eq!(path.total_hours, 4.0);
+
+        // An isolated task still counts once other tasks form a chain
+        let tasks = [(1, 2.5), (2, 2.5), (3, 10.0)];
+        let path = longest_path(&tasks, &[dependency(2, 1)]).unwrap();
+        assert_eq!(path.task_ids, vec![3]);
+        assert_eq!(path.total_hours, 10.0);
+
+        let empty = longest_path(&[], &[]).unwrap();
+        assert!(empty.task_ids.is_empty());
+    }
+
+    #[test]
+    fn test_longest_path_ignores_foreign_tasks_and_rejects_cycles() {
+        let tasks = [(1, 1.0), (2, 1.0)];
+        let path = longest_path(&tasks, &[dependency(2, 1), dependency(1, 99)]).unwrap();
+        assert_eq!(path.task_ids, vec![1, 2]);
+
+        let result = longest_path(&tasks, &[dependency(2, 1), dependency(1, 2)]);
+        assert!(matches!(result, Err(DevErpError::Validation(_))));
+    }
+
+    fn subtask(id: i64, parent: i64, status: TaskStatus) -> Task {
+        Task {
+            id,
+            parent_task_id: Some(parent),
+            title: format!("Task {}", id),
+            status,
+            ..Task::test_default()
+        }
+    }
+
+    #[test]
+    fn test_rollup_subtasks_recurses_into_nested_subtasks() {
+        // 1 has subtasks 2 (done), 3 (in review, but with its own subtasks)
+        // and 4 (cancelled); 3's subtasks are half done
+        let tasks = [
+            subtask(2, 1, TaskStatus::Done),
+            subtask(3, 1, TaskStatus::Review),
+            subtask(4, 1, TaskStatus::Cancelled),
+            subtask(5, 3, TaskStatus::Done),
+            subtask(6, 3, TaskStatus::Todo),
+        ];
+
+        let rollup = rollup_subtasks(1, &tasks);
+        assert_eq!(rollup.total_subtasks, 2);
+        assert_eq!(rollup.done_subtasks, 1);
+        // (100 + (100 + 0) / 2) / 2
+        assert_eq!(rollup.completion_pct, 75.0);
+
+        let nested = rollup_subtasks(3, &tasks);
+        assert_eq!((nested.done_subtasks, nested.total_subtasks), (1, 2));
+        assert_eq!(nested.completion_pct, 50.0);
+
+        let leaf = rollup_subtasks(6, &tasks);
+        assert_eq!(leaf.total_subtasks, 0);
+        assert_eq!(leaf.completion_pct, 0.0);
+    }
+
+    #[test]
+    fn test_dependency_adjacency_lists_every_task() {
+        let deps = [
+            dependency(3, 1),
+            dependency(2, 1),
+            dependency(3, 2),
+            dependency(2, 99),
+        ];
+        assert_eq!(
+            dependency_adjacency(&[3, 1, 2, 4], &deps),
+            vec![(1, vec![]), (2, vec![1]), (3, vec![1, 2]), (4, vec![])]
+        );
+    }
+
+    #[test]
+    fn test_variance_warning_above_threshold() {
+        let warning = variance_warning(Some(10.0), Some(16.0), 50.0).unwrap();
+        assert!(warning.contains("by 60%"), "{}", warning);
+
+        // Exactly at the threshold, under the estimate, or unknown: no warning
+        assert_eq!(variance_warning(Some(10.0), Some(15.0), 50.0), None);
+        assert_eq!(variance_warning(Some(10.0), Some(4.0), 50.0), None);
+        assert_eq!(variance_warning(None, Some(40.0), 50.0), None);
+        assert_eq!(variance_warning(Some(0.0), Some(3.0), 50.0), None);
+    }
+
+    #[test]
+    fn test_blocked_reason_required_when_entering_blocked() {
+        use TaskStatus::*;
+
+        assert!(
+            check_blocked_reason(&InProgress, Some(&Blocked), Some("Waiting on API keys")).is_ok()
+        );
+        assert!(check_blocked_reason(&InProgress, Some(&Blocked), None).is_err());
+        assert!(check_blocked_reason(&Todo, Some(&Blocked), Some("  ")).is_err());
+
+        // Already blocked: the reason may be kept or replaced
+        assert!(check_blocked_reason(&Blocked, Some(&Blocked), None).is_ok());
+        assert!(check_blocked_reason(&Blocked, None, Some("Vendor outage")).is_ok());
+
+        // Leaving blocked, or never blocked, takes no reason
+        assert!(check_blocked_reason(&Blocked, Some(&InProgress), None).is_ok());
+        assert!(check_blocked_reason(&Blocked, Some(&Todo), Some("stale")).is_err());
+        assert!(check_blocked_reason(&Todo, None, Some("why")).is_err());
+    }
+
+    #[test]
+    fn test_estimate_accuracy_ratio() {
+        assert_eq!(estimate_accuracy(Some(8.0), Some(12.0)), Some(1.5));
+        assert_eq!(estimate_accuracy(Some(10.0), Some(5.0)), Some(0.5));
+        assert_eq!(estimate_accuracy(Some(0.0), Some(3.0)), None);
+        assert_eq!(estimate_accuracy(None, Some(3.0)), None);
+        assert_eq!(estimate_accuracy(Some(4.0), None), None);
+    }
+
+    #[test]
+    fn test_merge_assignees_lists_primary_first() {
+        let assignee = |name: &str| TaskAssignee {
+            task_id: 1,
+            assignee: name.to_string(),
+            assigned_at: chrono::Utc::now(),
+        };
+
+        let names = merge_assignees(
+            Some("bob"),
+            vec![assignee("alice"), assignee("bob"), assignee("carol")],
+        );
+        assert_eq!(names, vec!["bob", "alice", "carol"]);
+
+        // A primary set only through assigned_to is still listed
+        assert_eq!(merge_assignees(Some("dave"), Vec::new()), vec!["dave"]);
+        assert!(merge_assignees(None, Vec::new()).is_empty());
+    }
+
     // Mock implementations for testing
     struct MockTaskRepository;
     #[async_trait::async_trait]
@@ -472,6 +1599,13 @@ mod tests {
         async fn create(&self, _task: CreateTask) -> Result<Task, DevErpError> {
             unimplemented!()
         }
+        async fn create_with_dependencies(
+            &self,
+            _task: CreateTask,
+            _depends_on: &[i64],
+        ) -> Result<(Task, Vec<TaskDependency>), DevErpError> {
+            unimplemented!()
+        }
         async fn find_by_id(&self, _id: i64) -> Result<Option<Task>, DevErpError> {
             unimplemented!()
         }
@@ -490,9 +1624,35 @@ mod tests {
         async fn soft_delete(&self, _id: i64) -> Result<bool, DevErpError> {
             unimplemented!()
         }
+        async fn restore(&self, _id: i64) -> Result<bool, DevErpError> {
+            unimplemented!()
+        }
         async fn count(&self, _filter: TaskFilter) -> Result<i64, DevErpError> {
             unimplemented!()
         }
+        async fn count_by_assignee(&self) -> Result<Vec<AssigneeStatusCount>, DevErpError> {
+            unimplemented!()
+        }
+        async fn count_by_project(
+            &self,
+            _project_ids: &[i64],
+        ) -> Result<Vec<ProjectTaskCount>, DevErpError> {
+            unimplemented!()
+        }
+        async fn last_status_change(
+            &self,
+            _task_id: i64,
+        ) -> Result<Option<DateTime<Utc>>, DevErpError> {
+            unimplemented!()
+        }
+        async fn move_to_project(
+            &self,
+            _task_id: i64,
+            _project_id: i64,
+            _clear_parent: bool,
+        ) -> Result<Task, DevErpError> {
+            unimplemented!()
+        }
     }
 
     struct MockDependencyRepository;
@@ -527,7 +1687,33 @@ mod tests {
         ) -> Result<bool, DevErpError> {
             unimplemented!()
         }
-        async fn get_dependency_chain(&self, _start_task_id: i64) -> Result<Vec<i64>, DevErpError> {
+        async fn get_dependency_chain(
+            &self,
+            _start_task_id: i64,
+            _max_depth: Option<u32>,
+        ) -> Result<Vec<i64>, DevErpError> {
+            unimplemented!()
+        }
+        async fn get_dependency_chain_batched(
+            &self,
+            _start_task_id: i64,
+            _max_depth: Option<u32>,
+        ) -> Result<Vec<i64>, DevErpError> {
+            unimplemented!()
+        }
+        async fn get_project_dependencies(
+            &self,
+            _project_id: i64,
+        ) -> Result<Vec<TaskDependency>, DevErpError> {
+            unimplemented!()
+        }
+        async fn find_dependent_projects(
+            &self,
+            _project_ids: &[i64],
+        ) -> Result<Vec<i64>, DevErpError> {
+            unimplemented!()
+        }
+        async fn find_all_cycles(&self, _project_id: i64) -> Result<Vec<Vec<i64>>, DevErpError> {
             unimplemented!()
         }
     }
@@ -554,5 +1740,92 @@ mod tests {
         async fn soft_delete(&self, _id: i64) -> Result<bool, DevErpError> {
             unimplemented!()
         }
+        async fn restore(&self, _id: i64) -> Result<bool, DevErpError> {
+            unimplemented!()
+        }
+    }
+
+    struct MockWorkLogRepository;
+    #[async_trait::async_trait]
+    impl TaskWorkLogRepository for MockWorkLogRepository {
+        async fn create(&self, _log: CreateTaskWorkLog) -> Result<TaskWorkLog, DevErpError> {
+            unimplemented!()
+        }
+        async fn find_recent_by_task(
+            &self,
+            _task_id: i64,
+            _limit: i64,
+        ) -> Result<Vec<TaskWorkLog>, DevErpError> {
+            unimplemented!()
+        }
+    }
+
+    struct MockAssigneeRepository;
+    #[async_trait::async_trait]
+    impl TaskAssigneeRepository for MockAssigneeRepository {
+        async fn add_assignee(&self, _task_id: i64, _assignee: &str) -> Result<bool, DevErpError> {
+            unimplemented!()
+        }
+        async fn remove_assignee(
+            &self,
+            _task_id: i64,
+            _assignee: &str,
+        ) -> Result<bool, DevErpError> {
+            unimplemented!()
+        }
+        async fn list_assignees(&self, _task_id: i64) -> Result<Vec<TaskAssignee>, DevErpError> {
+            unimplemented!()
+        }
+    }
+
+    struct MockProjectRepository;
+    #[async_trait::async_trait]
+    impl ProjectRepository for MockProjectRepository {
+        async fn create(&self, _project: CreateProject) -> Result<Project, DevErpError> {
+            unimplemented!()
+        }
+        async fn find_by_id(&self, _id: i64) -> Result<Option<Project>, DevErpError> {
+            unimplemented!()
+        }
+        async fn find_by_uuid(&self, _uuid: Uuid) -> Result<Option<Project>, DevErpError> {
+            unimplemented!()
+        }
+        async fn find_by_code(&self, _code: &str) -> Result<Option<Project>, DevErpError> {
+            unimplemented!()
+        }
+        async fn find_all(&self, _filter: ProjectFilter) -> Result<Vec<Project>, DevErpError> {
+            unimplemented!()
+        }
+        async fn count(&self, _filter: ProjectFilter) -> Result<i64, DevErpError> {
+            unimplemented!()
+        }
+        async fn update(&self, _project: UpdateProject) -> Result<Project, DevErpError> {
+            unimplemented!()
+        }
+        async fn soft_delete(&self, _id: i64) -> Result<bool, DevErpError> {
+            unimplemented!()
+        }
+        async fn soft_delete_cascade(&self, _id: i64) -> Result<bool, DevErpError> {
+            unimplemented!()
+        }
+        async fn delete(&self, _id: i64) -> Result<bool, DevErpError> {
+            unimplemented!()
+        }
+        async fn restore(&self, _id: i64) -> Result<bool, DevErpError> {
+            unimplemented!()
+        }
+        async fn code_exists(
+            &self,
+            _code: &str,
+            _exclude_id: Option<i64>,
+        ) -> Result<bool, DevErpError> {
+            unimplemented!()
+        }
+        async fn find_by_tag(&self, _tag: &str) -> Result<Vec<Project>, DevErpError> {
+            unimplemented!()
+        }
+        async fn find_overdue(&self, _today: NaiveDate) -> Result<Vec<Project>, DevErpError> {
+            unimplemented!()
+        }
     }
 }
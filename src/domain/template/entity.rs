@@ -0,0 +1,572 @@
+// Project template entities
+
+use chrono::{DateTime, Duration, NaiveDate, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+use crate::domain::project::entity::{CreateProject, Priority, Project};
+use crate::domain::task::entity::{CreateTask, Task, TaskPriority, TaskType};
+use crate::domain::timeline::entity::{
+    CreateMilestone, CreateTimeline, Milestone, Timeline, TimelineType,
+};
+
+/// A reusable project skeleton loaded from a JSON file
+///
+/// All dates are expressed as day offsets from the start date chosen when
+/// the template is applied.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProjectTemplate {
+    pub project: ProjectTemplateInfo,
+    #[serde(default)]
+    pub timelines: Vec<TimelineTemplate>,
+    #[serde(default)]
+    pub tasks: Vec<TaskTemplate>,
+}
+
+/// Project section of a template
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProjectTemplateInfo {
+    pub name: String,
+    pub description: Option<String>,
+    pub code: Option<String>,
+    pub priority: Option<String>,
+    /// Planned length; defaults to the end of the last timeline
+    pub duration_days: Option<i64>,
+    pub tags: Option<Vec<String>>,
+}
+
+/// Timeline section of a template
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TimelineTemplate {
+    pub name: String,
+    pub description: Option<String>,
+    pub timeline_type: Option<String>,
+    pub start_offset_days: i64,
+    pub end_offset_days: i64,
+    #[serde(default)]
+    pub milestones: Vec<MilestoneTemplate>,
+}
+
+/// Milestone section of a template
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MilestoneTemplate {
+    /// Template-local key that tasks use to reference this milestone
+    pub key: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub offset_days: i64,
+}
+
+/// Task section of a template
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TaskTemplate {
+    pub title: String,
+    pub description: Option<String>,
+    pub priority: Option<String>,
+    pub task_type: Option<String>,
+    pub assigned_to: Option<String>,
+    pub estimated_hours: Option<f64>,
+    pub due_offset_days: Option<i64>,
+    pub tags: Option<Vec<String>>,
+    /// Key of the milestone this task belongs to
+    pub milestone: Option<String>,
+}
+
+impl ProjectTemplate {
+    /// Validate the template, reporting every problem found
+    pub fn validate(&self) -> Result<(), String> {
+        let mut errors = Vec::new();
+
+        if self.project.name.trim().is_empty() {
+            errors.push("project.name cannot be empty".to_string());
+        }
+        if let Some(ref priority) = self.project.priority {
+            if let Err(e) = priority.parse::<Priority>() {
+                errors.push(format!("project.priority: {}", e));
+            }
+        }
+        if self.project.duration_days.is_some_and(|d| d < 0) {
+            errors.push("project.duration_days cannot be negative".to_string());
+        }
+
+        let mut milestone_keys = HashSet::new();
+        for (i, timeline) in self.timelines.iter().enumerate() {
+            let at = format!("timelines[{}]", i);
+            if timeline.name.trim().is_empty() {
+                errors.push(format!("{}.name cannot be empty", at));
+            }
+            if let Some(ref timeline_type) = timeline.timeline_type {
+                if let Err(e) = timeline_type.parse::<TimelineType>() {
+                    errors.push(format!("{}.timeline_type: {}", at, e));
+                }
+            }
+            if timeline.start_offset_days < 0 {
+                errors.push(format!("{}.start_offset_days cannot be negative", at));
+            }
+            if timeline.end_offset_days < timeline.start_offset_days {
+                errors.push(format!(
+                    "{}.end_offset_days must not be before start_offset_days",
+                    at
+                ));
+            }
+
+            for (j, milestone) in timeline.milestones.iter().enumerate() {
+                let at = format!("{}.milestones[{}]", at, j);
+                if milestone.name.trim().is_empty() {
+                    errors.push(format!("{}.name cannot be empty", at));
+                }
+                if milestone.offset_days < 0 {
+                    errors.push(format!("{}.offset_days cannot be negative", at));
+                }
+                if !milestone_keys.insert(milestone.key.as_str()) {
+                    errors.push(format!("{}.key '{}' is used twice", at, milestone.key));
+                }
+            }
+        }
+
+        for (i, task) in self.tasks.iter().enumerate() {
+            let at = format!("tasks[{}]", i);
+            if task.title.trim().is_empty() {
+                errors.push(format!("{}.title cannot be empty", at));
+            }
+            if let Some(ref priority) = task.priority {
+                if let Err(e) = priority.parse::<TaskPriority>() {
+                    errors.push(format!("{}.priority: {}", at, e));
+                }
+            }
+            if let Some(ref task_type) = task.task_type {
+                if let Err(e) = task_type.parse::<TaskType>() {
+                    errors.push(format!("{}.task_type: {}", at, e));
+                }
+            }
+            if task.estimated_hours.is_some_and(|h| h < 0.0) {
+                errors.push(format!("{}.estimated_hours cannot be negative", at));
+            }
+            if task.due_offset_days.is_some_and(|d| d < 0) {
+                errors.push(format!("{}.due_offset_days cannot be negative", at));
+            }
+            if let Some(ref key) = task.milestone {
+                if !milestone_keys.contains(key.as_str()) {
+                    errors.push(format!("{}.milestone '{}' is not defined", at, key));
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(format!("Invalid template: {}", errors.join("; ")))
+        }
+    }
+
+    /// Capture the structure of an existing project as a template
+    ///
+    /// Offsets are measured from the earliest of the project's start date and
+    /// its timeline starts (the creation date if neither is set); anything
+    /// earlier is clamped to day 0. The project code, task status, logged hours
+    /// and subtask nesting are not carried over.
+    pub fn capture(source: &TemplateSource) -> Self {
+        let project = &source.project;
+        let anchor = source.anchor_date();
+        let offset = |date: NaiveDate| (date - anchor).num_days().max(0);
+
+        let milestone_keys: HashMap<i64, String> = source
+            .milestones
+            .iter()
+            .map(|m| (m.id, format!("m{}", m.id)))
+            .collect();
+
+        let timelines = source
+            .timelines
+            .iter()
+            .map(|t| TimelineTemplate {
+                name: t.name.clone(),
+                description: t.description.clone(),
+                timeline_type: Some(t.timeline_type.as_str().to_string()),
+                start_offset_days: offset(t.start_date),
+                end_offset_days: offset(t.end_date),
+                milestones: source
+                    .milestones
+                    .iter()
+                    .filter(|m| m.timeline_id == t.id)
+                    .map(|m| MilestoneTemplate {
+                        key: milestone_keys[&m.id].clone(),
+                        name: m.name.clone(),
+                        description: m.description.clone(),
+                        offset_days: offset(m.target_date),
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        let tasks = source
+            .tasks
+            .iter()
+            .map(|t| TaskTemplate {
+                title: t.title.clone(),
+                description: t.description.clone(),
+                priority: Some(t.priority.to_string()),
+                task_type: t.task_type.as_ref().map(|v| v.to_string()),
+                assigned_to: t.assigned_to.clone(),
+                estimated_hours: t.estimated_hours,
+                due_offset_days: t.due_date.map(|d| offset(d.date_naive())),
+                tags: t.tags.clone(),
+                milestone: t
+                    .milestone_id
+                    .and_then(|id| milestone_keys.get(&id).cloned()),
+            })
+            .collect();
+
+        Self {
+            project: ProjectTemplateInfo {
+                name: project.name.clone(),
+                description: project.description.clone(),
+                code: None,
+                priority: Some(project.priority.as_str().to_string()),
+                duration_days: project.end_date.map(offset),
+                tags: project.tags.clone(),
+            },
+            timelines,
+            tasks,
+        }
+    }
+
+    /// Turn the template into concrete create inputs anchored at `start`
+    ///
+    /// The template must already be valid. Project, timeline and milestone ids
+    /// in the returned inputs are placeholders filled in when the plan is applied.
+    pub fn plan(&self, start: NaiveDate) -> TemplatePlan {
+        let day = |offset: i64| start + Duration::days(offset);
+
+        let end_date = match self.project.duration_days {
+            Some(days) => Some(day(days)),
+            None => self.timelines.iter().map(|t| day(t.end_offset_days)).max(),
+        };
+
+        let project = CreateProject {
+            name: self.project.name.clone(),
+            description: self.project.description.clone(),
+            code: self.project.code.clone(),
+            status: None,
+            priority: self.project.priority.as_ref().and_then(|p| p.parse().ok()),
+            start_date: Some(start),
+            end_date,
+            budget: None,
+            currency: None,
+            repository_url: None,
+            repository_branch: None,
+            tags: self.project.tags.clone(),
+            metadata: None,
+        };
+
+        let timelines = self
+            .timelines
+            .iter()
+            .map(|t| PlannedTimeline {
+                timeline: CreateTimeline {
+                    project_id: 0,
+                    name: t.name.clone(),
+                    description: t.description.clone(),
+                    timeline_type: t.timeline_type.as_ref().and_then(|v| v.parse().ok()),
+                    start_date: day(t.start_offset_days),
+                    end_date: day(t.end_offset_days),
+                    status: None,
+                },
+                milestones: t
+                    .milestones
+                    .iter()
+                    .map(|m| PlannedMilestone {
+                        key: m.key.clone(),
+                        milestone: CreateMilestone {
+                            timeline_id: 0,
+                            project_id: 0,
+                            name: m.name.clone(),
+                            description: m.description.clone(),
+                            target_date: day(m.offset_days),
+                            status: None,
+                            completion_percentage: None,
+                            metadata: None,
+                            task_ids: Vec::new(),
+                        },
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        let tasks = self
+            .tasks
+            .iter()
+            .map(|t| PlannedTask {
+                task: CreateTask {
+                    project_id: 0,
+                    parent_task_id: None,
+                    title: t.title.clone(),
+                    description: t.description.clone(),
+                    task_number: None,
+                    status: None,
+                    blocked_reason: None,
+                    priority: t.priority.as_ref().and_then(|p| p.parse().ok()),
+                    assigned_to: t.assigned_to.clone(),
+                    estimated_hours: t.estimated_hours,
+                    due_date: t.due_offset_days.map(|offset| {
+                        Utc.from_utc_datetime(&day(offset).and_hms_opt(0, 0, 0).unwrap())
+                    }),
+                    task_type: t.task_type.as_ref().and_then(|v| v.parse().ok()),
+                    tags: t.tags.clone(),
+                },
+                milestone_key: t.milestone.clone(),
+            })
+            .collect();
+
+        TemplatePlan {
+            project,
+            timelines,
+            tasks,
+        }
+    }
+}
+
+/// Concrete inputs produced from a template, ready to be created together
+#[derive(Debug, Clone)]
+pub struct TemplatePlan {
+    pub project: CreateProject,
+    pub timelines: Vec<PlannedTimeline>,
+    pub tasks: Vec<PlannedTask>,
+}
+
+/// A timeline to create along with its milestones
+#[derive(Debug, Clone)]
+pub struct PlannedTimeline {
+    pub timeline: CreateTimeline,
+    pub milestones: Vec<PlannedMilestone>,
+}
+
+/// A milestone to create, addressable by its template key
+#[derive(Debug, Clone)]
+pub struct PlannedMilestone {
+    pub key: String,
+    pub milestone: CreateMilestone,
+}
+
+/// A task to create, optionally linked to a planned milestone
+#[derive(Debug, Clone)]
+pub struct PlannedTask {
+    pub task: CreateTask,
+    pub milestone_key: Option<String>,
+}
+
+/// Everything created by applying a template
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AppliedTemplate {
+    pub project: Project,
+    pub timelines: Vec<Timeline>,
+    pub milestones: Vec<Milestone>,
+    pub tasks: Vec<Task>,
+}
+
+/// The live entities a template is captured from
+#[derive(Debug, Clone)]
+pub struct TemplateSource {
+    pub project: Project,
+    pub timelines: Vec<Timeline>,
+    pub milestones: Vec<Milestone>,
+    pub tasks: Vec<Task>,
+}
+
+impl TemplateSource {
+    /// Earliest of the project's start date and its timeline starts
+    ///
+    /// Falls back to the creation date when neither is set.
+    pub fn anchor_date(&self) -> NaiveDate {
+        self.project
+            .start_date
+            .into_iter()
+            .chain(self.timelines.iter().map(|t| t.start_date))
+            .min()
+            .unwrap_or_else(|| self.project.created_at.date_naive())
+    }
+
+    /// Plan a copy of the project with every date moved so it begins at `start`
+    ///
+    /// The copy starts in planning with no progress or actual dates. Timelines
+    /// and their milestones come along only with `with_timelines`; tasks only
+    /// with `with_tasks`, as unassigned `todo` skeletons without logged hours
+    /// or subtask nesting. A task keeps its milestone only when timelines are
+    /// copied too.
+    pub fn clone_plan(
+        &self,
+        name: String,
+        code: Option<String>,
+        start: NaiveDate,
+        with_timelines: bool,
+        with_tasks: bool,
+    ) -> TemplatePlan {
+        let shift = start - self.anchor_date();
+        let project = &self.project;
+
+        let milestone_key = |id: i64| format!("m{}", id);
+        let timelines = if with_timelines {
+            self.timelines
+                .iter()
+                .map(|t| PlannedTimeline {
+                    timeline: CreateTimeline {
+                        project_id: 0,
+                        name: t.name.clone(),
+                        description: t.description.clone(),
+                        timeline_type: Some(t.timeline_type.clone()),
+                        start_date: t.start_date + shift,
+                        end_date: t.end_date + shift,
+                        status: None,
+                    },
+                    milestones: self
+                        .milestones
+                        .iter()
+                        .filter(|m| m.timeline_id == t.id)
+                        .map(|m| PlannedMilestone {
+                            key: milestone_key(m.id),
+                            milestone: CreateMilestone {
+                                timeline_id: 0,
+                                project_id: 0,
+                                name: m.name.clone(),
+                                description: m.description.clone(),
+                                target_date: m.target_date + shift,
+                                status: None,
+                                completion_percentage: None,
+                                metadata: m.metadata.clone(),
+                                task_ids: Vec::new(),
+                            },
+                        })
+                        .collect(),
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        let tasks = if with_tasks {
+            self.tasks
+                .iter()
+                .map(|t| PlannedTask {
+                    task: CreateTask {
+                        project_id: 0,
+                        parent_task_id: None,
+                        title: t.title.clone(),
+                        description: t.description.clone(),
+                        task_number: None,
+                        status: None,
+                        blocked_reason: None,
+                        priority: Some(t.priority.clone()),
+                        assigned_to: None,
+                        estimated_hours: t.estimated_hours,
+                        due_date: t.due_date.map(|due| due + shift),
+                        task_type: t.task_type.clone(),
+                        tags: t.tags.clone(),
+                    },
+                    milestone_key: t.milestone_id.filter(|_| with_timelines).map(milestone_key),
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        TemplatePlan {
+            project: CreateProject {
+                name,
+                description: project.description.clone(),
+                code,
+                status: None,
+                priority: Some(project.priority.clone()),
+                start_date: Some(start),
+                end_date: project.end_date.map(|end| end + shift),
+                budget: None,
+                currency: None,
+                repository_url: project.repository_url.clone(),
+                repository_branch: project.repository_branch.clone(),
+                tags: project.tags.clone(),
+                metadata: project.metadata.clone(),
+            },
+            timelines,
+            tasks,
+        }
+    }
+}
+
+/// A template saved in the database under a name and version
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StoredTemplate {
+    pub id: i64,
+    pub name: String,
+    pub version: i32,
+    pub source_project_id: Option<i64>,
+    pub template: ProjectTemplate,
+    pub created_at: DateTime<Utc>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_template() -> ProjectTemplate {
+        serde_json::from_str(
+            r#"{
+                "project": { "name": "Service Skeleton", "priority": "high" },
+                "timelines": [{
+                    "name": "Delivery",
+                    "start_offset_days": 0,
+                    "end_offset_days": 30,
+                    "milestones": [{ "key": "beta", "name": "Beta", "offset_days": 20 }]
+                }],
+                "tasks": [
+                    { "title": "Design API", "due_offset_days": 5, "milestone": "beta" },
+                    { "title": "Write docs" }
+                ]
+            }"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_template_plan_offsets_dates() {
+        let template = sample_template();
+        assert!(template.validate().is_ok());
+
+        let start = NaiveDate::from_ymd_opt(2025, 3, 1).unwrap();
+        let plan = template.plan(start);
+
+        assert_eq!(plan.project.start_date, Some(start));
+        assert_eq!(plan.project.end_date, NaiveDate::from_ymd_opt(2025, 3, 31));
+        assert_eq!(
+            plan.timelines[0].milestones[0].milestone.target_date,
+            NaiveDate::from_ymd_opt(2025, 3, 21).unwrap()
+        );
+        assert_eq!(
+            plan.tasks[0].task.due_date.unwrap().date_naive(),
+            NaiveDate::from_ymd_opt(2025, 3, 6).unwrap()
+        );
+        assert_eq!(plan.tasks[0].milestone_key.as_deref(), Some("beta"));
+    }
+
+    #[test]
+    fn test_template_validation_reports_bad_references() {
+        let mut template = sample_template();
+        template.tasks[1].milestone = Some("ga".to_string());
+        template.tasks[1].priority = Some("urgent".to_string());
+
+        let err = template.validate().unwrap_err();
+        assert!(err.contains("tasks[1].milestone 'ga' is not defined"));
+        assert!(err.contains("tasks[1].priority"));
+    }
+
+    #[test]
+    fn test_template_rejects_unknown_fields() {
+        let result = serde_json::from_str::<ProjectTemplate>(
+            r#"{ "project": { "name": "X", "owner": "me" } }"#,
+        );
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,7 @@
+pub mod entity;
+pub mod repository;
+pub mod service;
+
+pub use entity::{AppliedTemplate, ProjectTemplate, StoredTemplate, TemplatePlan, TemplateSource};
+pub use repository::TemplateRepository;
+pub use service::TemplateService;
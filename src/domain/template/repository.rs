@@ -0,0 +1,44 @@
+// Template repository trait
+
+use async_trait::async_trait;
+
+use super::entity::{
+    AppliedTemplate, ProjectTemplate, StoredTemplate, TemplatePlan, TemplateSource,
+};
+use crate::utils::error::DevErpError;
+
+/// Persistence for project templates
+#[async_trait]
+pub trait TemplateRepository: Send + Sync {
+    /// Create the project, timelines, milestones and tasks of a plan
+    ///
+    /// Implementations must apply the whole plan atomically: either every
+    /// entity is created or none is.
+    async fn apply(&self, plan: TemplatePlan) -> Result<AppliedTemplate, DevErpError>;
+
+    /// Load a project with its non-deleted timelines, milestones and tasks
+    ///
+    /// # Returns
+    /// * `Ok(None)` - The project does not exist or is deleted
+    async fn load_source(&self, project_id: i64) -> Result<Option<TemplateSource>, DevErpError>;
+
+    /// Store a template as the next version of `name`
+    ///
+    /// The first save of a name is version 1.
+    async fn save(
+        &self,
+        name: &str,
+        source_project_id: Option<i64>,
+        template: &ProjectTemplate,
+    ) -> Result<StoredTemplate, DevErpError>;
+
+    /// Find a stored template by name, at `version` or the latest one
+    async fn find_stored(
+        &self,
+        name: &str,
+        version: Option<i32>,
+    ) -> Result<Option<StoredTemplate>, DevErpError>;
+
+    /// List every stored template version, by name then newest version first
+    async fn list_stored(&self) -> Result<Vec<StoredTemplate>, DevErpError>;
+}
@@ -0,0 +1,168 @@
+// Template service with business logic
+
+use chrono::NaiveDate;
+use std::path::Path;
+use std::sync::Arc;
+use tracing::{debug, info};
+
+use super::entity::{AppliedTemplate, ProjectTemplate, StoredTemplate};
+use super::repository::TemplateRepository;
+use crate::utils::error::DevErpError;
+
+/// Template service for bootstrapping projects from JSON or stored templates
+pub struct TemplateService {
+    repository: Arc<dyn TemplateRepository>,
+}
+
+impl TemplateService {
+    /// Create a new template service
+    ///
+    /// # Arguments
+    /// * `repository` - The template repository implementation
+    pub fn new(repository: Arc<dyn TemplateRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// Read and validate a template file
+    ///
+    /// # Returns
+    /// * `Ok(ProjectTemplate)` - The parsed, schema-checked template
+    /// * `Err(DevErpError)` - I/O error or validation error describing the problem
+    pub fn load_template(&self, path: &Path) -> Result<ProjectTemplate, DevErpError> {
+        debug!("Service: Loading template from {}", path.display());
+
+        let content = std::fs::read_to_string(path)?;
+        let template: ProjectTemplate = serde_json::from_str(&content).map_err(|e| {
+            DevErpError::Validation(format!("Invalid template {}: {}", path.display(), e))
+        })?;
+        template.validate().map_err(DevErpError::Validation)?;
+
+        Ok(template)
+    }
+
+    /// Create a project and its seed entities from a template
+    ///
+    /// # Arguments
+    /// * `template` - The template to apply
+    /// * `start` - Date that all template offsets are relative to
+    /// * `name` - Optional project name overriding the template's
+    /// * `code` - Optional project code overriding the template's
+    ///
+    /// # Business Rules
+    /// - The template is validated before anything is written
+    /// - Everything is created in a single transaction
+    pub async fn apply_template(
+        &self,
+        template: &ProjectTemplate,
+        start: NaiveDate,
+        name: Option<String>,
+        code: Option<String>,
+    ) -> Result<AppliedTemplate, DevErpError> {
+        template.validate().map_err(DevErpError::Validation)?;
+
+        let mut plan = template.plan(start);
+        if let Some(name) = name {
+            plan.project.name = name;
+        }
+        if code.is_some() {
+            plan.project.code = code;
+        }
+
+        debug!(
+            "Service: Applying template '{}' starting {}",
+            plan.project.name, start
+        );
+
+        let applied = self.repository.apply(plan).await?;
+
+        info!(
+            project_id = applied.project.id,
+            timelines = applied.timelines.len(),
+            milestones = applied.milestones.len(),
+            tasks = applied.tasks.len(),
+            "Template applied"
+        );
+
+        Ok(applied)
+    }
+
+    /// Capture a project's structure and store it as the next version of `name`
+    ///
+    /// # Arguments
+    /// * `project_id` - The project to capture
+    /// * `name` - Template name; saving an existing name adds a new version
+    ///
+    /// # Returns
+    /// * `Ok(StoredTemplate)` - The stored template with its version
+    /// * `Err(DevErpError::NotFound)` - The project does not exist
+    pub async fn save_template(
+        &self,
+        project_id: i64,
+        name: &str,
+    ) -> Result<StoredTemplate, DevErpError> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(DevErpError::Validation(
+                "Template name cannot be empty".to_string(),
+            ));
+        }
+        if name.len() > 100 {
+            return Err(DevErpError::Validation(
+                "Template name cannot exceed 100 characters".to_string(),
+            ));
+        }
+
+        debug!(
+            "Service: Saving project {} as template '{}'",
+            project_id, name
+        );
+
+        let source = self
+            .repository
+            .load_source(project_id)
+            .await?
+            .ok_or_else(|| {
+                DevErpError::NotFound(format!("Project with id {} not found", project_id))
+            })?;
+
+        let template = ProjectTemplate::capture(&source);
+        template.validate().map_err(DevErpError::Validation)?;
+
+        let stored = self
+            .repository
+            .save(name, Some(project_id), &template)
+            .await?;
+
+        info!(
+            template = %stored.name,
+            version = stored.version,
+            "Template saved"
+        );
+
+        Ok(stored)
+    }
+
+    /// Get a stored template by name
+    ///
+    /// # Arguments
+    /// * `name` - Template name
+    /// * `version` - A specific version, or `None` for the latest
+    pub async fn get_stored_template(
+        &self,
+        name: &str,
+        version: Option<i32>,
+    ) -> Result<StoredTemplate, DevErpError> {
+        self.repository
+            .find_stored(name, version)
+            .await?
+            .ok_or_else(|| match version {
+                Some(v) => DevErpError::NotFound(format!("Template '{}' v{} not found", name, v)),
+                None => DevErpError::NotFound(format!("Template '{}' not found", name)),
+            })
+    }
+
+    /// List every stored template version
+    pub async fn list_stored_templates(&self) -> Result<Vec<StoredTemplate>, DevErpError> {
+        self.repository.list_stored().await
+    }
+}
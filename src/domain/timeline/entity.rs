@@ -145,6 +145,9 @@ pub struct Milestone {
 
     pub completion_percentage: i32,
 
+    /// Position within the timeline; breaks ties between equal target dates
+    pub sequence: i32,
+
     pub metadata: Option<sqlx::types::JsonValue>,
 
     pub created_at: DateTime<Utc>,
@@ -154,10 +157,11 @@ pub struct Milestone {
 
 /// Milestone status enum
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
-#[serde(rename_all = "lowercase")]
+#[serde(rename_all = "snake_case")]
 #[sqlx(type_name = "varchar", rename_all = "snake_case")]
 pub enum MilestoneStatus {
     Pending,
+    #[serde(alias = "inprogress")]
     InProgress,
     Completed,
     Missed,
@@ -279,6 +283,9 @@ pub struct CreateMilestone {
     pub status: Option<MilestoneStatus>,
     pub completion_percentage: Option<i32>,
     pub metadata: Option<sqlx::types::JsonValue>,
+    /// Tasks to link to the milestone (must belong to the same project)
+    #[serde(default)]
+    pub task_ids: Vec<i64>,
 }
 
 impl CreateMilestone {
@@ -348,6 +355,9 @@ pub struct TimelineFilter {
     pub project_id: Option<i64>,
     pub timeline_type: Option<TimelineType>,
     pub status: Option<TimelineStatus>,
+    /// Include soft-deleted timelines; they are excluded by default
+    #[serde(default)]
+    pub include_deleted: bool,
     pub offset: Option<i64>,
     pub limit: Option<i64>,
 }
@@ -370,6 +380,11 @@ pub struct MilestoneFilter {
     pub timeline_id: Option<i64>,
     pub project_id: Option<i64>,
     pub status: Option<MilestoneStatus>,
+    /// Only pending/in-progress milestones whose target date is before this day
+    pub overdue_as_of: Option<NaiveDate>,
+    /// Include soft-deleted milestones; they are excluded by default
+    #[serde(default)]
+    pub include_deleted: bool,
     pub offset: Option<i64>,
     pub limit: Option<i64>,
 }
@@ -386,6 +401,124 @@ impl MilestoneFilter {
     }
 }
 
+/// A timeline with its milestones in sequence order
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimelineDetail {
+    pub timeline: Timeline,
+    pub milestones: Vec<Milestone>,
+}
+
+/// Linked task totals for a single milestone
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct MilestoneTaskCount {
+    pub milestone_id: i64,
+    /// Linked tasks, excluding cancelled ones
+    pub total_tasks: i64,
+    pub done_tasks: i64,
+}
+
+/// Effective completion of one milestone within a timeline
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MilestoneProgress {
+    pub milestone_id: i64,
+    pub name: String,
+    pub status: MilestoneStatus,
+    pub target_date: NaiveDate,
+    pub completion_percentage: f64,
+    /// Whether the percentage comes from linked tasks rather than the milestone itself
+    pub task_derived: bool,
+}
+
+/// Outcome of moving a timeline and its milestones by a number of days
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimelineShift {
+    pub timeline: Timeline,
+    pub days: i64,
+    pub milestones_moved: u64,
+}
+
+/// Completion summary for a single timeline
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimelineProgress {
+    pub timeline_id: i64,
+    pub timeline_name: String,
+    /// Average completion across non-cancelled milestones
+    pub completion_percentage: f64,
+    pub completed_milestones: usize,
+    pub missed_milestones: usize,
+    pub upcoming_milestones: usize,
+    pub milestones: Vec<MilestoneProgress>,
+}
+
+impl TimelineProgress {
+    /// Summarize a timeline from its milestones as of `today`
+    ///
+    /// Milestones with linked (non-cancelled) tasks use the share of done tasks;
+    /// all others use their own `completion_percentage`. A milestone counts as
+    /// missed when marked so, or when its target date has passed unfinished.
+    pub fn compute(
+        timeline: &Timeline,
+        milestones: &[Milestone],
+        task_counts: &[MilestoneTaskCount],
+        today: NaiveDate,
+    ) -> Self {
+        let mut progress = Self {
+            timeline_id: timeline.id,
+            timeline_name: timeline.name.clone(),
+            completion_percentage: 0.0,
+            completed_milestones: 0,
+            missed_milestones: 0,
+            upcoming_milestones: 0,
+            milestones: Vec::with_capacity(milestones.len()),
+        };
+
+        for milestone in milestones {
+            let counts = task_counts
+                .iter()
+                .find(|c| c.milestone_id == milestone.id && c.total_tasks > 0);
+
+            let (completion_percentage, task_derived) = match counts {
+                Some(c) => ((c.done_tasks as f64 / c.total_tasks as f64) * 100.0, true),
+                None => (milestone.completion_percentage as f64, false),
+            };
+
+            match milestone.status {
+                MilestoneStatus::Completed => progress.completed_milestones += 1,
+                MilestoneStatus::Missed => progress.missed_milestones += 1,
+                MilestoneStatus::Cancelled => {}
+                MilestoneStatus::Pending | MilestoneStatus::InProgress => {
+                    if milestone.target_date < today {
+                        progress.missed_milestones += 1;
+                    } else {
+                        progress.upcoming_milestones += 1;
+                    }
+                }
+            }
+
+            progress.milestones.push(MilestoneProgress {
+                milestone_id: milestone.id,
+                name: milestone.name.clone(),
+                status: milestone.status.clone(),
+                target_date: milestone.target_date,
+                completion_percentage,
+                task_derived,
+            });
+        }
+
+        let counted: Vec<f64> = progress
+            .milestones
+            .iter()
+            .filter(|m| m.status != MilestoneStatus::Cancelled)
+            .map(|m| m.completion_percentage)
+            .collect();
+        if !counted.is_empty() {
+            progress.completion_percentage = counted.iter().sum::<f64>() / counted.len() as f64;
+        }
+
+        progress
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -497,6 +630,7 @@ mod tests {
             status: None,
             completion_percentage: Some(50),
             metadata: None,
+            task_ids: Vec::new(),
         };
         assert!(valid.validate().is_ok());
 
@@ -1,10 +1,11 @@
 // Timeline repository trait
 
 use async_trait::async_trait;
+use chrono::NaiveDate;
 
 use super::entity::{
-    CreateMilestone, CreateTimeline, Milestone, MilestoneFilter, Timeline, TimelineFilter,
-    UpdateMilestone, UpdateTimeline,
+    CreateMilestone, CreateTimeline, Milestone, MilestoneFilter, MilestoneTaskCount, Timeline,
+    TimelineFilter, UpdateMilestone, UpdateTimeline,
 };
 use crate::utils::error::DevErpError;
 
@@ -57,7 +58,8 @@ pub trait TimelineRepository: Send + Sync {
     /// Count timelines matching the filter criteria
     ///
     /// # Arguments
-    /// * `filter` - Filter options (pagination is ignored)
+    /// * `filter` - Filter options (pagination is ignored); soft-deleted
+    ///   timelines are only counted when `include_deleted` is set
     ///
     /// # Returns
     /// * `Ok(i64)` - Number of matching timelines
@@ -103,6 +105,18 @@ pub trait TimelineRepository: Send + Sync {
     /// * `Ok(bool)` - true if timeline was restored, false if not found
     /// * `Err(DevErpError)` - Database error
     async fn restore(&self, id: i64) -> Result<bool, DevErpError>;
+
+    /// Move a timeline's dates and its milestones' dates by `days`, in one transaction
+    ///
+    /// # Arguments
+    /// * `id` - The internal timeline ID
+    /// * `days` - Days to add; negative moves dates earlier
+    ///
+    /// # Returns
+    /// * `Ok(u64)` - Number of milestones moved
+    /// * `Err(DevErpError::NotFound)` - If the timeline doesn't exist
+    /// * `Err(DevErpError)` - Database error
+    async fn shift_dates(&self, id: i64, days: i32) -> Result<u64, DevErpError>;
 }
 
 /// Repository trait for milestone data access
@@ -141,7 +155,7 @@ pub trait MilestoneRepository: Send + Sync {
     /// * `Err(DevErpError)` - Database error
     async fn find_all(&self, filter: MilestoneFilter) -> Result<Vec<Milestone>, DevErpError>;
 
-    /// Find all milestones for a specific timeline
+    /// Find all milestones for a specific timeline, in sequence order
     ///
     /// # Arguments
     /// * `timeline_id` - The timeline ID
@@ -164,7 +178,8 @@ pub trait MilestoneRepository: Send + Sync {
     /// Count milestones matching the filter criteria
     ///
     /// # Arguments
-    /// * `filter` - Filter options (pagination is ignored)
+    /// * `filter` - Filter options (pagination is ignored); soft-deleted
+    ///   milestones are only counted when `include_deleted` is set
     ///
     /// # Returns
     /// * `Ok(i64)` - Number of matching milestones
@@ -210,6 +225,48 @@ pub trait MilestoneRepository: Send + Sync {
     /// * `Ok(bool)` - true if milestone was restored, false if not found
     /// * `Err(DevErpError)` - Database error
     async fn restore(&self, id: i64) -> Result<bool, DevErpError>;
+
+    /// Count linked tasks per milestone of a timeline
+    ///
+    /// # Arguments
+    /// * `timeline_id` - The timeline whose milestones to inspect
+    ///
+    /// # Returns
+    /// * `Ok(Vec<MilestoneTaskCount>)` - One entry per milestone with linked tasks
+    /// * `Err(DevErpError)` - Database error
+    async fn task_counts_by_timeline(
+        &self,
+        timeline_id: i64,
+    ) -> Result<Vec<MilestoneTaskCount>, DevErpError>;
+
+    /// Mark pending/in-progress milestones whose target date is before `today` as missed
+    ///
+    /// # Arguments
+    /// * `project_id` - Limit the scan to one project (all projects if `None`)
+    /// * `timeline_id` - Limit the scan to one timeline (all timelines if `None`)
+    /// * `today` - The reference date
+    ///
+    /// # Returns
+    /// * `Ok(Vec<Milestone>)` - The milestones that were transitioned
+    /// * `Err(DevErpError)` - Database error
+    async fn mark_overdue_missed(
+        &self,
+        project_id: Option<i64>,
+        timeline_id: Option<i64>,
+        today: NaiveDate,
+    ) -> Result<Vec<Milestone>, DevErpError>;
+
+    /// Renumber a timeline's milestones in the given order, in one transaction
+    ///
+    /// # Arguments
+    /// * `timeline_id` - The timeline ID
+    /// * `ordered_ids` - Every live milestone of the timeline, first to last
+    ///
+    /// # Returns
+    /// * `Ok(())` - Sequence numbers rewritten as 1, 2, 3, ...
+    /// * `Err(DevErpError::Validation)` - If the IDs are not exactly the timeline's milestones
+    /// * `Err(DevErpError)` - Database error
+    async fn reorder(&self, timeline_id: i64, ordered_ids: &[i64]) -> Result<(), DevErpError>;
 }
 
 #[cfg(test)]
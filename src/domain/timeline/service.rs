@@ -1,13 +1,17 @@
 // Timeline service with business logic
 
+use chrono::{Duration, NaiveDate, Utc};
 use std::sync::Arc;
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
 
 use super::entity::{
-    CreateMilestone, CreateTimeline, Milestone, MilestoneFilter, Timeline, TimelineFilter,
-    UpdateMilestone, UpdateTimeline,
+    CreateMilestone, CreateTimeline, Milestone, MilestoneFilter, MilestoneStatus,
+    MilestoneTaskCount, Timeline, TimelineFilter, TimelineProgress, TimelineShift, UpdateMilestone,
+    UpdateTimeline,
 };
 use super::repository::{MilestoneRepository, TimelineRepository};
+use crate::domain::task::entity::{CompletionCheck, Task, TaskFilter};
+use crate::domain::task::repository::TaskRepository;
 use crate::utils::error::DevErpError;
 
 /// Timeline service containing business logic
@@ -21,6 +25,10 @@ use crate::utils::error::DevErpError;
 pub struct TimelineService {
     timeline_repository: Arc<dyn TimelineRepository>,
     milestone_repository: Arc<dyn MilestoneRepository>,
+    /// Needed only to list a milestone's linked tasks
+    task_repository: Arc<dyn TaskRepository>,
+    /// Earliest date `shift_timeline` may move anything to
+    date_floor: Option<NaiveDate>,
 }
 
 impl TimelineService {
@@ -29,16 +37,26 @@ impl TimelineService {
     /// # Arguments
     /// * `timeline_repository` - The timeline repository implementation
     /// * `milestone_repository` - The milestone repository implementation
+    /// * `task_repository` - The task repository implementation
     pub fn new(
         timeline_repository: Arc<dyn TimelineRepository>,
         milestone_repository: Arc<dyn MilestoneRepository>,
+        task_repository: Arc<dyn TaskRepository>,
     ) -> Self {
         Self {
             timeline_repository,
             milestone_repository,
+            task_repository,
+            date_floor: None,
         }
     }
 
+    /// Refuse shifts that would move any date before `floor`
+    pub fn with_date_floor(mut self, floor: Option<NaiveDate>) -> Self {
+        self.date_floor = floor;
+        self
+    }
+
     // ========== Timeline Operations ==========
 
     /// Create a new timeline
@@ -206,6 +224,105 @@ impl TimelineService {
         Ok(())
     }
 
+    /// Compute completion of a single timeline from its milestones
+    ///
+    /// # Arguments
+    /// * `id` - The timeline ID
+    ///
+    /// # Returns
+    /// * `Ok(TimelineProgress)` - Average completion plus milestone counts
+    /// * `Err(DevErpError::NotFound)` - If timeline doesn't exist
+    pub async fn progress(&self, id: i64) -> Result<TimelineProgress, DevErpError> {
+        debug!("Service: Computing progress for timeline {}", id);
+
+        let timeline = self.get_timeline(id).await?;
+        let milestones = self.milestone_repository.find_by_timeline(id).await?;
+        let task_counts = self
+            .milestone_repository
+            .task_counts_by_timeline(id)
+            .await?;
+
+        Ok(TimelineProgress::compute(
+            &timeline,
+            &milestones,
+            &task_counts,
+            Utc::now().date_naive(),
+        ))
+    }
+
+    /// Move a timeline and all its milestones by a number of days
+    ///
+    /// # Arguments
+    /// * `timeline_id` - The timeline ID
+    /// * `days` - Days to add; negative pulls the schedule in
+    ///
+    /// # Returns
+    /// * `Ok(TimelineShift)` - The moved timeline and how many milestones moved with it
+    /// * `Err(DevErpError::NotFound)` - If timeline doesn't exist
+    /// * `Err(DevErpError::Validation)` - If a shifted date would fall before the floor
+    ///
+    /// # Business Rules
+    /// - Start, end, milestone target dates and any recorded actual dates all move
+    /// - `days` must not be zero
+    /// - Nothing moves unless every shifted date is on or after the configured floor
+    pub async fn shift_timeline(
+        &self,
+        timeline_id: i64,
+        days: i64,
+    ) -> Result<TimelineShift, DevErpError> {
+        debug!(
+            "Service: Shifting timeline {} by {} day(s)",
+            timeline_id, days
+        );
+
+        if days == 0 {
+            return Err(DevErpError::Validation(
+                "Shift must move dates by at least one day".to_string(),
+            ));
+        }
+
+        let timeline = self.get_timeline(timeline_id).await?;
+        let milestones = self
+            .milestone_repository
+            .find_by_timeline(timeline_id)
+            .await?;
+
+        let dates = [timeline.start_date, timeline.end_date].into_iter().chain(
+            milestones
+                .iter()
+                .flat_map(|m| std::iter::once(m.target_date).chain(m.actual_date)),
+        );
+        let earliest = dates.clone().min().expect("timeline has a start date");
+        let latest = dates.max().expect("timeline has an end date");
+
+        let out_of_range =
+            || DevErpError::Validation(format!("Shifting by {} day(s) is out of range", days));
+        let offset = i32::try_from(days).map_err(|_| out_of_range())?;
+        let shift = |date: NaiveDate| date.checked_add_signed(Duration::days(days));
+        let new_earliest = shift(earliest).ok_or_else(out_of_range)?;
+        shift(latest).ok_or_else(out_of_range)?;
+
+        if let Some(floor) = self.date_floor.filter(|&floor| new_earliest < floor) {
+            return Err(DevErpError::Validation(format!(
+                "Shifting by {} day(s) would move {} to {}, before the earliest allowed date {}",
+                days, earliest, new_earliest, floor
+            )));
+        }
+
+        let milestones_moved = self
+            .timeline_repository
+            .shift_dates(timeline_id, offset)
+            .await?;
+
+        info!(timeline_id = %timeline_id, days, milestones_moved, "Timeline shifted");
+
+        Ok(TimelineShift {
+            timeline: self.get_timeline(timeline_id).await?,
+            days,
+            milestones_moved,
+        })
+    }
+
     // ========== Milestone Operations ==========
 
     /// Create a new milestone
@@ -238,6 +355,8 @@ impl TimelineService {
             );
         }
 
+        // Linked tasks are validated against the project and attached by the
+        // repository in the same transaction as the insert
         let milestone = self.milestone_repository.create(input).await?;
 
         info!(milestone_id = %milestone.id, milestone_name = %milestone.name, "Milestone created");
@@ -327,15 +446,43 @@ impl TimelineService {
 
     /// Update an existing milestone
     ///
+    /// Marking a milestone completed is rejected while any of its linked,
+    /// non-cancelled tasks is not done, unless `check` is `CompletionCheck::Skip`.
+    ///
     /// # Arguments
     /// * `input` - Milestone update data
+    /// * `check` - Whether to check the linked tasks first
     ///
     /// # Returns
     /// * `Ok(Milestone)` - The updated milestone
     /// * `Err(DevErpError)` - Validation or database error
-    pub async fn update_milestone(&self, input: UpdateMilestone) -> Result<Milestone, DevErpError> {
+    pub async fn update_milestone(
+        &self,
+        input: UpdateMilestone,
+        check: CompletionCheck,
+    ) -> Result<Milestone, DevErpError> {
         debug!("Service: Updating milestone {}", input.id);
 
+        if input.status == Some(MilestoneStatus::Completed) {
+            let existing = self.get_milestone(input.id).await?;
+            if existing.status != MilestoneStatus::Completed {
+                let progress = self.milestone_task_progress(input.id).await?;
+                if progress.done_tasks < progress.total_tasks {
+                    match check {
+                        CompletionCheck::Enforce => {
+                            return Err(DevErpError::Validation(format!(
+                                "Milestone '{}' cannot be completed: only {} of {} linked tasks are done",
+                                existing.name, progress.done_tasks, progress.total_tasks
+                            )));
+                        }
+                        CompletionCheck::Skip => {
+                            warn!(milestone_id = %input.id, "Skipping linked task check (forced)");
+                        }
+                    }
+                }
+            }
+        }
+
         let milestone = self.milestone_repository.update(input).await?;
 
         info!(milestone_id = %milestone.id, "Milestone updated");
@@ -343,6 +490,60 @@ impl TimelineService {
         Ok(milestone)
     }
 
+    /// Count the done and total linked tasks of a milestone
+    ///
+    /// # Arguments
+    /// * `milestone_id` - The milestone ID
+    ///
+    /// # Returns
+    /// * `Ok(MilestoneTaskCount)` - Cancelled tasks are left out; zero totals when none are linked
+    /// * `Err(DevErpError::NotFound)` - If milestone doesn't exist
+    pub async fn milestone_task_progress(
+        &self,
+        milestone_id: i64,
+    ) -> Result<MilestoneTaskCount, DevErpError> {
+        debug!(
+            "Service: Counting linked tasks of milestone {}",
+            milestone_id
+        );
+
+        let milestone = self.get_milestone(milestone_id).await?;
+        let counts = self
+            .milestone_repository
+            .task_counts_by_timeline(milestone.timeline_id)
+            .await?;
+
+        Ok(counts
+            .into_iter()
+            .find(|c| c.milestone_id == milestone_id)
+            .unwrap_or(MilestoneTaskCount {
+                milestone_id,
+                total_tasks: 0,
+                done_tasks: 0,
+            }))
+    }
+
+    /// List the tasks linked to a milestone
+    ///
+    /// # Arguments
+    /// * `milestone_id` - The milestone ID
+    ///
+    /// # Returns
+    /// * `Ok(Vec<Task>)` - Linked tasks, newest first
+    /// * `Err(DevErpError::NotFound)` - If milestone doesn't exist
+    pub async fn milestone_tasks(&self, milestone_id: i64) -> Result<Vec<Task>, DevErpError> {
+        debug!("Service: Listing tasks of milestone {}", milestone_id);
+
+        self.get_milestone(milestone_id).await?;
+
+        self.task_repository
+            .find_all(TaskFilter {
+                milestone_id: Some(milestone_id),
+                ..Default::default()
+            })
+            .await
+    }
+
     /// Delete a milestone (soft delete)
     ///
     /// # Arguments
@@ -392,16 +593,141 @@ impl TimelineService {
 
         Ok(())
     }
+
+    /// Move a milestone to a new position within its timeline
+    ///
+    /// # Arguments
+    /// * `id` - The milestone ID
+    /// * `position` - The new 1-based position among the timeline's milestones
+    ///
+    /// # Returns
+    /// * `Ok(Vec<Milestone>)` - The timeline's milestones in their new order
+    /// * `Err(DevErpError::NotFound)` - If milestone doesn't exist
+    /// * `Err(DevErpError::Validation)` - If the position is out of range
+    pub async fn reorder_milestone(
+        &self,
+        id: i64,
+        position: usize,
+    ) -> Result<Vec<Milestone>, DevErpError> {
+        debug!("Service: Moving milestone {} to position {}", id, position);
+
+        let milestone = self.get_milestone(id).await?;
+        let mut order: Vec<i64> = self
+            .milestone_repository
+            .find_by_timeline(milestone.timeline_id)
+            .await?
+            .iter()
+            .map(|m| m.id)
+            .filter(|&other| other != id)
+            .collect();
+
+        if position == 0 || position > order.len() + 1 {
+            return Err(DevErpError::Validation(format!(
+                "Position {} is out of range; timeline {} has {} milestone(s)",
+                position,
+                milestone.timeline_id,
+                order.len() + 1
+            )));
+        }
+        order.insert(position - 1, id);
+
+        self.milestone_repository
+            .reorder(milestone.timeline_id, &order)
+            .await?;
+
+        info!(milestone_id = %id, position, "Milestone reordered");
+
+        self.milestone_repository
+            .find_by_timeline(milestone.timeline_id)
+            .await
+    }
+
+    /// Mark overdue milestones as missed
+    ///
+    /// # Arguments
+    /// * `project_id` - Limit the scan to one project (all projects if `None`)
+    /// * `today` - The reference date; milestones targeted before it are overdue
+    ///
+    /// # Returns
+    /// * `Ok(Vec<Milestone>)` - The milestones that were marked missed
+    ///
+    /// # Business Rules
+    /// - Only pending and in-progress milestones are affected
+    /// - Completed, missed and cancelled milestones are left untouched
+    pub async fn scan_missed_milestones(
+        &self,
+        project_id: Option<i64>,
+        today: NaiveDate,
+    ) -> Result<Vec<Milestone>, DevErpError> {
+        debug!(
+            "Service: Scanning for missed milestones (project: {:?})",
+            project_id
+        );
+
+        let missed = self
+            .milestone_repository
+            .mark_overdue_missed(project_id, None, today)
+            .await?;
+
+        info!(count = missed.len(), "Overdue milestones marked as missed");
+
+        Ok(missed)
+    }
+
+    /// Mark one timeline's overdue milestones as missed
+    ///
+    /// # Arguments
+    /// * `timeline_id` - The timeline whose milestones are refreshed
+    /// * `today` - The reference date; milestones targeted before it are overdue
+    ///
+    /// # Returns
+    /// * `Ok(usize)` - Number of milestones that were marked missed
+    ///
+    /// # Business Rules
+    /// - The timeline must exist
+    /// - Completed and cancelled milestones are never overwritten; the status
+    ///   check happens in the same UPDATE, so a concurrent completion wins
+    pub async fn refresh_milestone_statuses(
+        &self,
+        timeline_id: i64,
+        today: NaiveDate,
+    ) -> Result<usize, DevErpError> {
+        debug!(
+            "Service: Refreshing milestone statuses for timeline {}",
+            timeline_id
+        );
+
+        self.get_timeline(timeline_id).await?;
+
+        let missed = self
+            .milestone_repository
+            .mark_overdue_missed(None, Some(timeline_id), today)
+            .await?;
+
+        info!(
+            timeline_id = %timeline_id,
+            count = missed.len(),
+            "Timeline milestone statuses refreshed"
+        );
+
+        Ok(missed.len())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::timeline::entity::{MilestoneStatus, TimelineStatus, TimelineType};
+    use crate::domain::task::entity::{
+        AssigneeStatusCount, CreateTask, ProjectTaskCount, TaskDependency, UpdateTask,
+    };
+    use crate::domain::timeline::entity::{
+        MilestoneStatus, MilestoneTaskCount, TimelineStatus, TimelineType,
+    };
     use async_trait::async_trait;
-    use chrono::{NaiveDate, Utc};
+    use chrono::NaiveDate;
     use mockall::mock;
     use mockall::predicate::*;
+    use uuid::Uuid;
 
     mock! {
         pub TimelineRepo {}
@@ -417,6 +743,7 @@ mod tests {
             async fn soft_delete(&self, id: i64) -> Result<bool, DevErpError>;
             async fn delete(&self, id: i64) -> Result<bool, DevErpError>;
             async fn restore(&self, id: i64) -> Result<bool, DevErpError>;
+            async fn shift_dates(&self, id: i64, days: i32) -> Result<u64, DevErpError>;
         }
     }
 
@@ -435,9 +762,50 @@ mod tests {
             async fn soft_delete(&self, id: i64) -> Result<bool, DevErpError>;
             async fn delete(&self, id: i64) -> Result<bool, DevErpError>;
             async fn restore(&self, id: i64) -> Result<bool, DevErpError>;
+            async fn task_counts_by_timeline(&self, timeline_id: i64) -> Result<Vec<MilestoneTaskCount>, DevErpError>;
+            async fn mark_overdue_missed(&self, project_id: Option<i64>, timeline_id: Option<i64>, today: NaiveDate) -> Result<Vec<Milestone>, DevErpError>;
+            async fn reorder(&self, timeline_id: i64, ordered_ids: &[i64]) -> Result<(), DevErpError>;
         }
     }
 
+    mock! {
+        pub TaskRepo {}
+
+        #[async_trait]
+        impl TaskRepository for TaskRepo {
+            async fn create(&self, task: CreateTask) -> Result<Task, DevErpError>;
+            async fn create_with_dependencies(
+                &self,
+                task: CreateTask,
+                depends_on: &[i64],
+            ) -> Result<(Task, Vec<TaskDependency>), DevErpError>;
+            async fn find_by_id(&self, id: i64) -> Result<Option<Task>, DevErpError>;
+            async fn find_by_uuid(&self, uuid: Uuid) -> Result<Option<Task>, DevErpError>;
+            async fn find_all(&self, filter: TaskFilter) -> Result<Vec<Task>, DevErpError>;
+            async fn update(&self, task: UpdateTask) -> Result<Task, DevErpError>;
+            async fn delete(&self, id: i64) -> Result<bool, DevErpError>;
+            async fn soft_delete(&self, id: i64) -> Result<bool, DevErpError>;
+            async fn restore(&self, id: i64) -> Result<bool, DevErpError>;
+            async fn count(&self, filter: TaskFilter) -> Result<i64, DevErpError>;
+            async fn count_by_assignee(&self) -> Result<Vec<AssigneeStatusCount>, DevErpError>;
+            async fn count_by_project(&self, project_ids: &[i64]) -> Result<Vec<ProjectTaskCount>, DevErpError>;
+            async fn last_status_change(&self, task_id: i64) -> Result<Option<chrono::DateTime<chrono::Utc>>, DevErpError>;
+            async fn move_to_project(&self, task_id: i64, project_id: i64, clear_parent: bool) -> Result<Task, DevErpError>;
+        }
+    }
+
+    /// Service over the given mocks, with a task mock that expects no calls
+    fn service_with(
+        timeline_repo: MockTimelineRepo,
+        milestone_repo: MockMilestoneRepo,
+    ) -> TimelineService {
+        TimelineService::new(
+            Arc::new(timeline_repo),
+            Arc::new(milestone_repo),
+            Arc::new(MockTaskRepo::new()),
+        )
+    }
+
     fn create_test_timeline(id: i64, project_id: i64, name: &str) -> Timeline {
         Timeline {
             id,
@@ -465,6 +833,7 @@ mod tests {
             actual_date: None,
             status: MilestoneStatus::Pending,
             completion_percentage: 0,
+            sequence: id as i32,
             metadata: None,
             created_at: Utc::now(),
             updated_at: Utc::now(),
@@ -482,8 +851,7 @@ mod tests {
             .times(1)
             .returning(move |_| Ok(create_test_timeline(1, 1, "Sprint 1")));
 
-        let service =
-            TimelineService::new(Arc::new(mock_timeline_repo), Arc::new(mock_milestone_repo));
+        let service = service_with(mock_timeline_repo, mock_milestone_repo);
 
         let input = CreateTimeline {
             project_id: 1,
@@ -513,8 +881,7 @@ mod tests {
             .times(1)
             .returning(move |_| Ok(Some(create_test_timeline(1, 1, "Sprint 1"))));
 
-        let service =
-            TimelineService::new(Arc::new(mock_timeline_repo), Arc::new(mock_milestone_repo));
+        let service = service_with(mock_timeline_repo, mock_milestone_repo);
 
         let result = service.get_timeline(1).await;
         assert!(result.is_ok());
@@ -533,8 +900,7 @@ mod tests {
             .times(1)
             .returning(|_| Ok(None));
 
-        let service =
-            TimelineService::new(Arc::new(mock_timeline_repo), Arc::new(mock_milestone_repo));
+        let service = service_with(mock_timeline_repo, mock_milestone_repo);
 
         let result = service.get_timeline(999).await;
         assert!(result.is_err());
@@ -563,8 +929,7 @@ mod tests {
             .times(1)
             .returning(move |_| Ok(create_test_milestone(1, 1, 1, "Feature Complete")));
 
-        let service =
-            TimelineService::new(Arc::new(mock_timeline_repo), Arc::new(mock_milestone_repo));
+        let service = service_with(mock_timeline_repo, mock_milestone_repo);
 
         let input = CreateMilestone {
             timeline_id: 1,
@@ -575,6 +940,7 @@ mod tests {
             status: Some(MilestoneStatus::Pending),
             completion_percentage: Some(0),
             metadata: None,
+            task_ids: Vec::new(),
         };
 
         let result = service.create_milestone(input).await;
@@ -595,8 +961,7 @@ mod tests {
             .times(1)
             .returning(|_| Ok(None));
 
-        let service =
-            TimelineService::new(Arc::new(mock_timeline_repo), Arc::new(mock_milestone_repo));
+        let service = service_with(mock_timeline_repo, mock_milestone_repo);
 
         let input = CreateMilestone {
             timeline_id: 999,
@@ -607,6 +972,7 @@ mod tests {
             status: None,
             completion_percentage: None,
             metadata: None,
+            task_ids: Vec::new(),
         };
 
         let result = service.create_milestone(input).await;
@@ -630,8 +996,7 @@ mod tests {
             .times(1)
             .returning(|_| Ok(true));
 
-        let service =
-            TimelineService::new(Arc::new(mock_timeline_repo), Arc::new(mock_milestone_repo));
+        let service = service_with(mock_timeline_repo, mock_milestone_repo);
 
         let result = service.delete_timeline(1).await;
         assert!(result.is_ok());
@@ -648,8 +1013,7 @@ mod tests {
             .times(1)
             .returning(|_| Ok(false));
 
-        let service =
-            TimelineService::new(Arc::new(mock_timeline_repo), Arc::new(mock_milestone_repo));
+        let service = service_with(mock_timeline_repo, mock_milestone_repo);
 
         let result = service.delete_timeline(999).await;
         assert!(result.is_err());
@@ -677,12 +1041,195 @@ mod tests {
                 ])
             });
 
-        let service =
-            TimelineService::new(Arc::new(mock_timeline_repo), Arc::new(mock_milestone_repo));
+        let service = service_with(mock_timeline_repo, mock_milestone_repo);
 
         let result = service.get_milestones_by_timeline(1).await;
         assert!(result.is_ok());
         let milestones = result.unwrap();
         assert_eq!(milestones.len(), 2);
     }
+
+    #[tokio::test]
+    async fn test_timeline_progress_averages_mixed_milestones() {
+        let mut mock_timeline_repo = MockTimelineRepo::new();
+        let mut mock_milestone_repo = MockMilestoneRepo::new();
+
+        mock_timeline_repo
+            .expect_find_by_id()
+            .with(eq(1))
+            .returning(|_| Ok(Some(create_test_timeline(1, 1, "Release"))));
+
+        mock_milestone_repo
+            .expect_find_by_timeline()
+            .with(eq(1))
+            .returning(|_| {
+                let today = Utc::now().date_naive();
+
+                let mut done = create_test_milestone(1, 1, 1, "Alpha");
+                done.status = MilestoneStatus::Completed;
+                done.completion_percentage = 100;
+
+                let mut late = create_test_milestone(2, 1, 1, "Beta");
+                late.status = MilestoneStatus::InProgress;
+                late.completion_percentage = 50;
+                late.target_date = today - chrono::Duration::days(3);
+
+                // Own percentage is ignored in favour of linked tasks (1 of 4 done)
+                let mut linked = create_test_milestone(3, 1, 1, "RC");
+                linked.completion_percentage = 90;
+                linked.target_date = today + chrono::Duration::days(10);
+
+                let mut dropped = create_test_milestone(4, 1, 1, "Extra");
+                dropped.status = MilestoneStatus::Cancelled;
+
+                Ok(vec![done, late, linked, dropped])
+            });
+
+        mock_milestone_repo
+            .expect_task_counts_by_timeline()
+            .with(eq(1))
+            .returning(|_| {
+                Ok(vec![MilestoneTaskCount {
+                    milestone_id: 3,
+                    total_tasks: 4,
+                    done_tasks: 1,
+                }])
+            });
+
+        let service = service_with(mock_timeline_repo, mock_milestone_repo);
+        let progress = service.progress(1).await.unwrap();
+
+        // (100 + 50 + 25) / 3, cancelled milestone excluded
+        assert!((progress.completion_percentage - 175.0 / 3.0).abs() < 1e-9);
+        assert_eq!(progress.completed_milestones, 1);
+        assert_eq!(progress.missed_milestones, 1);
+        assert_eq!(progress.upcoming_milestones, 1);
+        assert!(progress.milestones[2].task_derived);
+        assert!(!progress.milestones[1].task_derived);
+    }
+
+    #[tokio::test]
+    async fn test_reorder_milestone_moves_to_position() {
+        let mock_timeline_repo = MockTimelineRepo::new();
+        let mut mock_milestone_repo = MockMilestoneRepo::new();
+
+        mock_milestone_repo
+            .expect_find_by_id()
+            .with(eq(3))
+            .returning(|id| Ok(Some(create_test_milestone(id, 1, 1, "Launch"))));
+        mock_milestone_repo
+            .expect_find_by_timeline()
+            .with(eq(1))
+            .returning(|_| {
+                Ok([1, 2, 3]
+                    .into_iter()
+                    .map(|id| create_test_milestone(id, 1, 1, "Milestone"))
+                    .collect())
+            });
+        mock_milestone_repo
+            .expect_reorder()
+            .withf(|timeline_id, ordered_ids| *timeline_id == 1 && ordered_ids == [3, 1, 2])
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let service = service_with(mock_timeline_repo, mock_milestone_repo);
+
+        assert!(service.reorder_milestone(3, 1).await.is_ok());
+
+        let result = service.reorder_milestone(3, 4).await;
+        assert!(matches!(result, Err(DevErpError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_shift_timeline_respects_date_floor() {
+        let mut mock_timeline_repo = MockTimelineRepo::new();
+        let mut mock_milestone_repo = MockMilestoneRepo::new();
+
+        mock_timeline_repo
+            .expect_find_by_id()
+            .with(eq(1))
+            .returning(|id| Ok(Some(create_test_timeline(id, 1, "Sprint"))));
+        mock_milestone_repo
+            .expect_find_by_timeline()
+            .with(eq(1))
+            .returning(|_| {
+                let mut done = create_test_milestone(1, 1, 1, "Kickoff");
+                // Recorded before the timeline started
+                done.actual_date = NaiveDate::from_ymd_opt(2024, 12, 30);
+                Ok(vec![done])
+            });
+        mock_timeline_repo
+            .expect_shift_dates()
+            .with(eq(1), eq(-2))
+            .times(1)
+            .returning(|_, _| Ok(1));
+
+        let service = service_with(mock_timeline_repo, mock_milestone_repo)
+            .with_date_floor(NaiveDate::from_ymd_opt(2024, 12, 28));
+
+        let shifted = service.shift_timeline(1, -2).await.unwrap();
+        assert_eq!(shifted.milestones_moved, 1);
+
+        // The actual date would land on 2024-12-27, a day before the floor
+        let result = service.shift_timeline(1, -3).await;
+        assert!(matches!(result, Err(DevErpError::Validation(_))));
+
+        let result = service.shift_timeline(1, 0).await;
+        assert!(matches!(result, Err(DevErpError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_complete_milestone_requires_linked_tasks_done() {
+        let mock_timeline_repo = MockTimelineRepo::new();
+        let mut mock_milestone_repo = MockMilestoneRepo::new();
+
+        mock_milestone_repo
+            .expect_find_by_id()
+            .with(eq(1))
+            .returning(|id| Ok(Some(create_test_milestone(id, 1, 1, "GA"))));
+        mock_milestone_repo
+            .expect_task_counts_by_timeline()
+            .with(eq(1))
+            .returning(|_| {
+                Ok(vec![MilestoneTaskCount {
+                    milestone_id: 1,
+                    total_tasks: 3,
+                    done_tasks: 2,
+                }])
+            });
+        mock_milestone_repo
+            .expect_update()
+            .times(1)
+            .returning(|input| {
+                let mut milestone = create_test_milestone(input.id, 1, 1, "GA");
+                milestone.status = MilestoneStatus::Completed;
+                Ok(milestone)
+            });
+
+        let service = service_with(mock_timeline_repo, mock_milestone_repo);
+
+        let progress = service.milestone_task_progress(1).await.unwrap();
+        assert_eq!((progress.done_tasks, progress.total_tasks), (2, 3));
+
+        let complete = UpdateMilestone {
+            id: 1,
+            name: None,
+            description: None,
+            target_date: None,
+            actual_date: None,
+            status: Some(MilestoneStatus::Completed),
+            completion_percentage: Some(100),
+            metadata: None,
+        };
+        let result = service
+            .update_milestone(complete.clone(), CompletionCheck::Enforce)
+            .await;
+        assert!(matches!(result, Err(DevErpError::Validation(_))));
+
+        let milestone = service
+            .update_milestone(complete, CompletionCheck::Skip)
+            .await
+            .unwrap();
+        assert_eq!(milestone.status, MilestoneStatus::Completed);
+    }
 }
@@ -6,6 +6,7 @@ use crate::domain::config::entity::{
 };
 use crate::domain::config::repository::ConfigRepository;
 use crate::utils::error::DevErpError;
+use crate::utils::timing::QueryTimer;
 
 pub struct PostgresConfigRepository {
     pool: PgPool,
@@ -20,6 +21,7 @@ impl PostgresConfigRepository {
 #[async_trait]
 impl ConfigRepository for PostgresConfigRepository {
     async fn create(&self, config: CreateConfiguration) -> Result<Configuration, DevErpError> {
+        let _timer = QueryTimer::start("configurations.create");
         let result = sqlx::query_as!(
             Configuration,
             r#"
@@ -50,6 +52,7 @@ impl ConfigRepository for PostgresConfigRepository {
     }
 
     async fn find_by_key(&self, key: &str) -> Result<Option<Configuration>, DevErpError> {
+        let _timer = QueryTimer::start("configurations.find_by_key");
         let result = sqlx::query_as!(
             Configuration,
             r#"
@@ -75,6 +78,7 @@ impl ConfigRepository for PostgresConfigRepository {
     }
 
     async fn find_all(&self) -> Result<Vec<Configuration>, DevErpError> {
+        let _timer = QueryTimer::start("configurations.find_all");
         let results = sqlx::query_as!(
             Configuration,
             r#"
@@ -99,6 +103,7 @@ impl ConfigRepository for PostgresConfigRepository {
     }
 
     async fn update(&self, config: UpdateConfiguration) -> Result<Configuration, DevErpError> {
+        let _timer = QueryTimer::start("configurations.update");
         let result = sqlx::query_as!(
             Configuration,
             r#"
@@ -130,6 +135,7 @@ impl ConfigRepository for PostgresConfigRepository {
     }
 
     async fn delete(&self, key: &str) -> Result<bool, DevErpError> {
+        let _timer = QueryTimer::start("configurations.delete");
         let result = sqlx::query!(
             r#"
             DELETE FROM configurations
@@ -144,6 +150,7 @@ impl ConfigRepository for PostgresConfigRepository {
     }
 
     async fn reset_to_defaults(&self) -> Result<(), DevErpError> {
+        let _timer = QueryTimer::start("configurations.reset_to_defaults");
         // Begin transaction
         let mut tx = self.pool.begin().await?;
 
@@ -159,7 +166,16 @@ impl ConfigRepository for PostgresConfigRepository {
             ('default_project_status', 'planning', 'Default status for new projects', 'string'),
             ('default_task_status', 'todo', 'Default status for new tasks', 'string'),
             ('date_format', '%Y-%m-%d', 'Default date format', 'string'),
-            ('enable_audit_log', 'true', 'Enable audit logging', 'boolean')
+            ('enable_audit_log', 'true', 'Enable audit logging', 'boolean'),
+            ('timeline.default_type', 'project', 'Default type for new timelines (project, sprint, release, phase)', 'string'),
+            ('resource.unique_name_version', 'true', 'Reject new resources whose name and version match an existing one', 'boolean'),
+            ('resource.allowed_licenses', '[]', 'Licenses (JSON array) resource license-report treats as compliant; empty allows all', 'json'),
+            ('list.per_page', '50', 'Default page size for list commands when --per-page is omitted', 'integer'),
+            ('list.order_by', '', 'Default sort for project lists when --sort is omitted (created-at, name, start-date, end-date); empty keeps the built-in order', 'string'),
+            ('task.variance_threshold_pct', '50', 'Warn when a task update leaves actual hours more than this many percent over the estimate', 'integer'),
+            ('calendar.holidays', '[]', 'Holidays (JSON array of YYYY-MM-DD dates) left out of working-day durations', 'json'),
+            ('cost.hourly_rate', '0', 'Hourly rate applied to task actual hours for project cost; 0 leaves cost uncomputed', 'float'),
+            ('timeline.date_floor', '2000-01-01', 'Earliest date (YYYY-MM-DD) timeline shift may move a timeline or milestone to; empty disables the check', 'string')
             "#
         )
         .execute(&mut *tx)
@@ -177,6 +193,7 @@ mod tests {
     // use super::*;
 
     #[tokio::test]
+    #[allow(clippy::assertions_on_constants)]
     async fn test_config_repository_mock() {
         // This is a placeholder for actual integration tests with testcontainers
         // Real tests would require a PostgreSQL instance
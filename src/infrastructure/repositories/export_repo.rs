@@ -0,0 +1,142 @@
+// PostgreSQL implementation of ExportRepository
+
+use async_trait::async_trait;
+use sqlx::PgPool;
+use tracing::debug;
+
+use crate::domain::export::{entity::ExportData, repository::ExportRepository};
+use crate::domain::project::entity::Project;
+use crate::domain::resource::entity::Resource;
+use crate::domain::task::entity::Task;
+use crate::domain::timeline::entity::{
+    Milestone, MilestoneStatus, Timeline, TimelineStatus, TimelineType,
+};
+use crate::utils::error::DevErpError;
+use crate::utils::timing::QueryTimer;
+
+/// PostgreSQL implementation of the ExportRepository trait
+pub struct PostgresExportRepository {
+    pool: PgPool,
+}
+
+impl PostgresExportRepository {
+    /// Create a new PostgreSQL export repository
+    ///
+    /// # Arguments
+    /// * `pool` - PostgreSQL connection pool
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ExportRepository for PostgresExportRepository {
+    async fn load_all(&self) -> Result<ExportData, DevErpError> {
+        let _timer = QueryTimer::start("export.load_all");
+        debug!("Loading all entities for export");
+
+        // Read everything from one snapshot so cross-references stay consistent
+        let mut tx = self.pool.begin().await?;
+        sqlx::query("SET TRANSACTION ISOLATION LEVEL REPEATABLE READ READ ONLY")
+            .execute(&mut *tx)
+            .await?;
+
+        let projects = sqlx::query_as!(
+            Project,
+            r#"
+            SELECT
+                id, uuid, name, description, code,
+                status as "status: _", priority as "priority: _",
+                start_date, end_date, actual_start_date, actual_end_date,
+                progress_percentage, budget, currency, repository_url, repository_branch,
+                tags, metadata, version,
+                created_at, updated_at, deleted_at
+            FROM projects
+            WHERE deleted_at IS NULL
+            ORDER BY id
+            "#
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let tasks = sqlx::query_as!(
+            Task,
+            r#"
+            SELECT
+                id, uuid, project_id, parent_task_id, milestone_id, title, description, task_number,
+                status as "status: _", blocked_reason, priority as "priority: _", assigned_to,
+                estimated_hours, actual_hours, due_date, started_at, completed_at,
+                task_type as "task_type: _", tags, version, created_at, updated_at, deleted_at
+            FROM tasks
+            WHERE deleted_at IS NULL
+            ORDER BY id
+            "#
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let resources = sqlx::query_as!(
+            Resource,
+            r#"
+            SELECT
+                id, uuid, name, description,
+                resource_type as "resource_type: _",
+                version, url, documentation_url, license,
+                status as "status: _",
+                metadata, tags,
+                created_at, updated_at, deleted_at
+            FROM resources
+            WHERE deleted_at IS NULL
+            ORDER BY id
+            "#
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let timelines = sqlx::query_as!(
+            Timeline,
+            r#"
+            SELECT
+                id, project_id, name, description,
+                timeline_type as "timeline_type!: TimelineType",
+                start_date, end_date,
+                status as "status!: TimelineStatus",
+                created_at, updated_at, deleted_at
+            FROM timelines
+            WHERE deleted_at IS NULL
+            ORDER BY id
+            "#
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let milestones = sqlx::query_as!(
+            Milestone,
+            r#"
+            SELECT
+                id, timeline_id, project_id, name, description,
+                target_date, actual_date,
+                status as "status!: MilestoneStatus",
+                completion_percentage as "completion_percentage!",
+                sequence,
+                metadata,
+                created_at, updated_at, deleted_at
+            FROM milestones
+            WHERE deleted_at IS NULL
+            ORDER BY id
+            "#
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(ExportData {
+            projects,
+            tasks,
+            resources,
+            timelines,
+            milestones,
+        })
+    }
+}
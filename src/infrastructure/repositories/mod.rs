@@ -1,16 +1,43 @@
 // Repository implementations
 
+use crate::utils::error::DevErpError;
+
 pub mod config_repo;
+pub mod export_repo;
 pub mod project_repo;
 pub mod resource_repo;
+pub mod snapshot_repo;
 pub mod task_repo;
+pub mod template_repo;
 pub mod timeline_repo;
 
 // Re-export for convenience
 pub use config_repo::PostgresConfigRepository;
+pub use export_repo::PostgresExportRepository;
 pub use project_repo::PostgresProjectRepository;
 pub use resource_repo::PostgresResourceRepository;
+pub use snapshot_repo::PostgresSnapshotRepository;
 pub use task_repo::{
-    PostgresTaskCommentRepository, PostgresTaskDependencyRepository, PostgresTaskRepository,
+    PostgresTaskAssigneeRepository, PostgresTaskCommentRepository,
+    PostgresTaskDependencyRepository, PostgresTaskRepository, PostgresTaskWorkLogRepository,
 };
+pub use template_repo::PostgresTemplateRepository;
 pub use timeline_repo::{PostgresMilestoneRepository, PostgresTimelineRepository};
+
+/// Error for an update that matched no row
+///
+/// With an `expected_version` the row most likely moved on under us, so this
+/// is a conflict; without one the row is simply gone.
+pub(crate) fn stale_update_error(
+    entity: &str,
+    id: i64,
+    expected_version: Option<i32>,
+) -> DevErpError {
+    match expected_version {
+        Some(version) => DevErpError::Conflict(format!(
+            "{} {} is no longer at version {}; reload it and retry",
+            entity, id, version
+        )),
+        None => DevErpError::NotFound(format!("{} with id {} not found", entity, id)),
+    }
+}
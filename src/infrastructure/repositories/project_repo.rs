@@ -1,15 +1,18 @@
 // PostgreSQL implementation of ProjectRepository
 
 use async_trait::async_trait;
-use sqlx::{PgPool, Row};
+use chrono::NaiveDate;
+use sqlx::{PgExecutor, PgPool, Row};
 use tracing::{debug, info, warn};
 use uuid::Uuid;
 
+use super::stale_update_error;
 use crate::domain::project::{
     entity::{CreateProject, Project, ProjectFilter, UpdateProject},
     repository::ProjectRepository,
 };
 use crate::utils::error::DevErpError;
+use crate::utils::timing::QueryTimer;
 
 /// PostgreSQL implementation of the ProjectRepository trait
 pub struct PostgresProjectRepository {
@@ -26,9 +29,61 @@ impl PostgresProjectRepository {
     }
 }
 
+/// Insert a project row on any executor (pool or open transaction)
+pub(crate) async fn insert_project<'e, E>(
+    executor: E,
+    project: CreateProject,
+) -> Result<Project, DevErpError>
+where
+    E: PgExecutor<'e>,
+{
+    let result = sqlx::query_as!(
+        Project,
+        r#"
+        INSERT INTO projects (
+            name, description, code, status, priority,
+            start_date, end_date, budget, currency, repository_url, repository_branch,
+            tags, metadata
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
+        RETURNING
+            id, uuid, name, description, code,
+            status as "status: _", priority as "priority: _",
+            start_date, end_date, actual_start_date, actual_end_date,
+            progress_percentage, budget, currency, repository_url, repository_branch,
+            tags, metadata, version,
+            created_at, updated_at, deleted_at
+        "#,
+        project.name,
+        project.description,
+        project.code,
+        project
+            .status
+            .unwrap_or(crate::domain::project::entity::ProjectStatus::Planning)
+            .as_str(),
+        project
+            .priority
+            .unwrap_or(crate::domain::project::entity::Priority::Medium)
+            .as_str(),
+        project.start_date,
+        project.end_date,
+        project.budget,
+        project.currency,
+        project.repository_url,
+        project.repository_branch.or(Some("main".to_string())),
+        project.tags.as_deref(),
+        project.metadata
+    )
+    .fetch_one(executor)
+    .await?;
+
+    Ok(result)
+}
+
 #[async_trait]
 impl ProjectRepository for PostgresProjectRepository {
     async fn create(&self, project: CreateProject) -> Result<Project, DevErpError> {
+        let _timer = QueryTimer::start("projects.create");
         // Validate input
         project.validate().map_err(DevErpError::Validation)?;
 
@@ -44,43 +99,7 @@ impl ProjectRepository for PostgresProjectRepository {
 
         debug!("Creating project: {}", project.name);
 
-        let result = sqlx::query_as!(
-            Project,
-            r#"
-            INSERT INTO projects (
-                name, description, code, status, priority,
-                start_date, end_date, repository_url, repository_branch,
-                tags, metadata
-            )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
-            RETURNING
-                id, uuid, name, description, code,
-                status as "status: _", priority as "priority: _",
-                start_date, end_date, actual_start_date, actual_end_date,
-                progress_percentage, repository_url, repository_branch,
-                tags, metadata,
-                created_at, updated_at, deleted_at
-            "#,
-            project.name,
-            project.description,
-            project.code,
-            project
-                .status
-                .unwrap_or(crate::domain::project::entity::ProjectStatus::Planning)
-                .as_str(),
-            project
-                .priority
-                .unwrap_or(crate::domain::project::entity::Priority::Medium)
-                .as_str(),
-            project.start_date,
-            project.end_date,
-            project.repository_url,
-            project.repository_branch.or(Some("main".to_string())),
-            project.tags.as_deref(),
-            project.metadata
-        )
-        .fetch_one(&self.pool)
-        .await?;
+        let result = insert_project(&self.pool, project).await?;
 
         info!(project_id = %result.id, project_uuid = %result.uuid, "Project created successfully");
 
@@ -88,6 +107,7 @@ impl ProjectRepository for PostgresProjectRepository {
     }
 
     async fn find_by_id(&self, id: i64) -> Result<Option<Project>, DevErpError> {
+        let _timer = QueryTimer::start("projects.find_by_id");
         debug!("Finding project by id: {}", id);
 
         let result = sqlx::query_as!(
@@ -97,8 +117,8 @@ impl ProjectRepository for PostgresProjectRepository {
                 id, uuid, name, description, code,
                 status as "status: _", priority as "priority: _",
                 start_date, end_date, actual_start_date, actual_end_date,
-                progress_percentage, repository_url, repository_branch,
-                tags, metadata,
+                progress_percentage, budget, currency, repository_url, repository_branch,
+                tags, metadata, version,
                 created_at, updated_at, deleted_at
             FROM projects
             WHERE id = $1 AND deleted_at IS NULL
@@ -112,6 +132,7 @@ impl ProjectRepository for PostgresProjectRepository {
     }
 
     async fn find_by_uuid(&self, uuid: Uuid) -> Result<Option<Project>, DevErpError> {
+        let _timer = QueryTimer::start("projects.find_by_uuid");
         debug!("Finding project by uuid: {}", uuid);
 
         let result = sqlx::query_as!(
@@ -121,8 +142,8 @@ impl ProjectRepository for PostgresProjectRepository {
                 id, uuid, name, description, code,
                 status as "status: _", priority as "priority: _",
                 start_date, end_date, actual_start_date, actual_end_date,
-                progress_percentage, repository_url, repository_branch,
-                tags, metadata,
+                progress_percentage, budget, currency, repository_url, repository_branch,
+                tags, metadata, version,
                 created_at, updated_at, deleted_at
             FROM projects
             WHERE uuid = $1 AND deleted_at IS NULL
@@ -136,6 +157,7 @@ impl ProjectRepository for PostgresProjectRepository {
     }
 
     async fn find_by_code(&self, code: &str) -> Result<Option<Project>, DevErpError> {
+        let _timer = QueryTimer::start("projects.find_by_code");
         debug!("Finding project by code: {}", code);
 
         let result = sqlx::query_as!(
@@ -145,8 +167,8 @@ impl ProjectRepository for PostgresProjectRepository {
                 id, uuid, name, description, code,
                 status as "status: _", priority as "priority: _",
                 start_date, end_date, actual_start_date, actual_end_date,
-                progress_percentage, repository_url, repository_branch,
-                tags, metadata,
+                progress_percentage, budget, currency, repository_url, repository_branch,
+                tags, metadata, version,
                 created_at, updated_at, deleted_at
             FROM projects
             WHERE code = $1 AND deleted_at IS NULL
@@ -160,8 +182,11 @@ impl ProjectRepository for PostgresProjectRepository {
     }
 
     async fn find_all(&self, filter: ProjectFilter) -> Result<Vec<Project>, DevErpError> {
+        let _timer = QueryTimer::start("projects.find_all");
         debug!("Finding all projects with filter: {:?}", filter);
 
+        filter.validate().map_err(DevErpError::Validation)?;
+
         let limit = filter.get_limit();
         let offset = filter.get_offset();
 
@@ -172,17 +197,21 @@ impl ProjectRepository for PostgresProjectRepository {
                 id, uuid, name, description, code,
                 status, priority,
                 start_date, end_date, actual_start_date, actual_end_date,
-                progress_percentage, repository_url, repository_branch,
-                tags, metadata,
+                progress_percentage, budget, currency, repository_url, repository_branch,
+                tags, metadata, version,
                 created_at, updated_at, deleted_at
             FROM projects
-            WHERE deleted_at IS NULL
+            WHERE 1=1
             "#,
         );
 
         let mut conditions = Vec::new();
         let mut param_count = 1;
 
+        if !filter.include_deleted {
+            conditions.push("deleted_at IS NULL".to_string());
+        }
+
         // Add status filter
         if filter.status.is_some() {
             conditions.push(format!("status = ${}", param_count));
@@ -207,17 +236,28 @@ impl ProjectRepository for PostgresProjectRepository {
         // Add tag filter
         if let Some(ref tags) = filter.tags {
             if !tags.is_empty() {
-                conditions.push(format!("tags && ${}", param_count));
+                conditions.push(format!(
+                    "tags {} ${}",
+                    filter.tag_match_mode.operator(),
+                    param_count
+                ));
                 param_count += 1;
             }
         }
 
+        // Add date range filters
+        for (column, op, _) in filter.date_bounds() {
+            conditions.push(format!("{} {} ${}", column, op, param_count));
+            param_count += 1;
+        }
+
         if !conditions.is_empty() {
             query.push_str(" AND ");
             query.push_str(&conditions.join(" AND "));
         }
 
-        query.push_str(" ORDER BY created_at DESC");
+        query.push(' ');
+        query.push_str(&filter.order_by_clause());
         query.push_str(&format!(
             " LIMIT ${} OFFSET ${}",
             param_count,
@@ -246,6 +286,10 @@ impl ProjectRepository for PostgresProjectRepository {
             }
         }
 
+        for (_, _, date) in filter.date_bounds() {
+            query_builder = query_builder.bind(date);
+        }
+
         query_builder = query_builder.bind(limit).bind(offset);
 
         let results = query_builder.fetch_all(&self.pool).await?;
@@ -256,14 +300,20 @@ impl ProjectRepository for PostgresProjectRepository {
     }
 
     async fn count(&self, filter: ProjectFilter) -> Result<i64, DevErpError> {
+        let _timer = QueryTimer::start("projects.count");
         debug!("Counting projects with filter: {:?}", filter);
 
-        let mut query =
-            String::from("SELECT COUNT(*) as count FROM projects WHERE deleted_at IS NULL");
+        filter.validate().map_err(DevErpError::Validation)?;
+
+        let mut query = String::from("SELECT COUNT(*) as count FROM projects WHERE 1=1");
 
         let mut conditions = Vec::new();
         let mut param_count = 1;
 
+        if !filter.include_deleted {
+            conditions.push("deleted_at IS NULL".to_string());
+        }
+
         if filter.status.is_some() {
             conditions.push(format!("status = ${}", param_count));
             param_count += 1;
@@ -284,10 +334,20 @@ impl ProjectRepository for PostgresProjectRepository {
 
         if let Some(ref tags) = filter.tags {
             if !tags.is_empty() {
-                conditions.push(format!("tags && ${}", param_count));
+                conditions.push(format!(
+                    "tags {} ${}",
+                    filter.tag_match_mode.operator(),
+                    param_count
+                ));
+                param_count += 1;
             }
         }
 
+        for (column, op, _) in filter.date_bounds() {
+            conditions.push(format!("{} {} ${}", column, op, param_count));
+            param_count += 1;
+        }
+
         if !conditions.is_empty() {
             query.push_str(" AND ");
             query.push_str(&conditions.join(" AND "));
@@ -314,6 +374,10 @@ impl ProjectRepository for PostgresProjectRepository {
             }
         }
 
+        for (_, _, date) in filter.date_bounds() {
+            query_builder = query_builder.bind(date);
+        }
+
         let row = query_builder.fetch_one(&self.pool).await?;
         let count: i64 = row.try_get("count")?;
 
@@ -323,6 +387,7 @@ impl ProjectRepository for PostgresProjectRepository {
     }
 
     async fn update(&self, project: UpdateProject) -> Result<Project, DevErpError> {
+        let _timer = QueryTimer::start("projects.update");
         // Validate input
         project.validate().map_err(DevErpError::Validation)?;
 
@@ -352,7 +417,7 @@ impl ProjectRepository for PostgresProjectRepository {
             UPDATE projects
             SET
                 name = COALESCE($2, name),
-                description = COALESCE($3, description),
+                description = CASE WHEN $18 THEN $3 ELSE description END,
                 code = COALESCE($4, code),
                 status = COALESCE($5, status),
                 priority = COALESCE($6, priority),
@@ -365,19 +430,22 @@ impl ProjectRepository for PostgresProjectRepository {
                 repository_branch = COALESCE($13, repository_branch),
                 tags = COALESCE($14, tags),
                 metadata = COALESCE($15, metadata),
+                budget = COALESCE($16, budget),
+                currency = COALESCE($17, currency),
                 updated_at = CURRENT_TIMESTAMP
             WHERE id = $1 AND deleted_at IS NULL
+                AND ($19::INTEGER IS NULL OR version = $19)
             RETURNING
                 id, uuid, name, description, code,
                 status as "status: _", priority as "priority: _",
                 start_date, end_date, actual_start_date, actual_end_date,
-                progress_percentage, repository_url, repository_branch,
-                tags, metadata,
+                progress_percentage, budget, currency, repository_url, repository_branch,
+                tags, metadata, version,
                 created_at, updated_at, deleted_at
             "#,
             project.id,
             project.name,
-            project.description,
+            project.description.clone().flatten(),
             project.code,
             project.status.map(|s| s.as_str()),
             project.priority.map(|p| p.as_str()),
@@ -389,10 +457,15 @@ impl ProjectRepository for PostgresProjectRepository {
             project.repository_url,
             project.repository_branch,
             project.tags.as_deref(),
-            project.metadata
+            project.metadata,
+            project.budget,
+            project.currency,
+            project.description.is_some(),
+            project.expected_version
         )
-        .fetch_one(&self.pool)
-        .await?;
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or_else(|| stale_update_error("Project", project.id, project.expected_version))?;
 
         info!(project_id = %result.id, "Project updated successfully");
 
@@ -400,6 +473,7 @@ impl ProjectRepository for PostgresProjectRepository {
     }
 
     async fn soft_delete(&self, id: i64) -> Result<bool, DevErpError> {
+        let _timer = QueryTimer::start("projects.soft_delete");
         debug!("Soft deleting project: {}", id);
 
         let result = sqlx::query!(
@@ -420,7 +494,62 @@ impl ProjectRepository for PostgresProjectRepository {
         Ok(deleted)
     }
 
+    async fn soft_delete_cascade(&self, id: i64) -> Result<bool, DevErpError> {
+        let _timer = QueryTimer::start("projects.soft_delete_cascade");
+        debug!("Soft deleting project and its children: {}", id);
+
+        // CURRENT_TIMESTAMP is fixed for the transaction, so every row gets
+        // the same deleted_at
+        let mut tx = self.pool.begin().await?;
+
+        let result = sqlx::query!(
+            "UPDATE projects SET deleted_at = CURRENT_TIMESTAMP WHERE id = $1 AND deleted_at IS NULL",
+            id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            warn!(project_id = %id, "Project not found for cascade soft delete");
+            return Ok(false);
+        }
+
+        let tasks = sqlx::query!(
+            "UPDATE tasks SET deleted_at = CURRENT_TIMESTAMP WHERE project_id = $1 AND deleted_at IS NULL",
+            id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        let milestones = sqlx::query!(
+            "UPDATE milestones SET deleted_at = CURRENT_TIMESTAMP WHERE project_id = $1 AND deleted_at IS NULL",
+            id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        let timelines = sqlx::query!(
+            "UPDATE timelines SET deleted_at = CURRENT_TIMESTAMP WHERE project_id = $1 AND deleted_at IS NULL",
+            id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        info!(
+            project_id = %id,
+            tasks = tasks.rows_affected(),
+            timelines = timelines.rows_affected(),
+            milestones = milestones.rows_affected(),
+            "Project and its children soft deleted successfully"
+        );
+
+        Ok(true)
+    }
+
     async fn delete(&self, id: i64) -> Result<bool, DevErpError> {
+        let _timer = QueryTimer::start("projects.delete");
         warn!(project_id = %id, "Hard deleting project - this is irreversible");
 
         let result = sqlx::query!("DELETE FROM projects WHERE id = $1", id)
@@ -439,6 +568,7 @@ impl ProjectRepository for PostgresProjectRepository {
     }
 
     async fn restore(&self, id: i64) -> Result<bool, DevErpError> {
+        let _timer = QueryTimer::start("projects.restore");
         debug!("Restoring project: {}", id);
 
         let result = sqlx::query!(
@@ -460,6 +590,7 @@ impl ProjectRepository for PostgresProjectRepository {
     }
 
     async fn code_exists(&self, code: &str, exclude_id: Option<i64>) -> Result<bool, DevErpError> {
+        let _timer = QueryTimer::start("projects.code_exists");
         debug!("Checking if project code exists: {}", code);
 
         let count: i64 = if let Some(id) = exclude_id {
@@ -485,6 +616,7 @@ impl ProjectRepository for PostgresProjectRepository {
     }
 
     async fn find_by_tag(&self, tag: &str) -> Result<Vec<Project>, DevErpError> {
+        let _timer = QueryTimer::start("projects.find_by_tag");
         debug!("Finding projects by tag: {}", tag);
 
         let results = sqlx::query_as!(
@@ -494,8 +626,8 @@ impl ProjectRepository for PostgresProjectRepository {
                 id, uuid, name, description, code,
                 status as "status: _", priority as "priority: _",
                 start_date, end_date, actual_start_date, actual_end_date,
-                progress_percentage, repository_url, repository_branch,
-                tags, metadata,
+                progress_percentage, budget, currency, repository_url, repository_branch,
+                tags, metadata, version,
                 created_at, updated_at, deleted_at
             FROM projects
             WHERE $1 = ANY(tags) AND deleted_at IS NULL
@@ -510,6 +642,34 @@ impl ProjectRepository for PostgresProjectRepository {
 
         Ok(results)
     }
+
+    async fn find_overdue(&self, today: NaiveDate) -> Result<Vec<Project>, DevErpError> {
+        let _timer = QueryTimer::start("projects.find_overdue");
+        debug!("Finding active projects overdue as of {}", today);
+
+        let results = sqlx::query_as!(
+            Project,
+            r#"
+            SELECT
+                id, uuid, name, description, code,
+                status as "status: _", priority as "priority: _",
+                start_date, end_date, actual_start_date, actual_end_date,
+                progress_percentage, budget, currency, repository_url, repository_branch,
+                tags, metadata, version,
+                created_at, updated_at, deleted_at
+            FROM projects
+            WHERE status = 'active' AND end_date < $1 AND deleted_at IS NULL
+            ORDER BY end_date, id
+            "#,
+            today
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        debug!("Found {} overdue projects", results.len());
+
+        Ok(results)
+    }
 }
 
 #[cfg(test)]
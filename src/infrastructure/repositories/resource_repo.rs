@@ -1,15 +1,19 @@
 use async_trait::async_trait;
-use sqlx::PgPool;
+use sqlx::{PgExecutor, PgPool};
 use uuid::Uuid;
 
+use crate::domain::project::entity::TagMatchMode;
 use crate::domain::resource::{
     entity::{
-        CreateResource, LinkResourceToProject, ProjectResource, Resource, ResourceFilter,
-        ResourceUsageStats, UpdateProjectResource, UpdateResource,
+        CreateResource, CreateResourceDependency, CriticalDeprecatedResource,
+        LinkResourceToProject, ProjectResource, ProjectResourceDetail, Resource,
+        ResourceDependency, ResourceFilter, ResourceLicenseUsage, ResourceStatus, ResourceType,
+        ResourceUsageLink, ResourceUsageStats, UpdateProjectResource, UpdateResource,
     },
-    repository::ResourceRepository,
+    repository::{ResourceDependencyRepository, ResourceRepository},
 };
 use crate::utils::error::DevErpError;
+use crate::utils::timing::QueryTimer;
 
 /// PostgreSQL implementation of ResourceRepository
 pub struct PostgresResourceRepository {
@@ -23,9 +27,91 @@ impl PostgresResourceRepository {
     }
 }
 
+/// ResourceFilter flattened into nullable bind parameters
+///
+/// Shared by `find_all` and `count` so both apply every filter together.
+struct ResourceFilterParams {
+    resource_type: Option<String>,
+    status: Option<String>,
+    pattern: Option<String>,
+    tags: Option<Vec<String>>,
+    /// Require every tag (`@>`) instead of any (`&&`)
+    tags_all: bool,
+    stale_days: Option<i32>,
+    limit: Option<i64>,
+    offset: Option<i64>,
+}
+
+impl From<ResourceFilter> for ResourceFilterParams {
+    fn from(filter: ResourceFilter) -> Self {
+        Self {
+            resource_type: filter.resource_type.map(|t| t.to_string()),
+            status: filter.status.map(|s| s.to_string()),
+            pattern: filter.name_contains.map(|name| format!("%{}%", name)),
+            // An empty tag list is no filter, not "match nothing"
+            tags: filter.tags.filter(|tags| !tags.is_empty()),
+            tags_all: filter.tag_match_mode == TagMatchMode::All,
+            stale_days: filter.stale_days,
+            limit: filter.limit,
+            offset: filter.offset,
+        }
+    }
+}
+
+/// Apply a resource update on any executor (pool or open transaction)
+async fn update_resource<'e, E>(
+    executor: E,
+    resource: UpdateResource,
+) -> Result<Resource, DevErpError>
+where
+    E: PgExecutor<'e>,
+{
+    let rec = sqlx::query_as!(
+        Resource,
+        r#"
+        UPDATE resources
+        SET
+            name = COALESCE($2, name),
+            description = COALESCE($3, description),
+            resource_type = COALESCE($4, resource_type),
+            version = COALESCE($5, version),
+            url = COALESCE($6, url),
+            documentation_url = COALESCE($7, documentation_url),
+            license = COALESCE($8, license),
+            status = COALESCE($9, status),
+            metadata = COALESCE($10, metadata),
+            tags = COALESCE($11, tags)
+        WHERE id = $1 AND deleted_at IS NULL
+        RETURNING
+            id, uuid, name, description,
+            resource_type as "resource_type: _",
+            version, url, documentation_url, license,
+            status as "status: _",
+            metadata, tags,
+            created_at, updated_at, deleted_at
+        "#,
+        resource.id,
+        resource.name,
+        resource.description,
+        resource.resource_type.map(|rt| rt.to_string()),
+        resource.version,
+        resource.url,
+        resource.documentation_url,
+        resource.license,
+        resource.status.map(|s| s.to_string()),
+        resource.metadata,
+        resource.tags.as_deref(),
+    )
+    .fetch_one(executor)
+    .await?;
+
+    Ok(rec)
+}
+
 #[async_trait]
 impl ResourceRepository for PostgresResourceRepository {
     async fn create(&self, resource: CreateResource) -> Result<Resource, DevErpError> {
+        let _timer = QueryTimer::start("resources.create");
         let rec = sqlx::query_as!(
             Resource,
             r#"
@@ -60,6 +146,7 @@ impl ResourceRepository for PostgresResourceRepository {
     }
 
     async fn find_by_id(&self, id: i64) -> Result<Option<Resource>, DevErpError> {
+        let _timer = QueryTimer::start("resources.find_by_id");
         let resource = sqlx::query_as!(
             Resource,
             r#"
@@ -82,6 +169,7 @@ impl ResourceRepository for PostgresResourceRepository {
     }
 
     async fn find_by_uuid(&self, uuid: Uuid) -> Result<Option<Resource>, DevErpError> {
+        let _timer = QueryTimer::start("resources.find_by_uuid");
         let resource = sqlx::query_as!(
             Resource,
             r#"
@@ -103,172 +191,128 @@ impl ResourceRepository for PostgresResourceRepository {
         Ok(resource)
     }
 
-    async fn find_all(&self, filter: ResourceFilter) -> Result<Vec<Resource>, DevErpError> {
-        // For simplicity, we'll use a basic query with optional filters
-        // In production, consider using a query builder for complex filtering
-
-        let resources = if filter.resource_type.is_none()
-            && filter.status.is_none()
-            && filter.name_contains.is_none()
-            && filter.tags.is_none()
-        {
-            // No filters, get all resources
-            let query = sqlx::query_as!(
-                Resource,
-                r#"
-                SELECT
-                    id, uuid, name, description,
-                    resource_type as "resource_type: _",
-                    version, url, documentation_url, license,
-                    status as "status: _",
-                    metadata, tags,
-                    created_at, updated_at, deleted_at
-                FROM resources
-                WHERE deleted_at IS NULL
-                ORDER BY name ASC
-                "#
-            );
-
-            query.fetch_all(&self.pool).await?
-        } else if let Some(resource_type) = filter.resource_type {
-            // Filter by resource type
-            sqlx::query_as!(
-                Resource,
-                r#"
-                SELECT
-                    id, uuid, name, description,
-                    resource_type as "resource_type: _",
-                    version, url, documentation_url, license,
-                    status as "status: _",
-                    metadata, tags,
-                    created_at, updated_at, deleted_at
-                FROM resources
-                WHERE deleted_at IS NULL AND resource_type = $1
-                ORDER BY name ASC
-                "#,
-                resource_type as _
-            )
-            .fetch_all(&self.pool)
-            .await?
-        } else if let Some(status) = filter.status {
-            // Filter by status
-            sqlx::query_as!(
-                Resource,
-                r#"
-                SELECT
-                    id, uuid, name, description,
-                    resource_type as "resource_type: _",
-                    version, url, documentation_url, license,
-                    status as "status: _",
-                    metadata, tags,
-                    created_at, updated_at, deleted_at
-                FROM resources
-                WHERE deleted_at IS NULL AND status = $1
-                ORDER BY name ASC
-                "#,
-                status as _
-            )
-            .fetch_all(&self.pool)
-            .await?
-        } else if let Some(name_contains) = filter.name_contains {
-            // Filter by name
-            let pattern = format!("%{}%", name_contains);
-            sqlx::query_as!(
-                Resource,
-                r#"
-                SELECT
-                    id, uuid, name, description,
-                    resource_type as "resource_type: _",
-                    version, url, documentation_url, license,
-                    status as "status: _",
-                    metadata, tags,
-                    created_at, updated_at, deleted_at
-                FROM resources
-                WHERE deleted_at IS NULL AND name ILIKE $1
-                ORDER BY name ASC
-                "#,
-                pattern
-            )
-            .fetch_all(&self.pool)
-            .await?
-        } else {
-            // Default: return all
-            sqlx::query_as!(
-                Resource,
-                r#"
-                SELECT
-                    id, uuid, name, description,
-                    resource_type as "resource_type: _",
-                    version, url, documentation_url, license,
-                    status as "status: _",
-                    metadata, tags,
-                    created_at, updated_at, deleted_at
-                FROM resources
-                WHERE deleted_at IS NULL
-                ORDER BY name ASC
-                "#
-            )
-            .fetch_all(&self.pool)
-            .await?
-        };
-
-        // Apply pagination if specified
-        let offset = filter.offset.unwrap_or(0) as usize;
-        let limit = filter.limit.map(|l| l as usize);
-
-        let result: Vec<Resource> = resources
-            .into_iter()
-            .skip(offset)
-            .take(limit.unwrap_or(usize::MAX))
-            .collect();
+    async fn find_by_name_version(
+        &self,
+        name: &str,
+        version: Option<String>,
+    ) -> Result<Option<Resource>, DevErpError> {
+        let _timer = QueryTimer::start("resources.find_by_name_version");
+        let resource = sqlx::query_as!(
+            Resource,
+            r#"
+            SELECT
+                id, uuid, name, description,
+                resource_type as "resource_type: _",
+                version, url, documentation_url, license,
+                status as "status: _",
+                metadata, tags,
+                created_at, updated_at, deleted_at
+            FROM resources
+            WHERE name = $1 AND version IS NOT DISTINCT FROM $2 AND deleted_at IS NULL
+            ORDER BY id
+            LIMIT 1
+            "#,
+            name,
+            version
+        )
+        .fetch_optional(&self.pool)
+        .await?;
 
-        Ok(result)
+        Ok(resource)
     }
 
-    async fn update(&self, resource: UpdateResource) -> Result<Resource, DevErpError> {
-        let rec = sqlx::query_as!(
+    async fn find_all(&self, filter: ResourceFilter) -> Result<Vec<Resource>, DevErpError> {
+        let _timer = QueryTimer::start("resources.find_all");
+        let params = ResourceFilterParams::from(filter);
+
+        let resources = sqlx::query_as!(
             Resource,
             r#"
-            UPDATE resources
-            SET
-                name = COALESCE($2, name),
-                description = COALESCE($3, description),
-                resource_type = COALESCE($4, resource_type),
-                version = COALESCE($5, version),
-                url = COALESCE($6, url),
-                documentation_url = COALESCE($7, documentation_url),
-                license = COALESCE($8, license),
-                status = COALESCE($9, status),
-                metadata = COALESCE($10, metadata),
-                tags = COALESCE($11, tags)
-            WHERE id = $1 AND deleted_at IS NULL
-            RETURNING
+            SELECT
                 id, uuid, name, description,
                 resource_type as "resource_type: _",
                 version, url, documentation_url, license,
                 status as "status: _",
                 metadata, tags,
                 created_at, updated_at, deleted_at
+            FROM resources
+            WHERE deleted_at IS NULL
+              AND ($1::TEXT IS NULL OR resource_type = $1)
+              AND ($2::TEXT IS NULL OR status = $2)
+              AND ($3::TEXT IS NULL OR name ILIKE $3)
+              AND ($4::TEXT[] IS NULL OR CASE WHEN $8 THEN tags @> $4 ELSE tags && $4 END)
+              AND ($5::INT IS NULL OR updated_at < NOW() - make_interval(days => $5))
+            ORDER BY name ASC
+            LIMIT $6 OFFSET $7
             "#,
-            resource.id,
-            resource.name,
-            resource.description,
-            resource.resource_type.map(|rt| rt.to_string()),
-            resource.version,
-            resource.url,
-            resource.documentation_url,
-            resource.license,
-            resource.status.map(|s| s.to_string()),
-            resource.metadata,
-            resource.tags.as_deref(),
+            params.resource_type,
+            params.status,
+            params.pattern,
+            params.tags.as_deref(),
+            params.stale_days,
+            params.limit,
+            params.offset,
+            params.tags_all
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(resources)
+    }
+
+    async fn count(&self, filter: ResourceFilter) -> Result<i64, DevErpError> {
+        let _timer = QueryTimer::start("resources.count");
+
+        let params = ResourceFilterParams::from(filter);
+
+        let count = sqlx::query_scalar!(
+            r#"
+            SELECT COUNT(*) as "count!"
+            FROM resources
+            WHERE deleted_at IS NULL
+              AND ($1::TEXT IS NULL OR resource_type = $1)
+              AND ($2::TEXT IS NULL OR status = $2)
+              AND ($3::TEXT IS NULL OR name ILIKE $3)
+              AND ($4::TEXT[] IS NULL OR CASE WHEN $6 THEN tags @> $4 ELSE tags && $4 END)
+              AND ($5::INT IS NULL OR updated_at < NOW() - make_interval(days => $5))
+            "#,
+            params.resource_type,
+            params.status,
+            params.pattern,
+            params.tags.as_deref(),
+            params.stale_days,
+            params.tags_all
         )
         .fetch_one(&self.pool)
         .await?;
 
-        Ok(rec)
+        Ok(count)
+    }
+
+    async fn update(&self, resource: UpdateResource) -> Result<Resource, DevErpError> {
+        let _timer = QueryTimer::start("resources.update");
+        update_resource(&self.pool, resource).await
+    }
+
+    async fn update_many(
+        &self,
+        resources: Vec<UpdateResource>,
+    ) -> Result<Vec<Resource>, DevErpError> {
+        let _timer = QueryTimer::start("resources.update_many");
+        let mut tx = self.pool.begin().await?;
+
+        let mut updated = Vec::with_capacity(resources.len());
+        for resource in resources {
+            // Dropping the transaction on error rolls back earlier updates
+            updated.push(update_resource(&mut *tx, resource).await?);
+        }
+
+        tx.commit().await?;
+        Ok(updated)
     }
 
     async fn soft_delete(&self, id: i64) -> Result<bool, DevErpError> {
+        let _timer = QueryTimer::start("resources.soft_delete");
         let result = sqlx::query!(
             r#"
             UPDATE resources
@@ -283,7 +327,24 @@ impl ResourceRepository for PostgresResourceRepository {
         Ok(result.rows_affected() > 0)
     }
 
+    async fn restore(&self, id: i64) -> Result<bool, DevErpError> {
+        let _timer = QueryTimer::start("resources.restore");
+        let result = sqlx::query!(
+            r#"
+            UPDATE resources
+            SET deleted_at = NULL
+            WHERE id = $1 AND deleted_at IS NOT NULL
+            "#,
+            id
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
     async fn delete(&self, id: i64) -> Result<bool, DevErpError> {
+        let _timer = QueryTimer::start("resources.delete");
         let result = sqlx::query!(
             r#"
             DELETE FROM resources
@@ -301,6 +362,7 @@ impl ResourceRepository for PostgresResourceRepository {
         &self,
         link: LinkResourceToProject,
     ) -> Result<ProjectResource, DevErpError> {
+        let _timer = QueryTimer::start("resources.link_to_project");
         let rec = sqlx::query_as!(
             ProjectResource,
             r#"
@@ -329,6 +391,7 @@ impl ResourceRepository for PostgresResourceRepository {
         project_id: i64,
         resource_id: i64,
     ) -> Result<bool, DevErpError> {
+        let _timer = QueryTimer::start("resources.unlink_from_project");
         let result = sqlx::query!(
             r#"
             UPDATE project_resources
@@ -348,6 +411,7 @@ impl ResourceRepository for PostgresResourceRepository {
         &self,
         update: UpdateProjectResource,
     ) -> Result<ProjectResource, DevErpError> {
+        let _timer = QueryTimer::start("resources.update_project_resource");
         let rec = sqlx::query_as!(
             ProjectResource,
             r#"
@@ -374,6 +438,7 @@ impl ResourceRepository for PostgresResourceRepository {
     }
 
     async fn find_by_project_id(&self, project_id: i64) -> Result<Vec<Resource>, DevErpError> {
+        let _timer = QueryTimer::start("resources.find_by_project_id");
         let resources = sqlx::query_as!(
             Resource,
             r#"
@@ -399,10 +464,74 @@ impl ResourceRepository for PostgresResourceRepository {
         Ok(resources)
     }
 
+    async fn find_details_by_project_id(
+        &self,
+        project_id: i64,
+    ) -> Result<Vec<ProjectResourceDetail>, DevErpError> {
+        let _timer = QueryTimer::start("resources.find_details_by_project_id");
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                r.id, r.uuid, r.name, r.description,
+                r.resource_type as "resource_type: ResourceType",
+                r.version, r.url, r.documentation_url, r.license,
+                r.status as "status: ResourceStatus",
+                r.metadata, r.tags,
+                r.created_at, r.updated_at, r.deleted_at,
+                pr.project_id, pr.usage_notes, pr.version_used,
+                pr.is_critical, pr.added_at, pr.removed_at
+            FROM resources r
+            INNER JOIN project_resources pr ON r.id = pr.resource_id
+            WHERE pr.project_id = $1
+              AND r.deleted_at IS NULL
+              AND pr.removed_at IS NULL
+            ORDER BY pr.is_critical DESC NULLS LAST, r.name ASC
+            "#,
+            project_id
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        let details = rows
+            .into_iter()
+            .map(|row| ProjectResourceDetail {
+                resource: Resource {
+                    id: row.id,
+                    uuid: row.uuid,
+                    name: row.name,
+                    description: row.description,
+                    resource_type: row.resource_type,
+                    version: row.version,
+                    url: row.url,
+                    documentation_url: row.documentation_url,
+                    license: row.license,
+                    status: row.status,
+                    metadata: row.metadata,
+                    tags: row.tags,
+                    created_at: row.created_at,
+                    updated_at: row.updated_at,
+                    deleted_at: row.deleted_at,
+                },
+                link: ProjectResource {
+                    project_id: row.project_id,
+                    resource_id: row.id,
+                    usage_notes: row.usage_notes,
+                    version_used: row.version_used,
+                    is_critical: row.is_critical,
+                    added_at: row.added_at,
+                    removed_at: row.removed_at,
+                },
+            })
+            .collect();
+
+        Ok(details)
+    }
+
     async fn find_projects_using_resource(
         &self,
         resource_id: i64,
     ) -> Result<Vec<i64>, DevErpError> {
+        let _timer = QueryTimer::start("resources.find_projects_using_resource");
         let project_ids = sqlx::query_scalar!(
             r#"
             SELECT project_id
@@ -418,6 +547,7 @@ impl ResourceRepository for PostgresResourceRepository {
     }
 
     async fn get_usage_stats(&self, resource_id: i64) -> Result<ResourceUsageStats, DevErpError> {
+        let _timer = QueryTimer::start("resources.get_usage_stats");
         let stats = sqlx::query_as!(
             ResourceUsageStats,
             r#"
@@ -441,6 +571,7 @@ impl ResourceRepository for PostgresResourceRepository {
     }
 
     async fn get_all_usage_stats(&self) -> Result<Vec<ResourceUsageStats>, DevErpError> {
+        let _timer = QueryTimer::start("resources.get_all_usage_stats");
         let stats = sqlx::query_as!(
             ResourceUsageStats,
             r#"
@@ -462,4 +593,228 @@ impl ResourceRepository for PostgresResourceRepository {
 
         Ok(stats)
     }
+
+    async fn get_usage_matrix(&self) -> Result<Vec<ResourceUsageLink>, DevErpError> {
+        let _timer = QueryTimer::start("resources.get_usage_matrix");
+        let links = sqlx::query_as!(
+            ResourceUsageLink,
+            r#"
+            SELECT
+                r.id as resource_id,
+                r.name as resource_name,
+                p.id as project_id,
+                p.name as project_name,
+                pr.version_used,
+                COALESCE(pr.is_critical, false) as "is_critical!"
+            FROM project_resources pr
+            INNER JOIN resources r ON r.id = pr.resource_id
+            INNER JOIN projects p ON p.id = pr.project_id
+            WHERE pr.removed_at IS NULL
+              AND r.deleted_at IS NULL
+              AND p.deleted_at IS NULL
+            ORDER BY r.name ASC, p.name ASC
+            "#
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(links)
+    }
+
+    async fn find_critical_deprecated(
+        &self,
+    ) -> Result<Vec<CriticalDeprecatedResource>, DevErpError> {
+        let _timer = QueryTimer::start("resources.find_critical_deprecated");
+        let resources = sqlx::query_as!(
+            CriticalDeprecatedResource,
+            r#"
+            SELECT
+                r.id as resource_id,
+                r.name as resource_name,
+                array_agg(pr.project_id ORDER BY pr.project_id) as "affected_project_ids!"
+            FROM project_resources pr
+            INNER JOIN resources r ON r.id = pr.resource_id
+            INNER JOIN projects p ON p.id = pr.project_id
+            WHERE pr.is_critical
+              AND pr.removed_at IS NULL
+              AND r.status = 'deprecated'
+              AND r.deleted_at IS NULL
+              AND p.deleted_at IS NULL
+            GROUP BY r.id, r.name
+            ORDER BY r.name ASC, r.id ASC
+            "#
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(resources)
+    }
+
+    async fn get_license_usage(&self) -> Result<Vec<ResourceLicenseUsage>, DevErpError> {
+        let _timer = QueryTimer::start("resources.get_license_usage");
+        let rows = sqlx::query_as!(
+            ResourceLicenseUsage,
+            r#"
+            SELECT
+                r.id as resource_id,
+                r.name as resource_name,
+                r.version,
+                r.license,
+                p.id as "project_id?",
+                p.name as "project_name?"
+            FROM resources r
+            -- Links to deleted projects drop out inside the join, so a
+            -- resource used only by deleted projects is still listed (unused)
+            LEFT JOIN (
+                project_resources pr
+                JOIN projects p ON p.id = pr.project_id AND p.deleted_at IS NULL
+            ) ON pr.resource_id = r.id AND pr.removed_at IS NULL
+            WHERE r.deleted_at IS NULL
+            ORDER BY r.name ASC, r.id ASC, p.name ASC
+            "#
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows)
+    }
+}
+
+/// Advisory lock key serializing writes to the resource dependency graph
+const RESOURCE_DEPENDENCY_GRAPH_LOCK: i64 = 0x7265_7364_6570_7321;
+
+/// PostgreSQL implementation of ResourceDependencyRepository
+pub struct PostgresResourceDependencyRepository {
+    pool: PgPool,
+}
+
+impl PostgresResourceDependencyRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ResourceDependencyRepository for PostgresResourceDependencyRepository {
+    async fn add(
+        &self,
+        dependency: CreateResourceDependency,
+    ) -> Result<ResourceDependency, DevErpError> {
+        let _timer = QueryTimer::start("resource_dependencies.add");
+        dependency.validate().map_err(DevErpError::Validation)?;
+
+        // As with task dependencies, a transaction-scoped advisory lock
+        // serializes every edge insert, so two concurrent additions cannot
+        // each pass the cycle check and close a cycle together.
+        let mut tx = self.pool.begin().await?;
+        sqlx::query!(
+            "SELECT pg_advisory_xact_lock($1)",
+            RESOURCE_DEPENDENCY_GRAPH_LOCK
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        let exists = sqlx::query_scalar!(
+            r#"
+            SELECT EXISTS(
+                SELECT 1 FROM resource_dependencies
+                WHERE resource_id = $1 AND depends_on_resource_id = $2
+            ) as "exists!"
+            "#,
+            dependency.resource_id,
+            dependency.depends_on_resource_id
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+        if exists {
+            return Err(DevErpError::Conflict(format!(
+                "Dependency already exists: resource {} already depends on resource {}",
+                dependency.resource_id, dependency.depends_on_resource_id
+            )));
+        }
+
+        // Walk every edge, soft-deleted resources included: restoring one
+        // must not bring back a cycle. UNION stops at nodes already visited.
+        let closes_cycle = sqlx::query_scalar!(
+            r#"
+            WITH RECURSIVE chain(id) AS (
+                SELECT $1::BIGINT
+                UNION
+                SELECT d.depends_on_resource_id
+                FROM resource_dependencies d
+                JOIN chain c ON d.resource_id = c.id
+            )
+            SELECT EXISTS(SELECT 1 FROM chain WHERE id = $2) as "found!"
+            "#,
+            dependency.depends_on_resource_id,
+            dependency.resource_id
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+        if closes_cycle {
+            return Err(DevErpError::Validation(format!(
+                "Adding this dependency would create a circular dependency: resource {} already depends on resource {}",
+                dependency.depends_on_resource_id, dependency.resource_id
+            )));
+        }
+
+        let added = sqlx::query_as!(
+            ResourceDependency,
+            r#"
+            INSERT INTO resource_dependencies (resource_id, depends_on_resource_id)
+            VALUES ($1, $2)
+            RETURNING resource_id, depends_on_resource_id, created_at
+            "#,
+            dependency.resource_id,
+            dependency.depends_on_resource_id
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(added)
+    }
+
+    async fn remove(
+        &self,
+        resource_id: i64,
+        depends_on_resource_id: i64,
+    ) -> Result<bool, DevErpError> {
+        let _timer = QueryTimer::start("resource_dependencies.remove");
+        let result = sqlx::query!(
+            r#"
+            DELETE FROM resource_dependencies
+            WHERE resource_id = $1 AND depends_on_resource_id = $2
+            "#,
+            resource_id,
+            depends_on_resource_id
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn get_dependencies(
+        &self,
+        resource_id: i64,
+    ) -> Result<Vec<ResourceDependency>, DevErpError> {
+        let _timer = QueryTimer::start("resource_dependencies.get_dependencies");
+        // Soft-deleted resources no longer count as dependencies
+        let dependencies = sqlx::query_as!(
+            ResourceDependency,
+            r#"
+            SELECT d.resource_id, d.depends_on_resource_id, d.created_at
+            FROM resource_dependencies d
+            INNER JOIN resources r ON r.id = d.depends_on_resource_id
+            WHERE d.resource_id = $1 AND r.deleted_at IS NULL
+            ORDER BY d.depends_on_resource_id
+            "#,
+            resource_id
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(dependencies)
+    }
 }
@@ -0,0 +1,80 @@
+// PostgreSQL implementation of SnapshotRepository
+
+use async_trait::async_trait;
+use sqlx::PgPool;
+use tracing::debug;
+
+use crate::domain::snapshot::{
+    entity::{CreateStatusSnapshot, StatusSnapshot},
+    repository::SnapshotRepository,
+};
+use crate::utils::error::DevErpError;
+use crate::utils::timing::QueryTimer;
+
+/// PostgreSQL implementation of the SnapshotRepository trait
+pub struct PostgresSnapshotRepository {
+    pool: PgPool,
+}
+
+impl PostgresSnapshotRepository {
+    /// Create a new PostgreSQL snapshot repository
+    ///
+    /// # Arguments
+    /// * `pool` - PostgreSQL connection pool
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl SnapshotRepository for PostgresSnapshotRepository {
+    async fn create(&self, snapshot: CreateStatusSnapshot) -> Result<StatusSnapshot, DevErpError> {
+        let _timer = QueryTimer::start("status_snapshots.create");
+        debug!("Recording status snapshot");
+
+        let result = sqlx::query_as!(
+            StatusSnapshot,
+            r#"
+            INSERT INTO status_snapshots (
+                total_projects, active_projects, completed_projects,
+                delayed_projects, average_progress
+            )
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING
+                id, total_projects, active_projects, completed_projects,
+                delayed_projects, average_progress, captured_at
+            "#,
+            snapshot.total_projects,
+            snapshot.active_projects,
+            snapshot.completed_projects,
+            snapshot.delayed_projects,
+            snapshot.average_progress
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(result)
+    }
+
+    async fn find_recent(&self, limit: i64) -> Result<Vec<StatusSnapshot>, DevErpError> {
+        let _timer = QueryTimer::start("status_snapshots.find_recent");
+        debug!("Finding last {} status snapshots", limit);
+
+        let results = sqlx::query_as!(
+            StatusSnapshot,
+            r#"
+            SELECT
+                id, total_projects, active_projects, completed_projects,
+                delayed_projects, average_progress, captured_at
+            FROM status_snapshots
+            ORDER BY captured_at DESC, id DESC
+            LIMIT $1
+            "#,
+            limit
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(results)
+    }
+}
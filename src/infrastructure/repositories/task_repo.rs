@@ -1,14 +1,134 @@
 use async_trait::async_trait;
-use chrono::Utc;
-use sqlx::PgPool;
+use chrono::{DateTime, Utc};
+use sqlx::{PgExecutor, PgPool, Postgres, QueryBuilder};
 use std::collections::{HashSet, VecDeque};
 use uuid::Uuid;
 
+use super::stale_update_error;
+use crate::domain::task::graph::find_cycles;
 use crate::domain::task::{
-    CreateTask, CreateTaskComment, CreateTaskDependency, Task, TaskComment, TaskCommentRepository,
-    TaskDependency, TaskDependencyRepository, TaskFilter, TaskRepository, UpdateTask,
+    AssigneeStatusCount, CreateTask, CreateTaskComment, CreateTaskDependency, CreateTaskWorkLog,
+    ProjectTaskCount, Task, TaskAssignee, TaskAssigneeRepository, TaskComment,
+    TaskCommentRepository, TaskDependency, TaskDependencyRepository, TaskFilter, TaskRepository,
+    TaskStatus, TaskWorkLog, TaskWorkLogRepository, UpdateTask,
 };
 use crate::utils::error::DevErpError;
+use crate::utils::timing::QueryTimer;
+
+/// Append the `AND ...` conditions for a task filter, binding every value
+///
+/// Shared by `find_all` and `count` so both always agree on which rows match.
+fn push_task_filter(query: &mut QueryBuilder<'_, Postgres>, filter: &TaskFilter) {
+    if !filter.include_deleted {
+        query.push(" AND deleted_at IS NULL");
+    }
+
+    if let Some(project_id) = filter.project_id {
+        query.push(" AND project_id = ").push_bind(project_id);
+    }
+
+    if let Some(ref project_ids) = filter.project_ids {
+        query
+            .push(" AND project_id = ANY(")
+            .push_bind(project_ids.clone())
+            .push(")");
+    }
+
+    if let Some(ref status) = filter.status {
+        query.push(" AND status = ").push_bind(status.to_string());
+    }
+
+    if let Some(ref priority) = filter.priority {
+        query
+            .push(" AND priority = ")
+            .push_bind(priority.to_string());
+    }
+
+    if let Some(ref task_type) = filter.task_type {
+        query
+            .push(" AND task_type = ")
+            .push_bind(task_type.to_string());
+    }
+
+    if let Some(ref assigned_to) = filter.assigned_to {
+        query
+            .push(" AND assigned_to = ")
+            .push_bind(assigned_to.clone());
+    }
+
+    if let Some(parent_task_id) = filter.parent_task_id {
+        query
+            .push(" AND parent_task_id = ")
+            .push_bind(parent_task_id);
+    }
+
+    if let Some(milestone_id) = filter.milestone_id {
+        query.push(" AND milestone_id = ").push_bind(milestone_id);
+    }
+
+    if let Some(root_id) = filter.subtree_of {
+        // Walk down from the root; the visited path stops a corrupt parent
+        // cycle from recursing forever
+        query
+            .push(
+                " AND id IN (
+                    WITH RECURSIVE subtree (id, path) AS (
+                        SELECT id, ARRAY[id] FROM tasks WHERE id = ",
+            )
+            .push_bind(root_id)
+            .push(
+                "
+                        UNION ALL
+                        SELECT child.id, subtree.path || child.id
+                        FROM tasks child
+                        JOIN subtree ON child.parent_task_id = subtree.id
+                        WHERE NOT child.id = ANY(subtree.path)
+                    )
+                    SELECT id FROM subtree
+                )",
+            );
+    }
+
+    if let Some(search) = filter.search.as_deref().map(str::trim) {
+        if !search.is_empty() {
+            let pattern = format!("%{}%", search);
+            query
+                .push(" AND (title ILIKE ")
+                .push_bind(pattern.clone())
+                .push(" OR description ILIKE ")
+                .push_bind(pattern.clone());
+            if filter.search_comments {
+                query
+                    .push(
+                        " OR EXISTS (
+                        SELECT 1 FROM task_comments tc
+                        WHERE tc.task_id = tasks.id
+                          AND tc.deleted_at IS NULL
+                          AND tc.comment_text ILIKE ",
+                    )
+                    .push_bind(pattern)
+                    .push(")");
+            }
+            query.push(")");
+        }
+    }
+
+    if filter.estimate_missing {
+        query.push(" AND estimated_hours IS NULL");
+    }
+
+    if filter.actual_missing {
+        query.push(" AND actual_hours IS NULL");
+    }
+
+    if let Some(updated_after) = filter.updated_after {
+        query.push(" AND updated_at >= ").push_bind(updated_after);
+    }
+
+    if let Some(updated_before) = filter.updated_before {
+        query.push(" AND updated_at < ").push_bind(updated_before);
+    }
+}
 
 /// PostgreSQL implementation of TaskRepository
 pub struct PostgresTaskRepository {
@@ -21,53 +141,130 @@ impl PostgresTaskRepository {
     }
 }
 
+/// Insert a task row on any executor (pool or open transaction)
+pub(crate) async fn insert_task<'e, E>(executor: E, task: CreateTask) -> Result<Task, DevErpError>
+where
+    E: PgExecutor<'e>,
+{
+    let task = sqlx::query_as!(
+        Task,
+        r#"
+        INSERT INTO tasks (
+            project_id, parent_task_id, title, description, task_number,
+            status, priority, assigned_to, estimated_hours, due_date, task_type, tags,
+            started_at, blocked_reason
+        )
+        VALUES (
+            $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12,
+            CASE WHEN $6::VARCHAR = 'in_progress' THEN NOW() END, $13
+        )
+        RETURNING
+            id, uuid, project_id, parent_task_id, milestone_id, title, description, task_number,
+            status as "status: _", blocked_reason, priority as "priority: _", assigned_to,
+            estimated_hours, actual_hours, due_date, started_at, completed_at,
+            task_type as "task_type: _", tags, version, created_at, updated_at, deleted_at
+        "#,
+        task.project_id,
+        task.parent_task_id,
+        task.title,
+        task.description,
+        task.task_number,
+        task.status.unwrap_or_default().to_string(),
+        task.priority.unwrap_or_default().to_string(),
+        task.assigned_to,
+        task.estimated_hours,
+        task.due_date,
+        task.task_type.unwrap_or_default().to_string(),
+        task.tags.as_deref(),
+        task.blocked_reason,
+    )
+    .fetch_one(executor)
+    .await?;
+
+    Ok(task)
+}
+
 #[async_trait]
 impl TaskRepository for PostgresTaskRepository {
     async fn create(&self, task: CreateTask) -> Result<Task, DevErpError> {
+        let _timer = QueryTimer::start("tasks.create");
         task.validate().map_err(DevErpError::Validation)?;
 
-        let task = sqlx::query_as!(
-            Task,
-            r#"
-            INSERT INTO tasks (
-                project_id, parent_task_id, title, description, task_number,
-                status, priority, assigned_to, estimated_hours, due_date, task_type, tags
-            )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
-            RETURNING
-                id, uuid, project_id, parent_task_id, title, description, task_number,
-                status as "status: _", priority as "priority: _", assigned_to,
-                estimated_hours, actual_hours, due_date, started_at, completed_at,
-                task_type as "task_type: _", tags, created_at, updated_at, deleted_at
-            "#,
-            task.project_id,
-            task.parent_task_id,
-            task.title,
-            task.description,
-            task.task_number,
-            task.status.unwrap_or_default().to_string(),
-            task.priority.unwrap_or_default().to_string(),
-            task.assigned_to,
-            task.estimated_hours,
-            task.due_date,
-            task.task_type.unwrap_or_default().to_string(),
-            task.tags.as_deref(),
-        )
-        .fetch_one(&self.pool)
-        .await?;
+        let task = insert_task(&self.pool, task).await?;
 
         Ok(task)
     }
 
+    async fn create_with_dependencies(
+        &self,
+        task: CreateTask,
+        depends_on: &[i64],
+    ) -> Result<(Task, Vec<TaskDependency>), DevErpError> {
+        let _timer = QueryTimer::start("tasks.create_with_dependencies");
+        task.validate().map_err(DevErpError::Validation)?;
+
+        // Any early return drops the transaction, which rolls back the task
+        // together with the dependencies added so far
+        let mut tx = self.pool.begin().await?;
+        lock_dependency_graph(&mut *tx).await?;
+
+        let task = insert_task(&mut *tx, task).await?;
+
+        let mut dependencies: Vec<TaskDependency> = Vec::with_capacity(depends_on.len());
+        for &depends_on_task_id in depends_on {
+            let dependency = CreateTaskDependency {
+                task_id: task.id,
+                depends_on_task_id,
+                dependency_type: None,
+            };
+            dependency.validate().map_err(DevErpError::Validation)?;
+
+            if dependencies
+                .iter()
+                .any(|d| d.depends_on_task_id == depends_on_task_id)
+            {
+                return Err(DevErpError::Conflict(format!(
+                    "Dependency already exists: task {} already depends on task {}",
+                    task.id, depends_on_task_id
+                )));
+            }
+
+            let exists = sqlx::query_scalar!(
+                r#"SELECT EXISTS(SELECT 1 FROM tasks WHERE id = $1 AND deleted_at IS NULL) as "exists!""#,
+                depends_on_task_id
+            )
+            .fetch_one(&mut *tx)
+            .await?;
+            if !exists {
+                return Err(DevErpError::NotFound(format!(
+                    "Task with id {} not found",
+                    depends_on_task_id
+                )));
+            }
+
+            if reaches(&mut *tx, depends_on_task_id, task.id).await? {
+                return Err(DevErpError::Validation(
+                    "Adding this dependency would create a circular dependency".to_string(),
+                ));
+            }
+
+            dependencies.push(insert_dependency(&mut *tx, dependency).await?);
+        }
+
+        tx.commit().await?;
+        Ok((task, dependencies))
+    }
+
     async fn find_by_id(&self, id: i64) -> Result<Option<Task>, DevErpError> {
+        let _timer = QueryTimer::start("tasks.find_by_id");
         let task = sqlx::query_as!(
             Task,
             r#"
             SELECT
-                id, uuid, project_id, parent_task_id, title, description, task_number,
-                status as "status: _", priority as "priority: _", assigned_to,
+                id, uuid, project_id, parent_task_id, milestone_id, title, description, task_number,
+                status as "status: _", blocked_reason, priority as "priority: _", assigned_to,
                 estimated_hours, actual_hours, due_date, started_at, completed_at,
-                task_type as "task_type: _", tags, created_at, updated_at, deleted_at
+                task_type as "task_type: _", tags, version, created_at, updated_at, deleted_at
             FROM tasks
             WHERE id = $1 AND deleted_at IS NULL
             "#,
@@ -80,14 +277,15 @@ impl TaskRepository for PostgresTaskRepository {
     }
 
     async fn find_by_uuid(&self, uuid: Uuid) -> Result<Option<Task>, DevErpError> {
+        let _timer = QueryTimer::start("tasks.find_by_uuid");
         let task = sqlx::query_as!(
             Task,
             r#"
             SELECT
-                id, uuid, project_id, parent_task_id, title, description, task_number,
-                status as "status: _", priority as "priority: _", assigned_to,
+                id, uuid, project_id, parent_task_id, milestone_id, title, description, task_number,
+                status as "status: _", blocked_reason, priority as "priority: _", assigned_to,
                 estimated_hours, actual_hours, due_date, started_at, completed_at,
-                task_type as "task_type: _", tags, created_at, updated_at, deleted_at
+                task_type as "task_type: _", tags, version, created_at, updated_at, deleted_at
             FROM tasks
             WHERE uuid = $1 AND deleted_at IS NULL
             "#,
@@ -100,64 +298,37 @@ impl TaskRepository for PostgresTaskRepository {
     }
 
     async fn find_all(&self, filter: TaskFilter) -> Result<Vec<Task>, DevErpError> {
-        let mut query = String::from(
+        let _timer = QueryTimer::start("tasks.find_all");
+        let mut query = QueryBuilder::<Postgres>::new(
             r#"
             SELECT
-                id, uuid, project_id, parent_task_id, title, description, task_number,
-                status, priority, assigned_to, estimated_hours, actual_hours,
-                due_date, started_at, completed_at, task_type, tags,
+                id, uuid, project_id, parent_task_id, milestone_id, title, description, task_number,
+                status, blocked_reason, priority, assigned_to, estimated_hours, actual_hours,
+                due_date, started_at, completed_at, task_type, tags, version,
                 created_at, updated_at, deleted_at
             FROM tasks
             WHERE 1=1
             "#,
         );
+        push_task_filter(&mut query, &filter);
 
-        if !filter.include_deleted {
-            query.push_str(" AND deleted_at IS NULL");
-        }
-
-        if let Some(project_id) = filter.project_id {
-            query.push_str(&format!(" AND project_id = {}", project_id));
-        }
-
-        if let Some(ref status) = filter.status {
-            query.push_str(&format!(" AND status = '{}'", status));
-        }
-
-        if let Some(ref priority) = filter.priority {
-            query.push_str(&format!(" AND priority = '{}'", priority));
-        }
-
-        if let Some(ref task_type) = filter.task_type {
-            query.push_str(&format!(" AND task_type = '{}'", task_type));
-        }
-
-        if let Some(ref assigned_to) = filter.assigned_to {
-            query.push_str(&format!(" AND assigned_to = '{}'", assigned_to));
-        }
-
-        if let Some(parent_task_id) = filter.parent_task_id {
-            query.push_str(&format!(" AND parent_task_id = {}", parent_task_id));
-        }
-
-        query.push_str(" ORDER BY created_at DESC");
+        query.push(" ").push(filter.order_by_clause());
 
         if let Some(limit) = filter.limit {
-            query.push_str(&format!(" LIMIT {}", limit));
+            query.push(" LIMIT ").push_bind(limit);
         }
 
         if let Some(offset) = filter.offset {
-            query.push_str(&format!(" OFFSET {}", offset));
+            query.push(" OFFSET ").push_bind(offset);
         }
 
-        let tasks = sqlx::query_as::<_, Task>(&query)
-            .fetch_all(&self.pool)
-            .await?;
+        let tasks = query.build_query_as::<Task>().fetch_all(&self.pool).await?;
 
         Ok(tasks)
     }
 
     async fn update(&self, task: UpdateTask) -> Result<Task, DevErpError> {
+        let _timer = QueryTimer::start("tasks.update");
         task.validate().map_err(DevErpError::Validation)?;
 
         // Build dynamic update query
@@ -202,24 +373,41 @@ impl TaskRepository for PostgresTaskRepository {
         }
         if task.tags.is_some() {
             updates.push(format!("tags = ${}", args_index));
+            args_index += 1;
+        }
+        if task.blocked_reason.is_some() {
+            updates.push(format!("blocked_reason = ${}", args_index));
+            args_index += 1;
+        }
+        // A reason only makes sense while blocked; drop it on any other status
+        if matches!(task.status, Some(ref status) if *status != TaskStatus::Blocked) {
+            updates.push("blocked_reason = NULL".to_string());
         }
 
         if updates.is_empty() {
             return Err(DevErpError::Validation("No fields to update".to_string()));
         }
 
+        // Only guard on the version when the caller read one
+        let version_guard = if task.expected_version.is_some() {
+            format!(" AND version = ${}", args_index)
+        } else {
+            String::new()
+        };
+
         let query = format!(
             r#"
             UPDATE tasks
             SET {}
-            WHERE id = $1 AND deleted_at IS NULL
+            WHERE id = $1 AND deleted_at IS NULL{}
             RETURNING
-                id, uuid, project_id, parent_task_id, title, description, task_number,
-                status, priority, assigned_to, estimated_hours, actual_hours,
-                due_date, started_at, completed_at, task_type, tags,
+                id, uuid, project_id, parent_task_id, milestone_id, title, description, task_number,
+                status, blocked_reason, priority, assigned_to, estimated_hours, actual_hours,
+                due_date, started_at, completed_at, task_type, tags, version,
                 created_at, updated_at, deleted_at
             "#,
-            updates.join(", ")
+            updates.join(", "),
+            version_guard
         );
 
         let mut query_builder = sqlx::query_as::<_, Task>(&query);
@@ -255,13 +443,23 @@ impl TaskRepository for PostgresTaskRepository {
         if let Some(tags) = task.tags {
             query_builder = query_builder.bind(tags);
         }
+        if let Some(blocked_reason) = task.blocked_reason {
+            query_builder = query_builder.bind(blocked_reason);
+        }
+        if let Some(expected_version) = task.expected_version {
+            query_builder = query_builder.bind(expected_version);
+        }
 
-        let updated_task = query_builder.fetch_one(&self.pool).await?;
+        let updated_task = query_builder
+            .fetch_optional(&self.pool)
+            .await?
+            .ok_or_else(|| stale_update_error("Task", task.id, task.expected_version))?;
 
         Ok(updated_task)
     }
 
     async fn delete(&self, id: i64) -> Result<bool, DevErpError> {
+        let _timer = QueryTimer::start("tasks.delete");
         let result = sqlx::query!(
             r#"
             DELETE FROM tasks WHERE id = $1
@@ -275,6 +473,7 @@ impl TaskRepository for PostgresTaskRepository {
     }
 
     async fn soft_delete(&self, id: i64) -> Result<bool, DevErpError> {
+        let _timer = QueryTimer::start("tasks.soft_delete");
         let result = sqlx::query!(
             r#"
             UPDATE tasks
@@ -290,35 +489,196 @@ impl TaskRepository for PostgresTaskRepository {
         Ok(result.rows_affected() > 0)
     }
 
+    async fn restore(&self, id: i64) -> Result<bool, DevErpError> {
+        let _timer = QueryTimer::start("tasks.restore");
+        let result = sqlx::query!(
+            r#"
+            UPDATE tasks
+            SET deleted_at = NULL
+            WHERE id = $1 AND deleted_at IS NOT NULL
+            "#,
+            id
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
     async fn count(&self, filter: TaskFilter) -> Result<i64, DevErpError> {
-        let mut query = String::from("SELECT COUNT(*) as count FROM tasks WHERE 1=1");
+        let _timer = QueryTimer::start("tasks.count");
+        let mut query = QueryBuilder::<Postgres>::new("SELECT COUNT(*) FROM tasks WHERE 1=1");
+        push_task_filter(&mut query, &filter);
 
-        if !filter.include_deleted {
-            query.push_str(" AND deleted_at IS NULL");
-        }
+        let count: i64 = query.build_query_scalar().fetch_one(&self.pool).await?;
 
-        if let Some(project_id) = filter.project_id {
-            query.push_str(&format!(" AND project_id = {}", project_id));
-        }
+        Ok(count)
+    }
 
-        if let Some(ref status) = filter.status {
-            query.push_str(&format!(" AND status = '{}'", status));
-        }
+    async fn count_by_assignee(&self) -> Result<Vec<AssigneeStatusCount>, DevErpError> {
+        let _timer = QueryTimer::start("tasks.count_by_assignee");
+        let counts = sqlx::query_as!(
+            AssigneeStatusCount,
+            r#"
+            SELECT
+                assigned_to,
+                status as "status: _",
+                COUNT(*) as "count!"
+            FROM tasks
+            WHERE deleted_at IS NULL
+            GROUP BY assigned_to, status
+            ORDER BY assigned_to NULLS LAST, status
+            "#
+        )
+        .fetch_all(&self.pool)
+        .await?;
 
-        if let Some(ref priority) = filter.priority {
-            query.push_str(&format!(" AND priority = '{}'", priority));
-        }
+        Ok(counts)
+    }
 
-        if let Some(ref task_type) = filter.task_type {
-            query.push_str(&format!(" AND task_type = '{}'", task_type));
-        }
+    async fn count_by_project(
+        &self,
+        project_ids: &[i64],
+    ) -> Result<Vec<ProjectTaskCount>, DevErpError> {
+        let _timer = QueryTimer::start("tasks.count_by_project");
+        let counts = sqlx::query_as!(
+            ProjectTaskCount,
+            r#"
+            SELECT
+                project_id,
+                COUNT(*) as "total_tasks!",
+                COUNT(*) FILTER (WHERE status = 'done') as "completed_tasks!"
+            FROM tasks
+            WHERE project_id = ANY($1) AND deleted_at IS NULL
+            GROUP BY project_id
+            "#,
+            project_ids
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(counts)
+    }
 
-        let result: (i64,) = sqlx::query_as(&query).fetch_one(&self.pool).await?;
+    async fn last_status_change(&self, task_id: i64) -> Result<Option<DateTime<Utc>>, DevErpError> {
+        let _timer = QueryTimer::start("tasks.last_status_change");
+        let changed_at = sqlx::query_scalar!(
+            r#"
+            SELECT MAX(created_at)
+            FROM audit_logs
+            WHERE entity_type = 'task' AND entity_id = $1 AND action = 'status_change'
+            "#,
+            task_id
+        )
+        .fetch_one(&self.pool)
+        .await?;
 
-        Ok(result.0)
+        Ok(changed_at)
+    }
+
+    async fn move_to_project(
+        &self,
+        task_id: i64,
+        project_id: i64,
+        clear_parent: bool,
+    ) -> Result<Task, DevErpError> {
+        let _timer = QueryTimer::start("tasks.move_to_project");
+        // Milestones belong to a single project, so the old link cannot follow the task
+        let task = sqlx::query_as!(
+            Task,
+            r#"
+            UPDATE tasks
+            SET project_id = $2,
+                parent_task_id = CASE WHEN $3 THEN NULL ELSE parent_task_id END,
+                milestone_id = NULL
+            WHERE id = $1 AND deleted_at IS NULL
+            RETURNING
+                id, uuid, project_id, parent_task_id, milestone_id, title, description, task_number,
+                status as "status: _", blocked_reason, priority as "priority: _", assigned_to,
+                estimated_hours, actual_hours, due_date, started_at, completed_at,
+                task_type as "task_type: _", tags, version, created_at, updated_at, deleted_at
+            "#,
+            task_id,
+            project_id,
+            clear_parent
+        )
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or_else(|| DevErpError::NotFound(format!("Task with id {} not found", task_id)))?;
+
+        Ok(task)
     }
 }
 
+/// Advisory lock key serializing writes to the task dependency graph
+const DEPENDENCY_GRAPH_LOCK: i64 = 0x7461_736b_6465_7073;
+
+/// Take the dependency graph lock for the rest of the transaction
+async fn lock_dependency_graph<'e, E>(executor: E) -> Result<(), DevErpError>
+where
+    E: PgExecutor<'e>,
+{
+    sqlx::query!("SELECT pg_advisory_xact_lock($1)", DEPENDENCY_GRAPH_LOCK)
+        .execute(executor)
+        .await?;
+
+    Ok(())
+}
+
+/// Insert a dependency row on any executor (pool or open transaction)
+async fn insert_dependency<'e, E>(
+    executor: E,
+    dependency: CreateTaskDependency,
+) -> Result<TaskDependency, DevErpError>
+where
+    E: PgExecutor<'e>,
+{
+    let task_dep = sqlx::query_as!(
+        TaskDependency,
+        r#"
+        INSERT INTO task_dependencies (task_id, depends_on_task_id, dependency_type)
+        VALUES ($1, $2, $3)
+        RETURNING
+            task_id, depends_on_task_id,
+            dependency_type as "dependency_type: _",
+            created_at
+        "#,
+        dependency.task_id,
+        dependency.depends_on_task_id,
+        dependency.dependency_type.unwrap_or_default().to_string()
+    )
+    .fetch_one(executor)
+    .await?;
+
+    Ok(task_dep)
+}
+
+/// Whether `task_id` is reachable by following dependencies from `start_task_id`
+async fn reaches<'e, E>(executor: E, start_task_id: i64, task_id: i64) -> Result<bool, DevErpError>
+where
+    E: PgExecutor<'e>,
+{
+    // UNION (not UNION ALL) stops the walk at nodes already visited
+    let found = sqlx::query_scalar!(
+        r#"
+        WITH RECURSIVE chain(id) AS (
+            SELECT $1::BIGINT
+            UNION
+            SELECT d.depends_on_task_id
+            FROM task_dependencies d
+            JOIN chain c ON d.task_id = c.id
+        )
+        SELECT EXISTS(SELECT 1 FROM chain WHERE id = $2) as "found!"
+        "#,
+        start_task_id,
+        task_id
+    )
+    .fetch_one(executor)
+    .await?;
+
+    Ok(found)
+}
+
 /// PostgreSQL implementation of TaskDependencyRepository
 pub struct PostgresTaskDependencyRepository {
     pool: PgPool,
@@ -336,35 +696,27 @@ impl TaskDependencyRepository for PostgresTaskDependencyRepository {
         &self,
         dependency: CreateTaskDependency,
     ) -> Result<TaskDependency, DevErpError> {
+        let _timer = QueryTimer::start("task_dependencies.add_dependency");
         dependency.validate().map_err(DevErpError::Validation)?;
 
-        // Check if adding this dependency would create a cycle
-        if self
-            .would_create_cycle(dependency.task_id, dependency.depends_on_task_id)
-            .await?
-        {
+        // Two concurrent inserts can each pass the cycle check and close a
+        // cycle together, and locking only the two endpoint rows is not enough
+        // when the cycle runs through other tasks. A transaction-scoped
+        // advisory lock serializes every edge insert instead; under READ
+        // COMMITTED the check below then sees all previously committed edges.
+        // Removing edges cannot create a cycle, so deletes skip the lock.
+        let mut tx = self.pool.begin().await?;
+        lock_dependency_graph(&mut *tx).await?;
+
+        if reaches(&mut *tx, dependency.depends_on_task_id, dependency.task_id).await? {
             return Err(DevErpError::Validation(
                 "Adding this dependency would create a circular dependency".to_string(),
             ));
         }
 
-        let task_dep = sqlx::query_as!(
-            TaskDependency,
-            r#"
-            INSERT INTO task_dependencies (task_id, depends_on_task_id, dependency_type)
-            VALUES ($1, $2, $3)
-            RETURNING
-                task_id, depends_on_task_id,
-                dependency_type as "dependency_type: _",
-                created_at
-            "#,
-            dependency.task_id,
-            dependency.depends_on_task_id,
-            dependency.dependency_type.unwrap_or_default().to_string()
-        )
-        .fetch_one(&self.pool)
-        .await?;
+        let task_dep = insert_dependency(&mut *tx, dependency).await?;
 
+        tx.commit().await?;
         Ok(task_dep)
     }
 
@@ -373,6 +725,7 @@ impl TaskDependencyRepository for PostgresTaskDependencyRepository {
         task_id: i64,
         depends_on_task_id: i64,
     ) -> Result<bool, DevErpError> {
+        let _timer = QueryTimer::start("task_dependencies.remove_dependency");
         let result = sqlx::query!(
             r#"
             DELETE FROM task_dependencies
@@ -388,6 +741,7 @@ impl TaskDependencyRepository for PostgresTaskDependencyRepository {
     }
 
     async fn get_dependencies(&self, task_id: i64) -> Result<Vec<TaskDependency>, DevErpError> {
+        let _timer = QueryTimer::start("task_dependencies.get_dependencies");
         let dependencies = sqlx::query_as!(
             TaskDependency,
             r#"
@@ -407,6 +761,7 @@ impl TaskDependencyRepository for PostgresTaskDependencyRepository {
     }
 
     async fn get_dependents(&self, task_id: i64) -> Result<Vec<TaskDependency>, DevErpError> {
+        let _timer = QueryTimer::start("task_dependencies.get_dependents");
         let dependents = sqlx::query_as!(
             TaskDependency,
             r#"
@@ -430,38 +785,137 @@ impl TaskDependencyRepository for PostgresTaskDependencyRepository {
         task_id: i64,
         depends_on_task_id: i64,
     ) -> Result<bool, DevErpError> {
-        // Get the complete dependency chain starting from depends_on_task_id
-        let chain = self.get_dependency_chain(depends_on_task_id).await?;
-
-        // If task_id is in the chain, adding this dependency would create a cycle
-        Ok(chain.contains(&task_id))
+        let _timer = QueryTimer::start("task_dependencies.would_create_cycle");
+        // If task_id is reachable from depends_on_task_id, the new edge closes a cycle
+        reaches(&self.pool, depends_on_task_id, task_id).await
     }
 
-    async fn get_dependency_chain(&self, start_task_id: i64) -> Result<Vec<i64>, DevErpError> {
+    async fn get_dependency_chain(
+        &self,
+        start_task_id: i64,
+        max_depth: Option<u32>,
+    ) -> Result<Vec<i64>, DevErpError> {
+        let _timer = QueryTimer::start("task_dependencies.get_dependency_chain");
         // Use BFS to traverse the dependency graph
         let mut visited = HashSet::new();
         let mut queue = VecDeque::new();
         let mut chain = Vec::new();
 
-        queue.push_back(start_task_id);
+        queue.push_back((start_task_id, 0));
         visited.insert(start_task_id);
 
-        while let Some(current_id) = queue.pop_front() {
+        while let Some((current_id, depth)) = queue.pop_front() {
             chain.push(current_id);
 
+            // Tasks at the depth limit stay in the chain but are not expanded
+            if max_depth.is_some_and(|max| depth >= max) {
+                continue;
+            }
+
             // Get all tasks that current_id depends on
             let dependencies = self.get_dependencies(current_id).await?;
 
             for dep in dependencies {
                 if !visited.contains(&dep.depends_on_task_id) {
                     visited.insert(dep.depends_on_task_id);
-                    queue.push_back(dep.depends_on_task_id);
+                    queue.push_back((dep.depends_on_task_id, depth + 1));
                 }
             }
         }
 
         Ok(chain)
     }
+
+    async fn get_dependency_chain_batched(
+        &self,
+        start_task_id: i64,
+        max_depth: Option<u32>,
+    ) -> Result<Vec<i64>, DevErpError> {
+        let _timer = QueryTimer::start("task_dependencies.get_dependency_chain_batched");
+        let max_depth = max_depth.map(|depth| i32::try_from(depth).unwrap_or(i32::MAX));
+
+        // UNION drops repeated (id, depth) rows, and without a limit the
+        // depth is capped at the number of edges: no shortest path is longer,
+        // so the walk still ends if the graph somehow contains a cycle.
+        let chain = sqlx::query_scalar!(
+            r#"
+            WITH RECURSIVE chain(id, depth) AS (
+                SELECT $1::BIGINT, 0
+                UNION
+                SELECT d.depends_on_task_id, c.depth + 1
+                FROM task_dependencies d
+                JOIN chain c ON d.task_id = c.id
+                WHERE c.depth < COALESCE(
+                    $2::INT,
+                    (SELECT COUNT(*)::INT FROM task_dependencies)
+                )
+            )
+            SELECT id as "id!"
+            FROM chain
+            GROUP BY id
+            ORDER BY MIN(depth), id
+            "#,
+            start_task_id,
+            max_depth
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(chain)
+    }
+
+    async fn get_project_dependencies(
+        &self,
+        project_id: i64,
+    ) -> Result<Vec<TaskDependency>, DevErpError> {
+        let _timer = QueryTimer::start("task_dependencies.get_project_dependencies");
+        let dependencies = sqlx::query_as!(
+            TaskDependency,
+            r#"
+            SELECT
+                d.task_id, d.depends_on_task_id,
+                d.dependency_type as "dependency_type: _",
+                d.created_at
+            FROM task_dependencies d
+            JOIN tasks t ON t.id = d.task_id
+            JOIN tasks p ON p.id = d.depends_on_task_id
+            WHERE t.project_id = $1 AND p.project_id = $1
+              AND t.deleted_at IS NULL AND p.deleted_at IS NULL
+            "#,
+            project_id
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(dependencies)
+    }
+
+    async fn find_dependent_projects(&self, project_ids: &[i64]) -> Result<Vec<i64>, DevErpError> {
+        let _timer = QueryTimer::start("task_dependencies.find_dependent_projects");
+        let projects = sqlx::query_scalar!(
+            r#"
+            SELECT DISTINCT t.project_id as "project_id!"
+            FROM task_dependencies d
+            JOIN tasks t ON t.id = d.task_id
+            JOIN tasks p ON p.id = d.depends_on_task_id
+            WHERE p.project_id = ANY($1) AND t.project_id <> ALL($1)
+              AND t.deleted_at IS NULL AND p.deleted_at IS NULL
+            ORDER BY 1
+            "#,
+            project_ids
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(projects)
+    }
+
+    async fn find_all_cycles(&self, project_id: i64) -> Result<Vec<Vec<i64>>, DevErpError> {
+        let _timer = QueryTimer::start("task_dependencies.find_all_cycles");
+        let dependencies = self.get_project_dependencies(project_id).await?;
+
+        Ok(find_cycles(&dependencies))
+    }
 }
 
 /// PostgreSQL implementation of TaskCommentRepository
@@ -478,6 +932,7 @@ impl PostgresTaskCommentRepository {
 #[async_trait]
 impl TaskCommentRepository for PostgresTaskCommentRepository {
     async fn create(&self, comment: CreateTaskComment) -> Result<TaskComment, DevErpError> {
+        let _timer = QueryTimer::start("task_comments.create");
         comment.validate().map_err(DevErpError::Validation)?;
 
         let task_comment = sqlx::query_as!(
@@ -498,6 +953,7 @@ impl TaskCommentRepository for PostgresTaskCommentRepository {
     }
 
     async fn find_by_id(&self, id: i64) -> Result<Option<TaskComment>, DevErpError> {
+        let _timer = QueryTimer::start("task_comments.find_by_id");
         let comment = sqlx::query_as!(
             TaskComment,
             r#"
@@ -514,6 +970,7 @@ impl TaskCommentRepository for PostgresTaskCommentRepository {
     }
 
     async fn find_by_task_id(&self, task_id: i64) -> Result<Vec<TaskComment>, DevErpError> {
+        let _timer = QueryTimer::start("task_comments.find_by_task_id");
         let comments = sqlx::query_as!(
             TaskComment,
             r#"
@@ -531,6 +988,7 @@ impl TaskCommentRepository for PostgresTaskCommentRepository {
     }
 
     async fn update(&self, id: i64, comment_text: String) -> Result<TaskComment, DevErpError> {
+        let _timer = QueryTimer::start("task_comments.update");
         if comment_text.trim().is_empty() {
             return Err(DevErpError::Validation(
                 "Comment text cannot be empty".to_string(),
@@ -556,6 +1014,7 @@ impl TaskCommentRepository for PostgresTaskCommentRepository {
     }
 
     async fn soft_delete(&self, id: i64) -> Result<bool, DevErpError> {
+        let _timer = QueryTimer::start("task_comments.soft_delete");
         let result = sqlx::query!(
             r#"
             UPDATE task_comments
@@ -570,4 +1029,209 @@ impl TaskCommentRepository for PostgresTaskCommentRepository {
 
         Ok(result.rows_affected() > 0)
     }
+
+    async fn restore(&self, id: i64) -> Result<bool, DevErpError> {
+        let _timer = QueryTimer::start("task_comments.restore");
+        let result = sqlx::query!(
+            r#"
+            UPDATE task_comments
+            SET deleted_at = NULL
+            WHERE id = $1 AND deleted_at IS NOT NULL
+            "#,
+            id
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+/// PostgreSQL implementation of TaskWorkLogRepository
+pub struct PostgresTaskWorkLogRepository {
+    pool: PgPool,
+}
+
+impl PostgresTaskWorkLogRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl TaskWorkLogRepository for PostgresTaskWorkLogRepository {
+    async fn create(&self, log: CreateTaskWorkLog) -> Result<TaskWorkLog, DevErpError> {
+        let _timer = QueryTimer::start("task_work_logs.create");
+        log.validate().map_err(DevErpError::Validation)?;
+
+        // The entry and the running total change together or not at all
+        let mut tx = self.pool.begin().await?;
+
+        let updated = sqlx::query!(
+            r#"
+            UPDATE tasks
+            SET actual_hours = COALESCE(actual_hours, 0) + $2
+            WHERE id = $1 AND deleted_at IS NULL
+            "#,
+            log.task_id,
+            log.hours
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        if updated.rows_affected() == 0 {
+            return Err(DevErpError::NotFound(format!(
+                "Task with id {} not found",
+                log.task_id
+            )));
+        }
+
+        let work_log = sqlx::query_as!(
+            TaskWorkLog,
+            r#"
+            INSERT INTO task_work_logs (task_id, hours, logged_by, note)
+            VALUES ($1, $2, $3, $4)
+            RETURNING id, task_id, hours, logged_by, note, logged_at
+            "#,
+            log.task_id,
+            log.hours,
+            log.logged_by,
+            log.note
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(work_log)
+    }
+
+    async fn find_recent_by_task(
+        &self,
+        task_id: i64,
+        limit: i64,
+    ) -> Result<Vec<TaskWorkLog>, DevErpError> {
+        let _timer = QueryTimer::start("task_work_logs.find_recent_by_task");
+        let logs = sqlx::query_as!(
+            TaskWorkLog,
+            r#"
+            SELECT id, task_id, hours, logged_by, note, logged_at
+            FROM task_work_logs
+            WHERE task_id = $1
+            ORDER BY logged_at DESC, id DESC
+            LIMIT $2
+            "#,
+            task_id,
+            limit
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(logs)
+    }
+}
+
+/// PostgreSQL implementation of TaskAssigneeRepository
+pub struct PostgresTaskAssigneeRepository {
+    pool: PgPool,
+}
+
+impl PostgresTaskAssigneeRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl TaskAssigneeRepository for PostgresTaskAssigneeRepository {
+    async fn add_assignee(&self, task_id: i64, assignee: &str) -> Result<bool, DevErpError> {
+        let _timer = QueryTimer::start("task_assignees.add");
+        let mut tx = self.pool.begin().await?;
+
+        let inserted = sqlx::query!(
+            r#"
+            INSERT INTO task_assignees (task_id, assignee)
+            VALUES ($1, $2)
+            ON CONFLICT (task_id, assignee) DO NOTHING
+            "#,
+            task_id,
+            assignee
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        // An unassigned task gets its first assignee as the primary one
+        sqlx::query!(
+            r#"
+            UPDATE tasks
+            SET assigned_to = $2
+            WHERE id = $1 AND assigned_to IS NULL
+            "#,
+            task_id,
+            assignee
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(inserted.rows_affected() > 0)
+    }
+
+    async fn remove_assignee(&self, task_id: i64, assignee: &str) -> Result<bool, DevErpError> {
+        let _timer = QueryTimer::start("task_assignees.remove");
+        let mut tx = self.pool.begin().await?;
+
+        let deleted = sqlx::query!(
+            r#"
+            DELETE FROM task_assignees
+            WHERE task_id = $1 AND assignee = $2
+            "#,
+            task_id,
+            assignee
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        // The primary may predate this table, so it is checked even when no row was deleted
+        let demoted = sqlx::query!(
+            r#"
+            UPDATE tasks
+            SET assigned_to = (
+                SELECT assignee
+                FROM task_assignees
+                WHERE task_id = $1
+                ORDER BY assigned_at, assignee
+                LIMIT 1
+            )
+            WHERE id = $1 AND assigned_to = $2
+            "#,
+            task_id,
+            assignee
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(deleted.rows_affected() > 0 || demoted.rows_affected() > 0)
+    }
+
+    async fn list_assignees(&self, task_id: i64) -> Result<Vec<TaskAssignee>, DevErpError> {
+        let _timer = QueryTimer::start("task_assignees.list");
+        let assignees = sqlx::query_as!(
+            TaskAssignee,
+            r#"
+            SELECT task_id, assignee, assigned_at
+            FROM task_assignees
+            WHERE task_id = $1
+            ORDER BY assigned_at, assignee
+            "#,
+            task_id
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(assignees)
+    }
 }
@@ -0,0 +1,355 @@
+// PostgreSQL implementation of TemplateRepository
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use std::collections::HashMap;
+use tracing::{debug, info};
+
+use super::project_repo::insert_project;
+use super::task_repo::insert_task;
+use super::timeline_repo::{insert_milestone, insert_timeline};
+use crate::domain::project::entity::Project;
+use crate::domain::task::entity::Task;
+use crate::domain::template::{
+    entity::{AppliedTemplate, ProjectTemplate, StoredTemplate, TemplatePlan, TemplateSource},
+    repository::TemplateRepository,
+};
+use crate::domain::timeline::entity::{
+    Milestone, MilestoneStatus, Timeline, TimelineStatus, TimelineType,
+};
+use crate::utils::error::DevErpError;
+use crate::utils::timing::QueryTimer;
+
+/// PostgreSQL implementation of the TemplateRepository trait
+pub struct PostgresTemplateRepository {
+    pool: PgPool,
+}
+
+impl PostgresTemplateRepository {
+    /// Create a new PostgreSQL template repository
+    ///
+    /// # Arguments
+    /// * `pool` - PostgreSQL connection pool
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+/// Rebuild a stored template from its row values
+fn stored_template(
+    id: i64,
+    name: String,
+    version: i32,
+    source_project_id: Option<i64>,
+    template: serde_json::Value,
+    created_at: DateTime<Utc>,
+) -> Result<StoredTemplate, DevErpError> {
+    let template = serde_json::from_value(template).map_err(|e| {
+        DevErpError::Internal(format!("Stored template '{}' is unreadable: {}", name, e))
+    })?;
+
+    Ok(StoredTemplate {
+        id,
+        name,
+        version,
+        source_project_id,
+        template,
+        created_at,
+    })
+}
+
+#[async_trait]
+impl TemplateRepository for PostgresTemplateRepository {
+    async fn apply(&self, plan: TemplatePlan) -> Result<AppliedTemplate, DevErpError> {
+        let _timer = QueryTimer::start("templates.apply");
+        plan.project.validate().map_err(DevErpError::Validation)?;
+
+        let mut tx = self.pool.begin().await?;
+
+        if let Some(ref code) = plan.project.code {
+            let count = sqlx::query_scalar!(
+                "SELECT COUNT(*) as count FROM projects WHERE code = $1 AND deleted_at IS NULL",
+                code
+            )
+            .fetch_one(&mut *tx)
+            .await?
+            .unwrap_or(0);
+
+            if count > 0 {
+                return Err(DevErpError::Conflict(format!(
+                    "Project code '{}' already exists",
+                    code
+                )));
+            }
+        }
+
+        debug!("Applying template for project: {}", plan.project.name);
+
+        let project = insert_project(&mut *tx, plan.project).await?;
+
+        let mut timelines = Vec::with_capacity(plan.timelines.len());
+        let mut milestones = Vec::new();
+        let mut milestone_ids = HashMap::new();
+
+        for planned in plan.timelines {
+            let mut input = planned.timeline;
+            input.project_id = project.id;
+            input.validate().map_err(DevErpError::Validation)?;
+            let timeline = insert_timeline(&mut *tx, input).await?;
+
+            for planned_milestone in planned.milestones {
+                let mut input = planned_milestone.milestone;
+                input.timeline_id = timeline.id;
+                input.project_id = project.id;
+                input.validate().map_err(DevErpError::Validation)?;
+                let milestone = insert_milestone(&mut *tx, input).await?;
+
+                milestone_ids.insert(planned_milestone.key, milestone.id);
+                milestones.push(milestone);
+            }
+
+            timelines.push(timeline);
+        }
+
+        let mut tasks = Vec::with_capacity(plan.tasks.len());
+        for planned in plan.tasks {
+            let mut input = planned.task;
+            input.project_id = project.id;
+            input.validate().map_err(DevErpError::Validation)?;
+            let mut task = insert_task(&mut *tx, input).await?;
+
+            if let Some(key) = planned.milestone_key {
+                let milestone_id = *milestone_ids.get(&key).ok_or_else(|| {
+                    DevErpError::Validation(format!("Template milestone '{}' is not defined", key))
+                })?;
+
+                sqlx::query!(
+                    "UPDATE tasks SET milestone_id = $1 WHERE id = $2",
+                    milestone_id,
+                    task.id
+                )
+                .execute(&mut *tx)
+                .await?;
+                task.milestone_id = Some(milestone_id);
+            }
+
+            tasks.push(task);
+        }
+
+        tx.commit().await?;
+
+        info!(
+            project_id = project.id,
+            timelines = timelines.len(),
+            milestones = milestones.len(),
+            tasks = tasks.len(),
+            "Template applied successfully"
+        );
+
+        Ok(AppliedTemplate {
+            project,
+            timelines,
+            milestones,
+            tasks,
+        })
+    }
+
+    async fn load_source(&self, project_id: i64) -> Result<Option<TemplateSource>, DevErpError> {
+        let _timer = QueryTimer::start("templates.load_source");
+        debug!("Loading project {} structure for a template", project_id);
+
+        // Read everything from one snapshot so milestone links stay consistent
+        let mut tx = self.pool.begin().await?;
+        sqlx::query("SET TRANSACTION ISOLATION LEVEL REPEATABLE READ READ ONLY")
+            .execute(&mut *tx)
+            .await?;
+
+        let project = sqlx::query_as!(
+            Project,
+            r#"
+            SELECT
+                id, uuid, name, description, code,
+                status as "status: _", priority as "priority: _",
+                start_date, end_date, actual_start_date, actual_end_date,
+                progress_percentage, budget, currency, repository_url, repository_branch,
+                tags, metadata, version,
+                created_at, updated_at, deleted_at
+            FROM projects
+            WHERE id = $1 AND deleted_at IS NULL
+            "#,
+            project_id
+        )
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        let Some(project) = project else {
+            return Ok(None);
+        };
+
+        let timelines = sqlx::query_as!(
+            Timeline,
+            r#"
+            SELECT
+                id, project_id, name, description,
+                timeline_type as "timeline_type!: TimelineType",
+                start_date, end_date,
+                status as "status!: TimelineStatus",
+                created_at, updated_at, deleted_at
+            FROM timelines
+            WHERE project_id = $1 AND deleted_at IS NULL
+            ORDER BY start_date, id
+            "#,
+            project_id
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let milestones = sqlx::query_as!(
+            Milestone,
+            r#"
+            SELECT
+                id, timeline_id, project_id, name, description,
+                target_date, actual_date,
+                status as "status!: MilestoneStatus",
+                completion_percentage as "completion_percentage!",
+                sequence,
+                metadata,
+                created_at, updated_at, deleted_at
+            FROM milestones
+            WHERE project_id = $1 AND deleted_at IS NULL
+            ORDER BY target_date, id
+            "#,
+            project_id
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let tasks = sqlx::query_as!(
+            Task,
+            r#"
+            SELECT
+                id, uuid, project_id, parent_task_id, milestone_id, title, description, task_number,
+                status as "status: _", blocked_reason, priority as "priority: _", assigned_to,
+                estimated_hours, actual_hours, due_date, started_at, completed_at,
+                task_type as "task_type: _", tags, version, created_at, updated_at, deleted_at
+            FROM tasks
+            WHERE project_id = $1 AND deleted_at IS NULL
+            ORDER BY id
+            "#,
+            project_id
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(Some(TemplateSource {
+            project,
+            timelines,
+            milestones,
+            tasks,
+        }))
+    }
+
+    async fn save(
+        &self,
+        name: &str,
+        source_project_id: Option<i64>,
+        template: &ProjectTemplate,
+    ) -> Result<StoredTemplate, DevErpError> {
+        let _timer = QueryTimer::start("templates.save");
+        debug!("Saving template '{}'", name);
+
+        let body = serde_json::to_value(template)
+            .map_err(|e| DevErpError::Internal(format!("Template serialization error: {}", e)))?;
+
+        // A concurrent save of the same name trips the (name, version) unique key
+        let row = sqlx::query!(
+            r#"
+            INSERT INTO project_templates (name, version, source_project_id, template)
+            SELECT $1::VARCHAR, COALESCE(MAX(version), 0) + 1, $2, $3
+            FROM project_templates
+            WHERE name = $1
+            RETURNING id, name, version, source_project_id, template, created_at
+            "#,
+            name,
+            source_project_id,
+            body
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        info!(template = %row.name, version = row.version, "Template stored");
+
+        stored_template(
+            row.id,
+            row.name,
+            row.version,
+            row.source_project_id,
+            row.template,
+            row.created_at,
+        )
+    }
+
+    async fn find_stored(
+        &self,
+        name: &str,
+        version: Option<i32>,
+    ) -> Result<Option<StoredTemplate>, DevErpError> {
+        let _timer = QueryTimer::start("templates.find_stored");
+
+        let row = sqlx::query!(
+            r#"
+            SELECT id, name, version, source_project_id, template, created_at
+            FROM project_templates
+            WHERE name = $1 AND ($2::INTEGER IS NULL OR version = $2)
+            ORDER BY version DESC
+            LIMIT 1
+            "#,
+            name,
+            version
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.map(|r| {
+            stored_template(
+                r.id,
+                r.name,
+                r.version,
+                r.source_project_id,
+                r.template,
+                r.created_at,
+            )
+        })
+        .transpose()
+    }
+
+    async fn list_stored(&self) -> Result<Vec<StoredTemplate>, DevErpError> {
+        let _timer = QueryTimer::start("templates.list_stored");
+
+        let rows = sqlx::query!(
+            r#"
+            SELECT id, name, version, source_project_id, template, created_at
+            FROM project_templates
+            ORDER BY name, version DESC
+            "#
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter()
+            .map(|r| {
+                stored_template(
+                    r.id,
+                    r.name,
+                    r.version,
+                    r.source_project_id,
+                    r.template,
+                    r.created_at,
+                )
+            })
+            .collect()
+    }
+}
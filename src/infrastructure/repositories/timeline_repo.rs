@@ -1,17 +1,20 @@
 // PostgreSQL implementation of TimelineRepository and MilestoneRepository
 
 use async_trait::async_trait;
-use sqlx::PgPool;
+use chrono::NaiveDate;
+use sqlx::{PgExecutor, PgPool};
 use tracing::{debug, info};
 
 use crate::domain::timeline::{
     entity::{
-        CreateMilestone, CreateTimeline, Milestone, MilestoneFilter, MilestoneStatus, Timeline,
-        TimelineFilter, TimelineStatus, TimelineType, UpdateMilestone, UpdateTimeline,
+        CreateMilestone, CreateTimeline, Milestone, MilestoneFilter, MilestoneStatus,
+        MilestoneTaskCount, Timeline, TimelineFilter, TimelineStatus, TimelineType,
+        UpdateMilestone, UpdateTimeline,
     },
     repository::{MilestoneRepository, TimelineRepository},
 };
 use crate::utils::error::DevErpError;
+use crate::utils::timing::QueryTimer;
 
 /// PostgreSQL implementation of the TimelineRepository trait
 pub struct PostgresTimelineRepository {
@@ -28,9 +31,50 @@ impl PostgresTimelineRepository {
     }
 }
 
+/// Insert a timeline row on any executor (pool or open transaction)
+pub(crate) async fn insert_timeline<'e, E>(
+    executor: E,
+    timeline: CreateTimeline,
+) -> Result<Timeline, DevErpError>
+where
+    E: PgExecutor<'e>,
+{
+    let result = sqlx::query_as!(
+        Timeline,
+        r#"
+        INSERT INTO timelines (
+            project_id, name, description, timeline_type,
+            start_date, end_date, status
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7)
+        RETURNING
+            id, project_id, name, description,
+            timeline_type as "timeline_type!: TimelineType",
+            start_date, end_date,
+            status as "status!: TimelineStatus",
+            created_at, updated_at, deleted_at
+        "#,
+        timeline.project_id,
+        timeline.name,
+        timeline.description,
+        timeline
+            .timeline_type
+            .unwrap_or(TimelineType::Project)
+            .as_str(),
+        timeline.start_date,
+        timeline.end_date,
+        timeline.status.unwrap_or(TimelineStatus::Planned).as_str()
+    )
+    .fetch_one(executor)
+    .await?;
+
+    Ok(result)
+}
+
 #[async_trait]
 impl TimelineRepository for PostgresTimelineRepository {
     async fn create(&self, timeline: CreateTimeline) -> Result<Timeline, DevErpError> {
+        let _timer = QueryTimer::start("timelines.create");
         // Validate input
         timeline.validate().map_err(DevErpError::Validation)?;
 
@@ -39,34 +83,7 @@ impl TimelineRepository for PostgresTimelineRepository {
             timeline.name, timeline.project_id
         );
 
-        let result = sqlx::query_as!(
-            Timeline,
-            r#"
-            INSERT INTO timelines (
-                project_id, name, description, timeline_type,
-                start_date, end_date, status
-            )
-            VALUES ($1, $2, $3, $4, $5, $6, $7)
-            RETURNING
-                id, project_id, name, description,
-                timeline_type as "timeline_type!: TimelineType",
-                start_date, end_date,
-                status as "status!: TimelineStatus",
-                created_at, updated_at, deleted_at
-            "#,
-            timeline.project_id,
-            timeline.name,
-            timeline.description,
-            timeline
-                .timeline_type
-                .unwrap_or(TimelineType::Project)
-                .as_str(),
-            timeline.start_date,
-            timeline.end_date,
-            timeline.status.unwrap_or(TimelineStatus::Planned).as_str()
-        )
-        .fetch_one(&self.pool)
-        .await?;
+        let result = insert_timeline(&self.pool, timeline).await?;
 
         info!(timeline_id = %result.id, project_id = %result.project_id, "Timeline created successfully");
 
@@ -74,6 +91,7 @@ impl TimelineRepository for PostgresTimelineRepository {
     }
 
     async fn find_by_id(&self, id: i64) -> Result<Option<Timeline>, DevErpError> {
+        let _timer = QueryTimer::start("timelines.find_by_id");
         debug!("Finding timeline by id: {}", id);
 
         let result = sqlx::query_as!(
@@ -97,10 +115,10 @@ impl TimelineRepository for PostgresTimelineRepository {
     }
 
     async fn find_all(&self, filter: TimelineFilter) -> Result<Vec<Timeline>, DevErpError> {
+        let _timer = QueryTimer::start("timelines.find_all");
         debug!("Finding timelines with filter: {:?}", filter);
 
-        // Fetch all non-deleted timelines
-        let mut results = sqlx::query_as!(
+        let results = sqlx::query_as!(
             Timeline,
             r#"
             SELECT
@@ -110,42 +128,28 @@ impl TimelineRepository for PostgresTimelineRepository {
                 status as "status!: TimelineStatus",
                 created_at, updated_at, deleted_at
             FROM timelines
-            WHERE deleted_at IS NULL
+            WHERE ($1 OR deleted_at IS NULL)
+              AND ($2::BIGINT IS NULL OR project_id = $2)
+              AND ($3::TEXT IS NULL OR timeline_type = $3)
+              AND ($4::TEXT IS NULL OR status = $4)
             ORDER BY start_date DESC
-            "#
+            LIMIT $5 OFFSET $6
+            "#,
+            filter.include_deleted,
+            filter.project_id,
+            filter.timeline_type.as_ref().map(|t| t.as_str()),
+            filter.status.as_ref().map(|s| s.as_str()),
+            filter.get_limit(),
+            filter.get_offset()
         )
         .fetch_all(&self.pool)
         .await?;
 
-        // Apply filters in memory
-        if let Some(project_id) = filter.project_id {
-            results.retain(|t| t.project_id == project_id);
-        }
-
-        if let Some(ref timeline_type) = filter.timeline_type {
-            results.retain(|t| &t.timeline_type == timeline_type);
-        }
-
-        if let Some(ref status) = filter.status {
-            results.retain(|t| &t.status == status);
-        }
-
-        // Apply pagination
-        let offset = filter.get_offset() as usize;
-        let limit = filter.get_limit() as usize;
-        let total = results.len();
-
-        if offset < total {
-            let end = (offset + limit).min(total);
-            results = results[offset..end].to_vec();
-        } else {
-            results.clear();
-        }
-
         Ok(results)
     }
 
     async fn find_by_project(&self, project_id: i64) -> Result<Vec<Timeline>, DevErpError> {
+        let _timer = QueryTimer::start("timelines.find_by_project");
         debug!("Finding timelines for project_id: {}", project_id);
 
         let results = sqlx::query_as!(
@@ -170,24 +174,31 @@ impl TimelineRepository for PostgresTimelineRepository {
     }
 
     async fn count(&self, filter: TimelineFilter) -> Result<i64, DevErpError> {
+        let _timer = QueryTimer::start("timelines.count");
         debug!("Counting timelines with filter: {:?}", filter);
 
-        // For simplicity, reuse find_all and count in memory
-        // This is acceptable for a CLI application with limited data
-        let timelines = self
-            .find_all(TimelineFilter {
-                project_id: filter.project_id,
-                timeline_type: filter.timeline_type,
-                status: filter.status,
-                offset: None,
-                limit: None,
-            })
-            .await?;
+        let count = sqlx::query_scalar!(
+            r#"
+            SELECT COUNT(*) as "count!"
+            FROM timelines
+            WHERE ($1 OR deleted_at IS NULL)
+              AND ($2::BIGINT IS NULL OR project_id = $2)
+              AND ($3::TEXT IS NULL OR timeline_type = $3)
+              AND ($4::TEXT IS NULL OR status = $4)
+            "#,
+            filter.include_deleted,
+            filter.project_id,
+            filter.timeline_type.as_ref().map(|t| t.as_str()),
+            filter.status.as_ref().map(|s| s.as_str())
+        )
+        .fetch_one(&self.pool)
+        .await?;
 
-        Ok(timelines.len() as i64)
+        Ok(count)
     }
 
     async fn update(&self, timeline: UpdateTimeline) -> Result<Timeline, DevErpError> {
+        let _timer = QueryTimer::start("timelines.update");
         // Validate input
         timeline.validate().map_err(DevErpError::Validation)?;
 
@@ -236,6 +247,7 @@ impl TimelineRepository for PostgresTimelineRepository {
     }
 
     async fn soft_delete(&self, id: i64) -> Result<bool, DevErpError> {
+        let _timer = QueryTimer::start("timelines.soft_delete");
         debug!("Soft deleting timeline id: {}", id);
 
         let result = sqlx::query!(
@@ -255,6 +267,7 @@ impl TimelineRepository for PostgresTimelineRepository {
     }
 
     async fn delete(&self, id: i64) -> Result<bool, DevErpError> {
+        let _timer = QueryTimer::start("timelines.delete");
         debug!("Hard deleting timeline id: {}", id);
 
         let result = sqlx::query!("DELETE FROM timelines WHERE id = $1", id)
@@ -271,6 +284,7 @@ impl TimelineRepository for PostgresTimelineRepository {
     }
 
     async fn restore(&self, id: i64) -> Result<bool, DevErpError> {
+        let _timer = QueryTimer::start("timelines.restore");
         debug!("Restoring timeline id: {}", id);
 
         let result = sqlx::query!(
@@ -288,6 +302,52 @@ impl TimelineRepository for PostgresTimelineRepository {
 
         Ok(restored)
     }
+
+    async fn shift_dates(&self, id: i64, days: i32) -> Result<u64, DevErpError> {
+        let _timer = QueryTimer::start("timelines.shift_dates");
+        debug!("Shifting timeline id {} by {} day(s)", id, days);
+
+        let mut tx = self.pool.begin().await?;
+
+        let timeline = sqlx::query!(
+            r#"
+            UPDATE timelines
+            SET start_date = start_date + $2::INTEGER, end_date = end_date + $2::INTEGER
+            WHERE id = $1 AND deleted_at IS NULL
+            "#,
+            id,
+            days
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        if timeline.rows_affected() == 0 {
+            tx.rollback().await?;
+            return Err(DevErpError::NotFound(format!(
+                "Timeline with id {} not found",
+                id
+            )));
+        }
+
+        // NULL actual dates stay NULL
+        let milestones = sqlx::query!(
+            r#"
+            UPDATE milestones
+            SET target_date = target_date + $2::INTEGER, actual_date = actual_date + $2::INTEGER
+            WHERE timeline_id = $1 AND deleted_at IS NULL
+            "#,
+            id,
+            days
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        info!(timeline_id = %id, days, "Timeline shifted successfully");
+
+        Ok(milestones.rows_affected())
+    }
 }
 
 /// PostgreSQL implementation of the MilestoneRepository trait
@@ -305,9 +365,56 @@ impl PostgresMilestoneRepository {
     }
 }
 
+/// Insert a milestone row on any executor; task links are not touched
+pub(crate) async fn insert_milestone<'e, E>(
+    executor: E,
+    milestone: CreateMilestone,
+) -> Result<Milestone, DevErpError>
+where
+    E: PgExecutor<'e>,
+{
+    let result = sqlx::query_as!(
+        Milestone,
+        r#"
+        INSERT INTO milestones (
+            timeline_id, project_id, name, description,
+            target_date, status, completion_percentage, metadata, sequence
+        )
+        VALUES (
+            $1, $2, $3, $4, $5, $6, $7, $8,
+            (SELECT COALESCE(MAX(sequence), 0) + 1 FROM milestones WHERE timeline_id = $1)
+        )
+        RETURNING
+            id, timeline_id, project_id, name, description,
+            target_date, actual_date,
+            status as "status!: MilestoneStatus",
+            completion_percentage as "completion_percentage!",
+            sequence,
+            metadata,
+            created_at, updated_at, deleted_at
+        "#,
+        milestone.timeline_id,
+        milestone.project_id,
+        milestone.name,
+        milestone.description,
+        milestone.target_date,
+        milestone
+            .status
+            .unwrap_or(MilestoneStatus::Pending)
+            .as_str(),
+        milestone.completion_percentage.unwrap_or(0),
+        milestone.metadata
+    )
+    .fetch_one(executor)
+    .await?;
+
+    Ok(result)
+}
+
 #[async_trait]
 impl MilestoneRepository for PostgresMilestoneRepository {
     async fn create(&self, milestone: CreateMilestone) -> Result<Milestone, DevErpError> {
+        let _timer = QueryTimer::start("milestones.create");
         // Validate input
         milestone.validate().map_err(DevErpError::Validation)?;
 
@@ -316,36 +423,44 @@ impl MilestoneRepository for PostgresMilestoneRepository {
             milestone.name, milestone.timeline_id
         );
 
-        let result = sqlx::query_as!(
-            Milestone,
-            r#"
-            INSERT INTO milestones (
-                timeline_id, project_id, name, description,
-                target_date, status, completion_percentage, metadata
+        let mut tx = self.pool.begin().await?;
+
+        let result = insert_milestone(&mut *tx, milestone.clone()).await?;
+
+        // Link the requested tasks; all of them must belong to the milestone's project
+        if !milestone.task_ids.is_empty() {
+            let linked = sqlx::query_scalar!(
+                r#"
+                UPDATE tasks
+                SET milestone_id = $1
+                WHERE id = ANY($2) AND project_id = $3 AND deleted_at IS NULL
+                RETURNING id
+                "#,
+                result.id,
+                &milestone.task_ids,
+                milestone.project_id
             )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
-            RETURNING
-                id, timeline_id, project_id, name, description,
-                target_date, actual_date,
-                status as "status!: MilestoneStatus",
-                completion_percentage as "completion_percentage!",
-                metadata,
-                created_at, updated_at, deleted_at
-            "#,
-            milestone.timeline_id,
-            milestone.project_id,
-            milestone.name,
-            milestone.description,
-            milestone.target_date,
-            milestone
-                .status
-                .unwrap_or(MilestoneStatus::Pending)
-                .as_str(),
-            milestone.completion_percentage.unwrap_or(0),
-            milestone.metadata
-        )
-        .fetch_one(&self.pool)
-        .await?;
+            .fetch_all(&mut *tx)
+            .await?;
+
+            let invalid: Vec<String> = milestone
+                .task_ids
+                .iter()
+                .filter(|id| !linked.contains(id))
+                .map(|id| id.to_string())
+                .collect();
+
+            if !invalid.is_empty() {
+                tx.rollback().await?;
+                return Err(DevErpError::Validation(format!(
+                    "Tasks {} do not exist in project {}",
+                    invalid.join(", "),
+                    milestone.project_id
+                )));
+            }
+        }
+
+        tx.commit().await?;
 
         info!(milestone_id = %result.id, timeline_id = %result.timeline_id, "Milestone created successfully");
 
@@ -353,6 +468,7 @@ impl MilestoneRepository for PostgresMilestoneRepository {
     }
 
     async fn find_by_id(&self, id: i64) -> Result<Option<Milestone>, DevErpError> {
+        let _timer = QueryTimer::start("milestones.find_by_id");
         debug!("Finding milestone by id: {}", id);
 
         let result = sqlx::query_as!(
@@ -363,6 +479,7 @@ impl MilestoneRepository for PostgresMilestoneRepository {
                 target_date, actual_date,
                 status as "status!: MilestoneStatus",
                 completion_percentage as "completion_percentage!",
+                sequence,
                 metadata,
                 created_at, updated_at, deleted_at
             FROM milestones
@@ -377,10 +494,10 @@ impl MilestoneRepository for PostgresMilestoneRepository {
     }
 
     async fn find_all(&self, filter: MilestoneFilter) -> Result<Vec<Milestone>, DevErpError> {
+        let _timer = QueryTimer::start("milestones.find_all");
         debug!("Finding milestones with filter: {:?}", filter);
 
-        // Fetch all non-deleted milestones
-        let mut results = sqlx::query_as!(
+        let results = sqlx::query_as!(
             Milestone,
             r#"
             SELECT
@@ -388,45 +505,35 @@ impl MilestoneRepository for PostgresMilestoneRepository {
                 target_date, actual_date,
                 status as "status!: MilestoneStatus",
                 completion_percentage as "completion_percentage!",
+                sequence,
                 metadata,
                 created_at, updated_at, deleted_at
             FROM milestones
-            WHERE deleted_at IS NULL
-            ORDER BY target_date ASC
-            "#
+            WHERE ($1 OR deleted_at IS NULL)
+              AND ($2::BIGINT IS NULL OR timeline_id = $2)
+              AND ($3::BIGINT IS NULL OR project_id = $3)
+              AND ($4::TEXT IS NULL OR status = $4)
+              AND ($7::DATE IS NULL
+                   OR (status IN ('pending', 'in_progress') AND target_date < $7))
+            ORDER BY target_date ASC, id ASC
+            LIMIT $5 OFFSET $6
+            "#,
+            filter.include_deleted,
+            filter.timeline_id,
+            filter.project_id,
+            filter.status.as_ref().map(|s| s.as_str()),
+            filter.get_limit(),
+            filter.get_offset(),
+            filter.overdue_as_of
         )
         .fetch_all(&self.pool)
         .await?;
 
-        // Apply filters in memory
-        if let Some(timeline_id) = filter.timeline_id {
-            results.retain(|m| m.timeline_id == timeline_id);
-        }
-
-        if let Some(project_id) = filter.project_id {
-            results.retain(|m| m.project_id == project_id);
-        }
-
-        if let Some(ref status) = filter.status {
-            results.retain(|m| &m.status == status);
-        }
-
-        // Apply pagination
-        let offset = filter.get_offset() as usize;
-        let limit = filter.get_limit() as usize;
-        let total = results.len();
-
-        if offset < total {
-            let end = (offset + limit).min(total);
-            results = results[offset..end].to_vec();
-        } else {
-            results.clear();
-        }
-
         Ok(results)
     }
 
     async fn find_by_timeline(&self, timeline_id: i64) -> Result<Vec<Milestone>, DevErpError> {
+        let _timer = QueryTimer::start("milestones.find_by_timeline");
         debug!("Finding milestones for timeline_id: {}", timeline_id);
 
         let results = sqlx::query_as!(
@@ -437,11 +544,12 @@ impl MilestoneRepository for PostgresMilestoneRepository {
                 target_date, actual_date,
                 status as "status!: MilestoneStatus",
                 completion_percentage as "completion_percentage!",
+                sequence,
                 metadata,
                 created_at, updated_at, deleted_at
             FROM milestones
             WHERE timeline_id = $1 AND deleted_at IS NULL
-            ORDER BY target_date ASC
+            ORDER BY sequence ASC, target_date ASC
             "#,
             timeline_id
         )
@@ -452,6 +560,7 @@ impl MilestoneRepository for PostgresMilestoneRepository {
     }
 
     async fn find_by_project(&self, project_id: i64) -> Result<Vec<Milestone>, DevErpError> {
+        let _timer = QueryTimer::start("milestones.find_by_project");
         debug!("Finding milestones for project_id: {}", project_id);
 
         let results = sqlx::query_as!(
@@ -462,6 +571,7 @@ impl MilestoneRepository for PostgresMilestoneRepository {
                 target_date, actual_date,
                 status as "status!: MilestoneStatus",
                 completion_percentage as "completion_percentage!",
+                sequence,
                 metadata,
                 created_at, updated_at, deleted_at
             FROM milestones
@@ -477,23 +587,34 @@ impl MilestoneRepository for PostgresMilestoneRepository {
     }
 
     async fn count(&self, filter: MilestoneFilter) -> Result<i64, DevErpError> {
+        let _timer = QueryTimer::start("milestones.count");
         debug!("Counting milestones with filter: {:?}", filter);
 
-        // For simplicity, reuse find_all and count in memory
-        let milestones = self
-            .find_all(MilestoneFilter {
-                timeline_id: filter.timeline_id,
-                project_id: filter.project_id,
-                status: filter.status,
-                offset: None,
-                limit: None,
-            })
-            .await?;
+        let count = sqlx::query_scalar!(
+            r#"
+            SELECT COUNT(*) as "count!"
+            FROM milestones
+            WHERE ($1 OR deleted_at IS NULL)
+              AND ($2::BIGINT IS NULL OR timeline_id = $2)
+              AND ($3::BIGINT IS NULL OR project_id = $3)
+              AND ($4::TEXT IS NULL OR status = $4)
+              AND ($5::DATE IS NULL
+                   OR (status IN ('pending', 'in_progress') AND target_date < $5))
+            "#,
+            filter.include_deleted,
+            filter.timeline_id,
+            filter.project_id,
+            filter.status.as_ref().map(|s| s.as_str()),
+            filter.overdue_as_of
+        )
+        .fetch_one(&self.pool)
+        .await?;
 
-        Ok(milestones.len() as i64)
+        Ok(count)
     }
 
     async fn update(&self, milestone: UpdateMilestone) -> Result<Milestone, DevErpError> {
+        let _timer = QueryTimer::start("milestones.update");
         // Validate input
         milestone.validate().map_err(DevErpError::Validation)?;
 
@@ -524,6 +645,7 @@ impl MilestoneRepository for PostgresMilestoneRepository {
                 target_date, actual_date,
                 status as "status!: MilestoneStatus",
                 completion_percentage as "completion_percentage!",
+                sequence,
                 metadata,
                 created_at, updated_at, deleted_at
             "#,
@@ -545,6 +667,7 @@ impl MilestoneRepository for PostgresMilestoneRepository {
     }
 
     async fn soft_delete(&self, id: i64) -> Result<bool, DevErpError> {
+        let _timer = QueryTimer::start("milestones.soft_delete");
         debug!("Soft deleting milestone id: {}", id);
 
         let result = sqlx::query!(
@@ -564,6 +687,7 @@ impl MilestoneRepository for PostgresMilestoneRepository {
     }
 
     async fn delete(&self, id: i64) -> Result<bool, DevErpError> {
+        let _timer = QueryTimer::start("milestones.delete");
         debug!("Hard deleting milestone id: {}", id);
 
         let result = sqlx::query!("DELETE FROM milestones WHERE id = $1", id)
@@ -580,6 +704,7 @@ impl MilestoneRepository for PostgresMilestoneRepository {
     }
 
     async fn restore(&self, id: i64) -> Result<bool, DevErpError> {
+        let _timer = QueryTimer::start("milestones.restore");
         debug!("Restoring milestone id: {}", id);
 
         let result = sqlx::query!(
@@ -597,4 +722,123 @@ impl MilestoneRepository for PostgresMilestoneRepository {
 
         Ok(restored)
     }
+    async fn task_counts_by_timeline(
+        &self,
+        timeline_id: i64,
+    ) -> Result<Vec<MilestoneTaskCount>, DevErpError> {
+        let _timer = QueryTimer::start("milestones.task_counts_by_timeline");
+        debug!("Counting linked tasks for timeline_id: {}", timeline_id);
+
+        let counts = sqlx::query_as!(
+            MilestoneTaskCount,
+            r#"
+            SELECT
+                m.id as "milestone_id!",
+                COUNT(t.id) FILTER (WHERE t.status <> 'cancelled') as "total_tasks!",
+                COUNT(t.id) FILTER (WHERE t.status = 'done') as "done_tasks!"
+            FROM milestones m
+            JOIN tasks t ON t.milestone_id = m.id AND t.deleted_at IS NULL
+            WHERE m.timeline_id = $1 AND m.deleted_at IS NULL
+            GROUP BY m.id
+            "#,
+            timeline_id
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(counts)
+    }
+
+    async fn mark_overdue_missed(
+        &self,
+        project_id: Option<i64>,
+        timeline_id: Option<i64>,
+        today: NaiveDate,
+    ) -> Result<Vec<Milestone>, DevErpError> {
+        let _timer = QueryTimer::start("milestones.mark_overdue_missed");
+        debug!(
+            "Marking overdue milestones as missed (project_id: {:?}, timeline_id: {:?}, today: {})",
+            project_id, timeline_id, today
+        );
+
+        let results = sqlx::query_as!(
+            Milestone,
+            r#"
+            UPDATE milestones
+            SET status = 'missed', updated_at = NOW()
+            WHERE deleted_at IS NULL
+              AND status IN ('pending', 'in_progress')
+              AND target_date < $1
+              AND ($2::BIGINT IS NULL OR project_id = $2)
+              AND ($3::BIGINT IS NULL OR timeline_id = $3)
+            RETURNING
+                id, timeline_id, project_id, name, description,
+                target_date, actual_date,
+                status as "status!: MilestoneStatus",
+                completion_percentage as "completion_percentage!",
+                sequence,
+                metadata,
+                created_at, updated_at, deleted_at
+            "#,
+            today,
+            project_id,
+            timeline_id
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(results)
+    }
+
+    async fn reorder(&self, timeline_id: i64, ordered_ids: &[i64]) -> Result<(), DevErpError> {
+        let _timer = QueryTimer::start("milestones.reorder");
+        debug!(
+            "Reordering milestones of timeline_id {}: {:?}",
+            timeline_id, ordered_ids
+        );
+
+        let mut tx = self.pool.begin().await?;
+
+        // Lock the timeline's milestones so the set cannot change underneath us
+        let mut current = sqlx::query_scalar!(
+            r#"
+            SELECT id
+            FROM milestones
+            WHERE timeline_id = $1 AND deleted_at IS NULL
+            FOR UPDATE
+            "#,
+            timeline_id
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let mut requested = ordered_ids.to_vec();
+        current.sort_unstable();
+        requested.sort_unstable();
+        if current != requested {
+            tx.rollback().await?;
+            return Err(DevErpError::Validation(format!(
+                "The new order must list each milestone of timeline {} exactly once",
+                timeline_id
+            )));
+        }
+
+        sqlx::query!(
+            r#"
+            UPDATE milestones m
+            SET sequence = o.position::INTEGER, updated_at = NOW()
+            FROM UNNEST($1::BIGINT[]) WITH ORDINALITY AS o(id, position)
+            WHERE m.id = o.id
+            "#,
+            ordered_ids
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        info!(timeline_id = %timeline_id, "Milestones reordered successfully");
+
+        Ok(())
+    }
 }
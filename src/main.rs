@@ -5,12 +5,12 @@ use deverp::Result;
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    // Initialize logger
-    logger::init()?;
-
     // Parse CLI arguments
     let cli = Cli::parse();
 
+    // Initialize logger (--verbose turns on debug diagnostics)
+    logger::init(cli.verbose)?;
+
     // Handle commands
     cli.execute().await?;
 
@@ -0,0 +1,88 @@
+// Working-day arithmetic for scheduling
+
+use std::collections::HashSet;
+
+use chrono::{Datelike, NaiveDate, Weekday};
+
+/// True for Monday through Friday
+fn is_weekday(date: NaiveDate) -> bool {
+    !matches!(date.weekday(), Weekday::Sat | Weekday::Sun)
+}
+
+/// Weekdays in `[start, end)` that are not in `holidays`
+///
+/// Counts the same half-open range as `(end - start).num_days()`, so a
+/// Monday-to-Monday span is five working days. Holidays falling on a weekend
+/// or outside the range have no effect, and duplicates count once. A reversed
+/// range gives the negated count.
+pub fn working_days_between(start: NaiveDate, end: NaiveDate, holidays: &[NaiveDate]) -> i64 {
+    if end < start {
+        return -working_days_between(end, start, holidays);
+    }
+
+    let total = (end - start).num_days();
+    let full_weeks = total / 7;
+    let mut days = full_weeks * 5;
+    // The leftover days after the whole weeks, walked one by one
+    let mut date = start + chrono::Duration::days(full_weeks * 7);
+    while date < end {
+        if is_weekday(date) {
+            days += 1;
+        }
+        date = date.succ_opt().expect("date within range");
+    }
+
+    let skipped = holidays
+        .iter()
+        .filter(|&&day| day >= start && day < end && is_weekday(day))
+        .collect::<HashSet<_>>()
+        .len() as i64;
+
+    days - skipped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_working_days_skip_weekends() {
+        // Monday to the following Monday
+        assert_eq!(working_days_between(date(3, 3), date(3, 10), &[]), 5);
+        // Friday to Monday spans only the Friday
+        assert_eq!(working_days_between(date(3, 7), date(3, 10), &[]), 1);
+        // Saturday to Monday has no working days
+        assert_eq!(working_days_between(date(3, 8), date(3, 10), &[]), 0);
+        assert_eq!(working_days_between(date(3, 3), date(3, 3), &[]), 0);
+    }
+
+    #[test]
+    fn test_working_days_across_several_weekends() {
+        // Wednesday 2025-03-05 to Thursday 2025-03-27: three weekends in between
+        assert_eq!(working_days_between(date(3, 5), date(3, 27), &[]), 16);
+        // All of March 2025 (starts on a Saturday)
+        assert_eq!(working_days_between(date(3, 1), date(4, 1), &[]), 21);
+        assert_eq!(working_days_between(date(3, 27), date(3, 5), &[]), -16);
+    }
+
+    #[test]
+    fn test_working_days_exclude_weekday_holidays() {
+        let holidays = [
+            // Wednesday, counted once despite the duplicate
+            date(3, 12),
+            date(3, 12),
+            // Saturday, already not a working day
+            date(3, 15),
+            // Outside the range
+            date(4, 2),
+        ];
+
+        assert_eq!(working_days_between(date(3, 10), date(3, 17), &holidays), 4);
+        // The end date is exclusive, so a holiday there changes nothing
+        assert_eq!(working_days_between(date(3, 10), date(3, 12), &holidays), 2);
+    }
+}
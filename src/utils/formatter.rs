@@ -9,11 +9,9 @@ pub enum OutputFormat {
     Table,
     Json,
     Plain,
-}
-
-/// Display a success message
-pub fn success(message: &str) {
-    println!("{} {}", "✓".green().bold(), message);
+    Csv,
+    Yaml,
+    Count,
 }
 
 /// Display an error message
@@ -21,53 +19,201 @@ pub fn error(message: &str) {
     eprintln!("{} {}", "✗".red().bold(), message);
 }
 
-/// Display an info message
-pub fn info(message: &str) {
-    println!("{} {}", "ℹ".blue().bold(), message);
+/// Write a bare row count followed by a newline
+pub fn write_count<W: std::io::Write>(out: &mut W, count: i64) -> std::io::Result<()> {
+    writeln!(out, "{}", count)
 }
 
-/// Display a warning message
-pub fn warning(message: &str) {
-    println!("{} {}", "⚠".yellow().bold(), message);
+/// Serialize items as newline-delimited JSON, one compact object per line
+pub fn to_ndjson<T: Serialize>(items: &[T]) -> crate::Result<String> {
+    let mut out = String::new();
+    for item in items {
+        let line = serde_json::to_string(item).map_err(|e| {
+            crate::utils::error::DevErpError::Internal(format!("JSON serialization error: {}", e))
+        })?;
+        out.push_str(&line);
+        out.push('\n');
+    }
+    Ok(out)
 }
 
-/// Output data in JSON format
-pub fn output_json<T: Serialize + ?Sized>(data: &T) -> crate::Result<()> {
-    let json = serde_json::to_string_pretty(data).map_err(|e| {
-        crate::utils::error::DevErpError::Internal(format!("JSON serialization error: {}", e))
-    })?;
-    println!("{}", json);
-    Ok(())
+/// Serialize data to a YAML document
+///
+/// `None` fields are kept and rendered as `null`, matching the JSON output.
+pub fn to_yaml<T: Serialize + ?Sized>(data: &T) -> crate::Result<String> {
+    serde_yaml::to_string(data).map_err(|e| {
+        crate::utils::error::DevErpError::Internal(format!("YAML serialization error: {}", e))
+    })
 }
 
-/// Print a table header
-pub fn table_header(columns: &[&str]) {
+/// Render a table header and its separator line
+pub fn format_table_header(columns: &[&str]) -> String {
     let header = columns
         .iter()
         .map(|col| col.bold().cyan().to_string())
         .collect::<Vec<_>>()
         .join(" | ");
-    println!("{}", header);
 
     let separator = columns
         .iter()
         .map(|col| "-".repeat(col.len()))
         .collect::<Vec<_>>()
         .join("-+-");
-    println!("{}", separator);
+
+    format!("{}\n{}", header, separator)
 }
 
-/// Print a table row
-pub fn table_row(values: &[String]) {
-    println!("{}", values.join(" | "));
+/// Escape a single CSV field (RFC 4180)
+pub fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Render a header and rows as CSV text
+pub fn to_csv(headers: &[&str], rows: &[Vec<String>]) -> String {
+    let mut out = String::new();
+    let header: Vec<String> = headers.iter().map(|h| csv_field(h)).collect();
+    out.push_str(&header.join(","));
+    out.push('\n');
+
+    for row in rows {
+        let fields: Vec<String> = row.iter().map(|f| csv_field(f)).collect();
+        out.push_str(&fields.join(","));
+        out.push('\n');
+    }
+
+    out
+}
+
+/// Check that every requested field is in the allowlist
+pub fn validate_fields(fields: &[String], allowed: &[&str]) -> crate::Result<()> {
+    let unknown: Vec<&str> = fields
+        .iter()
+        .map(String::as_str)
+        .filter(|f| !allowed.contains(f))
+        .collect();
+
+    if unknown.is_empty() {
+        Ok(())
+    } else {
+        Err(crate::utils::error::DevErpError::Validation(format!(
+            "Unknown field(s): {}. Available fields: {}",
+            unknown.join(", "),
+            allowed.join(", ")
+        )))
+    }
 }
 
-/// Print a key-value pair
-pub fn key_value(key: &str, value: &str) {
-    println!("{}: {}", key.bold(), value);
+/// Serialize each item and keep only the requested top-level fields
+pub fn project_fields<T: Serialize>(
+    items: &[T],
+    fields: &[String],
+) -> crate::Result<Vec<serde_json::Value>> {
+    items
+        .iter()
+        .map(|item| {
+            let value = serde_json::to_value(item).map_err(|e| {
+                crate::utils::error::DevErpError::Internal(format!(
+                    "JSON serialization error: {}",
+                    e
+                ))
+            })?;
+
+            let mut projected = serde_json::Map::new();
+            if let serde_json::Value::Object(mut map) = value {
+                for field in fields {
+                    if let Some(v) = map.remove(field) {
+                        projected.insert(field.clone(), v);
+                    }
+                }
+            }
+            Ok(serde_json::Value::Object(projected))
+        })
+        .collect()
 }
 
-/// Print a section header
-pub fn section_header(title: &str) {
-    println!("\n{}", title.bold().underline());
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_csv_field_escaping() {
+        assert_eq!(csv_field("serde"), "serde");
+        assert_eq!(csv_field("Acme, Inc."), "\"Acme, Inc.\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(csv_field("two\nlines"), "\"two\nlines\"");
+    }
+
+    #[test]
+    fn test_to_csv() {
+        let csv = to_csv(
+            &["name", "count"],
+            &[vec!["a,b".to_string(), "1".to_string()]],
+        );
+        assert_eq!(csv, "name,count\n\"a,b\",1\n");
+    }
+
+    #[test]
+    fn test_validate_fields_rejects_unknown() {
+        let allowed = ["id", "title", "status"];
+        assert!(validate_fields(&["id".to_string(), "status".to_string()], &allowed).is_ok());
+        assert!(validate_fields(&["id".to_string(), "secret".to_string()], &allowed).is_err());
+    }
+
+    #[test]
+    fn test_yaml_project_round_trip() {
+        use crate::domain::project::entity::{Priority, Project, ProjectStatus};
+        use chrono::{NaiveDate, TimeZone, Utc};
+
+        let timestamp = Utc.with_ymd_and_hms(2025, 1, 2, 3, 4, 5).unwrap();
+        let project = Project {
+            id: 7,
+            uuid: uuid::Uuid::new_v4(),
+            name: "YAML Project".to_string(),
+            description: None,
+            code: Some("YAML-1".to_string()),
+            status: ProjectStatus::OnHold,
+            priority: Priority::High,
+            start_date: NaiveDate::from_ymd_opt(2025, 1, 1),
+            end_date: None,
+            actual_start_date: None,
+            actual_end_date: None,
+            progress_percentage: Some(40),
+            budget: None,
+            currency: None,
+            repository_url: None,
+            repository_branch: Some("main".to_string()),
+            tags: Some(vec!["yaml".to_string(), "export".to_string()]),
+            metadata: Some(serde_json::json!({ "owner": "ops", "budget": 1200 })),
+            version: 1,
+            created_at: timestamp,
+            updated_at: timestamp,
+            deleted_at: None,
+        };
+
+        let yaml = to_yaml(&project).unwrap();
+        assert!(yaml.contains("description: null"));
+        // Enums use the same lowercase names as JSON and the CLI
+        assert!(yaml.contains("status: on_hold"));
+        assert!(yaml.contains("priority: high"));
+
+        let parsed: Project = serde_yaml::from_str(&yaml).unwrap();
+        assert_eq!(
+            serde_json::to_value(&parsed).unwrap(),
+            serde_json::to_value(&project).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_count_output_is_bare_integer() {
+        let mut out = Vec::new();
+        write_count(&mut out, 12).unwrap();
+
+        let text = String::from_utf8(out).unwrap();
+        assert_eq!(text, "12\n");
+        assert_eq!(text.trim().parse::<i64>().unwrap(), 12);
+    }
 }
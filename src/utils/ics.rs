@@ -0,0 +1,125 @@
+// iCalendar (RFC 5545) output
+
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+
+/// Product identifier written into every calendar
+const PRODID: &str = "-//DevERP//DevERP//EN";
+
+/// Longest content line, in octets, before it must be folded
+const MAX_LINE_OCTETS: usize = 75;
+
+/// An all-day calendar event
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalendarEvent {
+    /// Globally unique, stable identifier so re-imports update the same event
+    pub uid: String,
+    pub date: NaiveDate,
+    pub summary: String,
+    pub description: String,
+}
+
+/// Render events as a VCALENDAR document with CRLF line endings
+///
+/// `stamp` becomes every event's DTSTAMP, i.e. when the calendar was generated.
+pub fn to_ics(events: &[CalendarEvent], stamp: DateTime<Utc>) -> String {
+    let stamp = stamp.format("%Y%m%dT%H%M%SZ").to_string();
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        format!("PRODID:{}", PRODID),
+        "CALSCALE:GREGORIAN".to_string(),
+    ];
+
+    for event in events {
+        lines.push("BEGIN:VEVENT".to_string());
+        lines.push(format!("UID:{}", escape_text(&event.uid)));
+        lines.push(format!("DTSTAMP:{}", stamp));
+        lines.push(format!(
+            "DTSTART;VALUE=DATE:{}",
+            event.date.format("%Y%m%d")
+        ));
+        // DTEND is exclusive, so an all-day event ends the next day
+        lines.push(format!(
+            "DTEND;VALUE=DATE:{}",
+            (event.date + Duration::days(1)).format("%Y%m%d")
+        ));
+        lines.push(format!("SUMMARY:{}", escape_text(&event.summary)));
+        lines.push(format!("DESCRIPTION:{}", escape_text(&event.description)));
+        lines.push("END:VEVENT".to_string());
+    }
+
+    lines.push("END:VCALENDAR".to_string());
+
+    lines.iter().map(|line| fold_line(line) + "\r\n").collect()
+}
+
+/// Escape a TEXT value (backslash, semicolon, comma and newlines)
+fn escape_text(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Fold a content line at 75 octets without splitting a UTF-8 character
+fn fold_line(line: &str) -> String {
+    let mut folded = String::with_capacity(line.len());
+    let mut octets = 0;
+    for c in line.chars() {
+        if octets + c.len_utf8() > MAX_LINE_OCTETS {
+            folded.push_str("\r\n ");
+            // The leading space of a continuation line counts towards its length
+            octets = 1;
+        }
+        folded.push(c);
+        octets += c.len_utf8();
+    }
+    folded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_to_ics_all_day_event() {
+        let event = CalendarEvent {
+            uid: "task-7@deverp".to_string(),
+            date: NaiveDate::from_ymd_opt(2025, 3, 31).unwrap(),
+            summary: "Fix login; again".to_string(),
+            description: "3 days overdue, see notes".to_string(),
+        };
+        let stamp = Utc.with_ymd_and_hms(2025, 4, 3, 9, 0, 0).unwrap();
+
+        let ics = to_ics(&[event], stamp);
+        let lines: Vec<&str> = ics.split("\r\n").collect();
+
+        assert_eq!(lines[0], "BEGIN:VCALENDAR");
+        assert!(lines.contains(&"DTSTART;VALUE=DATE:20250331"));
+        assert!(lines.contains(&"DTEND;VALUE=DATE:20250401"));
+        assert!(lines.contains(&"DTSTAMP:20250403T090000Z"));
+        assert!(lines.contains(&"SUMMARY:Fix login\\; again"));
+        assert!(lines.contains(&"DESCRIPTION:3 days overdue\\, see notes"));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+    }
+
+    #[test]
+    fn test_long_lines_are_folded() {
+        let line = format!("SUMMARY:{}", "é".repeat(60));
+        let folded = fold_line(&line);
+
+        for part in folded.split("\r\n") {
+            assert!(part.len() <= MAX_LINE_OCTETS);
+        }
+        assert_eq!(folded.replace("\r\n ", ""), line);
+    }
+}
@@ -3,9 +3,12 @@
 use crate::Result;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
 
-pub fn init() -> Result<()> {
-    // Set default log level to info if RUST_LOG is not set
-    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
+pub fn init(verbose: bool) -> Result<()> {
+    // RUST_LOG wins; otherwise --verbose enables debug output (including
+    // repository timings) for deverp, and info is the default
+    let default_directives = if verbose { "info,deverp=debug" } else { "info" };
+    let filter =
+        EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(default_directives));
 
     tracing_subscriber::registry()
         .with(filter)
@@ -1,5 +1,11 @@
 // Utility modules
 
+pub mod calendar;
 pub mod error;
 pub mod formatter;
+pub mod ics;
 pub mod logger;
+pub mod retry;
+pub mod time;
+pub mod timing;
+pub mod watch;
@@ -0,0 +1,122 @@
+// Retry helper for transient database failures
+
+use std::future::Future;
+use std::time::Duration;
+use tracing::warn;
+
+use crate::utils::error::DevErpError;
+
+/// Delay before the first retry; doubled after every failed attempt
+const BASE_DELAY: Duration = Duration::from_millis(200);
+
+/// Whether an error is worth retrying
+///
+/// Only connection-level problems and PostgreSQL serialization/deadlock
+/// failures qualify; validation, not-found and constraint errors never do.
+pub fn is_transient(err: &DevErpError) -> bool {
+    match err {
+        DevErpError::Database(db_err) => match db_err {
+            sqlx::Error::Io(_) | sqlx::Error::PoolTimedOut => true,
+            sqlx::Error::Database(e) => e.code().is_some_and(|code| {
+                // 08xxx: connection exception, 40001: serialization failure,
+                // 40P01: deadlock, 53300: too many connections, 57P0x: shutdown
+                code.starts_with("08")
+                    || matches!(code.as_ref(), "40001" | "40P01" | "53300")
+                    || code.starts_with("57P0")
+            }),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// Run `op`, retrying up to `retries` extra times while it fails transiently
+///
+/// With `retries == 0` the operation runs exactly once.
+pub async fn retry_transient<T, F, Fut>(retries: u32, mut op: F) -> Result<T, DevErpError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, DevErpError>>,
+{
+    let mut attempt = 0;
+    let mut delay = BASE_DELAY;
+
+    loop {
+        match op().await {
+            Err(err) if attempt < retries && is_transient(&err) => {
+                attempt += 1;
+                warn!(
+                    "Transient failure ({}), retrying in {:?} (attempt {}/{})",
+                    err, delay, attempt, retries
+                );
+                tokio::time::sleep(delay).await;
+                delay *= 2;
+            }
+            result => return result,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    fn transient_error() -> DevErpError {
+        DevErpError::Database(sqlx::Error::PoolTimedOut)
+    }
+
+    #[test]
+    fn test_is_transient() {
+        assert!(is_transient(&transient_error()));
+        assert!(!is_transient(&DevErpError::Database(
+            sqlx::Error::RowNotFound
+        )));
+        assert!(!is_transient(&DevErpError::Validation("bad".to_string())));
+    }
+
+    #[tokio::test]
+    async fn test_transient_failure_succeeds_on_retry() {
+        let calls = AtomicU32::new(0);
+
+        let result = retry_transient(2, || async {
+            if calls.fetch_add(1, Ordering::SeqCst) == 0 {
+                Err(transient_error())
+            } else {
+                Ok(42)
+            }
+        })
+        .await;
+
+        assert_eq!(result.unwrap(), 42);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_non_transient_error_is_not_retried() {
+        let calls = AtomicU32::new(0);
+
+        let result: Result<(), _> = retry_transient(3, || async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Err(DevErpError::Validation("bad input".to_string()))
+        })
+        .await;
+
+        assert!(matches!(result, Err(DevErpError::Validation(_))));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_no_retry_by_default() {
+        let calls = AtomicU32::new(0);
+
+        let result: Result<(), _> = retry_transient(0, || async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Err(transient_error())
+        })
+        .await;
+
+        assert!(result.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+}
@@ -0,0 +1,53 @@
+// Calendar helpers for timezone-aware filters
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use chrono_tz::Tz;
+
+/// UTC bounds `[start, end)` of the local calendar day containing `now`
+///
+/// Day boundaries follow `tz`, so the window is 23 or 25 hours long on
+/// DST transition days.
+pub fn local_day_bounds(tz: Tz, now: DateTime<Utc>) -> (DateTime<Utc>, DateTime<Utc>) {
+    let today = now.with_timezone(&tz).date_naive();
+    let start_of = |date: chrono::NaiveDate| {
+        let midnight = date.and_hms_opt(0, 0, 0).expect("midnight is a valid time");
+        // A DST gap can swallow midnight; fall back to reading it as UTC
+        tz.from_local_datetime(&midnight)
+            .earliest()
+            .map(|dt| dt.with_timezone(&Utc))
+            .unwrap_or_else(|| Utc.from_utc_datetime(&midnight))
+    };
+
+    (start_of(today), start_of(today + Duration::days(1)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_local_day_bounds_follow_timezone() {
+        // 01:30 on 2025-03-11 in Seoul (UTC+9)
+        let now = Utc.with_ymd_and_hms(2025, 3, 10, 16, 30, 0).unwrap();
+        let (start, end) = local_day_bounds(chrono_tz::Asia::Seoul, now);
+
+        assert_eq!(start, Utc.with_ymd_and_hms(2025, 3, 10, 15, 0, 0).unwrap());
+        assert_eq!(end, Utc.with_ymd_and_hms(2025, 3, 11, 15, 0, 0).unwrap());
+
+        // The same instant is still the 10th in UTC
+        let (utc_start, _) = local_day_bounds(chrono_tz::UTC, now);
+        assert_eq!(
+            utc_start,
+            Utc.with_ymd_and_hms(2025, 3, 10, 0, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_local_day_bounds_across_dst_change() {
+        // US clocks spring forward on 2025-03-09
+        let now = Utc.with_ymd_and_hms(2025, 3, 9, 18, 0, 0).unwrap();
+        let (start, end) = local_day_bounds(chrono_tz::America::New_York, now);
+
+        assert_eq!(end - start, Duration::hours(23));
+    }
+}
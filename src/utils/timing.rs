@@ -0,0 +1,109 @@
+// Query timing diagnostics
+
+use std::time::Instant;
+use tracing::{debug, Level};
+
+/// Tracing target used for repository timing events
+pub const TIMING_TARGET: &str = "deverp::timing";
+
+/// Guard that logs the elapsed time of a repository call when dropped
+///
+/// The clock is only started when debug logging is enabled for
+/// [`TIMING_TARGET`], so the guard costs a single level check otherwise.
+pub struct QueryTimer {
+    operation: &'static str,
+    start: Option<Instant>,
+}
+
+impl QueryTimer {
+    /// Start timing the given repository operation
+    pub fn start(operation: &'static str) -> Self {
+        let start = if tracing::enabled!(target: TIMING_TARGET, Level::DEBUG) {
+            Some(Instant::now())
+        } else {
+            None
+        };
+
+        Self { operation, start }
+    }
+}
+
+impl Drop for QueryTimer {
+    fn drop(&mut self) {
+        if let Some(start) = self.start {
+            let elapsed = start.elapsed();
+            debug!(
+                target: TIMING_TARGET,
+                operation = self.operation,
+                elapsed_ms = elapsed.as_secs_f64() * 1000.0,
+                "Repository call finished in {:.2?}",
+                elapsed
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use std::sync::{Arc, Mutex};
+    use tracing_subscriber::fmt::MakeWriter;
+
+    #[derive(Clone, Default)]
+    struct CapturedLogs(Arc<Mutex<Vec<u8>>>);
+
+    impl CapturedLogs {
+        fn contents(&self) -> String {
+            String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+        }
+    }
+
+    impl Write for CapturedLogs {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl<'a> MakeWriter<'a> for CapturedLogs {
+        type Writer = CapturedLogs;
+
+        fn make_writer(&'a self) -> Self::Writer {
+            self.clone()
+        }
+    }
+
+    fn run_with_level(level: Level) -> String {
+        let logs = CapturedLogs::default();
+        let subscriber = tracing_subscriber::fmt()
+            .with_max_level(level)
+            .with_writer(logs.clone())
+            .with_ansi(false)
+            .finish();
+
+        tracing::subscriber::with_default(subscriber, || {
+            let _timer = QueryTimer::start("projects.find_by_id");
+        });
+
+        logs.contents()
+    }
+
+    #[test]
+    fn test_timer_logs_duration_when_verbose() {
+        let output = run_with_level(Level::DEBUG);
+        assert!(output.contains("projects.find_by_id"));
+        assert!(output.contains("elapsed_ms"));
+        assert!(output.contains("Repository call finished in"));
+    }
+
+    #[test]
+    fn test_timer_silent_when_debug_disabled() {
+        let output = run_with_level(Level::INFO);
+        assert!(output.is_empty());
+    }
+}
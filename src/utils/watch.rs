@@ -0,0 +1,141 @@
+// Polling loop for watch-style commands
+
+use serde::Serialize;
+use std::future::Future;
+use std::time::Duration;
+use tokio::time::MissedTickBehavior;
+
+use crate::utils::error::DevErpError;
+
+/// Top-level fields whose serialized values differ between two snapshots
+///
+/// Fields are compared through their JSON representation, so any entity that
+/// implements `Serialize` can be diffed without a `PartialEq` impl.
+pub fn changed_fields<T: Serialize>(before: &T, after: &T) -> Vec<String> {
+    let (before, after) = match (serde_json::to_value(before), serde_json::to_value(after)) {
+        (Ok(before), Ok(after)) => (before, after),
+        _ => return Vec::new(),
+    };
+
+    match (before, after) {
+        (serde_json::Value::Object(before), serde_json::Value::Object(after)) => {
+            let mut changed: Vec<String> = after
+                .iter()
+                .filter(|(key, value)| before.get(*key) != Some(*value))
+                .map(|(key, _)| key.clone())
+                .collect();
+            changed.extend(
+                before
+                    .keys()
+                    .filter(|key| !after.contains_key(*key))
+                    .cloned(),
+            );
+            changed
+        }
+        (before, after) if before != after => vec!["value".to_string()],
+        _ => Vec::new(),
+    }
+}
+
+/// Poll `fetch` every `interval` and call `render` when the result changes
+///
+/// The first snapshot is always rendered with an empty change list; later
+/// snapshots are rendered only when [`changed_fields`] reports a difference.
+/// Returns `Ok(())` once Ctrl-C is received, or the first fetch error.
+pub async fn watch<T, F, Fut, R>(
+    interval: Duration,
+    mut fetch: F,
+    mut render: R,
+) -> Result<(), DevErpError>
+where
+    T: Serialize,
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, DevErpError>>,
+    R: FnMut(&T, &[String]),
+{
+    let mut ticker = tokio::time::interval(interval);
+    ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    // Created once so a Ctrl-C that arrives mid-fetch is still observed
+    let ctrl_c = tokio::signal::ctrl_c();
+    tokio::pin!(ctrl_c);
+
+    let mut previous: Option<T> = None;
+    loop {
+        tokio::select! {
+            _ = &mut ctrl_c => return Ok(()),
+            _ = ticker.tick() => {
+                let current = fetch().await?;
+                match previous {
+                    None => render(&current, &[]),
+                    Some(ref prev) => {
+                        let changed = changed_fields(prev, &current);
+                        if !changed.is_empty() {
+                            render(&current, &changed);
+                        }
+                    }
+                }
+                previous = Some(current);
+            }
+        }
+    }
+}
+
+/// Call `tick` every `interval` until Ctrl-C is received
+///
+/// Unlike [`watch`], every tick runs regardless of what changed. Returns
+/// `Ok(())` once Ctrl-C is received, or the first error from `tick`.
+pub async fn repeat<F, Fut>(interval: Duration, mut tick: F) -> Result<(), DevErpError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<(), DevErpError>>,
+{
+    let mut ticker = tokio::time::interval(interval);
+    ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    let ctrl_c = tokio::signal::ctrl_c();
+    tokio::pin!(ctrl_c);
+
+    loop {
+        tokio::select! {
+            _ = &mut ctrl_c => return Ok(()),
+            _ = ticker.tick() => tick().await?,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize, Clone)]
+    struct Snapshot {
+        title: String,
+        status: String,
+        estimated_hours: Option<f64>,
+    }
+
+    fn snapshot() -> Snapshot {
+        Snapshot {
+            title: "Fix login".to_string(),
+            status: "todo".to_string(),
+            estimated_hours: None,
+        }
+    }
+
+    #[test]
+    fn test_identical_snapshots_have_no_changes() {
+        assert!(changed_fields(&snapshot(), &snapshot()).is_empty());
+    }
+
+    #[test]
+    fn test_changed_fields_are_reported() {
+        let mut after = snapshot();
+        after.status = "in_progress".to_string();
+        after.estimated_hours = Some(3.5);
+
+        let mut changed = changed_fields(&snapshot(), &after);
+        changed.sort();
+        assert_eq!(changed, vec!["estimated_hours", "status"]);
+    }
+}
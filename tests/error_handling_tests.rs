@@ -1,15 +1,8 @@
 mod helpers;
 
 use chrono::NaiveDate;
-use deverp::domain::project::entity::{CreateProject, Priority, ProjectStatus};
-use deverp::domain::project::service::ProjectService;
-use deverp::domain::resource::service::ResourceService;
-use deverp::domain::task::service::TaskService;
-use deverp::infrastructure::repositories::project_repo::PostgresProjectRepository;
-use deverp::infrastructure::repositories::resource_repo::PostgresResourceRepository;
-use deverp::infrastructure::repositories::task_repo::{
-    PostgresTaskCommentRepository, PostgresTaskDependencyRepository, PostgresTaskRepository,
-};
+use deverp::domain::project::entity::{CreateProject, Priority, ProjectStatus, UpdateProject};
+use deverp::domain::task::entity::{CompletionCheck, UpdateTask};
 use deverp::utils::error::DevErpError;
 use helpers::*;
 use std::sync::Arc;
@@ -20,8 +13,7 @@ async fn test_validation_errors() {
     let pool = setup_test_database()
         .await
         .expect("Failed to setup test database");
-    let project_repo = Arc::new(PostgresProjectRepository::new(pool.clone()));
-    let project_service = ProjectService::new(project_repo);
+    let project_service = project_service(&pool);
 
     println!("Testing validation errors...");
 
@@ -34,6 +26,8 @@ async fn test_validation_errors() {
         priority: Some(Priority::Medium),
         start_date: None,
         end_date: None,
+        budget: None,
+        currency: None,
         repository_url: None,
         repository_branch: None,
         tags: None,
@@ -53,6 +47,8 @@ async fn test_validation_errors() {
         priority: Some(Priority::Medium),
         start_date: Some(NaiveDate::from_ymd_opt(2025, 12, 31).unwrap()),
         end_date: Some(NaiveDate::from_ymd_opt(2025, 1, 1).unwrap()), // End before start
+        budget: None,
+        currency: None,
         repository_url: None,
         repository_branch: None,
         tags: None,
@@ -77,13 +73,8 @@ async fn test_not_found_errors() {
         .await
         .expect("Failed to setup test database");
 
-    let project_repo = Arc::new(PostgresProjectRepository::new(pool.clone()));
-    let task_repo = Arc::new(PostgresTaskRepository::new(pool.clone()));
-    let task_dependency_repo = Arc::new(PostgresTaskDependencyRepository::new(pool.clone()));
-    let task_comment_repo = Arc::new(PostgresTaskCommentRepository::new(pool.clone()));
-
-    let project_service = ProjectService::new(project_repo);
-    let task_service = TaskService::new(task_repo, task_dependency_repo, task_comment_repo);
+    let project_service = project_service(&pool);
+    let task_service = task_service(&pool);
 
     println!("Testing not found errors...");
 
@@ -113,10 +104,13 @@ async fn test_not_found_errors() {
         actual_start_date: None,
         actual_end_date: None,
         progress_percentage: None,
+        budget: None,
+        currency: None,
         repository_url: None,
         repository_branch: None,
         tags: None,
         metadata: None,
+        expected_version: None,
     };
     let _result = project_service.update_project(fake_project_update).await;
     // Note: update might succeed if ID doesn't exist yet, so we test with a very large ID
@@ -129,12 +123,23 @@ async fn test_not_found_errors() {
     // Test 4: Get tasks for non-existent project
     let filter = deverp::domain::task::entity::TaskFilter {
         project_id: Some(999999),
+        project_ids: None,
         status: None,
         priority: None,
         task_type: None,
         assigned_to: None,
         parent_task_id: None,
+        milestone_id: None,
+        subtree_of: None,
+        search: None,
+        search_comments: false,
         include_deleted: false,
+        estimate_missing: false,
+        actual_missing: false,
+        updated_after: None,
+        updated_before: None,
+        sort_by: None,
+        sort_desc: false,
         offset: None,
         limit: None,
     };
@@ -156,8 +161,7 @@ async fn test_duplicate_errors() {
     let pool = setup_test_database()
         .await
         .expect("Failed to setup test database");
-    let project_repo = Arc::new(PostgresProjectRepository::new(pool.clone()));
-    let project_service = ProjectService::new(project_repo);
+    let project_service = project_service(&pool);
 
     println!("Testing duplicate errors...");
 
@@ -199,10 +203,7 @@ async fn test_referential_integrity() {
         .await
         .expect("Failed to setup test database");
 
-    let task_repo = Arc::new(PostgresTaskRepository::new(pool.clone()));
-    let task_dependency_repo = Arc::new(PostgresTaskDependencyRepository::new(pool.clone()));
-    let task_comment_repo = Arc::new(PostgresTaskCommentRepository::new(pool.clone()));
-    let task_service = TaskService::new(task_repo, task_dependency_repo, task_comment_repo);
+    let task_service = task_service(&pool);
 
     println!("Testing referential integrity...");
 
@@ -234,13 +235,8 @@ async fn test_transaction_rollback() {
         .await
         .expect("Failed to setup test database");
 
-    let project_repo = Arc::new(PostgresProjectRepository::new(pool.clone()));
-    let task_repo = Arc::new(PostgresTaskRepository::new(pool.clone()));
-    let task_dependency_repo = Arc::new(PostgresTaskDependencyRepository::new(pool.clone()));
-    let task_comment_repo = Arc::new(PostgresTaskCommentRepository::new(pool.clone()));
-
-    let project_service = ProjectService::new(project_repo.clone());
-    let task_service = TaskService::new(task_repo, task_dependency_repo, task_comment_repo);
+    let project_service = project_service(&pool);
+    let task_service = task_service(&pool);
 
     println!("Testing transaction rollback...");
 
@@ -259,12 +255,23 @@ async fn test_transaction_rollback() {
     // Count tasks before
     let filter_before = deverp::domain::task::entity::TaskFilter {
         project_id: Some(project.id),
+        project_ids: None,
         status: None,
         priority: None,
         task_type: None,
         assigned_to: None,
         parent_task_id: None,
+        milestone_id: None,
+        subtree_of: None,
+        search: None,
+        search_comments: false,
         include_deleted: false,
+        estimate_missing: false,
+        actual_missing: false,
+        updated_after: None,
+        updated_before: None,
+        sort_by: None,
+        sort_desc: false,
         offset: None,
         limit: None,
     };
@@ -302,12 +309,23 @@ async fn test_transaction_rollback() {
     // Verify data is still consistent
     let filter_after = deverp::domain::task::entity::TaskFilter {
         project_id: Some(project.id),
+        project_ids: None,
         status: None,
         priority: None,
         task_type: None,
         assigned_to: None,
         parent_task_id: None,
+        milestone_id: None,
+        subtree_of: None,
+        search: None,
+        search_comments: false,
         include_deleted: false,
+        estimate_missing: false,
+        actual_missing: false,
+        updated_after: None,
+        updated_before: None,
+        sort_by: None,
+        sort_desc: false,
         offset: None,
         limit: None,
     };
@@ -351,8 +369,7 @@ async fn test_concurrent_conflicts() {
         .await
         .expect("Failed to setup test database");
 
-    let project_repo = Arc::new(PostgresProjectRepository::new(pool.clone()));
-    let project_service = Arc::new(ProjectService::new(project_repo));
+    let project_service = Arc::new(project_service(&pool));
 
     println!("Testing concurrent access...");
 
@@ -381,10 +398,13 @@ async fn test_concurrent_conflicts() {
             actual_start_date: None,
             actual_end_date: None,
             progress_percentage: Some(50),
+            budget: None,
+            currency: None,
             repository_url: None,
             repository_branch: None,
             tags: None,
             metadata: None,
+            expected_version: None,
         };
         service1.update_project(update).await
     });
@@ -402,10 +422,13 @@ async fn test_concurrent_conflicts() {
             actual_start_date: None,
             actual_end_date: None,
             progress_percentage: Some(75),
+            budget: None,
+            currency: None,
             repository_url: None,
             repository_branch: None,
             tags: None,
             metadata: None,
+            expected_version: None,
         };
         service2.update_project(update).await
     });
@@ -423,6 +446,122 @@ async fn test_concurrent_conflicts() {
     println!("✅ Concurrent conflict tests completed");
 }
 
+/// Test that an update carrying a stale version is rejected instead of
+/// overwriting a concurrent edit
+#[tokio::test]
+async fn test_stale_version_update_rejected() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+
+    let project_service = project_service(&pool);
+    let task_service = task_service(&pool);
+
+    println!("Testing optimistic concurrency...");
+
+    // Two editors read the same project version
+    let project = project_service
+        .create_project(create_test_project("Versioned Project"))
+        .await
+        .expect("Failed to create project");
+    assert_eq!(project.version, 1);
+
+    let first = project_service
+        .update_project(UpdateProject {
+            id: project.id,
+            progress_percentage: Some(40),
+            expected_version: Some(project.version),
+            ..Default::default()
+        })
+        .await
+        .expect("First update should succeed");
+    assert_eq!(first.version, 2);
+
+    let stale = project_service
+        .update_project(UpdateProject {
+            id: project.id,
+            progress_percentage: Some(90),
+            expected_version: Some(project.version),
+            ..Default::default()
+        })
+        .await;
+    assert!(
+        matches!(stale, Err(DevErpError::Conflict(_))),
+        "Stale project update should conflict, got {:?}",
+        stale
+    );
+    let current = project_service
+        .get_project(project.id)
+        .await
+        .expect("Failed to reload project");
+    assert_eq!(current.progress_percentage, Some(40));
+    assert_eq!(current.version, 2);
+    println!("✓ Stale project update rejected");
+
+    // Updates without an expected version still apply and bump the version
+    let unguarded = project_service
+        .update_project(UpdateProject {
+            id: project.id,
+            progress_percentage: Some(50),
+            ..Default::default()
+        })
+        .await
+        .expect("Unguarded update should succeed");
+    assert_eq!(unguarded.version, 3);
+
+    // Same for tasks
+    let task = task_service
+        .create_task(create_test_task(project.id, "Versioned Task"))
+        .await
+        .expect("Failed to create task");
+    assert_eq!(task.version, 1);
+
+    let task_update = |title: &str, expected_version| UpdateTask {
+        id: task.id,
+        title: Some(title.to_string()),
+        description: None,
+        status: None,
+        blocked_reason: None,
+        priority: None,
+        assigned_to: None,
+        estimated_hours: None,
+        actual_hours: None,
+        due_date: None,
+        task_type: None,
+        tags: None,
+        expected_version,
+    };
+
+    let first = task_service
+        .update_task(
+            task_update("Renamed once", Some(task.version)),
+            CompletionCheck::Enforce,
+        )
+        .await
+        .expect("First task update should succeed");
+    assert_eq!(first.task.version, 2);
+
+    let stale = task_service
+        .update_task(
+            task_update("Renamed twice", Some(task.version)),
+            CompletionCheck::Enforce,
+        )
+        .await;
+    assert!(
+        matches!(stale, Err(DevErpError::Conflict(_))),
+        "Stale task update should conflict, got {:?}",
+        stale.map(|result| result.task)
+    );
+    let current = task_service
+        .get_task_by_id(task.id)
+        .await
+        .expect("Failed to reload task");
+    assert_eq!(current.title, "Renamed once");
+    println!("✓ Stale task update rejected");
+
+    println!("✅ Optimistic concurrency tests completed");
+}
+
 /// Test resource cleanup after errors
 #[tokio::test]
 async fn test_resource_cleanup() {
@@ -430,11 +569,8 @@ async fn test_resource_cleanup() {
         .await
         .expect("Failed to setup test database");
 
-    let project_repo = Arc::new(PostgresProjectRepository::new(pool.clone()));
-    let resource_repo = Arc::new(PostgresResourceRepository::new(pool.clone()));
-
-    let project_service = ProjectService::new(project_repo);
-    let resource_service = ResourceService::new(resource_repo);
+    let project_service = project_service(&pool);
+    let resource_service = resource_service(&pool);
 
     println!("Testing resource cleanup after errors...");
 
@@ -14,7 +14,7 @@ pub async fn create_test_pool() -> Result<PgPool, DevErpError> {
         .max_connections(5)
         .connect(&database_url)
         .await
-        .map_err(|e| DevErpError::Database(e))?;
+        .map_err(DevErpError::Database)?;
 
     Ok(pool)
 }
@@ -70,6 +70,18 @@ pub async fn cleanup_database(pool: &PgPool) -> Result<(), DevErpError> {
         .execute(pool)
         .await?;
 
+    sqlx::query("TRUNCATE TABLE status_snapshots")
+        .execute(pool)
+        .await?;
+
+    sqlx::query("TRUNCATE TABLE project_templates")
+        .execute(pool)
+        .await?;
+
+    sqlx::query("TRUNCATE TABLE audit_logs")
+        .execute(pool)
+        .await?;
+
     Ok(())
 }
 
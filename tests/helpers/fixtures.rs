@@ -14,6 +14,8 @@ pub fn create_test_project(name: &str) -> CreateProject {
         priority: Some(Priority::Medium),
         start_date: Some(NaiveDate::from_ymd_opt(2025, 1, 1).unwrap()),
         end_date: Some(NaiveDate::from_ymd_opt(2025, 12, 31).unwrap()),
+        budget: None,
+        currency: None,
         repository_url: Some("https://github.com/test/repo".to_string()),
         repository_branch: Some("main".to_string()),
         tags: Some(vec!["test".to_string(), "integration".to_string()]),
@@ -31,6 +33,7 @@ pub fn create_test_task(project_id: i64, title: &str) -> CreateTask {
         description: Some(format!("Test task: {}", title)),
         task_number: Some(format!("TASK-{}", title.to_uppercase().replace(" ", "-"))),
         status: Some(TaskStatus::Todo),
+        blocked_reason: None,
         priority: Some(TaskPriority::Medium),
         assigned_to: Some("test_user".to_string()),
         estimated_hours: Some(8.0),
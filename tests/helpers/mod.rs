@@ -1,5 +1,7 @@
 pub mod database;
 pub mod fixtures;
+pub mod services;
 
 pub use database::*;
 pub use fixtures::*;
+pub use services::*;
@@ -0,0 +1,61 @@
+use deverp::domain::project::service::ProjectService;
+use deverp::domain::resource::service::ResourceService;
+use deverp::domain::task::service::TaskService;
+use deverp::domain::timeline::service::TimelineService;
+use deverp::infrastructure::repositories::project_repo::PostgresProjectRepository;
+use deverp::infrastructure::repositories::resource_repo::{
+    PostgresResourceDependencyRepository, PostgresResourceRepository,
+};
+use deverp::infrastructure::repositories::task_repo::{
+    PostgresTaskAssigneeRepository, PostgresTaskCommentRepository,
+    PostgresTaskDependencyRepository, PostgresTaskRepository, PostgresTaskWorkLogRepository,
+};
+use deverp::infrastructure::repositories::template_repo::PostgresTemplateRepository;
+use deverp::infrastructure::repositories::timeline_repo::{
+    PostgresMilestoneRepository, PostgresTimelineRepository,
+};
+use sqlx::PgPool;
+use std::sync::Arc;
+
+/// Creates a project service with every repository on the test pool
+#[allow(dead_code)]
+pub fn project_service(pool: &PgPool) -> ProjectService {
+    ProjectService::new(
+        Arc::new(PostgresProjectRepository::new(pool.clone())),
+        Arc::new(PostgresTimelineRepository::new(pool.clone())),
+        Arc::new(PostgresTaskRepository::new(pool.clone())),
+        Arc::new(PostgresTemplateRepository::new(pool.clone())),
+    )
+}
+
+/// Creates a task service with every repository on the test pool
+#[allow(dead_code)]
+pub fn task_service(pool: &PgPool) -> TaskService {
+    TaskService::new(
+        Arc::new(PostgresTaskRepository::new(pool.clone())),
+        Arc::new(PostgresTaskDependencyRepository::new(pool.clone())),
+        Arc::new(PostgresTaskCommentRepository::new(pool.clone())),
+        Arc::new(PostgresTaskWorkLogRepository::new(pool.clone())),
+        Arc::new(PostgresTaskAssigneeRepository::new(pool.clone())),
+        Arc::new(PostgresProjectRepository::new(pool.clone())),
+    )
+}
+
+/// Creates a resource service with every repository on the test pool
+#[allow(dead_code)]
+pub fn resource_service(pool: &PgPool) -> ResourceService {
+    ResourceService::new(
+        Arc::new(PostgresResourceRepository::new(pool.clone())),
+        Arc::new(PostgresResourceDependencyRepository::new(pool.clone())),
+    )
+}
+
+/// Creates a timeline service with every repository on the test pool
+#[allow(dead_code)]
+pub fn timeline_service(pool: &PgPool) -> TimelineService {
+    TimelineService::new(
+        Arc::new(PostgresTimelineRepository::new(pool.clone())),
+        Arc::new(PostgresMilestoneRepository::new(pool.clone())),
+        Arc::new(PostgresTaskRepository::new(pool.clone())),
+    )
+}
@@ -4,13 +4,41 @@
 
 mod helpers;
 
-use deverp::domain::project::entity::ProjectFilter;
-use deverp::domain::project::service::ProjectService;
-use deverp::domain::task::service::TaskService;
+use chrono::{NaiveDate, Utc};
+use clap::Parser;
+use deverp::cli::commands::Commands;
+use deverp::cli::output::{empty_list_message, BufferSink};
+use deverp::cli::{config, project, report, task, timeline, AppContext, Cli};
+use deverp::domain::export::{ExportFormat, ExportService};
+use deverp::domain::project::entity::{
+    CloneProjectOptions, Project, ProjectFilter, ProjectSortField, ProjectStatus, TagMatchMode,
+};
+use deverp::domain::project::repository::ProjectRepository;
+use deverp::domain::report::ReportService;
+use deverp::domain::resource::entity::ResourceFilter;
+use deverp::domain::resource::repository::ResourceRepository;
+use deverp::domain::snapshot::SnapshotService;
+use deverp::domain::task::entity::{
+    CompletionCheck, CreateTask, CreateTaskComment, CreateTaskDependency, TaskDetail, TaskFilter,
+    TaskPriority, TaskSortField, TaskStatus,
+};
+use deverp::domain::task::repository::TaskRepository;
+use deverp::domain::template::{ProjectTemplate, TemplateService};
+use deverp::domain::timeline::entity::{
+    CreateMilestone, MilestoneFilter, MilestoneStatus, UpdateMilestone,
+};
+use deverp::domain::timeline::repository::{MilestoneRepository, TimelineRepository};
+use deverp::infrastructure::repositories::export_repo::PostgresExportRepository;
 use deverp::infrastructure::repositories::project_repo::PostgresProjectRepository;
-use deverp::infrastructure::repositories::task_repo::{
-    PostgresTaskCommentRepository, PostgresTaskDependencyRepository, PostgresTaskRepository,
+use deverp::infrastructure::repositories::resource_repo::PostgresResourceRepository;
+use deverp::infrastructure::repositories::snapshot_repo::PostgresSnapshotRepository;
+use deverp::infrastructure::repositories::task_repo::PostgresTaskRepository;
+use deverp::infrastructure::repositories::template_repo::PostgresTemplateRepository;
+use deverp::infrastructure::repositories::timeline_repo::{
+    PostgresMilestoneRepository, PostgresTimelineRepository,
 };
+use deverp::utils::error::DevErpError;
+use deverp::utils::time::local_day_bounds;
 use helpers::*;
 use std::sync::Arc;
 
@@ -20,8 +48,7 @@ async fn test_create_and_get_project() {
     let pool = setup_test_database()
         .await
         .expect("Failed to setup test database");
-    let project_repo = Arc::new(PostgresProjectRepository::new(pool.clone()));
-    let project_service = ProjectService::new(project_repo);
+    let project_service = project_service(&pool);
 
     // Create a project
     let create_input = create_test_project("Integration Test Project");
@@ -51,8 +78,7 @@ async fn test_list_projects() {
     let pool = setup_test_database()
         .await
         .expect("Failed to setup test database");
-    let project_repo = Arc::new(PostgresProjectRepository::new(pool.clone()));
-    let project_service = ProjectService::new(project_repo);
+    let project_service = project_service(&pool);
 
     // Create multiple projects
     for i in 1..=5 {
@@ -71,6 +97,7 @@ async fn test_list_projects() {
         tags: None,
         offset: None,
         limit: None,
+        ..Default::default()
     };
 
     let projects = project_service
@@ -83,6 +110,362 @@ async fn test_list_projects() {
     println!("✅ Project listing test passed");
 }
 
+/// Test ordering projects by planned end date with empty end dates last
+#[tokio::test]
+async fn test_list_projects_sorted_by_end_date() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+    let project_service = project_service(&pool);
+
+    let end_dates = [
+        ("Deadline Sort March", NaiveDate::from_ymd_opt(2025, 3, 1)),
+        ("Deadline Sort Open", None),
+        (
+            "Deadline Sort January",
+            NaiveDate::from_ymd_opt(2025, 1, 15),
+        ),
+    ];
+    for (name, end_date) in end_dates {
+        let mut input = create_test_project(name);
+        input.end_date = end_date;
+        input.start_date = None;
+        project_service
+            .create_project(input)
+            .await
+            .expect("Failed to create project");
+    }
+
+    let filter = ProjectFilter {
+        search: Some("Deadline Sort".to_string()),
+        sort_by: Some(ProjectSortField::EndDate),
+        ..Default::default()
+    };
+    let projects = project_service
+        .list_projects(filter)
+        .await
+        .expect("Failed to list projects");
+
+    let names: Vec<&str> = projects.iter().map(|p| p.name.as_str()).collect();
+    assert_eq!(
+        names,
+        vec![
+            "Deadline Sort January",
+            "Deadline Sort March",
+            "Deadline Sort Open"
+        ]
+    );
+
+    println!("✅ Project end date sorting test passed");
+}
+
+/// Test filtering projects by start and end date ranges
+#[tokio::test]
+async fn test_list_projects_by_date_range() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+    let project_service = project_service(&pool);
+
+    let date = |m, d| NaiveDate::from_ymd_opt(2025, m, d);
+    let schedules = [
+        ("Range Q1 Short", date(1, 10), date(2, 28)),
+        ("Range Q1 Long", date(3, 1), date(9, 30)),
+        ("Range Q2", date(4, 1), date(5, 31)),
+        ("Range Undated", None, None),
+    ];
+    for (name, start_date, end_date) in schedules {
+        let mut input = create_test_project(name);
+        input.start_date = start_date;
+        input.end_date = end_date;
+        project_service
+            .create_project(input)
+            .await
+            .expect("Failed to create project");
+    }
+
+    let filter = ProjectFilter {
+        search: Some("Range".to_string()),
+        start_date_from: date(1, 1),
+        start_date_to: date(3, 31),
+        end_date_to: date(6, 30),
+        ..Default::default()
+    };
+    let projects = project_service
+        .list_projects(filter.clone())
+        .await
+        .expect("Failed to list projects");
+    let names: Vec<&str> = projects.iter().map(|p| p.name.as_str()).collect();
+    assert_eq!(names, vec!["Range Q1 Short"]);
+    assert_eq!(project_service.count_projects(filter).await.unwrap(), 1);
+
+    let inverted = ProjectFilter {
+        start_date_from: date(3, 31),
+        start_date_to: date(1, 1),
+        ..Default::default()
+    };
+    let result = project_service.list_projects(inverted).await;
+    assert!(matches!(result, Err(DevErpError::Validation(_))));
+
+    println!("✅ Project date range filter test passed");
+}
+
+/// `--tags-all` keeps only projects carrying every requested tag
+#[tokio::test]
+async fn test_list_projects_matching_all_tags() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+    let project_service = project_service(&pool);
+
+    let tagged = [
+        ("Tag Mode Both", vec!["tagmode-api", "tagmode-web"]),
+        ("Tag Mode Api", vec!["tagmode-api"]),
+        ("Tag Mode None", vec![]),
+    ];
+    for (name, tags) in tagged {
+        let mut input = create_test_project(name);
+        input.tags = Some(tags.into_iter().map(String::from).collect());
+        project_service
+            .create_project(input)
+            .await
+            .expect("Failed to create project");
+    }
+
+    let names = |mode| {
+        let filter = ProjectFilter {
+            search: Some("Tag Mode".to_string()),
+            tags: Some(vec!["tagmode-api".to_string(), "tagmode-web".to_string()]),
+            tag_match_mode: mode,
+            sort_by: Some(ProjectSortField::Name),
+            ..Default::default()
+        };
+        let project_service = &project_service;
+        async move {
+            let count = project_service
+                .count_projects(filter.clone())
+                .await
+                .unwrap();
+            let projects = project_service.list_projects(filter).await.unwrap();
+            assert_eq!(count, projects.len() as i64);
+            projects.into_iter().map(|p| p.name).collect::<Vec<_>>()
+        }
+    };
+
+    assert_eq!(
+        names(TagMatchMode::Any).await,
+        vec!["Tag Mode Api", "Tag Mode Both"]
+    );
+    assert_eq!(names(TagMatchMode::All).await, vec!["Tag Mode Both"]);
+
+    println!("✅ Project tag match mode test passed");
+}
+
+/// Per-status counts add up to the filtered project list
+#[tokio::test]
+async fn test_count_projects_by_status_matches_filter() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+    let project_service = project_service(&pool);
+
+    let projects = [
+        ("Counted Alpha", ProjectStatus::Active),
+        ("Counted Beta", ProjectStatus::Active),
+        ("Counted Gamma", ProjectStatus::Completed),
+        ("Skipped Delta", ProjectStatus::Active),
+    ];
+    for (name, status) in projects {
+        let mut input = create_test_project(name);
+        input.status = Some(status);
+        project_service
+            .create_project(input)
+            .await
+            .expect("Failed to create project");
+    }
+
+    let filter = ProjectFilter {
+        search: Some("Counted".to_string()),
+        limit: Some(1),
+        ..Default::default()
+    };
+    let counts = project_service
+        .count_projects_by_status(filter.clone())
+        .await
+        .expect("Failed to count projects");
+    let listed = project_service
+        .list_projects(ProjectFilter {
+            limit: None,
+            ..filter.clone()
+        })
+        .await
+        .expect("Failed to list projects");
+
+    // Pagination does not shrink the counts
+    let total: i64 = counts.iter().map(|(_, count)| count).sum();
+    assert_eq!(total, listed.len() as i64);
+    assert_eq!(total, 3);
+    for (status, count) in &counts {
+        let expected = listed.iter().filter(|p| &p.status == status).count() as i64;
+        assert_eq!(*count, expected, "{} count", status);
+    }
+
+    // A status filter narrows the breakdown to that status
+    let completed = project_service
+        .count_projects_by_status(ProjectFilter {
+            status: Some(ProjectStatus::Completed),
+            ..filter
+        })
+        .await
+        .expect("Failed to count projects");
+    assert_eq!(completed, vec![(ProjectStatus::Completed, 1)]);
+}
+
+/// A list command writes through the context's sink, so its output can be captured
+#[tokio::test]
+async fn test_project_list_writes_to_sink() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+    let project_service = project_service(&pool);
+
+    for name in ["Captured Alpha", "Captured Beta", "Elsewhere Gamma"] {
+        project_service
+            .create_project(create_test_project(name))
+            .await
+            .expect("Failed to create project");
+    }
+
+    let buffer = Arc::new(BufferSink::new());
+    let ctx = AppContext::new(pool).with_sink(buffer.clone());
+    let cli = Cli::try_parse_from([
+        "deverp", "--format", "json", "project", "list", "--search", "Captured",
+    ])
+    .expect("Failed to parse arguments");
+    let Commands::Project(command) = cli.command else {
+        panic!("Expected a project command");
+    };
+
+    project::handle(&ctx, command, cli.format)
+        .await
+        .expect("Failed to list projects");
+
+    let listed: Vec<Project> =
+        serde_json::from_str(&buffer.contents()).expect("Output is not a JSON project list");
+    let mut names: Vec<&str> = listed.iter().map(|p| p.name.as_str()).collect();
+    names.sort();
+    assert_eq!(names, ["Captured Alpha", "Captured Beta"]);
+}
+
+/// --dry-run shows the project a delete would remove and leaves it in place
+#[tokio::test]
+async fn test_dry_run_delete_keeps_project() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+    let project_service = project_service(&pool);
+    let project = project_service
+        .create_project(create_test_project("Dry Run Target"))
+        .await
+        .expect("Failed to create project");
+
+    let buffer = Arc::new(BufferSink::new());
+    let ctx = AppContext::new(pool)
+        .with_sink(buffer.clone())
+        .with_dry_run(true);
+    let id = project.id.to_string();
+    let cli = Cli::try_parse_from(["deverp", "--dry-run", "project", "delete", &id])
+        .expect("Failed to parse arguments");
+    assert!(cli.dry_run);
+    let Commands::Project(command) = cli.command else {
+        panic!("Expected a project command");
+    };
+
+    project::handle(&ctx, command, cli.format)
+        .await
+        .expect("Dry run should succeed without confirmation");
+
+    let output = buffer.contents();
+    assert!(output.contains("Dry run: would delete project"));
+    assert!(output.contains("Dry Run Target"));
+    let still_there = project_service
+        .get_project(project.id)
+        .await
+        .expect("Project should not have been deleted");
+    assert!(still_there.deleted_at.is_none());
+}
+
+/// Test restoring soft-deleted projects, tasks and comments
+#[tokio::test]
+async fn test_restore_soft_deleted_project_and_task() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+
+    let project_service = project_service(&pool);
+    let task_service = task_service(&pool);
+
+    let project = project_service
+        .create_project(create_test_project("Restorable Project"))
+        .await
+        .expect("Failed to create project");
+    let task = task_service
+        .create_task(create_test_task(project.id, "Restorable Task"))
+        .await
+        .expect("Failed to create task");
+
+    let listed = |projects: Vec<Project>| projects.iter().any(|p| p.id == project.id);
+
+    assert!(project_service.delete_project(project.id).await.unwrap());
+    let projects = project_service
+        .list_projects(ProjectFilter::default())
+        .await
+        .unwrap();
+    assert!(!listed(projects));
+
+    assert!(project_service.restore_project(project.id).await.unwrap());
+    let projects = project_service
+        .list_projects(ProjectFilter::default())
+        .await
+        .unwrap();
+    assert!(listed(projects));
+
+    // A project that is not deleted cannot be restored
+    assert!(!project_service.restore_project(project.id).await.unwrap());
+
+    task_service.delete_task(task.id).await.unwrap();
+    task_service.restore_task(task.id).await.unwrap();
+    let restored = task_service.get_task_by_id(task.id).await.unwrap();
+    assert!(restored.deleted_at.is_none());
+
+    let result = task_service.restore_task(task.id).await;
+    assert!(matches!(result, Err(DevErpError::NotFound(_))));
+
+    let comment = task_service
+        .add_task_comment(CreateTaskComment {
+            task_id: task.id,
+            comment_text: "Worth keeping".to_string(),
+            author: Some("alice".to_string()),
+        })
+        .await
+        .unwrap();
+    task_service.delete_task_comment(comment.id).await.unwrap();
+    assert!(task_service
+        .get_task_comments(task.id)
+        .await
+        .unwrap()
+        .is_empty());
+
+    task_service.restore_task_comment(comment.id).await.unwrap();
+    let comments = task_service.get_task_comments(task.id).await.unwrap();
+    assert_eq!(comments.len(), 1);
+
+    let result = task_service.restore_task_comment(comment.id).await;
+    assert!(matches!(result, Err(DevErpError::NotFound(_))));
+
+    println!("✅ Restore test passed");
+}
+
 /// Test task creation and association with project
 #[tokio::test]
 async fn test_create_task_for_project() {
@@ -90,13 +473,8 @@ async fn test_create_task_for_project() {
         .await
         .expect("Failed to setup test database");
 
-    let project_repo = Arc::new(PostgresProjectRepository::new(pool.clone()));
-    let task_repo = Arc::new(PostgresTaskRepository::new(pool.clone()));
-    let dependency_repo = Arc::new(PostgresTaskDependencyRepository::new(pool.clone()));
-    let comment_repo = Arc::new(PostgresTaskCommentRepository::new(pool.clone()));
-
-    let project_service = ProjectService::new(project_repo);
-    let task_service = TaskService::new(task_repo, dependency_repo, comment_repo);
+    let project_service = project_service(&pool);
+    let task_service = task_service(&pool);
 
     // Create a project
     let project = project_service
@@ -113,6 +491,20 @@ async fn test_create_task_for_project() {
 
     assert_eq!(task.project_id, project.id);
     assert!(!task.title.is_empty());
+    assert!(task.started_at.is_none(), "Todo tasks are not started");
+
+    // Tasks created in progress (`task create --start-now`) are started right away
+    let mut started_input = create_test_task(project.id, "Already Started Task");
+    started_input.status = Some(TaskStatus::InProgress);
+    let before = Utc::now();
+    let started = task_service
+        .create_task(started_input)
+        .await
+        .expect("Failed to create task");
+
+    assert_eq!(started.status, TaskStatus::InProgress);
+    let started_at = started.started_at.expect("started_at should be set");
+    assert!(started_at >= before - chrono::Duration::seconds(5));
 
     println!("✅ Task creation test passed");
 }
@@ -123,8 +515,7 @@ async fn test_project_crud() {
     let pool = setup_test_database()
         .await
         .expect("Failed to setup test database");
-    let project_repo = Arc::new(PostgresProjectRepository::new(pool.clone()));
-    let project_service = ProjectService::new(project_repo);
+    let project_service = project_service(&pool);
 
     // Create
     let create_input = create_test_project("CRUD Test Project");
@@ -155,10 +546,13 @@ async fn test_project_crud() {
         actual_start_date: None,
         actual_end_date: None,
         progress_percentage: Some(50),
+        budget: None,
+        currency: None,
         repository_url: None,
         repository_branch: None,
         tags: None,
         metadata: None,
+        expected_version: None,
     };
 
     let updated = project_service
@@ -180,21 +574,2359 @@ async fn test_project_crud() {
     println!("✅ CRUD operations test passed");
 }
 
-/// Test database connection and migration
+/// Test applying a project template with linked tasks and a milestone
 #[tokio::test]
-async fn test_database_connection() {
-    let result = setup_test_database().await;
-    assert!(result.is_ok(), "Database connection should succeed");
+async fn test_create_project_from_template() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
 
-    let pool = result.unwrap();
+    let template_path =
+        std::env::temp_dir().join(format!("deverp_template_{}.json", uuid::Uuid::new_v4()));
+    std::fs::write(
+        &template_path,
+        r#"{
+            "project": { "name": "Template Project", "priority": "high" },
+            "timelines": [{
+                "name": "Release Plan",
+                "start_offset_days": 0,
+                "end_offset_days": 60,
+                "milestones": [{ "key": "mvp", "name": "MVP", "offset_days": 30 }]
+            }],
+            "tasks": [
+                { "title": "Build backend", "due_offset_days": 20, "milestone": "mvp" },
+                { "title": "Build frontend", "due_offset_days": 25, "milestone": "mvp" }
+            ]
+        }"#,
+    )
+    .expect("Failed to write template");
 
-    // Verify we can execute a simple query
-    let row: (i32,) = sqlx::query_as("SELECT 1")
-        .fetch_one(&pool)
+    let template_service =
+        TemplateService::new(Arc::new(PostgresTemplateRepository::new(pool.clone())));
+    let template = template_service
+        .load_template(&template_path)
+        .expect("Template should be valid");
+    std::fs::remove_file(&template_path).ok();
+
+    let start = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+    let applied = template_service
+        .apply_template(&template, start, None, None)
         .await
-        .expect("Failed to execute test query");
+        .expect("Failed to apply template");
 
-    assert_eq!(row.0, 1);
+    let project = &applied.project;
+    assert_eq!(project.name, "Template Project");
+    assert_eq!(project.start_date, Some(start));
+    assert_eq!(project.end_date, NaiveDate::from_ymd_opt(2025, 3, 7));
 
-    println!("✅ Database connection test passed");
+    assert_eq!(applied.timelines.len(), 1);
+    assert_eq!(applied.timelines[0].project_id, project.id);
+
+    assert_eq!(applied.milestones.len(), 1);
+    let milestone = &applied.milestones[0];
+    assert_eq!(milestone.project_id, project.id);
+    assert_eq!(milestone.timeline_id, applied.timelines[0].id);
+    assert_eq!(
+        milestone.target_date,
+        NaiveDate::from_ymd_opt(2025, 2, 5).unwrap()
+    );
+
+    assert_eq!(applied.tasks.len(), 2);
+    let task_repo = PostgresTaskRepository::new(pool.clone());
+    for task in &applied.tasks {
+        let stored = task_repo
+            .find_by_id(task.id)
+            .await
+            .expect("Failed to load task")
+            .expect("Task should exist");
+        assert_eq!(stored.project_id, project.id);
+        assert_eq!(stored.milestone_id, Some(milestone.id));
+    }
+    assert_eq!(
+        applied.tasks[0].due_date.unwrap().date_naive(),
+        NaiveDate::from_ymd_opt(2025, 1, 26).unwrap()
+    );
+
+    println!("✅ Project template test passed");
+}
+
+/// Test that a project saved as a stored template can be re-instantiated
+#[tokio::test]
+async fn test_save_and_apply_stored_template() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+
+    let template_service =
+        TemplateService::new(Arc::new(PostgresTemplateRepository::new(pool.clone())));
+
+    // Build the source project from an inline template
+    let seed: ProjectTemplate = serde_json::from_str(
+        r#"{
+            "project": { "name": "Standard Service", "priority": "high", "duration_days": 45 },
+            "timelines": [
+                {
+                    "name": "Build",
+                    "timeline_type": "sprint",
+                    "start_offset_days": 0,
+                    "end_offset_days": 20,
+                    "milestones": [{ "key": "alpha", "name": "Alpha", "offset_days": 14 }]
+                },
+                { "name": "Launch", "start_offset_days": 21, "end_offset_days": 45 }
+            ],
+            "tasks": [
+                { "title": "Scaffold service", "due_offset_days": 3, "milestone": "alpha" },
+                { "title": "Write runbook", "estimated_hours": 4.0 }
+            ]
+        }"#,
+    )
+    .expect("Seed template should parse");
+    let source = template_service
+        .apply_template(
+            &seed,
+            NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+            None,
+            None,
+        )
+        .await
+        .expect("Failed to create source project");
+
+    let stored = template_service
+        .save_template(source.project.id, "standard")
+        .await
+        .expect("Failed to save template");
+    assert_eq!(stored.version, 1);
+    assert_eq!(stored.source_project_id, Some(source.project.id));
+
+    let start = NaiveDate::from_ymd_opt(2025, 6, 2).unwrap();
+    let latest = template_service
+        .get_stored_template("standard", None)
+        .await
+        .expect("Failed to load template");
+    let applied = template_service
+        .apply_template(&latest.template, start, Some("New".to_string()), None)
+        .await
+        .expect("Failed to apply stored template");
+
+    assert_eq!(applied.project.name, "New");
+    assert_eq!(applied.project.start_date, Some(start));
+    assert_eq!(
+        applied.project.end_date,
+        NaiveDate::from_ymd_opt(2025, 7, 17)
+    );
+
+    let timeline_names: Vec<&str> = applied.timelines.iter().map(|t| t.name.as_str()).collect();
+    assert_eq!(timeline_names, vec!["Build", "Launch"]);
+    assert_eq!(
+        applied.timelines[1].start_date,
+        start + chrono::Duration::days(21)
+    );
+
+    assert_eq!(applied.milestones.len(), 1);
+    assert_eq!(applied.milestones[0].name, "Alpha");
+    assert_eq!(applied.milestones[0].timeline_id, applied.timelines[0].id);
+
+    let mut titles: Vec<&str> = applied.tasks.iter().map(|t| t.title.as_str()).collect();
+    titles.sort();
+    assert_eq!(titles, vec!["Scaffold service", "Write runbook"]);
+    let scaffold = applied
+        .tasks
+        .iter()
+        .find(|t| t.title == "Scaffold service")
+        .unwrap();
+    assert_eq!(scaffold.milestone_id, Some(applied.milestones[0].id));
+    assert_eq!(
+        scaffold.due_date.unwrap().date_naive(),
+        start + chrono::Duration::days(3)
+    );
+
+    // Saving the same name again adds a version instead of replacing it
+    let second = template_service
+        .save_template(applied.project.id, "standard")
+        .await
+        .expect("Failed to save second version");
+    assert_eq!(second.version, 2);
+
+    let listed = template_service
+        .list_stored_templates()
+        .await
+        .expect("Failed to list templates");
+    let versions: Vec<i32> = listed.iter().map(|t| t.version).collect();
+    assert_eq!(versions, vec![2, 1]);
+
+    println!("✅ Stored template test passed");
+}
+
+/// Test cloning a project's timelines and task skeletons onto a new start date
+#[tokio::test]
+async fn test_clone_project_shifts_dates_and_resets_progress() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+
+    let template_service =
+        TemplateService::new(Arc::new(PostgresTemplateRepository::new(pool.clone())));
+    let seed: ProjectTemplate = serde_json::from_str(
+        r#"{
+            "project": { "name": "Clone Source", "code": "CLONE-SRC", "duration_days": 30 },
+            "timelines": [{
+                "name": "Build",
+                "start_offset_days": 0,
+                "end_offset_days": 30,
+                "milestones": [{ "key": "beta", "name": "Beta", "offset_days": 20 }]
+            }],
+            "tasks": [
+                { "title": "Ship beta", "due_offset_days": 18, "milestone": "beta", "estimated_hours": 6.0 }
+            ]
+        }"#,
+    )
+    .expect("Seed template should parse");
+    let source = template_service
+        .apply_template(
+            &seed,
+            NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+            None,
+            None,
+        )
+        .await
+        .expect("Failed to create source project");
+
+    // Move the source along so there is progress to reset
+    let project_service = project_service(&pool);
+    project_service
+        .update_progress(source.project.id, 60)
+        .await
+        .expect("Failed to update progress");
+    let task_repo = PostgresTaskRepository::new(pool.clone());
+    task_repo
+        .update(deverp::domain::task::entity::UpdateTask {
+            id: source.tasks[0].id,
+            title: None,
+            description: None,
+            status: Some(TaskStatus::Done),
+            blocked_reason: None,
+            priority: None,
+            assigned_to: None,
+            estimated_hours: None,
+            actual_hours: Some(9.0),
+            due_date: None,
+            task_type: None,
+            tags: None,
+            expected_version: None,
+        })
+        .await
+        .expect("Failed to complete source task");
+
+    let start = NaiveDate::from_ymd_opt(2025, 3, 1).unwrap();
+    let full = CloneProjectOptions {
+        start,
+        with_timelines: true,
+        with_tasks: true,
+    };
+    let cloned = project_service
+        .clone_project(
+            source.project.id,
+            "Clone Copy".to_string(),
+            Some("CLONE-COPY".to_string()),
+            full,
+        )
+        .await
+        .expect("Failed to clone project");
+
+    let project = &cloned.project;
+    assert_eq!(project.name, "Clone Copy");
+    assert_eq!(project.code.as_deref(), Some("CLONE-COPY"));
+    assert_eq!(project.status, ProjectStatus::Planning);
+    assert_eq!(project.progress_percentage.unwrap_or(0), 0);
+    assert_eq!(project.actual_start_date, None);
+    assert_eq!(project.start_date, Some(start));
+    assert_eq!(project.end_date, NaiveDate::from_ymd_opt(2025, 3, 31));
+
+    assert_eq!(cloned.timelines.len(), 1);
+    assert_eq!(cloned.timelines[0].start_date, start);
+    assert_eq!(cloned.milestones.len(), 1);
+    assert_eq!(
+        cloned.milestones[0].target_date,
+        NaiveDate::from_ymd_opt(2025, 3, 21).unwrap()
+    );
+
+    assert_eq!(cloned.tasks.len(), 1);
+    let task = &cloned.tasks[0];
+    assert_eq!(task.title, "Ship beta");
+    assert_eq!(task.status, TaskStatus::Todo);
+    assert_eq!(task.estimated_hours, Some(6.0));
+    assert_eq!(task.actual_hours, None);
+    assert_eq!(
+        task.due_date.unwrap().date_naive(),
+        NaiveDate::from_ymd_opt(2025, 3, 19).unwrap()
+    );
+    let stored = task_repo
+        .find_by_id(task.id)
+        .await
+        .expect("Failed to load task")
+        .expect("Task should exist");
+    assert_eq!(stored.milestone_id, Some(cloned.milestones[0].id));
+
+    // Without flags only the project row is copied
+    let bare = project_service
+        .clone_project(
+            source.project.id,
+            "Clone Bare".to_string(),
+            None,
+            CloneProjectOptions {
+                with_timelines: false,
+                with_tasks: false,
+                ..full
+            },
+        )
+        .await
+        .expect("Failed to clone bare project");
+    assert!(bare.timelines.is_empty());
+    assert!(bare.tasks.is_empty());
+
+    // Codes stay unique
+    let duplicate = project_service
+        .clone_project(
+            source.project.id,
+            "Clone Again".to_string(),
+            Some("CLONE-COPY".to_string()),
+            full,
+        )
+        .await;
+    assert!(matches!(duplicate, Err(DevErpError::Conflict(_))));
+
+    println!("✅ Project clone test passed");
+}
+
+/// Test that tasks can be listed across a set of projects
+#[tokio::test]
+async fn test_list_tasks_for_project_set() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+
+    let project_service = project_service(&pool);
+    let task_service = task_service(&pool);
+
+    let mut project_ids = Vec::new();
+    for name in ["Platform", "Mobile App", "Marketing Site"] {
+        let project = project_service
+            .create_project(create_test_project(name))
+            .await
+            .expect("Failed to create project");
+        task_service
+            .create_task(create_test_task(project.id, &format!("{} Task", name)))
+            .await
+            .expect("Failed to create task");
+        project_ids.push(project.id);
+    }
+
+    let filter = TaskFilter {
+        project_ids: Some(project_ids[..2].to_vec()),
+        ..Default::default()
+    };
+    let mut titles: Vec<String> = task_service
+        .list_tasks(filter.clone())
+        .await
+        .expect("Failed to list tasks")
+        .into_iter()
+        .map(|t| t.title)
+        .collect();
+    titles.sort();
+    assert_eq!(titles, vec!["Mobile App Task", "Platform Task"]);
+    assert_eq!(task_service.count_tasks(filter).await.unwrap(), 2);
+
+    println!("✅ Project set task filter test passed");
+}
+
+/// Test that filter values with quotes and semicolons are bound, not spliced into SQL
+#[tokio::test]
+async fn test_task_filter_values_are_escaped() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+
+    let project_service = project_service(&pool);
+    let task_service = task_service(&pool);
+
+    let project = project_service
+        .create_project(create_test_project("Escaping Project"))
+        .await
+        .expect("Failed to create project");
+
+    let tricky = "O'Brien'; DROP TABLE tasks; --";
+    let mut input = create_test_task(project.id, "Quoted Assignee");
+    input.assigned_to = Some(tricky.to_string());
+    let task = task_service
+        .create_task(input)
+        .await
+        .expect("Failed to create task");
+    task_service
+        .create_task(create_test_task(project.id, "Someone Else"))
+        .await
+        .expect("Failed to create task");
+
+    for assignee in ["O'Brien", tricky] {
+        let filter = TaskFilter {
+            assigned_to: Some(assignee.to_string()),
+            ..Default::default()
+        };
+        let listed = task_service
+            .list_tasks(filter.clone())
+            .await
+            .expect("Quoted filter value should not break the query");
+        let count = task_service
+            .count_tasks(filter)
+            .await
+            .expect("Quoted filter value should not break the count");
+
+        let expected = usize::from(assignee == tricky);
+        assert_eq!(listed.len(), expected);
+        assert_eq!(count, expected as i64);
+    }
+
+    let listed = task_service
+        .list_tasks(TaskFilter {
+            assigned_to: Some(tricky.to_string()),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(listed[0].id, task.id);
+
+    // The table survived
+    let total = task_service
+        .count_tasks(TaskFilter {
+            project_id: Some(project.id),
+            ..Default::default()
+        })
+        .await
+        .expect("Tasks table should still exist");
+    assert_eq!(total, 2);
+
+    println!("✅ Task filter escaping test passed");
+}
+
+/// Test task search over titles, descriptions and (optionally) comments
+#[tokio::test]
+async fn test_task_search() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+
+    let project_service = project_service(&pool);
+    let task_service = task_service(&pool);
+
+    let project = project_service
+        .create_project(create_test_project("Search Project"))
+        .await
+        .expect("Failed to create project");
+
+    let mut ids = Vec::new();
+    for (title, description) in [
+        ("Fix Login Bug", "Session expires too early"),
+        ("Refactor auth", "Split the LOGIN BUG workaround out"),
+        ("Write release notes", "Mention the new search"),
+    ] {
+        let mut input = create_test_task(project.id, title);
+        input.description = Some(description.to_string());
+        let task = task_service
+            .create_task(input)
+            .await
+            .expect("Failed to create task");
+        ids.push(task.id);
+    }
+    task_service
+        .add_task_comment(CreateTaskComment {
+            task_id: ids[2],
+            comment_text: "Reported as a login bug by QA".to_string(),
+            author: None,
+        })
+        .await
+        .expect("Failed to add comment");
+
+    let search = |query: &str, search_comments| TaskFilter {
+        project_id: Some(project.id),
+        search: Some(query.to_string()),
+        search_comments,
+        ..Default::default()
+    };
+    let matching_ids = |tasks: Vec<deverp::domain::task::entity::Task>| {
+        let mut ids: Vec<i64> = tasks.into_iter().map(|t| t.id).collect();
+        ids.sort();
+        ids
+    };
+
+    // Title and description, case-insensitive
+    let tasks = task_service
+        .list_tasks(search("login bug", false))
+        .await
+        .expect("Failed to search tasks");
+    assert_eq!(matching_ids(tasks), vec![ids[0], ids[1]]);
+    assert_eq!(
+        task_service
+            .count_tasks(search("login bug", false))
+            .await
+            .unwrap(),
+        2
+    );
+
+    // Comments only count with --search-comments
+    let tasks = task_service
+        .list_tasks(search("login bug", true))
+        .await
+        .expect("Failed to search tasks");
+    assert_eq!(matching_ids(tasks), ids.clone());
+
+    // A blank search does not filter
+    let tasks = task_service
+        .list_tasks(search("  ", false))
+        .await
+        .expect("Failed to search tasks");
+    assert_eq!(tasks.len(), 3);
+
+    println!("✅ Task search test passed");
+}
+
+/// Test that a recursive project listing follows cross-project dependencies
+#[tokio::test]
+async fn test_list_tasks_for_dependent_projects() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+
+    let project_service = project_service(&pool);
+    let task_service = task_service(&pool);
+
+    // Mobile depends on Core, Web depends on Mobile, Docs stands alone
+    let mut projects = Vec::new();
+    let mut tasks = Vec::new();
+    for name in ["Core", "Mobile", "Web", "Docs"] {
+        let project = project_service
+            .create_project(create_test_project(&format!("Recursive {}", name)))
+            .await
+            .expect("Failed to create project");
+        let task = task_service
+            .create_task(create_test_task(project.id, &format!("{} Task", name)))
+            .await
+            .expect("Failed to create task");
+        projects.push(project.id);
+        tasks.push(task.id);
+    }
+    for (task, depends_on) in [(tasks[1], tasks[0]), (tasks[2], tasks[1])] {
+        task_service
+            .add_task_dependency(CreateTaskDependency {
+                task_id: task,
+                depends_on_task_id: depends_on,
+                dependency_type: None,
+            })
+            .await
+            .expect("Failed to add dependency");
+    }
+
+    assert_eq!(
+        task_service
+            .expand_dependent_projects(projects[0], 0)
+            .await
+            .unwrap(),
+        vec![projects[0]]
+    );
+
+    let one_level = task_service
+        .expand_dependent_projects(projects[0], 1)
+        .await
+        .unwrap();
+    assert_eq!(one_level, vec![projects[0], projects[1]]);
+
+    let mut titles: Vec<String> = task_service
+        .list_tasks(TaskFilter {
+            project_ids: Some(one_level),
+            ..Default::default()
+        })
+        .await
+        .expect("Failed to list tasks")
+        .into_iter()
+        .map(|t| t.title)
+        .collect();
+    titles.sort();
+    assert_eq!(titles, vec!["Core Task", "Mobile Task"]);
+
+    assert_eq!(
+        task_service
+            .expand_dependent_projects(projects[0], 5)
+            .await
+            .unwrap(),
+        projects[..3].to_vec()
+    );
+
+    println!("✅ Recursive project task listing test passed");
+}
+
+/// Test that soft-deleted tasks are only counted when requested
+#[tokio::test]
+async fn test_count_excludes_soft_deleted_tasks() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+
+    let project_service = project_service(&pool);
+    let task_service = task_service(&pool);
+
+    let project = project_service
+        .create_project(create_test_project("Soft Delete Count Project"))
+        .await
+        .expect("Failed to create project");
+
+    let kept = task_service
+        .create_task(create_test_task(project.id, "Kept Task"))
+        .await
+        .expect("Failed to create task");
+    let removed = task_service
+        .create_task(create_test_task(project.id, "Removed Task"))
+        .await
+        .expect("Failed to create task");
+
+    task_service
+        .delete_task(removed.id)
+        .await
+        .expect("Failed to soft delete task");
+
+    let default_count = task_service
+        .count_tasks(TaskFilter {
+            project_id: Some(project.id),
+            ..Default::default()
+        })
+        .await
+        .expect("Failed to count tasks");
+    assert_eq!(default_count, 1, "Soft-deleted task should be excluded");
+
+    let with_deleted = task_service
+        .count_tasks(TaskFilter {
+            project_id: Some(project.id),
+            include_deleted: true,
+            ..Default::default()
+        })
+        .await
+        .expect("Failed to count tasks");
+    assert_eq!(with_deleted, 2, "Soft-deleted task should be included");
+
+    let listed = task_service
+        .list_tasks(TaskFilter {
+            project_id: Some(project.id),
+            ..Default::default()
+        })
+        .await
+        .expect("Failed to list tasks");
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed[0].id, kept.id);
+
+    println!("✅ Soft-delete count test passed");
+}
+
+/// Test that soft-deleted projects are only counted when requested
+#[tokio::test]
+async fn test_count_excludes_soft_deleted_projects() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+
+    let project_service = project_service(&pool);
+
+    project_service
+        .create_project(create_test_project("Counted Kept"))
+        .await
+        .expect("Failed to create project");
+    let removed = project_service
+        .create_project(create_test_project("Counted Removed"))
+        .await
+        .expect("Failed to create project");
+    project_service
+        .delete_project(removed.id)
+        .await
+        .expect("Failed to soft delete project");
+
+    let filter = ProjectFilter {
+        search: Some("Counted".to_string()),
+        ..Default::default()
+    };
+    let default_count = project_service
+        .count_projects(filter.clone())
+        .await
+        .expect("Failed to count projects");
+    assert_eq!(default_count, 1, "Soft-deleted project should be excluded");
+
+    let with_deleted = project_service
+        .count_projects(ProjectFilter {
+            include_deleted: true,
+            ..filter
+        })
+        .await
+        .expect("Failed to count projects");
+    assert_eq!(with_deleted, 2, "Soft-deleted project should be included");
+}
+
+/// Test that soft-deleted resources are left out of the resource count
+#[tokio::test]
+async fn test_count_excludes_soft_deleted_resources() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+
+    let resource_service = resource_service(&pool);
+
+    resource_service
+        .create_resource(create_test_resource("counted-kept"))
+        .await
+        .expect("Failed to create resource");
+    let removed = resource_service
+        .create_resource(create_test_resource("counted-removed"))
+        .await
+        .expect("Failed to create resource");
+
+    let filter = ResourceFilter {
+        name_contains: Some("counted".to_string()),
+        ..Default::default()
+    };
+    let before = resource_service
+        .count_resources(filter.clone())
+        .await
+        .expect("Failed to count resources");
+    assert_eq!(before, 2);
+
+    resource_service
+        .delete_resource(removed.id)
+        .await
+        .expect("Failed to soft delete resource");
+
+    let after = resource_service
+        .count_resources(filter.clone())
+        .await
+        .expect("Failed to count resources");
+    assert_eq!(after, 1, "Soft-deleted resource should be excluded");
+    let listed = resource_service
+        .list_resources(filter)
+        .await
+        .expect("Failed to list resources");
+    assert_eq!(listed.len() as i64, after);
+}
+
+/// Test that the onboarding hint only appears when no projects exist at all
+#[tokio::test]
+async fn test_empty_project_list_onboarding_hint() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+
+    let project_service = project_service(&pool);
+    let create_command = "deverp project create --name <NAME>";
+
+    // Fresh database: the unfiltered list is empty and nothing exists yet
+    let projects = project_service
+        .list_projects(ProjectFilter::default())
+        .await
+        .expect("Failed to list projects");
+    assert!(projects.is_empty());
+    let total = project_service
+        .count_projects(ProjectFilter::default())
+        .await
+        .expect("Failed to count projects");
+    let message = empty_list_message("projects", total, create_command);
+    assert!(message.contains("deverp project create"), "{}", message);
+
+    // A filter that matches nothing on a non-empty database gets no hint
+    project_service
+        .create_project(create_test_project("Onboarded Project"))
+        .await
+        .expect("Failed to create project");
+    let filtered = project_service
+        .list_projects(ProjectFilter {
+            status: Some(ProjectStatus::Archived),
+            ..Default::default()
+        })
+        .await
+        .expect("Failed to list projects");
+    assert!(filtered.is_empty());
+    let total = project_service
+        .count_projects(ProjectFilter::default())
+        .await
+        .expect("Failed to count projects");
+    let message = empty_list_message("projects", total, create_command);
+    assert!(!message.contains("deverp project create"), "{}", message);
+
+    println!("✅ Onboarding hint test passed");
+}
+
+/// Test that the changed-today window follows the configured timezone
+#[tokio::test]
+async fn test_list_tasks_changed_today() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+
+    let project_service = project_service(&pool);
+    let task_service = task_service(&pool);
+
+    let project = project_service
+        .create_project(create_test_project("Standup Project"))
+        .await
+        .expect("Failed to create project");
+
+    let today_task = task_service
+        .create_task(create_test_task(project.id, "Touched Today"))
+        .await
+        .expect("Failed to create task");
+    let yesterday_task = task_service
+        .create_task(create_test_task(project.id, "Touched Yesterday"))
+        .await
+        .expect("Failed to create task");
+
+    let (start, end) = local_day_bounds(chrono_tz::Pacific::Auckland, Utc::now());
+
+    // Backdate one task to the last hour of the previous local day; the
+    // updated_at trigger would otherwise overwrite the value
+    let mut tx = pool.begin().await.expect("Failed to begin transaction");
+    sqlx::query("ALTER TABLE tasks DISABLE TRIGGER update_tasks_updated_at")
+        .execute(&mut *tx)
+        .await
+        .expect("Failed to disable trigger");
+    sqlx::query("UPDATE tasks SET updated_at = $1 WHERE id = $2")
+        .bind(start - chrono::Duration::hours(1))
+        .bind(yesterday_task.id)
+        .execute(&mut *tx)
+        .await
+        .expect("Failed to backdate task");
+    sqlx::query("ALTER TABLE tasks ENABLE TRIGGER update_tasks_updated_at")
+        .execute(&mut *tx)
+        .await
+        .expect("Failed to enable trigger");
+    tx.commit().await.expect("Failed to commit");
+
+    let changed = task_service
+        .list_tasks(TaskFilter {
+            project_id: Some(project.id),
+            updated_after: Some(start),
+            updated_before: Some(end),
+            ..Default::default()
+        })
+        .await
+        .expect("Failed to list tasks");
+    let ids: Vec<i64> = changed.iter().map(|t| t.id).collect();
+    assert_eq!(ids, vec![today_task.id]);
+
+    let count = task_service
+        .count_tasks(TaskFilter {
+            project_id: Some(project.id),
+            updated_after: Some(start),
+            updated_before: Some(end),
+            ..Default::default()
+        })
+        .await
+        .expect("Failed to count tasks");
+    assert_eq!(count, 1);
+
+    println!("✅ Changed-today filter test passed");
+}
+
+/// Test that bulk project health covers every active project, worst first
+#[tokio::test]
+async fn test_project_health_for_all_active_projects() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+
+    let project_repo = Arc::new(PostgresProjectRepository::new(pool.clone()));
+    let project_service = project_service(&pool);
+    let task_service = task_service(&pool);
+    let report_service = ReportService::new(
+        project_repo,
+        Arc::new(PostgresTaskRepository::new(pool.clone())),
+        Arc::new(PostgresResourceRepository::new(pool.clone())),
+        Arc::new(PostgresTimelineRepository::new(pool.clone())),
+        Arc::new(PostgresMilestoneRepository::new(pool.clone())),
+    );
+
+    let mut ids = Vec::new();
+    for name in ["Healthy Service", "Troubled Service", "Planned Service"] {
+        let mut input = create_test_project(name);
+        if name != "Planned Service" {
+            input.status = Some(ProjectStatus::Active);
+        }
+        let project = project_service
+            .create_project(input)
+            .await
+            .expect("Failed to create project");
+        ids.push(project.id);
+    }
+
+    let today = NaiveDate::from_ymd_opt(2025, 7, 2).unwrap();
+    let mut overdue = create_test_task(ids[1], "Missed Deadline");
+    overdue.due_date = Some(
+        NaiveDate::from_ymd_opt(2025, 6, 1)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap()
+            .and_utc(),
+    );
+    task_service
+        .create_task(overdue)
+        .await
+        .expect("Failed to create task");
+    let mut blocked = create_test_task(ids[1], "Waiting On Vendor");
+    blocked.status = Some(TaskStatus::Blocked);
+    blocked.blocked_reason = Some("Vendor outage".to_string());
+    task_service
+        .create_task(blocked)
+        .await
+        .expect("Failed to create task");
+
+    let health = report_service
+        .generate_project_health(today)
+        .await
+        .expect("Failed to compute project health");
+
+    // One entry per active project; the planning project is left out
+    let names: Vec<&str> = health.iter().map(|h| h.project_name.as_str()).collect();
+    assert_eq!(names, vec!["Troubled Service", "Healthy Service"]);
+    assert!(health[0].score < health[1].score);
+    assert_eq!(health[0].overdue_tasks, 1);
+    assert_eq!(health[0].blocked_tasks, 1);
+    assert_eq!(health[1].overdue_tasks, 0);
+    assert!(health.iter().all(|h| (0..=100).contains(&h.score)));
+
+    println!("✅ Project health test passed");
+}
+
+/// Test that the status report JSON carries the requested number of trend points
+#[tokio::test]
+async fn test_status_report_with_trend() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+
+    let project_repo = Arc::new(PostgresProjectRepository::new(pool.clone()));
+    let report_service = ReportService::new(
+        project_repo.clone(),
+        Arc::new(PostgresTaskRepository::new(pool.clone())),
+        Arc::new(PostgresResourceRepository::new(pool.clone())),
+        Arc::new(PostgresTimelineRepository::new(pool.clone())),
+        Arc::new(PostgresMilestoneRepository::new(pool.clone())),
+    );
+    let snapshot_service =
+        SnapshotService::new(Arc::new(PostgresSnapshotRepository::new(pool.clone())));
+
+    project_service(&pool)
+        .create_project(create_test_project("Trend Project"))
+        .await
+        .expect("Failed to create project");
+
+    for _ in 0..3 {
+        let report = report_service
+            .generate_project_status_report()
+            .await
+            .expect("Failed to generate report");
+        snapshot_service
+            .capture(&report)
+            .await
+            .expect("Failed to capture snapshot");
+    }
+
+    let report = report_service
+        .generate_project_status_report()
+        .await
+        .expect("Failed to generate report");
+    let with_trend = snapshot_service
+        .with_trend(report, 2)
+        .await
+        .expect("Failed to load trend");
+
+    let json = serde_json::to_value(&with_trend).expect("Failed to serialize report");
+    assert_eq!(json["total_projects"], 1);
+    let trend = json["trend"].as_array().expect("trend should be an array");
+    assert_eq!(trend.len(), 2);
+    assert_eq!(trend[0]["total_projects"], 1);
+    assert!(trend[0]["id"].as_i64() < trend[1]["id"].as_i64());
+
+    println!("✅ Status trend test passed");
+}
+
+/// Test per-entity export into a directory in both formats
+#[tokio::test]
+async fn test_export_to_output_dir() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+
+    let project = PostgresProjectRepository::new(pool.clone())
+        .create(create_test_project("Export Project"))
+        .await
+        .expect("Failed to create project");
+    PostgresTaskRepository::new(pool.clone())
+        .create(create_test_task(project.id, "Export Task"))
+        .await
+        .expect("Failed to create task");
+    PostgresResourceRepository::new(pool.clone())
+        .create(create_test_resource("export-lib"))
+        .await
+        .expect("Failed to create resource");
+    PostgresTimelineRepository::new(pool.clone())
+        .create(create_test_timeline(project.id, "Export Timeline"))
+        .await
+        .expect("Failed to create timeline");
+
+    let service = ExportService::new(Arc::new(PostgresExportRepository::new(pool.clone())));
+    let entities = ["projects", "tasks", "resources", "timelines", "milestones"];
+
+    let json_dir = std::env::temp_dir().join(format!("deverp_export_{}", uuid::Uuid::new_v4()));
+    let files = service
+        .export_to_dir(&json_dir, ExportFormat::Json)
+        .await
+        .expect("Failed to export JSON");
+    assert_eq!(files.len(), entities.len());
+
+    for entity in entities {
+        let path = json_dir.join(format!("{}.json", entity));
+        let content = std::fs::read_to_string(&path).expect("Export file should exist");
+        let records: Vec<serde_json::Value> =
+            serde_json::from_str(&content).expect("Export file should be valid JSON");
+        let expected = if entity == "milestones" { 0 } else { 1 };
+        assert_eq!(records.len(), expected, "{} record count", entity);
+    }
+    std::fs::remove_dir_all(&json_dir).ok();
+
+    let csv_dir = std::env::temp_dir().join(format!("deverp_export_{}", uuid::Uuid::new_v4()));
+    service
+        .export_to_dir(&csv_dir, ExportFormat::Csv)
+        .await
+        .expect("Failed to export CSV");
+
+    for entity in ["projects", "tasks", "resources", "timelines"] {
+        let path = csv_dir.join(format!("{}.csv", entity));
+        let content = std::fs::read_to_string(&path).expect("Export file should exist");
+        let lines: Vec<&str> = content.lines().collect();
+        assert_eq!(
+            lines.len(),
+            2,
+            "{} should have a header and one row",
+            entity
+        );
+        assert!(lines[0].split(',').any(|h| h == "id"));
+    }
+    assert!(csv_dir.join("milestones.csv").exists());
+    std::fs::remove_dir_all(&csv_dir).ok();
+
+    println!("✅ Directory export test passed");
+}
+
+/// Test the estimate/actual hygiene filters on task listing
+#[tokio::test]
+async fn test_list_tasks_missing_estimate() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+
+    let project = PostgresProjectRepository::new(pool.clone())
+        .create(create_test_project("Hygiene Project"))
+        .await
+        .expect("Failed to create project");
+
+    let task_repo = PostgresTaskRepository::new(pool.clone());
+    task_repo
+        .create(create_test_task(project.id, "Estimated Task"))
+        .await
+        .expect("Failed to create task");
+
+    let mut unestimated = create_test_task(project.id, "Unestimated Task");
+    unestimated.estimated_hours = None;
+    let unestimated = task_repo
+        .create(unestimated)
+        .await
+        .expect("Failed to create task");
+
+    let all = task_repo
+        .find_all(TaskFilter {
+            project_id: Some(project.id),
+            ..Default::default()
+        })
+        .await
+        .expect("Failed to list tasks");
+    assert_eq!(all.len(), 2);
+
+    let missing = task_repo
+        .find_all(TaskFilter {
+            project_id: Some(project.id),
+            estimate_missing: true,
+            ..Default::default()
+        })
+        .await
+        .expect("Failed to list tasks");
+    assert_eq!(missing.len(), 1);
+    assert_eq!(missing[0].id, unestimated.id);
+
+    // Neither task has logged hours yet
+    let no_actuals = task_repo
+        .count(TaskFilter {
+            project_id: Some(project.id),
+            actual_missing: true,
+            ..Default::default()
+        })
+        .await
+        .expect("Failed to count tasks");
+    assert_eq!(no_actuals, 2);
+
+    println!("✅ Missing estimate filter test passed");
+}
+
+/// Test database connection and migration
+#[tokio::test]
+async fn test_database_connection() {
+    let result = setup_test_database().await;
+    assert!(result.is_ok(), "Database connection should succeed");
+
+    let pool = result.unwrap();
+
+    // Verify we can execute a simple query
+    let row: (i32,) = sqlx::query_as("SELECT 1")
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to execute test query");
+
+    assert_eq!(row.0, 1);
+
+    println!("✅ Database connection test passed");
+}
+
+/// Task counts for a page of projects match each project's own tasks
+#[tokio::test]
+async fn test_project_list_with_task_counts() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+
+    let task_repo = Arc::new(PostgresTaskRepository::new(pool.clone()));
+    let project_service = project_service(&pool);
+
+    let busy = project_service
+        .create_project(create_test_project("Busy Project"))
+        .await
+        .expect("Failed to create project");
+    let idle = project_service
+        .create_project(create_test_project("Idle Project"))
+        .await
+        .expect("Failed to create project");
+
+    let mut task_ids = Vec::new();
+    for title in ["One", "Two", "Three"] {
+        let task = task_repo
+            .create(create_test_task(busy.id, title))
+            .await
+            .expect("Failed to create task");
+        task_ids.push(task.id);
+    }
+    sqlx::query("UPDATE tasks SET status = 'done' WHERE id = $1")
+        .bind(task_ids[0])
+        .execute(&pool)
+        .await
+        .expect("Failed to complete task");
+    task_repo
+        .soft_delete(task_ids[2])
+        .await
+        .expect("Failed to delete task");
+
+    let projects = project_service
+        .list_projects(ProjectFilter::default())
+        .await
+        .expect("Failed to list projects");
+    let counted = project_service
+        .with_task_counts(projects)
+        .await
+        .expect("Failed to count tasks");
+
+    let counts_of = |id: i64| {
+        counted
+            .iter()
+            .find(|p| p.project.id == id)
+            .map(|p| (p.total_tasks, p.completed_tasks))
+    };
+    assert_eq!(counts_of(busy.id), Some((2, 1)));
+    assert_eq!(counts_of(idle.id), Some((0, 0)));
+
+    // Counts are flattened next to the project fields in JSON output
+    let json = serde_json::to_value(&counted[0]).unwrap();
+    assert!(json.get("name").is_some());
+    assert!(json.get("total_tasks").is_some());
+}
+
+/// Invalid tasks in an import are reported while the rest are created
+#[tokio::test]
+async fn test_import_tasks_reports_invalid_entries() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+
+    let project_service = project_service(&pool);
+    let task_service = task_service(&pool);
+
+    let project = project_service
+        .create_project(create_test_project("Import Project"))
+        .await
+        .expect("Failed to create project");
+
+    let mut orphan = create_test_task(0, "Orphan");
+    orphan.parent_task_id = Some(999_999);
+    let tasks = vec![
+        create_test_task(0, "First"),
+        create_test_task(0, "   "),
+        orphan,
+        create_test_task(0, "Last"),
+    ];
+
+    let summary = task_service
+        .import_tasks(project.id, tasks)
+        .await
+        .expect("Import should not abort on invalid tasks");
+
+    let created: Vec<&str> = summary.created.iter().map(|t| t.title.as_str()).collect();
+    assert_eq!(created, vec!["First", "Last"]);
+    assert!(summary.created.iter().all(|t| t.project_id == project.id));
+
+    let failed: Vec<usize> = summary.failed.iter().map(|f| f.index).collect();
+    assert_eq!(failed, vec![1, 2]);
+    assert!(summary.failed[0].error.contains("title cannot be empty"));
+}
+
+/// Creating or importing a blocked task needs a reason, as blocking one later does
+#[tokio::test]
+async fn test_blocked_task_creation_requires_reason() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+
+    let project_service = project_service(&pool);
+    let task_service = task_service(&pool);
+
+    let project = project_service
+        .create_project(create_test_project("Blocked Project"))
+        .await
+        .expect("Failed to create project");
+
+    let mut unexplained = create_test_task(project.id, "Unexplained");
+    unexplained.status = Some(TaskStatus::Blocked);
+    let result = task_service.create_task(unexplained.clone()).await;
+    assert!(matches!(result, Err(DevErpError::Validation(_))));
+
+    let mut explained = unexplained.clone();
+    explained.title = "Explained".to_string();
+    explained.blocked_reason = Some("Waiting on API keys".to_string());
+    let task = task_service
+        .create_task(explained)
+        .await
+        .expect("Failed to create blocked task");
+    assert_eq!(task.status, TaskStatus::Blocked);
+    assert_eq!(task.blocked_reason.as_deref(), Some("Waiting on API keys"));
+
+    let summary = task_service
+        .import_tasks(project.id, vec![unexplained])
+        .await
+        .expect("Import should not abort on invalid tasks");
+    assert!(summary.created.is_empty());
+    assert!(summary.failed[0].error.contains("reason is required"));
+}
+
+/// Task stats read the time in status from the recorded status change
+#[tokio::test]
+async fn test_task_stats_after_status_change() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+
+    let project_service = project_service(&pool);
+    let task_service = task_service(&pool);
+
+    let project = project_service
+        .create_project(create_test_project("Stats Project"))
+        .await
+        .expect("Failed to create project");
+    let task = task_service
+        .create_task(create_test_task(project.id, "Measured"))
+        .await
+        .expect("Failed to create task");
+
+    // Backdate creation and record a status change two days ago
+    sqlx::query(
+        "UPDATE tasks SET created_at = NOW() - INTERVAL '10 days', \
+         due_date = NOW() - INTERVAL '1 day', actual_hours = 12, status = 'in_progress' \
+         WHERE id = $1",
+    )
+    .bind(task.id)
+    .execute(&pool)
+    .await
+    .expect("Failed to update task");
+    sqlx::query(
+        "UPDATE audit_logs SET created_at = NOW() - INTERVAL '2 days' \
+         WHERE entity_type = 'task' AND entity_id = $1",
+    )
+    .bind(task.id)
+    .execute(&pool)
+    .await
+    .expect("Failed to backdate status change");
+
+    let stats = task_service
+        .task_stats(task.id, Utc::now())
+        .await
+        .expect("Failed to compute stats");
+
+    assert_eq!(stats.status, TaskStatus::InProgress);
+    assert_eq!(stats.age_days, 10);
+    assert_eq!(stats.days_in_status, Some(2));
+    assert_eq!(stats.estimate_accuracy, Some(1.5));
+    assert!(stats.overdue);
+}
+
+/// Logged work is kept per entry and adds up in actual_hours
+#[tokio::test]
+async fn test_log_work_increments_actual_hours() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+
+    let project_service = project_service(&pool);
+    let task_service = task_service(&pool);
+
+    let project = project_service
+        .create_project(create_test_project("Work Log Project"))
+        .await
+        .expect("Failed to create project");
+    let task = task_service
+        .create_task(create_test_task(project.id, "Timed"))
+        .await
+        .expect("Failed to create task");
+
+    task_service
+        .log_work(task.id, 2.5, "alice".to_string(), None)
+        .await
+        .expect("Failed to log work");
+    task_service
+        .log_work(task.id, 1.0, "bob".to_string(), Some("review".to_string()))
+        .await
+        .expect("Failed to log work");
+
+    let task = task_service
+        .get_task_by_id(task.id)
+        .await
+        .expect("Failed to get task");
+    assert_eq!(task.actual_hours, Some(3.5));
+
+    let logs = task_service
+        .recent_work_logs(task.id, 5)
+        .await
+        .expect("Failed to list work logs");
+    let authors: Vec<&str> = logs.iter().map(|l| l.logged_by.as_str()).collect();
+    assert_eq!(authors, vec!["bob", "alice"]);
+
+    let result = task_service
+        .log_work(task.id, 0.0, "alice".to_string(), None)
+        .await;
+    assert!(matches!(result, Err(DevErpError::Validation(_))));
+
+    let result = task_service
+        .log_work(999_999, 1.0, "alice".to_string(), None)
+        .await;
+    assert!(matches!(result, Err(DevErpError::NotFound(_))));
+}
+
+/// A subtree listing reaches every level below the root
+#[tokio::test]
+async fn test_list_task_subtree() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+
+    let project_service = project_service(&pool);
+    let task_service = task_service(&pool);
+
+    let project = project_service
+        .create_project(create_test_project("Subtree Project"))
+        .await
+        .expect("Failed to create project");
+
+    let root = task_service
+        .create_task(create_test_task(project.id, "Root"))
+        .await
+        .expect("Failed to create root task");
+    let child = task_service
+        .create_task(CreateTask {
+            parent_task_id: Some(root.id),
+            ..create_test_task(project.id, "Child")
+        })
+        .await
+        .expect("Failed to create child task");
+    let grandchild = task_service
+        .create_task(CreateTask {
+            parent_task_id: Some(child.id),
+            status: Some(TaskStatus::Done),
+            ..create_test_task(project.id, "Grandchild")
+        })
+        .await
+        .expect("Failed to create grandchild task");
+    task_service
+        .create_task(create_test_task(project.id, "Unrelated"))
+        .await
+        .expect("Failed to create unrelated task");
+
+    let subtree = task_service
+        .list_tasks(TaskFilter {
+            subtree_of: Some(root.id),
+            ..Default::default()
+        })
+        .await
+        .expect("Failed to list subtree");
+    let mut ids: Vec<i64> = subtree.iter().map(|t| t.id).collect();
+    ids.sort();
+    assert_eq!(ids, vec![root.id, child.id, grandchild.id]);
+
+    // Other filters narrow the subtree rather than replacing it
+    let done = task_service
+        .list_tasks(TaskFilter {
+            subtree_of: Some(child.id),
+            status: Some(TaskStatus::Done),
+            ..Default::default()
+        })
+        .await
+        .expect("Failed to list filtered subtree");
+    let ids: Vec<i64> = done.iter().map(|t| t.id).collect();
+    assert_eq!(ids, vec![grandchild.id]);
+}
+
+/// Extra assignees sit alongside the primary one kept in assigned_to
+#[tokio::test]
+async fn test_task_multiple_assignees() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+
+    let project_service = project_service(&pool);
+    let task_service = task_service(&pool);
+
+    let project = project_service
+        .create_project(create_test_project("Assignee Project"))
+        .await
+        .expect("Failed to create project");
+    // Created with assigned_to = "test_user" as the primary assignee
+    let task = task_service
+        .create_task(create_test_task(project.id, "Pairing"))
+        .await
+        .expect("Failed to create task");
+
+    task_service
+        .assign_task(task.id, "bob")
+        .await
+        .expect("Failed to assign bob");
+    task_service
+        .assign_task(task.id, "carol")
+        .await
+        .expect("Failed to assign carol");
+    let assignees = task_service
+        .list_assignees(task.id)
+        .await
+        .expect("Failed to list assignees");
+    assert_eq!(assignees, vec!["test_user", "bob", "carol"]);
+
+    let result = task_service.assign_task(task.id, "bob").await;
+    assert!(matches!(result, Err(DevErpError::Conflict(_))));
+
+    // Removing the primary hands the role to the earliest remaining assignee
+    task_service
+        .unassign_task(task.id, "test_user")
+        .await
+        .expect("Failed to unassign primary");
+    let task = task_service
+        .get_task_by_id(task.id)
+        .await
+        .expect("Failed to get task");
+    assert_eq!(task.assigned_to.as_deref(), Some("bob"));
+    let assignees = task_service
+        .list_assignees(task.id)
+        .await
+        .expect("Failed to list assignees");
+    assert_eq!(assignees, vec!["bob", "carol"]);
+
+    let result = task_service.unassign_task(task.id, "dave").await;
+    assert!(matches!(result, Err(DevErpError::NotFound(_))));
+}
+
+/// Test milestone sequence numbers and reordering within a timeline
+#[tokio::test]
+async fn test_reorder_milestones_within_timeline() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+
+    let project_service = project_service(&pool);
+    let timeline_service = timeline_service(&pool);
+
+    let project = project_service
+        .create_project(create_test_project("Milestone Order"))
+        .await
+        .expect("Failed to create project");
+    let timeline = timeline_service
+        .create_timeline(create_test_timeline(project.id, "Release"))
+        .await
+        .expect("Failed to create timeline");
+
+    // All three land on the same day, so only the sequence orders them
+    let mut ids = Vec::new();
+    for name in ["Code freeze", "QA sign-off", "Ship"] {
+        let milestone = timeline_service
+            .create_milestone(CreateMilestone {
+                timeline_id: timeline.id,
+                project_id: project.id,
+                name: name.to_string(),
+                description: None,
+                target_date: NaiveDate::from_ymd_opt(2025, 6, 30).unwrap(),
+                status: None,
+                completion_percentage: None,
+                metadata: None,
+                task_ids: Vec::new(),
+            })
+            .await
+            .expect("Failed to create milestone");
+        ids.push(milestone.id);
+    }
+
+    let milestones = timeline_service
+        .get_milestones_by_timeline(timeline.id)
+        .await
+        .expect("Failed to list milestones");
+    let sequences: Vec<i32> = milestones.iter().map(|m| m.sequence).collect();
+    assert_eq!(sequences, vec![1, 2, 3]);
+
+    let reordered = timeline_service
+        .reorder_milestone(ids[2], 1)
+        .await
+        .expect("Failed to reorder milestone");
+    let names: Vec<&str> = reordered.iter().map(|m| m.name.as_str()).collect();
+    assert_eq!(names, vec!["Ship", "Code freeze", "QA sign-off"]);
+    let sequences: Vec<i32> = reordered.iter().map(|m| m.sequence).collect();
+    assert_eq!(sequences, vec![1, 2, 3]);
+
+    // The repository rejects an order that leaves a milestone out
+    let result = PostgresMilestoneRepository::new(pool.clone())
+        .reorder(timeline.id, &ids[..2])
+        .await;
+    assert!(matches!(result, Err(DevErpError::Validation(_))));
+}
+
+/// Shifting a timeline moves its dates and every milestone's dates together
+#[tokio::test]
+async fn test_shift_timeline_moves_milestones() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+
+    let project_service = project_service(&pool);
+    let timeline_service =
+        timeline_service(&pool).with_date_floor(NaiveDate::from_ymd_opt(2024, 12, 1));
+
+    let project = project_service
+        .create_project(create_test_project("Slipping Sprint"))
+        .await
+        .expect("Failed to create project");
+    let timeline = timeline_service
+        .create_timeline(create_test_timeline(project.id, "Sprint 12"))
+        .await
+        .expect("Failed to create timeline");
+
+    let mut milestones = Vec::new();
+    for (name, day) in [("Demo", 10), ("Retro", 20)] {
+        milestones.push(
+            timeline_service
+                .create_milestone(CreateMilestone {
+                    timeline_id: timeline.id,
+                    project_id: project.id,
+                    name: name.to_string(),
+                    description: None,
+                    target_date: NaiveDate::from_ymd_opt(2025, 3, day).unwrap(),
+                    status: None,
+                    completion_percentage: None,
+                    metadata: None,
+                    task_ids: Vec::new(),
+                })
+                .await
+                .expect("Failed to create milestone"),
+        );
+    }
+    timeline_service
+        .update_milestone(
+            UpdateMilestone {
+                id: milestones[0].id,
+                name: None,
+                description: None,
+                target_date: None,
+                actual_date: NaiveDate::from_ymd_opt(2025, 3, 11),
+                status: None,
+                completion_percentage: None,
+                metadata: None,
+            },
+            CompletionCheck::Enforce,
+        )
+        .await
+        .expect("Failed to record actual date");
+
+    let shift = timeline_service
+        .shift_timeline(timeline.id, 7)
+        .await
+        .expect("Failed to shift timeline");
+    assert_eq!(shift.milestones_moved, 2);
+    assert_eq!(
+        shift.timeline.start_date,
+        NaiveDate::from_ymd_opt(2025, 1, 8).unwrap()
+    );
+    assert_eq!(
+        shift.timeline.end_date,
+        NaiveDate::from_ymd_opt(2026, 1, 7).unwrap()
+    );
+
+    let moved = timeline_service
+        .get_milestones_by_timeline(timeline.id)
+        .await
+        .expect("Failed to list milestones");
+    assert_eq!(
+        moved[0].target_date,
+        NaiveDate::from_ymd_opt(2025, 3, 17).unwrap()
+    );
+    assert_eq!(moved[0].actual_date, NaiveDate::from_ymd_opt(2025, 3, 18));
+    assert_eq!(
+        moved[1].target_date,
+        NaiveDate::from_ymd_opt(2025, 3, 27).unwrap()
+    );
+    assert_eq!(moved[1].actual_date, None);
+
+    // Pulling in past the floor is rejected and leaves everything in place
+    let result = timeline_service.shift_timeline(timeline.id, -60).await;
+    assert!(matches!(result, Err(DevErpError::Validation(_))));
+    let unchanged = timeline_service
+        .get_timeline(timeline.id)
+        .await
+        .expect("Failed to get timeline");
+    assert_eq!(unchanged.start_date, shift.timeline.start_date);
+}
+
+/// Paging milestones that share a target date neither skips nor repeats any
+#[tokio::test]
+async fn test_milestone_pages_are_stable_on_equal_dates() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+
+    let project_service = project_service(&pool);
+    let timeline_service = timeline_service(&pool);
+
+    let project = project_service
+        .create_project(create_test_project("Release Train"))
+        .await
+        .expect("Failed to create project");
+    let timeline = timeline_service
+        .create_timeline(create_test_timeline(project.id, "Q2"))
+        .await
+        .expect("Failed to create timeline");
+
+    let mut ids = Vec::new();
+    for name in ["Freeze", "Branch", "Tag", "Ship"] {
+        let milestone = timeline_service
+            .create_milestone(CreateMilestone {
+                timeline_id: timeline.id,
+                project_id: project.id,
+                name: name.to_string(),
+                description: None,
+                target_date: NaiveDate::from_ymd_opt(2025, 6, 30).unwrap(),
+                status: None,
+                completion_percentage: None,
+                metadata: None,
+                task_ids: Vec::new(),
+            })
+            .await
+            .expect("Failed to create milestone");
+        ids.push(milestone.id);
+    }
+
+    let repo = PostgresMilestoneRepository::new(pool.clone());
+    let mut paged = Vec::new();
+    for offset in 0..ids.len() as i64 {
+        let page = repo
+            .find_all(MilestoneFilter {
+                project_id: Some(project.id),
+                limit: Some(1),
+                offset: Some(offset),
+                ..Default::default()
+            })
+            .await
+            .expect("Failed to list milestones");
+        paged.extend(page.into_iter().map(|m| m.id));
+    }
+    assert_eq!(paged, ids);
+}
+
+/// A milestone's linked tasks gate its completion unless forced
+#[tokio::test]
+async fn test_milestone_tasks_gate_completion() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+
+    let project_service = project_service(&pool);
+    let task_service = task_service(&pool);
+    let timeline_service = timeline_service(&pool);
+
+    let project = project_service
+        .create_project(create_test_project("Milestone Tasks"))
+        .await
+        .expect("Failed to create project");
+    let timeline = timeline_service
+        .create_timeline(create_test_timeline(project.id, "Release"))
+        .await
+        .expect("Failed to create timeline");
+
+    let mut task_ids = Vec::new();
+    for title in ["Write docs", "Drop legacy API", "Unrelated"] {
+        let task = task_service
+            .create_task(create_test_task(project.id, title))
+            .await
+            .expect("Failed to create task");
+        task_ids.push(task.id);
+    }
+
+    let milestone = timeline_service
+        .create_milestone(CreateMilestone {
+            timeline_id: timeline.id,
+            project_id: project.id,
+            name: "GA".to_string(),
+            description: None,
+            target_date: NaiveDate::from_ymd_opt(2025, 6, 30).unwrap(),
+            status: None,
+            completion_percentage: None,
+            metadata: None,
+            task_ids: task_ids[..2].to_vec(),
+        })
+        .await
+        .expect("Failed to create milestone");
+
+    let linked = timeline_service
+        .milestone_tasks(milestone.id)
+        .await
+        .expect("Failed to list milestone tasks");
+    assert_eq!(linked.len(), 2);
+    assert!(linked.iter().all(|t| t.milestone_id == Some(milestone.id)));
+    let filtered = task_service
+        .count_tasks(TaskFilter {
+            milestone_id: Some(milestone.id),
+            ..Default::default()
+        })
+        .await
+        .expect("Failed to count tasks");
+    assert_eq!(filtered, 2);
+
+    let complete = UpdateMilestone {
+        id: milestone.id,
+        name: None,
+        description: None,
+        target_date: None,
+        actual_date: None,
+        status: Some(MilestoneStatus::Completed),
+        completion_percentage: Some(100),
+        metadata: None,
+    };
+    let result = timeline_service
+        .update_milestone(complete.clone(), CompletionCheck::Enforce)
+        .await;
+    assert!(matches!(result, Err(DevErpError::Validation(msg)) if !msg.contains("--force")));
+
+    // The CLI is what points the user at --force
+    let ctx = AppContext::new(pool.clone()).with_sink(Arc::new(BufferSink::new()));
+    let id = milestone.id.to_string();
+    let cli = Cli::try_parse_from(["deverp", "timeline", "complete-milestone", &id])
+        .expect("Failed to parse arguments");
+    let Commands::Timeline(command) = cli.command else {
+        panic!("Expected a timeline command");
+    };
+    let result = timeline::handle(&ctx, command, cli.format).await;
+    assert!(matches!(result, Err(DevErpError::Validation(msg)) if msg.contains("--force")));
+
+    // Cancelled tasks drop out of the total
+    task_service
+        .change_task_status(task_ids[0], TaskStatus::InProgress, None)
+        .await
+        .expect("Failed to start task");
+    task_service
+        .change_task_status(task_ids[0], TaskStatus::Done, None)
+        .await
+        .expect("Failed to complete task");
+    task_service
+        .change_task_status(task_ids[1], TaskStatus::Cancelled, None)
+        .await
+        .expect("Failed to cancel task");
+    let progress = timeline_service
+        .milestone_task_progress(milestone.id)
+        .await
+        .expect("Failed to count milestone tasks");
+    assert_eq!((progress.done_tasks, progress.total_tasks), (1, 1));
+
+    let completed = timeline_service
+        .update_milestone(complete, CompletionCheck::Enforce)
+        .await
+        .expect("Failed to complete milestone");
+    assert_eq!(completed.status, MilestoneStatus::Completed);
+}
+
+/// Test moving a task, with a parent and a subtask, to another project
+#[tokio::test]
+async fn test_move_task_to_another_project() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+
+    let project_service = project_service(&pool);
+    let task_service = task_service(&pool);
+
+    let source = project_service
+        .create_project(create_test_project("Filed Here"))
+        .await
+        .expect("Failed to create project");
+    let target = project_service
+        .create_project(create_test_project("Belongs Here"))
+        .await
+        .expect("Failed to create project");
+
+    let parent = task_service
+        .create_task(create_test_task(source.id, "Epic"))
+        .await
+        .expect("Failed to create task");
+    let mut input = create_test_task(source.id, "Misfiled task");
+    input.parent_task_id = Some(parent.id);
+    let task = task_service
+        .create_task(input)
+        .await
+        .expect("Failed to create task");
+    let mut input = create_test_task(source.id, "Subtask");
+    input.parent_task_id = Some(task.id);
+    let subtask = task_service
+        .create_task(input)
+        .await
+        .expect("Failed to create task");
+
+    let result = task_service
+        .move_task(task.id, target.id)
+        .await
+        .expect("Failed to move task");
+    assert_eq!(result.task.project_id, target.id);
+    // The parent stays behind, so the link is cleared
+    assert_eq!(result.task.parent_task_id, None);
+    assert_eq!(result.warnings.len(), 1);
+    assert!(result.warnings[0].contains(&format!("#{}", subtask.id)));
+
+    let subtask = task_service
+        .get_task_by_id(subtask.id)
+        .await
+        .expect("Failed to get subtask");
+    assert_eq!(subtask.project_id, source.id);
+
+    let result = task_service.move_task(task.id, target.id).await;
+    assert!(matches!(result, Err(DevErpError::Validation(_))));
+    let result = task_service.move_task(task.id, i64::MAX).await;
+    assert!(matches!(result, Err(DevErpError::NotFound(_))));
+}
+
+/// Test that blocking a task needs a reason and unblocking clears it
+#[tokio::test]
+async fn test_blocked_reason_lifecycle() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+
+    let project_service = project_service(&pool);
+    let task_service = task_service(&pool);
+
+    let project = project_service
+        .create_project(create_test_project("Blocked Reasons"))
+        .await
+        .expect("Failed to create project");
+    let task = task_service
+        .create_task(create_test_task(project.id, "Waiting on vendor"))
+        .await
+        .expect("Failed to create task");
+
+    let result = task_service
+        .change_task_status(task.id, TaskStatus::Blocked, None)
+        .await;
+    assert!(matches!(result, Err(DevErpError::Validation(_))));
+
+    let blocked = task_service
+        .change_task_status(
+            task.id,
+            TaskStatus::Blocked,
+            Some("Waiting for API keys".to_string()),
+        )
+        .await
+        .expect("Failed to block task");
+    assert_eq!(blocked.status, TaskStatus::Blocked);
+    assert_eq!(
+        blocked.blocked_reason.as_deref(),
+        Some("Waiting for API keys")
+    );
+
+    let resumed = task_service
+        .change_task_status(task.id, TaskStatus::InProgress, None)
+        .await
+        .expect("Failed to resume task");
+    assert_eq!(resumed.blocked_reason, None);
+}
+
+/// config check-schema passes on a migrated database
+#[tokio::test]
+async fn test_config_check_schema_on_migrated_database() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+
+    let buffer = Arc::new(BufferSink::new());
+    let ctx = AppContext::new(pool).with_sink(buffer.clone());
+    let cli = Cli::try_parse_from(["deverp", "config", "check-schema"])
+        .expect("Failed to parse arguments");
+    let Commands::Config(command) = cli.command else {
+        panic!("Expected a config command");
+    };
+
+    config::handle(&ctx, command, cli.format)
+        .await
+        .expect("Schema check should pass on a migrated database");
+
+    assert!(buffer
+        .contents()
+        .contains("All expected tables are present"));
+}
+
+/// Subtask progress rolls up through nested subtasks and can complete the parent
+#[tokio::test]
+async fn test_rollup_progress_completes_parent() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+
+    let project_service = project_service(&pool);
+    let task_service = task_service(&pool);
+
+    let project = project_service
+        .create_project(create_test_project("Rollup Project"))
+        .await
+        .expect("Failed to create project");
+
+    let create_under = |parent: Option<i64>, title: &str| {
+        let mut input = create_test_task(project.id, title);
+        input.parent_task_id = parent;
+        input
+    };
+    let epic = task_service
+        .create_task(create_under(None, "Epic"))
+        .await
+        .expect("Failed to create parent");
+    let api = task_service
+        .create_task(create_under(Some(epic.id), "API"))
+        .await
+        .expect("Failed to create subtask");
+    let ui = task_service
+        .create_task(create_under(Some(epic.id), "UI"))
+        .await
+        .expect("Failed to create subtask");
+    let ui_forms = task_service
+        .create_task(create_under(Some(ui.id), "UI forms"))
+        .await
+        .expect("Failed to create nested subtask");
+
+    let finish = |id: i64| {
+        let service = &task_service;
+        async move {
+            service
+                .change_task_status(id, TaskStatus::InProgress, None)
+                .await
+                .expect("Failed to start task");
+            service
+                .change_task_status(id, TaskStatus::Done, None)
+                .await
+                .expect("Failed to finish task");
+        }
+    };
+
+    finish(api.id).await;
+    finish(ui_forms.id).await;
+    // UI itself is still todo, but its only subtask is done
+    let rollup = task_service
+        .rollup_progress(epic.id, true)
+        .await
+        .expect("Failed to roll up");
+    assert_eq!((rollup.done_subtasks, rollup.total_subtasks), (1, 2));
+    assert_eq!(rollup.completion_pct, 100.0);
+    assert!(!rollup.marked_done);
+
+    task_service
+        .change_task_status(epic.id, TaskStatus::InProgress, None)
+        .await
+        .expect("Failed to start parent");
+    finish(ui.id).await;
+    let rollup = task_service
+        .rollup_progress(epic.id, true)
+        .await
+        .expect("Failed to roll up");
+    assert_eq!((rollup.done_subtasks, rollup.total_subtasks), (2, 2));
+    assert!(rollup.marked_done);
+
+    let epic = task_service
+        .get_task_by_id(epic.id)
+        .await
+        .expect("Parent missing");
+    assert_eq!(epic.status, TaskStatus::Done);
+}
+
+/// Sorting by priority uses its weight rather than the stored text
+#[tokio::test]
+async fn test_list_tasks_sorted_by_priority() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+
+    let project_service = project_service(&pool);
+    let task_service = task_service(&pool);
+
+    let project = project_service
+        .create_project(create_test_project("Priority Sort Project"))
+        .await
+        .expect("Failed to create project");
+
+    for (title, priority) in [
+        ("Medium", TaskPriority::Medium),
+        ("Critical", TaskPriority::Critical),
+        ("Low", TaskPriority::Low),
+        ("High", TaskPriority::High),
+    ] {
+        let mut input = create_test_task(project.id, title);
+        input.priority = Some(priority);
+        task_service
+            .create_task(input)
+            .await
+            .expect("Failed to create task");
+    }
+
+    let titles = |sort_desc: bool| {
+        let service = &task_service;
+        async move {
+            service
+                .list_tasks(TaskFilter {
+                    project_id: Some(project.id),
+                    sort_by: Some(TaskSortField::Priority),
+                    sort_desc,
+                    ..Default::default()
+                })
+                .await
+                .expect("Failed to list tasks")
+                .into_iter()
+                .map(|t| t.title)
+                .collect::<Vec<_>>()
+        }
+    };
+
+    assert_eq!(titles(true).await, ["Critical", "High", "Medium", "Low"]);
+    assert_eq!(titles(false).await, ["Low", "Medium", "High", "Critical"]);
+}
+
+/// --clear-description and --clear-due-date set the fields back to NULL
+#[tokio::test]
+async fn test_update_clears_nullable_fields() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+
+    let project_service = project_service(&pool);
+    let task_service = task_service(&pool);
+
+    let project = project_service
+        .create_project(create_test_project("Clearable Fields"))
+        .await
+        .expect("Failed to create project");
+    let task = task_service
+        .create_task(create_test_task(project.id, "Write changelog"))
+        .await
+        .expect("Failed to create task");
+
+    let ctx = AppContext::new(pool).with_sink(Arc::new(BufferSink::new()));
+    let task_id = task.id.to_string();
+    let project_id = project.id.to_string();
+    let commands: [&[&str]; 4] = [
+        &[
+            "deverp",
+            "task",
+            "update",
+            &task_id,
+            "--description",
+            "Draft notes",
+            "--due-date",
+            "2025-06-30",
+        ],
+        &[
+            "deverp",
+            "project",
+            "update",
+            &project_id,
+            "--description",
+            "Docs sprint",
+        ],
+        &[
+            "deverp",
+            "task",
+            "update",
+            &task_id,
+            "--clear-description",
+            "--clear-due-date",
+        ],
+        &[
+            "deverp",
+            "project",
+            "update",
+            &project_id,
+            "--clear-description",
+        ],
+    ];
+
+    for (step, args) in commands.iter().enumerate() {
+        let cli = Cli::try_parse_from(*args).expect("Failed to parse arguments");
+        match cli.command {
+            Commands::Task(command) => task::handle(&ctx, command, cli.format).await,
+            Commands::Project(command) => project::handle(&ctx, command, cli.format).await,
+            _ => panic!("Expected a task or project command"),
+        }
+        .expect("Failed to update");
+
+        let task = task_service
+            .get_task_by_id(task.id)
+            .await
+            .expect("Task missing");
+        let project = project_service
+            .get_project(project.id)
+            .await
+            .expect("Project missing");
+        match step {
+            0 => {
+                assert_eq!(task.description.as_deref(), Some("Draft notes"));
+                assert_eq!(
+                    task.due_date.map(|d| d.date_naive()),
+                    NaiveDate::from_ymd_opt(2025, 6, 30)
+                );
+            }
+            1 => {
+                assert_eq!(project.description.as_deref(), Some("Docs sprint"));
+                // Updating the project leaves the task alone
+                assert_eq!(task.description.as_deref(), Some("Draft notes"));
+            }
+            2 => {
+                assert_eq!(task.description, None);
+                assert_eq!(task.due_date, None);
+                assert_eq!(task.title, "Write changelog");
+                assert_eq!(project.description.as_deref(), Some("Docs sprint"));
+            }
+            _ => assert_eq!(project.description, None),
+        }
+    }
+
+    let conflicting = Cli::try_parse_from([
+        "deverp",
+        "task",
+        "update",
+        &task_id,
+        "--due-date",
+        "2025-07-01",
+        "--clear-due-date",
+    ]);
+    assert!(conflicting.is_err());
+}
+
+/// --format json on task show nests dependencies and comments under the task
+#[tokio::test]
+async fn test_task_show_outputs_json_detail() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+
+    let project_service = project_service(&pool);
+    let task_service = task_service(&pool);
+
+    let project = project_service
+        .create_project(create_test_project("Detail Project"))
+        .await
+        .expect("Failed to create project");
+    let design = task_service
+        .create_task(create_test_task(project.id, "Design"))
+        .await
+        .expect("Failed to create task");
+    let build = task_service
+        .create_task(create_test_task(project.id, "Build"))
+        .await
+        .expect("Failed to create task");
+    task_service
+        .add_task_dependency(CreateTaskDependency {
+            task_id: build.id,
+            depends_on_task_id: design.id,
+            dependency_type: None,
+        })
+        .await
+        .expect("Failed to add dependency");
+    task_service
+        .add_task_comment(CreateTaskComment {
+            task_id: build.id,
+            comment_text: "Waiting on the design review".to_string(),
+            author: Some("alice".to_string()),
+        })
+        .await
+        .expect("Failed to add comment");
+
+    let buffer = Arc::new(BufferSink::new());
+    let ctx = AppContext::new(pool).with_sink(buffer.clone());
+    let id = build.id.to_string();
+    let cli = Cli::try_parse_from(["deverp", "--format", "json", "task", "show", id.as_str()])
+        .expect("Failed to parse arguments");
+    let Commands::Task(command) = cli.command else {
+        panic!("Expected a task command");
+    };
+
+    task::handle(&ctx, command, cli.format)
+        .await
+        .expect("Failed to show task");
+
+    let detail: TaskDetail =
+        serde_json::from_str(&buffer.contents()).expect("Output is not a JSON task detail");
+    assert_eq!(detail.task.id, build.id);
+    assert_eq!(detail.assignees, vec!["test_user"]);
+    assert_eq!(detail.dependencies.len(), 1);
+    assert_eq!(detail.dependencies[0].depends_on_task_id, design.id);
+    assert_eq!(detail.comments.len(), 1);
+    assert_eq!(detail.comments[0].author.as_deref(), Some("alice"));
+    assert!(detail.recent_work_logs.is_empty());
+}
+
+/// Test that a project's budget is stored and costed against logged hours
+#[tokio::test]
+async fn test_project_budget_and_cost_to_date() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+
+    let task_repo = Arc::new(PostgresTaskRepository::new(pool.clone()));
+    let project_service = project_service(&pool).with_hourly_rate(Some(100.0));
+
+    let mut input = create_test_project("Budgeted Project");
+    input.budget = Some(4000.0);
+    input.currency = Some("EUR".to_string());
+    let project = project_service
+        .create_project(input)
+        .await
+        .expect("Failed to create project");
+    assert_eq!(project.budget, Some(4000.0));
+    assert_eq!(project.currency.as_deref(), Some("EUR"));
+
+    for (title, hours) in [("Backend", 6.0), ("Frontend", 4.0)] {
+        let task = task_repo
+            .create(create_test_task(project.id, title))
+            .await
+            .expect("Failed to create task");
+        task_repo
+            .update(deverp::domain::task::entity::UpdateTask {
+                id: task.id,
+                title: None,
+                description: None,
+                status: None,
+                blocked_reason: None,
+                priority: None,
+                assigned_to: None,
+                estimated_hours: None,
+                actual_hours: Some(hours),
+                due_date: None,
+                task_type: None,
+                tags: None,
+                expected_version: None,
+            })
+            .await
+            .expect("Failed to log hours");
+    }
+
+    let stats = project_service
+        .get_project_stats(project.id)
+        .await
+        .expect("Failed to get stats");
+    assert_eq!(stats.budget, Some(4000.0));
+    assert_eq!(stats.estimated_cost, Some(1000.0));
+    assert_eq!(stats.budget_utilization_pct, Some(25.0));
+
+    use deverp::domain::project::entity::UpdateProject;
+    let updated = project_service
+        .update_project(UpdateProject {
+            id: project.id,
+            budget: Some(2000.0),
+            ..Default::default()
+        })
+        .await
+        .expect("Failed to update budget");
+    assert_eq!(updated.budget, Some(2000.0));
+    assert_eq!(updated.currency.as_deref(), Some("EUR"));
+
+    let mut invalid = create_test_project("Bad Currency");
+    invalid.currency = Some("euro".to_string());
+    let result = project_service.create_project(invalid).await;
+    assert!(matches!(result, Err(DevErpError::Validation(_))));
+}
+
+/// Test that report export-all writes one file per report in either format
+#[tokio::test]
+async fn test_report_export_all_writes_every_report() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+    let reports = [
+        "project_status",
+        "task_analytics",
+        "resource_usage",
+        "timeline_progress",
+        "project_summary",
+    ];
+
+    for format in ["json", "csv"] {
+        let dir = std::env::temp_dir().join(format!("deverp_reports_{}", uuid::Uuid::new_v4()));
+        let buffer = Arc::new(BufferSink::new());
+        let ctx = AppContext::new(pool.clone()).with_sink(buffer.clone());
+        let cli = Cli::try_parse_from([
+            "deverp",
+            "--format",
+            format,
+            "report",
+            "export-all",
+            "--dir",
+            dir.to_str().unwrap(),
+        ])
+        .expect("Failed to parse arguments");
+        let Commands::Report(command) = cli.command else {
+            panic!("Expected a report command");
+        };
+
+        report::handle(&ctx, command, cli.format)
+            .await
+            .expect("Failed to export reports");
+
+        for name in reports {
+            let path = dir.join(format!("{}.{}", name, format));
+            assert!(path.exists(), "{} should be written", path.display());
+            assert!(buffer.contents().contains(&path.display().to_string()));
+        }
+        if format == "json" {
+            let content = std::fs::read_to_string(dir.join("project_status.json")).unwrap();
+            let status: serde_json::Value =
+                serde_json::from_str(&content).expect("Report should be valid JSON");
+            assert!(status.get("total_projects").is_some());
+        }
+        std::fs::remove_dir_all(&dir).ok();
+    }
 }
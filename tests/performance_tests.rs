@@ -1,11 +1,5 @@
 mod helpers;
 
-use deverp::domain::project::service::ProjectService;
-use deverp::domain::task::service::TaskService;
-use deverp::infrastructure::repositories::project_repo::PostgresProjectRepository;
-use deverp::infrastructure::repositories::task_repo::{
-    PostgresTaskCommentRepository, PostgresTaskDependencyRepository, PostgresTaskRepository,
-};
 use helpers::*;
 use std::sync::Arc;
 use std::time::Instant;
@@ -18,8 +12,7 @@ async fn test_bulk_project_creation() {
     let pool = setup_test_database()
         .await
         .expect("Failed to setup test database");
-    let project_repo = Arc::new(PostgresProjectRepository::new(pool.clone()));
-    let project_service = ProjectService::new(project_repo);
+    let project_service = project_service(&pool);
 
     println!("Performance Test: Creating 100 projects...");
 
@@ -30,7 +23,7 @@ async fn test_bulk_project_creation() {
         project_service
             .create_project(project_input)
             .await
-            .expect(&format!("Failed to create project {}", i));
+            .unwrap_or_else(|_| panic!("Failed to create project {}", i));
     }
 
     let duration = start.elapsed();
@@ -46,6 +39,7 @@ async fn test_bulk_project_creation() {
         search: None,
         offset: None,
         limit: None,
+        ..Default::default()
     };
     let all_projects = project_service
         .list_projects(filter)
@@ -70,13 +64,8 @@ async fn test_bulk_task_creation() {
         .await
         .expect("Failed to setup test database");
 
-    let project_repo = Arc::new(PostgresProjectRepository::new(pool.clone()));
-    let task_repo = Arc::new(PostgresTaskRepository::new(pool.clone()));
-    let task_dependency_repo = Arc::new(PostgresTaskDependencyRepository::new(pool.clone()));
-    let task_comment_repo = Arc::new(PostgresTaskCommentRepository::new(pool.clone()));
-
-    let project_service = ProjectService::new(project_repo);
-    let task_service = TaskService::new(task_repo, task_dependency_repo, task_comment_repo);
+    let project_service = project_service(&pool);
+    let task_service = task_service(&pool);
 
     println!("Performance Test: Creating 1000 tasks across 10 projects...");
 
@@ -127,13 +116,8 @@ async fn test_query_performance_large_dataset() {
         .await
         .expect("Failed to setup test database");
 
-    let project_repo = Arc::new(PostgresProjectRepository::new(pool.clone()));
-    let task_repo = Arc::new(PostgresTaskRepository::new(pool.clone()));
-    let task_dependency_repo = Arc::new(PostgresTaskDependencyRepository::new(pool.clone()));
-    let task_comment_repo = Arc::new(PostgresTaskCommentRepository::new(pool.clone()));
-
-    let project_service = ProjectService::new(project_repo);
-    let task_service = TaskService::new(task_repo, task_dependency_repo, task_comment_repo);
+    let project_service = project_service(&pool);
+    let task_service = task_service(&pool);
 
     println!("Performance Test: Query performance with large dataset...");
 
@@ -165,6 +149,7 @@ async fn test_query_performance_large_dataset() {
         search: None,
         offset: None,
         limit: None,
+        ..Default::default()
     };
     let all_projects = project_service
         .list_projects(filter)
@@ -202,12 +187,23 @@ async fn test_query_performance_large_dataset() {
     let start = Instant::now();
     let task_filter = deverp::domain::task::entity::TaskFilter {
         project_id: Some(project_ids[0]),
+        project_ids: None,
         status: None,
         priority: None,
         task_type: None,
         assigned_to: None,
         parent_task_id: None,
+        milestone_id: None,
+        subtree_of: None,
+        search: None,
+        search_comments: false,
         include_deleted: false,
+        estimate_missing: false,
+        actual_missing: false,
+        updated_after: None,
+        updated_before: None,
+        sort_by: None,
+        sort_desc: false,
         offset: None,
         limit: None,
     };
@@ -238,8 +234,7 @@ async fn test_memory_usage() {
         .await
         .expect("Failed to setup test database");
 
-    let project_repo = Arc::new(PostgresProjectRepository::new(pool.clone()));
-    let project_service = ProjectService::new(project_repo);
+    let project_service = project_service(&pool);
 
     println!("Performance Test: Memory usage with pagination...");
 
@@ -265,6 +260,7 @@ async fn test_memory_usage() {
             search: None,
             offset: Some(offset),
             limit: Some(50),
+            ..Default::default()
         };
         let projects = project_service
             .list_projects(filter)
@@ -296,8 +292,7 @@ async fn test_concurrent_operations() {
         .await
         .expect("Failed to setup test database");
 
-    let project_repo = Arc::new(PostgresProjectRepository::new(pool.clone()));
-    let project_service = Arc::new(ProjectService::new(project_repo));
+    let project_service = Arc::new(project_service(&pool));
 
     println!("Performance Test: Concurrent operations...");
 
@@ -337,6 +332,7 @@ async fn test_concurrent_operations() {
         search: None,
         offset: None,
         limit: None,
+        ..Default::default()
     };
     let all_projects = project_service
         .list_projects(filter)
@@ -361,8 +357,7 @@ async fn test_connection_pool_efficiency() {
         .await
         .expect("Failed to setup test database");
 
-    let project_repo = Arc::new(PostgresProjectRepository::new(pool.clone()));
-    let project_service = Arc::new(ProjectService::new(project_repo));
+    let project_service = Arc::new(project_service(&pool));
 
     println!("Performance Test: Connection pool efficiency...");
 
@@ -385,6 +380,7 @@ async fn test_connection_pool_efficiency() {
             search: None,
             offset: None,
             limit: Some(10),
+            ..Default::default()
         };
         project_service
             .list_projects(filter)
@@ -1,22 +1,19 @@
 mod helpers;
 
 use chrono::NaiveDate;
-use deverp::domain::project::entity::ProjectStatus;
-use deverp::domain::project::service::ProjectService;
+use deverp::domain::project::entity::{OverdueAction, ProjectStatus};
 use deverp::domain::report::service::ReportService;
-use deverp::domain::resource::service::ResourceService;
-use deverp::domain::task::entity::TaskStatus;
-use deverp::domain::task::service::TaskService;
-use deverp::domain::timeline::entity::{CreateMilestone, MilestoneStatus, TimelineStatus};
-use deverp::domain::timeline::service::TimelineService;
+use deverp::domain::task::entity::{CompletionCheck, TaskStatus};
+use deverp::domain::timeline::entity::{
+    CreateMilestone, MilestoneFilter, MilestoneStatus, TimelineFilter, TimelineStatus, TimelineType,
+};
 use deverp::infrastructure::repositories::project_repo::PostgresProjectRepository;
 use deverp::infrastructure::repositories::resource_repo::PostgresResourceRepository;
-use deverp::infrastructure::repositories::task_repo::{
-    PostgresTaskCommentRepository, PostgresTaskDependencyRepository, PostgresTaskRepository,
-};
+use deverp::infrastructure::repositories::task_repo::PostgresTaskRepository;
 use deverp::infrastructure::repositories::timeline_repo::{
     PostgresMilestoneRepository, PostgresTimelineRepository,
 };
+use deverp::utils::error::DevErpError;
 use helpers::*;
 use std::sync::Arc;
 
@@ -41,21 +38,15 @@ async fn test_complete_project_lifecycle() {
     // Initialize repositories
     let project_repo = Arc::new(PostgresProjectRepository::new(pool.clone()));
     let task_repo = Arc::new(PostgresTaskRepository::new(pool.clone()));
-    let task_dependency_repo = Arc::new(PostgresTaskDependencyRepository::new(pool.clone()));
-    let task_comment_repo = Arc::new(PostgresTaskCommentRepository::new(pool.clone()));
     let timeline_repo = Arc::new(PostgresTimelineRepository::new(pool.clone()));
     let milestone_repo = Arc::new(PostgresMilestoneRepository::new(pool.clone()));
     let resource_repo = Arc::new(PostgresResourceRepository::new(pool.clone()));
 
     // Initialize services
-    let project_service = ProjectService::new(project_repo.clone());
-    let task_service = TaskService::new(
-        task_repo.clone(),
-        task_dependency_repo.clone(),
-        task_comment_repo.clone(),
-    );
-    let timeline_service = TimelineService::new(timeline_repo.clone(), milestone_repo.clone());
-    let resource_service = ResourceService::new(resource_repo.clone());
+    let project_service = project_service(&pool);
+    let task_service = task_service(&pool);
+    let timeline_service = timeline_service(&pool);
+    let resource_service = resource_service(&pool);
     let report_service = ReportService::new(
         project_repo.clone(),
         task_repo.clone(),
@@ -128,6 +119,7 @@ async fn test_complete_project_lifecycle() {
         status: Some(MilestoneStatus::Pending),
         completion_percentage: Some(0),
         metadata: None,
+        task_ids: Vec::new(),
     };
 
     let milestone2 = CreateMilestone {
@@ -139,6 +131,7 @@ async fn test_complete_project_lifecycle() {
         status: Some(MilestoneStatus::Pending),
         completion_percentage: Some(0),
         metadata: None,
+        task_ids: Vec::new(),
     };
 
     let m1 = timeline_service
@@ -201,34 +194,42 @@ async fn test_complete_project_lifecycle() {
 
     // Complete task 1
     task_service
-        .update_task(deverp::domain::task::entity::UpdateTask {
-            id: tasks[0].id,
-            title: None,
-            description: None,
-            status: Some(TaskStatus::Done),
-            priority: None,
-            assigned_to: None,
-            estimated_hours: None,
-            actual_hours: Some(10.0),
-            due_date: None,
-            task_type: None,
-            tags: None,
-        })
+        .update_task(
+            deverp::domain::task::entity::UpdateTask {
+                id: tasks[0].id,
+                title: None,
+                description: None,
+                status: Some(TaskStatus::Done),
+                blocked_reason: None,
+                priority: None,
+                assigned_to: None,
+                estimated_hours: None,
+                actual_hours: Some(10.0),
+                due_date: None,
+                task_type: None,
+                tags: None,
+                expected_version: None,
+            },
+            CompletionCheck::Enforce,
+        )
         .await
         .expect("Failed to update task 1");
 
     // Complete milestone 1
     timeline_service
-        .update_milestone(deverp::domain::timeline::entity::UpdateMilestone {
-            id: m1.id,
-            name: None,
-            description: None,
-            target_date: None,
-            actual_date: Some(chrono::Utc::now().date_naive()),
-            status: Some(deverp::domain::timeline::entity::MilestoneStatus::Completed),
-            completion_percentage: Some(100),
-            metadata: None,
-        })
+        .update_milestone(
+            deverp::domain::timeline::entity::UpdateMilestone {
+                id: m1.id,
+                name: None,
+                description: None,
+                target_date: None,
+                actual_date: Some(chrono::Utc::now().date_naive()),
+                status: Some(deverp::domain::timeline::entity::MilestoneStatus::Completed),
+                completion_percentage: Some(100),
+                metadata: None,
+            },
+            CompletionCheck::Enforce,
+        )
         .await
         .expect("Failed to complete milestone 1");
 
@@ -246,10 +247,13 @@ async fn test_complete_project_lifecycle() {
             actual_start_date: Some(NaiveDate::from_ymd_opt(2025, 1, 15).unwrap()),
             actual_end_date: None,
             progress_percentage: Some(25),
+            budget: None,
+            currency: None,
             repository_url: None,
             repository_branch: None,
             tags: None,
             metadata: None,
+            expected_version: None,
         })
         .await
         .expect("Failed to update project");
@@ -281,19 +285,24 @@ async fn test_complete_project_lifecycle() {
 
     for task in tasks.iter().skip(1) {
         task_service
-            .update_task(deverp::domain::task::entity::UpdateTask {
-                id: task.id,
-                title: None,
-                description: None,
-                status: Some(TaskStatus::Done),
-                priority: None,
-                assigned_to: None,
-                estimated_hours: None,
-                actual_hours: Some(8.0),
-                due_date: None,
-                task_type: None,
-                tags: None,
-            })
+            .update_task(
+                deverp::domain::task::entity::UpdateTask {
+                    id: task.id,
+                    title: None,
+                    description: None,
+                    status: Some(TaskStatus::Done),
+                    blocked_reason: None,
+                    priority: None,
+                    assigned_to: None,
+                    estimated_hours: None,
+                    actual_hours: Some(8.0),
+                    due_date: None,
+                    task_type: None,
+                    tags: None,
+                    expected_version: None,
+                },
+                CompletionCheck::Enforce,
+            )
             .await
             .expect("Failed to complete task");
     }
@@ -312,10 +321,13 @@ async fn test_complete_project_lifecycle() {
             actual_start_date: None,
             actual_end_date: Some(NaiveDate::from_ymd_opt(2025, 3, 15).unwrap()),
             progress_percentage: Some(100),
+            budget: None,
+            currency: None,
             repository_url: None,
             repository_branch: None,
             tags: None,
             metadata: None,
+            expected_version: None,
         })
         .await
         .expect("Failed to complete project");
@@ -338,3 +350,712 @@ async fn test_complete_project_lifecycle() {
 
     println!("✅ Scenario 1: Project lifecycle test completed successfully!");
 }
+
+/// Milestones can only be linked to tasks of their own project
+#[tokio::test]
+async fn test_milestone_link_tasks_rejects_other_project() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+
+    let project_service = project_service(&pool);
+    let task_service = task_service(&pool);
+    let timeline_service = timeline_service(&pool);
+
+    let project = project_service
+        .create_project(create_test_project("Milestone Link Home"))
+        .await
+        .expect("Failed to create project");
+    let other_project = project_service
+        .create_project(create_test_project("Milestone Link Other"))
+        .await
+        .expect("Failed to create project");
+
+    let own_task = task_service
+        .create_task(create_test_task(project.id, "Own Task"))
+        .await
+        .expect("Failed to create task");
+    let foreign_task = task_service
+        .create_task(create_test_task(other_project.id, "Foreign Task"))
+        .await
+        .expect("Failed to create task");
+
+    let timeline = timeline_service
+        .create_timeline(create_test_timeline(project.id, "Link Timeline"))
+        .await
+        .expect("Failed to create timeline");
+
+    let input = CreateMilestone {
+        timeline_id: timeline.id,
+        project_id: project.id,
+        name: "Beta".to_string(),
+        description: None,
+        target_date: NaiveDate::from_ymd_opt(2025, 6, 1).unwrap(),
+        status: None,
+        completion_percentage: None,
+        metadata: None,
+        task_ids: vec![own_task.id, foreign_task.id],
+    };
+
+    // A task from another project aborts the whole create
+    let result = timeline_service.create_milestone(input.clone()).await;
+    assert!(result.is_err(), "Foreign task should be rejected");
+    let milestones = timeline_service
+        .get_milestones_by_timeline(timeline.id)
+        .await
+        .expect("Failed to list milestones");
+    assert!(milestones.is_empty(), "Milestone must not be created");
+    let own_task = task_service
+        .get_task_by_id(own_task.id)
+        .await
+        .expect("Failed to get task");
+    assert_eq!(own_task.milestone_id, None);
+
+    // Tasks of the same project are linked
+    let milestone = timeline_service
+        .create_milestone(CreateMilestone {
+            task_ids: vec![own_task.id],
+            ..input
+        })
+        .await
+        .expect("Failed to create milestone");
+    let own_task = task_service
+        .get_task_by_id(own_task.id)
+        .await
+        .expect("Failed to get task");
+    assert_eq!(own_task.milestone_id, Some(milestone.id));
+
+    println!("✅ Milestone task linking validated project membership");
+}
+
+/// Per-assignee task analytics add up to the overall totals
+#[tokio::test]
+async fn test_task_analytics_by_assignee_sums_to_totals() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+
+    let project_repo = Arc::new(PostgresProjectRepository::new(pool.clone()));
+    let task_repo = Arc::new(PostgresTaskRepository::new(pool.clone()));
+    let task_service = task_service(&pool);
+    let report_service = ReportService::new(
+        project_repo.clone(),
+        task_repo,
+        Arc::new(PostgresResourceRepository::new(pool.clone())),
+        Arc::new(PostgresTimelineRepository::new(pool.clone())),
+        Arc::new(PostgresMilestoneRepository::new(pool.clone())),
+    );
+
+    let project = project_service(&pool)
+        .create_project(create_test_project("Assignee Analytics"))
+        .await
+        .expect("Failed to create project");
+
+    let tasks = [
+        ("Alice One", Some("alice"), TaskStatus::Done),
+        ("Alice Two", Some("alice"), TaskStatus::InProgress),
+        ("Bob One", Some("bob"), TaskStatus::Todo),
+        ("Nobody One", None, TaskStatus::Todo),
+        ("Nobody Two", None, TaskStatus::Blocked),
+    ];
+    for (title, assignee, status) in tasks {
+        let mut input = create_test_task(project.id, title);
+        input.assigned_to = assignee.map(str::to_string);
+        if status == TaskStatus::Blocked {
+            input.blocked_reason = Some("Waiting on review".to_string());
+        }
+        input.status = Some(status);
+        task_service
+            .create_task(input)
+            .await
+            .expect("Failed to create task");
+    }
+
+    let report = report_service
+        .generate_task_analytics(true)
+        .await
+        .expect("Failed to generate task analytics");
+    let breakdown = report.by_assignee.expect("Breakdown should be present");
+
+    let names: Vec<Option<&str>> = breakdown.iter().map(|b| b.assigned_to.as_deref()).collect();
+    assert_eq!(names, vec![Some("alice"), Some("bob"), None]);
+
+    let total: i64 = breakdown.iter().map(|b| b.total_tasks).sum();
+    assert_eq!(total, report.total_tasks);
+    let done: i64 = breakdown.iter().map(|b| b.tasks_by_status.done).sum();
+    assert_eq!(done, report.tasks_by_status.done);
+    let todo: i64 = breakdown.iter().map(|b| b.tasks_by_status.todo).sum();
+    assert_eq!(todo, report.tasks_by_status.todo);
+
+    assert_eq!(breakdown[0].total_tasks, 2);
+    assert!((breakdown[0].completion_rate - 50.0).abs() < f64::EPSILON);
+    assert_eq!(breakdown[2].total_tasks, 2);
+
+    let plain = report_service
+        .generate_task_analytics(false)
+        .await
+        .expect("Failed to generate task analytics");
+    assert!(plain.by_assignee.is_none());
+
+    println!("✅ Per-assignee analytics match overall totals");
+}
+
+/// Derived project dates span every timeline of the project
+#[tokio::test]
+async fn test_derive_project_dates_from_timelines() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+
+    let project_service = project_service(&pool);
+    let timeline_service = timeline_service(&pool);
+
+    let mut input = create_test_project("Derived Window");
+    input.start_date = None;
+    input.end_date = None;
+    let project = project_service
+        .create_project(input)
+        .await
+        .expect("Failed to create project");
+
+    let windows = [
+        ((2025, 3, 1), (2025, 5, 31)),
+        ((2025, 2, 15), (2025, 4, 30)),
+        ((2025, 4, 1), (2025, 9, 30)),
+    ];
+    for (i, (start, end)) in windows.into_iter().enumerate() {
+        let mut timeline = create_test_timeline(project.id, &format!("Phase {}", i + 1));
+        timeline.start_date = NaiveDate::from_ymd_opt(start.0, start.1, start.2).unwrap();
+        timeline.end_date = NaiveDate::from_ymd_opt(end.0, end.1, end.2).unwrap();
+        timeline_service
+            .create_timeline(timeline)
+            .await
+            .expect("Failed to create timeline");
+    }
+
+    let project = project_service
+        .derive_dates_from_timelines(project.id, false)
+        .await
+        .expect("Failed to derive dates");
+    assert_eq!(project.start_date, NaiveDate::from_ymd_opt(2025, 2, 15));
+    assert_eq!(project.end_date, NaiveDate::from_ymd_opt(2025, 9, 30));
+
+    // A new, longer timeline changes the window; existing dates need --force
+    let mut extension = create_test_timeline(project.id, "Extension");
+    extension.start_date = NaiveDate::from_ymd_opt(2025, 9, 1).unwrap();
+    extension.end_date = NaiveDate::from_ymd_opt(2025, 12, 15).unwrap();
+    timeline_service
+        .create_timeline(extension)
+        .await
+        .expect("Failed to create timeline");
+
+    let result = project_service
+        .derive_dates_from_timelines(project.id, false)
+        .await;
+    assert!(result.is_err(), "Existing dates must not be overwritten");
+
+    let project = project_service
+        .derive_dates_from_timelines(project.id, true)
+        .await
+        .expect("Failed to derive dates with force");
+    assert_eq!(project.start_date, NaiveDate::from_ymd_opt(2025, 2, 15));
+    assert_eq!(project.end_date, NaiveDate::from_ymd_opt(2025, 12, 15));
+
+    println!("✅ Project dates span all timelines");
+}
+
+#[tokio::test]
+async fn test_scan_missed_milestones() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+
+    let project_service = project_service(&pool);
+    let timeline_service = timeline_service(&pool);
+
+    let mut milestone_ids = Vec::new();
+    for project_name in ["Scanned Project", "Other Project"] {
+        let project = project_service
+            .create_project(create_test_project(project_name))
+            .await
+            .expect("Failed to create project");
+        let timeline = timeline_service
+            .create_timeline(create_test_timeline(project.id, "Roadmap"))
+            .await
+            .expect("Failed to create timeline");
+
+        let mut ids = Vec::new();
+        for (name, target, status) in [
+            ("Overdue", (2025, 3, 1), MilestoneStatus::Pending),
+            ("Shipped", (2025, 2, 1), MilestoneStatus::Completed),
+            ("Upcoming", (2025, 8, 1), MilestoneStatus::Pending),
+        ] {
+            let milestone = timeline_service
+                .create_milestone(CreateMilestone {
+                    timeline_id: timeline.id,
+                    project_id: project.id,
+                    name: name.to_string(),
+                    description: None,
+                    target_date: NaiveDate::from_ymd_opt(target.0, target.1, target.2).unwrap(),
+                    status: Some(status),
+                    completion_percentage: None,
+                    metadata: None,
+                    task_ids: Vec::new(),
+                })
+                .await
+                .expect("Failed to create milestone");
+            ids.push(milestone.id);
+        }
+        milestone_ids.push((project.id, ids));
+    }
+
+    let (project_id, ids) = &milestone_ids[0];
+    let today = NaiveDate::from_ymd_opt(2025, 6, 1).unwrap();
+
+    let missed = timeline_service
+        .scan_missed_milestones(Some(*project_id), today)
+        .await
+        .expect("Failed to scan milestones");
+    assert_eq!(missed.len(), 1);
+    assert_eq!(missed[0].id, ids[0]);
+    assert_eq!(missed[0].status, MilestoneStatus::Missed);
+
+    let shipped = timeline_service.get_milestone(ids[1]).await.unwrap();
+    assert_eq!(shipped.status, MilestoneStatus::Completed);
+    let upcoming = timeline_service.get_milestone(ids[2]).await.unwrap();
+    assert_eq!(upcoming.status, MilestoneStatus::Pending);
+
+    // The other project's overdue milestone is outside the scan
+    let other_overdue = timeline_service
+        .get_milestone(milestone_ids[1].1[0])
+        .await
+        .unwrap();
+    assert_eq!(other_overdue.status, MilestoneStatus::Pending);
+
+    // Re-running is a no-op for milestones already marked missed
+    let rerun = timeline_service
+        .scan_missed_milestones(Some(*project_id), today)
+        .await
+        .expect("Failed to rescan milestones");
+    assert!(rerun.is_empty());
+
+    println!("✅ Overdue milestones marked missed");
+}
+
+#[tokio::test]
+async fn test_list_overdue_milestones() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+
+    let project_service = project_service(&pool);
+    let timeline_service = timeline_service(&pool);
+
+    let mut milestone_ids = Vec::new();
+    for project_name in ["Listed Project", "Other Project"] {
+        let project = project_service
+            .create_project(create_test_project(project_name))
+            .await
+            .expect("Failed to create project");
+        let timeline = timeline_service
+            .create_timeline(create_test_timeline(project.id, "Roadmap"))
+            .await
+            .expect("Failed to create timeline");
+
+        let mut ids = Vec::new();
+        for (name, target, status) in [
+            ("Overdue", (2025, 3, 1), MilestoneStatus::Pending),
+            ("Shipped", (2025, 2, 1), MilestoneStatus::Completed),
+            ("Upcoming", (2025, 8, 1), MilestoneStatus::Pending),
+        ] {
+            let milestone = timeline_service
+                .create_milestone(CreateMilestone {
+                    timeline_id: timeline.id,
+                    project_id: project.id,
+                    name: name.to_string(),
+                    description: None,
+                    target_date: NaiveDate::from_ymd_opt(target.0, target.1, target.2).unwrap(),
+                    status: Some(status),
+                    completion_percentage: None,
+                    metadata: None,
+                    task_ids: Vec::new(),
+                })
+                .await
+                .expect("Failed to create milestone");
+            ids.push(milestone.id);
+        }
+        milestone_ids.push((project.id, ids));
+    }
+
+    let (project_id, ids) = &milestone_ids[0];
+    let filter = MilestoneFilter {
+        project_id: Some(*project_id),
+        overdue_as_of: NaiveDate::from_ymd_opt(2025, 6, 1),
+        ..Default::default()
+    };
+
+    let overdue = timeline_service
+        .list_milestones(filter.clone())
+        .await
+        .expect("Failed to list milestones");
+    let overdue_ids: Vec<i64> = overdue.iter().map(|m| m.id).collect();
+    assert_eq!(overdue_ids, vec![ids[0]]);
+    assert_eq!(timeline_service.count_milestones(filter).await.unwrap(), 1);
+
+    // Listing is read-only: the overdue milestone is not marked missed
+    let listed = timeline_service.get_milestone(ids[0]).await.unwrap();
+    assert_eq!(listed.status, MilestoneStatus::Pending);
+
+    println!("✅ Overdue milestones listed per project");
+}
+
+#[tokio::test]
+async fn test_refresh_milestone_statuses_for_timeline() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+
+    let project_service = project_service(&pool);
+    let timeline_service = timeline_service(&pool);
+
+    let project = project_service
+        .create_project(create_test_project("Refreshed Project"))
+        .await
+        .expect("Failed to create project");
+
+    let mut timelines = Vec::new();
+    for name in ["Refreshed", "Untouched"] {
+        let timeline = timeline_service
+            .create_timeline(create_test_timeline(project.id, name))
+            .await
+            .expect("Failed to create timeline");
+
+        let mut ids = Vec::new();
+        for status in [
+            MilestoneStatus::Pending,
+            MilestoneStatus::InProgress,
+            MilestoneStatus::Completed,
+            MilestoneStatus::Cancelled,
+        ] {
+            let milestone = timeline_service
+                .create_milestone(CreateMilestone {
+                    timeline_id: timeline.id,
+                    project_id: project.id,
+                    name: format!("{} {}", name, status),
+                    description: None,
+                    target_date: NaiveDate::from_ymd_opt(2025, 3, 1).unwrap(),
+                    status: Some(status),
+                    completion_percentage: None,
+                    metadata: None,
+                    task_ids: Vec::new(),
+                })
+                .await
+                .expect("Failed to create milestone");
+            ids.push(milestone.id);
+        }
+        timelines.push((timeline.id, ids));
+    }
+
+    let today = NaiveDate::from_ymd_opt(2025, 6, 1).unwrap();
+    let (timeline_id, ids) = &timelines[0];
+
+    let updated = timeline_service
+        .refresh_milestone_statuses(*timeline_id, today)
+        .await
+        .expect("Failed to refresh milestones");
+    assert_eq!(updated, 2);
+
+    let mut statuses = Vec::new();
+    for id in ids {
+        statuses.push(timeline_service.get_milestone(*id).await.unwrap().status);
+    }
+    assert_eq!(
+        statuses,
+        vec![
+            MilestoneStatus::Missed,
+            MilestoneStatus::Missed,
+            MilestoneStatus::Completed,
+            MilestoneStatus::Cancelled,
+        ]
+    );
+
+    // Milestones on the project's other timeline are not refreshed
+    let other = timeline_service
+        .get_milestone(timelines[1].1[0])
+        .await
+        .unwrap();
+    assert_eq!(other.status, MilestoneStatus::Pending);
+
+    let result = timeline_service
+        .refresh_milestone_statuses(i64::MAX, today)
+        .await;
+    assert!(matches!(result, Err(DevErpError::NotFound(_))));
+
+    println!("✅ Timeline milestone statuses refreshed");
+}
+
+#[tokio::test]
+async fn test_scan_overdue_projects() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+
+    let project_service = project_service(&pool);
+
+    // All end on 2025-12-31 unless overridden
+    let mut ids = Vec::new();
+    for (name, status, end_date) in [
+        ("Late Active", ProjectStatus::Active, None),
+        ("Late Completed", ProjectStatus::Completed, None),
+        (
+            "On Track",
+            ProjectStatus::Active,
+            NaiveDate::from_ymd_opt(2026, 6, 30),
+        ),
+    ] {
+        let mut input = create_test_project(name);
+        input.status = Some(status);
+        if end_date.is_some() {
+            input.end_date = end_date;
+        }
+        let project = project_service
+            .create_project(input)
+            .await
+            .expect("Failed to create project");
+        ids.push(project.id);
+    }
+
+    let today = NaiveDate::from_ymd_opt(2026, 1, 15).unwrap();
+
+    let flagged = project_service
+        .scan_overdue(OverdueAction::Tag, today)
+        .await
+        .expect("Failed to scan overdue projects");
+    assert_eq!(flagged.len(), 1);
+    assert_eq!(flagged[0].id, ids[0]);
+    assert!(flagged[0]
+        .tags
+        .as_ref()
+        .unwrap()
+        .contains(&"overdue".to_string()));
+
+    let completed = project_service.get_project(ids[1]).await.unwrap();
+    assert!(!completed.tags.unwrap().contains(&"overdue".to_string()));
+
+    let held = project_service
+        .scan_overdue(OverdueAction::SetStatus(ProjectStatus::OnHold), today)
+        .await
+        .expect("Failed to scan overdue projects");
+    assert_eq!(held.len(), 1);
+    assert_eq!(held[0].status, ProjectStatus::OnHold);
+
+    // Once on hold the project is no longer active, so nothing is left to flag
+    let rerun = project_service
+        .scan_overdue(OverdueAction::Notify, today)
+        .await
+        .expect("Failed to rescan overdue projects");
+    assert!(rerun.is_empty());
+
+    println!("✅ Overdue projects flagged");
+}
+
+#[tokio::test]
+async fn test_timeline_and_milestone_filters_paginate_in_sql() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+
+    let project_service = project_service(&pool);
+    let timeline_service = timeline_service(&pool);
+
+    let project = project_service
+        .create_project(create_test_project("Paged Timelines"))
+        .await
+        .expect("Failed to create project");
+    let other = project_service
+        .create_project(create_test_project("Other Timelines"))
+        .await
+        .expect("Failed to create project");
+
+    let mut timeline_ids = Vec::new();
+    for (month, timeline_type) in [
+        (1, TimelineType::Project),
+        (3, TimelineType::Sprint),
+        (5, TimelineType::Sprint),
+    ] {
+        let mut input = create_test_timeline(project.id, &format!("Month {}", month));
+        input.start_date = NaiveDate::from_ymd_opt(2025, month, 1).unwrap();
+        input.timeline_type = Some(timeline_type);
+        let timeline = timeline_service
+            .create_timeline(input)
+            .await
+            .expect("Failed to create timeline");
+        timeline_ids.push(timeline.id);
+    }
+    timeline_service
+        .create_timeline(create_test_timeline(other.id, "Elsewhere"))
+        .await
+        .expect("Failed to create timeline");
+
+    // Newest start date first, paged after filtering
+    let page = TimelineFilter {
+        project_id: Some(project.id),
+        limit: Some(2),
+        ..Default::default()
+    };
+    let first: Vec<i64> = timeline_service
+        .list_timelines(page.clone())
+        .await
+        .expect("Failed to list timelines")
+        .iter()
+        .map(|t| t.id)
+        .collect();
+    assert_eq!(first, vec![timeline_ids[2], timeline_ids[1]]);
+    let second: Vec<i64> = timeline_service
+        .list_timelines(TimelineFilter {
+            offset: Some(2),
+            ..page.clone()
+        })
+        .await
+        .expect("Failed to list timelines")
+        .iter()
+        .map(|t| t.id)
+        .collect();
+    assert_eq!(second, vec![timeline_ids[0]]);
+    assert_eq!(timeline_service.count_timelines(page).await.unwrap(), 3);
+
+    let sprints = TimelineFilter {
+        project_id: Some(project.id),
+        timeline_type: Some(TimelineType::Sprint),
+        ..Default::default()
+    };
+    assert_eq!(
+        timeline_service
+            .list_timelines(sprints.clone())
+            .await
+            .unwrap()
+            .len(),
+        2
+    );
+    assert_eq!(timeline_service.count_timelines(sprints).await.unwrap(), 2);
+
+    for (day, status) in [
+        (10, MilestoneStatus::Completed),
+        (20, MilestoneStatus::Pending),
+        (25, MilestoneStatus::Pending),
+    ] {
+        timeline_service
+            .create_milestone(CreateMilestone {
+                timeline_id: timeline_ids[1],
+                project_id: project.id,
+                name: format!("Day {}", day),
+                description: None,
+                target_date: NaiveDate::from_ymd_opt(2025, 3, day).unwrap(),
+                status: Some(status),
+                completion_percentage: None,
+                metadata: None,
+                task_ids: Vec::new(),
+            })
+            .await
+            .expect("Failed to create milestone");
+    }
+
+    let pending = MilestoneFilter {
+        timeline_id: Some(timeline_ids[1]),
+        status: Some(MilestoneStatus::Pending),
+        limit: Some(1),
+        ..Default::default()
+    };
+    let listed = timeline_service
+        .list_milestones(pending.clone())
+        .await
+        .expect("Failed to list milestones");
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed[0].name, "Day 20");
+    assert_eq!(timeline_service.count_milestones(pending).await.unwrap(), 2);
+
+    println!("✅ Timeline and milestone filters applied in SQL");
+}
+
+/// Cascade delete marks every child row deleted; plain delete leaves them alone
+#[tokio::test]
+async fn test_delete_project_cascade_soft_deletes_children() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+
+    let project_service = project_service(&pool);
+    let task_service = task_service(&pool);
+    let timeline_service = timeline_service(&pool);
+
+    let mut project_ids = Vec::new();
+    for name in ["Cascade Project", "Plain Project"] {
+        let project = project_service
+            .create_project(create_test_project(name))
+            .await
+            .expect("Failed to create project");
+        for title in ["First", "Second"] {
+            task_service
+                .create_task(create_test_task(project.id, title))
+                .await
+                .expect("Failed to create task");
+        }
+        let timeline = timeline_service
+            .create_timeline(create_test_timeline(project.id, "Release"))
+            .await
+            .expect("Failed to create timeline");
+        timeline_service
+            .create_milestone(CreateMilestone {
+                timeline_id: timeline.id,
+                project_id: project.id,
+                name: "Beta".to_string(),
+                description: None,
+                target_date: NaiveDate::from_ymd_opt(2025, 6, 1).unwrap(),
+                status: Some(MilestoneStatus::Pending),
+                completion_percentage: Some(0),
+                metadata: None,
+                task_ids: Vec::new(),
+            })
+            .await
+            .expect("Failed to create milestone");
+        project_ids.push(project.id);
+    }
+    let (cascaded, plain) = (project_ids[0], project_ids[1]);
+
+    assert!(project_service
+        .delete_project_cascade(cascaded)
+        .await
+        .expect("Failed to cascade delete"));
+    assert!(project_service
+        .delete_project(plain)
+        .await
+        .expect("Failed to delete project"));
+
+    // Live (not soft-deleted) rows per table for a project
+    let live_rows = |project_id: i64| {
+        let pool = pool.clone();
+        async move {
+            sqlx::query_as::<_, (i64, i64, i64)>(
+                r#"
+                SELECT
+                    (SELECT COUNT(*) FROM tasks WHERE project_id = $1 AND deleted_at IS NULL),
+                    (SELECT COUNT(*) FROM timelines WHERE project_id = $1 AND deleted_at IS NULL),
+                    (SELECT COUNT(*) FROM milestones WHERE project_id = $1 AND deleted_at IS NULL)
+                "#,
+            )
+            .bind(project_id)
+            .fetch_one(&pool)
+            .await
+            .expect("Failed to count live rows")
+        }
+    };
+
+    assert_eq!(live_rows(cascaded).await, (0, 0, 0));
+    assert_eq!(live_rows(plain).await, (2, 1, 1));
+
+    // An already deleted project is reported as not found
+    assert!(!project_service
+        .delete_project_cascade(cascaded)
+        .await
+        .expect("Failed to cascade delete"));
+}
@@ -1,10 +1,11 @@
 mod helpers;
 
-use deverp::domain::project::service::ProjectService;
-use deverp::domain::task::entity::DependencyType;
-use deverp::domain::task::service::TaskService;
-use deverp::infrastructure::repositories::project_repo::PostgresProjectRepository;
-use deverp::infrastructure::repositories::task_repo::PostgresTaskRepository;
+use deverp::domain::task::entity::{
+    CompletionCheck, CreateTaskDependency, DependencyType, TaskStatus, UpdateTask,
+};
+use deverp::domain::task::repository::TaskDependencyRepository;
+use deverp::infrastructure::repositories::task_repo::PostgresTaskDependencyRepository;
+use deverp::utils::error::DevErpError;
 use helpers::*;
 use std::sync::Arc;
 
@@ -23,21 +24,8 @@ async fn test_task_dependency_management() {
         .expect("Failed to setup test database");
 
     // Initialize repositories and services
-    let project_repo = Arc::new(PostgresProjectRepository::new(pool.clone()));
-    let task_repo = Arc::new(PostgresTaskRepository::new(pool.clone()));
-    let dependency_repo = Arc::new(
-        deverp::infrastructure::repositories::task_repo::PostgresTaskDependencyRepository::new(
-            pool.clone(),
-        ),
-    );
-    let comment_repo = Arc::new(
-        deverp::infrastructure::repositories::task_repo::PostgresTaskCommentRepository::new(
-            pool.clone(),
-        ),
-    );
-
-    let project_service = ProjectService::new(project_repo);
-    let task_service = TaskService::new(task_repo, dependency_repo, comment_repo);
+    let project_service = project_service(&pool);
+    let task_service = task_service(&pool);
 
     // Step 1: Create a project and multiple tasks
     println!("Step 1: Creating project and tasks...");
@@ -267,21 +255,8 @@ async fn test_self_dependency_rejection() {
         .await
         .expect("Failed to setup test database");
 
-    let project_repo = Arc::new(PostgresProjectRepository::new(pool.clone()));
-    let task_repo = Arc::new(PostgresTaskRepository::new(pool.clone()));
-    let dependency_repo = Arc::new(
-        deverp::infrastructure::repositories::task_repo::PostgresTaskDependencyRepository::new(
-            pool.clone(),
-        ),
-    );
-    let comment_repo = Arc::new(
-        deverp::infrastructure::repositories::task_repo::PostgresTaskCommentRepository::new(
-            pool.clone(),
-        ),
-    );
-
-    let project_service = ProjectService::new(project_repo);
-    let task_service = TaskService::new(task_repo, dependency_repo, comment_repo);
+    let project_service = project_service(&pool);
+    let task_service = task_service(&pool);
 
     let project = project_service
         .create_project(create_test_project("Self Dependency Test"))
@@ -306,6 +281,56 @@ async fn test_self_dependency_rejection() {
     println!("✅ Self-dependency correctly rejected");
 }
 
+/// Test that adding the same dependency twice reports a conflict
+#[tokio::test]
+async fn test_duplicate_dependency_conflict() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+
+    let project_service = project_service(&pool);
+    let task_service = task_service(&pool);
+
+    let project = project_service
+        .create_project(create_test_project("Duplicate Dependency Test"))
+        .await
+        .expect("Failed to create project");
+
+    let design = task_service
+        .create_task(create_test_task(project.id, "Design"))
+        .await
+        .expect("Failed to create task");
+    let build = task_service
+        .create_task(create_test_task(project.id, "Build"))
+        .await
+        .expect("Failed to create task");
+
+    let dependency = deverp::domain::task::entity::CreateTaskDependency {
+        task_id: build.id,
+        depends_on_task_id: design.id,
+        dependency_type: Some(DependencyType::FinishToStart),
+    };
+
+    task_service
+        .add_task_dependency(dependency.clone())
+        .await
+        .expect("Failed to add dependency");
+
+    let result = task_service.add_task_dependency(dependency).await;
+    match result {
+        Err(DevErpError::Conflict(msg)) => assert!(msg.contains("already exists")),
+        other => panic!("Expected Conflict error, got {:?}", other),
+    }
+
+    let dependencies = task_service
+        .get_task_dependencies(build.id)
+        .await
+        .expect("Failed to get dependencies");
+    assert_eq!(dependencies.len(), 1);
+
+    println!("✅ Duplicate dependency correctly reported as conflict");
+}
+
 /// Test complex circular dependency detection
 #[tokio::test]
 async fn test_complex_circular_dependency() {
@@ -313,21 +338,8 @@ async fn test_complex_circular_dependency() {
         .await
         .expect("Failed to setup test database");
 
-    let project_repo = Arc::new(PostgresProjectRepository::new(pool.clone()));
-    let task_repo = Arc::new(PostgresTaskRepository::new(pool.clone()));
-    let dependency_repo = Arc::new(
-        deverp::infrastructure::repositories::task_repo::PostgresTaskDependencyRepository::new(
-            pool.clone(),
-        ),
-    );
-    let comment_repo = Arc::new(
-        deverp::infrastructure::repositories::task_repo::PostgresTaskCommentRepository::new(
-            pool.clone(),
-        ),
-    );
-
-    let project_service = ProjectService::new(project_repo);
-    let task_service = TaskService::new(task_repo, dependency_repo, comment_repo);
+    let project_service = project_service(&pool);
+    let task_service = task_service(&pool);
 
     let project = project_service
         .create_project(create_test_project("Complex Cycle Test"))
@@ -378,3 +390,461 @@ async fn test_complex_circular_dependency() {
     );
     println!("✅ Complex circular dependency correctly detected");
 }
+
+/// Concurrent additions that would only form a cycle together must not both succeed
+#[tokio::test]
+async fn test_concurrent_dependencies_cannot_close_cycle() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+
+    let project_service = project_service(&pool);
+    let task_service = task_service(&pool);
+
+    let project = project_service
+        .create_project(create_test_project("Concurrent Cycle Test"))
+        .await
+        .expect("Failed to create project");
+
+    let mut ids = Vec::new();
+    for title in ["Task A", "Task B", "Task C", "Task D"] {
+        let task = task_service
+            .create_task(create_test_task(project.id, title))
+            .await
+            .expect("Failed to create task");
+        ids.push(task.id);
+    }
+    let (a, b, c, d) = (ids[0], ids[1], ids[2], ids[3]);
+
+    // Existing edges B -> C and D -> A; adding both A -> B and C -> D closes
+    // A -> B -> C -> D -> A, although each edge alone is fine
+    let edge = |task_id, depends_on_task_id| CreateTaskDependency {
+        task_id,
+        depends_on_task_id,
+        dependency_type: None,
+    };
+    let repo = PostgresTaskDependencyRepository::new(pool.clone());
+    repo.add_dependency(edge(b, c))
+        .await
+        .expect("Failed to add dependency");
+    repo.add_dependency(edge(d, a))
+        .await
+        .expect("Failed to add dependency");
+
+    let first = PostgresTaskDependencyRepository::new(pool.clone());
+    let second = PostgresTaskDependencyRepository::new(pool.clone());
+    let (ab, cd) = tokio::join!(
+        first.add_dependency(edge(a, b)),
+        second.add_dependency(edge(c, d))
+    );
+
+    assert!(
+        ab.is_ok() != cd.is_ok(),
+        "Exactly one of the concurrent additions should succeed"
+    );
+    let failed = if ab.is_ok() { cd } else { ab };
+    assert!(matches!(failed, Err(DevErpError::Validation(_))));
+
+    let edges: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM task_dependencies")
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to count dependencies");
+    assert_eq!(edges, 3);
+
+    println!("✅ Concurrent dependency additions kept the graph acyclic");
+}
+
+/// Creating a task with an invalid dependency must not leave the task behind
+#[tokio::test]
+async fn test_create_task_with_invalid_dependency_rolls_back() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+
+    let project_service = project_service(&pool);
+    let task_service = task_service(&pool);
+
+    let project = project_service
+        .create_project(create_test_project("Create With Deps"))
+        .await
+        .expect("Failed to create project");
+
+    let base = task_service
+        .create_task(create_test_task(project.id, "Base Task"))
+        .await
+        .expect("Failed to create base task");
+
+    // Valid dependencies are added and reported
+    let (task, deps) = task_service
+        .create_task_with_dependencies(create_test_task(project.id, "Follow Up"), &[base.id])
+        .await
+        .expect("Failed to create task with dependencies");
+    assert_eq!(deps.len(), 1);
+    assert_eq!(deps[0].task_id, task.id);
+    assert_eq!(deps[0].depends_on_task_id, base.id);
+
+    // A new task has no incoming edges, so the only cycle it can close runs
+    // through itself. The next sequence value is the id the insert will get
+    // (tests run single-threaded against the shared database).
+    let doomed_id: i64 = sqlx::query_scalar("SELECT last_value + 1 FROM tasks_id_seq")
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to read task id sequence");
+
+    // The first dependency is valid, the second is a cycle: nothing may remain
+    let result = task_service
+        .create_task_with_dependencies(
+            create_test_task(project.id, "Doomed Task"),
+            &[base.id, doomed_id],
+        )
+        .await;
+    assert!(
+        matches!(
+            result,
+            Err(deverp::utils::error::DevErpError::Validation(_))
+        ),
+        "Cyclic dependency should abort the create, got {:?}",
+        result
+    );
+
+    let filter = deverp::domain::task::entity::TaskFilter {
+        project_id: Some(project.id),
+        ..Default::default()
+    };
+    let remaining = task_service
+        .list_tasks(filter)
+        .await
+        .expect("Failed to list tasks");
+    assert_eq!(remaining.len(), 2, "Rolled back task must not be persisted");
+    assert!(remaining.iter().all(|t| t.title != "Doomed Task"));
+
+    let dependents = task_service
+        .get_task_dependents(base.id)
+        .await
+        .expect("Failed to get dependents");
+    assert_eq!(
+        dependents.len(),
+        1,
+        "Rolled back dependency must be removed"
+    );
+
+    println!("✅ Invalid dependency rolled back task creation");
+}
+
+/// The critical path follows the heaviest dependency chain within a project
+#[tokio::test]
+async fn test_compute_critical_path() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+
+    let project_service = project_service(&pool);
+    let task_service = task_service(&pool);
+
+    let project = project_service
+        .create_project(create_test_project("Critical Path"))
+        .await
+        .expect("Failed to create project");
+    let other = project_service
+        .create_project(create_test_project("Critical Path Elsewhere"))
+        .await
+        .expect("Failed to create project");
+
+    // Design (4h) -> Build (8h) -> Ship (unestimated), Docs (2h) -> Ship
+    let mut ids = Vec::new();
+    for (title, hours) in [
+        ("Design", Some(4.0)),
+        ("Build", Some(8.0)),
+        ("Docs", Some(2.0)),
+        ("Ship", None),
+    ] {
+        let mut input = create_test_task(project.id, title);
+        input.estimated_hours = hours;
+        ids.push(
+            task_service
+                .create_task(input)
+                .await
+                .expect("Failed to create task")
+                .id,
+        );
+    }
+    let foreign = task_service
+        .create_task(create_test_task(other.id, "Foreign"))
+        .await
+        .expect("Failed to create task");
+
+    // Before any dependency exists, the heaviest task is its own path
+    let trivial = task_service
+        .compute_critical_path(project.id)
+        .await
+        .expect("Failed to compute critical path");
+    assert_eq!(trivial.task_ids, vec![ids[1]]);
+    assert_eq!(trivial.total_hours, 8.0);
+
+    for (task_id, depends_on_task_id) in [
+        (ids[1], ids[0]),
+        (ids[3], ids[1]),
+        (ids[3], ids[2]),
+        (ids[0], foreign.id),
+    ] {
+        task_service
+            .add_task_dependency(CreateTaskDependency {
+                task_id,
+                depends_on_task_id,
+                dependency_type: Some(DependencyType::FinishToStart),
+            })
+            .await
+            .expect("Failed to add dependency");
+    }
+
+    let path = task_service
+        .compute_critical_path(project.id)
+        .await
+        .expect("Failed to compute critical path");
+    assert_eq!(path.task_ids, vec![ids[0], ids[1], ids[3]]);
+    assert_eq!(path.total_hours, 12.0);
+
+    println!("✅ Critical path computed across the dependency chain");
+}
+
+/// The single-query chain matches the breadth-first one, with and without a depth limit
+#[tokio::test]
+async fn test_batched_dependency_chain_matches_bfs() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+
+    let project_service = project_service(&pool);
+    let dependency_repo = Arc::new(PostgresTaskDependencyRepository::new(pool.clone()));
+    let task_service = task_service(&pool);
+
+    let project = project_service
+        .create_project(create_test_project("Chain Project"))
+        .await
+        .expect("Failed to create project");
+
+    let mut ids = Vec::new();
+    for title in ["A", "B", "C", "D", "E", "F"] {
+        let task = task_service
+            .create_task(create_test_task(project.id, title))
+            .await
+            .expect("Failed to create task");
+        ids.push(task.id);
+    }
+    let [a, b, c, d, e, f] = ids[..] else {
+        unreachable!()
+    };
+
+    // A needs B and C, both need D (a diamond), D needs E; F is unrelated
+    for (task_id, depends_on_task_id) in [(a, b), (a, c), (b, d), (c, d), (d, e)] {
+        task_service
+            .add_task_dependency(CreateTaskDependency {
+                task_id,
+                depends_on_task_id,
+                dependency_type: Some(DependencyType::FinishToStart),
+            })
+            .await
+            .expect("Failed to add dependency");
+    }
+
+    let sorted = |mut chain: Vec<i64>| {
+        chain.sort();
+        chain
+    };
+
+    let bfs = dependency_repo
+        .get_dependency_chain(a, None)
+        .await
+        .expect("Failed to walk chain");
+    let batched = dependency_repo
+        .get_dependency_chain_batched(a, None)
+        .await
+        .expect("Failed to fetch chain");
+    assert_eq!(batched[0], a);
+    assert_eq!(batched[3..], [d, e]);
+    assert!(!batched.contains(&f));
+    assert_eq!(sorted(bfs), sorted(batched.clone()));
+    assert_eq!(sorted(batched), sorted(vec![a, b, c, d, e]));
+
+    // One step out reaches only the direct dependencies
+    let bfs = dependency_repo
+        .get_dependency_chain(a, Some(1))
+        .await
+        .expect("Failed to walk chain");
+    let batched = dependency_repo
+        .get_dependency_chain_batched(a, Some(1))
+        .await
+        .expect("Failed to fetch chain");
+    assert_eq!(sorted(bfs), sorted(batched.clone()));
+    assert_eq!(sorted(batched), sorted(vec![a, b, c]));
+
+    let chain = task_service
+        .get_dependency_chain(e, None)
+        .await
+        .expect("Failed to get chain");
+    assert_eq!(chain, vec![e]);
+}
+
+/// Cycles written straight to the database are found by the audit
+#[tokio::test]
+async fn test_audit_cycles_finds_seeded_cycle() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+
+    let project_service = project_service(&pool);
+    let task_service = task_service(&pool);
+
+    let project = project_service
+        .create_project(create_test_project("Cycle Audit"))
+        .await
+        .expect("Failed to create project");
+
+    let mut ids = Vec::new();
+    for title in ["A", "B", "C", "D"] {
+        let task = task_service
+            .create_task(create_test_task(project.id, title))
+            .await
+            .expect("Failed to create task");
+        ids.push(task.id);
+    }
+    let [a, b, c, d] = ids[..] else {
+        unreachable!()
+    };
+
+    // D -> A is a legitimate edge made through the service
+    task_service
+        .add_task_dependency(CreateTaskDependency {
+            task_id: d,
+            depends_on_task_id: a,
+            dependency_type: Some(DependencyType::FinishToStart),
+        })
+        .await
+        .expect("Failed to add dependency");
+    let cycles = task_service
+        .audit_cycles(project.id)
+        .await
+        .expect("Failed to audit cycles");
+    assert!(cycles.is_empty());
+
+    // A -> B -> C -> A bypasses the cycle check, as a direct DB edit would
+    for (task_id, depends_on_task_id) in [(a, b), (b, c), (c, a)] {
+        sqlx::query("INSERT INTO task_dependencies (task_id, depends_on_task_id) VALUES ($1, $2)")
+            .bind(task_id)
+            .bind(depends_on_task_id)
+            .execute(&pool)
+            .await
+            .expect("Failed to seed dependency");
+    }
+
+    let cycles = task_service
+        .audit_cycles(project.id)
+        .await
+        .expect("Failed to audit cycles");
+    assert_eq!(cycles, vec![vec![a, b, c]]);
+}
+
+/// Marking a task done waits for its finish-to-start predecessors unless forced
+#[tokio::test]
+async fn test_done_requires_finished_predecessors() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+
+    let project_service = project_service(&pool);
+    let task_service = task_service(&pool);
+
+    let project = project_service
+        .create_project(create_test_project("Completion Order Project"))
+        .await
+        .expect("Failed to create project");
+
+    let mut ids = Vec::new();
+    for title in ["Ship", "Build", "Design", "Announce"] {
+        let task = task_service
+            .create_task(create_test_task(project.id, title))
+            .await
+            .expect("Failed to create task");
+        ids.push(task.id);
+    }
+    let [ship, build, design, announce] = ids[..] else {
+        unreachable!()
+    };
+
+    // Ship waits for Build to finish; Design only has to have started
+    for (task_id, depends_on_task_id, dependency_type) in [
+        (ship, build, DependencyType::FinishToStart),
+        (ship, design, DependencyType::StartToStart),
+        (announce, build, DependencyType::FinishToStart),
+    ] {
+        task_service
+            .add_task_dependency(CreateTaskDependency {
+                task_id,
+                depends_on_task_id,
+                dependency_type: Some(dependency_type),
+            })
+            .await
+            .expect("Failed to add dependency");
+    }
+
+    let mark_done = |id| UpdateTask {
+        id,
+        title: None,
+        description: None,
+        status: Some(TaskStatus::Done),
+        blocked_reason: None,
+        priority: None,
+        assigned_to: None,
+        estimated_hours: None,
+        actual_hours: None,
+        due_date: None,
+        task_type: None,
+        tags: None,
+        expected_version: None,
+    };
+
+    let result = task_service
+        .update_task(mark_done(ship), CompletionCheck::Enforce)
+        .await;
+    match result {
+        Err(DevErpError::Validation(msg)) => {
+            assert!(
+                msg.contains(&build.to_string()),
+                "lists open predecessor: {}",
+                msg
+            );
+            assert!(
+                !msg.contains(&design.to_string()),
+                "start-to-start is ignored: {}",
+                msg
+            );
+        }
+        other => panic!("Expected validation error, got {:?}", other),
+    }
+    let ship_task = task_service.get_task_by_id(ship).await.unwrap();
+    assert_eq!(
+        ship_task.status,
+        TaskStatus::Todo,
+        "Blocked update is not applied"
+    );
+
+    // Skipping the check completes the task anyway
+    let forced = task_service
+        .update_task(mark_done(ship), CompletionCheck::Skip)
+        .await
+        .expect("Forced update should succeed");
+    assert_eq!(forced.task.status, TaskStatus::Done);
+
+    // Once the predecessor is done, dependents complete normally
+    task_service
+        .update_task(mark_done(build), CompletionCheck::Enforce)
+        .await
+        .expect("Task without predecessors completes");
+    let announced = task_service
+        .update_task(mark_done(announce), CompletionCheck::Enforce)
+        .await
+        .expect("Predecessor is finished");
+    assert_eq!(announced.task.status, TaskStatus::Done);
+
+    println!("✅ Done status waits for finish-to-start predecessors");
+}
@@ -1,12 +1,12 @@
 mod helpers;
 
-use deverp::domain::project::service::ProjectService;
-use deverp::domain::resource::entity::ResourceStatus;
-use deverp::domain::resource::service::ResourceService;
-use deverp::infrastructure::repositories::project_repo::PostgresProjectRepository;
-use deverp::infrastructure::repositories::resource_repo::PostgresResourceRepository;
+use deverp::domain::resource::entity::{
+    CreateResourceDependency, ResourceFilter, ResourceStatus, ResourceType,
+};
+use deverp::domain::resource::repository::ResourceDependencyRepository;
+use deverp::infrastructure::repositories::resource_repo::PostgresResourceDependencyRepository;
+use deverp::utils::error::DevErpError;
 use helpers::*;
-use std::sync::Arc;
 
 /// Scenario 3: Resource management
 ///
@@ -23,11 +23,9 @@ async fn test_resource_management() {
         .expect("Failed to setup test database");
 
     // Initialize repositories and services
-    let project_repo = Arc::new(PostgresProjectRepository::new(pool.clone()));
-    let resource_repo = Arc::new(PostgresResourceRepository::new(pool.clone()));
 
-    let project_service = ProjectService::new(project_repo);
-    let resource_service = ResourceService::new(resource_repo);
+    let project_service = project_service(&pool);
+    let resource_service = resource_service(&pool);
 
     // Step 1: Create resources
     println!("Step 1: Creating resources...");
@@ -161,10 +159,7 @@ async fn test_resource_management() {
                 is_critical: Some(true),
             })
             .await
-            .expect(&format!(
-                "Failed to link resource {} to project 3",
-                resource_id
-            ));
+            .unwrap_or_else(|_| panic!("Failed to link resource {} to project 3", resource_id));
     }
 
     println!("Linked resources to projects");
@@ -214,6 +209,8 @@ async fn test_resource_management() {
             status: None,
             name_contains: None,
             tags: None,
+            tag_match_mode: Default::default(),
+            stale_days: None,
             offset: None,
             limit: None,
         })
@@ -318,6 +315,8 @@ async fn test_resource_management() {
             status: None,
             name_contains: None,
             tags: None,
+            tag_match_mode: Default::default(),
+            stale_days: None,
             offset: None,
             limit: None,
         })
@@ -340,6 +339,8 @@ async fn test_resource_management() {
             status: Some(ResourceStatus::Active),
             name_contains: None,
             tags: None,
+            tag_match_mode: Default::default(),
+            stale_days: None,
             offset: None,
             limit: None,
         })
@@ -357,6 +358,8 @@ async fn test_resource_management() {
             status: Some(ResourceStatus::Deprecated),
             name_contains: None,
             tags: None,
+            tag_match_mode: Default::default(),
+            stale_days: None,
             offset: None,
             limit: None,
         })
@@ -400,8 +403,7 @@ async fn test_resource_search() {
     let pool = setup_test_database()
         .await
         .expect("Failed to setup test database");
-    let resource_repo = Arc::new(PostgresResourceRepository::new(pool.clone()));
-    let resource_service = ResourceService::new(resource_repo);
+    let resource_service = resource_service(&pool);
 
     // Create resources with different names
     resource_service
@@ -433,3 +435,611 @@ async fn test_resource_search() {
 
     println!("✅ Resource search test completed successfully!");
 }
+
+/// Bulk deprecation by tag only touches resources carrying that tag
+#[tokio::test]
+async fn test_deprecate_resources_by_tag() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+
+    let project_service = project_service(&pool);
+    let resource_service = resource_service(&pool);
+
+    let mut legacy_input = create_test_resource("Legacy ORM");
+    legacy_input.tags = Some(vec!["legacy".to_string(), "orm".to_string()]);
+    let legacy = resource_service
+        .create_resource(legacy_input)
+        .await
+        .expect("Failed to create legacy resource");
+
+    let mut legacy_tool_input = create_test_resource("Legacy Build Tool");
+    legacy_tool_input.tags = Some(vec!["legacy".to_string()]);
+    let legacy_tool = resource_service
+        .create_resource(legacy_tool_input)
+        .await
+        .expect("Failed to create legacy tool");
+
+    let current = resource_service
+        .create_resource(create_test_resource("Current ORM"))
+        .await
+        .expect("Failed to create current resource");
+
+    let project = project_service
+        .create_project(create_test_project("Deprecation Target"))
+        .await
+        .expect("Failed to create project");
+    resource_service
+        .link_resource_to_project(deverp::domain::resource::entity::LinkResourceToProject {
+            project_id: project.id,
+            resource_id: legacy.id,
+            usage_notes: None,
+            version_used: None,
+            is_critical: Some(true),
+        })
+        .await
+        .expect("Failed to link legacy resource");
+
+    let result = resource_service
+        .deprecate_resources_by_tag("legacy", Some("EOL".to_string()))
+        .await
+        .expect("Failed to deprecate by tag");
+
+    let mut deprecated_ids: Vec<i64> = result.deprecated.iter().map(|r| r.id).collect();
+    deprecated_ids.sort();
+    assert_eq!(deprecated_ids, vec![legacy.id, legacy_tool.id]);
+    assert_eq!(result.already_deprecated, 0);
+    assert_eq!(result.affected_links.len(), 1);
+    assert_eq!(result.affected_links[0].project_id, project.id);
+
+    let legacy = resource_service.get_resource(legacy.id).await.unwrap();
+    assert_eq!(legacy.status, Some(ResourceStatus::Deprecated));
+    assert_eq!(legacy.metadata.unwrap()["deprecation_reason"], "EOL");
+
+    let current = resource_service.get_resource(current.id).await.unwrap();
+    assert_eq!(current.status, Some(ResourceStatus::Active));
+
+    // Running again finds nothing new to deprecate
+    let rerun = resource_service
+        .deprecate_resources_by_tag("legacy", None)
+        .await
+        .expect("Failed to re-run deprecation");
+    assert!(rerun.deprecated.is_empty());
+    assert_eq!(rerun.already_deprecated, 2);
+
+    println!("✅ Only tagged resources were deprecated");
+}
+
+/// Project resource details carry the link's version and list critical links first
+#[tokio::test]
+async fn test_project_resource_details() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+
+    let project_service = project_service(&pool);
+    let resource_service = resource_service(&pool);
+
+    let project = project_service
+        .create_project(create_test_project("Detail Project"))
+        .await
+        .expect("Failed to create project");
+
+    let optional = resource_service
+        .create_resource(create_test_resource("Alpha Lint"))
+        .await
+        .expect("Failed to create resource");
+    let critical = resource_service
+        .create_resource(create_test_resource("Zeta Runtime"))
+        .await
+        .expect("Failed to create resource");
+
+    for (resource_id, version, is_critical) in
+        [(optional.id, "0.3.1", false), (critical.id, "2.4.0", true)]
+    {
+        resource_service
+            .link_resource_to_project(deverp::domain::resource::entity::LinkResourceToProject {
+                project_id: project.id,
+                resource_id,
+                usage_notes: None,
+                version_used: Some(version.to_string()),
+                is_critical: Some(is_critical),
+            })
+            .await
+            .expect("Failed to link resource");
+    }
+
+    let details = resource_service
+        .get_project_resource_details(project.id)
+        .await
+        .expect("Failed to get resource details");
+
+    assert_eq!(details.len(), 2);
+    assert_eq!(
+        details[0].resource.id, critical.id,
+        "Critical resource first"
+    );
+    assert_eq!(details[0].link.version_used.as_deref(), Some("2.4.0"));
+    assert_eq!(details[0].link.is_critical, Some(true));
+    assert_eq!(details[1].resource.id, optional.id);
+    assert_eq!(details[1].link.version_used.as_deref(), Some("0.3.1"));
+
+    println!("✅ Project resource details include link versions");
+}
+
+/// The full resource record serializes usage and linked projects alongside the resource
+#[tokio::test]
+async fn test_resource_record_json() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+
+    let project_service = project_service(&pool);
+    let resource_service = resource_service(&pool);
+
+    let resource = resource_service
+        .create_resource(create_test_resource("Record Cache"))
+        .await
+        .expect("Failed to create resource");
+
+    for (name, is_critical) in [("Record Api", true), ("Record Worker", false)] {
+        let project = project_service
+            .create_project(create_test_project(name))
+            .await
+            .expect("Failed to create project");
+        resource_service
+            .link_resource_to_project(deverp::domain::resource::entity::LinkResourceToProject {
+                project_id: project.id,
+                resource_id: resource.id,
+                usage_notes: None,
+                version_used: None,
+                is_critical: Some(is_critical),
+            })
+            .await
+            .expect("Failed to link resource");
+    }
+
+    let record = resource_service
+        .get_resource_record(resource.id)
+        .await
+        .expect("Failed to get resource record");
+    let json = serde_json::to_value(&record).expect("Failed to serialize record");
+
+    assert_eq!(json["resource"]["id"], resource.id);
+    assert_eq!(json["usage"]["total_projects"], 2);
+    assert_eq!(json["usage"]["critical_projects"], 1);
+    let projects = json["projects"].as_array().expect("projects array");
+    assert_eq!(projects.len(), 2);
+    assert_eq!(projects[0]["project_name"], "Record Api");
+    assert_eq!(projects[0]["is_critical"], true);
+
+    println!("✅ Full resource record includes usage and projects");
+}
+
+#[tokio::test]
+async fn test_count_resources_matches_list() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+
+    let resource_service = resource_service(&pool);
+
+    for (name, resource_type) in [
+        ("serde", ResourceType::Library),
+        ("tokio", ResourceType::Library),
+        ("GitHub API", ResourceType::Api),
+    ] {
+        let mut input = create_test_resource(name);
+        input.resource_type = resource_type;
+        resource_service
+            .create_resource(input)
+            .await
+            .expect("Failed to create resource");
+    }
+
+    let libraries = ResourceFilter {
+        resource_type: Some(ResourceType::Library),
+        limit: Some(1),
+        ..Default::default()
+    };
+
+    // Pagination limits the list but not the count
+    let listed = resource_service
+        .list_resources(libraries.clone())
+        .await
+        .expect("Failed to list resources");
+    assert_eq!(listed.len(), 1);
+    assert_eq!(
+        resource_service.count_resources(libraries).await.unwrap(),
+        2
+    );
+
+    let by_name = ResourceFilter {
+        name_contains: Some("API".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(resource_service.count_resources(by_name).await.unwrap(), 1);
+
+    println!("✅ Resource count matches list filters");
+}
+
+/// Test that --stale returns only resources untouched for the given number of days
+#[tokio::test]
+async fn test_list_stale_resources() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+
+    let resource_service = resource_service(&pool);
+
+    let stale = resource_service
+        .create_resource(create_test_resource("Legacy SDK"))
+        .await
+        .expect("Failed to create resource");
+    let fresh = resource_service
+        .create_resource(create_test_resource("Current SDK"))
+        .await
+        .expect("Failed to create resource");
+
+    // Backdate one resource; the updated_at trigger would otherwise reset it
+    let mut tx = pool.begin().await.expect("Failed to begin transaction");
+    sqlx::query("ALTER TABLE resources DISABLE TRIGGER update_resources_updated_at")
+        .execute(&mut *tx)
+        .await
+        .expect("Failed to disable trigger");
+    sqlx::query("UPDATE resources SET updated_at = NOW() - INTERVAL '200 days' WHERE id = $1")
+        .bind(stale.id)
+        .execute(&mut *tx)
+        .await
+        .expect("Failed to backdate resource");
+    sqlx::query("ALTER TABLE resources ENABLE TRIGGER update_resources_updated_at")
+        .execute(&mut *tx)
+        .await
+        .expect("Failed to enable trigger");
+    tx.commit().await.expect("Failed to commit");
+
+    let filter = ResourceFilter {
+        stale_days: Some(90),
+        ..Default::default()
+    };
+    let listed = resource_service
+        .list_resources(filter.clone())
+        .await
+        .expect("Failed to list stale resources");
+    let ids: Vec<i64> = listed.iter().map(|r| r.id).collect();
+    assert_eq!(ids, vec![stale.id]);
+    assert!(!ids.contains(&fresh.id));
+    assert_eq!(resource_service.count_resources(filter).await.unwrap(), 1);
+
+    // Combined with status to focus on active-but-stale entries
+    let active_stale = ResourceFilter {
+        stale_days: Some(90),
+        status: Some(ResourceStatus::Active),
+        ..Default::default()
+    };
+    let deprecated_stale = ResourceFilter {
+        status: Some(ResourceStatus::Deprecated),
+        ..active_stale.clone()
+    };
+    assert_eq!(
+        resource_service
+            .count_resources(active_stale)
+            .await
+            .unwrap(),
+        1
+    );
+    assert_eq!(
+        resource_service
+            .count_resources(deprecated_stale)
+            .await
+            .unwrap(),
+        0
+    );
+
+    println!("✅ Stale resource filter test passed");
+}
+
+/// The risk report lists deprecated resources still linked as critical
+#[tokio::test]
+async fn test_find_critical_deprecated() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+
+    let project_service = project_service(&pool);
+    let resource_service = resource_service(&pool);
+
+    let billing = project_service
+        .create_project(create_test_project("Risk Billing"))
+        .await
+        .expect("Failed to create project");
+    let portal = project_service
+        .create_project(create_test_project("Risk Portal"))
+        .await
+        .expect("Failed to create project");
+
+    let mut created = Vec::new();
+    for name in [
+        "Risky Crypto Lib",
+        "Optional Charts",
+        "Dropped Queue",
+        "Healthy Cache",
+    ] {
+        created.push(
+            resource_service
+                .create_resource(create_test_resource(name))
+                .await
+                .expect("Failed to create resource"),
+        );
+    }
+    let [crypto, charts, queue, cache] = created.as_slice() else {
+        unreachable!();
+    };
+
+    let links = [
+        (billing.id, crypto.id, true),
+        (portal.id, crypto.id, true),
+        // Not critical, so no risk
+        (billing.id, charts.id, false),
+        // Critical, but the link is removed below
+        (portal.id, queue.id, true),
+        // Critical, but the resource stays active
+        (billing.id, cache.id, true),
+    ];
+    for (project_id, resource_id, is_critical) in links {
+        resource_service
+            .link_resource_to_project(deverp::domain::resource::entity::LinkResourceToProject {
+                project_id,
+                resource_id,
+                usage_notes: None,
+                version_used: None,
+                is_critical: Some(is_critical),
+            })
+            .await
+            .expect("Failed to link resource");
+    }
+    resource_service
+        .unlink_resource_from_project(portal.id, queue.id)
+        .await
+        .expect("Failed to unlink resource");
+
+    for resource in [crypto, charts, queue] {
+        resource_service
+            .deprecate_resource(resource.id, None)
+            .await
+            .expect("Failed to deprecate resource");
+    }
+
+    let ours = [crypto.id, charts.id, queue.id, cache.id];
+    let risks: Vec<_> = resource_service
+        .find_critical_deprecated()
+        .await
+        .expect("Failed to build risk report")
+        .into_iter()
+        .filter(|risk| ours.contains(&risk.resource_id))
+        .collect();
+
+    assert_eq!(risks.len(), 1);
+    assert_eq!(risks[0].resource_id, crypto.id);
+    assert_eq!(risks[0].resource_name, "Risky Crypto Lib");
+    let mut expected = vec![billing.id, portal.id];
+    expected.sort();
+    assert_eq!(risks[0].affected_project_ids, expected);
+
+    println!("✅ Critical deprecated resources reported");
+}
+
+/// The license report groups resources by license and flags those outside the allowlist
+#[tokio::test]
+async fn test_generate_license_report() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+
+    let project_service = project_service(&pool);
+    let resource_service = resource_service(&pool).with_allowed_licenses(vec!["mit".to_string()]);
+
+    let billing = project_service
+        .create_project(create_test_project("License Billing"))
+        .await
+        .expect("Failed to create project");
+    let portal = project_service
+        .create_project(create_test_project("License Portal"))
+        .await
+        .expect("Failed to create project");
+
+    let mut created = Vec::new();
+    for (name, license) in [
+        ("License Permissive Lib", Some("MIT")),
+        ("License Copyleft Lib", Some("GPL-3.0")),
+        ("License Vendored Lib", None),
+        ("License Orphaned Lib", Some("AGPL-3.0")),
+    ] {
+        let mut input = create_test_resource(name);
+        input.license = license.map(str::to_string);
+        created.push(
+            resource_service
+                .create_resource(input)
+                .await
+                .expect("Failed to create resource"),
+        );
+    }
+    let [permissive, copyleft, vendored, orphaned] = created.as_slice() else {
+        unreachable!();
+    };
+
+    // Only used by a project that is then deleted: must still be reported
+    let retired = project_service
+        .create_project(create_test_project("License Retired"))
+        .await
+        .expect("Failed to create project");
+
+    for (project_id, resource_id) in [
+        (billing.id, permissive.id),
+        (portal.id, permissive.id),
+        (portal.id, copyleft.id),
+        (retired.id, orphaned.id),
+    ] {
+        resource_service
+            .link_resource_to_project(deverp::domain::resource::entity::LinkResourceToProject {
+                project_id,
+                resource_id,
+                usage_notes: None,
+                version_used: None,
+                is_critical: None,
+            })
+            .await
+            .expect("Failed to link resource");
+    }
+
+    project_service
+        .delete_project(retired.id)
+        .await
+        .expect("Failed to delete project");
+
+    let report = resource_service
+        .generate_license_report()
+        .await
+        .expect("Failed to build license report");
+
+    let find = |resource_id: i64| {
+        report
+            .groups
+            .iter()
+            .find_map(|group| {
+                group
+                    .resources
+                    .iter()
+                    .find(|resource| resource.resource_id == resource_id)
+                    .map(|resource| (group, resource))
+            })
+            .expect("Resource missing from license report")
+    };
+
+    let (group, resource) = find(permissive.id);
+    assert_eq!(group.license, "MIT");
+    assert!(group.compliant);
+    assert_eq!(
+        resource.project_names,
+        vec!["License Billing".to_string(), "License Portal".to_string()]
+    );
+
+    let (group, resource) = find(copyleft.id);
+    assert_eq!(group.license, "GPL-3.0");
+    assert!(!group.compliant);
+    assert_eq!(resource.project_names, vec!["License Portal".to_string()]);
+
+    let (group, resource) = find(vendored.id);
+    assert_eq!(group.license, "(unspecified)");
+    assert!(!group.compliant);
+    assert!(resource.project_names.is_empty());
+    assert_eq!(report.groups.last().unwrap().license, "(unspecified)");
+
+    let (group, resource) = find(orphaned.id);
+    assert_eq!(group.license, "AGPL-3.0");
+    assert!(!group.compliant);
+    assert!(resource.project_names.is_empty());
+
+    println!("✅ License report grouped and flagged resources");
+}
+
+/// Resource dependency cycles are rejected, even through soft-deleted resources
+#[tokio::test]
+async fn test_resource_dependency_cycles_through_deleted_resources() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+
+    let resource_service = resource_service(&pool);
+
+    let mut ids = Vec::new();
+    for name in ["Cycle App", "Cycle Lib", "Cycle Runtime"] {
+        let resource = resource_service
+            .create_resource(create_test_resource(name))
+            .await
+            .expect("Failed to create resource");
+        ids.push(resource.id);
+    }
+    let (app, lib, runtime) = (ids[0], ids[1], ids[2]);
+    let edge = |resource_id, depends_on_resource_id| CreateResourceDependency {
+        resource_id,
+        depends_on_resource_id,
+    };
+
+    // App -> Lib -> Runtime
+    for (from, to) in [(app, lib), (lib, runtime)] {
+        resource_service
+            .add_resource_dependency(edge(from, to))
+            .await
+            .expect("Failed to add dependency");
+    }
+
+    let duplicate = resource_service
+        .add_resource_dependency(edge(app, lib))
+        .await;
+    assert!(matches!(duplicate, Err(DevErpError::Conflict(_))));
+
+    // Deleting Lib hides it, but restoring it would revive Runtime -> App -> Lib -> Runtime
+    resource_service
+        .delete_resource(lib)
+        .await
+        .expect("Failed to delete resource");
+    let cycle = resource_service
+        .add_resource_dependency(edge(runtime, app))
+        .await;
+    assert!(matches!(cycle, Err(DevErpError::Validation(msg)) if msg.contains("circular")));
+
+    println!("✅ Resource dependency cycles rejected through deleted resources");
+}
+
+/// Concurrent additions that would only form a cycle together must not both succeed
+#[tokio::test]
+async fn test_concurrent_resource_dependencies_cannot_close_cycle() {
+    let pool = setup_test_database()
+        .await
+        .expect("Failed to setup test database");
+
+    let resource_service = resource_service(&pool);
+
+    let mut ids = Vec::new();
+    for name in ["Race A", "Race B", "Race C", "Race D"] {
+        let resource = resource_service
+            .create_resource(create_test_resource(name))
+            .await
+            .expect("Failed to create resource");
+        ids.push(resource.id);
+    }
+    let (a, b, c, d) = (ids[0], ids[1], ids[2], ids[3]);
+    let edge = |resource_id, depends_on_resource_id| CreateResourceDependency {
+        resource_id,
+        depends_on_resource_id,
+    };
+
+    // Existing B -> C and D -> A; A -> B and C -> D together close a cycle
+    let repo = PostgresResourceDependencyRepository::new(pool.clone());
+    repo.add(edge(b, c))
+        .await
+        .expect("Failed to add dependency");
+    repo.add(edge(d, a))
+        .await
+        .expect("Failed to add dependency");
+
+    let first = PostgresResourceDependencyRepository::new(pool.clone());
+    let second = PostgresResourceDependencyRepository::new(pool.clone());
+    let (ab, cd) = tokio::join!(first.add(edge(a, b)), second.add(edge(c, d)));
+
+    assert!(
+        ab.is_ok() != cd.is_ok(),
+        "Exactly one of the concurrent additions should succeed"
+    );
+    let failed = if ab.is_ok() { cd } else { ab };
+    assert!(matches!(failed, Err(DevErpError::Validation(_))));
+
+    let edges: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM resource_dependencies")
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to count dependencies");
+    assert_eq!(edges, 3);
+
+    println!("✅ Concurrent resource dependency additions kept the graph acyclic");
+}